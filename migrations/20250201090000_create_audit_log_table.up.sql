@@ -0,0 +1,25 @@
+CREATE TYPE "audit_action" AS ENUM ('create', 'update', 'delete');
+CREATE TYPE "audit_entity" AS ENUM ('event', 'employment', 'job_position', 'user', 'venue');
+CREATE TABLE IF NOT EXISTS "audit_log" (
+    "id" SERIAL PRIMARY KEY,
+    "actor_id" INT,
+    "entity" "audit_entity" NOT NULL,
+    "entity_id" INT NOT NULL,
+    "event_id" INT,
+    "action" "audit_action" NOT NULL,
+    "before" JSONB,
+    "after" JSONB,
+    "created_at" TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+CREATE INDEX IF NOT EXISTS "audit_log_entity_idx" ON "audit_log" ("entity", "entity_id");
+CREATE INDEX IF NOT EXISTS "audit_log_event_idx" ON "audit_log" ("event_id");
+
+CREATE OR REPLACE FUNCTION "audit_log_append_only"() RETURNS TRIGGER AS $$
+BEGIN
+    RAISE EXCEPTION 'audit_log is append-only';
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER "audit_log_append_only"
+    BEFORE UPDATE OR DELETE ON "audit_log"
+    FOR EACH ROW EXECUTE FUNCTION "audit_log_append_only"();
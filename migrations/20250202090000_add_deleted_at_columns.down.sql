@@ -0,0 +1,4 @@
+ALTER TABLE "user" DROP COLUMN IF EXISTS "deleted_at";
+ALTER TABLE "job_position" DROP COLUMN IF EXISTS "deleted_at";
+ALTER TABLE "venue" DROP COLUMN IF EXISTS "deleted_at";
+ALTER TABLE "event" DROP COLUMN IF EXISTS "deleted_at";
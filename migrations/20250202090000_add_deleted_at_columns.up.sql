@@ -0,0 +1,4 @@
+ALTER TABLE "event" ADD COLUMN "deleted_at" TIMESTAMPTZ;
+ALTER TABLE "venue" ADD COLUMN "deleted_at" TIMESTAMPTZ;
+ALTER TABLE "job_position" ADD COLUMN "deleted_at" TIMESTAMPTZ;
+ALTER TABLE "user" ADD COLUMN "deleted_at" TIMESTAMPTZ;
@@ -0,0 +1,3 @@
+-- PostgreSQL cannot drop values from an enum type; the values are removed
+-- together with the type by the audit log table migration.
+SELECT 1;
@@ -0,0 +1,2 @@
+ALTER TYPE "audit_action" ADD VALUE IF NOT EXISTS 'restore';
+ALTER TYPE "audit_action" ADD VALUE IF NOT EXISTS 'purge';
@@ -0,0 +1,3 @@
+-- PostgreSQL cannot drop values from an enum type, so 'cancelled' stays in "employment_state".
+ALTER TABLE "event" DROP COLUMN IF EXISTS "cancellation_reason";
+ALTER TABLE "event" DROP COLUMN IF EXISTS "cancelled_at";
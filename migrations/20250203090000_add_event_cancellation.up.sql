@@ -0,0 +1,3 @@
+ALTER TABLE "event" ADD COLUMN "cancelled_at" TIMESTAMPTZ;
+ALTER TABLE "event" ADD COLUMN "cancellation_reason" TEXT;
+ALTER TYPE "employment_state" ADD VALUE IF NOT EXISTS 'cancelled';
@@ -0,0 +1,9 @@
+CREATE TABLE IF NOT EXISTS "notification" (
+    "id" SERIAL PRIMARY KEY,
+    "user_id" INT NOT NULL REFERENCES "user" ("id") ON DELETE CASCADE,
+    "message" TEXT NOT NULL,
+    "created_at" TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    "read_at" TIMESTAMPTZ
+);
+
+CREATE INDEX IF NOT EXISTS "notification_user_id_idx" ON "notification" ("user_id");
@@ -0,0 +1,4 @@
+ALTER TABLE "event" ADD COLUMN "is_draft" BOOLEAN NOT NULL DEFAULT FALSE;
+UPDATE "event" SET "is_draft" = ("status" = 'draft');
+ALTER TABLE "event" DROP COLUMN IF EXISTS "status";
+DROP TYPE IF EXISTS "event_status";
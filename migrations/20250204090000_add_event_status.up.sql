@@ -0,0 +1,21 @@
+CREATE TYPE "event_status" AS ENUM (
+    'draft',
+    'published',
+    'registration_closed',
+    'in_progress',
+    'completed',
+    'archived',
+    'cancelled'
+);
+
+ALTER TABLE "event" ADD COLUMN "status" "event_status" NOT NULL DEFAULT 'draft';
+
+UPDATE "event" SET "status" = CASE
+    WHEN "cancelled_at" IS NOT NULL THEN 'cancelled'::"event_status"
+    WHEN "is_draft" THEN 'draft'::"event_status"
+    WHEN "date_end" < CURRENT_DATE THEN 'completed'::"event_status"
+    WHEN "date_start" <= CURRENT_DATE THEN 'in_progress'::"event_status"
+    ELSE 'published'::"event_status"
+END;
+
+ALTER TABLE "event" DROP COLUMN "is_draft";
@@ -0,0 +1,4 @@
+DROP TABLE IF EXISTS "scheduler_run";
+ALTER TABLE "event" DROP COLUMN IF EXISTS "publish_at";
+ALTER TABLE "job_position" DROP COLUMN IF EXISTS "registration_closes_at";
+ALTER TABLE "job_position" DROP COLUMN IF EXISTS "registration_opens_at";
@@ -0,0 +1,10 @@
+ALTER TABLE "job_position" ADD COLUMN "registration_opens_at" TIMESTAMPTZ;
+ALTER TABLE "job_position" ADD COLUMN "registration_closes_at" TIMESTAMPTZ;
+ALTER TABLE "event" ADD COLUMN "publish_at" TIMESTAMPTZ;
+
+CREATE TABLE IF NOT EXISTS "scheduler_run" (
+    "task" TEXT PRIMARY KEY,
+    "last_run_at" TIMESTAMPTZ NOT NULL
+);
+
+INSERT INTO "scheduler_run" ("task", "last_run_at") VALUES ('registration_windows', NOW());
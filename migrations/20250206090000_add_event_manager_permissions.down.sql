@@ -0,0 +1,6 @@
+ALTER TABLE "event_manager_relation" DROP COLUMN "can_manage_managers";
+ALTER TABLE "event_manager_relation" DROP COLUMN "can_view_payroll";
+ALTER TABLE "event_manager_relation" DROP COLUMN "can_approve_hours";
+ALTER TABLE "event_manager_relation" DROP COLUMN "can_manage_applicants";
+ALTER TABLE "event_manager_relation" DROP COLUMN "can_manage_jobs";
+ALTER TABLE "event_manager_relation" DROP COLUMN "can_edit_event";
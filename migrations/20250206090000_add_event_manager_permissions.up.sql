@@ -0,0 +1,15 @@
+ALTER TABLE "event_manager_relation" ADD COLUMN "can_edit_event" BOOLEAN NOT NULL DEFAULT FALSE;
+ALTER TABLE "event_manager_relation" ADD COLUMN "can_manage_jobs" BOOLEAN NOT NULL DEFAULT FALSE;
+ALTER TABLE "event_manager_relation" ADD COLUMN "can_manage_applicants" BOOLEAN NOT NULL DEFAULT FALSE;
+ALTER TABLE "event_manager_relation" ADD COLUMN "can_approve_hours" BOOLEAN NOT NULL DEFAULT FALSE;
+ALTER TABLE "event_manager_relation" ADD COLUMN "can_view_payroll" BOOLEAN NOT NULL DEFAULT FALSE;
+ALTER TABLE "event_manager_relation" ADD COLUMN "can_manage_managers" BOOLEAN NOT NULL DEFAULT FALSE;
+
+-- Existing managers keep the full access they had so far.
+UPDATE "event_manager_relation" SET
+    "can_edit_event" = TRUE,
+    "can_manage_jobs" = TRUE,
+    "can_manage_applicants" = TRUE,
+    "can_approve_hours" = TRUE,
+    "can_view_payroll" = TRUE,
+    "can_manage_managers" = TRUE;
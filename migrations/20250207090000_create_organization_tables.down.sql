@@ -0,0 +1,6 @@
+ALTER TABLE "position_category" DROP COLUMN IF EXISTS "organization_id";
+ALTER TABLE "venue" DROP COLUMN IF EXISTS "organization_id";
+ALTER TABLE "event" DROP COLUMN IF EXISTS "organization_id";
+DROP TABLE IF EXISTS "organization_membership";
+DROP TABLE IF EXISTS "organization";
+DROP TYPE IF EXISTS "organization_role";
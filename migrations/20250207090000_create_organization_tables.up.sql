@@ -0,0 +1,45 @@
+CREATE TYPE "organization_role" AS ENUM ('owner', 'organizer', 'employee');
+CREATE TABLE IF NOT EXISTS "organization" (
+    "id" SERIAL PRIMARY KEY,
+    "name" TEXT UNIQUE NOT NULL,
+    "invite_code" TEXT UNIQUE NOT NULL DEFAULT gen_random_uuid()::TEXT,
+    "created_at" TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+CREATE TABLE IF NOT EXISTS "organization_membership" (
+    "organization_id" INT NOT NULL REFERENCES "organization" ("id") ON DELETE CASCADE,
+    "user_id" INT NOT NULL REFERENCES "user" ("id") ON DELETE CASCADE,
+    "role" "organization_role" NOT NULL,
+    "created_at" TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY ("organization_id", "user_id")
+);
+CREATE INDEX IF NOT EXISTS "organization_membership_user_id_idx" ON "organization_membership" ("user_id");
+
+-- Everything created so far belongs to a single agency.
+INSERT INTO "organization" ("name") VALUES ('GigLog');
+
+INSERT INTO "organization_membership" ("organization_id", "user_id", "role")
+SELECT
+    (SELECT "id" FROM "organization" WHERE "name" = 'GigLog'),
+    "id",
+    CASE "role"
+        WHEN 'admin' THEN 'owner'::"organization_role"
+        WHEN 'organizer' THEN 'organizer'::"organization_role"
+        ELSE 'employee'::"organization_role"
+    END
+FROM "user";
+
+ALTER TABLE "event" ADD COLUMN "organization_id" INT REFERENCES "organization" ("id");
+ALTER TABLE "venue" ADD COLUMN "organization_id" INT REFERENCES "organization" ("id");
+ALTER TABLE "position_category" ADD COLUMN "organization_id" INT REFERENCES "organization" ("id");
+
+UPDATE "event" SET "organization_id" = (SELECT "id" FROM "organization" WHERE "name" = 'GigLog');
+UPDATE "venue" SET "organization_id" = (SELECT "id" FROM "organization" WHERE "name" = 'GigLog');
+UPDATE "position_category" SET "organization_id" = (SELECT "id" FROM "organization" WHERE "name" = 'GigLog');
+
+ALTER TABLE "event" ALTER COLUMN "organization_id" SET NOT NULL;
+ALTER TABLE "venue" ALTER COLUMN "organization_id" SET NOT NULL;
+ALTER TABLE "position_category" ALTER COLUMN "organization_id" SET NOT NULL;
+
+CREATE INDEX IF NOT EXISTS "event_organization_id_idx" ON "event" ("organization_id");
+CREATE INDEX IF NOT EXISTS "venue_organization_id_idx" ON "venue" ("organization_id");
+CREATE INDEX IF NOT EXISTS "position_category_organization_id_idx" ON "position_category" ("organization_id");
@@ -0,0 +1,2 @@
+-- PostgreSQL cannot drop values from an enum type, so the invitation states stay in "employment_state".
+ALTER TABLE "employment" DROP COLUMN IF EXISTS "invitation_expires_at";
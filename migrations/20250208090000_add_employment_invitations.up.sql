@@ -0,0 +1,4 @@
+ALTER TYPE "employment_state" ADD VALUE IF NOT EXISTS 'invited';
+ALTER TYPE "employment_state" ADD VALUE IF NOT EXISTS 'declined';
+ALTER TYPE "employment_state" ADD VALUE IF NOT EXISTS 'expired';
+ALTER TABLE "employment" ADD COLUMN "invitation_expires_at" TIMESTAMPTZ;
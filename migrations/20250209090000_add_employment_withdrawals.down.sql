@@ -0,0 +1,3 @@
+-- PostgreSQL cannot drop values from an enum type, so 'withdrawn' stays in "employment_state".
+DROP TABLE IF EXISTS "employment_withdrawal";
+ALTER TABLE "event" DROP COLUMN IF EXISTS "withdrawal_cutoff_hours";
@@ -0,0 +1,14 @@
+ALTER TYPE "employment_state" ADD VALUE IF NOT EXISTS 'withdrawn';
+
+ALTER TABLE "event" ADD COLUMN "withdrawal_cutoff_hours" INT NOT NULL DEFAULT 48;
+
+CREATE TABLE IF NOT EXISTS "employment_withdrawal" (
+    "id" SERIAL PRIMARY KEY,
+    "employment_id" INT NOT NULL REFERENCES "employment" ("id") ON DELETE CASCADE,
+    "user_id" INT NOT NULL REFERENCES "user" ("id") ON DELETE CASCADE,
+    "reason" TEXT,
+    "is_late" BOOLEAN NOT NULL,
+    "created_at" TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS "employment_withdrawal_user_id_idx" ON "employment_withdrawal" ("user_id");
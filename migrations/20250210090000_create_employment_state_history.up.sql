@@ -0,0 +1,14 @@
+CREATE TABLE IF NOT EXISTS "employment_state_history" (
+    "id" SERIAL PRIMARY KEY,
+    "employment_id" INT NOT NULL REFERENCES "employment" ("id") ON DELETE CASCADE,
+    "from_state" "employment_state",
+    "to_state" "employment_state" NOT NULL,
+    "changed_by" INT REFERENCES "user" ("id") ON DELETE SET NULL,
+    "changed_at" TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS "employment_state_history_employment_id_idx" ON "employment_state_history" ("employment_id");
+
+-- Existing employments start their history in the state they are in now.
+INSERT INTO "employment_state_history" ("employment_id", "from_state", "to_state")
+SELECT "id", NULL, "state" FROM "employment";
@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS "attendance_record";
+DROP TYPE IF EXISTS "attendance_outcome";
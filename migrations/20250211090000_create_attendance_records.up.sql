@@ -0,0 +1,14 @@
+CREATE TYPE "attendance_outcome" AS ENUM ('attended', 'late', 'no_show', 'left_early');
+
+CREATE TABLE IF NOT EXISTS "attendance_record" (
+    "id" SERIAL PRIMARY KEY,
+    "employment_id" INT NOT NULL REFERENCES "employment" ("id") ON DELETE CASCADE,
+    "date" DATE NOT NULL,
+    "outcome" "attendance_outcome" NOT NULL,
+    "note" TEXT,
+    "recorded_by" INT REFERENCES "user" ("id") ON DELETE SET NULL,
+    "created_at" TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE ("employment_id", "date")
+);
+
+CREATE INDEX IF NOT EXISTS "attendance_record_outcome_idx" ON "attendance_record" ("outcome", "date");
@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS "review";
+DROP TYPE IF EXISTS "review_direction";
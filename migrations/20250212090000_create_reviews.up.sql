@@ -0,0 +1,27 @@
+CREATE TYPE "review_direction" AS ENUM ('organizer_to_worker', 'worker_to_organizer');
+
+CREATE TABLE IF NOT EXISTS "review" (
+    "id" SERIAL PRIMARY KEY,
+    "employment_id" INT NOT NULL REFERENCES "employment" ("id") ON DELETE CASCADE,
+    "direction" "review_direction" NOT NULL,
+    "author_id" INT REFERENCES "user" ("id") ON DELETE SET NULL,
+    -- The reviewed worker, or the owner of the event for reviews written by workers.
+    "subject_id" INT NOT NULL REFERENCES "user" ("id") ON DELETE CASCADE,
+    "event_id" INT NOT NULL REFERENCES "event" ("id") ON DELETE CASCADE,
+    "overall" INT NOT NULL CHECK ("overall" BETWEEN 1 AND 5),
+    "punctuality" INT CHECK ("punctuality" BETWEEN 1 AND 5),
+    "skill" INT CHECK ("skill" BETWEEN 1 AND 5),
+    "organization" INT CHECK ("organization" BETWEEN 1 AND 5),
+    "communication" INT CHECK ("communication" BETWEEN 1 AND 5),
+    "comment" TEXT NOT NULL DEFAULT '',
+    "flagged_at" TIMESTAMPTZ,
+    "flagged_by" INT REFERENCES "user" ("id") ON DELETE SET NULL,
+    "flag_reason" TEXT,
+    "is_hidden" BOOLEAN NOT NULL DEFAULT FALSE,
+    "created_at" TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE ("employment_id", "direction")
+);
+
+CREATE INDEX IF NOT EXISTS "review_subject_id_idx" ON "review" ("subject_id", "direction");
+CREATE INDEX IF NOT EXISTS "review_event_id_idx" ON "review" ("event_id");
+CREATE INDEX IF NOT EXISTS "review_flagged_at_idx" ON "review" ("flagged_at") WHERE "flagged_at" IS NOT NULL;
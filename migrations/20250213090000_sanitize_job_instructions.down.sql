@@ -0,0 +1,3 @@
+UPDATE "job_position" SET "instructions_html" = "instructions_markdown";
+
+ALTER TABLE "job_position" DROP COLUMN IF EXISTS "instructions_markdown";
@@ -0,0 +1,9 @@
+-- Instructions are authored in Markdown and rendered into sanitized HTML by the app.
+ALTER TABLE "job_position" ADD COLUMN "instructions_markdown" TEXT NOT NULL DEFAULT '';
+
+-- Existing instructions become the Markdown source. Their stored HTML is cleared, so that
+-- nothing unsanitized is shown, until `pv281-giglog sanitize-instructions` renders it again.
+UPDATE "job_position"
+SET
+    "instructions_markdown" = "instructions_html",
+    "instructions_html" = '';
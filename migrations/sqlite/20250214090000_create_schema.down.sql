@@ -0,0 +1,19 @@
+DROP TABLE IF EXISTS "review";
+DROP TABLE IF EXISTS "attendance_record";
+DROP TABLE IF EXISTS "notification";
+DROP TRIGGER IF EXISTS "audit_log_no_delete";
+DROP TRIGGER IF EXISTS "audit_log_no_update";
+DROP TABLE IF EXISTS "audit_log";
+DROP TABLE IF EXISTS "event_manager_relation";
+DROP TABLE IF EXISTS "worked_hours";
+DROP TABLE IF EXISTS "employment_withdrawal";
+DROP TABLE IF EXISTS "employment_state_history";
+DROP TABLE IF EXISTS "employment";
+DROP TABLE IF EXISTS "scheduler_run";
+DROP TABLE IF EXISTS "job_position";
+DROP TABLE IF EXISTS "position_category";
+DROP TABLE IF EXISTS "event";
+DROP TABLE IF EXISTS "organization_membership";
+DROP TABLE IF EXISTS "user";
+DROP TABLE IF EXISTS "venue";
+DROP TABLE IF EXISTS "organization";
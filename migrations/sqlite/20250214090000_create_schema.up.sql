@@ -0,0 +1,228 @@
+-- The schema of the Postgres migrations as it stands after all of them. Enum types are
+-- stored as text constrained to the same values, timestamps as RFC 3339 text in UTC.
+
+CREATE TABLE IF NOT EXISTS "organization" (
+    "id" INTEGER PRIMARY KEY,
+    "name" TEXT UNIQUE NOT NULL,
+    "invite_code" TEXT UNIQUE NOT NULL DEFAULT (lower(hex(randomblob(16)))),
+    "created_at" TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now'))
+);
+
+CREATE TABLE IF NOT EXISTS "venue" (
+    "id" INTEGER PRIMARY KEY,
+    "name" TEXT NOT NULL,
+    "description" TEXT,
+    "state" TEXT NOT NULL,
+    "postal_code" TEXT NOT NULL,
+    "town" TEXT NOT NULL,
+    "street_name" TEXT NOT NULL,
+    "street_number" TEXT NOT NULL,
+    "address_url" TEXT,
+    "deleted_at" TEXT,
+    "organization_id" INTEGER NOT NULL REFERENCES "organization" ("id")
+);
+CREATE INDEX IF NOT EXISTS "venue_organization_id_idx" ON "venue" ("organization_id");
+
+CREATE TABLE IF NOT EXISTS "user" (
+    "id" INTEGER PRIMARY KEY,
+    "first_name" TEXT NOT NULL,
+    "last_name" TEXT NOT NULL,
+    "username" TEXT UNIQUE NOT NULL,
+    "gender" TEXT NOT NULL CHECK ("gender" IN ('male', 'female', 'other')),
+    "birth_date" TEXT NOT NULL,
+    "email" TEXT UNIQUE NOT NULL,
+    "phone" TEXT NOT NULL,
+    "password_hash" TEXT NOT NULL,
+    "role" TEXT NOT NULL CHECK ("role" IN ('employee', 'organizer', 'admin')),
+    "tax_rate" REAL NOT NULL,
+    "avatar_url" TEXT,
+    "deleted_at" TEXT
+);
+
+CREATE TABLE IF NOT EXISTS "organization_membership" (
+    "organization_id" INTEGER NOT NULL REFERENCES "organization" ("id") ON DELETE CASCADE,
+    "user_id" INTEGER NOT NULL REFERENCES "user" ("id") ON DELETE CASCADE,
+    "role" TEXT NOT NULL CHECK ("role" IN ('owner', 'organizer', 'employee')),
+    "created_at" TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+    PRIMARY KEY ("organization_id", "user_id")
+);
+CREATE INDEX IF NOT EXISTS "organization_membership_user_id_idx" ON "organization_membership" ("user_id");
+
+CREATE TABLE IF NOT EXISTS "event" (
+    "id" INTEGER PRIMARY KEY,
+    "name" TEXT NOT NULL,
+    "date_start" TEXT NOT NULL,
+    "date_end" TEXT NOT NULL,
+    "img_url" TEXT NOT NULL,
+    "description" TEXT,
+    "venue_id" INTEGER NOT NULL REFERENCES "venue" ("id"),
+    "owner_id" INTEGER NOT NULL REFERENCES "user" ("id") ON DELETE CASCADE,
+    "deleted_at" TEXT,
+    "cancelled_at" TEXT,
+    "cancellation_reason" TEXT,
+    "status" TEXT NOT NULL DEFAULT 'draft' CHECK ("status" IN (
+        'draft', 'published', 'registration_closed', 'in_progress', 'completed', 'archived', 'cancelled'
+    )),
+    "publish_at" TEXT,
+    "organization_id" INTEGER NOT NULL REFERENCES "organization" ("id"),
+    "withdrawal_cutoff_hours" INTEGER NOT NULL DEFAULT 48
+);
+CREATE INDEX IF NOT EXISTS "event_organization_id_idx" ON "event" ("organization_id");
+
+CREATE TABLE IF NOT EXISTS "position_category" (
+    "id" INTEGER PRIMARY KEY,
+    "name" TEXT NOT NULL,
+    "organization_id" INTEGER NOT NULL REFERENCES "organization" ("id")
+);
+CREATE INDEX IF NOT EXISTS "position_category_organization_id_idx" ON "position_category" ("organization_id");
+
+CREATE TABLE IF NOT EXISTS "job_position" (
+    "id" INTEGER PRIMARY KEY,
+    "name" TEXT NOT NULL,
+    "description" TEXT,
+    "salary" REAL NOT NULL,
+    "capacity" INTEGER NOT NULL,
+    "instructions_html" TEXT NOT NULL,
+    "is_opened_for_registration" BOOLEAN NOT NULL,
+    "currency" TEXT NOT NULL CHECK ("currency" IN ('CZK', 'EUR')),
+    "event_id" INTEGER NOT NULL REFERENCES "event" ("id") ON DELETE CASCADE,
+    "position_category_id" INTEGER NOT NULL REFERENCES "position_category" ("id") ON DELETE CASCADE,
+    "deleted_at" TEXT,
+    "registration_opens_at" TEXT,
+    "registration_closes_at" TEXT,
+    "instructions_markdown" TEXT NOT NULL DEFAULT ''
+);
+
+CREATE TABLE IF NOT EXISTS "scheduler_run" (
+    "task" TEXT PRIMARY KEY,
+    "last_run_at" TEXT NOT NULL
+);
+
+INSERT INTO "scheduler_run" ("task", "last_run_at")
+VALUES ('registration_windows', strftime('%Y-%m-%dT%H:%M:%fZ', 'now'));
+
+CREATE TABLE IF NOT EXISTS "employment" (
+    "id" INTEGER PRIMARY KEY,
+    "rating" INTEGER NOT NULL,
+    "state" TEXT NOT NULL CHECK ("state" IN (
+        'pending', 'accepted', 'rejected', 'done', 'cancelled', 'invited', 'declined', 'expired', 'withdrawn'
+    )),
+    "user_id" INTEGER NOT NULL REFERENCES "user" ("id") ON DELETE CASCADE,
+    "position_id" INTEGER NOT NULL REFERENCES "job_position" ("id") ON DELETE CASCADE,
+    "invitation_expires_at" TEXT
+);
+
+CREATE TABLE IF NOT EXISTS "employment_state_history" (
+    "id" INTEGER PRIMARY KEY,
+    "employment_id" INTEGER NOT NULL REFERENCES "employment" ("id") ON DELETE CASCADE,
+    "from_state" TEXT CHECK ("from_state" IN (
+        'pending', 'accepted', 'rejected', 'done', 'cancelled', 'invited', 'declined', 'expired', 'withdrawn'
+    )),
+    "to_state" TEXT NOT NULL CHECK ("to_state" IN (
+        'pending', 'accepted', 'rejected', 'done', 'cancelled', 'invited', 'declined', 'expired', 'withdrawn'
+    )),
+    "changed_by" INTEGER REFERENCES "user" ("id") ON DELETE SET NULL,
+    "changed_at" TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now'))
+);
+CREATE INDEX IF NOT EXISTS "employment_state_history_employment_id_idx" ON "employment_state_history" ("employment_id");
+
+CREATE TABLE IF NOT EXISTS "employment_withdrawal" (
+    "id" INTEGER PRIMARY KEY,
+    "employment_id" INTEGER NOT NULL REFERENCES "employment" ("id") ON DELETE CASCADE,
+    "user_id" INTEGER NOT NULL REFERENCES "user" ("id") ON DELETE CASCADE,
+    "reason" TEXT,
+    "is_late" BOOLEAN NOT NULL,
+    "created_at" TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now'))
+);
+CREATE INDEX IF NOT EXISTS "employment_withdrawal_user_id_idx" ON "employment_withdrawal" ("user_id");
+
+CREATE TABLE IF NOT EXISTS "worked_hours" (
+    "id" INTEGER PRIMARY KEY,
+    "date" TEXT NOT NULL,
+    "hours_worked" REAL NOT NULL,
+    "employment_id" INTEGER NOT NULL REFERENCES "employment" ("id") ON DELETE CASCADE
+);
+
+CREATE TABLE IF NOT EXISTS "event_manager_relation" (
+    "user_id" INTEGER NOT NULL REFERENCES "user" ("id") ON DELETE CASCADE,
+    "event_id" INTEGER NOT NULL REFERENCES "event" ("id") ON DELETE CASCADE,
+    "can_edit_event" BOOLEAN NOT NULL DEFAULT FALSE,
+    "can_manage_jobs" BOOLEAN NOT NULL DEFAULT FALSE,
+    "can_manage_applicants" BOOLEAN NOT NULL DEFAULT FALSE,
+    "can_approve_hours" BOOLEAN NOT NULL DEFAULT FALSE,
+    "can_view_payroll" BOOLEAN NOT NULL DEFAULT FALSE,
+    "can_manage_managers" BOOLEAN NOT NULL DEFAULT FALSE,
+    CONSTRAINT "unique_user_event" UNIQUE ("user_id", "event_id")
+);
+
+CREATE TABLE IF NOT EXISTS "audit_log" (
+    "id" INTEGER PRIMARY KEY,
+    "actor_id" INTEGER,
+    "entity" TEXT NOT NULL CHECK ("entity" IN ('event', 'employment', 'job_position', 'user', 'venue')),
+    "entity_id" INTEGER NOT NULL,
+    "event_id" INTEGER,
+    "action" TEXT NOT NULL CHECK ("action" IN ('create', 'update', 'delete', 'restore', 'purge')),
+    "before" TEXT,
+    "after" TEXT,
+    "created_at" TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now'))
+);
+CREATE INDEX IF NOT EXISTS "audit_log_entity_idx" ON "audit_log" ("entity", "entity_id");
+CREATE INDEX IF NOT EXISTS "audit_log_event_idx" ON "audit_log" ("event_id");
+
+CREATE TRIGGER IF NOT EXISTS "audit_log_no_update"
+    BEFORE UPDATE ON "audit_log"
+BEGIN
+    SELECT RAISE(ABORT, 'audit_log is append-only');
+END;
+
+CREATE TRIGGER IF NOT EXISTS "audit_log_no_delete"
+    BEFORE DELETE ON "audit_log"
+BEGIN
+    SELECT RAISE(ABORT, 'audit_log is append-only');
+END;
+
+CREATE TABLE IF NOT EXISTS "notification" (
+    "id" INTEGER PRIMARY KEY,
+    "user_id" INTEGER NOT NULL REFERENCES "user" ("id") ON DELETE CASCADE,
+    "message" TEXT NOT NULL,
+    "created_at" TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+    "read_at" TEXT
+);
+CREATE INDEX IF NOT EXISTS "notification_user_id_idx" ON "notification" ("user_id");
+
+CREATE TABLE IF NOT EXISTS "attendance_record" (
+    "id" INTEGER PRIMARY KEY,
+    "employment_id" INTEGER NOT NULL REFERENCES "employment" ("id") ON DELETE CASCADE,
+    "date" TEXT NOT NULL,
+    "outcome" TEXT NOT NULL CHECK ("outcome" IN ('attended', 'late', 'no_show', 'left_early')),
+    "note" TEXT,
+    "recorded_by" INTEGER REFERENCES "user" ("id") ON DELETE SET NULL,
+    "created_at" TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+    UNIQUE ("employment_id", "date")
+);
+CREATE INDEX IF NOT EXISTS "attendance_record_outcome_idx" ON "attendance_record" ("outcome", "date");
+
+CREATE TABLE IF NOT EXISTS "review" (
+    "id" INTEGER PRIMARY KEY,
+    "employment_id" INTEGER NOT NULL REFERENCES "employment" ("id") ON DELETE CASCADE,
+    "direction" TEXT NOT NULL CHECK ("direction" IN ('organizer_to_worker', 'worker_to_organizer')),
+    "author_id" INTEGER REFERENCES "user" ("id") ON DELETE SET NULL,
+    -- The reviewed worker, or the owner of the event for reviews written by workers.
+    "subject_id" INTEGER NOT NULL REFERENCES "user" ("id") ON DELETE CASCADE,
+    "event_id" INTEGER NOT NULL REFERENCES "event" ("id") ON DELETE CASCADE,
+    "overall" INTEGER NOT NULL CHECK ("overall" BETWEEN 1 AND 5),
+    "punctuality" INTEGER CHECK ("punctuality" BETWEEN 1 AND 5),
+    "skill" INTEGER CHECK ("skill" BETWEEN 1 AND 5),
+    "organization" INTEGER CHECK ("organization" BETWEEN 1 AND 5),
+    "communication" INTEGER CHECK ("communication" BETWEEN 1 AND 5),
+    "comment" TEXT NOT NULL DEFAULT '',
+    "flagged_at" TEXT,
+    "flagged_by" INTEGER REFERENCES "user" ("id") ON DELETE SET NULL,
+    "flag_reason" TEXT,
+    "is_hidden" BOOLEAN NOT NULL DEFAULT FALSE,
+    "created_at" TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+    UNIQUE ("employment_id", "direction")
+);
+CREATE INDEX IF NOT EXISTS "review_subject_id_idx" ON "review" ("subject_id", "direction");
+CREATE INDEX IF NOT EXISTS "review_event_id_idx" ON "review" ("event_id");
+CREATE INDEX IF NOT EXISTS "review_flagged_at_idx" ON "review" ("flagged_at") WHERE "flagged_at" IS NOT NULL;
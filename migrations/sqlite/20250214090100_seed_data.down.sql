@@ -0,0 +1,11 @@
+DELETE FROM "employment_state_history";
+DELETE FROM "event_manager_relation";
+DELETE FROM "worked_hours";
+DELETE FROM "employment";
+DELETE FROM "job_position";
+DELETE FROM "position_category";
+DELETE FROM "event";
+DELETE FROM "organization_membership";
+DELETE FROM "user";
+DELETE FROM "venue";
+DELETE FROM "organization" WHERE "name" = 'GigLog';
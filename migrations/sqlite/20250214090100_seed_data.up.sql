@@ -0,0 +1,250 @@
+-- The sample data of the Postgres seed migrations, all of it belonging to a single agency.
+
+INSERT INTO "organization" ("id", "name") VALUES
+    (1, 'GigLog');
+
+INSERT INTO "venue" ("id", "name", "description", "state", "postal_code", "town", "street_name", "street_number", "address_url", "organization_id") VALUES
+    (1, 'Amfiteátr Jihlava', 'V malebném údolí řeky Jihlavy v těsném sousedství ze zologickou zahradou přímo v centru Jihlavy se nachází areál Amfiteátru a parku Malý Heulos, který je místem odpočinku, relaxace a zábavy pro celou rodinu. Areál je ideální pro pořádání venkovních kulturních a společenských akcí. Zázemí pod plátnem umožňuje pořádat celoročně klubovou a schůzovní činnost. Součástí areálu je moderní dětské hřiště se spoustou atrakcí.', 'Česká republika', '586 01', 'Jihlava', 'Březinovy sady', '4733/16', 'https://www.google.com/maps/place/AMFITEÁTR+JIHLAVA/@49.3988825,15.5931122,19.25z/data=!4m10!1m2!2m1!1samfiteátr+jihlava+akce!3m6!1s0x470d1a6e88c50739:0x59ee7e0ca9cb878a!8m2!3d49.3990002!4d15.593529!15sChdhbWZpdGXDoXRyIGppaGxhdmEgYWtjZVoZIhdhbWZpdGXDoXRyIGppaGxhdmEgYWtjZZIBDGFtcGhpdGhlYXRyZZoBI0NoWkRTVWhOTUc5blMwVkpRMEZuU1VSd01reElOMWRCRUFF4AEA-gEECAAQPA!16s%2Fg%2F11b7hgqkb0?hl=cs&entry=ttu&g_ep=EgoyMDI1MDEyNi4wIKXMDSoASAFQAw%3D%3D', 1),
+    (2, 'Plzeň Plaza Amfiteátr', 'mfiteátr Plzeň Plaza je venkovní multifunkční prostor v Plzni, který se nachází v blízkosti nákupního centra Plaza. Tento amfiteátr slouží jako místo pro pořádání různorodých kulturních a společenských akcí, jako jsou koncerty, divadelní představení, letní kina, festivaly nebo firemní akce.', 'Česká republika', '301 00', 'Plzeň', 'Radčická', '3', 'https://www.google.com/maps/place/Plaza+Amfiteátr/@49.7488385,13.3670824,17z/data=!3m1!5s0x470af1f9e2850607:0x7f98e917a94e0ab7!4m14!1m7!3m6!1s0x470af1f9e47d6eed:0x1a93525b7f58b08e!2sPlzeň+Plaza!8m2!3d49.7488385!4d13.3696573!16s%2Fm%2F03d89dr!3m5!1s0x470af1f746f22d07:0xdbf3e306be208620!8m2!3d49.7499495!4d13.3703466!16s%2Fg%2F12hl3b2td?hl=cs&entry=ttu&g_ep=EgoyMDI1MDEyNi4wIKXMDSoASAFQAw%3D%3D', 1),
+    (3, 'Brněnské výstaviště', 'Brněnské výstaviště je rozsáhlý areál v Brně, známý pořádáním mezinárodních veletrhů, výstav a kulturních akcí. Bylo otevřeno v roce 1928 a zahrnuje unikátní funkcionalistickou architekturu. Nabízí moderní pavilony, velkorysé výstavní plochy a výbornou dostupnost, čímž patří k nejvýznamnějším výstavním centrům v Evropě.', 'Česká republika', '603 00', 'Brno', 'Výstaviště', '405/1', 'https://www.google.com/maps/place/Výstaviště,+603+00+Brno-střed/@49.1886703,16.584325,17z/data=!3m1!4b1!4m6!3m5!1s0x471295b4d44c1501:0xeadd6aac61d91d1f!8m2!3d49.1886703!4d16.5868999!16s%2Fg%2F1tdmz77h?hl=cs&entry=ttu&g_ep=EgoyMDI1MDEyNi4wIKXMDSoASAFQAw%3D%3D', 1),
+    (4, 'Letiště Hradec Králové', 'Letiště Hradec Králové je známé jako dějiště významných kulturních a společenských akcí. Pravidelně hostí festival Rock for People, letecké dny a další velké venkovní akce. Díky svým rozlehlým plochám a dobré dostupnosti je ideálním místem pro pořádání hudebních a společenských událostí.', 'Česká republika', '503 41', 'Hradec Králové', 'Letiště', '38', 'https://www.google.com/maps/place/Letiště+Hradec+Králové/@50.2285066,15.8229514,13.58z/data=!4m6!3m5!1s0x470c2aa5be91d3b1:0xd3d7d261a8ea1625!8m2!3d50.253194!4d15.8448225!16s%2Fm%2F04fngz_?hl=cs&entry=ttu&g_ep=EgoyMDI1MDEyNi4wIKXMDSoASAFQAw%3D%3D', 1),
+    (5, 'O2 arena', 'O₂ arena je víceúčelová hala, která stojí v Libni v Praze 9, těsně u hranice Vysočan. Jejím majitelem a provozovatelem je akciová společnost Bestsport. Od svého vzniku do 29. února 2008 nesla název Sazka arena. Byla otevřena při příležitosti mistrovství světa v ledním hokeji 2004.', 'Česká republika', '190 00', 'Praha', 'Českomoravská', '2345/17a', 'https://www.google.com/maps/place/O2+arena/@50.104819,14.4910523,17z/data=!3m1!4b1!4m6!3m5!1s0x470b934b352296e9:0x2f97352726696cf2!8m2!3d50.104819!4d14.4936272!16zL20vMDYzdzF6?hl=cs&entry=ttu&g_ep=EgoyMDI1MDEyNi4wIKXMDSoASAFQAw%3D%3D', 1),
+    (6, 'Pivovar Samson', 'Pivovar Samson v Českých Budějovicích je nejen místem s bohatou pivovarnickou tradicí, ale také dějištěm různých kulturních a společenských akcí. Díky svému historickému areálu nabízí unikátní atmosféru pro koncerty, festivaly a další události, které přitahují místní i návštěvníky z okolí.', 'Česká republika', '370 07', 'České Budějovice', 'Lidická', '458/51', 'https://www.google.com/maps/place/Pivovar+Samson/@48.9623201,14.474351,16.92z/data=!4m10!1m2!2m1!1spivovar+samson+české+budějovice!3m6!1s0x47734fc1c29e7f29:0xc8ccc6156505ab67!8m2!3d48.9639894!4d14.4746796!15sCiJwaXZvdmFyIHNhbXNvbiDEjWVza8OpIGJ1ZMSbam92aWNlWiQiInBpdm92YXIgc2Ftc29uIMSNZXNrw6kgYnVkxJtqb3ZpY2WSAQdicmV3ZXJ5mgEjQ2haRFNVaE5NRzluUzBWSlEwRm5TVU5xZFdGRFMyWlJFQUXgAQD6AQQIXhAm!16s%2Fg%2F11g65bp2p2?hl=cs&entry=ttu&g_ep=EgoyMDI1MDEyNi4wIKXMDSoASAFQAw%3D%3D', 1),
+    (7, 'OSTRAVAR ARÉNA', 'Ostravar Aréna je multifunkční hala v Ostravě, na katastru místní části Zábřeh v městském obvodu Ostrava-Jih. Dokončena byla v roce 1986. Od roku 2004 nesla název ČEZ Aréna, pak se dočasně jmenovala Ostrava Aréna.', 'Česká republika', '700 30', 'Ostrava', 'Ruská', '3077/135', 'https://www.google.com/maps/place/OSTRAVAR+ARÉNA/@49.8049479,18.2458598,17z/data=!3m1!4b1!4m6!3m5!1s0x4713e498fa3aac25:0x45af281e4e461453!8m2!3d49.8049479!4d18.2484347!16zL20vMDc0Zm5r?hl=cs&entry=ttu&g_ep=EgoyMDI1MDEyNi4wIKXMDSoASAFQAw%3D%3D', 1),
+    (8, 'Drnovice u Vyškova - kulturní dům', 'Slavnostní otevření KD (tehdy Osvětového domu) se konalo 8.dubna 1951 provedením Smetanovy opery Prodaná nevěsta. Od otevření až do současnosti se na jevišti tohoto kulturního stánku vystřídala celá řada známých i méně známých umělců. Za všechny jmenujme např. hudební skupiny Olympic, Arakain, Kabát, Citron, Team, nebo sólisté Karel Gott, Jiří Korn, Jarmila Šuláková, Václav Neckář a spousta dalších.', 'Česká republika', '683 04', 'Drnovice', 'Drnovice', '713', 'https://www.google.com/maps/place/Kulturní+dům,+Drnovice/@49.2762417,16.9511065,16.5z/data=!4m6!3m5!1s0x4712f00dc79b0d5b:0x172faeb844c93fd1!8m2!3d49.2763588!4d16.9524474!16s%2Fg%2F11b7dv6ctc?hl=cs&entry=ttu&g_ep=EgoyMDI1MDEyNi4wIKXMDSoASAFQAw%3D%3D', 1),
+    (9, 'Areál u Koupaliště Cihelna', 'Areál u Koupaliště Cihelna v Pardubicích je oblíbeným místem pro pořádání kulturních, sportovních a společenských akcí. Díky svému umístění u koupaliště nabízí příjemné prostředí pro koncerty, festivaly a další venkovní události. Prostor je snadno dostupný a vhodný pro rodiny i větší skupiny návštěvníků.', 'Česká republika', '530 09', 'Pardubice', 'Cihelna', '12', 'https://www.google.com/maps/place/Koupaliště+Cihelna+Pardubice/@50.0467483,15.7766079,17z/data=!4m6!3m5!1s0x470dcce6191567ad:0x483182f4bee3a1ee!8m2!3d50.0480011!4d15.7786471!16s%2Fg%2F12qgtv2tg?hl=cs&entry=ttu&g_ep=EgoyMDI1MDEyNi4wIKXMDSoASAFQAw%3D%3D', 1),
+    (10, 'Fabrika Svitavy', 'Fabrika Svitavy je moderní multifunkční kulturní centrum, které slouží jako místo pro koncerty, divadelní představení, výstavy, přednášky a společenské akce. Nachází se v zrekonstruované budově bývalé textilní továrny, což jí dodává unikátní industriální atmosféru. Díky své variabilitě a kvalitnímu zázemí je oblíbeným místem pro široké spektrum událostí.', 'Česká republika', '683 04', 'Svitavy', 'Wolkerova alej', '92/18', 'https://www.google.com/maps/place/FABRIKA+SVITAVY/@49.7560056,16.4697885,17z/data=!3m1!4b1!4m6!3m5!1s0x470d8bd31235d373:0x70009221619f4200!8m2!3d49.7560056!4d16.4723634!16s%2Fg%2F11fy4pl_ms?hl=cs&entry=ttu&g_ep=EgoyMDI1MDEyNi4wIKXMDSoASAFQAw%3D%3D', 1);
+
+INSERT INTO "user" ("id", "first_name", "last_name", "username", "gender", "birth_date", "email", "phone", "password_hash", "role", "tax_rate", "avatar_url") VALUES
+    (1, 'Dexter', 'Morgan', 'admin', 'male', '2010-01-16', 'demorgan@rediff.com', '391998864', '$argon2d$v=19$m=12,t=3,p=1$bHk2dTlzN3JjZDAwMDAwMA$rxf8jHFU0HO6+EA5F3qGYQ', 'admin', 0.15, 'https://homework2storage.blob.core.windows.net/blob/public/pictures/user_avatar_img/user_00016.jpg'),
+    (2, 'Josef', 'Buba', 'pepe232', 'male', '2001-04-11', 'joko@nba.com', '715170373', '$argon2d$v=19$m=12,t=3,p=1$bHk2dTlzN3JjZDAwMDAwMA$rxf8jHFU0HO6+EA5F3qGYQ', 'admin', 0.15, 'https://homework2storage.blob.core.windows.net/blob/public/pictures/user_avatar_img/user_00017.jpg'),
+    (3, 'Radek', 'Srejch', 'brember', 'male', '2000-05-12', 'brember@mail.com', '221260507', '$argon2d$v=19$m=12,t=3,p=1$bHk2dTlzN3JjZDAwMDAwMA$rxf8jHFU0HO6+EA5F3qGYQ', 'admin', 0.15, 'https://homework2storage.blob.core.windows.net/blob/public/pictures/user_avatar_img/user_00018.jpg'),
+    (4, 'Lukáš', 'Nadvojský', 'lasicak', 'male', '2001-07-31', 'lasicak@mail.com', '628466345', '$argon2d$v=19$m=12,t=3,p=1$bHk2dTlzN3JjZDAwMDAwMA$rxf8jHFU0HO6+EA5F3qGYQ', 'admin', 0.15, 'https://homework2storage.blob.core.windows.net/blob/public/pictures/user_avatar_img/user_00019.jpg'),
+    (5, 'Michal', 'Uherácký', 'fousek', 'male', '2003-02-25', 'michuh@mail.com', '830294413', '$argon2d$v=19$m=12,t=3,p=1$bHk2dTlzN3JjZDAwMDAwMA$rxf8jHFU0HO6+EA5F3qGYQ', 'admin', 0.15, 'https://homework2storage.blob.core.windows.net/blob/public/pictures/user_avatar_img/user_00020.jpg'),
+    (6, 'Ema', 'Nováková', 'ema14', 'female', '2004-03-14', 'ema.novakova@mail.com', '702843215', '$argon2d$v=19$m=12,t=3,p=1$bHk2dTlzN3JjZDAwMDAwMA$rxf8jHFU0HO6+EA5F3qGYQ', 'organizer', 0.15, 'https://homework2storage.blob.core.windows.net/blob/public/pictures/user_avatar_img/user_00001.jpg'),
+    (7, 'Tomáš', 'Král', 'tommy98', 'male', '1998-08-21', 'tomas.kral@gmail.com', '721456789', '$argon2d$v=19$m=12,t=3,p=1$bHk2dTlzN3JjZDAwMDAwMA$rxf8jHFU0HO6+EA5F3qGYQ', 'organizer', 0.15, 'https://homework2storage.blob.core.windows.net/blob/public/pictures/user_avatar_img/user_00021.jpg'),
+    (8, 'Karolína', 'Horská', 'karol5', 'female', '1995-12-05', 'karolina.horska@mail.cz', '606987654', '$argon2d$v=19$m=12,t=3,p=1$bHk2dTlzN3JjZDAwMDAwMA$rxf8jHFU0HO6+EA5F3qGYQ', 'organizer', 0.15, 'https://homework2storage.blob.core.windows.net/blob/public/pictures/user_avatar_img/user_00002.jpg'),
+    (9, 'Adam', 'Růžička', 'adamrz', 'male', '2002-06-18', 'adam.ruzicka@seznam.cz', '774123456', '$argon2d$v=19$m=12,t=3,p=1$bHk2dTlzN3JjZDAwMDAwMA$rxf8jHFU0HO6+EA5F3qGYQ', 'organizer', 0.15, 'https://homework2storage.blob.core.windows.net/blob/public/pictures/user_avatar_img/user_00022.jpg'),
+    (10, 'Barbora', 'Černá', 'bara02', 'female', '2000-11-02', 'bara.cerna@centrum.cz', '739654987', '$argon2d$v=19$m=12,t=3,p=1$bHk2dTlzN3JjZDAwMDAwMA$rxf8jHFU0HO6+EA5F3qGYQ', 'organizer', 0.15, 'https://homework2storage.blob.core.windows.net/blob/public/pictures/user_avatar_img/user_00003.jpg'),
+    (11, 'Filip', 'Dvořák', 'fildvor', 'other', '1999-09-09', 'filip.dvorak@mail.com', '608345672', '$argon2d$v=19$m=12,t=3,p=1$bHk2dTlzN3JjZDAwMDAwMA$rxf8jHFU0HO6+EA5F3qGYQ', 'organizer', 0.15, 'https://homework2storage.blob.core.windows.net/blob/public/pictures/user_avatar_img/user_00023.jpg'),
+    (12, 'Petra', 'Malá', 'petulka', 'female', '2001-02-17', 'petra.mala@mail.com', '776234567', '$argon2d$v=19$m=12,t=3,p=1$bHk2dTlzN3JjZDAwMDAwMA$rxf8jHFU0HO6+EA5F3qGYQ', 'organizer', 0.15, 'https://homework2storage.blob.core.windows.net/blob/public/pictures/user_avatar_img/user_00004.jpg'),
+    (13, 'Martin', 'Horák', 'martinh', 'other', '1997-07-12', 'martin.horak@gmail.com', '604987123', '$argon2d$v=19$m=12,t=3,p=1$bHk2dTlzN3JjZDAwMDAwMA$rxf8jHFU0HO6+EA5F3qGYQ', 'organizer', 0.15, 'https://homework2storage.blob.core.windows.net/blob/public/pictures/user_avatar_img/user_00024.jpg'),
+    (14, 'Veronika', 'Svobodová', 'vero96', 'female', '1996-10-30', 'vero.svobodova@seznam.cz', '720456123', '$argon2d$v=19$m=12,t=3,p=1$bHk2dTlzN3JjZDAwMDAwMA$rxf8jHFU0HO6+EA5F3qGYQ', 'organizer', 0.15, 'https://homework2storage.blob.core.windows.net/blob/public/pictures/user_avatar_img/user_00005.jpg'),
+    (15, 'Daniel', 'Novotný', 'danek94', 'male', '1994-05-25', 'daniel.novotny@mail.com', '777123098', '$argon2d$v=19$m=12,t=3,p=1$bHk2dTlzN3JjZDAwMDAwMA$rxf8jHFU0HO6+EA5F3qGYQ', 'organizer', 0.15, 'https://homework2storage.blob.core.windows.net/blob/public/pictures/user_avatar_img/user_00025.jpg'),
+    (16, 'Andrea', 'Poláková', 'andyp', 'other', '2000-03-07', 'andrea.polakova@mail.cz', '728456908', '$argon2d$v=19$m=12,t=3,p=1$bHk2dTlzN3JjZDAwMDAwMA$rxf8jHFU0HO6+EA5F3qGYQ', 'employee', 0.15, 'https://homework2storage.blob.core.windows.net/blob/public/pictures/user_avatar_img/user_00026.jpg'),
+    (17, 'Lukáš', 'Veselý', 'luki95', 'male', '1995-04-19', 'lukas.vesely@mail.com', '731567890', '$argon2d$v=19$m=12,t=3,p=1$bHk2dTlzN3JjZDAwMDAwMA$rxf8jHFU0HO6+EA5F3qGYQ', 'employee', 0.15, 'https://homework2storage.blob.core.windows.net/blob/public/pictures/user_avatar_img/user_00027.jpg'),
+    (18, 'Kateřina', 'Benešová', 'katka11', 'female', '1998-01-11', 'katerina.benesova@mail.com', '722345678', '$argon2d$v=19$m=12,t=3,p=1$bHk2dTlzN3JjZDAwMDAwMA$rxf8jHFU0HO6+EA5F3qGYQ', 'employee', 0.15, 'https://homework2storage.blob.core.windows.net/blob/public/pictures/user_avatar_img/user_00006.jpg'),
+    (19, 'Pavel', 'Mach', 'pmach', 'male', '2003-08-29', 'pavel.mach@gmail.com', '702567432', '$argon2d$v=19$m=12,t=3,p=1$bHk2dTlzN3JjZDAwMDAwMA$rxf8jHFU0HO6+EA5F3qGYQ', 'employee', 0.15, 'https://homework2storage.blob.core.windows.net/blob/public/pictures/user_avatar_img/user_00028.jpg'),
+    (20, 'Lucie', 'Pokorná', 'lucie22', 'female', '1999-12-22', 'lucie.pokorna@seznam.cz', '733987654', '$argon2d$v=19$m=12,t=3,p=1$bHk2dTlzN3JjZDAwMDAwMA$rxf8jHFU0HO6+EA5F3qGYQ', 'employee', 0.15, 'https://homework2storage.blob.core.windows.net/blob/public/pictures/user_avatar_img/user_00007.jpg'),
+    (21, 'Ondřej', 'Zeman', 'ondraz', 'male', '1997-06-06', 'ondrej.zeman@mail.cz', '608213456', '$argon2d$v=19$m=12,t=3,p=1$bHk2dTlzN3JjZDAwMDAwMA$rxf8jHFU0HO6+EA5F3qGYQ', 'employee', 0.15, 'https://homework2storage.blob.core.windows.net/blob/public/pictures/user_avatar_img/user_00029.jpg'),
+    (22, 'Hana', 'Urbanová', 'hanicka', 'female', '2002-07-01', 'hana.urbanova@mail.com', '774321567', '$argon2d$v=19$m=12,t=3,p=1$bHk2dTlzN3JjZDAwMDAwMA$rxf8jHFU0HO6+EA5F3qGYQ', 'employee', 0.15, 'https://homework2storage.blob.core.windows.net/blob/public/pictures/user_avatar_img/user_00008.jpg'),
+    (23, 'Jakub', 'Kolář', 'jakub23', 'male', '1999-03-23', 'jakub.kolar@gmail.com', '732987543', '$argon2d$v=19$m=12,t=3,p=1$bHk2dTlzN3JjZDAwMDAwMA$rxf8jHFU0HO6+EA5F3qGYQ', 'employee', 0.15, 'https://homework2storage.blob.core.windows.net/blob/public/pictures/user_avatar_img/user_00030.jpg'),
+    (24, 'Ivana', 'Šimková', 'ivana01', 'female', '2001-11-14', 'ivana.simkova@mail.cz', '776654321', '$argon2d$v=19$m=12,t=3,p=1$bHk2dTlzN3JjZDAwMDAwMA$rxf8jHFU0HO6+EA5F3qGYQ', 'employee', 0.15, 'https://homework2storage.blob.core.windows.net/blob/public/pictures/user_avatar_img/user_00009.jpg'),
+    (25, 'Marek', 'Holý', 'marekh', 'other', '1998-09-27', 'marek.holy@mail.com', '606789123', '$argon2d$v=19$m=12,t=3,p=1$bHk2dTlzN3JjZDAwMDAwMA$rxf8jHFU0HO6+EA5F3qGYQ', 'employee', 0.15, 'https://homework2storage.blob.core.windows.net/blob/public/pictures/user_avatar_img/user_00031.jpg'),
+    (26, 'Tereza', 'Kučerová', 'terka09', 'female', '2000-01-09', 'tereza.kucerova@mail.com', '723456789', '$argon2d$v=19$m=12,t=3,p=1$bHk2dTlzN3JjZDAwMDAwMA$rxf8jHFU0HO6+EA5F3qGYQ', 'employee', 0.15, 'https://homework2storage.blob.core.windows.net/blob/public/pictures/user_avatar_img/user_00010.jpg'),
+    (27, 'David', 'Kříž', 'david97', 'male', '1997-05-04', 'david.kriz@gmail.com', '701987654', '$argon2d$v=19$m=12,t=3,p=1$bHk2dTlzN3JjZDAwMDAwMA$rxf8jHFU0HO6+EA5F3qGYQ', 'employee', 0.15, 'https://homework2storage.blob.core.windows.net/blob/public/pictures/user_avatar_img/user_00032.jpg'),
+    (28, 'Aneta', 'Vlčková', 'anetka15', 'female', '2002-12-15', 'aneta.vlckova@mail.com', '775432198', '$argon2d$v=19$m=12,t=3,p=1$bHk2dTlzN3JjZDAwMDAwMA$rxf8jHFU0HO6+EA5F3qGYQ', 'employee', 0.15, 'https://homework2storage.blob.core.windows.net/blob/public/pictures/user_avatar_img/user_00011.jpg'),
+    (29, 'Jan', 'Procházka', 'jproch', 'male', '1995-10-07', 'jan.prochazka@seznam.cz', '739876543', '$argon2d$v=19$m=12,t=3,p=1$bHk2dTlzN3JjZDAwMDAwMA$rxf8jHFU0HO6+EA5F3qGYQ', 'employee', 0.15, 'https://homework2storage.blob.core.windows.net/blob/public/pictures/user_avatar_img/user_00033.jpg'),
+    (30, 'Nikola', 'Šťastná', 'nikca03', 'female', '2003-02-03', 'nikola.stastna@mail.com', '702345671', '$argon2d$v=19$m=12,t=3,p=1$bHk2dTlzN3JjZDAwMDAwMA$rxf8jHFU0HO6+EA5F3qGYQ', 'employee', 0.15, 'https://homework2storage.blob.core.windows.net/blob/public/pictures/user_avatar_img/user_00012.jpg'),
+    (31, 'Aleš', 'Bláha', 'ales98', 'male', '1998-08-19', 'ales.blaha@mail.cz', '731654908', '$argon2d$v=19$m=12,t=3,p=1$bHk2dTlzN3JjZDAwMDAwMA$rxf8jHFU0HO6+EA5F3qGYQ', 'employee', 0.15, 'https://homework2storage.blob.core.windows.net/blob/public/pictures/user_avatar_img/user_00034.jpg'),
+    (32, 'Simona', 'Havlová', 'simca01', 'other', '2001-11-25', 'simona.havlova@mail.com', '777908765', '$argon2d$v=19$m=12,t=3,p=1$bHk2dTlzN3JjZDAwMDAwMA$rxf8jHFU0HO6+EA5F3qGYQ', 'employee', 0.15, 'https://homework2storage.blob.core.windows.net/blob/public/pictures/user_avatar_img/user_00034.jpg'),
+    (33, 'Michaela', 'Sedláčková', 'misa30', 'female', '1999-09-30', 'michaela.sedlackova@mail.cz', '720789654', '$argon2d$v=19$m=12,t=3,p=1$bHk2dTlzN3JjZDAwMDAwMA$rxf8jHFU0HO6+EA5F3qGYQ', 'employee', 0.15, 'https://homework2storage.blob.core.windows.net/blob/public/pictures/user_avatar_img/user_00013.jpg'),
+    (34, 'Jiří', 'Šimek', 'jirka18', 'male', '2000-03-18', 'jiri.simek@gmail.com', '609123987', '$argon2d$v=19$m=12,t=3,p=1$bHk2dTlzN3JjZDAwMDAwMA$rxf8jHFU0HO6+EA5F3qGYQ', 'employee', 0.15, 'https://homework2storage.blob.core.windows.net/blob/public/pictures/user_avatar_img/user_00036.jpg'),
+    (35, 'Eliška', 'Vítková', 'eliska08', 'female', '2002-07-08', 'eliska.vitkova@mail.com', '776432198', '$argon2d$v=19$m=12,t=3,p=1$bHk2dTlzN3JjZDAwMDAwMA$rxf8jHFU0HO6+EA5F3qGYQ', 'employee', 0.15, 'https://homework2storage.blob.core.windows.net/blob/public/pictures/user_avatar_img/user_00014.jpg'),
+    (36, 'Patrik', 'Holub', 'patrik94', 'male', '1994-06-10', 'patrik.holub@mail.com', '704567321', '$argon2d$v=19$m=12,t=3,p=1$bHk2dTlzN3JjZDAwMDAwMA$rxf8jHFU0HO6+EA5F3qGYQ', 'employee', 0.15, 'https://homework2storage.blob.core.windows.net/blob/public/pictures/user_avatar_img/user_00037.jpg'),
+    (37, 'Klára', 'Soukupová', 'klaras', 'other', '1996-04-14', 'klara.soukupova@mail.cz', '731987654', '$argon2d$v=19$m=12,t=3,p=1$bHk2dTlzN3JjZDAwMDAwMA$rxf8jHFU0HO6+EA5F3qGYQ', 'employee', 0.15, 'https://homework2storage.blob.core.windows.net/blob/public/pictures/user_avatar_img/user_00038.jpg'),
+    (38, 'Matěj', 'Němec', 'matej01', 'male', '2001-12-01', 'matej.nemec@seznam.cz', '602345987', '$argon2d$v=19$m=12,t=3,p=1$bHk2dTlzN3JjZDAwMDAwMA$rxf8jHFU0HO6+EA5F3qGYQ', 'employee', 0.15, 'https://homework2storage.blob.core.windows.net/blob/public/pictures/user_avatar_img/user_00039.jpg'),
+    (39, 'Barbora', 'Kovářová', 'bkovar', 'female', '1997-11-20', 'barbora.kovarova@mail.com', '730567894', '$argon2d$v=19$m=12,t=3,p=1$bHk2dTlzN3JjZDAwMDAwMA$rxf8jHFU0HO6+EA5F3qGYQ', 'employee', 0.15, 'https://homework2storage.blob.core.windows.net/blob/public/pictures/user_avatar_img/user_00015.jpg'),
+    (40, 'Tomáš', 'Němeček', 'tomas98', 'other', '1998-01-05', 'tomas.nemecek@mail.cz', '707234567', '$argon2d$v=19$m=12,t=3,p=1$bHk2dTlzN3JjZDAwMDAwMA$rxf8jHFU0HO6+EA5F3qGYQ', 'employee', 0.15, 'https://homework2storage.blob.core.windows.net/blob/public/pictures/user_avatar_img/user_00040.jpg');
+
+INSERT INTO "organization_membership" ("organization_id", "user_id", "role") VALUES
+    (1, 1, 'owner'),
+    (1, 2, 'owner'),
+    (1, 3, 'owner'),
+    (1, 4, 'owner'),
+    (1, 5, 'owner'),
+    (1, 6, 'organizer'),
+    (1, 7, 'organizer'),
+    (1, 8, 'organizer'),
+    (1, 9, 'organizer'),
+    (1, 10, 'organizer'),
+    (1, 11, 'organizer'),
+    (1, 12, 'organizer'),
+    (1, 13, 'organizer'),
+    (1, 14, 'organizer'),
+    (1, 15, 'organizer'),
+    (1, 16, 'employee'),
+    (1, 17, 'employee'),
+    (1, 18, 'employee'),
+    (1, 19, 'employee'),
+    (1, 20, 'employee'),
+    (1, 21, 'employee'),
+    (1, 22, 'employee'),
+    (1, 23, 'employee'),
+    (1, 24, 'employee'),
+    (1, 25, 'employee'),
+    (1, 26, 'employee'),
+    (1, 27, 'employee'),
+    (1, 28, 'employee'),
+    (1, 29, 'employee'),
+    (1, 30, 'employee'),
+    (1, 31, 'employee'),
+    (1, 32, 'employee'),
+    (1, 33, 'employee'),
+    (1, 34, 'employee'),
+    (1, 35, 'employee'),
+    (1, 36, 'employee'),
+    (1, 37, 'employee'),
+    (1, 38, 'employee'),
+    (1, 39, 'employee'),
+    (1, 40, 'employee');
+
+INSERT INTO "event" ("id", "name", "date_start", "date_end", "img_url", "description", "venue_id", "owner_id", "cancellation_reason", "status", "organization_id", "withdrawal_cutoff_hours") VALUES
+    (1, 'MAGMAFEST 2024', '2024-08-09', '2024-08-10', 'https://homework2storage.blob.core.windows.net/blob/public/pictures/event_img/event_00001.jpg', 'Magmafest je již kultovní rockový festival za účasti těch nejpopulárnějších českých a slovenských rockových kapel u nás. V roce 2024 proběhne již dvanáctý ročník festivalu MAGMAFEST, který se bude konat v pátek 9. 8. a sobotu 10. 8. 2024 v Jihlavě v mystickém areálu letního kina (ul. Na stoupách).', 1, 6, NULL, 'completed', 1, 48),
+    (2, 'MAGMAFEST 2025', '2025-08-15', '2025-08-15', 'https://homework2storage.blob.core.windows.net/blob/public/pictures/event_img/event_00002.jpg', 'Magmafest je již kultovní rockový festival za účasti špičkových českých a slovenských rockových kapel u nás. V roce 2025 proběhne již třináctý ročník festivalu Magmafest, který se bude konat v pátek 15. 8. a sobotu 16. 8. 2025 v Jihlavě v letním kině.', 1, 6, NULL, 'completed', 1, 48),
+    (3, 'Festival HIMLHERGOTFEST', '2025-09-06', '2025-09-06', 'https://homework2storage.blob.core.windows.net/blob/public/pictures/event_img/event_00003.png', 'Echtšlágrgruppe TRAUTENBERK slibuje playlist plný osvědčených pecek i nových songů, koncert plný nasazení a potu. Aničku svůdnější víc, než kdy jindy, Zemského radu přísnějšího, než kdy jindy a zbytek kapely hlasitější, než kdy jindy! V Čechách stále stoupající hvězda a festivalová stálice nenechá tvoji taneční kyčel v klidu a vykouzlí Ti dlouhý úsměv na tváři. Tak doraž na koncert!', 2, 7, NULL, 'completed', 1, 48),
+    (4, 'Festival ROCK IN PLZEŇ', '2025-08-15', '2025-08-15', 'https://homework2storage.blob.core.windows.net/blob/public/pictures/event_img/event_00004.png', 'Magmafest je již kultovní rockový festival za účasti špičkových českých a slovenských rockových kapel u nás. V roce 2025 proběhne již třináctý ročník festivalu Magmafest, který se bude konat v pátek 15. 8. a sobotu 16. 8. 2025 v Jihlavě v letním kině.', 2, 7, NULL, 'completed', 1, 48),
+    (5, 'Animefest', '2025-05-23', '2025-05-25', 'https://homework2storage.blob.core.windows.net/blob/public/pictures/event_img/event_00005.png', 'Animefest je největší festival tradiční i populární japonské kultury v České republice již od roku 2004. Další ročník se koná od 23. do 25. května 2025 na brněnském výstavišti. Naši návštěvníci mají široký okruh zájmů, od anime a mangy, komiksů, filmů a televizních seriálů, po hry a cosplay.', 3, 8, NULL, 'completed', 1, 48),
+    (6, 'Rock for People 2025', '2025-06-11', '2025-06-14', 'https://homework2storage.blob.core.windows.net/blob/public/pictures/event_img/event_00006.jpg', 'Již 30. ročník festivalu Rock for People proběhne 11. do 15. června 2025 v hradeckém Parku 360. Na čtyřdenní termín festivalu od 11. do 14. června 2025 naváže speciální přidaný Narozeninový den 15. 6. v hlavní roli s legendárními Guns N’ Roses a dalšími kapelami.', 4, 8, NULL, 'completed', 1, 48),
+    (7, 'LYNYRD SKYNYRD 2025 Tour', '2025-07-07', '2025-07-07', 'https://homework2storage.blob.core.windows.net/blob/public/pictures/event_img/event_00007.jpg', 'Legendární americká kapela Lynyrd Skynyrd poprvé vystoupí v Praze! Autoři ikonického hitu Sweet Home Alabama se představí 7. července 2025 v O2 areně. Koncert je součástí turné, které oslavuje neuvěřitelných 50 let jejich hudební kariéry.', 5, 9, NULL, 'completed', 1, 48),
+    (8, 'Rock in 2025', '2025-05-25', '2025-05-25', 'https://homework2storage.blob.core.windows.net/blob/public/pictures/event_img/event_00008.avif', 'Zažijte to nejlepší z domácí rockové scény v jeden den na jednom pódiu přímo u vás! Ve vašem městě, ve vašem amfiteátru se vystřídají zvučná jména, s důrazem na profesionální zázemí, špičkovou techniku a maximální komfort pro návštěvníky.', 6, 9, NULL, 'completed', 1, 48),
+    (9, 'Beats for love', '2025-07-02', '2025-07-05', 'https://homework2storage.blob.core.windows.net/blob/public/pictures/event_img/event_00009.jpg', 'Beats for Love je festival elektronické taneční hudby pořádaný v jádru industriální národní kulturní památky. Prostředí Dolních Vítkovic plné železných kulis se na čtyři dny zaplní spoustou vynikající hudby a bohatého doprovodného programu. Vzniká tak jedinečná atmosféra plná zábavy a zážitků.', 7, 9, NULL, 'completed', 1, 48),
+    (10, 'Harley', '2025-03-22', '2025-03-22', 'https://homework2storage.blob.core.windows.net/blob/public/pictures/event_img/event_00010.avif', 'Název Jedem si pro vás naznačuje, že oproti plánovaným halovým koncertům ve velkých městech na podzim tohoto roku, se na jaře kapela vypraví blíže fanouškům a vyrazí za nimi do sálů napříč celou Českou republikou. Součástí každého večera bude samozřejmě i autogramiáda kapely a playlist bude opravdu nabušený největšími hity kapely a fanoušci si ho vychutnají v téměř dvouhodinvé délce.', 8, 9, NULL, 'completed', 1, 48),
+    (11, 'MIRAI Summer Fest 2025', '2025-09-06', '2025-09-06', 'https://homework2storage.blob.core.windows.net/blob/public/pictures/event_img/event_00011.jpg', 'Náš vlastní festival Mirai Summer Fest se po letošním úspěšném ročníku vrací do vašich měst i v roce 2025! A my z toho máme obrovskou radost. Skvělou atmosféru s našlapaným programem, super jídlem, drinky, a především nabitou večerní show přivezeme do 15 měst. Není pro nás nic cennějšího než si užít festivalový vibe s vámi, našimi fanoušky a opět spolu strávit čas.', 9, 9, NULL, 'completed', 1, 48),
+    (12, 'Na muzice ve Fabrice - Bigband Svitavy', '2025-04-27', '2025-04-27', 'https://homework2storage.blob.core.windows.net/blob/public/pictures/event_img/event_00012.webp', 'Na muzice ve Fabrice SBB zve všechny příznivce a milovníky taneční a swingové hudby na pohodové nedělní odpoledne. V programu zazní swingové evergreeny a jazzové standardy, které jsou prověřeny časem a kvalitní úpravy skladeb ze starší i současné popové scény. Odpoledne se stolovou úpravou, s konzumací, k poslechu i k tanci.', 10, 10, NULL, 'completed', 1, 48);
+
+INSERT INTO "position_category" ("id", "name", "organization_id") VALUES
+    (1, 'Bezpečnost a kontrola', 1),
+    (2, 'Organizace a logistika', 1),
+    (3, 'Obsluha a zákaznický servis', 1),
+    (4, 'Úklid', 1),
+    (5, 'Zdravotní služby', 1),
+    (6, 'Občerstvení a prodej', 1),
+    (7, 'Technická podpora a údržba', 1);
+
+INSERT INTO "job_position" ("id", "name", "description", "salary", "capacity", "instructions_html", "is_opened_for_registration", "currency", "event_id", "position_category_id", "instructions_markdown") VALUES
+    (33, 'Vstupenky', 'Organizace vstupů', 150, 2, '<p>Organizuj vstupy, kontroluj vstupenky a usměrňuj tok návštěvníků.</p>', FALSE, 'CZK', 11, 3, 'Organizuj vstupy, kontroluj vstupenky a usměrňuj tok návštěvníků.'),
+    (1, 'Stánek s hotdogy', 'Prodej hotdogů a dalších rychlých jídel návštěvníkům', 150, 2, '<p>Buď připraven obsluhovat rychle a s úsměvem. Udržuj pult čistý, dodržuj hygienické standardy a buď připraven na rychlou přípravu a prodej hotdogů.</p>', TRUE, 'CZK', 1, 6, 'Buď připraven obsluhovat rychle a s úsměvem. Udržuj pult čistý, dodržuj hygienické standardy a buď připraven na rychlou přípravu a prodej hotdogů.'),
+    (2, 'Technická podpora', 'Kompletní technické zajištění festivalu', 180, 3, '<p>Zajisti kompletní technické vybavení včetně ozvučení, osvětlení a dalších technických zařízení. Buď připraven rychle reagovat na případné technické problémy.</p>', TRUE, 'CZK', 1, 7, 'Zajisti kompletní technické vybavení včetně ozvučení, osvětlení a dalších technických zařízení. Buď připraven rychle reagovat na případné technické problémy.'),
+    (3, 'Koordinace vstupu', 'Komplexní organizace vstupů a registrace návštěvníků', 160, 2, '<p>Organizuj registraci a kontrolu vstupenek. Buď přehledný, komunikativní a dodržuj stanovený postup při vpouštění návštěvníků.</p>', FALSE, 'CZK', 1, 2, 'Organizuj registraci a kontrolu vstupenek. Buď přehledný, komunikativní a dodržuj stanovený postup při vpouštění návštěvníků.'),
+    (4, 'Koordinace parkoviště', 'Precizní organizace parkování', 140, 1, '<p>Usměrňuj parkování, udržuj pořádek a plynulost provozu. Buď vstřícný a jasně naviguj řidiče na volná místa.</p>', TRUE, 'CZK', 2, 2, 'Usměrňuj parkování, udržuj pořádek a plynulost provozu. Buď vstřícný a jasně naviguj řidiče na volná místa.'),
+    (5, 'Zdravotnický tým', 'Komplexní zdravotnické zabezpečení akce', 200, 5, '<p>Buď připraven poskytovat první pomoc, mít pohotovostní lékárničku a rychle reagovat na zdravotní komplikace návštěvníků.</p>', FALSE, 'CZK', 2, 5, 'Buď připraven poskytovat první pomoc, mít pohotovostní lékárničku a rychle reagovat na zdravotní komplikace návštěvníků.'),
+    (6, 'Úklidový tým', 'Komplexní úklid a údržba areálu', 130, 2, '<p>Pravidelně uklízej prostory, udržuj čistotu a pořádek. Buď flexibilní a připravený na okamžitý úklid.</p>', TRUE, 'CZK', 2, 4, 'Pravidelně uklízej prostory, udržuj čistotu a pořádek. Buď flexibilní a připravený na okamžitý úklid.'),
+    (7, 'Ochranka', 'Bezpečnostní dohled nad festivalem', 170, 1, '<p>Zajišťuj bezpečnost, monitoruj prostor a předcházej konfliktním situacím. Jednej profesionálně a klidně.</p>', TRUE, 'CZK', 3, 1, 'Zajišťuj bezpečnost, monitoruj prostor a předcházej konfliktním situacím. Jednej profesionálně a klidně.'),
+    (8, 'Kontrola vstupenek', 'Komplexní organizace vstupů', 160, 3, '<p>Pečlivě kontroluj vstupenky, ověřuj jejich platnost a usměrňuj tok návštěvníků.</p>', TRUE, 'CZK', 3, 1, 'Pečlivě kontroluj vstupenky, ověřuj jejich platnost a usměrňuj tok návštěvníků.'),
+    (9, 'Výpomoc v šatně', 'Organizace šatny a péče o věci návštěvníků', 130, 2, '<p>Organizuj ukládání a vydávání osobních věcí, buď pozorný a pečlivý při manipulaci s věcmi návštěvníků.</p>', FALSE, 'CZK', 3, 3, 'Organizuj ukládání a vydávání osobních věcí, buď pozorný a pečlivý při manipulaci s věcmi návštěvníků.'),
+    (10, 'Technická podpora', 'Specializovaná technická podpora pódia', 180, 5, '<p>Specializovaná technická podpora pro pódiové vybavení. Buď připraven na okamžité řešení technických záležitostí.</p>', TRUE, 'CZK', 4, 7, 'Specializovaná technická podpora pro pódiové vybavení. Buď připraven na okamžité řešení technických záležitostí.'),
+    (11, 'Koordinace parkoviště', 'Komplexní řízení parkování', 150, 3, '<p>Organizuj registraci a kontrolu vstupenek. Buď přehledný, komunikativní a dodržuj stanovený postup při vpouštění návštěvníků.</p>', TRUE, 'CZK', 4, 2, 'Organizuj registraci a kontrolu vstupenek. Buď přehledný, komunikativní a dodržuj stanovený postup při vpouštění návštěvníků.'),
+    (12, 'Úklidový tým', 'Údržba čistoty a pořádku', 140, 2, '<p>Specializovaná technická podpora pro pódiové vybavení. Buď připraven na okamžité řešení technických záležitostí.</p>', FALSE, 'CZK', 4, 4, 'Specializovaná technická podpora pro pódiové vybavení. Buď připraven na okamžité řešení technických záležitostí.'),
+    (13, 'Zdravotnický tým', 'Specializovaná zdravotnická pomoc', 200, 1, '<p>Poskytuj specializovanou zdravotnickou pomoc, buď připraven řešit různé zdravotní situace.</p>', TRUE, 'CZK', 5, 5, 'Poskytuj specializovanou zdravotnickou pomoc, buď připraven řešit různé zdravotní situace.'),
+    (14, 'Kontrola vstupenek', 'Komplexní organizace vstupů', 160, 3, '<p>Pečlivě kontroluj vstupenky, ověřuj jejich platnost a usměrňuj tok návštěvníků.</p>', TRUE, 'CZK', 5, 1, 'Pečlivě kontroluj vstupenky, ověřuj jejich platnost a usměrňuj tok návštěvníků.'),
+    (15, 'Občerstvení', 'Příprava a distribuce jídla', 150, 2, '<p>Připravuj a distribuuj jídlo, udržuj čistotu stánku a buď rychlý a vstřícný.</p>', FALSE, 'CZK', 5, 6, 'Připravuj a distribuuj jídlo, udržuj čistotu stánku a buď rychlý a vstřícný.'),
+    (16, 'Bezpečnostní tým', 'Specializovaný bezpečnostní dohled', 180, 1, '<p>Zajišťuj komplexní bezpečnostní dohled, buď připraven rychle a profesionálně řešit potenciální bezpečnostní rizika.</p>', TRUE, 'CZK', 6, 1, 'Zajišťuj komplexní bezpečnostní dohled, buď připraven rychle a profesionálně řešit potenciální bezpečnostní rizika.'),
+    (17, 'Šatnový tým', 'Komplexní správa šatny', 130, 3, '<p>Komplexně spravuj šatnu, buď organizovaný a pečlivý při přijímání a vydávání svršků.</p>', TRUE, 'CZK', 6, 3, 'Komplexně spravuj šatnu, buď organizovaný a pečlivý při přijímání a vydávání svršků.'),
+    (18, 'Úklidové práce', 'Komplexní údržba areálu', 140, 2, '<p>Pravidelně uklízej prostory, udržuj čistotu a pořádek. Buď flexibilní a připravený na okamžitý úklid.</p>', FALSE, 'CZK', 6, 4, 'Pravidelně uklízej prostory, udržuj čistotu a pořádek. Buď flexibilní a připravený na okamžitý úklid.'),
+    (19, 'Technická podpora', 'Specializovaná technická asistence', 170, 2, '<p>Specializovaná technická asistence. Buď připraven na okamžité řešení technických záležitostí.</p>', TRUE, 'CZK', 7, 7, 'Specializovaná technická asistence. Buď připraven na okamžité řešení technických záležitostí.'),
+    (20, 'Zdravotnický tým', 'Komplexní zdravotnické zabezpečení', 190, 3, '<p>Komplexní zdravotnické zabezpečení. Buď připraven poskytovat specializovanou zdravotnickou pomoc.</p>', TRUE, 'CZK', 7, 5, 'Komplexní zdravotnické zabezpečení. Buď připraven poskytovat specializovanou zdravotnickou pomoc.'),
+    (21, 'Organizace vstupu', 'Koordinace vstupů a registrace', 150, 2, '<p>Koordinuj vstupy a registrace. Buď přehledný, komunikativní a dodržuj stanovený postup.</p>', FALSE, 'CZK', 7, 2, 'Koordinuj vstupy a registrace. Buď přehledný, komunikativní a dodržuj stanovený postup.'),
+    (22, 'Koordinace dopravy', 'Specializovaná dopravní organizace', 135, 1, '<p>Specializovaná dopravní organizace. Usměrňuj přesuny a zajišťuj plynulost pohybu.</p>', TRUE, 'CZK', 8, 2, 'Specializovaná dopravní organizace. Usměrňuj přesuny a zajišťuj plynulost pohybu.'),
+    (23, 'Občerstvení', 'Komplexní zajištění stravování', 145, 3, '<p>Komplexně zajišťuj stravování, buď rychlý, vstřícný a udržuj čistotu stánku.</p>', TRUE, 'CZK', 8, 6, 'Komplexně zajišťuj stravování, buď rychlý, vstřícný a udržuj čistotu stánku.'),
+    (24, 'Úklidový tým', 'Údržba pořádku a čistoty', 125, 2, '<p>Udržuj pořádek a čistotu. Buď flexibilní a připravený na okamžitý úklid.</p>', FALSE, 'CZK', 8, 4, 'Udržuj pořádek a čistotu. Buď flexibilní a připravený na okamžitý úklid.'),
+    (25, 'Kontrola vstupu', 'Specializovaná vstupní kontrola', 130, 3, '<p>Prováděj specializovanou vstupní kontrolu, buď důsledný a pozorný při ověřování vstupenek.</p>', TRUE, 'CZK', 9, 1, 'Prováděj specializovanou vstupní kontrolu, buď důsledný a pozorný při ověřování vstupenek.'),
+    (26, 'Technická podpora', 'Komplexní technické zabezpečení', 160, 3, '<p>Komplexní technické zabezpečení. Buď připraven rychle reagovat na technické problémy.</p>', TRUE, 'CZK', 9, 7, 'Komplexní technické zabezpečení. Buď připraven rychle reagovat na technické problémy.'),
+    (27, 'Koordinace parkoviště', 'Organizace parkování', 140, 5, '<p>Organizuj parkování, udržuj pořádek a usměrňuj dopravu na parkovišti.</p>', FALSE, 'CZK', 9, 2, 'Organizuj parkování, udržuj pořádek a usměrňuj dopravu na parkovišti.'),
+    (28, 'Úklidový tým', 'Specializovaný úklid', 120, 1, '<p>Prováděj specializovaný úklid, buď důsledný a flexibilní při udržování čistoty.</p>', TRUE, 'CZK', 10, 4, 'Prováděj specializovaný úklid, buď důsledný a flexibilní při udržování čistoty.'),
+    (29, 'Zdravotnická péče', 'Komplexní zdravotnické služby', 190, 3, '<p>Poskytuj komplexní zdravotnické služby, buď připraven rychle reagovat na zdravotní komplikace.</p>', TRUE, 'CZK', 10, 5, 'Poskytuj komplexní zdravotnické služby, buď připraven rychle reagovat na zdravotní komplikace.'),
+    (30, 'Bezpečnostní dohled', 'Organizace bezpečnosti', 170, 2, '<p>Organizuj bezpečnost, monitoruj prostor a předcházej potenciálním konfliktním situacím.</p>', FALSE, 'CZK', 10, 1, 'Organizuj bezpečnost, monitoruj prostor a předcházej potenciálním konfliktním situacím.'),
+    (31, 'Šatnový personál', 'Specializovaná správa šatny', 130, 1, '<p>Specializovaná správa šatny. Buď organizovaný a pečlivý při manipulaci s osobními věcmi.</p>', TRUE, 'CZK', 11, 3, 'Specializovaná správa šatny. Buď organizovaný a pečlivý při manipulaci s osobními věcmi.'),
+    (32, 'Koordinace parkoviště', 'Komplexní řízení dopravy', 145, 3, '<p>Komplexně řiď dopravu, usměrňuj parkování a zajišťuj plynulost provozu.</p>', TRUE, 'CZK', 11, 2, 'Komplexně řiď dopravu, usměrňuj parkování a zajišťuj plynulost provozu.'),
+    (34, 'Technická podpora', 'Specializovaná technická asistence', 160, 3, '<p>Specializovaná technická asistence. Buď připraven rychle řešit technické záležitosti.</p>', TRUE, 'CZK', 12, 7, 'Specializovaná technická asistence. Buď připraven rychle řešit technické záležitosti.'),
+    (35, 'Zdravotnický tým', 'Komplexní zdravotnické zabezpečení', 190, 3, '<p>Komplexní zdravotnické zabezpečení. Poskytuj profesionální zdravotnickou pomoc.</p>', TRUE, 'CZK', 12, 5, 'Komplexní zdravotnické zabezpečení. Poskytuj profesionální zdravotnickou pomoc.'),
+    (36, 'Úklidové práce', 'Údržba pořádku', 140, 2, '<p>Udržuj pořádek, prováděj pravidelný úklid a buď flexibilní při řešení úklidových potřeb.</p>', FALSE, 'CZK', 12, 4, 'Udržuj pořádek, prováděj pravidelný úklid a buď flexibilní při řešení úklidových potřeb.');
+
+INSERT INTO "employment" ("id", "rating", "state", "user_id", "position_id") VALUES
+    (1, 0, 'done', 16, 1),
+    (2, 0, 'rejected', 17, 5),
+    (3, 0, 'done', 18, 26),
+    (4, 0, 'rejected', 19, 5),
+    (5, 0, 'pending', 19, 10),
+    (6, 0, 'accepted', 20, 14),
+    (7, 0, 'rejected', 20, 10),
+    (8, 0, 'rejected', 21, 10),
+    (9, 0, 'pending', 21, 36),
+    (10, 0, 'rejected', 22, 29),
+    (11, 0, 'rejected', 22, 35),
+    (12, 0, 'accepted', 19, 27),
+    (13, 0, 'accepted', 23, 27),
+    (14, 0, 'accepted', 23, 18),
+    (15, 0, 'rejected', 24, 23),
+    (16, 0, 'rejected', 25, 20),
+    (17, 0, 'pending', 25, 25),
+    (18, 0, 'accepted', 25, 28),
+    (19, 0, 'pending', 26, 14),
+    (20, 0, 'done', 26, 17),
+    (21, 0, 'rejected', 27, 19),
+    (22, 0, 'accepted', 27, 36),
+    (23, 0, 'pending', 28, 17),
+    (24, 0, 'pending', 28, 34),
+    (25, 0, 'pending', 29, 7),
+    (26, 0, 'done', 29, 27),
+    (27, 0, 'rejected', 29, 34),
+    (28, 0, 'accepted', 30, 32),
+    (29, 0, 'rejected', 31, 13),
+    (30, 0, 'rejected', 31, 19),
+    (31, 0, 'pending', 32, 35),
+    (32, 0, 'done', 32, 27),
+    (33, 0, 'done', 33, 24),
+    (34, 4, 'rejected', 33, 2),
+    (35, 0, 'pending', 33, 25),
+    (36, 0, 'rejected', 34, 5),
+    (37, 0, 'pending', 34, 26),
+    (38, 0, 'accepted', 35, 8),
+    (39, 0, 'rejected', 35, 10),
+    (40, 0, 'rejected', 36, 26),
+    (41, 0, 'rejected', 37, 32),
+    (42, 3, 'accepted', 37, 3),
+    (43, 5, 'accepted', 38, 3),
+    (44, 0, 'done', 38, 34),
+    (45, 0, 'accepted', 38, 32),
+    (46, 0, 'pending', 39, 23),
+    (47, 0, 'accepted', 39, 5),
+    (48, 0, 'done', 40, 20),
+    (49, 0, 'accepted', 1, 1);
+
+INSERT INTO "worked_hours" ("id", "date", "hours_worked", "employment_id") VALUES
+    (1, '2024-08-09', 5.1, 1),
+    (2, '2025-07-03', 7.5, 3),
+    (3, '2025-07-04', 9.2, 3),
+    (4, '2025-06-13', 10.2, 20),
+    (5, '2025-04-27', 4, 22),
+    (6, '2025-04-27', 8.9, 22),
+    (7, '2025-07-05', 2.5, 26),
+    (8, '2025-07-02', 10.9, 26),
+    (9, '2025-07-04', 3.8, 32),
+    (10, '2025-05-25', 5.3, 33),
+    (11, '2025-05-25', 5.5, 33),
+    (12, '2025-04-27', 6.6, 44),
+    (13, '2025-04-27', 11.8, 44),
+    (14, '2025-09-06', 5.7, 45),
+    (15, '2025-08-15', 9.5, 47),
+    (16, '2025-07-07', 3, 48),
+    (17, '2025-07-07', 7.7, 48),
+    (18, '2024-08-09', 6, 49);
+
+INSERT INTO "event_manager_relation" ("user_id", "event_id", "can_edit_event", "can_manage_jobs", "can_manage_applicants", "can_approve_hours", "can_view_payroll", "can_manage_managers") VALUES
+    (7, 1, TRUE, TRUE, TRUE, TRUE, TRUE, TRUE),
+    (8, 1, TRUE, TRUE, TRUE, TRUE, TRUE, TRUE),
+    (15, 2, TRUE, TRUE, TRUE, TRUE, TRUE, TRUE),
+    (15, 3, TRUE, TRUE, TRUE, TRUE, TRUE, TRUE),
+    (6, 4, TRUE, TRUE, TRUE, TRUE, TRUE, TRUE),
+    (14, 5, TRUE, TRUE, TRUE, TRUE, TRUE, TRUE),
+    (12, 6, TRUE, TRUE, TRUE, TRUE, TRUE, TRUE),
+    (7, 7, TRUE, TRUE, TRUE, TRUE, TRUE, TRUE),
+    (10, 8, TRUE, TRUE, TRUE, TRUE, TRUE, TRUE),
+    (10, 9, TRUE, TRUE, TRUE, TRUE, TRUE, TRUE),
+    (11, 11, TRUE, TRUE, TRUE, TRUE, TRUE, TRUE),
+    (12, 12, TRUE, TRUE, TRUE, TRUE, TRUE, TRUE);
+
+-- Existing employments start their history in the state they are in now.
+INSERT INTO "employment_state_history" ("employment_id", "from_state", "to_state")
+SELECT "id", NULL, "state" FROM "employment";
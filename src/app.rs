@@ -5,46 +5,103 @@ use axum::{
     routing::{get, post},
     Router,
 };
+use axum::extract::DefaultBodyLimit;
 use axum::routing::patch;
 use axum_login::{login_required, AuthManagerLayerBuilder};
-use sqlx::{postgres::PgPoolOptions, PgPool};
 use tokio::net::TcpListener;
 use tower_http::{services::ServeDir, trace::TraceLayer};
-use tower_sessions::{cookie::Key, SessionManagerLayer};
-use tower_sessions_sqlx_store::PostgresStore;
+use tower_sessions::{cookie::Key, SessionManagerLayer, SessionStore};
+use tower_sessions_sqlx_store::{PostgresStore, SqliteStore};
 
 use crate::middleware;
 use crate::{
     auth::Backend,
-    handlers,
+    csrf, handlers,
+    models::{attendance::NoShowBlock, staffing::StaffingWeights},
+    policy::EventPolicy,
     repositories::{
-        employment::PgEmploymentRepository, event::PgEventRepository,
-        event_manager_relation::PgEventManagerRelationRepository,
-        job_position::PgJobPositionRepository, pool_handler::PoolHandler,
-        position_category::PgPositionCategoryRepository, user::PgUserRepository,
-        venue::PgVenueRepository, worked_hours::PgWorkedHoursRepository,
+        attendance::AttendanceRepository,
+        audit_log::AuditLogRepository,
+        database::Database,
+        employment::EmploymentRepository,
+        event::EventRepository,
+        event_manager_relation::EventManagerRelationRepository,
+        job_position::{JobPositionRepository, PgJobPositionRepository},
+        notification::NotificationRepository,
+        organization::OrganizationRepository,
+        position_category::PositionCategoryRepository,
+        read_model::ReadModelRepository,
+        review::ReviewRepository,
+        sqlite::job_position::SqliteJobPositionRepository,
+        user::UserRepository,
+        venue::VenueRepository,
+        worked_hours::WorkedHoursRepository,
     },
+    scheduler,
+    security_headers::{security_headers, SecurityHeaders},
+    storage::{blob_store_from_env, upload::UploadLimits, BlobStore},
 };
 
 const DEFAULT_HOSTNAME: &str = "0.0.0.0";
 const DEFAULT_PORT: &str = "3000";
 
 pub struct App {
-    database_url: String,
     server_address: String,
-    db_pool: Arc<PgPool>,
+    database: Database,
 }
 
 #[derive(Clone)]
 pub struct AppState {
-    pub user_repository: PgUserRepository,
-    pub employment_repository: PgEmploymentRepository,
-    pub event_repository: PgEventRepository,
-    pub venue_repository: PgVenueRepository,
-    pub job_position_repository: PgJobPositionRepository,
-    pub position_category_repository: PgPositionCategoryRepository,
-    pub worked_hours_repository: PgWorkedHoursRepository,
-    pub event_manager_relation_repository: PgEventManagerRelationRepository,
+    /// Backend of the repositories, for handlers that compose repository calls in a
+    /// `UnitOfWork`.
+    pub database: Database,
+    pub user_repository: Arc<dyn UserRepository>,
+    pub employment_repository: Arc<dyn EmploymentRepository>,
+    pub event_repository: Arc<dyn EventRepository>,
+    pub venue_repository: Arc<dyn VenueRepository>,
+    pub job_position_repository: Arc<dyn JobPositionRepository>,
+    pub position_category_repository: Arc<dyn PositionCategoryRepository>,
+    pub worked_hours_repository: Arc<dyn WorkedHoursRepository>,
+    pub event_manager_relation_repository: Arc<dyn EventManagerRelationRepository>,
+    pub audit_log_repository: Arc<dyn AuditLogRepository>,
+    pub attendance_repository: Arc<dyn AttendanceRepository>,
+    pub review_repository: Arc<dyn ReviewRepository>,
+    pub notification_repository: Arc<dyn NotificationRepository>,
+    pub organization_repository: Arc<dyn OrganizationRepository>,
+    pub read_model_repository: Arc<dyn ReadModelRepository>,
+    pub event_policy: EventPolicy,
+    pub staffing_weights: StaffingWeights,
+    pub no_show_block: NoShowBlock,
+    pub blob_store: Arc<dyn BlobStore>,
+    pub upload_limits: UploadLimits,
+}
+
+impl AppState {
+    pub fn new(database: Database, blob_store: Arc<dyn BlobStore>) -> Self {
+        let repositories = database.repositories();
+        Self {
+            event_policy: EventPolicy::from_repositories(&repositories),
+            database,
+            user_repository: repositories.user,
+            employment_repository: repositories.employment,
+            event_repository: repositories.event,
+            venue_repository: repositories.venue,
+            job_position_repository: repositories.job_position,
+            position_category_repository: repositories.position_category,
+            worked_hours_repository: repositories.worked_hours,
+            event_manager_relation_repository: repositories.event_manager_relation,
+            audit_log_repository: repositories.audit_log,
+            attendance_repository: repositories.attendance,
+            review_repository: repositories.review,
+            notification_repository: repositories.notification,
+            organization_repository: repositories.organization,
+            read_model_repository: repositories.read_model,
+            staffing_weights: StaffingWeights::from_env(),
+            no_show_block: NoShowBlock::from_env(),
+            blob_store,
+            upload_limits: UploadLimits::from_env(),
+        }
+    }
 }
 
 impl App {
@@ -61,18 +118,12 @@ impl App {
         let database_url =
             env::var("DATABASE_URL").expect("DATABASE_URL was not found in .env file.");
 
-        // Create database pool
-        let db_pool = Arc::new(
-            PgPoolOptions::new()
-                .max_connections(5)
-                .connect(&database_url)
-                .await?,
-        );
+        // Connect to the database, Postgres or SQLite depending on the scheme of the URL
+        let database = Database::connect(&database_url).await?;
 
         Ok(Self {
-            database_url,
             server_address,
-            db_pool,
+            database,
         })
     }
 
@@ -83,143 +134,30 @@ impl App {
             .init();
 
         // Initialize global app state
-        let app_state = AppState {
-            user_repository: PgUserRepository::new(PoolHandler::new(self.db_pool.clone())),
-            employment_repository: PgEmploymentRepository::new(PoolHandler::new(
-                self.db_pool.clone(),
-            )),
-            event_repository: PgEventRepository::new(PoolHandler::new(self.db_pool.clone())),
-            venue_repository: PgVenueRepository::new(PoolHandler::new(self.db_pool.clone())),
-            job_position_repository: PgJobPositionRepository::new(PoolHandler::new(
-                self.db_pool.clone(),
-            )),
-            position_category_repository: PgPositionCategoryRepository::new(PoolHandler::new(
-                self.db_pool.clone(),
-            )),
-            worked_hours_repository: PgWorkedHoursRepository::new(PoolHandler::new(
-                self.db_pool.clone(),
-            )),
-            event_manager_relation_repository: PgEventManagerRelationRepository::new(
-                PoolHandler::new(self.db_pool.clone()),
-            ),
-        };
+        let app_state = AppState::new(self.database.clone(), blob_store_from_env()?);
 
-        // Setup auth
-        let session_store = PostgresStore::new(
-            PgPoolOptions::new()
-                .max_connections(5)
-                .connect(&self.database_url)
-                .await
-                .expect("Can't connect to database!"),
+        // Start background jobs
+        scheduler::spawn_scheduler(
+            app_state.event_repository.clone(),
+            app_state.job_position_repository.clone(),
+            app_state.employment_repository.clone(),
         );
-        session_store.migrate().await?;
-        let key = Key::generate();
-        let session_layer = SessionManagerLayer::new(session_store)
-            .with_secure(false)
-            .with_signed(key);
+
+        // Setup auth, keeping sessions in the same database as everything else
         let backend = Backend::new(app_state.user_repository.clone());
-        let auth_layer = AuthManagerLayerBuilder::new(backend, session_layer).build();
-
-        // Setup routes
-        let app_router = Router::new()
-            .route("/admin/users", get(handlers::app::admin::users::get::users).post(handlers::app::admin::users::post::users))
-            .route("/admin/user/:user_id", get(handlers::app::admin::user::get::user).delete(handlers::app::admin::user::delete::user))
-            .route("/admin/user", patch(handlers::app::admin::user::patch::user).post(handlers::app::admin::user::post::register))
-            .route("/admin/user/create", get(handlers::app::admin::user::get_create_template::user))
-            .route(
-                "/admin/events",
-                get(handlers::app::admin::events::get::events),
-            )
-            .route("/admin/jobs", get(handlers::app::admin::jobs::get::jobs))
-            .route_layer(axum::middleware::from_fn_with_state(app_state.clone(), middleware::global::check_admin))
-            .route("/employments", get(handlers::app::employments::get::employments).post(handlers::app::employments::post::employments))
-            .route("/employments/action", post(handlers::partials::base::main::employments::employments_action::post::action))
-            .route("/employees", get(handlers::app::employees::get::employees))
-            .route(
-                "/employees/:employee_id",
-                get(handlers::app::employees::employee::get::employee),
-            )
-            .route(
-                "/manage/events/:event_id",
-                get(handlers::app::events::event::manage::get::manage),
-            )
-            .route(
-                "/manage/events",
-                get(handlers::app::events::manage::get::manage),
-            )
-            .route(
-                "/manage/jobs",
-                get(handlers::app::jobs::manage::get::manage).patch(handlers::app::jobs::job::manage::patch::manage),
-            )
-            .route(
-                "/manage/jobs/:job_id",
-                get(handlers::app::jobs::job::manage::get::manage).delete(handlers::app::jobs::job::manage::delete::manage),
-            )
-            .route(
-                "/manage/venues",
-                get(handlers::app::venues::manage::get::manage).patch(handlers::app::venues::venue::manage::patch::manage),
-            )
-            .route(
-                "/manage/venues/:venue_id",
-                get(handlers::app::venues::venue::manage::get::manage).delete(handlers::app::venues::venue::manage::delete::manage),
-            )
-            .route(
-                "/create/venues",
-                get(handlers::app::venues::venue::create::get::create).post(handlers::app::venues::venue::create::post::create),
-            )
-            .route(
-                "/create/events",
-                get(handlers::app::events::event::create::get::create),
-            )
-            .route(
-                "/create/jobs",
-                get(handlers::app::jobs::create::get::create).post(handlers::app::jobs::create::post::create),
-            )
-            .route_layer(axum::middleware::from_fn_with_state(app_state.clone(), middleware::global::check_organizer))
-            .route(
-                "/partials/base/main/attendance/attendance-log",
-                get(handlers::partials::base::main::attendance::attendance_log::get::attendance_log).patch(handlers::partials::base::main::attendance::attendance_log::patch::attendance_log),
-            )
-            .route(
-                "/attendance",
-                get(handlers::app::attendance::get::attendance),
-            )
-            .route(
-                "/partials/base/main/attendance/attendance-job-options",
-                get(
-                    handlers::partials::base::main::attendance::attendance_job_options::get::attendance_job_options,
-                ),
-            )
-            .route("/jobs", get(handlers::app::jobs::get::jobs).post(handlers::app::jobs::post::jobs))
-            .route("/partials/base/main/events/event/job-state/:job_id", post(handlers::partials::base::main::events::event::job_state::post::job_state))
-            .route("/events", post(handlers::app::events::post::events).patch(handlers::app::events::patch::events))
-            .route(
-                "/employment",
-                post(handlers::app::employment::post::employment).delete(handlers::app::employment::delete::employment),
-            )
-            .route("/event-manager-relation", post(handlers::app::event_manager_relation::post::event_manager_relation).delete(handlers::app::event_manager_relation::delete::event_manager_relation))
-            .route("/settings/details", get(handlers::app::settings::details::get::details).patch(handlers::app::settings::details::patch::details))
-            .route("/settings/password", get(handlers::app::settings::password::get::password).patch(handlers::app::settings::password::patch::password))
-
-            .route("/logout", get(handlers::app::auth::get::logout))
-            .route_layer(login_required!(Backend, login_url = "/login"))
-            .route("/", get(handlers::app::index::get::index))
-            .route(
-                "/login",
-                get(handlers::app::auth::get::login).post(handlers::app::auth::post::login),
-            )
-            .route("/register", get(handlers::app::auth::get::register).post(handlers::app::auth::post::register))
-            .route("/events", get(handlers::app::events::get::events))
-            .route(
-                "/events/:event_id",
-                get(handlers::app::events::event::get::event).delete(handlers::app::events::event::delete::event),
-            )
-            .route("/partials/base/main/events/events-content", get(handlers::partials::base::main::events::events_content::get::events_content))
-            .fallback(handlers::app::page_not_found::page_not_found)
-            .with_state(app_state)
-            .nest_service("/public", ServeDir::new("public"))
-            .layer(TraceLayer::new_for_http())
-            .layer(auth_layer);
+        let app_router = match &self.database {
+            Database::Postgres(pool_handler) => {
+                let session_store = PostgresStore::new(pool_handler.pool().clone());
+                session_store.migrate().await?;
+                with_auth(router(app_state), backend, session_store)
+            }
+            Database::Sqlite(pool) => {
+                let session_store = SqliteStore::new(pool.clone());
+                session_store.migrate().await?;
+                with_auth(router(app_state), backend, session_store)
+            }
+            Database::Memory(_) => anyhow::bail!("The in-memory database cannot be served"),
+        };
 
         // Bind server
         let listener = TcpListener::bind(&self.server_address).await?;
@@ -234,4 +172,186 @@ impl App {
         axum::serve(listener, app_router).await?;
         Ok(())
     }
+
+    /// Renders job instructions left without HTML by the migration to Markdown.
+    pub async fn sanitize_instructions(&self) -> Result<()> {
+        let sanitized = match &self.database {
+            Database::Postgres(pool_handler) => {
+                PgJobPositionRepository::new(pool_handler.clone())
+                    .sanitize_legacy_instructions()
+                    .await?
+            }
+            Database::Sqlite(pool) => {
+                SqliteJobPositionRepository::new(pool.clone())
+                    .sanitize_legacy_instructions()
+                    .await?
+            }
+            Database::Memory(_) => anyhow::bail!("The in-memory database has no instructions"),
+        };
+        println!("Sanitized the instructions of {sanitized} job position(s)");
+        Ok(())
+    }
+}
+
+/// Adds authentication to `router`, with sessions kept in `session_store`.
+fn with_auth<S: SessionStore + Clone>(
+    router: Router,
+    backend: Backend,
+    session_store: S,
+) -> Router {
+    let key = Key::generate();
+    let session_layer = SessionManagerLayer::new(session_store)
+        .with_secure(false)
+        .with_signed(key);
+    let auth_layer = AuthManagerLayerBuilder::new(backend, session_layer).build();
+    router.layer(auth_layer)
+}
+
+/// Routes of the application with every layer except authentication, which the caller
+/// adds on top so it can pick the session store.
+pub fn router(app_state: AppState) -> Router {
+    Router::new()
+        .route("/admin/users", get(handlers::app::admin::users::get::users).post(handlers::app::admin::users::post::users))
+        .route("/admin/user/:user_id", get(handlers::app::admin::user::get::user).delete(handlers::app::admin::user::delete::user))
+        .route("/admin/user", patch(handlers::app::admin::user::patch::user).post(handlers::app::admin::user::post::register))
+        .route("/admin/user/create", get(handlers::app::admin::user::get_create_template::user))
+        .route(
+            "/admin/events",
+            get(handlers::app::admin::events::get::events),
+        )
+        .route("/admin/jobs", get(handlers::app::admin::jobs::get::jobs))
+        .route("/admin/audit", get(handlers::app::admin::audit::get::audit).post(handlers::app::admin::audit::post::audit))
+        .route("/admin/trash", get(handlers::app::admin::trash::get::trash))
+        .route("/admin/reviews", get(handlers::app::admin::reviews::get::reviews))
+        .route("/admin/reviews/:review_id", patch(handlers::app::admin::reviews::patch::reviews))
+        .route("/admin/trash/:entity/:id", patch(handlers::app::admin::trash::patch::trash).delete(handlers::app::admin::trash::delete::trash))
+        .route_layer(axum::middleware::from_fn_with_state(app_state.clone(), middleware::global::check_admin))
+        .route("/employments", get(handlers::app::employments::get::employments).post(handlers::app::employments::post::employments))
+        .route("/employments/action", post(handlers::partials::base::main::employments::employments_action::post::action))
+        .route("/employments/bulk-action", post(handlers::partials::base::main::employments::employments_action::post::bulk_action))
+        .route("/employments/:employment_id/attendance", post(handlers::partials::base::main::employments::attendance::post::attendance))
+        .route("/employments/:employment_id/review", post(handlers::partials::base::main::employments::review::post::review))
+        .route("/employees", get(handlers::app::employees::get::employees))
+        .route(
+            "/employees/:employee_id",
+            get(handlers::app::employees::employee::get::employee),
+        )
+        .route(
+            "/manage/events/:event_id",
+            get(handlers::app::events::event::manage::get::manage),
+        )
+        .route(
+            "/manage/events/:event_id/cancel",
+            post(handlers::app::events::event::manage::post::cancel),
+        )
+        .route(
+            "/manage/events/:event_id/status",
+            patch(handlers::app::events::event::manage::patch::status),
+        )
+        .route(
+            "/manage/events/:event_id/hero",
+            post(handlers::app::uploads::post::event_hero)
+                .layer(DefaultBodyLimit::max(app_state.upload_limits.body_limit())),
+        )
+        .route(
+            "/manage/events",
+            get(handlers::app::events::manage::get::manage),
+        )
+        .route(
+            "/manage/jobs",
+            get(handlers::app::jobs::manage::get::manage).patch(handlers::app::jobs::job::manage::patch::manage),
+        )
+        .route(
+            "/manage/jobs/:job_id",
+            get(handlers::app::jobs::job::manage::get::manage).delete(handlers::app::jobs::job::manage::delete::manage),
+        )
+        .route(
+            "/manage/jobs/:job_id/suggestions",
+            get(handlers::app::jobs::job::suggestions::get::suggestions),
+        )
+        .route(
+            "/manage/venues",
+            get(handlers::app::venues::manage::get::manage).patch(handlers::app::venues::venue::manage::patch::manage),
+        )
+        .route(
+            "/manage/venues/:venue_id",
+            get(handlers::app::venues::venue::manage::get::manage).delete(handlers::app::venues::venue::manage::delete::manage),
+        )
+        .route(
+            "/create/venues",
+            get(handlers::app::venues::venue::create::get::create).post(handlers::app::venues::venue::create::post::create),
+        )
+        .route(
+            "/create/events",
+            get(handlers::app::events::event::create::get::create),
+        )
+        .route(
+            "/create/jobs",
+            get(handlers::app::jobs::create::get::create).post(handlers::app::jobs::create::post::create),
+        )
+        .route(
+            "/partials/base/main/jobs/instructions-preview",
+            post(handlers::partials::base::main::jobs::instructions_preview::post::instructions_preview),
+        )
+        .route_layer(axum::middleware::from_fn_with_state(app_state.clone(), middleware::global::check_organizer))
+        .route(
+            "/partials/base/main/attendance/attendance-log",
+            get(handlers::partials::base::main::attendance::attendance_log::get::attendance_log).patch(handlers::partials::base::main::attendance::attendance_log::patch::attendance_log),
+        )
+        .route(
+            "/attendance",
+            get(handlers::app::attendance::get::attendance),
+        )
+        .route(
+            "/notifications",
+            get(handlers::app::notifications::get::notifications),
+        )
+        .route(
+            "/partials/base/main/attendance/attendance-job-options",
+            get(
+                handlers::partials::base::main::attendance::attendance_job_options::get::attendance_job_options,
+            ),
+        )
+        .route("/jobs", get(handlers::app::jobs::get::jobs).post(handlers::app::jobs::post::jobs))
+        .route("/jobs/invitations/:employment_id", post(handlers::app::jobs::invitation::post::invitation))
+        .route("/jobs/withdrawals/:employment_id", post(handlers::app::jobs::withdrawal::post::withdrawal))
+        .route("/jobs/reviews/:employment_id", post(handlers::app::jobs::review::post::review))
+        .route("/reviews/:review_id/flag", post(handlers::app::reviews::post::flag))
+        .route("/partials/base/main/events/event/job-state/:job_id", post(handlers::partials::base::main::events::event::job_state::post::job_state))
+        .route("/events", post(handlers::app::events::post::events).patch(handlers::app::events::patch::events))
+        .route(
+            "/employment",
+            post(handlers::app::employment::post::employment).delete(handlers::app::employment::delete::employment),
+        )
+        .route("/event-manager-relation", post(handlers::app::event_manager_relation::post::event_manager_relation).patch(handlers::app::event_manager_relation::patch::event_manager_relation).delete(handlers::app::event_manager_relation::delete::event_manager_relation))
+        .route("/settings/details", get(handlers::app::settings::details::get::details).patch(handlers::app::settings::details::patch::details))
+        .route("/settings/password", get(handlers::app::settings::password::get::password).patch(handlers::app::settings::password::patch::password))
+        .route("/settings/avatar", post(handlers::app::uploads::post::avatar).layer(DefaultBodyLimit::max(app_state.upload_limits.body_limit())))
+        .route("/organizations", get(handlers::app::organizations::get::organizations).post(handlers::app::organizations::post::organizations))
+        .route("/organizations/active", post(handlers::app::organizations::post::active))
+        .route("/organizations/members", post(handlers::app::organizations::post::members).delete(handlers::app::organizations::delete::members))
+
+        .route("/logout", get(handlers::app::auth::get::logout))
+        .route_layer(login_required!(Backend, login_url = "/login"))
+        .route("/", get(handlers::app::index::get::index))
+        .route(
+            "/login",
+            get(handlers::app::auth::get::login).post(handlers::app::auth::post::login),
+        )
+        .route("/register", get(handlers::app::auth::get::register).post(handlers::app::auth::post::register))
+        .route("/events", get(handlers::app::events::get::events))
+        .route(
+            "/events/:event_id",
+            get(handlers::app::events::event::get::event).delete(handlers::app::events::event::delete::event),
+        )
+        .route("/partials/base/main/events/events-content", get(handlers::partials::base::main::events::events_content::get::events_content))
+        .route("/uploads/*key", get(handlers::app::uploads::get::upload))
+        .fallback(handlers::app::page_not_found::page_not_found)
+        .layer(axum::middleware::from_fn(middleware::error_pages::render_error_pages))
+        .layer(axum::middleware::from_fn_with_state(app_state.clone(), middleware::global::resolve_organization))
+        .layer(axum::middleware::from_fn(csrf::csrf_protection))
+        .with_state(app_state)
+        .nest_service("/public", ServeDir::new("public"))
+        .layer(axum::middleware::from_fn_with_state(SecurityHeaders::from_env(), security_headers))
+        .layer(TraceLayer::new_for_http())
 }
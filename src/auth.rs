@@ -3,12 +3,11 @@ use axum_login::{AuthUser, AuthnBackend, UserId};
 use core::str;
 use password_auth::verify_password;
 use serde::Deserialize;
+use std::fmt;
+use std::sync::Arc;
 use tokio::task;
 
-use crate::{
-    models::user::User,
-    repositories::user::{PgUserRepository, UserRepository},
-};
+use crate::{error::RepositoryError, models::user::User, repositories::user::UserRepository};
 
 impl AuthUser for User {
     type Id = i32;
@@ -29,17 +28,23 @@ pub struct Credentials {
     pub next: Option<String>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct Backend {
-    user_repository: PgUserRepository,
+    user_repository: Arc<dyn UserRepository>,
 }
 
 impl Backend {
-    pub fn new(user_repository: PgUserRepository) -> Self {
+    pub fn new(user_repository: Arc<dyn UserRepository>) -> Self {
         Self { user_repository }
     }
 }
 
+impl fmt::Debug for Backend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Backend").finish_non_exhaustive()
+    }
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
     #[error(transparent)]
@@ -82,7 +87,10 @@ impl AuthnBackend for Backend {
     }
 
     async fn get_user(&self, user_id: &UserId<Self>) -> Result<Option<Self::User>, Self::Error> {
-        let user = self.user_repository.get_user_by_id(*user_id).await.unwrap();
-        Ok(Some(user))
+        match self.user_repository.get_user_by_id(*user_id).await {
+            Ok(user) => Ok(Some(user)),
+            Err(RepositoryError::NotFound) => Ok(None),
+            Err(err) => Err(Error::Anyhow(anyhow::Error::new(err))),
+        }
     }
 }
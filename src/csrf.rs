@@ -0,0 +1,67 @@
+use axum::{
+    extract::Request,
+    http::StatusCode,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use tower_sessions::Session;
+use uuid::Uuid;
+
+/// Header HTMX sends the token in, set by the script in the base layout.
+pub const CSRF_HEADER: &str = "x-csrf-token";
+const CSRF_SESSION_KEY: &str = "csrf_token";
+
+tokio::task_local! {
+    static CSRF_TOKEN: String;
+}
+
+/// CSRF token of the session of the request being handled, empty outside of
+/// [`csrf_protection`].
+pub fn csrf_token() -> String {
+    CSRF_TOKEN.try_with(Clone::clone).unwrap_or_default()
+}
+
+/// Rejects state-changing requests that do not carry the session's CSRF token.
+///
+/// The token is generated once per session and kept in it. Pages get it rendered into them
+/// rather than through a cookie, so another site can neither read it nor plant its own.
+pub async fn csrf_protection(session: Session, request: Request, next: Next) -> Response {
+    let token = match session.get::<String>(CSRF_SESSION_KEY).await {
+        Ok(Some(token)) => token,
+        Ok(None) => {
+            let token = Uuid::new_v4().simple().to_string();
+            if session.insert(CSRF_SESSION_KEY, &token).await.is_err() {
+                return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+            }
+            token
+        }
+        Err(_) => return StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+    };
+
+    if !request.method().is_safe() {
+        let provided = request
+            .headers()
+            .get(CSRF_HEADER)
+            .and_then(|value| value.to_str().ok());
+        if !provided.is_some_and(|provided| tokens_match(provided, &token)) {
+            return (
+                StatusCode::FORBIDDEN,
+                "The request could not be verified. Please reload the page and try again.",
+            )
+                .into_response();
+        }
+    }
+
+    // Pages are rendered by the handler, within the scope of the token.
+    CSRF_TOKEN.scope(token, next.run(request)).await
+}
+
+/// Compares the tokens in constant time, so that the token cannot be guessed from timing.
+fn tokens_match(provided: &str, expected: &str) -> bool {
+    provided.len() == expected.len()
+        && provided
+            .bytes()
+            .zip(expected.bytes())
+            .fold(0, |difference, (a, b)| difference | (a ^ b))
+            == 0
+}
@@ -2,17 +2,61 @@ use askama_axum::{IntoResponse, Response};
 use axum::http::StatusCode;
 use serde::Serialize;
 use thiserror::Error;
+use uuid::Uuid;
+use validator::ValidationErrors;
 
 pub struct AppError(pub anyhow::Error);
 
-// Tell axum how to convert `AppError` into a response.
+impl AppError {
+    /// The user-facing error, found by looking through the chain of causes.
+    pub fn api_error(&self) -> ApiError {
+        for cause in self.0.chain() {
+            if let Some(error) = cause.downcast_ref::<ApiError>() {
+                return *error;
+            }
+            if let Some(error) = cause.downcast_ref::<RepositoryError>() {
+                return error.into();
+            }
+            if let Some(StorageError::NotFound) = cause.downcast_ref::<StorageError>() {
+                return ApiError::NotFound;
+            }
+            if cause.is::<ValidationErrors>() || cause.is::<UploadError>() {
+                return ApiError::BadRequest;
+            }
+        }
+        ApiError::InternalServerError
+    }
+}
+
+/// Attached to error responses, so that the layer rendering error pages knows what happened.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ErrorDetails {
+    pub error: ApiError,
+    pub correlation_id: Uuid,
+}
+
+// Tell axum how to convert `AppError` into a response. The details are only logged, the user
+// gets the correlation id to refer to them.
 impl IntoResponse for AppError {
     fn into_response(self) -> Response {
-        (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            format!("Something went wrong: {}", self.0),
-        )
-            .into_response()
+        let error = self.api_error();
+        let correlation_id = Uuid::new_v4();
+        if error == ApiError::InternalServerError {
+            tracing::error!(%correlation_id, "request failed: {:#}", self.0);
+        } else {
+            tracing::warn!(%correlation_id, "request rejected: {:#}", self.0);
+        }
+
+        let error_body = serde_json::json!({
+            "error": error.to_string(),
+            "correlation_id": correlation_id,
+        });
+        let mut response = (error.status_code(), axum::Json(error_body)).into_response();
+        response.extensions_mut().insert(ErrorDetails {
+            error,
+            correlation_id,
+        });
+        response
     }
 }
 
@@ -35,47 +79,81 @@ pub enum RepositoryError {
     ConnectionError(String),
     #[error("no record found")]
     NotFound,
+    #[error("conflicting record: {0}")]
+    Conflict(String),
 }
 
 /// User-facing error type
-#[derive(Error, Debug, Serialize)]
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum ApiError {
     #[error("internal server error")]
     InternalServerError,
     #[error("not found")]
     NotFound,
-    #[allow(dead_code)]
     #[error("bad request")]
     BadRequest,
+    #[error("unauthorized")]
+    Unauthorized,
+    #[error("conflict")]
+    Conflict,
 }
 
-impl From<RepositoryError> for ApiError {
-    fn from(error: RepositoryError) -> Self {
+impl ApiError {
+    pub fn status_code(&self) -> StatusCode {
+        match self {
+            ApiError::InternalServerError => StatusCode::INTERNAL_SERVER_ERROR,
+            ApiError::NotFound => StatusCode::NOT_FOUND,
+            ApiError::BadRequest => StatusCode::BAD_REQUEST,
+            ApiError::Unauthorized => StatusCode::UNAUTHORIZED,
+            ApiError::Conflict => StatusCode::CONFLICT,
+        }
+    }
+
+    /// Message shown to the user on error pages and toasts.
+    pub fn message(&self) -> &'static str {
+        match self {
+            ApiError::InternalServerError => "Something went wrong on our side.",
+            ApiError::NotFound => "The requested record was not found.",
+            ApiError::BadRequest => "The request is not valid.",
+            ApiError::Unauthorized => "You are not authorized to do this.",
+            ApiError::Conflict => "The record conflicts with an existing one.",
+        }
+    }
+}
+
+impl From<&RepositoryError> for ApiError {
+    fn from(error: &RepositoryError) -> Self {
         match error {
             RepositoryError::ConnectionError(_) | RepositoryError::GenericError(_) => {
                 Self::InternalServerError
             }
             RepositoryError::NotFound => Self::NotFound,
+            RepositoryError::Conflict(_) => Self::Conflict,
         }
     }
 }
 
+impl From<RepositoryError> for ApiError {
+    fn from(error: RepositoryError) -> Self {
+        Self::from(&error)
+    }
+}
+
 impl IntoResponse for ApiError {
     fn into_response(self) -> Response {
-        let status_code = match self {
-            ApiError::InternalServerError => StatusCode::INTERNAL_SERVER_ERROR,
-            ApiError::NotFound => StatusCode::NOT_FOUND,
-            ApiError::BadRequest => StatusCode::BAD_REQUEST,
-        };
-
-        let error_body = serde_json::json!({ "error": self.to_string() });
-        (status_code, axum::Json(error_body)).into_response()
+        AppError::from(self).into_response()
     }
 }
 
 impl From<sqlx::Error> for RepositoryError {
     fn from(err: sqlx::Error) -> Self {
-        RepositoryError::ConnectionError(err.to_string())
+        match err {
+            sqlx::Error::RowNotFound => RepositoryError::NotFound,
+            sqlx::Error::Database(err) if err.is_unique_violation() => {
+                RepositoryError::Conflict(err.to_string())
+            }
+            err => RepositoryError::ConnectionError(err.to_string()),
+        }
     }
 }
 
@@ -84,3 +162,33 @@ impl From<anyhow::Error> for RepositoryError {
         RepositoryError::GenericError(err.to_string())
     }
 }
+
+#[derive(Error, Debug)]
+pub enum StorageError {
+    #[error("blob not found")]
+    NotFound,
+    #[error("blob store error: {0}")]
+    BackendError(String),
+}
+
+impl From<std::io::Error> for StorageError {
+    fn from(err: std::io::Error) -> Self {
+        match err.kind() {
+            std::io::ErrorKind::NotFound => StorageError::NotFound,
+            _ => StorageError::BackendError(err.to_string()),
+        }
+    }
+}
+
+/// Reasons an uploaded image is rejected, the messages are shown to the uploader.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum UploadError {
+    #[error("No file was uploaded.")]
+    MissingFile,
+    #[error("Only PNG, JPEG and WebP images can be uploaded.")]
+    UnsupportedType,
+    #[error("The image is too large. Maximum is {0} MB.")]
+    TooLarge(usize),
+    #[error("The file is not a valid image.")]
+    InvalidImage,
+}
@@ -8,6 +8,10 @@ pub mod event_manager_relation;
 pub mod events;
 pub mod index;
 pub mod jobs;
+pub mod notifications;
+pub mod organizations;
 pub mod page_not_found;
+pub mod reviews;
 pub mod settings;
+pub mod uploads;
 pub mod venues;
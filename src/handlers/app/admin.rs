@@ -1,4 +1,7 @@
+pub mod audit;
 pub mod events;
 pub mod users;
 pub mod jobs;
+pub mod reviews;
+pub mod trash;
 pub mod user;
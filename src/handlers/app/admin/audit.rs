@@ -0,0 +1,171 @@
+use crate::handlers::app::auth::AuthSession;
+use askama::Template;
+use axum::{extract::State, response::Html, Form};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use crate::{
+    app::AppState,
+    error::{ApiError, AppError},
+    models::{
+        audit_log::{
+            all_audit_actions, all_audit_entities, AuditAction, AuditEntity, AuditLog,
+            SelectManyFilter,
+        },
+        pagination::{ListParams, Page},
+    },
+    templates::{ActiveRoute, AdminAuditTableTemplate, AdminAuditTemplate},
+    utils::table_utils::{optional_filter, parse_filter, parse_optional_date},
+    view_models::{audit_log::AuditLogViewModel, pagination::PaginationViewModel},
+};
+
+pub(crate) async fn generate_audit_log_viewmodels(
+    logs: Vec<AuditLog>,
+    app_state: &AppState,
+) -> Result<Vec<AuditLogViewModel>, AppError> {
+    let mut actor_names: HashMap<i32, String> = HashMap::new();
+    let mut viewmodels = Vec::new();
+
+    for log in logs {
+        let actor_name = match log.actor_id {
+            Some(actor_id) => match actor_names.get(&actor_id) {
+                Some(actor_name) => actor_name.clone(),
+                None => {
+                    let actor_name = match app_state.user_repository.get_user_by_id(actor_id).await
+                    {
+                        Ok(actor) => actor.first_name + " " + actor.last_name.as_str(),
+                        Err(_) => format!("Deleted user #{}", actor_id),
+                    };
+                    actor_names.insert(actor_id, actor_name.clone());
+                    actor_name
+                }
+            },
+            None => "System".to_string(),
+        };
+        viewmodels.push(AuditLogViewModel::new(log, actor_name));
+    }
+
+    Ok(viewmodels)
+}
+
+pub mod get {
+    use super::*;
+
+    pub async fn audit(
+        auth_session: AuthSession,
+        State(app_state): State<AppState>,
+    ) -> Result<Html<String>, AppError> {
+        let page = app_state
+            .audit_log_repository
+            .list_audit_logs_page(
+                SelectManyFilter {
+                    actor_id: None,
+                    entity: None,
+                    entity_id: None,
+                    event_id: None,
+                    action: None,
+                    date_from: None,
+                    date_to: None,
+                },
+                &ListParams::default().spec(),
+            )
+            .await?;
+        let pagination = PaginationViewModel::new(&page, None);
+
+        let template = AdminAuditTemplate {
+            session: auth_session,
+            active_route: Some(ActiveRoute::AdminPanel),
+            audit_logs: generate_audit_log_viewmodels(page.items, &app_state).await?,
+            entities: all_audit_entities(),
+            actions: all_audit_actions(),
+            pagination,
+        };
+
+        Ok(Html(template.render().unwrap()))
+    }
+}
+
+pub mod post {
+    use super::*;
+
+    #[derive(Deserialize)]
+    pub struct FilterData {
+        entity: String,
+        action: String,
+        actor: String,
+        date_from: String,
+        date_to: String,
+        page: Option<i64>,
+    }
+
+    pub async fn audit(
+        State(app_state): State<AppState>,
+        _auth_session: AuthSession,
+        Form(payload): Form<FilterData>,
+    ) -> Result<Html<String>, AppError> {
+        let entity = parse_filter(
+            payload.entity.as_str(),
+            |entity| AuditEntity::from_str(entity).map_err(|_| ApiError::NotFound),
+            "All entities",
+        )?;
+        let action = parse_filter(
+            payload.action.as_str(),
+            |action| AuditAction::from_str(action).map_err(|_| ApiError::NotFound),
+            "All actions",
+        )?;
+        let date_from = parse_optional_date(&payload.date_from)?;
+        let date_to = parse_optional_date(&payload.date_to)?;
+
+        let spec = ListParams {
+            page: payload.page,
+            ..Default::default()
+        }
+        .spec();
+
+        let actor_id = match optional_filter(payload.actor) {
+            Some(username) => match app_state
+                .user_repository
+                .get_user_by_username(username)
+                .await
+            {
+                Ok(actor) => Some(actor.id),
+                Err(_) => {
+                    let template = AdminAuditTableTemplate {
+                        audit_logs: Vec::new(),
+                        pagination: PaginationViewModel::new(
+                            &Page::<AuditLog>::new(Vec::new(), 0, &spec, |log| log.id),
+                            None,
+                        ),
+                    };
+                    return Ok(Html(template.render().unwrap()));
+                }
+            },
+            None => None,
+        };
+
+        let page = app_state
+            .audit_log_repository
+            .list_audit_logs_page(
+                SelectManyFilter {
+                    actor_id,
+                    entity,
+                    entity_id: None,
+                    event_id: None,
+                    action,
+                    date_from,
+                    date_to,
+                },
+                &spec,
+            )
+            .await?;
+        let pagination = PaginationViewModel::new(&page, None);
+
+        let template = AdminAuditTableTemplate {
+            audit_logs: generate_audit_log_viewmodels(page.items, &app_state).await?,
+            pagination,
+        };
+
+        Ok(Html(template.render().unwrap()))
+    }
+}
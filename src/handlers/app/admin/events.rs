@@ -3,60 +3,72 @@ use askama::Template;
 use axum::{extract::State, response::Html};
 
 use crate::error::AppError;
+use crate::models::organization::ActiveOrganization;
 
 pub mod get {
     use super::*;
-    use crate::models::event::SelectManyFilter;
-    use crate::repositories::event::EventRepository;
-    use crate::repositories::user::UserRepository;
-    use crate::repositories::venue::VenueRepository;
+    use crate::error::ApiError;
+    use crate::models::event::{all_event_statuses, EventStatus, SelectManyFilter, SortColumn};
+    use crate::models::pagination::ListParams;
     use crate::templates::ManageEventsTemplate;
+    use crate::utils::table_utils::parse_filter;
+    use crate::view_models::pagination::PaginationViewModel;
     use crate::{app::AppState, view_models::event::EventViewModel};
+    use axum::extract::Query;
+    use serde::Deserialize;
+    use std::str::FromStr;
+
+    #[derive(Deserialize)]
+    pub struct Params {
+        status: Option<String>,
+    }
 
     pub async fn events(
+        params: Query<Params>,
+        Query(list_params): Query<ListParams<SortColumn>>,
         auth_session: AuthSession,
         State(app_state): State<AppState>,
+        active_organization: ActiveOrganization,
     ) -> Result<Html<String>, AppError> {
-        let mut events: Vec<EventViewModel> = Vec::new();
+        let selected_status = parse_filter(
+            params.status.as_deref().unwrap_or_default(),
+            |status| EventStatus::from_str(status).map_err(|_| ApiError::NotFound),
+            "",
+        )?;
 
-        let empty_filter = SelectManyFilter {
+        let filter = SelectManyFilter {
+            organization_id: Some(active_organization.id),
             date_from: None,
             date_to: None,
-            is_draft: None,
+            status: selected_status,
             venue_id: None,
             owner_id: None,
             city: None,
             state: None,
             name: None,
+            managed_by: None,
         };
 
-        let all_events = app_state.event_repository.list_events(empty_filter).await?;
-
-        for event in all_events {
-            let venue = app_state
-                .venue_repository
-                .get_venue_by_id(event.venue_id)
-                .await?;
-            let owner = app_state
-                .user_repository
-                .get_user_by_id(event.owner_id)
-                .await?;
-            events.push(EventViewModel {
-                id: event.id,
-                name: event.name,
-                date_start: event.date_start,
-                date_end: event.date_end,
-                img_url: event.img_url,
-                is_draft: event.is_draft,
-                venue,
-                owner,
-            });
+        let page = app_state
+            .read_model_repository
+            .list_event_listings_page(filter, &list_params.spec())
+            .await?;
+        let mut link_path = format!("/admin/events?{}", list_params.sort_query());
+        if let Some(status) = &params.status {
+            link_path.push_str(&format!("&status={status}"));
         }
+        let pagination = PaginationViewModel::new(&page, Some(link_path));
+
+        let events: Vec<EventViewModel> =
+            page.items.into_iter().map(EventViewModel::from).collect();
 
         let template = ManageEventsTemplate {
             session: auth_session,
             active_route: Some(crate::templates::ActiveRoute::Manage),
             events,
+            event_statuses: all_event_statuses(),
+            selected_status,
+            pagination,
         };
 
         let html = template.render()?;
@@ -3,77 +3,55 @@ use askama::Template;
 use axum::{extract::State, response::Html};
 
 use crate::error::AppError;
+use crate::models::organization::ActiveOrganization;
 
 pub mod get {
     use crate::app::AppState;
-    use crate::models::{employment, job_position};
-    use crate::models::employment::EmploymentState;
-    use crate::repositories::employment::EmploymentRepository;
-    use crate::repositories::event::EventRepository;
-    use crate::repositories::job_position::JobPositionRepository;
-    use crate::repositories::position_category::PositionCategoryRepository;
+    use crate::models::job_position::SortColumn;
+    use crate::models::pagination::ListParams;
+    use crate::models::job_position;
     use crate::templates::ManageJobsTemplate;
     use crate::view_models::jobs::ManageJobPositionsViewModel;
+    use crate::view_models::pagination::PaginationViewModel;
+    use axum::extract::Query;
     use super::*;
 
     pub async fn jobs(
+        Query(params): Query<ListParams<SortColumn>>,
         auth_session: AuthSession,
         State(app_state): State<AppState>,
+        active_organization: ActiveOrganization,
     ) -> Result<Html<String>, AppError> {
-        let mut jobs: Vec<ManageJobPositionsViewModel> = Vec::new();
-
-        let all_jobs = app_state
-            .job_position_repository
-            .list_job_positions(job_position::SelectManyFilter {
-                event_id: None,
-                position_category_id: None,
-                salary: None,
-                currency: None,
-                capacity: None,
-                is_opened_for_registration: None,
-            })
+        let page = app_state
+            .read_model_repository
+            .list_job_position_listings_page(
+                job_position::SelectManyFilter {
+                    organization_id: Some(active_organization.id),
+                    event_id: None,
+                    position_category_id: None,
+                    salary: None,
+                    currency: None,
+                    capacity: None,
+                    is_opened_for_registration: None,
+                    managed_by: None,
+                },
+                &params.spec(),
+            )
             .await?;
-        for job in all_jobs {
-            let employments = app_state
-                .employment_repository
-                .list_employment(employment::SelectManyFilter {
-                    position_id: Some(job.id),
-                    user_id: None,
-                    state: None,
-                    rating: None,
-                })
-                .await?;
-            let current_capacity = employments
-                .iter()
-                .filter(|e| {
-                    e.state == EmploymentState::Accepted || e.state == EmploymentState::Done
-                })
-                .count();
-            let category = app_state
-                .position_category_repository
-                .get_position_category_by_id(job.position_category_id)
-                .await?;
-            let event = app_state
-                .event_repository
-                .get_event_by_id(job.event_id)
-                .await?;
-            jobs.push(ManageJobPositionsViewModel {
-                id: job.id,
-                name: job.name,
-                salary: job.salary,
-                current_capacity,
-                max_capacity: job.capacity,
-                is_opened_for_registration: job.is_opened_for_registration,
-                currency: job.currency,
-                event: event.clone(),
-                category,
-            });
-        }
+        let pagination =
+            PaginationViewModel::new(&page, Some(format!("/admin/jobs?{}", params.sort_query())));
+
+        let jobs: Vec<ManageJobPositionsViewModel> = page
+            .items
+            .into_iter()
+            .map(ManageJobPositionsViewModel::from)
+            .collect();
 
         let template = ManageJobsTemplate {
             session: auth_session,
             active_route: Some(crate::templates::ActiveRoute::Manage),
             jobs,
+            pagination,
         };
         let html = template.render().unwrap();
         Ok(Html(html))
@@ -0,0 +1,79 @@
+use crate::handlers::app::auth::AuthSession;
+use askama::Template;
+use axum::{
+    extract::{Path, Query, State},
+    response::{Html, Response},
+    Form,
+};
+use serde::Deserialize;
+
+use crate::{
+    app::AppState,
+    error::{ApiError, AppError, RepositoryError},
+    models::{pagination::ListParams, review::SortColumn},
+    templates::{ActiveRoute, AdminReviewsTemplate},
+    view_models::{pagination::PaginationViewModel, review::FlaggedReviewViewModel},
+};
+
+pub mod get {
+    use super::*;
+
+    /// Reviews flagged by users, oldest flag first.
+    pub async fn reviews(
+        auth_session: AuthSession,
+        State(app_state): State<AppState>,
+        Query(params): Query<ListParams<SortColumn>>,
+    ) -> Result<Html<String>, AppError> {
+        let page = app_state
+            .review_repository
+            .list_flagged_reviews_page(&params.spec())
+            .await?;
+        let pagination = PaginationViewModel::new(&page, Some("/admin/reviews".to_string()));
+        let reviews = page
+            .items
+            .into_iter()
+            .map(FlaggedReviewViewModel::from)
+            .collect();
+
+        let template = AdminReviewsTemplate {
+            session: auth_session,
+            active_route: Some(ActiveRoute::AdminPanel),
+            reviews,
+            pagination,
+        };
+
+        Ok(Html(template.render().unwrap()))
+    }
+}
+
+pub mod patch {
+    use super::*;
+
+    #[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+    #[serde(rename_all = "lowercase")]
+    pub enum ModerationDecision {
+        Hide,
+        Keep,
+    }
+
+    #[derive(Deserialize)]
+    pub struct ModerationForm {
+        decision: ModerationDecision,
+    }
+
+    pub async fn reviews(
+        Path(review_id): Path<i32>,
+        State(app_state): State<AppState>,
+        Form(payload): Form<ModerationForm>,
+    ) -> Result<Response, AppError> {
+        match app_state
+            .review_repository
+            .moderate_review(review_id, payload.decision == ModerationDecision::Hide)
+            .await
+        {
+            Ok(_) => Ok(Response::new("".into())),
+            Err(RepositoryError::NotFound) => Err(AppError::from(ApiError::NotFound)),
+            Err(err) => Err(AppError::from(err)),
+        }
+    }
+}
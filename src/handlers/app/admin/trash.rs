@@ -0,0 +1,209 @@
+use crate::handlers::app::auth::AuthSession;
+use askama::Template;
+use axum::{
+    extract::{Path, Query, State},
+    response::{Html, Response},
+};
+use serde::Deserialize;
+
+use crate::{
+    app::AppState,
+    error::{ApiError, AppError, RepositoryError},
+    models::{
+        organization::ActiveOrganization,
+        pagination::{ListParams, Page},
+    },
+    templates::{ActiveRoute, AdminTrashTemplate, ToastType},
+    utils::response_utils::generate_toast_response,
+    view_models::{pagination::PaginationViewModel, trash::TrashItemViewModel},
+};
+
+fn error_response(err: RepositoryError) -> Result<Response, AppError> {
+    match err {
+        RepositoryError::GenericError(message) => {
+            Ok(generate_toast_response(ToastType::Error, message))
+        }
+        RepositoryError::NotFound => Err(AppError::from(ApiError::NotFound)),
+        err => Err(AppError::from(err)),
+    }
+}
+
+pub mod get {
+    use super::*;
+
+    #[derive(Deserialize)]
+    pub struct TrashParams {
+        entity: Option<String>,
+        page: Option<i64>,
+    }
+
+    fn trash_page<T: Into<TrashItemViewModel>>(
+        page: Page<T>,
+        entity_path: &str,
+    ) -> (Vec<TrashItemViewModel>, PaginationViewModel) {
+        let pagination =
+            PaginationViewModel::new(&page, Some(format!("/admin/trash?entity={entity_path}")));
+        (page.items.into_iter().map(Into::into).collect(), pagination)
+    }
+
+    /// Soft-deleted records of one kind, events unless the query asks for another.
+    pub async fn trash(
+        auth_session: AuthSession,
+        State(app_state): State<AppState>,
+        active_organization: ActiveOrganization,
+        Query(params): Query<TrashParams>,
+    ) -> Result<Html<String>, AppError> {
+        let spec = ListParams {
+            page: params.page,
+            ..Default::default()
+        }
+        .spec();
+        let entity_path = params.entity.unwrap_or_else(|| "events".to_string());
+
+        let (items, pagination) = match entity_path.as_str() {
+            "events" => trash_page(
+                app_state
+                    .event_repository
+                    .list_deleted_events(active_organization.id, &spec)
+                    .await?,
+                &entity_path,
+            ),
+            "jobs" => trash_page(
+                app_state
+                    .job_position_repository
+                    .list_deleted_job_positions(active_organization.id, &spec)
+                    .await?,
+                &entity_path,
+            ),
+            "venues" => trash_page(
+                app_state
+                    .venue_repository
+                    .list_deleted_venues(active_organization.id, &spec)
+                    .await?,
+                &entity_path,
+            ),
+            "users" => trash_page(
+                app_state
+                    .user_repository
+                    .list_deleted_users(active_organization.id, &spec)
+                    .await?,
+                &entity_path,
+            ),
+            _ => return Err(AppError::from(ApiError::NotFound)),
+        };
+
+        let template = AdminTrashTemplate {
+            session: auth_session,
+            active_route: Some(ActiveRoute::AdminPanel),
+            entity_path,
+            items,
+            pagination,
+        };
+
+        Ok(Html(template.render().unwrap()))
+    }
+}
+
+pub mod patch {
+    use super::*;
+
+    /// Restores a soft-deleted record.
+    pub async fn trash(
+        Path((entity, id)): Path<(String, i32)>,
+        auth_session: AuthSession,
+        State(app_state): State<AppState>,
+        active_organization: ActiveOrganization,
+    ) -> Result<Response, AppError> {
+        let current_user = match auth_session.user {
+            Some(user) => user,
+            None => return Err(AppError::from(ApiError::InternalServerError)),
+        };
+
+        let result = match entity.as_str() {
+            "events" => app_state
+                .event_repository
+                .with_actor(current_user.id)
+                .restore_event(id, active_organization.id)
+                .await
+                .map(|_| ()),
+            "jobs" => app_state
+                .job_position_repository
+                .with_actor(current_user.id)
+                .restore_job_position(id, active_organization.id)
+                .await
+                .map(|_| ()),
+            "venues" => app_state
+                .venue_repository
+                .with_actor(current_user.id)
+                .restore_venue(id, active_organization.id)
+                .await
+                .map(|_| ()),
+            "users" => app_state
+                .user_repository
+                .with_actor(current_user.id)
+                .restore_user(id, active_organization.id)
+                .await
+                .map(|_| ()),
+            _ => return Err(AppError::from(ApiError::NotFound)),
+        };
+
+        match result {
+            Ok(_) => Ok(Response::new("".into())),
+            Err(err) => error_response(err),
+        }
+    }
+}
+
+pub mod delete {
+    use super::*;
+
+    /// Permanently removes a soft-deleted record.
+    pub async fn trash(
+        Path((entity, id)): Path<(String, i32)>,
+        auth_session: AuthSession,
+        State(app_state): State<AppState>,
+        active_organization: ActiveOrganization,
+    ) -> Result<Response, AppError> {
+        let current_user = match auth_session.user {
+            Some(user) => user,
+            None => return Err(AppError::from(ApiError::InternalServerError)),
+        };
+
+        let result = match entity.as_str() {
+            "events" => {
+                app_state
+                    .event_repository
+                    .with_actor(current_user.id)
+                    .purge_event(id, active_organization.id)
+                    .await
+            }
+            "jobs" => {
+                app_state
+                    .job_position_repository
+                    .with_actor(current_user.id)
+                    .purge_job_position(id, active_organization.id)
+                    .await
+            }
+            "venues" => {
+                app_state
+                    .venue_repository
+                    .with_actor(current_user.id)
+                    .purge_venue(id, active_organization.id)
+                    .await
+            }
+            "users" => {
+                app_state
+                    .user_repository
+                    .with_actor(current_user.id)
+                    .purge_user(id, active_organization.id)
+                    .await
+            }
+            _ => return Err(AppError::from(ApiError::NotFound)),
+        };
+
+        match result {
+            Ok(_) => Ok(Response::new("".into())),
+            Err(err) => error_response(err),
+        }
+    }
+}
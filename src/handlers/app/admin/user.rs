@@ -2,7 +2,7 @@ use crate::handlers::app::auth::AuthSession;
 use askama::Template;
 use axum::{extract::State, response::Html};
 
-use crate::{error::AppError, repositories::user::UserRepository};
+use crate::error::{ApiError, AppError};
 
 pub mod get {
     use super::*;
@@ -71,10 +71,15 @@ pub mod patch {
     }
 
     pub async fn user(
-        _auth_session: AuthSession,
+        auth_session: AuthSession,
         State(app_state): State<AppState>,
         params: Form<Params>,
     ) -> Result<Response, AppError> {
+        let current_user = match auth_session.user {
+            Some(user) => user,
+            None => return Err(AppError::from(ApiError::InternalServerError)),
+        };
+
         match params.validate() {
             Ok(_) => (),
             Err(errors) => return Ok(generate_form_errors_response(errors)),
@@ -103,6 +108,7 @@ pub mod patch {
 
         app_state
             .user_repository
+            .with_actor(current_user.id)
             .update_user(
                 params.user_id,
                 PartialUser {
@@ -131,17 +137,45 @@ pub mod patch {
 pub mod delete {
     use super::*;
     use crate::app::AppState;
+    use crate::error::RepositoryError;
+    use crate::templates::{ToastTemplate, ToastType};
+    use askama_axum::IntoResponse;
     use axum::extract::Path;
-    use axum::http::StatusCode;
+    use axum::response::Response;
 
     pub async fn user(
-        _auth_session: AuthSession,
+        auth_session: AuthSession,
         State(app_state): State<AppState>,
         Path(user_id): Path<i32>,
-    ) -> Result<StatusCode, AppError> {
-        app_state.user_repository.delete_user(user_id).await?;
+    ) -> Result<Response, AppError> {
+        let current_user = match auth_session.user {
+            Some(user) => user,
+            None => return Err(AppError::from(ApiError::InternalServerError)),
+        };
+
+        match app_state
+            .user_repository
+            .with_actor(current_user.id)
+            .delete_user(user_id)
+            .await
+        {
+            Ok(_) => Ok(Response::new("".into())),
+            Err(RepositoryError::GenericError(_)) => {
+                let template = ToastTemplate {
+                    toast_type: ToastType::Error,
+                    message: "User cannot be deleted, because they still own events.".to_string(),
+                };
+                let html = template.render().unwrap();
 
-        Ok(StatusCode::OK)
+                Ok(Response::builder()
+                    .header("HX-Reswap", "innerHTML")
+                    .status(400)
+                    .body(html)
+                    .unwrap()
+                    .into_response())
+            }
+            Err(err) => Err(AppError::from(err)),
+        }
     }
 }
 
@@ -166,6 +200,9 @@ pub mod get_create_template {
 pub mod post {
     use super::*;
     use crate::app::AppState;
+    use crate::models::organization::{
+        ActiveOrganization, CreateOrganizationMembership, OrganizationRole,
+    };
     use crate::models::user::{CreateUser, Gender, UserRole};
     use crate::regex::RE_DATE;
     use crate::regex::RE_PHONE_NUMBER;
@@ -206,9 +243,16 @@ pub mod post {
     }
 
     pub async fn register(
+        auth_session: AuthSession,
         State(app_state): State<AppState>,
+        active_organization: ActiveOrganization,
         params: Form<Params>,
     ) -> Result<Response, StatusCode> {
+        let current_user = match auth_session.user {
+            Some(user) => user,
+            None => return Err(StatusCode::INTERNAL_SERVER_ERROR),
+        };
+
         match params.validate() {
             Ok(_) => (),
             Err(errors) => return Ok(generate_form_errors_response(errors)),
@@ -239,9 +283,35 @@ pub mod post {
             Ok(_) => (),
             Err(errors) => return Ok(generate_form_errors_response(errors)),
         };
-        let created_user_result = app_state.user_repository.create_user(new_user).await;
+        let mut unit_of_work = app_state
+            .database
+            .begin()
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        let created_user_result = app_state
+            .user_repository
+            .with_actor(current_user.id)
+            .create_user_in(&mut unit_of_work, new_user)
+            .await;
         match created_user_result {
-            Ok(_) => {
+            Ok(created_user) => {
+                // New users join the organization the admin is currently working in.
+                app_state
+                    .organization_repository
+                    .create_membership_in(
+                        &mut unit_of_work,
+                        CreateOrganizationMembership {
+                            organization_id: active_organization.id,
+                            user_id: created_user.id,
+                            role: OrganizationRole::from_user_role(&created_user.role),
+                        },
+                    )
+                    .await
+                    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+                unit_of_work
+                    .commit()
+                    .await
+                    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
                 let headers = AppendHeaders([("HX-Redirect", "/admin/users")]);
                 Ok((headers, "").into_response())
             }
@@ -7,24 +7,33 @@ use std::str::FromStr;
 use crate::{
     app::AppState,
     error::{ApiError, AppError},
-    models::user::{SelectManyFilter, UserRole},
-    repositories::user::UserRepository,
+    models::{
+        organization::ActiveOrganization,
+        pagination::ListParams,
+        user::{SelectManyFilter, SortColumn, UserRole},
+    },
     templates::{ActiveRoute, AdminUsersTableTemplate, AdminUsersTemplate},
     utils::{
         date_utils::convert_date_time_to_date,
         table_utils::{optional_filter, parse_filter},
     },
-    view_models::user::UserViewModel,
+    view_models::{pagination::PaginationViewModel, user::UserViewModel},
 };
 
 async fn create_user_viewmodels(
     users_filter: SelectManyFilter,
+    params: ListParams<SortColumn>,
     app_state: AppState,
-) -> Result<Vec<UserViewModel>, AppError> {
+) -> Result<(Vec<UserViewModel>, PaginationViewModel), AppError> {
     let current_date = convert_date_time_to_date(chrono::Local::now());
-    let all_users = app_state.user_repository.list_users(users_filter).await?;
-
-    Ok(all_users
+    let page = app_state
+        .user_repository
+        .list_users_page(users_filter, &params.spec())
+        .await?;
+    let pagination = PaginationViewModel::new(&page, None);
+
+    let users = page
+        .items
         .into_iter()
         .map(|user| {
             let age = ((current_date - user.birth_date).as_seconds_f32()
@@ -45,7 +54,9 @@ async fn create_user_viewmodels(
                 avatar_url: user.avatar_url,
             }
         })
-        .collect())
+        .collect();
+
+    Ok((users, pagination))
 }
 
 pub mod get {
@@ -54,22 +65,28 @@ pub mod get {
     pub async fn users(
         auth_session: AuthSession,
         State(app_state): State<AppState>,
+        active_organization: ActiveOrganization,
     ) -> Result<Html<String>, AppError> {
         let users_filter = SelectManyFilter {
+            organization_id: Some(active_organization.id),
             first_name: None,
             last_name: None,
             username: None,
             gender: None,
             role: None,
             tax_rate: None,
+            last_name_contains: None,
+            username_contains: None,
         };
 
-        let users = create_user_viewmodels(users_filter, app_state).await?;
+        let (users, pagination) =
+            create_user_viewmodels(users_filter, ListParams::default(), app_state).await?;
 
         let template = AdminUsersTemplate {
             session: auth_session,
             active_route: Some(ActiveRoute::AdminPanel),
             users,
+            pagination,
         };
 
         Ok(Html(template.render().unwrap()))
@@ -84,40 +101,13 @@ pub mod post {
         role: String,
         last_name: String,
         username: String,
-    }
-
-    fn apply_filters(
-        users: Vec<UserViewModel>,
-        role: Option<UserRole>,
-        last_name: Option<String>,
-        username: Option<String>,
-    ) -> Vec<UserViewModel> {
-        users
-            .into_iter()
-            .filter(|user| match &role {
-                Some(role) => user.role.eq(role),
-                None => true,
-            })
-            .filter(|user| match &last_name {
-                Some(user_last) => user
-                    .last_name
-                    .to_lowercase()
-                    .contains(&user_last.to_lowercase()),
-                None => true,
-            })
-            .filter(|user| match &username {
-                Some(username) => user
-                    .username
-                    .to_lowercase()
-                    .contains(&username.to_lowercase()),
-                None => true,
-            })
-            .collect()
+        page: Option<i64>,
     }
 
     pub async fn users(
         State(app_state): State<AppState>,
         _auth_session: AuthSession,
+        active_organization: ActiveOrganization,
         Form(payload): Form<FilterData>,
     ) -> Result<Html<String>, AppError> {
         let role = parse_filter(
@@ -126,25 +116,25 @@ pub mod post {
             "All roles",
         )?;
 
-        let last_name = optional_filter(payload.last_name);
-        let username = optional_filter(payload.username);
-
         let users_filter = SelectManyFilter {
+            organization_id: Some(active_organization.id),
             first_name: None,
             last_name: None,
             username: None,
             gender: None,
-            role: None,
+            role,
             tax_rate: None,
+            last_name_contains: optional_filter(payload.last_name),
+            username_contains: optional_filter(payload.username),
+        };
+        let params = ListParams {
+            page: payload.page,
+            ..Default::default()
         };
 
-        let users = create_user_viewmodels(users_filter, app_state).await?;
-
-        let filtered_users = apply_filters(users, role, last_name, username);
+        let (users, pagination) = create_user_viewmodels(users_filter, params, app_state).await?;
 
-        let template = AdminUsersTableTemplate {
-            users: filtered_users,
-        };
+        let template = AdminUsersTableTemplate { users, pagination };
 
         Ok(Html(template.render().unwrap()))
     }
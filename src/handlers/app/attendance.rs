@@ -3,13 +3,13 @@ use askama::Template;
 use axum::{extract::State, response::Html};
 
 use crate::error::AppError;
+use crate::models::organization::ActiveOrganization;
 
 pub mod get {
 
     use crate::{
         app::AppState,
         error::ApiError,
-        repositories::event::EventRepository,
         templates::{ActiveRoute, AttendanceTemplate},
     };
 
@@ -18,6 +18,7 @@ pub mod get {
     pub async fn attendance(
         auth_session: AuthSession,
         State(app_state): State<AppState>,
+        active_organization: ActiveOrganization,
     ) -> Result<Html<String>, AppError> {
         let current_user = match auth_session.clone().user {
             Some(user) => user,
@@ -25,7 +26,7 @@ pub mod get {
         };
         let events = app_state
             .event_repository
-            .list_events_worked_by_user(current_user.id)
+            .list_events_worked_by_user(current_user.id, active_organization.id)
             .await?;
 
         let template = AttendanceTemplate {
@@ -60,8 +60,10 @@ pub mod post {
 
     use crate::{
         app::AppState,
-        models::user::{CreateUser, UserRole},
-        repositories::user::UserRepository,
+        models::{
+            organization::{CreateOrganizationMembership, OrganizationRole},
+            user::{CreateUser, UserRole},
+        },
         templates::{RegisterSuccessTemplate, ToastType},
         utils::{
             date_utils::parse_date,
@@ -136,6 +138,7 @@ pub mod post {
         #[validate(length(min = 8, message = "Password must be at least 8 characters long."))]
         password: String,
         password_confirm: String,
+        invite_code: String,
     }
 
     pub async fn register(
@@ -173,17 +176,69 @@ pub mod post {
             Ok(_) => (),
             Err(errors) => return Ok(generate_form_errors_response(errors)),
         };
-        let created_user_result = app_state.user_repository.create_user(new_user).await;
-        match created_user_result {
-            Ok(_) => (),
+        let invite_code = params.invite_code.trim();
+        let organization = if invite_code.is_empty() {
+            None
+        } else {
+            match app_state
+                .organization_repository
+                .get_organization_by_invite_code(invite_code)
+                .await
+            {
+                Ok(organization) => Some(organization),
+                Err(_) => {
+                    return Ok(generate_toast_response(
+                        ToastType::Error,
+                        "The invitation code is not valid.".to_string(),
+                    ))
+                }
+            }
+        };
+        // The user is only kept together with the membership, so that a failed
+        // registration can be retried with the same username.
+        let mut unit_of_work = app_state
+            .database
+            .begin()
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        let created_user_result = app_state
+            .user_repository
+            .create_user_in(&mut unit_of_work, new_user)
+            .await;
+        let created_user = match created_user_result {
+            Ok(user) => user,
             Err(_) => {
                 return Ok(generate_toast_response(
                     ToastType::Error,
                     format!("User with username '{}' already exists.", params.username),
                 ));
             }
+        };
+        // Users without an invitation belong to no agency until one of its owners adds them.
+        if let Some(organization) = &organization {
+            if app_state
+                .organization_repository
+                .create_membership_in(
+                    &mut unit_of_work,
+                    CreateOrganizationMembership {
+                        organization_id: organization.id,
+                        user_id: created_user.id,
+                        role: OrganizationRole::Employee,
+                    },
+                )
+                .await
+                .is_err()
+            {
+                return Err(StatusCode::INTERNAL_SERVER_ERROR);
+            }
         }
-        let template = RegisterSuccessTemplate {};
+        unit_of_work
+            .commit()
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        let template = RegisterSuccessTemplate {
+            organization_name: organization.map(|organization| organization.name),
+        };
         let html = template.render().unwrap();
         Ok(Html(html).into_response())
     }
@@ -2,40 +2,55 @@ use crate::handlers::app::auth::AuthSession;
 use askama::Template;
 use axum::{extract::State, response::Html};
 
-use crate::{error::AppError, repositories::user::UserRepository, templates::EmployeesTemplate};
+use crate::{error::AppError, templates::EmployeesTemplate};
 
 pub mod employee;
 
 pub mod get {
     use crate::{
         app::AppState,
-        models::user::{SelectManyFilter, UserRole},
+        models::{
+            organization::ActiveOrganization,
+            pagination::ListParams,
+            user::{SelectManyFilter, SortColumn, UserRole},
+        },
         utils::date_utils::convert_date_time_to_date,
-        view_models::user::UserViewModel,
+        view_models::{pagination::PaginationViewModel, user::UserViewModel},
     };
+    use axum::extract::Query;
 
     use super::*;
 
     pub async fn employees(
+        Query(params): Query<ListParams<SortColumn>>,
         auth_session: AuthSession,
         State(app_state): State<AppState>,
+        active_organization: ActiveOrganization,
     ) -> Result<Html<String>, AppError> {
         let current_date_time = chrono::Local::now();
         let current_date = convert_date_time_to_date(current_date_time);
         let mut users_view_model_vec: Vec<UserViewModel> = Vec::new();
-        let users = app_state
+        let page = app_state
             .user_repository
-            .list_users(SelectManyFilter {
-                first_name: None,
-                last_name: None,
-                username: None,
-                gender: None,
-                role: Some(UserRole::Employee),
-                tax_rate: None,
-            })
+            .list_users_page(
+                SelectManyFilter {
+                    organization_id: Some(active_organization.id),
+                    first_name: None,
+                    last_name: None,
+                    username: None,
+                    gender: None,
+                    role: Some(UserRole::Employee),
+                    tax_rate: None,
+                    last_name_contains: None,
+                    username_contains: None,
+                },
+                &params.spec(),
+            )
             .await?;
+        let pagination =
+            PaginationViewModel::new(&page, Some(format!("/employees?{}", params.sort_query())));
 
-        for user in users {
+        for user in page.items {
             let age =
                 (current_date - user.birth_date).as_seconds_f32() / (60.0 * 60.0 * 24.0 * 365.25);
             let age = age.floor() as i32;
@@ -59,6 +74,7 @@ pub mod get {
             session: auth_session,
             active_route: Some(crate::templates::ActiveRoute::Employees),
             employees: users_view_model_vec,
+            pagination,
         };
         let html = template.render().unwrap();
         Ok(Html(html))
@@ -2,47 +2,47 @@ use crate::handlers::app::auth::AuthSession;
 use askama::Template;
 use axum::{extract::State, response::Html};
 
-use crate::{
-    error::AppError,
-    repositories::user::UserRepository,
-};
+use crate::error::AppError;
 
 pub mod get {
     use super::*;
     use crate::{
         app::AppState,
+        error::ApiError,
         models::{
-            employment::{EmploymentState, SelectManyFilter},
+            employment::{EmploymentState, JobsFilter, JobsSortColumn},
+            organization::ActiveOrganization,
+            pagination::ListParams,
+            review::{ReviewDirection, ReviewSummary},
             user::UserRole,
-            worked_hours,
-        },
-        repositories::{
-            employment::EmploymentRepository, event::EventRepository,
-            job_position::JobPositionRepository, venue::VenueRepository,
-            worked_hours::WorkedHoursRepository,
         },
+        policy::EventAction,
         templates::EmployeeTemplate,
         utils::{
             date_utils::convert_date_time_to_date, response_utils::generate_unauthorized_response,
         },
         view_models::{
             jobs::{PastJobEventViewModel, PastJobsViewModel},
+            pagination::PaginationViewModel,
+            review::ReviewViewModel,
             user::UserViewModel,
         },
     };
     use askama_axum::IntoResponse;
-    use axum::extract::Path;
+    use axum::extract::{Path, Query};
     use axum::response::Response;
-    
-    
-    
-    
 
     pub async fn employee(
         Path(employee_id): Path<i32>,
+        Query(params): Query<ListParams<JobsSortColumn>>,
         auth_session: AuthSession,
         State(app_state): State<AppState>,
+        active_organization: ActiveOrganization,
     ) -> Result<Response, AppError> {
+        let current_user = match auth_session.clone().user {
+            Some(user) => user,
+            None => return Err(AppError::from(ApiError::InternalServerError)),
+        };
         let current_date_time = chrono::Local::now();
         let current_date = convert_date_time_to_date(current_date_time);
         let user = app_state
@@ -53,61 +53,92 @@ pub mod get {
             UserRole::Employee => (),
             _ => return Ok(generate_unauthorized_response()),
         }
+        if app_state
+            .organization_repository
+            .get_membership(active_organization.id, employee_id)
+            .await
+            .is_err()
+        {
+            return Err(AppError::from(ApiError::NotFound));
+        }
         let age = (current_date - user.birth_date).as_seconds_f32() / (60.0 * 60.0 * 24.0 * 365.25);
         let age = age.floor() as i32;
 
         let mut past_jobs: Vec<PastJobsViewModel> = Vec::new();
-        let employments = app_state
-            .employment_repository
-            .list_employment(SelectManyFilter {
-                position_id: None,
-                user_id: Some(employee_id),
-                state: Some(EmploymentState::Done),
-                rating: None,
-            })
+        let page = app_state
+            .read_model_repository
+            .list_job_listings_page(
+                JobsFilter {
+                    organization_id: active_organization.id,
+                    user_id: employee_id,
+                    state: Some(EmploymentState::Done),
+                    starts_from: None,
+                    ends_until: None,
+                    event_name_contains: None,
+                    venue_name_contains: None,
+                    job_name_contains: None,
+                },
+                &params.spec(),
+            )
             .await?;
+        let pagination = PaginationViewModel::new(
+            &page,
+            Some(format!("/employees/{employee_id}?{}", params.sort_query())),
+        );
 
-        for employment in employments {
-            let job = app_state
-                .job_position_repository
-                .get_job_position_by_id(employment.position_id)
-                .await?;
-            let event = app_state
-                .event_repository
-                .get_event_by_id(job.event_id)
-                .await?;
-            let venue = app_state
-                .venue_repository
-                .get_venue_by_id(event.venue_id)
-                .await?;
-            let worked_hours = app_state
-                .worked_hours_repository
-                .list_worked_hours(worked_hours::SelectManyFilter {
-                    hours_worked: None,
-                    date: None,
-                    employment_id: Some(employment.id),
-                })
+        for listing in page.items {
+            // Worked hours are payroll data, only shown to those allowed to see it.
+            let can_view_payroll = app_state
+                .event_policy
+                .is_authorized(&current_user, &listing.event, EventAction::ViewPayroll)
                 .await?;
-            let mut total_worked_hours = 0.0;
-
-            for hours in worked_hours {
-                total_worked_hours += hours.hours_worked;
-            }
 
             past_jobs.push(PastJobsViewModel {
-                job_name: job.name,
+                job_name: listing.position.name,
                 event: PastJobEventViewModel {
-                    id: event.id,
-                    name: event.name,
+                    id: listing.event.id,
+                    name: listing.event.name,
                 },
-                date_start: event.date_start,
-                date_end: event.date_end,
-                venue,
-                hours_worked: total_worked_hours as i32,
-                rating: employment.rating,
+                date_start: listing.event.date_start,
+                date_end: listing.event.date_end,
+                venue: listing.venue,
+                hours_worked: can_view_payroll.then_some(listing.hours_worked as i32),
+                rating: listing.employment.rating,
             });
         }
 
+        let withdrawals = app_state
+            .employment_repository
+            .withdrawal_stats(employee_id, Some(active_organization.id))
+            .await?;
+        let attendance = app_state
+            .attendance_repository
+            .attendance_summary(employee_id, Some(active_organization.id))
+            .await?;
+        let reliability_score = attendance.reliability_score(&withdrawals);
+        let recent_no_shows = app_state
+            .attendance_repository
+            .count_no_shows_since(
+                employee_id,
+                Some(active_organization.id),
+                app_state.no_show_block.period_start(current_date),
+            )
+            .await?;
+        let blocked_no_shows = app_state
+            .no_show_block
+            .is_blocked(recent_no_shows)
+            .then_some(recent_no_shows);
+        let reviews = app_state
+            .review_repository
+            .list_published_reviews(
+                employee_id,
+                ReviewDirection::OrganizerToWorker,
+                Some(active_organization.id),
+                current_date,
+            )
+            .await?;
+        let review_summary = ReviewSummary::from_reviews(&reviews);
+
         let template = EmployeeTemplate {
             session: auth_session,
             active_route: Some(crate::templates::ActiveRoute::Employees),
@@ -125,6 +156,13 @@ pub mod get {
                 avatar_url: user.avatar_url,
             },
             past_jobs,
+            pagination,
+            withdrawals,
+            attendance,
+            reliability_score,
+            blocked_no_shows,
+            review_summary,
+            reviews: reviews.into_iter().map(ReviewViewModel::from).collect(),
         };
         let html = template.render().unwrap();
         Ok(Html(html).into_response())
@@ -7,19 +7,21 @@ pub mod post {
         Form,
     };
     use serde::Deserialize;
+    use sqlx::types::time::OffsetDateTime;
     use validator::Validate;
 
     use crate::{
         app::AppState,
-        error::AppError,
+        error::{ApiError, AppError, RepositoryError},
         handlers::app::auth::AuthSession,
         models::{
-            employment::{self, CreateEmployment, EmploymentState},
+            employment::{self, invitation_deadline, CreateEmployment, EmploymentState},
+            notification::CreateNotification,
             user::{self, UserRole},
         },
-        repositories::{employment::EmploymentRepository, user::UserRepository},
+        policy::{EventAction, EventScope},
         templates::{JobEmployeesTemplate, ToastType},
-        utils::response_utils::generate_toast_response,
+        utils::response_utils::{generate_toast_response, generate_unauthorized_response},
         view_models::jobs::ManageJobEmployeeViewModel,
     };
 
@@ -30,40 +32,99 @@ pub mod post {
     }
 
     pub async fn employment(
-        _auth_session: AuthSession,
+        auth_session: AuthSession,
         State(app_state): State<AppState>,
         params: Form<Params>,
     ) -> Result<Response, AppError> {
+        let current_user = match auth_session.user {
+            Some(user) => user,
+            None => return Err(AppError::from(ApiError::InternalServerError)),
+        };
+
+        if !app_state
+            .event_policy
+            .authorize(
+                &current_user,
+                EventScope::JobPosition(params.job_id),
+                EventAction::ManageApplicants,
+            )
+            .await?
+        {
+            return Ok(generate_unauthorized_response());
+        }
+
+        let job = app_state
+            .job_position_repository
+            .get_job_position_by_id(params.job_id)
+            .await?;
+        let event = app_state
+            .event_repository
+            .get_event_by_id(job.event_id)
+            .await?;
+        if app_state
+            .organization_repository
+            .get_membership(event.organization_id, params.employee_id)
+            .await
+            .is_err()
+        {
+            return Ok(generate_toast_response(
+                ToastType::Error,
+                "The employee is not a member of this organization.".to_string(),
+            ));
+        }
+
         let _new_employment = match app_state
             .employment_repository
+            .with_actor(current_user.id)
             .create_employment(CreateEmployment {
                 rating: 0,
-                state: EmploymentState::Accepted,
+                state: EmploymentState::Invited,
                 user_id: params.employee_id,
                 position_id: params.job_id,
+                invitation_expires_at: Some(invitation_deadline(
+                    OffsetDateTime::now_utc(),
+                    event.date_start,
+                )),
             })
             .await
         {
             Ok(new_employment) => new_employment,
-            Err(_) => {
-                return Ok(generate_toast_response(
-                    ToastType::Error,
-                    "Job capacity is full.".to_string(),
-                ))
+            Err(RepositoryError::GenericError(message)) => {
+                return Ok(generate_toast_response(ToastType::Error, message))
             }
+            Err(err) => return Err(err.into()),
         };
 
+        app_state
+            .notification_repository
+            .create_notification(CreateNotification {
+                user_id: params.employee_id,
+                message: format!(
+                    "You have been invited to work as {} at {}",
+                    job.name, event.name
+                ),
+            })
+            .await?;
+
         let mut employees: Vec<ManageJobEmployeeViewModel> = Vec::new();
         let employments = app_state
             .employment_repository
             .list_employment(employment::SelectManyFilter {
+                organization_id: None,
                 position_id: Some(params.job_id),
                 user_id: None,
-                state: Some(EmploymentState::Accepted),
+                state: None,
                 rating: None,
+                event_name_contains: None,
+                job_name_contains: None,
+                employee_name_contains: None,
+                managed_by: None,
             })
             .await?;
-        for employment in employments {
+        for employment in employments
+            .into_iter()
+            .filter(|employment| employment.state.is_staffing())
+        {
             let employee = app_state
                 .user_repository
                 .get_user_by_id(employment.user_id)
@@ -88,12 +149,15 @@ pub mod post {
         let possible_employees = app_state
             .user_repository
             .list_users(user::SelectManyFilter {
+                organization_id: Some(event.organization_id),
                 first_name: None,
                 last_name: None,
                 username: None,
                 gender: None,
                 role: Some(UserRole::Employee),
                 tax_rate: None,
+                last_name_contains: None,
+                username_contains: None,
             })
             .await?;
         let possible_employees = possible_employees
@@ -127,14 +191,15 @@ pub mod delete {
 
     use crate::{
         app::AppState,
-        error::AppError,
+        error::{ApiError, AppError},
         handlers::app::auth::AuthSession,
         models::{
-            employment::{self, EmploymentState},
+            employment,
             user::{self, UserRole},
         },
-        repositories::{employment::EmploymentRepository, user::UserRepository},
+        policy::{EventAction, EventScope},
         templates::JobEmployeesTemplate,
+        utils::response_utils::generate_unauthorized_response,
         view_models::jobs::ManageJobEmployeeViewModel,
     };
 
@@ -147,26 +212,61 @@ pub mod delete {
     }
 
     pub async fn employment(
-        _auth_session: AuthSession,
+        auth_session: AuthSession,
         State(app_state): State<AppState>,
         params: Query<Params>,
     ) -> Result<Response, AppError> {
+        let current_user = match auth_session.user {
+            Some(user) => user,
+            None => return Err(AppError::from(ApiError::InternalServerError)),
+        };
+
+        for scope in [
+            EventScope::JobPosition(params.job_id),
+            EventScope::Employment(params.employment_id),
+        ] {
+            if !app_state
+                .event_policy
+                .authorize(&current_user, scope, EventAction::ManageApplicants)
+                .await?
+            {
+                return Ok(generate_unauthorized_response());
+            }
+        }
+
         app_state
             .employment_repository
+            .with_actor(current_user.id)
             .delete_employment(params.employment_id)
             .await?;
+        let job = app_state
+            .job_position_repository
+            .get_job_position_by_id(params.job_id)
+            .await?;
+        let event = app_state
+            .event_repository
+            .get_event_by_id(job.event_id)
+            .await?;
 
         let mut employees: Vec<ManageJobEmployeeViewModel> = Vec::new();
         let employments = app_state
             .employment_repository
             .list_employment(employment::SelectManyFilter {
+                organization_id: None,
                 position_id: Some(params.job_id),
                 user_id: None,
-                state: Some(EmploymentState::Accepted),
+                state: None,
                 rating: None,
+                event_name_contains: None,
+                job_name_contains: None,
+                employee_name_contains: None,
+                managed_by: None,
             })
             .await?;
-        for employment in employments {
+        for employment in employments
+            .into_iter()
+            .filter(|employment| employment.state.is_staffing())
+        {
             let employee = app_state
                 .user_repository
                 .get_user_by_id(employment.user_id)
@@ -191,12 +291,15 @@ pub mod delete {
         let possible_employees = app_state
             .user_repository
             .list_users(user::SelectManyFilter {
+                organization_id: Some(event.organization_id),
                 first_name: None,
                 last_name: None,
                 username: None,
                 gender: None,
                 role: Some(UserRole::Employee),
                 tax_rate: None,
+                last_name_contains: None,
+                username_contains: None,
             })
             .await?;
         let possible_employees = possible_employees
@@ -1,26 +1,93 @@
-pub mod post {
-    use askama::Template;
-    use askama_axum::IntoResponse;
-    use axum::{
-        extract::State,
-        response::{Html, Response},
-        Form,
+use askama::Template;
+use askama_axum::IntoResponse;
+use axum::response::{Html, Response};
+
+use crate::{
+    app::AppState,
+    error::AppError,
+    models::user::{self, User, UserRole},
+    templates::EventManagersTemplate,
+    view_models::event::EventManagerViewModel,
+};
+
+/// Lists managers of the event together with the organizers who can still be added.
+pub(crate) async fn generate_event_manager_viewmodels(
+    app_state: &AppState,
+    event_id: i32,
+) -> Result<(Vec<EventManagerViewModel>, Vec<User>), AppError> {
+    let mut managers: Vec<EventManagerViewModel> = Vec::new();
+    let event_manager_relations = app_state
+        .event_manager_relation_repository
+        .list_event_managers(event_id)
+        .await?;
+    for event_manager_relation in event_manager_relations {
+        let manager = app_state
+            .user_repository
+            .get_user_by_id(event_manager_relation.user_id)
+            .await?;
+        managers.push(EventManagerViewModel {
+            id: manager.id,
+            first_name: manager.first_name,
+            last_name: manager.last_name,
+            username: manager.username,
+            avatar_url: manager.avatar_url,
+            permissions: event_manager_relation.permissions(),
+        });
+    }
+
+    let event = app_state.event_repository.get_event_by_id(event_id).await?;
+    let possible_managers = app_state
+        .user_repository
+        .list_users(user::SelectManyFilter {
+            organization_id: Some(event.organization_id),
+            first_name: None,
+            last_name: None,
+            username: None,
+            gender: None,
+            role: Some(UserRole::Organizer),
+            tax_rate: None,
+            last_name_contains: None,
+            username_contains: None,
+        })
+        .await?;
+    let possible_managers = possible_managers
+        .into_iter()
+        .filter(|possible_manager| {
+            !managers
+                .iter()
+                .any(|manager| manager.id == possible_manager.id)
+        })
+        .collect();
+
+    Ok((managers, possible_managers))
+}
+
+async fn render_event_managers(app_state: &AppState, event_id: i32) -> Result<Response, AppError> {
+    let (managers, possible_managers) =
+        generate_event_manager_viewmodels(app_state, event_id).await?;
+
+    let template = EventManagersTemplate {
+        possible_managers,
+        managers,
+        event_id,
     };
+    let html = template.render().unwrap();
+    Ok(Html(html).into_response())
+}
+
+pub mod post {
+    use axum::{extract::State, response::Response, Form};
     use serde::Deserialize;
     use validator::Validate;
 
+    use super::render_event_managers;
     use crate::{
         app::AppState,
-        error::AppError,
+        error::{ApiError, AppError},
         handlers::app::auth::AuthSession,
-        models::{
-            event_manager_relation::CreateEventManagerRelation,
-            user::{self, User, UserRole},
-        },
-        repositories::{
-            event_manager_relation::EventManagerRelationRepository, user::UserRepository,
-        },
-        templates::EventManagersTemplate,
+        models::event_manager_relation::CreateEventManagerRelation,
+        policy::{EventAction, EventScope},
+        utils::response_utils::generate_unauthorized_response,
     };
 
     #[derive(Deserialize, Validate)]
@@ -30,10 +97,41 @@ pub mod post {
     }
 
     pub async fn event_manager_relation(
-        _auth_session: AuthSession,
+        auth_session: AuthSession,
         State(app_state): State<AppState>,
         params: Form<Params>,
     ) -> Result<Response, AppError> {
+        let current_user = match auth_session.user {
+            Some(user) => user,
+            None => return Err(AppError::from(ApiError::InternalServerError)),
+        };
+
+        if !app_state
+            .event_policy
+            .authorize(
+                &current_user,
+                EventScope::Event(params.event_id),
+                EventAction::ManageManagers,
+            )
+            .await?
+        {
+            return Ok(generate_unauthorized_response());
+        }
+
+        // Managers have to come from the organization running the event.
+        let event = app_state
+            .event_repository
+            .get_event_by_id(params.event_id)
+            .await?;
+        if app_state
+            .organization_repository
+            .get_membership(event.organization_id, params.manager_id)
+            .await
+            .is_err()
+        {
+            return Ok(generate_unauthorized_response());
+        }
+
         let _new_event_manager_relation = app_state
             .event_manager_relation_repository
             .create_relation(CreateEventManagerRelation {
@@ -42,70 +140,128 @@ pub mod post {
             })
             .await?;
 
-        let mut managers: Vec<User> = Vec::new();
-        let event_manager_relations = app_state
-            .event_manager_relation_repository
-            .list_event_managers(params.event_id)
+        render_event_managers(&app_state, params.event_id).await
+    }
+}
+
+pub mod patch {
+    use axum::{extract::State, response::Response, Form};
+    use serde::Deserialize;
+
+    use super::render_event_managers;
+    use crate::{
+        app::AppState,
+        error::{ApiError, AppError},
+        handlers::app::auth::AuthSession,
+        models::{event_manager_relation::EventManagerPermissions, user::UserRole},
+        policy::{EventAction, EventRelation},
+        templates::ToastType,
+        utils::response_utils::{generate_toast_response, generate_unauthorized_response},
+    };
+
+    #[derive(Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    pub enum Permission {
+        EditEvent,
+        ManageJobs,
+        ManageApplicants,
+        ApproveHours,
+        ViewPayroll,
+        ManageManagers,
+    }
+
+    #[derive(Deserialize)]
+    pub struct Params {
+        event_id: i32,
+        manager_id: i32,
+        permission: Permission,
+        enabled: bool,
+    }
+
+    fn set_permission(
+        permissions: &mut EventManagerPermissions,
+        permission: &Permission,
+        value: bool,
+    ) {
+        match permission {
+            Permission::EditEvent => permissions.can_edit_event = value,
+            Permission::ManageJobs => permissions.can_manage_jobs = value,
+            Permission::ManageApplicants => permissions.can_manage_applicants = value,
+            Permission::ApproveHours => permissions.can_approve_hours = value,
+            Permission::ViewPayroll => permissions.can_view_payroll = value,
+            Permission::ManageManagers => permissions.can_manage_managers = value,
+        }
+    }
+
+    pub async fn event_manager_relation(
+        auth_session: AuthSession,
+        State(app_state): State<AppState>,
+        Form(params): Form<Params>,
+    ) -> Result<Response, AppError> {
+        let current_user = match auth_session.user {
+            Some(user) => user,
+            None => return Err(AppError::from(ApiError::InternalServerError)),
+        };
+
+        let event = app_state
+            .event_repository
+            .get_event_by_id(params.event_id)
             .await?;
-        for event_manager_relation in event_manager_relations {
-            let manager = app_state
-                .user_repository
-                .get_user_by_id(event_manager_relation.user_id)
-                .await?;
-            managers.push(manager);
+        if !app_state
+            .event_policy
+            .is_authorized(&current_user, &event, EventAction::ManageManagers)
+            .await?
+        {
+            return Ok(generate_unauthorized_response());
         }
-        let possible_managers = app_state
-            .user_repository
-            .list_users(user::SelectManyFilter {
-                first_name: None,
-                last_name: None,
-                username: None,
-                gender: None,
-                role: Some(UserRole::Organizer),
-                tax_rate: None,
-            })
+
+        let mut permissions = app_state
+            .event_manager_relation_repository
+            .get_relation(params.event_id, params.manager_id)
+            .await?
+            .permissions();
+        set_permission(&mut permissions, &params.permission, params.enabled);
+
+        // Managers may only hand out permissions they hold themselves.
+        if let EventRelation::Manager(own_permissions) = app_state
+            .event_policy
+            .relation(&current_user, &event)
+            .await?
+        {
+            let mut granted = EventManagerPermissions::default();
+            set_permission(&mut granted, &params.permission, params.enabled);
+            if current_user.role != UserRole::Admin && !granted.is_subset_of(&own_permissions) {
+                return Ok(generate_toast_response(
+                    ToastType::Error,
+                    "You can't grant permissions you don't have.".to_string(),
+                ));
+            }
+        }
+
+        app_state
+            .event_manager_relation_repository
+            .update_permissions(params.event_id, params.manager_id, permissions)
             .await?;
-        let possible_managers = possible_managers
-            .into_iter()
-            .filter(|possible_manager| {
-                !managers
-                    .iter()
-                    .any(|manager| manager.id == possible_manager.id)
-            })
-            .collect();
 
-        let template = EventManagersTemplate {
-            possible_managers,
-            managers,
-            event_id: params.event_id,
-        };
-        let html = template.render().unwrap();
-        Ok(Html(html).into_response())
+        render_event_managers(&app_state, params.event_id).await
     }
 }
 
 pub mod delete {
-    use askama::Template;
-    use askama_axum::IntoResponse;
     use axum::{
         extract::{Query, State},
-        response::{Html, Response},
+        response::Response,
     };
     use serde::Deserialize;
     use validator::Validate;
 
+    use super::render_event_managers;
     use crate::{
         app::AppState,
-        error::AppError,
+        error::{ApiError, AppError},
         handlers::app::auth::AuthSession,
-        models::{
-            event_manager_relation::EventManagerRelation,
-            user::{self, User, UserRole},
-        },
-        repositories::{
-            event_manager_relation::EventManagerRelationRepository, user::UserRepository,
-        },
-        templates::EventManagersTemplate,
+        policy::{EventAction, EventScope},
+        utils::response_utils::generate_unauthorized_response,
     };
 
     #[derive(Deserialize, Validate)]
@@ -115,48 +271,36 @@ pub mod delete {
     }
 
     pub async fn event_manager_relation(
-        _auth_session: AuthSession,
+        auth_session: AuthSession,
         State(app_state): State<AppState>,
         params: Query<Params>,
     ) -> Result<Response, AppError> {
-        app_state
-            .event_manager_relation_repository
-            .delete_relation(EventManagerRelation {
-                user_id: params.manager_id,
-                event_id: params.event_id,
-            })
-            .await?;
+        let current_user = match auth_session.user {
+            Some(user) => user,
+            None => return Err(AppError::from(ApiError::InternalServerError)),
+        };
 
-        let possible_managers = app_state
-            .user_repository
-            .list_users(user::SelectManyFilter {
-                first_name: None,
-                last_name: None,
-                username: None,
-                gender: None,
-                role: Some(UserRole::Organizer),
-                tax_rate: None,
-            })
+        if !app_state
+            .event_policy
+            .authorize(
+                &current_user,
+                EventScope::Event(params.event_id),
+                EventAction::ManageManagers,
+            )
+            .await?
+        {
+            return Ok(generate_unauthorized_response());
+        }
+
+        let relation = app_state
+            .event_manager_relation_repository
+            .get_relation(params.event_id, params.manager_id)
             .await?;
-        let mut managers: Vec<User> = Vec::new();
-        let event_manager_relations = app_state
+        app_state
             .event_manager_relation_repository
-            .list_event_managers(params.event_id)
+            .delete_relation(relation)
             .await?;
-        for event_manager_relation in event_manager_relations {
-            let manager = app_state
-                .user_repository
-                .get_user_by_id(event_manager_relation.user_id)
-                .await?;
-            managers.push(manager);
-        }
 
-        let template = EventManagersTemplate {
-            possible_managers,
-            managers,
-            event_id: params.event_id,
-        };
-        let html = template.render().unwrap();
-        Ok(Html(html).into_response())
+        render_event_managers(&app_state, params.event_id).await
     }
 }
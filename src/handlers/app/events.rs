@@ -1,8 +1,8 @@
 use crate::handlers::app::auth::AuthSession;
 use askama::Template;
-use axum::{extract::State, response::Html};
+use axum::{extract::State, response::Html, Extension};
 
-use crate::{error::AppError, repositories::user::UserRepository, templates::EventsTemplate};
+use crate::{error::AppError, models::organization::ActiveOrganization, templates::EventsTemplate};
 
 pub mod event;
 pub mod manage;
@@ -10,13 +10,9 @@ pub mod manage;
 pub mod get {
     use crate::{
         app::AppState,
-        models::event::SelectManyFilter,
-        repositories::{
-            event::EventRepository, event_manager_relation::EventManagerRelationRepository,
-            venue::VenueRepository,
-        },
+        models::event::{all_event_statuses, SelectManyFilter},
         utils::date_utils::convert_date_time_to_date,
-        view_models::event::{EventViewModel, IEventViewModel},
+        view_models::event::EventViewModel,
     };
 
     use sqlx::types::{chrono, time::Date};
@@ -26,111 +22,67 @@ pub mod get {
     pub async fn events(
         auth_session: AuthSession,
         State(app_state): State<AppState>,
+        active_organization: Option<Extension<ActiveOrganization>>,
     ) -> Result<Html<String>, AppError> {
+        // Visitors browse every agency's events, members only those of their organization.
+        let organization_id = active_organization.map(|Extension(organization)| organization.id);
         let current_date_time = chrono::Local::now();
-        let states = app_state.venue_repository.list_states().await?;
-        let cities = app_state.venue_repository.list_cities().await?;
-        let mut future_view_events: Vec<EventViewModel> = Vec::new();
+        let states = app_state
+            .venue_repository
+            .list_states(organization_id)
+            .await?;
+        let cities = app_state
+            .venue_repository
+            .list_cities(organization_id)
+            .await?;
+        // Drafts are only listed for users allowed to view them.
+        let viewer = auth_session.user.as_ref();
         let future_events = app_state
-            .event_repository
-            .list_events(SelectManyFilter {
-                date_from: Some(convert_date_time_to_date(current_date_time)),
-                date_to: None,
-                is_draft: None,
-                venue_id: None,
-                owner_id: None,
-                city: None,
-                state: None,
-                name: None,
-            })
+            .read_model_repository
+            .list_event_listings(
+                SelectManyFilter {
+                    organization_id,
+                    date_from: Some(convert_date_time_to_date(current_date_time)),
+                    date_to: None,
+                    status: None,
+                    venue_id: None,
+                    owner_id: None,
+                    city: None,
+                    state: None,
+                    name: None,
+                    managed_by: None,
+                },
+                viewer,
+            )
             .await?;
-        for event in future_events {
-            let venue = app_state
-                .venue_repository
-                .get_venue_by_id(event.venue_id)
-                .await?;
-            let owner = app_state
-                .user_repository
-                .get_user_by_id(event.owner_id)
-                .await?;
-
-            // Check if user is owner or manager to show draft event.
-            if event.is_draft {
-                match auth_session.user {
-                    Some(ref user) => {
-                        let event_manager_relations = app_state
-                            .event_manager_relation_repository
-                            .list_event_managers(event.id)
-                            .await?;
-
-                        if event.owner_id != user.id {
-                            let user_is_manager = event_manager_relations
-                                .into_iter()
-                                .any(|relation| relation.user_id == user.id);
-                            if !user_is_manager {
-                                continue;
-                            }
-                        }
-                    }
-                    None => continue,
-                }
-            }
-
-            future_view_events.push(EventViewModel::new(event, venue, owner));
-        }
-
-        let mut past_view_events: Vec<EventViewModel> = Vec::new();
         let past_events = app_state
-            .event_repository
-            .list_events(SelectManyFilter {
-                date_from: None,
-                date_to: Some(
-                    convert_date_time_to_date(current_date_time)
-                        .previous_day()
-                        .unwrap_or(Date::MIN),
-                ),
-                is_draft: None,
-                venue_id: None,
-                owner_id: None,
-                city: None,
-                state: None,
-                name: None,
-            })
+            .read_model_repository
+            .list_event_listings(
+                SelectManyFilter {
+                    organization_id,
+                    date_from: None,
+                    date_to: Some(
+                        convert_date_time_to_date(current_date_time)
+                            .previous_day()
+                            .unwrap_or(Date::MIN),
+                    ),
+                    status: None,
+                    venue_id: None,
+                    owner_id: None,
+                    city: None,
+                    state: None,
+                    name: None,
+                    managed_by: None,
+                },
+                viewer,
+            )
             .await?;
-        for event in past_events {
-            let venue = app_state
-                .venue_repository
-                .get_venue_by_id(event.venue_id)
-                .await?;
-            let owner = app_state
-                .user_repository
-                .get_user_by_id(event.owner_id)
-                .await?;
-
-            // Check if user is owner or manager to show draft event.
-            if event.is_draft {
-                match auth_session.user {
-                    Some(ref user) => {
-                        let event_manager_relations = app_state
-                            .event_manager_relation_repository
-                            .list_event_managers(event.id)
-                            .await?;
-
-                        if event.owner_id != user.id {
-                            let user_is_manager = event_manager_relations
-                                .into_iter()
-                                .any(|relation| relation.user_id == user.id);
-                            if !user_is_manager {
-                                continue;
-                            }
-                        }
-                    }
-                    None => continue,
-                }
-            }
-
-            past_view_events.push(EventViewModel::new(event, venue, owner));
-        }
+        let future_view_events: Vec<EventViewModel> = future_events
+            .into_iter()
+            .map(EventViewModel::from)
+            .collect();
+        let past_view_events: Vec<EventViewModel> =
+            past_events.into_iter().map(EventViewModel::from).collect();
 
         let template = EventsTemplate {
             session: auth_session,
@@ -139,6 +91,7 @@ pub mod get {
             past_events: past_view_events,
             states,
             cities,
+            event_statuses: all_event_statuses(),
         };
         let html = template.render().unwrap();
         Ok(Html(html))
@@ -150,11 +103,11 @@ pub mod post {
     use crate::{
         app::AppState,
         error::ApiError,
-        models::event::CreateEvent,
-        repositories::event::EventRepository,
+        models::event::{CreateEvent, EventStatus, DEFAULT_WITHDRAWAL_CUTOFF_HOURS},
+        storage::validate_image_url,
         templates::ToastType,
         utils::{
-            date_utils::parse_date,
+            date_utils::{parse_date, parse_optional_date_time},
             response_utils::{
                 generate_form_errors_response, generate_htmx_redirect, generate_toast_response,
                 CheckboxState,
@@ -177,11 +130,15 @@ pub mod post {
         ))]
         event_name: String,
         create_as_draft: Option<CheckboxState>,
+        publish_at: String,
         #[validate(length(min = 1, message = "Beginning date is required."))]
         date_start: String,
         #[validate(length(min = 1, message = "End date is required."))]
         date_end: String,
-        #[validate(url(message = "Hero image URL is not in the correct format."))]
+        #[validate(custom(
+            function = "validate_image_url",
+            message = "Hero image URL is not in the correct format."
+        ))]
         hero_img_url: String,
         venue_id: Option<i32>,
         #[validate(length(
@@ -189,11 +146,18 @@ pub mod post {
             message = "Event description is too long. Maximum is 300 characters."
         ))]
         description: String,
+        #[validate(range(
+            min = 0,
+            max = 720,
+            message = "Withdrawal cutoff has to be between 0 and 720 hours."
+        ))]
+        withdrawal_cutoff_hours: Option<i32>,
     }
 
     pub async fn events(
         auth_session: AuthSession,
         State(app_state): State<AppState>,
+        active_organization: ActiveOrganization,
         params: Form<Params>,
     ) -> Result<Response, AppError> {
         let current_user = match auth_session.clone().user {
@@ -217,6 +181,13 @@ pub mod post {
                 ))
             }
         };
+        let venue = app_state.venue_repository.get_venue_by_id(venue_id).await?;
+        if venue.organization_id != active_organization.id {
+            return Ok(generate_toast_response(
+                ToastType::Error,
+                "The venue belongs to another organization.".to_string(),
+            ));
+        }
         let date_start = match parse_date(&params.date_start) {
             Ok(date_start) => date_start,
             Err(_) => {
@@ -242,17 +213,37 @@ pub mod post {
             ));
         }
 
+        let publish_at = match parse_optional_date_time(&params.publish_at) {
+            Ok(publish_at) => publish_at.filter(|_| create_as_draft),
+            Err(_) => {
+                return Ok(generate_toast_response(
+                    ToastType::Error,
+                    "Publish date is not in the correct format.".to_string(),
+                ))
+            }
+        };
+
         let _new_event = app_state
             .event_repository
+            .with_actor(current_user.id)
             .create_event(CreateEvent {
                 name: params.event_name.clone(),
                 date_start,
                 date_end,
                 img_url: params.hero_img_url.clone(),
                 description: params.description.clone(),
-                is_draft: create_as_draft,
+                status: if create_as_draft {
+                    EventStatus::Draft
+                } else {
+                    EventStatus::Published
+                },
                 venue_id,
                 owner_id: current_user.id,
+                publish_at,
+                organization_id: active_organization.id,
+                withdrawal_cutoff_hours: params
+                    .withdrawal_cutoff_hours
+                    .unwrap_or(DEFAULT_WITHDRAWAL_CUTOFF_HOURS),
             })
             .await?;
         Ok(generate_htmx_redirect("/manage/events"))
@@ -264,16 +255,15 @@ pub mod patch {
     use crate::{
         app::AppState,
         error::ApiError,
-        models::{event::PartialEvent, user::UserRole},
-        repositories::{
-            event::EventRepository, event_manager_relation::EventManagerRelationRepository,
-        },
+        models::event::PartialEvent,
+        policy::EventAction,
+        storage::validate_image_url,
         templates::ToastType,
         utils::{
-            date_utils::parse_date,
+            date_utils::{parse_date, parse_optional_date_time},
             response_utils::{
                 generate_form_errors_response, generate_htmx_redirect, generate_toast_response,
-                generate_unauthorized_response, CheckboxState,
+                generate_unauthorized_response,
             },
         },
     };
@@ -292,12 +282,14 @@ pub mod patch {
             message = "Event name has to be 3 to 32 characters long."
         ))]
         event_name: String,
-        create_as_draft: Option<CheckboxState>,
         #[validate(length(min = 1, message = "Beginning date is required."))]
         date_start: String,
         #[validate(length(min = 1, message = "End date is required."))]
         date_end: String,
-        #[validate(url(message = "Hero image URL is not in the correct format."))]
+        #[validate(custom(
+            function = "validate_image_url",
+            message = "Hero image URL is not in the correct format."
+        ))]
         hero_img_url: String,
         venue_id: Option<i32>,
         #[validate(length(
@@ -305,6 +297,13 @@ pub mod patch {
             message = "Event description is too long. Maximum is 300 characters."
         ))]
         description: String,
+        #[validate(range(
+            min = 0,
+            max = 720,
+            message = "Withdrawal cutoff has to be between 0 and 720 hours."
+        ))]
+        withdrawal_cutoff_hours: Option<i32>,
+        publish_at: Option<String>,
         event_id: i32,
     }
 
@@ -323,27 +322,18 @@ pub mod patch {
             .event_repository
             .get_event_by_id(params.event_id)
             .await?;
-        if current_user.id != event.owner_id && current_user.role != UserRole::Admin {
-            let current_user_managed_events = app_state
-                .event_manager_relation_repository
-                .list_managers_events(current_user.id)
-                .await?;
-            if !current_user_managed_events
-                .into_iter()
-                .any(|event_manager_relation| event_manager_relation.event_id == params.event_id)
-            {
-                return Ok(generate_unauthorized_response());
-            }
+        if !app_state
+            .event_policy
+            .is_authorized(&current_user, &event, EventAction::EditEvent)
+            .await?
+        {
+            return Ok(generate_unauthorized_response());
         }
 
         match params.validate() {
             Ok(_) => (),
             Err(errors) => return Ok(generate_form_errors_response(errors)),
         }
-        let create_as_draft = match params.create_as_draft {
-            Some(_create_as_draft) => true,
-            None => false,
-        };
         let venue_id = match params.venue_id {
             Some(venue_id) => venue_id,
             None => {
@@ -353,6 +343,13 @@ pub mod patch {
                 ))
             }
         };
+        let venue = app_state.venue_repository.get_venue_by_id(venue_id).await?;
+        if venue.organization_id != event.organization_id {
+            return Ok(generate_toast_response(
+                ToastType::Error,
+                "The venue belongs to another organization.".to_string(),
+            ));
+        }
         let date_start = match parse_date(&params.date_start) {
             Ok(date_start) => date_start,
             Err(_) => {
@@ -378,8 +375,20 @@ pub mod patch {
             ));
         }
 
+        let publish_at = match params.publish_at.as_deref().map(parse_optional_date_time) {
+            Some(Ok(publish_at)) => Some(publish_at),
+            Some(Err(_)) => {
+                return Ok(generate_toast_response(
+                    ToastType::Error,
+                    "Publish date is not in the correct format.".to_string(),
+                ))
+            }
+            None => None,
+        };
+
         let _updated_event = app_state
             .event_repository
+            .with_actor(current_user.id)
             .update_event(
                 params.event_id,
                 PartialEvent {
@@ -388,9 +397,10 @@ pub mod patch {
                     date_end: Some(date_end),
                     img_url: Some(params.hero_img_url.clone()),
                     description: Some(params.description.clone()),
-                    is_draft: Some(create_as_draft),
                     venue_id: Some(venue_id),
                     owner_id: None,
+                    publish_at,
+                    withdrawal_cutoff_hours: params.withdrawal_cutoff_hours,
                 },
             )
             .await?;
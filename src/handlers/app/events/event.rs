@@ -2,7 +2,7 @@ use crate::handlers::app::auth::AuthSession;
 use askama::Template;
 use axum::{extract::State, response::Html};
 
-use crate::{error::AppError, repositories::user::UserRepository, templates::EventTemplate};
+use crate::{error::AppError, templates::EventTemplate};
 
 pub mod create;
 pub mod manage;
@@ -11,21 +11,20 @@ pub mod get {
     use super::*;
     use crate::{
         app::AppState,
+        error::ApiError,
         models::{
             employment::{self, EmploymentState},
             job_position::{JobPositionViewModel, SelectManyFilter},
+            review::{ReviewDirection, ReviewSummary},
         },
-        repositories::{
-            employment::EmploymentRepository, event::EventRepository,
-            job_position::JobPositionRepository, position_category::PositionCategoryRepository,
-            venue::VenueRepository,
-        },
+        policy::EventAction,
         utils::date_utils::is_date_in_past,
-        view_models::event::EventDetailViewModel,
+        view_models::{event::EventDetailViewModel, review::ReviewViewModel},
     };
     use askama_axum::IntoResponse;
     use axum::extract::Path;
     use axum::response::Response;
+    use sqlx::types::time::OffsetDateTime;
 
     pub async fn event(
         Path(event_id): Path<i32>,
@@ -34,6 +33,22 @@ pub mod get {
     ) -> Result<Response, AppError> {
         let current_user_id = auth_session.clone().user.map(|user| user.id);
         let event = app_state.event_repository.get_event_by_id(event_id).await?;
+
+        // Drafts are only visible to the people organizing the event.
+        if event.is_draft() {
+            let can_view = match auth_session.user {
+                Some(ref user) => {
+                    app_state
+                        .event_policy
+                        .is_authorized(user, &event, EventAction::ViewDraft)
+                        .await?
+                }
+                None => false,
+            };
+            if !can_view {
+                return Err(AppError::from(ApiError::NotFound));
+            }
+        }
         let venue = app_state
             .venue_repository
             .get_venue_by_id(event.venue_id)
@@ -45,12 +60,14 @@ pub mod get {
         let job_positions = app_state
             .job_position_repository
             .list_job_positions(SelectManyFilter {
+                organization_id: None,
                 event_id: Some(event.id),
                 position_category_id: None,
                 salary: None,
                 currency: None,
                 capacity: None,
                 is_opened_for_registration: None,
+                managed_by: None,
             })
             .await?;
         let mut job_positions_view_model_vec: Vec<JobPositionViewModel> = Vec::new();
@@ -63,19 +80,29 @@ pub mod get {
             let mut employments = app_state
                 .employment_repository
                 .list_employment(employment::SelectManyFilter {
+                    organization_id: None,
                     position_id: Some(job_position.id),
                     user_id: None,
                     state: Some(EmploymentState::Accepted),
                     rating: None,
+                    event_name_contains: None,
+                    job_name_contains: None,
+                    employee_name_contains: None,
+                    managed_by: None,
                 })
                 .await?;
             let mut employments_done = app_state
                 .employment_repository
                 .list_employment(employment::SelectManyFilter {
+                    organization_id: None,
                     position_id: Some(job_position.id),
                     user_id: None,
                     state: Some(EmploymentState::Done),
                     rating: None,
+                    event_name_contains: None,
+                    job_name_contains: None,
+                    employee_name_contains: None,
+                    managed_by: None,
                 })
                 .await?;
             employments.append(&mut employments_done);
@@ -84,10 +111,15 @@ pub mod get {
                     app_state
                         .employment_repository
                         .list_employment(employment::SelectManyFilter {
+                            organization_id: None,
                             position_id: Some(job_position.id),
                             user_id: Some(current_user_id),
                             state: None,
                             rating: None,
+                            event_name_contains: None,
+                            job_name_contains: None,
+                            employee_name_contains: None,
+                            managed_by: None,
                         })
                         .await?
                 }
@@ -97,6 +129,8 @@ pub mod get {
             let employment_state = employment_state
                 .first()
                 .map(|employment| employment.state.clone());
+            let is_registration_open =
+                job_position.is_registration_open_at(OffsetDateTime::now_utc());
 
             job_positions_view_model_vec.push(JobPositionViewModel {
                 id: job_position.id,
@@ -106,7 +140,7 @@ pub mod get {
                 salary: job_position.salary,
                 current_capacity: employments.len() as i32,
                 max_capacity: job_position.capacity,
-                is_opened_for_registration: job_position.is_opened_for_registration,
+                is_opened_for_registration: is_registration_open,
                 employment_state,
                 position_category: match job_category {
                     Ok(category) => Some(category),
@@ -115,10 +149,28 @@ pub mod get {
             });
         }
 
+        let today = OffsetDateTime::now_utc().date();
+        let reviews = app_state
+            .review_repository
+            .list_event_reviews(event.id, today)
+            .await?;
+        let organizer_reviews = app_state
+            .review_repository
+            .list_published_reviews(
+                event.owner_id,
+                ReviewDirection::WorkerToOrganizer,
+                Some(event.organization_id),
+                today,
+            )
+            .await?;
+
         let template = EventTemplate {
             session: auth_session,
             active_route: Some(crate::templates::ActiveRoute::Events),
             is_in_past: is_date_in_past(event.date_start),
+            review_summary: ReviewSummary::from_reviews(&reviews),
+            organizer_review_summary: ReviewSummary::from_reviews(&organizer_reviews),
+            reviews: reviews.into_iter().map(ReviewViewModel::from).collect(),
             event: EventDetailViewModel {
                 id: event.id,
                 name: event.name,
@@ -126,7 +178,8 @@ pub mod get {
                 date_end: event.date_end,
                 img_url: event.img_url,
                 description: event.description,
-                is_draft: event.is_draft,
+                status: event.status,
+                cancellation_reason: event.cancellation_reason,
                 venue,
                 owner,
                 job_positions: job_positions_view_model_vec,
@@ -140,12 +193,7 @@ pub mod get {
 pub mod delete {
     use super::*;
     use crate::{
-        app::AppState,
-        error::ApiError,
-        models::user::UserRole,
-        repositories::{
-            event::EventRepository, event_manager_relation::EventManagerRelationRepository,
-        },
+        app::AppState, error::ApiError, policy::EventAction,
         utils::response_utils::generate_unauthorized_response,
     };
     use axum::extract::Path;
@@ -163,20 +211,19 @@ pub mod delete {
 
         // Check if current user can delete event
         let event = app_state.event_repository.get_event_by_id(event_id).await?;
-        if current_user.id != event.owner_id && current_user.role != UserRole::Admin {
-            let current_user_managed_events = app_state
-                .event_manager_relation_repository
-                .list_managers_events(current_user.id)
-                .await?;
-            if !current_user_managed_events
-                .into_iter()
-                .any(|event_manager_relation| event_manager_relation.event_id == event_id)
-            {
-                return Ok(generate_unauthorized_response());
-            }
+        if !app_state
+            .event_policy
+            .is_authorized(&current_user, &event, EventAction::DeleteEvent)
+            .await?
+        {
+            return Ok(generate_unauthorized_response());
         }
 
-        app_state.event_repository.delete_event(event_id).await?;
+        app_state
+            .event_repository
+            .with_actor(current_user.id)
+            .delete_event(event_id)
+            .await?;
 
         Ok(Response::new("".into()))
     }
@@ -7,17 +7,20 @@ use crate::error::AppError;
 pub mod get {
     use super::*;
     use crate::{
-        app::AppState, models::venue, repositories::venue::VenueRepository,
+        app::AppState,
+        models::{organization::ActiveOrganization, venue},
         templates::CreateEventTemplate,
     };
 
     pub async fn create(
         auth_session: AuthSession,
         State(app_state): State<AppState>,
+        active_organization: ActiveOrganization,
     ) -> Result<Html<String>, AppError> {
         let venues = app_state
             .venue_repository
             .list_venues(venue::SelectManyFilter {
+                organization_id: Some(active_organization.id),
                 name: None,
                 description: None,
                 state: None,
@@ -2,23 +2,23 @@ use crate::handlers::app::auth::AuthSession;
 use askama::Template;
 use axum::{extract::State, response::Html};
 
-use crate::{error::AppError, repositories::user::UserRepository};
+use crate::error::AppError;
 
 pub mod get {
     use super::*;
     use crate::{
         app::AppState,
         error::ApiError,
-        models::{
-            user::{self, User, UserRole},
-            venue::SelectManyFilter,
-        },
-        repositories::{
-            event::EventRepository, event_manager_relation::EventManagerRelationRepository,
-            venue::VenueRepository,
+        handlers::app::{
+            admin::audit::generate_audit_log_viewmodels,
+            event_manager_relation::generate_event_manager_viewmodels,
         },
+        models::{audit_log, venue::SelectManyFilter},
+        policy::EventAction,
         templates::ManageEventTemplate,
-        utils::response_utils::generate_unauthorized_response,
+        utils::{
+            date_utils::format_date_time_input, response_utils::generate_unauthorized_response,
+        },
         view_models::event::ManageEventViewModel,
     };
     use askama_axum::IntoResponse;
@@ -38,17 +38,12 @@ pub mod get {
         let event = app_state.event_repository.get_event_by_id(event_id).await?;
 
         // Check if current user can update event
-        if current_user.id != event.owner_id && current_user.role != UserRole::Admin {
-            let current_user_managed_events = app_state
-                .event_manager_relation_repository
-                .list_managers_events(current_user.id)
-                .await?;
-            if !current_user_managed_events
-                .into_iter()
-                .any(|event_manager_relation| event_manager_relation.event_id == event_id)
-            {
-                return Ok(generate_unauthorized_response());
-            }
+        if !app_state
+            .event_policy
+            .is_authorized(&current_user, &event, EventAction::EditEvent)
+            .await?
+        {
+            return Ok(generate_unauthorized_response());
         }
 
         let venue = app_state
@@ -58,6 +53,7 @@ pub mod get {
         let venues = app_state
             .venue_repository
             .list_venues(SelectManyFilter {
+                organization_id: Some(event.organization_id),
                 name: None,
                 description: None,
                 state: None,
@@ -71,37 +67,25 @@ pub mod get {
             .into_iter()
             .filter(|filter_venue| filter_venue.id != venue.id)
             .collect();
-        let mut managers: Vec<User> = Vec::new();
-        let event_manager_relations = app_state
-            .event_manager_relation_repository
-            .list_event_managers(event_id)
+        let (managers, possible_managers) =
+            generate_event_manager_viewmodels(&app_state, event_id).await?;
+        let can_manage_managers = app_state
+            .event_policy
+            .is_authorized(&current_user, &event, EventAction::ManageManagers)
             .await?;
-        for event_manager_relation in event_manager_relations {
-            let manager = app_state
-                .user_repository
-                .get_user_by_id(event_manager_relation.user_id)
-                .await?;
-            managers.push(manager);
-        }
-        let possible_managers = app_state
-            .user_repository
-            .list_users(user::SelectManyFilter {
-                first_name: None,
-                last_name: None,
-                username: None,
-                gender: None,
-                role: Some(UserRole::Organizer),
-                tax_rate: None,
+        let audit_logs = app_state
+            .audit_log_repository
+            .list_audit_logs(audit_log::SelectManyFilter {
+                actor_id: None,
+                entity: None,
+                entity_id: None,
+                event_id: Some(event_id),
+                action: None,
+                date_from: None,
+                date_to: None,
             })
             .await?;
-        let possible_managers = possible_managers
-            .into_iter()
-            .filter(|possible_manager| {
-                !managers
-                    .iter()
-                    .any(|manager| manager.id == possible_manager.id)
-            })
-            .collect();
+        let audit_logs = generate_audit_log_viewmodels(audit_logs, &app_state).await?;
 
         let template = ManageEventTemplate {
             session: auth_session,
@@ -112,7 +96,10 @@ pub mod get {
                 date_start: event.date_start,
                 date_end: event.date_end,
                 img_url: event.img_url,
-                is_draft: event.is_draft,
+                status: event.status,
+                cancellation_reason: event.cancellation_reason,
+                publish_at: event.publish_at.map(format_date_time_input),
+                withdrawal_cutoff_hours: event.withdrawal_cutoff_hours,
                 venue,
                 owner: current_user,
                 description: event.description,
@@ -120,8 +107,166 @@ pub mod get {
             venues,
             managers,
             possible_managers,
+            can_manage_managers,
+            audit_logs,
         };
         let html = template.render().unwrap();
         Ok(Html(html).into_response())
     }
 }
+
+pub mod post {
+    use super::*;
+    use crate::{
+        app::AppState,
+        error::{ApiError, RepositoryError},
+        models::event::CancelEvent,
+        policy::EventAction,
+        templates::ToastType,
+        utils::response_utils::{
+            generate_form_errors_response, generate_htmx_redirect, generate_toast_response,
+            generate_unauthorized_response,
+        },
+    };
+    use axum::extract::Path;
+    use axum::response::Response;
+    use axum::Form;
+    use serde::Deserialize;
+    use validator::Validate;
+
+    #[derive(Deserialize)]
+    pub struct CancelEventForm {
+        reason: String,
+        compensation_hours: String,
+    }
+
+    pub async fn cancel(
+        Path(event_id): Path<i32>,
+        auth_session: AuthSession,
+        State(app_state): State<AppState>,
+        Form(payload): Form<CancelEventForm>,
+    ) -> Result<Response, AppError> {
+        let current_user = match auth_session.user {
+            Some(user) => user,
+            None => return Err(AppError::from(ApiError::InternalServerError)),
+        };
+
+        let event = app_state.event_repository.get_event_by_id(event_id).await?;
+
+        // Check if current user can cancel event
+        if !app_state
+            .event_policy
+            .is_authorized(&current_user, &event, EventAction::EditEvent)
+            .await?
+        {
+            return Ok(generate_unauthorized_response());
+        }
+
+        let compensation_hours = match payload.compensation_hours.trim() {
+            "" => None,
+            hours => match hours.parse::<f32>() {
+                Ok(hours) => Some(hours),
+                Err(_) => {
+                    return Ok(generate_toast_response(
+                        ToastType::Error,
+                        "Compensation hours are not a number.".to_string(),
+                    ))
+                }
+            },
+        };
+        let cancellation = CancelEvent {
+            reason: payload.reason.trim().to_string(),
+            compensation_hours,
+        };
+        match cancellation.validate() {
+            Ok(_) => (),
+            Err(errors) => return Ok(generate_form_errors_response(errors)),
+        };
+
+        match app_state
+            .event_repository
+            .with_actor(current_user.id)
+            .cancel_event(event_id, cancellation)
+            .await
+        {
+            Ok(_) => Ok(generate_htmx_redirect(&format!("/events/{}", event_id))),
+            Err(RepositoryError::GenericError(message)) => {
+                Ok(generate_toast_response(ToastType::Error, message))
+            }
+            Err(err) => Err(AppError::from(err)),
+        }
+    }
+}
+
+pub mod patch {
+    use super::*;
+    use crate::{
+        app::AppState,
+        error::{ApiError, RepositoryError},
+        models::event::EventStatus,
+        policy::EventAction,
+        templates::ToastType,
+        utils::response_utils::{
+            generate_htmx_redirect, generate_toast_response, generate_unauthorized_response,
+        },
+    };
+    use axum::extract::Path;
+    use axum::response::Response;
+    use axum::Form;
+    use serde::Deserialize;
+    use std::str::FromStr;
+
+    #[derive(Deserialize)]
+    pub struct StatusForm {
+        status: String,
+    }
+
+    pub async fn status(
+        Path(event_id): Path<i32>,
+        auth_session: AuthSession,
+        State(app_state): State<AppState>,
+        Form(payload): Form<StatusForm>,
+    ) -> Result<Response, AppError> {
+        let current_user = match auth_session.user {
+            Some(user) => user,
+            None => return Err(AppError::from(ApiError::InternalServerError)),
+        };
+
+        let event = app_state.event_repository.get_event_by_id(event_id).await?;
+
+        // Check if current user can change the event status
+        if !app_state
+            .event_policy
+            .is_authorized(&current_user, &event, EventAction::EditEvent)
+            .await?
+        {
+            return Ok(generate_unauthorized_response());
+        }
+
+        let status = match EventStatus::from_str(&payload.status) {
+            Ok(status) => status,
+            Err(_) => {
+                return Ok(generate_toast_response(
+                    ToastType::Error,
+                    "Unknown event status.".to_string(),
+                ))
+            }
+        };
+
+        match app_state
+            .event_repository
+            .with_actor(current_user.id)
+            .transition_event(event_id, status)
+            .await
+        {
+            Ok(_) => Ok(generate_htmx_redirect(&format!(
+                "/manage/events/{}",
+                event_id
+            ))),
+            Err(RepositoryError::GenericError(message)) => {
+                Ok(generate_toast_response(ToastType::Error, message))
+            }
+            Err(err) => Err(AppError::from(err)),
+        }
+    }
+}
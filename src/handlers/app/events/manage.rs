@@ -2,98 +2,84 @@ use crate::handlers::app::auth::AuthSession;
 use askama::Template;
 use axum::{extract::State, response::Html};
 
-use crate::{error::AppError, repositories::user::UserRepository};
+use crate::error::AppError;
 
 pub mod get {
     use super::*;
     use crate::{
         app::AppState,
         error::ApiError,
-        models::event::SelectManyFilter,
-        repositories::{
-            event::EventRepository, event_manager_relation::EventManagerRelationRepository,
-            venue::VenueRepository,
+        models::{
+            event::{all_event_statuses, EventStatus, ManagedBy, SelectManyFilter, SortColumn},
+            organization::ActiveOrganization,
+            pagination::ListParams,
         },
         templates::ManageEventsTemplate,
-        view_models::event::EventViewModel,
+        utils::table_utils::parse_filter,
+        view_models::{event::EventViewModel, pagination::PaginationViewModel},
     };
+    use axum::extract::Query;
+    use serde::Deserialize;
+    use std::str::FromStr;
+
+    #[derive(Deserialize)]
+    pub struct Params {
+        status: Option<String>,
+    }
 
     pub async fn manage(
+        params: Query<Params>,
+        Query(list_params): Query<ListParams<SortColumn>>,
         auth_session: AuthSession,
         State(app_state): State<AppState>,
+        active_organization: ActiveOrganization,
     ) -> Result<Html<String>, AppError> {
         let current_user = match auth_session.clone().user {
             Some(user) => user,
             None => return Err(AppError::from(ApiError::InternalServerError)),
         };
-        let mut events: Vec<EventViewModel> = Vec::new();
-        let event_manager_relations = app_state
-            .event_manager_relation_repository
-            .list_managers_events(current_user.id)
+        let selected_status = parse_filter(
+            params.status.as_deref().unwrap_or_default(),
+            |status| EventStatus::from_str(status).map_err(|_| ApiError::NotFound),
+            "",
+        )?;
+        let page = app_state
+            .read_model_repository
+            .list_event_listings_page(
+                SelectManyFilter {
+                    organization_id: Some(active_organization.id),
+                    date_from: None,
+                    date_to: None,
+                    status: selected_status,
+                    venue_id: None,
+                    owner_id: None,
+                    city: None,
+                    state: None,
+                    name: None,
+                    managed_by: Some(ManagedBy {
+                        user_id: current_user.id,
+                        actions: Vec::new(),
+                    }),
+                },
+                &list_params.spec(),
+            )
             .await?;
-        for relation in event_manager_relations {
-            let event = app_state
-                .event_repository
-                .get_event_by_id(relation.event_id)
-                .await?;
-            let venue = app_state
-                .venue_repository
-                .get_venue_by_id(event.venue_id)
-                .await?;
-            let owner = app_state
-                .user_repository
-                .get_user_by_id(event.owner_id)
-                .await?;
-            events.push(EventViewModel {
-                id: event.id,
-                name: event.name,
-                date_start: event.date_start,
-                date_end: event.date_end,
-                img_url: event.img_url,
-                is_draft: event.is_draft,
-                venue,
-                owner,
-            });
+        let mut link_path = format!("/manage/events?{}", list_params.sort_query());
+        if let Some(status) = &params.status {
+            link_path.push_str(&format!("&status={status}"));
         }
+        let pagination = PaginationViewModel::new(&page, Some(link_path));
 
-        let owned_events = app_state
-            .event_repository
-            .list_events(SelectManyFilter {
-                date_from: None,
-                date_to: None,
-                is_draft: None,
-                venue_id: None,
-                owner_id: Some(current_user.id),
-                city: None,
-                state: None,
-                name: None,
-            })
-            .await?;
-        for event in owned_events {
-            let venue = app_state
-                .venue_repository
-                .get_venue_by_id(event.venue_id)
-                .await?;
-            let owner = app_state
-                .user_repository
-                .get_user_by_id(event.owner_id)
-                .await?;
-            events.push(EventViewModel {
-                id: event.id,
-                name: event.name,
-                date_start: event.date_start,
-                date_end: event.date_end,
-                img_url: event.img_url,
-                is_draft: event.is_draft,
-                venue,
-                owner,
-            });
-        }
+        let events: Vec<EventViewModel> =
+            page.items.into_iter().map(EventViewModel::from).collect();
 
         let template = ManageEventsTemplate {
             session: auth_session,
             active_route: Some(crate::templates::ActiveRoute::Manage),
             events,
+            event_statuses: all_event_statuses(),
+            selected_status,
+            pagination,
         };
         let html = template.render().unwrap();
         Ok(Html(html))
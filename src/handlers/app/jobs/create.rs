@@ -6,6 +6,7 @@ use axum::{
 };
 
 use crate::error::AppError;
+use crate::models::organization::ActiveOrganization;
 
 pub mod get {
     use super::*;
@@ -13,16 +14,13 @@ pub mod get {
         app::AppState,
         error::ApiError,
         models::event::{Event, SelectManyFilter},
-        repositories::{
-            event::EventRepository, event_manager_relation::EventManagerRelationRepository,
-            position_category::PositionCategoryRepository,
-        },
         templates::CreateJobTemplate,
     };
 
     pub async fn create(
         auth_session: AuthSession,
         State(app_state): State<AppState>,
+        active_organization: ActiveOrganization,
     ) -> Result<Html<String>, AppError> {
         let current_user = match auth_session.clone().user {
             Some(user) => user,
@@ -31,22 +29,27 @@ pub mod get {
         let mut events = app_state
             .event_repository
             .list_events(SelectManyFilter {
+                organization_id: Some(active_organization.id),
                 date_from: None,
                 date_to: None,
-                is_draft: None,
+                status: None,
                 venue_id: None,
                 owner_id: Some(current_user.id),
                 city: None,
                 state: None,
                 name: None,
+                managed_by: None,
             })
             .await?;
         let mut managed_events: Vec<Event> = Vec::new();
         let event_manager_relations = app_state
             .event_manager_relation_repository
-            .list_managers_events(current_user.id)
+            .list_managers_events(current_user.id, active_organization.id)
             .await?;
-        for relation in event_manager_relations {
+        for relation in event_manager_relations
+            .into_iter()
+            .filter(|relation| relation.can_manage_jobs)
+        {
             let event = app_state
                 .event_repository
                 .get_event_by_id(relation.event_id)
@@ -57,7 +60,7 @@ pub mod get {
 
         let job_categories = app_state
             .position_category_repository
-            .list_position_categories()
+            .list_position_categories(active_organization.id)
             .await?;
 
         let template = CreateJobTemplate {
@@ -76,18 +79,15 @@ pub mod post {
     use crate::{
         app::AppState,
         error::ApiError,
-        models::{
-            job_position::{CreateJobPosition, SalaryCurrency},
-            user::UserRole,
-        },
-        repositories::{
-            event::EventRepository, event_manager_relation::EventManagerRelationRepository,
-            job_position::JobPositionRepository,
-        },
+        models::job_position::{CreateJobPosition, SalaryCurrency},
+        policy::EventAction,
         templates::ToastType,
-        utils::response_utils::{
-            generate_form_errors_response, generate_htmx_redirect, generate_toast_response,
-            generate_unauthorized_response, CheckboxState,
+        utils::{
+            date_utils::parse_optional_date_time,
+            response_utils::{
+                generate_form_errors_response, generate_htmx_redirect, generate_toast_response,
+                generate_unauthorized_response, CheckboxState,
+            },
         },
     };
     use axum::Form;
@@ -106,6 +106,8 @@ pub mod post {
         ))]
         job_name: String,
         opened_for_registration: Option<CheckboxState>,
+        registration_opens_at: String,
+        registration_closes_at: String,
         #[validate(length(min = 1, message = "Salary is required."))]
         salary: String,
         #[validate(length(min = 1, message = "Capacity is required."))]
@@ -173,6 +175,34 @@ pub mod post {
             ));
         }
 
+        let registration_opens_at = match parse_optional_date_time(&params.registration_opens_at) {
+            Ok(registration_opens_at) => registration_opens_at,
+            Err(_) => {
+                return Ok(generate_toast_response(
+                    ToastType::Error,
+                    "Registration opening is not in the correct format.".to_string(),
+                ))
+            }
+        };
+        let registration_closes_at = match parse_optional_date_time(&params.registration_closes_at)
+        {
+            Ok(registration_closes_at) => registration_closes_at,
+            Err(_) => {
+                return Ok(generate_toast_response(
+                    ToastType::Error,
+                    "Registration closing is not in the correct format.".to_string(),
+                ))
+            }
+        };
+        if let (Some(opens_at), Some(closes_at)) = (registration_opens_at, registration_closes_at) {
+            if closes_at <= opens_at {
+                return Ok(generate_toast_response(
+                    ToastType::Error,
+                    "Registration has to close after it opens.".to_string(),
+                ));
+            }
+        }
+
         let event_id = match params.event_id {
             Some(event_id) => event_id,
             None => {
@@ -194,33 +224,48 @@ pub mod post {
 
         // Check if current user can create job in event
         let event = app_state.event_repository.get_event_by_id(event_id).await?;
-        if current_user.id != event.owner_id && current_user.role != UserRole::Admin {
-            let current_user_managed_events = app_state
-                .event_manager_relation_repository
-                .list_managers_events(current_user.id)
-                .await?;
-            if !current_user_managed_events
-                .into_iter()
-                .any(|event_manager_relation| event_manager_relation.event_id == event_id)
-            {
-                return Ok(generate_unauthorized_response());
-            }
+        if !app_state
+            .event_policy
+            .is_authorized(&current_user, &event, EventAction::ManageJobs)
+            .await?
+        {
+            return Ok(generate_unauthorized_response());
+        }
+        // The category is read in the same unit of work as the job is created in, so that
+        // it cannot be deleted in between.
+        let mut unit_of_work = app_state.database.begin().await?;
+        let category = app_state
+            .position_category_repository
+            .get_position_category_by_id_in(&mut unit_of_work, category_id)
+            .await?;
+        if category.organization_id != event.organization_id {
+            return Ok(generate_toast_response(
+                ToastType::Error,
+                "The job category belongs to another organization.".to_string(),
+            ));
         }
 
-        let _new_job = app_state
+        app_state
             .job_position_repository
-            .create_job_position(CreateJobPosition {
-                name: params.job_name.clone(),
-                description: params.description.clone(),
-                salary,
-                currency: SalaryCurrency::CZK,
-                capacity,
-                instructions_html: params.instructions.clone(),
-                is_opened_for_registration: opened_for_registration,
-                event_id,
-                position_category_id: category_id,
-            })
+            .with_actor(current_user.id)
+            .create_job_position_in(
+                &mut unit_of_work,
+                CreateJobPosition {
+                    name: params.job_name.clone(),
+                    description: params.description.clone(),
+                    salary,
+                    currency: SalaryCurrency::CZK,
+                    capacity,
+                    instructions_markdown: params.instructions.clone(),
+                    is_opened_for_registration: opened_for_registration,
+                    event_id,
+                    position_category_id: category_id,
+                    registration_opens_at,
+                    registration_closes_at,
+                },
+            )
             .await?;
+        unit_of_work.commit().await?;
 
         Ok(generate_htmx_redirect("/manage/jobs"))
     }
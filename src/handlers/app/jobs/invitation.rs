@@ -0,0 +1,105 @@
+pub mod post {
+    use axum::{
+        extract::{Path, State},
+        response::Response,
+        Form,
+    };
+    use serde::Deserialize;
+    use sqlx::types::time::OffsetDateTime;
+
+    use crate::{
+        app::AppState,
+        error::{ApiError, AppError, RepositoryError},
+        handlers::app::auth::AuthSession,
+        models::{employment::EmploymentState, notification::CreateNotification},
+        templates::ToastType,
+        utils::response_utils::{
+            generate_htmx_redirect, generate_toast_response, generate_unauthorized_response,
+        },
+    };
+
+    #[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+    #[serde(rename_all = "lowercase")]
+    pub enum InvitationAnswer {
+        Accept,
+        Decline,
+    }
+
+    #[derive(Deserialize)]
+    pub struct InvitationForm {
+        answer: InvitationAnswer,
+    }
+
+    pub async fn invitation(
+        auth_session: AuthSession,
+        State(app_state): State<AppState>,
+        Path(employment_id): Path<i32>,
+        Form(payload): Form<InvitationForm>,
+    ) -> Result<Response, AppError> {
+        let current_user = match auth_session.user {
+            Some(user) => user,
+            None => return Err(AppError::from(ApiError::InternalServerError)),
+        };
+
+        let employment = app_state
+            .employment_repository
+            .get_employment_by_id(employment_id)
+            .await?;
+        if employment.user_id != current_user.id {
+            return Ok(generate_unauthorized_response());
+        }
+
+        // The answer only counts once the event owner has been notified about it.
+        let mut unit_of_work = app_state.database.begin().await?;
+        let employment = match app_state
+            .employment_repository
+            .with_actor(current_user.id)
+            .respond_to_invitation_in(
+                &mut unit_of_work,
+                employment_id,
+                payload.answer == InvitationAnswer::Accept,
+                OffsetDateTime::now_utc(),
+            )
+            .await
+        {
+            Ok(employment) => employment,
+            Err(RepositoryError::GenericError(message)) => {
+                return Ok(generate_toast_response(ToastType::Error, message))
+            }
+            Err(err) => return Err(err.into()),
+        };
+
+        let job = app_state
+            .job_position_repository
+            .get_job_position_by_id(employment.position_id)
+            .await?;
+        let event = app_state
+            .event_repository
+            .get_event_by_id(job.event_id)
+            .await?;
+        let answer = match employment.state {
+            EmploymentState::Accepted => "accepted",
+            _ => "declined",
+        };
+        app_state
+            .notification_repository
+            .create_notification_in(
+                &mut unit_of_work,
+                CreateNotification {
+                    user_id: event.owner_id,
+                    message: format!(
+                        "{} {} has {} the invitation to work as {} at {}",
+                        current_user.first_name,
+                        current_user.last_name,
+                        answer,
+                        job.name,
+                        event.name
+                    ),
+                },
+            )
+            .await?;
+        unit_of_work.commit().await?;
+
+        Ok(generate_htmx_redirect("/jobs"))
+    }
+}
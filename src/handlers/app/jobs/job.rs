@@ -1 +1,2 @@
 pub mod manage;
+pub mod suggestions;
@@ -5,7 +5,7 @@ use axum::{
     response::{Html, Response},
 };
 
-use crate::{error::AppError, repositories::user::UserRepository};
+use crate::error::AppError;
 
 pub mod get {
     use super::*;
@@ -13,18 +13,16 @@ pub mod get {
         app::AppState,
         error::ApiError,
         models::{
-            employment::{self, EmploymentState},
+            employment,
             event::{Event, SelectManyFilter},
             job_position::SalaryCurrency,
             user::{self, UserRole},
         },
-        repositories::{
-            employment::EmploymentRepository, event::EventRepository,
-            event_manager_relation::EventManagerRelationRepository,
-            job_position::JobPositionRepository, position_category::PositionCategoryRepository,
-        },
+        policy::EventAction,
         templates::ManageJobTemplate,
-        utils::response_utils::generate_unauthorized_response,
+        utils::{
+            date_utils::format_date_time_input, response_utils::generate_unauthorized_response,
+        },
         view_models::jobs::{ManageJobEmployeeViewModel, ManageJobPositionViewModel},
     };
     use askama_axum::IntoResponse;
@@ -54,38 +52,38 @@ pub mod get {
             .await?;
 
         // Check if current user can create job in event
-        if current_user.id != event.owner_id && current_user.role != UserRole::Admin {
-            let current_user_managed_events = app_state
-                .event_manager_relation_repository
-                .list_managers_events(current_user.id)
-                .await?;
-            if !current_user_managed_events
-                .into_iter()
-                .any(|event_manager_relation| event_manager_relation.event_id == event.id)
-            {
-                return Ok(generate_unauthorized_response());
-            }
+        if !app_state
+            .event_policy
+            .is_authorized(&current_user, &event, EventAction::ManageJobs)
+            .await?
+        {
+            return Ok(generate_unauthorized_response());
         }
 
         let mut possible_events = app_state
             .event_repository
             .list_events(SelectManyFilter {
+                organization_id: Some(event.organization_id),
                 date_from: None,
                 date_to: None,
-                is_draft: None,
+                status: None,
                 venue_id: None,
                 owner_id: Some(current_user.id),
                 city: None,
                 state: None,
                 name: None,
+                managed_by: None,
             })
             .await?;
         let mut managed_events: Vec<Event> = Vec::new();
         let event_manager_relations = app_state
             .event_manager_relation_repository
-            .list_managers_events(current_user.id)
+            .list_managers_events(current_user.id, event.organization_id)
             .await?;
-        for relation in event_manager_relations {
+        for relation in event_manager_relations
+            .into_iter()
+            .filter(|relation| relation.can_manage_jobs)
+        {
             let event = app_state
                 .event_repository
                 .get_event_by_id(relation.event_id)
@@ -100,7 +98,7 @@ pub mod get {
 
         let possible_job_categories = app_state
             .position_category_repository
-            .list_position_categories()
+            .list_position_categories(event.organization_id)
             .await?;
         let possible_job_categories = possible_job_categories
             .into_iter()
@@ -111,13 +109,21 @@ pub mod get {
         let employments = app_state
             .employment_repository
             .list_employment(employment::SelectManyFilter {
+                organization_id: None,
                 position_id: Some(job_id),
                 user_id: None,
-                state: Some(EmploymentState::Accepted),
+                state: None,
                 rating: None,
+                event_name_contains: None,
+                job_name_contains: None,
+                employee_name_contains: None,
+                managed_by: None,
             })
             .await?;
-        for employment in employments {
+        for employment in employments
+            .into_iter()
+            .filter(|employment| employment.state.is_staffing())
+        {
             let employee = app_state
                 .user_repository
                 .get_user_by_id(employment.user_id)
@@ -142,12 +148,15 @@ pub mod get {
         let possible_employees = app_state
             .user_repository
             .list_users(user::SelectManyFilter {
+                organization_id: Some(event.organization_id),
                 first_name: None,
                 last_name: None,
                 username: None,
                 gender: None,
                 role: Some(UserRole::Employee),
                 tax_rate: None,
+                last_name_contains: None,
+                username_contains: None,
             })
             .await?;
         let possible_employees = possible_employees
@@ -170,10 +179,12 @@ pub mod get {
                 salary: job.salary,
                 capacity: job.capacity,
                 is_opened_for_registration: job.is_opened_for_registration,
+                registration_opens_at: job.registration_opens_at.map(format_date_time_input),
+                registration_closes_at: job.registration_closes_at.map(format_date_time_input),
                 currency: SalaryCurrency::CZK,
                 event,
                 category,
-                instructions: job.instructions_html,
+                instructions: job.instructions_markdown,
                 description: job.description,
             },
             employees,
@@ -189,15 +200,15 @@ pub mod patch {
     use crate::{
         app::AppState,
         error::ApiError,
-        models::{job_position::PartialJobPosition, user::UserRole},
-        repositories::{
-            event::EventRepository, event_manager_relation::EventManagerRelationRepository,
-            job_position::JobPositionRepository,
-        },
+        models::job_position::PartialJobPosition,
+        policy::{EventAction, EventScope},
         templates::ToastType,
-        utils::response_utils::{
-            generate_form_errors_response, generate_htmx_redirect, generate_toast_response,
-            generate_unauthorized_response, CheckboxState,
+        utils::{
+            date_utils::parse_optional_date_time,
+            response_utils::{
+                generate_form_errors_response, generate_htmx_redirect, generate_toast_response,
+                generate_unauthorized_response, CheckboxState,
+            },
         },
     };
     use axum::Form;
@@ -216,6 +227,8 @@ pub mod patch {
         ))]
         job_name: String,
         opened_for_registration: Option<CheckboxState>,
+        registration_opens_at: String,
+        registration_closes_at: String,
         #[validate(length(min = 1, message = "Salary is required."))]
         salary: String,
         #[validate(length(min = 1, message = "Capacity is required."))]
@@ -284,6 +297,34 @@ pub mod patch {
             ));
         }
 
+        let registration_opens_at = match parse_optional_date_time(&params.registration_opens_at) {
+            Ok(registration_opens_at) => registration_opens_at,
+            Err(_) => {
+                return Ok(generate_toast_response(
+                    ToastType::Error,
+                    "Registration opening is not in the correct format.".to_string(),
+                ))
+            }
+        };
+        let registration_closes_at = match parse_optional_date_time(&params.registration_closes_at)
+        {
+            Ok(registration_closes_at) => registration_closes_at,
+            Err(_) => {
+                return Ok(generate_toast_response(
+                    ToastType::Error,
+                    "Registration closing is not in the correct format.".to_string(),
+                ))
+            }
+        };
+        if let (Some(opens_at), Some(closes_at)) = (registration_opens_at, registration_closes_at) {
+            if closes_at <= opens_at {
+                return Ok(generate_toast_response(
+                    ToastType::Error,
+                    "Registration has to close after it opens.".to_string(),
+                ));
+            }
+        }
+
         let event_id = match params.event_id {
             Some(event_id) => event_id,
             None => {
@@ -303,23 +344,40 @@ pub mod patch {
             }
         };
 
-        // Check if current user can update job in event
+        // Check if current user can update the job and move it to the event
+        if !app_state
+            .event_policy
+            .authorize(
+                &current_user,
+                EventScope::JobPosition(params.job_id),
+                EventAction::ManageJobs,
+            )
+            .await?
+        {
+            return Ok(generate_unauthorized_response());
+        }
         let event = app_state.event_repository.get_event_by_id(event_id).await?;
-        if current_user.id != event.owner_id && current_user.role != UserRole::Admin {
-            let current_user_managed_events = app_state
-                .event_manager_relation_repository
-                .list_managers_events(current_user.id)
-                .await?;
-            if !current_user_managed_events
-                .into_iter()
-                .any(|event_manager_relation| event_manager_relation.event_id == event_id)
-            {
-                return Ok(generate_unauthorized_response());
-            }
+        if !app_state
+            .event_policy
+            .is_authorized(&current_user, &event, EventAction::ManageJobs)
+            .await?
+        {
+            return Ok(generate_unauthorized_response());
+        }
+        let category = app_state
+            .position_category_repository
+            .get_position_category_by_id(category_id)
+            .await?;
+        if category.organization_id != event.organization_id {
+            return Ok(generate_toast_response(
+                ToastType::Error,
+                "The job category belongs to another organization.".to_string(),
+            ));
         }
 
         let _updated_job = app_state
             .job_position_repository
+            .with_actor(current_user.id)
             .update_job_position(
                 params.job_id,
                 PartialJobPosition {
@@ -328,10 +386,12 @@ pub mod patch {
                     salary: Some(salary),
                     currency: None,
                     capacity: Some(capacity),
-                    instructions_html: Some(params.instructions.clone()),
+                    instructions_markdown: Some(params.instructions.clone()),
                     is_opened_for_registration: Some(opened_for_registration),
                     event_id: Some(event_id),
                     position_category_id: Some(category_id),
+                    registration_opens_at: Some(registration_opens_at),
+                    registration_closes_at: Some(registration_closes_at),
                 },
             )
             .await?;
@@ -343,13 +403,7 @@ pub mod patch {
 pub mod delete {
     use super::*;
     use crate::{
-        app::AppState,
-        error::ApiError,
-        models::user::UserRole,
-        repositories::{
-            event::EventRepository, event_manager_relation::EventManagerRelationRepository,
-            job_position::JobPositionRepository,
-        },
+        app::AppState, error::ApiError, policy::EventAction,
         utils::response_utils::generate_unauthorized_response,
     };
     use axum::extract::Path;
@@ -373,21 +427,17 @@ pub mod delete {
             .event_repository
             .get_event_by_id(job.event_id)
             .await?;
-        if current_user.id != event.owner_id && current_user.role != UserRole::Admin {
-            let current_user_managed_events = app_state
-                .event_manager_relation_repository
-                .list_managers_events(current_user.id)
-                .await?;
-            if !current_user_managed_events
-                .into_iter()
-                .any(|event_manager_relation| event_manager_relation.event_id == job.event_id)
-            {
-                return Ok(generate_unauthorized_response());
-            }
+        if !app_state
+            .event_policy
+            .is_authorized(&current_user, &event, EventAction::ManageJobs)
+            .await?
+        {
+            return Ok(generate_unauthorized_response());
         }
 
         app_state
             .job_position_repository
+            .with_actor(current_user.id)
             .delete_job_position(job_id)
             .await?;
         Ok(Response::new("".into()))
@@ -0,0 +1,68 @@
+pub mod get {
+    use askama::Template;
+    use askama_axum::IntoResponse;
+    use axum::{
+        extract::{Path, State},
+        response::{Html, Response},
+    };
+
+    use crate::{
+        app::AppState,
+        error::{ApiError, AppError},
+        handlers::app::auth::AuthSession,
+        models::staffing,
+        policy::{EventAction, EventScope},
+        templates::JobSuggestionsTemplate,
+        utils::response_utils::generate_unauthorized_response,
+        view_models::jobs::StaffSuggestionViewModel,
+    };
+
+    pub async fn suggestions(
+        Path(job_id): Path<i32>,
+        auth_session: AuthSession,
+        State(app_state): State<AppState>,
+    ) -> Result<Response, AppError> {
+        let current_user = match auth_session.user {
+            Some(user) => user,
+            None => return Err(AppError::from(ApiError::InternalServerError)),
+        };
+        if !app_state
+            .event_policy
+            .authorize(
+                &current_user,
+                EventScope::JobPosition(job_id),
+                EventAction::ManageApplicants,
+            )
+            .await?
+        {
+            return Ok(generate_unauthorized_response());
+        }
+
+        let candidates = app_state
+            .employment_repository
+            .list_staffing_candidates(job_id)
+            .await?;
+        let mut suggestions: Vec<StaffSuggestionViewModel> = candidates
+            .into_iter()
+            .map(|candidate| StaffSuggestionViewModel {
+                score: staffing::score(&candidate, &app_state.staffing_weights),
+                user_id: candidate.user_id,
+                first_name: candidate.first_name,
+                last_name: candidate.last_name,
+                username: candidate.username,
+                average_rating: candidate.average_rating,
+                done_in_category: candidate.done_in_category,
+                conflicts: candidate.conflicts,
+                no_shows: candidate.no_shows,
+            })
+            .collect();
+        suggestions.sort_by(|a, b| b.score.total.total_cmp(&a.score.total));
+
+        let template = JobSuggestionsTemplate {
+            suggestions,
+            job_id,
+        };
+        let html = template.render().unwrap();
+        Ok(Html(html).into_response())
+    }
+}
@@ -0,0 +1,99 @@
+pub mod post {
+    use axum::{
+        extract::{Path, State},
+        response::Response,
+        Form,
+    };
+    use serde::Deserialize;
+    use sqlx::types::time::OffsetDateTime;
+    use validator::Validate;
+
+    use crate::{
+        app::AppState,
+        error::{ApiError, AppError, RepositoryError},
+        handlers::app::auth::AuthSession,
+        models::{
+            notification::CreateNotification,
+            review::{CreateReview, ReviewDirection},
+        },
+        templates::ToastType,
+        utils::response_utils::{
+            generate_form_errors_response, generate_htmx_redirect, generate_toast_response,
+            generate_unauthorized_response,
+        },
+    };
+
+    #[derive(Deserialize)]
+    pub struct EventReviewForm {
+        overall: i32,
+        organization: i32,
+        communication: i32,
+        #[serde(default)]
+        comment: String,
+    }
+
+    /// Worker's review of the event and its organizer.
+    pub async fn review(
+        auth_session: AuthSession,
+        State(app_state): State<AppState>,
+        Path(employment_id): Path<i32>,
+        Form(payload): Form<EventReviewForm>,
+    ) -> Result<Response, AppError> {
+        let current_user = match auth_session.user {
+            Some(user) => user,
+            None => return Err(AppError::from(ApiError::InternalServerError)),
+        };
+
+        let employment = app_state
+            .employment_repository
+            .get_employment_by_id(employment_id)
+            .await?;
+        if employment.user_id != current_user.id {
+            return Ok(generate_unauthorized_response());
+        }
+
+        let new_review = CreateReview {
+            employment_id,
+            direction: ReviewDirection::WorkerToOrganizer,
+            author_id: current_user.id,
+            overall: payload.overall,
+            punctuality: None,
+            skill: None,
+            organization: Some(payload.organization),
+            communication: Some(payload.communication),
+            comment: payload.comment,
+        };
+        if let Err(errors) = new_review.validate() {
+            return Ok(generate_form_errors_response(errors));
+        }
+
+        let review = match app_state
+            .review_repository
+            .create_review(new_review, OffsetDateTime::now_utc().date())
+            .await
+        {
+            Ok(review) => review,
+            Err(RepositoryError::GenericError(message)) => {
+                return Ok(generate_toast_response(ToastType::Error, message))
+            }
+            Err(err) => return Err(err.into()),
+        };
+
+        let event = app_state
+            .event_repository
+            .get_event_by_id(review.event_id)
+            .await?;
+        app_state
+            .notification_repository
+            .create_notification(CreateNotification {
+                user_id: review.subject_id,
+                message: format!(
+                    "{} {} has reviewed {}",
+                    current_user.first_name, current_user.last_name, event.name
+                ),
+            })
+            .await?;
+
+        Ok(generate_htmx_redirect("/jobs"))
+    }
+}
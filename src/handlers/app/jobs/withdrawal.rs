@@ -0,0 +1,96 @@
+pub mod post {
+    use axum::{
+        extract::{Path, State},
+        response::Response,
+        Form,
+    };
+    use serde::Deserialize;
+    use sqlx::types::time::OffsetDateTime;
+    use validator::Validate;
+
+    use crate::{
+        app::AppState,
+        error::{ApiError, AppError, RepositoryError},
+        handlers::app::auth::AuthSession,
+        models::notification::CreateNotification,
+        templates::ToastType,
+        utils::response_utils::{
+            generate_form_errors_response, generate_htmx_redirect, generate_toast_response,
+            generate_unauthorized_response,
+        },
+    };
+
+    #[derive(Deserialize, Validate)]
+    pub struct WithdrawalForm {
+        #[validate(length(max = 300, message = "Reason is too long. Maximum is 300 characters."))]
+        reason: Option<String>,
+    }
+
+    pub async fn withdrawal(
+        auth_session: AuthSession,
+        State(app_state): State<AppState>,
+        Path(employment_id): Path<i32>,
+        Form(payload): Form<WithdrawalForm>,
+    ) -> Result<Response, AppError> {
+        let current_user = match auth_session.user {
+            Some(user) => user,
+            None => return Err(AppError::from(ApiError::InternalServerError)),
+        };
+        if let Err(errors) = payload.validate() {
+            return Ok(generate_form_errors_response(errors));
+        }
+
+        let employment = app_state
+            .employment_repository
+            .get_employment_by_id(employment_id)
+            .await?;
+        if employment.user_id != current_user.id {
+            return Ok(generate_unauthorized_response());
+        }
+
+        let employment = match app_state
+            .employment_repository
+            .with_actor(current_user.id)
+            .withdraw_employment(
+                employment_id,
+                payload.reason.clone(),
+                OffsetDateTime::now_utc(),
+            )
+            .await
+        {
+            Ok(employment) => employment,
+            Err(RepositoryError::GenericError(message)) => {
+                return Ok(generate_toast_response(ToastType::Error, message))
+            }
+            Err(err) => return Err(err.into()),
+        };
+
+        let job = app_state
+            .job_position_repository
+            .get_job_position_by_id(employment.position_id)
+            .await?;
+        let event = app_state
+            .event_repository
+            .get_event_by_id(job.event_id)
+            .await?;
+        let reason = payload
+            .reason
+            .as_deref()
+            .map(str::trim)
+            .filter(|reason| !reason.is_empty())
+            .map(|reason| format!(": {}", reason))
+            .unwrap_or_default();
+        app_state
+            .notification_repository
+            .create_notification(CreateNotification {
+                user_id: event.owner_id,
+                message: format!(
+                    "{} {} has withdrawn from {} at {}{}",
+                    current_user.first_name, current_user.last_name, job.name, event.name, reason
+                ),
+            })
+            .await?;
+
+        Ok(generate_htmx_redirect("/jobs"))
+    }
+}
@@ -0,0 +1,45 @@
+use crate::handlers::app::auth::AuthSession;
+use askama::Template;
+use axum::{extract::State, response::Html};
+
+use crate::error::AppError;
+
+pub mod get {
+    use crate::{
+        app::AppState,
+        error::ApiError,
+        templates::{ActiveRoute, NotificationsTemplate},
+        view_models::notification::NotificationViewModel,
+    };
+
+    use super::*;
+
+    pub async fn notifications(
+        auth_session: AuthSession,
+        State(app_state): State<AppState>,
+    ) -> Result<Html<String>, AppError> {
+        let current_user = match auth_session.clone().user {
+            Some(user) => user,
+            None => return Err(AppError::from(ApiError::InternalServerError)),
+        };
+        let notifications = app_state
+            .notification_repository
+            .list_notifications(current_user.id)
+            .await?;
+        app_state
+            .notification_repository
+            .mark_notifications_read(current_user.id)
+            .await?;
+
+        let template = NotificationsTemplate {
+            session: auth_session,
+            active_route: Some(ActiveRoute::Notifications),
+            notifications: notifications
+                .into_iter()
+                .map(NotificationViewModel::from)
+                .collect(),
+        };
+        let html = template.render().unwrap();
+        Ok(Html(html))
+    }
+}
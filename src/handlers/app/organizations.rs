@@ -0,0 +1,309 @@
+use crate::handlers::app::auth::AuthSession;
+use askama::Template;
+use axum::{
+    extract::State,
+    response::{Html, Response},
+};
+
+use crate::{
+    app::AppState,
+    error::{ApiError, AppError},
+    models::{
+        organization::{ActiveOrganization, OrganizationRole},
+        user::UserRole,
+    },
+    templates::OrganizationsTemplate,
+    view_models::organization::OrganizationViewModel,
+};
+
+async fn render_organizations(
+    auth_session: AuthSession,
+    app_state: &AppState,
+    active_organization: Option<ActiveOrganization>,
+) -> Result<Html<String>, AppError> {
+    let current_user = match auth_session.clone().user {
+        Some(user) => user,
+        None => return Err(AppError::from(ApiError::InternalServerError)),
+    };
+
+    let active_id = active_organization
+        .as_ref()
+        .map(|organization| organization.id);
+    let mut organizations = Vec::new();
+    if current_user.role == UserRole::Admin {
+        for organization in app_state
+            .organization_repository
+            .list_organizations()
+            .await?
+        {
+            organizations.push(OrganizationViewModel::new(
+                organization,
+                OrganizationRole::Owner,
+                active_id,
+            ));
+        }
+    } else {
+        for organization in app_state
+            .organization_repository
+            .list_user_organizations(current_user.id)
+            .await?
+        {
+            let membership = app_state
+                .organization_repository
+                .get_membership(organization.id, current_user.id)
+                .await?;
+            organizations.push(OrganizationViewModel::new(
+                organization,
+                membership.role,
+                active_id,
+            ));
+        }
+    }
+
+    let template = OrganizationsTemplate {
+        is_admin: current_user.role == UserRole::Admin,
+        session: auth_session,
+        active_route: None,
+        can_manage_members: active_organization
+            .as_ref()
+            .is_some_and(|organization| organization.role == OrganizationRole::Owner),
+        active_organization,
+        organizations,
+        organization_roles: vec![
+            OrganizationRole::Employee,
+            OrganizationRole::Organizer,
+            OrganizationRole::Owner,
+        ],
+    };
+    let html = template.render().unwrap();
+    Ok(Html(html))
+}
+
+pub mod get {
+    use super::*;
+
+    pub async fn organizations(
+        auth_session: AuthSession,
+        State(app_state): State<AppState>,
+        active_organization: Option<ActiveOrganization>,
+    ) -> Result<Html<String>, AppError> {
+        render_organizations(auth_session, &app_state, active_organization).await
+    }
+}
+
+pub mod post {
+    use axum::Form;
+    use serde::Deserialize;
+    use tower_sessions::Session;
+    use validator::Validate;
+
+    use super::*;
+    use crate::{
+        error::RepositoryError,
+        middleware::global::ACTIVE_ORGANIZATION_KEY,
+        models::organization::{CreateOrganization, CreateOrganizationMembership},
+        templates::ToastType,
+        utils::response_utils::{
+            generate_form_errors_response, generate_htmx_redirect, generate_toast_response,
+            generate_unauthorized_response,
+        },
+    };
+
+    #[derive(Deserialize)]
+    pub struct ActiveParams {
+        organization_id: i32,
+    }
+
+    /// Switches the organization the user works in for the rest of the session.
+    pub async fn active(
+        auth_session: AuthSession,
+        session: Session,
+        State(app_state): State<AppState>,
+        Form(params): Form<ActiveParams>,
+    ) -> Result<Response, AppError> {
+        let current_user = match auth_session.clone().user {
+            Some(user) => user,
+            None => return Err(AppError::from(ApiError::InternalServerError)),
+        };
+
+        if current_user.role == UserRole::Admin {
+            app_state
+                .organization_repository
+                .get_organization_by_id(params.organization_id)
+                .await?;
+        } else {
+            app_state
+                .organization_repository
+                .get_membership(params.organization_id, current_user.id)
+                .await?;
+        }
+
+        if session
+            .insert(ACTIVE_ORGANIZATION_KEY, params.organization_id)
+            .await
+            .is_err()
+        {
+            return Err(AppError::from(ApiError::InternalServerError));
+        }
+
+        Ok(generate_htmx_redirect("/"))
+    }
+
+    #[derive(Deserialize, Validate)]
+    pub struct CreateParams {
+        #[validate(length(
+            min = 3,
+            max = 64,
+            message = "Organization name has to be 3 to 64 characters long."
+        ))]
+        name: String,
+    }
+
+    /// Creates a new organization. Only admins may found organizations.
+    pub async fn organizations(
+        auth_session: AuthSession,
+        State(app_state): State<AppState>,
+        Form(params): Form<CreateParams>,
+    ) -> Result<Response, AppError> {
+        let current_user = match auth_session.clone().user {
+            Some(user) => user,
+            None => return Err(AppError::from(ApiError::InternalServerError)),
+        };
+        if current_user.role != UserRole::Admin {
+            return Ok(generate_unauthorized_response());
+        }
+        if let Err(errors) = params.validate() {
+            return Ok(generate_form_errors_response(errors));
+        }
+
+        let result = app_state
+            .organization_repository
+            .create_organization(CreateOrganization {
+                name: params.name.trim().to_string(),
+            })
+            .await;
+        match result {
+            Ok(_) => (),
+            Err(RepositoryError::GenericError(message)) => {
+                return Ok(generate_toast_response(ToastType::Error, message))
+            }
+            Err(err) => return Err(err.into()),
+        }
+
+        Ok(generate_htmx_redirect("/organizations"))
+    }
+
+    #[derive(Deserialize)]
+    pub struct MemberParams {
+        username: String,
+        role: OrganizationRole,
+    }
+
+    /// Adds an existing user to the active organization or changes their role in it.
+    pub async fn members(
+        State(app_state): State<AppState>,
+        active_organization: ActiveOrganization,
+        Form(params): Form<MemberParams>,
+    ) -> Result<Response, AppError> {
+        if active_organization.role != OrganizationRole::Owner {
+            return Ok(generate_unauthorized_response());
+        }
+
+        let user = match app_state
+            .user_repository
+            .get_user_by_username(params.username.trim().to_string())
+            .await
+        {
+            Ok(user) => user,
+            Err(_) => {
+                return Ok(generate_toast_response(
+                    ToastType::Error,
+                    format!("User '{}' does not exist.", params.username.trim()),
+                ))
+            }
+        };
+
+        app_state
+            .organization_repository
+            .create_membership(CreateOrganizationMembership {
+                organization_id: active_organization.id,
+                user_id: user.id,
+                role: params.role,
+            })
+            .await?;
+
+        Ok(generate_toast_response(
+            ToastType::Success,
+            format!(
+                "{} is now a member of {} as {}.",
+                user.username,
+                active_organization.name,
+                params.role.to_string().to_lowercase()
+            ),
+        ))
+    }
+}
+
+pub mod delete {
+    use axum::extract::Query;
+    use serde::Deserialize;
+
+    use super::*;
+    use crate::{
+        error::RepositoryError,
+        templates::ToastType,
+        utils::response_utils::{generate_toast_response, generate_unauthorized_response},
+    };
+
+    #[derive(Deserialize)]
+    pub struct MemberParams {
+        username: String,
+    }
+
+    pub async fn members(
+        State(app_state): State<AppState>,
+        active_organization: ActiveOrganization,
+        Query(params): Query<MemberParams>,
+    ) -> Result<Response, AppError> {
+        if active_organization.role != OrganizationRole::Owner {
+            return Ok(generate_unauthorized_response());
+        }
+
+        let username = params.username.trim().to_string();
+        let user = match app_state
+            .user_repository
+            .get_user_by_username(username.clone())
+            .await
+        {
+            Ok(user) => user,
+            Err(_) => {
+                return Ok(generate_toast_response(
+                    ToastType::Error,
+                    format!("User '{}' does not exist.", username),
+                ))
+            }
+        };
+
+        match app_state
+            .organization_repository
+            .delete_membership(active_organization.id, user.id)
+            .await
+        {
+            Ok(()) => Ok(generate_toast_response(
+                ToastType::Success,
+                format!(
+                    "{} is no longer a member of {}.",
+                    user.username, active_organization.name
+                ),
+            )),
+            Err(RepositoryError::NotFound) => Ok(generate_toast_response(
+                ToastType::Error,
+                format!(
+                    "{} is not a member of {}.",
+                    user.username, active_organization.name
+                ),
+            )),
+            Err(err) => Err(err.into()),
+        }
+    }
+}
@@ -0,0 +1,49 @@
+pub mod post {
+    use axum::{
+        extract::{Path, State},
+        response::Response,
+        Form,
+    };
+    use serde::Deserialize;
+
+    use crate::{
+        app::AppState,
+        error::{ApiError, AppError, RepositoryError},
+        handlers::app::auth::AuthSession,
+        templates::ToastType,
+        utils::response_utils::generate_toast_response,
+    };
+
+    #[derive(Deserialize)]
+    pub struct FlagForm {
+        reason: String,
+    }
+
+    /// Reports a published review to the admins.
+    pub async fn flag(
+        auth_session: AuthSession,
+        State(app_state): State<AppState>,
+        Path(review_id): Path<i32>,
+        Form(payload): Form<FlagForm>,
+    ) -> Result<Response, AppError> {
+        let current_user = match auth_session.user {
+            Some(user) => user,
+            None => return Err(AppError::from(ApiError::InternalServerError)),
+        };
+
+        match app_state
+            .review_repository
+            .flag_review(review_id, current_user.id, payload.reason)
+            .await
+        {
+            Ok(_) => Ok(generate_toast_response(
+                ToastType::Success,
+                "Review was flagged for moderation.".to_string(),
+            )),
+            Err(RepositoryError::GenericError(message)) => {
+                Ok(generate_toast_response(ToastType::Error, message))
+            }
+            Err(err) => Err(err.into()),
+        }
+    }
+}
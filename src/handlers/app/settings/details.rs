@@ -5,7 +5,6 @@ use axum::{extract::State, response::Html};
 use crate::{
     error::AppError,
     regex::{RE_DATE, RE_PHONE_NUMBER},
-    repositories::user::UserRepository,
 };
 
 use axum::response::Response;
@@ -38,6 +37,7 @@ pub mod patch {
         app::AppState,
         error::ApiError,
         models::user::{Gender, PartialUser},
+        storage::validate_image_url,
         templates::ToastType,
         utils::{
             date_utils::parse_date,
@@ -60,7 +60,10 @@ pub mod patch {
         email: Option<String>,
         #[validate(regex(path = *RE_PHONE_NUMBER, message = "Phone is not in the correct format."))]
         phone: Option<String>,
-        #[validate(url(message = "Avatar URL is not in the correct format."))]
+        #[validate(custom(
+            function = "validate_image_url",
+            message = "Avatar URL is not in the correct format."
+        ))]
         avatar_url: Option<String>,
     }
 
@@ -83,6 +86,7 @@ pub mod patch {
         };
         let _updated_user = app_state
             .user_repository
+            .with_actor(current_user.id)
             .update_user(
                 current_user.id,
                 PartialUser {
@@ -2,7 +2,7 @@ use crate::handlers::app::auth::AuthSession;
 use askama::Template;
 use axum::{extract::State, response::Html};
 
-use crate::{error::AppError, repositories::user::UserRepository};
+use crate::error::AppError;
 
 pub mod get {
 
@@ -89,6 +89,7 @@ pub mod patch {
         };
         let _updated_user = app_state
             .user_repository
+            .with_actor(current_user.id)
             .update_user(
                 current_user.id,
                 PartialUser {
@@ -0,0 +1,193 @@
+use axum::{extract::Multipart, http::StatusCode};
+
+use crate::{
+    app::AppState,
+    error::{AppError, UploadError},
+    storage::upload::{process_image, store_image, validate_upload, ImageKind},
+};
+
+/// Reads the `image` field of the form, validates it and stores it resized. Returns the URL
+/// of the stored image, or the reason the upload was rejected.
+async fn upload_image(
+    app_state: &AppState,
+    mut multipart: Multipart,
+    kind: ImageKind,
+) -> Result<Result<String, UploadError>, AppError> {
+    let limits = app_state.upload_limits;
+    let too_large = UploadError::TooLarge(limits.max_megabytes());
+
+    let mut field = loop {
+        match multipart.next_field().await {
+            Ok(Some(field)) if field.name() == Some("image") => break field,
+            Ok(Some(_)) => continue,
+            Ok(None) => return Ok(Err(UploadError::MissingFile)),
+            Err(err) if err.status() == StatusCode::PAYLOAD_TOO_LARGE => return Ok(Err(too_large)),
+            Err(err) => return Err(err.into()),
+        }
+    };
+    let content_type = field.content_type().map(str::to_string);
+    let mut bytes = Vec::new();
+    loop {
+        match field.chunk().await {
+            Ok(Some(chunk)) if bytes.len() + chunk.len() > limits.max_bytes => {
+                return Ok(Err(too_large))
+            }
+            Ok(Some(chunk)) => bytes.extend_from_slice(&chunk),
+            Ok(None) => break,
+            Err(err) if err.status() == StatusCode::PAYLOAD_TOO_LARGE => return Ok(Err(too_large)),
+            Err(err) => return Err(err.into()),
+        }
+    }
+
+    let format = match validate_upload(content_type.as_deref(), &bytes, &limits) {
+        Ok(format) => format,
+        Err(err) => return Ok(Err(err)),
+    };
+    let processed =
+        match tokio::task::spawn_blocking(move || process_image(&bytes, format, kind)).await? {
+            Ok(processed) => processed,
+            Err(err) => return Ok(Err(err)),
+        };
+    let url = store_image(app_state.blob_store.as_ref(), kind, processed).await?;
+    Ok(Ok(url))
+}
+
+pub mod get {
+    use axum::{
+        body::Body,
+        extract::{Path, State},
+        http::{header, StatusCode},
+        response::{IntoResponse, Response},
+    };
+
+    use crate::{
+        app::AppState,
+        error::{AppError, StorageError},
+    };
+
+    pub async fn upload(
+        State(app_state): State<AppState>,
+        Path(key): Path<String>,
+    ) -> Result<Response, AppError> {
+        match app_state.blob_store.get(&key).await {
+            Ok(blob) => Ok(Response::builder()
+                .header(header::CONTENT_TYPE, blob.content_type)
+                .header(header::X_CONTENT_TYPE_OPTIONS, "nosniff")
+                // Every upload gets a fresh key, so the content behind a URL never changes.
+                .header(header::CACHE_CONTROL, "public, max-age=31536000, immutable")
+                .body(Body::from(blob.bytes))
+                .unwrap()),
+            Err(StorageError::NotFound) => Ok(StatusCode::NOT_FOUND.into_response()),
+            Err(err) => Err(err.into()),
+        }
+    }
+}
+
+pub mod post {
+    use axum::{
+        extract::{Multipart, Path, State},
+        response::Response,
+    };
+
+    use super::upload_image;
+    use crate::{
+        app::AppState,
+        error::{ApiError, AppError},
+        handlers::app::auth::AuthSession,
+        models::{event::PartialEvent, user::PartialUser},
+        policy::EventAction,
+        storage::upload::ImageKind,
+        templates::ToastType,
+        utils::response_utils::{
+            generate_htmx_redirect, generate_toast_response, generate_unauthorized_response,
+        },
+    };
+
+    /// Replaces the current user's avatar with an uploaded image.
+    pub async fn avatar(
+        auth_session: AuthSession,
+        State(app_state): State<AppState>,
+        multipart: Multipart,
+    ) -> Result<Response, AppError> {
+        let current_user = match auth_session.user {
+            Some(user) => user,
+            None => return Err(AppError::from(ApiError::InternalServerError)),
+        };
+
+        let avatar_url = match upload_image(&app_state, multipart, ImageKind::Avatar).await? {
+            Ok(url) => url,
+            Err(err) => return Ok(generate_toast_response(ToastType::Error, err.to_string())),
+        };
+        app_state
+            .user_repository
+            .with_actor(current_user.id)
+            .update_user(
+                current_user.id,
+                PartialUser {
+                    first_name: None,
+                    last_name: None,
+                    username: None,
+                    gender: None,
+                    birth_date: None,
+                    email: None,
+                    phone: None,
+                    password_hash: None,
+                    role: None,
+                    tax_rate: None,
+                    avatar_url: Some(avatar_url),
+                },
+            )
+            .await?;
+
+        Ok(generate_htmx_redirect("/settings/details"))
+    }
+
+    /// Replaces the event's hero image with an uploaded one.
+    pub async fn event_hero(
+        auth_session: AuthSession,
+        State(app_state): State<AppState>,
+        Path(event_id): Path<i32>,
+        multipart: Multipart,
+    ) -> Result<Response, AppError> {
+        let current_user = match auth_session.user {
+            Some(user) => user,
+            None => return Err(AppError::from(ApiError::InternalServerError)),
+        };
+
+        let event = app_state.event_repository.get_event_by_id(event_id).await?;
+        if !app_state
+            .event_policy
+            .is_authorized(&current_user, &event, EventAction::EditEvent)
+            .await?
+        {
+            return Ok(generate_unauthorized_response());
+        }
+
+        let img_url = match upload_image(&app_state, multipart, ImageKind::EventHero).await? {
+            Ok(url) => url,
+            Err(err) => return Ok(generate_toast_response(ToastType::Error, err.to_string())),
+        };
+        app_state
+            .event_repository
+            .with_actor(current_user.id)
+            .update_event(
+                event_id,
+                PartialEvent {
+                    name: None,
+                    date_start: None,
+                    date_end: None,
+                    img_url: Some(img_url),
+                    description: None,
+                    venue_id: None,
+                    owner_id: None,
+                    publish_at: None,
+                    withdrawal_cutoff_hours: None,
+                },
+            )
+            .await?;
+
+        Ok(generate_htmx_redirect(&format!(
+            "/manage/events/{event_id}"
+        )))
+    }
+}
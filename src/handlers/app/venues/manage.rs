@@ -8,32 +8,48 @@ pub mod get {
     use super::*;
     use crate::{
         app::AppState,
-        models::venue::{self},
-        repositories::venue::VenueRepository,
+        models::{
+            organization::ActiveOrganization,
+            pagination::ListParams,
+            venue::{self, SortColumn},
+        },
         templates::ManageVenuesTemplate,
+        view_models::pagination::PaginationViewModel,
     };
+    use axum::extract::Query;
 
     pub async fn manage(
+        Query(params): Query<ListParams<SortColumn>>,
         auth_session: AuthSession,
         State(app_state): State<AppState>,
+        active_organization: ActiveOrganization,
     ) -> Result<Html<String>, AppError> {
-        let venues = app_state
+        let page = app_state
             .venue_repository
-            .list_venues(venue::SelectManyFilter {
-                name: None,
-                description: None,
-                state: None,
-                postal_code: None,
-                town: None,
-                street_name: None,
-                street_number: None,
-            })
+            .list_venues_page(
+                venue::SelectManyFilter {
+                    organization_id: Some(active_organization.id),
+                    name: None,
+                    description: None,
+                    state: None,
+                    postal_code: None,
+                    town: None,
+                    street_name: None,
+                    street_number: None,
+                },
+                &params.spec(),
+            )
             .await?;
+        let pagination = PaginationViewModel::new(
+            &page,
+            Some(format!("/manage/venues?{}", params.sort_query())),
+        );
 
         let template = ManageVenuesTemplate {
             session: auth_session,
             active_route: Some(crate::templates::ActiveRoute::Manage),
-            venues,
+            venues: page.items,
+            pagination,
         };
         let html = template.render().unwrap();
         Ok(Html(html))
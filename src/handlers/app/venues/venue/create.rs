@@ -26,9 +26,8 @@ pub mod post {
     use crate::{
         app::AppState,
         error::ApiError,
-        models::venue::CreateVenue,
+        models::{organization::ActiveOrganization, venue::CreateVenue},
         regex::{RE_POSTAL_CODE, RE_STREET_NUMBER},
-        repositories::venue::VenueRepository,
         utils::response_utils::{generate_form_errors_response, generate_htmx_redirect},
     };
     use axum::{response::Response, Form};
@@ -79,9 +78,10 @@ pub mod post {
     pub async fn create(
         auth_session: AuthSession,
         State(app_state): State<AppState>,
+        active_organization: ActiveOrganization,
         params: Form<Params>,
     ) -> Result<Response, AppError> {
-        let _current_user = match auth_session.clone().user {
+        let current_user = match auth_session.clone().user {
             Some(user) => user,
             None => return Err(AppError::from(ApiError::InternalServerError)),
         };
@@ -92,6 +92,7 @@ pub mod post {
 
         let _new_venue = app_state
             .venue_repository
+            .with_actor(current_user.id)
             .create_venue(CreateVenue {
                 name: params.venue_name.clone(),
                 description: params.description.clone(),
@@ -101,6 +102,7 @@ pub mod post {
                 street_name: params.street_name.clone(),
                 street_number: params.street_number.clone(),
                 address_url: params.address_url.clone(),
+                organization_id: active_organization.id,
             })
             .await?;
 
@@ -5,21 +5,26 @@ use axum::{
     response::{Html, Response},
 };
 
-use crate::error::AppError;
+use crate::{
+    error::{ApiError, AppError},
+    models::organization::ActiveOrganization,
+};
 
 pub mod get {
     use super::*;
-    use crate::{
-        app::AppState, repositories::venue::VenueRepository, templates::ManageVenueTemplate,
-    };
+    use crate::{app::AppState, templates::ManageVenueTemplate};
     use axum::extract::Path;
 
     pub async fn manage(
         Path(venue_id): Path<i32>,
         auth_session: AuthSession,
         State(app_state): State<AppState>,
+        active_organization: ActiveOrganization,
     ) -> Result<Html<String>, AppError> {
         let venue = app_state.venue_repository.get_venue_by_id(venue_id).await?;
+        if venue.organization_id != active_organization.id {
+            return Err(AppError::from(ApiError::NotFound));
+        }
 
         let template = ManageVenueTemplate {
             session: auth_session,
@@ -37,7 +42,6 @@ pub mod patch {
         app::AppState,
         models::venue::PartialVenue,
         regex::{RE_POSTAL_CODE, RE_STREET_NUMBER},
-        repositories::venue::VenueRepository,
         utils::response_utils::{generate_form_errors_response, generate_htmx_redirect},
     };
     use axum::Form;
@@ -87,17 +91,31 @@ pub mod patch {
     }
 
     pub async fn manage(
-        _auth_session: AuthSession,
+        auth_session: AuthSession,
         State(app_state): State<AppState>,
+        active_organization: ActiveOrganization,
         params: Form<Params>,
     ) -> Result<Response, AppError> {
+        let current_user = match auth_session.user {
+            Some(user) => user,
+            None => return Err(AppError::from(ApiError::InternalServerError)),
+        };
+
         match params.validate() {
             Ok(_) => (),
             Err(errors) => return Ok(generate_form_errors_response(errors)),
         };
+        let venue = app_state
+            .venue_repository
+            .get_venue_by_id(params.venue_id)
+            .await?;
+        if venue.organization_id != active_organization.id {
+            return Err(AppError::from(ApiError::NotFound));
+        }
 
         let _updated_venue = app_state
             .venue_repository
+            .with_actor(current_user.id)
             .update_venue(
                 params.venue_id,
                 PartialVenue {
@@ -121,7 +139,6 @@ pub mod delete {
     use super::*;
     use crate::{
         app::AppState,
-        repositories::venue::VenueRepository,
         templates::{ToastTemplate, ToastType},
     };
     use askama_axum::IntoResponse;
@@ -129,10 +146,25 @@ pub mod delete {
 
     pub async fn manage(
         Path(venue_id): Path<i32>,
-        _auth_session: AuthSession,
+        auth_session: AuthSession,
         State(app_state): State<AppState>,
+        active_organization: ActiveOrganization,
     ) -> Result<Response, AppError> {
-        match app_state.venue_repository.delete_venue(venue_id).await {
+        let current_user = match auth_session.user {
+            Some(user) => user,
+            None => return Err(AppError::from(ApiError::InternalServerError)),
+        };
+        let venue = app_state.venue_repository.get_venue_by_id(venue_id).await?;
+        if venue.organization_id != active_organization.id {
+            return Err(AppError::from(ApiError::NotFound));
+        }
+
+        match app_state
+            .venue_repository
+            .with_actor(current_user.id)
+            .delete_venue(venue_id)
+            .await
+        {
             Ok(_) => Ok(Response::new("".into())),
             Err(_) => {
                 let template = ToastTemplate {
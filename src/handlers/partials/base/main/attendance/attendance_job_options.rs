@@ -9,10 +9,7 @@ pub mod get {
 
     use serde::Deserialize;
 
-    use crate::{
-        app::AppState, error::ApiError, repositories::job_position::JobPositionRepository,
-        templates::AttendanceJobOptionsTemplate,
-    };
+    use crate::{app::AppState, error::ApiError, templates::AttendanceJobOptionsTemplate};
 
     use super::*;
 
@@ -21,10 +21,6 @@ pub mod get {
             employment::{self, EmploymentState},
             worked_hours::{self, WorkedHours},
         },
-        repositories::{
-            employment::EmploymentRepository, event::EventRepository,
-            job_position::JobPositionRepository, worked_hours::WorkedHoursRepository,
-        },
         templates::AttendanceLogTemplate,
         utils::date_utils::from_date_range_to_vec,
     };
@@ -58,10 +54,15 @@ pub mod get {
         let employment = app_state
             .employment_repository
             .list_employment(employment::SelectManyFilter {
+                organization_id: None,
                 position_id: Some(params.job_position_id),
                 user_id: Some(current_user.id),
                 state: Some(EmploymentState::Accepted),
                 rating: None,
+                event_name_contains: None,
+                job_name_contains: None,
+                employee_name_contains: None,
+                managed_by: None,
             })
             .await?;
         let employment = employment.first();
@@ -114,7 +115,6 @@ pub mod patch {
             employment::{self},
             worked_hours::{CreateWorkedHours, PartialWorkedHours},
         },
-        repositories::{employment::EmploymentRepository, worked_hours::WorkedHoursRepository},
         templates::{HoursWorkedInputTemplate, ToastType},
         utils::response_utils::{
             generate_form_errors_response, generate_toast_response, generate_unauthorized_response,
@@ -157,10 +157,15 @@ pub mod patch {
         let user_employments = app_state
             .employment_repository
             .list_employment(employment::SelectManyFilter {
+                organization_id: None,
                 position_id: None,
                 user_id: Some(current_user.id),
                 state: None,
                 rating: None,
+                event_name_contains: None,
+                job_name_contains: None,
+                employee_name_contains: None,
+                managed_by: None,
             })
             .await?;
         if !user_employments
@@ -1 +1,3 @@
-pub mod employments_action;
\ No newline at end of file
+pub mod attendance;
+pub mod employments_action;
+pub mod review;
\ No newline at end of file
@@ -0,0 +1,136 @@
+pub mod post {
+    use askama::Template;
+    use askama_axum::IntoResponse;
+    use axum::{
+        extract::{Path, State},
+        response::{Html, Response},
+        Form,
+    };
+    use serde::Deserialize;
+    use validator::Validate;
+
+    use crate::{
+        app::AppState,
+        error::{ApiError, AppError, RepositoryError},
+        handlers::app::auth::AuthSession,
+        models::{
+            attendance::{AttendanceOutcome, RecordAttendance},
+            notification::CreateNotification,
+        },
+        policy::{EventAction, EventScope},
+        templates::{EmploymentAttendanceTemplate, ToastType},
+        utils::{
+            date_utils::parse_date,
+            response_utils::{
+                generate_form_errors_response, generate_toast_response,
+                generate_unauthorized_response,
+            },
+        },
+        view_models::employments::{AttendanceRecordViewModel, EmploymentAttendanceViewModel},
+    };
+
+    #[derive(Deserialize)]
+    pub struct AttendanceForm {
+        date: String,
+        outcome: AttendanceOutcome,
+        note: Option<String>,
+    }
+
+    pub async fn attendance(
+        auth_session: AuthSession,
+        State(app_state): State<AppState>,
+        Path(employment_id): Path<i32>,
+        Form(payload): Form<AttendanceForm>,
+    ) -> Result<Response, AppError> {
+        let current_user = match auth_session.user {
+            Some(user) => user,
+            None => return Err(AppError::from(ApiError::InternalServerError)),
+        };
+        if !app_state
+            .event_policy
+            .authorize(
+                &current_user,
+                EventScope::Employment(employment_id),
+                EventAction::ApproveHours,
+            )
+            .await?
+        {
+            return Ok(generate_unauthorized_response());
+        }
+
+        let date = match parse_date(&payload.date) {
+            Ok(date) => date,
+            Err(_) => {
+                return Ok(generate_toast_response(
+                    ToastType::Error,
+                    "Invalid attendance date".to_string(),
+                ))
+            }
+        };
+        let record = RecordAttendance {
+            employment_id,
+            date,
+            outcome: payload.outcome,
+            note: payload.note,
+        };
+        if let Err(errors) = record.validate() {
+            return Ok(generate_form_errors_response(errors));
+        }
+
+        let record = match app_state
+            .attendance_repository
+            .with_actor(current_user.id)
+            .record_attendance(record)
+            .await
+        {
+            Ok(record) => record,
+            Err(RepositoryError::GenericError(message)) => {
+                return Ok(generate_toast_response(ToastType::Error, message))
+            }
+            Err(err) => return Err(err.into()),
+        };
+
+        let employment = app_state
+            .employment_repository
+            .get_employment_by_id(employment_id)
+            .await?;
+        let job = app_state
+            .job_position_repository
+            .get_job_position_by_id(employment.position_id)
+            .await?;
+        let event = app_state
+            .event_repository
+            .get_event_by_id(job.event_id)
+            .await?;
+        if record.outcome == AttendanceOutcome::NoShow {
+            app_state
+                .notification_repository
+                .create_notification(CreateNotification {
+                    user_id: employment.user_id,
+                    message: format!(
+                        "You were recorded as a no-show for {} at {} on {}",
+                        job.name, event.name, record.date
+                    ),
+                })
+                .await?;
+        }
+
+        let records = app_state
+            .attendance_repository
+            .list_attendance(&[employment_id])
+            .await?;
+        let template = EmploymentAttendanceTemplate {
+            attendance: EmploymentAttendanceViewModel {
+                employment_id,
+                date_start: event.date_start,
+                date_end: event.date_end,
+                records: records
+                    .into_iter()
+                    .map(AttendanceRecordViewModel::from)
+                    .collect(),
+            },
+        };
+        let html = template.render().unwrap();
+        Ok(Html(html).into_response())
+    }
+}
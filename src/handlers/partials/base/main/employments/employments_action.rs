@@ -1,20 +1,22 @@
 pub mod post {
     use crate::app::AppState;
-    use crate::error::{ApiError, AppError};
+    use crate::error::{ApiError, AppError, RepositoryError};
     use crate::handlers::app::auth::AuthSession;
-    use crate::handlers::app::employments::filter_and_sort_employments;
-    use crate::handlers::app::employments::post::SortColumn;
-    use crate::models::employment::{EmploymentState, PartialEmployment, SelectManyFilter};
-    use crate::repositories::employment::EmploymentRepository;
-    use crate::repositories::job_position::JobPositionRepository;
-    use crate::templates::{EmploymentsTableTemplate, ToastTemplate};
-    use crate::utils::table_utils::{optional_filter, parse_filter, SortDirection};
+    use crate::handlers::app::employments::{employments_page, TableFilters};
+    use crate::models::employment::{
+        BulkOrder, EmploymentState, PartialEmployment, SelectManyFilter, SkippedEmployment,
+        SortColumn,
+    };
+    use crate::models::organization::ActiveOrganization;
+    use crate::models::pagination::SortDirection;
+    use crate::policy::{EventAction, EventScope};
+    use crate::templates::{EmploymentsTableTemplate, ToastTemplate, ToastType};
+    use crate::utils::response_utils::{generate_toast_response, generate_unauthorized_response};
     use askama_axum::Template;
     use axum::extract::State;
     use axum::response::{Html, IntoResponse, Response};
     use axum::Form;
     use serde::Deserialize;
-    use std::str::FromStr;
 
     #[derive(Deserialize)]
     pub struct Method {
@@ -28,6 +30,7 @@ pub mod post {
         employee_name: String,
         sort_by: Option<SortColumn>,
         sort_direction: Option<SortDirection>,
+        page: Option<i64>,
     }
 
     async fn get_position_details(
@@ -60,10 +63,15 @@ pub mod post {
         position_id: i32,
     ) -> Result<usize, ApiError> {
         let employments_filter = SelectManyFilter {
+            organization_id: None,
             position_id: Some(position_id),
             user_id: None,
             state: None,
             rating: None,
+            event_name_contains: None,
+            job_name_contains: None,
+            employee_name_contains: None,
+            managed_by: None,
         };
 
         let employments = app_state
@@ -87,65 +95,89 @@ pub mod post {
         update_values: PartialEmployment,
         Form(payload): Form<Method>,
         current_user_id: i32,
+        organization_id: i32,
     ) -> Result<Response, ApiError> {
-        app_state
+        match app_state
             .employment_repository
+            .with_actor(current_user_id)
             .update_employment(employment_id, update_values)
             .await
-            .map_err(|err| {
+        {
+            Ok(_) => (),
+            Err(RepositoryError::GenericError(message)) => {
+                return Ok(generate_toast_response(ToastType::Error, message))
+            }
+            Err(err) => {
                 eprintln!("Failed to update employment: {:?}", err);
-                ApiError::NotFound
-            })?;
+                return Err(ApiError::NotFound);
+            }
+        }
 
-        let employment_state = parse_filter(
-            payload.state.as_str(),
-            |state| EmploymentState::from_str(state).map_err(|_| ApiError::NotFound),
-            "All states",
-        )?;
-        let event_filter = optional_filter(payload.event);
-        let job_name_filter = optional_filter(payload.job_name);
-        let employee_filter = optional_filter(payload.employee_name);
-        let sort_by = payload.sort_by.unwrap_or_default();
-        let sort_direction = payload.sort_direction.unwrap_or_default();
-
-        let employments_viewmodels =
-            crate::handlers::app::employments::generate_employment_viewmodels(
-                current_user_id,
-                app_state,
-            )
-            .await
-            .map_err(|_| ApiError::InternalServerError)?;
-
-        let sorted_filtered_employments = filter_and_sort_employments(
-            employments_viewmodels,
-            employment_state,
-            event_filter,
-            job_name_filter,
-            employee_filter,
-            sort_by,
-            sort_direction,
-        );
+        let html = render_employments_table(
+            app_state,
+            TableFilters {
+                state: payload.state,
+                event: payload.event,
+                job_name: payload.job_name,
+                employee_name: payload.employee_name,
+                sort_by: payload.sort_by,
+                sort_direction: payload.sort_direction,
+                page: payload.page,
+            },
+            current_user_id,
+            organization_id,
+        )
+        .await?;
+        Ok(Html(html).into_response())
+    }
+
+    async fn render_employments_table(
+        app_state: &AppState,
+        filters: TableFilters,
+        current_user_id: i32,
+        organization_id: i32,
+    ) -> Result<String, ApiError> {
+        let (employments, pagination) =
+            employments_page(current_user_id, organization_id, filters, app_state)
+                .await
+                .map_err(|_| ApiError::InternalServerError)?;
 
         let template = EmploymentsTableTemplate {
-            employments: sorted_filtered_employments,
+            employments,
+            pagination,
         };
 
-        Ok(template
-            .render()
-            .map(Html)
-            .map_err(|_| ApiError::NotFound)
-            .into_response())
+        template.render().map_err(|_| ApiError::NotFound)
     }
 
     pub async fn action(
         auth_session: AuthSession,
         State(app_state): State<AppState>,
+        active_organization: ActiveOrganization,
         Form(payload): Form<Method>,
     ) -> Result<Response, AppError> {
         let current_user = auth_session
             .user
             .ok_or_else(|| AppError::from(ApiError::InternalServerError))?;
 
+        // Accepting and rejecting applicants is separate from confirming finished work.
+        let action = match payload.method.as_str() {
+            "Accept" | "Reject" => EventAction::ManageApplicants,
+            "Finish" | "Rating" => EventAction::ApproveHours,
+            _ => return Err(AppError::from(ApiError::NotFound)),
+        };
+        if !app_state
+            .event_policy
+            .authorize(
+                &current_user,
+                EventScope::Employment(payload.employment_id),
+                action,
+            )
+            .await?
+        {
+            return Ok(generate_unauthorized_response());
+        }
+
         let position = get_position_details(&app_state, payload.employment_id).await?;
         let current_job_capacity = get_current_job_capacity(&app_state, position.id).await?;
 
@@ -200,8 +232,202 @@ pub mod post {
             update_values,
             Form(payload),
             current_user.id,
+            active_organization.id,
         )
         .await
         .map_err(AppError::from)
     }
+
+    #[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+    #[serde(rename_all = "snake_case")]
+    pub enum BulkMethod {
+        AcceptByRating,
+        AcceptByApplicationTime,
+        Reject,
+        Finish,
+        RejectRemaining,
+    }
+
+    #[derive(Deserialize)]
+    pub struct BulkPayload {
+        method: BulkMethod,
+        /// Comma separated ids of the selected employments.
+        employment_ids: String,
+        state: String,
+        event: String,
+        job_name: String,
+        employee_name: String,
+        sort_by: Option<SortColumn>,
+        sort_direction: Option<SortDirection>,
+        page: Option<i64>,
+    }
+
+    pub async fn bulk_action(
+        auth_session: AuthSession,
+        State(app_state): State<AppState>,
+        active_organization: ActiveOrganization,
+        Form(payload): Form<BulkPayload>,
+    ) -> Result<Response, AppError> {
+        let current_user = auth_session
+            .user
+            .ok_or_else(|| AppError::from(ApiError::InternalServerError))?;
+
+        let selected: Vec<i32> = payload
+            .employment_ids
+            .split(',')
+            .filter_map(|id| id.trim().parse().ok())
+            .collect();
+        if selected.is_empty() {
+            return Ok(generate_toast_response(
+                ToastType::Error,
+                "No employments are selected.".to_string(),
+            ));
+        }
+
+        let (action, state, order) = match payload.method {
+            BulkMethod::AcceptByRating => (
+                EventAction::ManageApplicants,
+                EmploymentState::Accepted,
+                BulkOrder::Rating,
+            ),
+            BulkMethod::AcceptByApplicationTime => (
+                EventAction::ManageApplicants,
+                EmploymentState::Accepted,
+                BulkOrder::ApplicationTime,
+            ),
+            BulkMethod::Reject | BulkMethod::RejectRemaining => (
+                EventAction::ManageApplicants,
+                EmploymentState::Rejected,
+                BulkOrder::ApplicationTime,
+            ),
+            BulkMethod::Finish => (
+                EventAction::ApproveHours,
+                EmploymentState::Done,
+                BulkOrder::ApplicationTime,
+            ),
+        };
+
+        let mut skipped = Vec::new();
+        let mut employment_ids = Vec::new();
+        if payload.method == BulkMethod::RejectRemaining {
+            let mut positions: Vec<(i32, Vec<i32>)> = Vec::new();
+            for employment_id in selected {
+                match app_state
+                    .employment_repository
+                    .get_employment_by_id(employment_id)
+                    .await
+                {
+                    Ok(employment) => match positions
+                        .iter_mut()
+                        .find(|(position_id, _)| *position_id == employment.position_id)
+                    {
+                        Some((_, employment_ids)) => employment_ids.push(employment_id),
+                        None => positions.push((employment.position_id, vec![employment_id])),
+                    },
+                    Err(_) => skipped.push(SkippedEmployment {
+                        employment_id,
+                        reason: "The employment does not exist".to_string(),
+                    }),
+                }
+            }
+            for (position_id, selected_ids) in positions {
+                if !app_state
+                    .event_policy
+                    .authorize(&current_user, EventScope::JobPosition(position_id), action)
+                    .await?
+                {
+                    skipped.extend(selected_ids.into_iter().map(|employment_id| {
+                        SkippedEmployment {
+                            employment_id,
+                            reason: "You are not allowed to change it".to_string(),
+                        }
+                    }));
+                    continue;
+                }
+                let pending = app_state
+                    .employment_repository
+                    .list_employment(SelectManyFilter {
+                        organization_id: None,
+                        position_id: Some(position_id),
+                        user_id: None,
+                        state: Some(EmploymentState::Pending),
+                        rating: None,
+                        event_name_contains: None,
+                        job_name_contains: None,
+                        employee_name_contains: None,
+                        managed_by: None,
+                    })
+                    .await?;
+                employment_ids.extend(pending.into_iter().map(|employment| employment.id));
+            }
+        } else {
+            for employment_id in selected {
+                if app_state
+                    .event_policy
+                    .authorize(&current_user, EventScope::Employment(employment_id), action)
+                    .await?
+                {
+                    employment_ids.push(employment_id);
+                } else {
+                    skipped.push(SkippedEmployment {
+                        employment_id,
+                        reason: "You are not allowed to change it".to_string(),
+                    });
+                }
+            }
+        }
+
+        let result = app_state
+            .employment_repository
+            .with_actor(current_user.id)
+            .bulk_update_state(&employment_ids, state, order)
+            .await?;
+        skipped.extend(result.skipped);
+
+        let mut message = format!("Updated {} employment(s).", result.updated.len());
+        if !skipped.is_empty() {
+            let reasons: Vec<String> = skipped
+                .iter()
+                .map(|skipped| format!("#{}: {}", skipped.employment_id, skipped.reason))
+                .collect();
+            message.push_str(&format!(
+                " Skipped {}: {}",
+                skipped.len(),
+                reasons.join("; ")
+            ));
+        }
+        let toast = ToastTemplate {
+            toast_type: if result.updated.is_empty() && !skipped.is_empty() {
+                ToastType::Error
+            } else {
+                ToastType::Success
+            },
+            message,
+        }
+        .render()
+        .map_err(|_| AppError::from(ApiError::InternalServerError))?;
+
+        let table = render_employments_table(
+            &app_state,
+            TableFilters {
+                state: payload.state,
+                event: payload.event,
+                job_name: payload.job_name,
+                employee_name: payload.employee_name,
+                sort_by: payload.sort_by,
+                sort_direction: payload.sort_direction,
+                page: payload.page,
+            },
+            current_user.id,
+            active_organization.id,
+        )
+        .await?;
+
+        // The summary is swapped into the global toast next to the refreshed table.
+        Ok(Html(format!(
+            r#"{}<div id="global-toast" hx-swap-oob="true" class="absolute bottom-4 right-4 z-50">{}</div>"#,
+            table, toast
+        ))
+        .into_response())
+    }
 }
@@ -0,0 +1,105 @@
+pub mod post {
+    use axum::{
+        extract::{Path, State},
+        response::Response,
+        Form,
+    };
+    use serde::Deserialize;
+    use sqlx::types::time::OffsetDateTime;
+    use validator::Validate;
+
+    use crate::{
+        app::AppState,
+        error::{ApiError, AppError, RepositoryError},
+        handlers::app::auth::AuthSession,
+        models::{
+            notification::CreateNotification,
+            review::{CreateReview, ReviewDirection},
+        },
+        policy::{EventAction, EventScope},
+        templates::ToastType,
+        utils::response_utils::{
+            generate_form_errors_response, generate_toast_response, generate_unauthorized_response,
+        },
+    };
+
+    #[derive(Deserialize)]
+    pub struct WorkerReviewForm {
+        overall: i32,
+        punctuality: i32,
+        skill: i32,
+        #[serde(default)]
+        comment: String,
+    }
+
+    /// Organizer's review of the worker who finished the job.
+    pub async fn review(
+        auth_session: AuthSession,
+        State(app_state): State<AppState>,
+        Path(employment_id): Path<i32>,
+        Form(payload): Form<WorkerReviewForm>,
+    ) -> Result<Response, AppError> {
+        let current_user = match auth_session.user {
+            Some(user) => user,
+            None => return Err(AppError::from(ApiError::InternalServerError)),
+        };
+        if !app_state
+            .event_policy
+            .authorize(
+                &current_user,
+                EventScope::Employment(employment_id),
+                EventAction::ApproveHours,
+            )
+            .await?
+        {
+            return Ok(generate_unauthorized_response());
+        }
+
+        let new_review = CreateReview {
+            employment_id,
+            direction: ReviewDirection::OrganizerToWorker,
+            author_id: current_user.id,
+            overall: payload.overall,
+            punctuality: Some(payload.punctuality),
+            skill: Some(payload.skill),
+            organization: None,
+            communication: None,
+            comment: payload.comment,
+        };
+        if let Err(errors) = new_review.validate() {
+            return Ok(generate_form_errors_response(errors));
+        }
+
+        let review = match app_state
+            .review_repository
+            .create_review(new_review, OffsetDateTime::now_utc().date())
+            .await
+        {
+            Ok(review) => review,
+            Err(RepositoryError::GenericError(message)) => {
+                return Ok(generate_toast_response(ToastType::Error, message))
+            }
+            Err(err) => return Err(err.into()),
+        };
+
+        let event = app_state
+            .event_repository
+            .get_event_by_id(review.event_id)
+            .await?;
+        app_state
+            .notification_repository
+            .create_notification(CreateNotification {
+                user_id: review.subject_id,
+                message: format!(
+                    "You were reviewed for your work at {}. Review the event to see it right away",
+                    event.name
+                ),
+            })
+            .await?;
+
+        Ok(generate_toast_response(
+            ToastType::Success,
+            "Review was saved, it is published once the worker reviews the event too.".to_string(),
+        ))
+    }
+}
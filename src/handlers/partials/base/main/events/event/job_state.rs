@@ -9,19 +9,17 @@ use askama_axum::IntoResponse;
 pub mod post {
     use axum::extract::Path;
     use axum::response::Response;
+    use sqlx::types::time::OffsetDateTime;
 
     use crate::{
         app::AppState,
+        error::RepositoryError,
         models::{
             employment::{CreateEmployment, EmploymentState},
             job_position::JobPositionViewModel,
         },
-        repositories::{
-            employment::EmploymentRepository, event::EventRepository,
-            job_position::JobPositionRepository, position_category::PositionCategoryRepository,
-        },
-        templates::JobStateTemplate,
-        utils::date_utils::is_date_in_past,
+        templates::{JobStateTemplate, ToastType},
+        utils::{date_utils::is_date_in_past, response_utils::generate_toast_response},
     };
 
     use super::*;
@@ -32,27 +30,61 @@ pub mod post {
         State(app_state): State<AppState>,
     ) -> Result<Response, AppError> {
         let current_user_id = auth_session.clone().user.map(|user| user.id);
-        let new_employment = app_state
+        let job_position = app_state
+            .job_position_repository
+            .get_job_position_by_id(job_id)
+            .await?;
+        let event = app_state
+            .event_repository
+            .get_event_by_id(job_position.event_id)
+            .await?;
+
+        if app_state.no_show_block.limit.is_some() {
+            let recent_no_shows = app_state
+                .attendance_repository
+                .count_no_shows_since(
+                    current_user_id.unwrap_or(-1),
+                    Some(event.organization_id),
+                    app_state
+                        .no_show_block
+                        .period_start(OffsetDateTime::now_utc().date()),
+                )
+                .await?;
+            if app_state.no_show_block.is_blocked(recent_no_shows) {
+                return Ok(generate_toast_response(
+                    ToastType::Error,
+                    format!(
+                        "You cannot apply for jobs after {} no-shows in the last {} days",
+                        recent_no_shows, app_state.no_show_block.period_days
+                    ),
+                ));
+            }
+        }
+
+        let new_employment = match app_state
             .employment_repository
+            .with_actor(current_user_id.unwrap_or(-1))
             .create_employment(CreateEmployment {
                 rating: 0,
                 state: EmploymentState::Pending,
                 user_id: current_user_id.unwrap_or(-1),
                 position_id: job_id,
+                invitation_expires_at: None,
             })
-            .await?;
-        let job_position = app_state
-            .job_position_repository
-            .get_job_position_by_id(job_id)
-            .await?;
+            .await
+        {
+            Ok(new_employment) => new_employment,
+            Err(RepositoryError::GenericError(message)) => {
+                return Ok(generate_toast_response(ToastType::Error, message))
+            }
+            Err(err) => return Err(AppError::from(err)),
+        };
         let job_category = app_state
             .position_category_repository
             .get_position_category_by_id(job_position.position_category_id)
             .await;
-        let event = app_state
-            .event_repository
-            .get_event_by_id(job_position.event_id)
-            .await?;
+
+        let is_registration_open = job_position.is_registration_open_at(OffsetDateTime::now_utc());
 
         let template = JobStateTemplate {
             session: auth_session,
@@ -64,7 +96,7 @@ pub mod post {
                 salary: job_position.salary,
                 current_capacity: 0,
                 max_capacity: 0,
-                is_opened_for_registration: job_position.is_opened_for_registration,
+                is_opened_for_registration: is_registration_open,
                 employment_state: Some(new_employment.state),
                 position_category: match job_category {
                     Ok(category) => Some(category),
@@ -1,25 +1,23 @@
 use crate::handlers::app::auth::AuthSession;
 use askama::Template;
-use axum::{extract::State, response::Html};
+use axum::{extract::State, response::Html, Extension};
 
-use crate::{error::AppError, repositories::user::UserRepository};
+use crate::{error::AppError, models::organization::ActiveOrganization};
 
 pub mod get {
     use axum::extract::Query;
 
     use serde::Deserialize;
     use sqlx::types::time::Date;
+    use std::str::FromStr;
 
     use crate::{
         app::AppState,
-        models::event::SelectManyFilter,
-        repositories::{
-            event::EventRepository, event_manager_relation::EventManagerRelationRepository,
-            venue::VenueRepository,
-        },
+        error::ApiError,
+        models::event::{EventStatus, SelectManyFilter},
         templates::EventsContentTemplate,
-        utils::date_utils::convert_date_time_to_date,
-        view_models::event::{EventViewModel, IEventViewModel},
+        utils::{date_utils::convert_date_time_to_date, table_utils::parse_filter},
+        view_models::event::EventViewModel,
     };
 
     use super::*;
@@ -29,122 +27,81 @@ pub mod get {
         state: Option<String>,
         city: Option<String>,
         name: Option<String>,
+        status: Option<String>,
     }
 
     pub async fn events_content(
         params: Query<Params>,
         auth_session: AuthSession,
         State(app_state): State<AppState>,
+        active_organization: Option<Extension<ActiveOrganization>>,
     ) -> Result<Html<String>, AppError> {
+        let organization_id = active_organization.map(|Extension(organization)| organization.id);
         let state = params.state.clone().filter(|state| !state.is_empty());
         let city = params.city.clone().filter(|city| !city.is_empty());
         let name = params.name.clone().filter(|name| !name.is_empty());
+        let status = parse_filter(
+            params.status.as_deref().unwrap_or_default(),
+            |status| EventStatus::from_str(status).map_err(|_| ApiError::NotFound),
+            "",
+        )?;
         let current_date_time = chrono::Local::now();
-        let _states = app_state.venue_repository.list_states().await?;
-        let _cities = app_state.venue_repository.list_cities().await?;
-        let mut future_view_events: Vec<EventViewModel> = Vec::new();
+        let _states = app_state
+            .venue_repository
+            .list_states(organization_id)
+            .await?;
+        let _cities = app_state
+            .venue_repository
+            .list_cities(organization_id)
+            .await?;
+        // Drafts are only listed for users allowed to view them.
+        let viewer = auth_session.user.as_ref();
         let future_events = app_state
-            .event_repository
-            .list_events(SelectManyFilter {
-                date_from: Some(convert_date_time_to_date(current_date_time)),
-                date_to: None,
-                is_draft: None,
-                venue_id: None,
-                owner_id: None,
-                city: city.clone(),
-                state: state.clone(),
-                name: name.clone(),
-            })
+            .read_model_repository
+            .list_event_listings(
+                SelectManyFilter {
+                    organization_id,
+                    date_from: Some(convert_date_time_to_date(current_date_time)),
+                    date_to: None,
+                    status,
+                    venue_id: None,
+                    owner_id: None,
+                    city: city.clone(),
+                    state: state.clone(),
+                    name: name.clone(),
+                    managed_by: None,
+                },
+                viewer,
+            )
             .await?;
-
-        for event in future_events {
-            let venue = app_state
-                .venue_repository
-                .get_venue_by_id(event.venue_id)
-                .await?;
-            let owner = app_state
-                .user_repository
-                .get_user_by_id(event.owner_id)
-                .await?;
-
-            // Check if user is owner or manager to show draft event.
-            if event.is_draft {
-                match auth_session.user {
-                    Some(ref user) => {
-                        let event_manager_relations = app_state
-                            .event_manager_relation_repository
-                            .list_event_managers(event.id)
-                            .await?;
-
-                        if event.owner_id != user.id {
-                            let user_is_manager = event_manager_relations
-                                .into_iter()
-                                .any(|relation| relation.user_id == user.id);
-                            if !user_is_manager {
-                                continue;
-                            }
-                        }
-                    }
-                    None => continue,
-                }
-            }
-
-            future_view_events.push(EventViewModel::new(event, venue, owner));
-        }
-
-        let mut past_view_events: Vec<EventViewModel> = Vec::new();
         let past_events = app_state
-            .event_repository
-            .list_events(SelectManyFilter {
-                date_from: None,
-                date_to: Some(
-                    convert_date_time_to_date(current_date_time)
-                        .previous_day()
-                        .unwrap_or(Date::MIN),
-                ),
-                is_draft: None,
-                venue_id: None,
-                owner_id: None,
-                city,
-                state,
-                name,
-            })
+            .read_model_repository
+            .list_event_listings(
+                SelectManyFilter {
+                    organization_id,
+                    date_from: None,
+                    date_to: Some(
+                        convert_date_time_to_date(current_date_time)
+                            .previous_day()
+                            .unwrap_or(Date::MIN),
+                    ),
+                    status,
+                    venue_id: None,
+                    owner_id: None,
+                    city,
+                    state,
+                    name,
+                    managed_by: None,
+                },
+                viewer,
+            )
             .await?;
-
-        for event in past_events {
-            let venue = app_state
-                .venue_repository
-                .get_venue_by_id(event.venue_id)
-                .await?;
-            let owner = app_state
-                .user_repository
-                .get_user_by_id(event.owner_id)
-                .await?;
-
-            // Check if user is owner or manager to show draft event.
-            if event.is_draft {
-                match auth_session.user {
-                    Some(ref user) => {
-                        let event_manager_relations = app_state
-                            .event_manager_relation_repository
-                            .list_event_managers(event.id)
-                            .await?;
-
-                        if event.owner_id != user.id {
-                            let user_is_manager = event_manager_relations
-                                .into_iter()
-                                .any(|relation| relation.user_id == user.id);
-                            if !user_is_manager {
-                                continue;
-                            }
-                        }
-                    }
-                    None => continue,
-                }
-            }
-
-            past_view_events.push(EventViewModel::new(event, venue, owner));
-        }
+        let future_view_events: Vec<EventViewModel> = future_events
+            .into_iter()
+            .map(EventViewModel::from)
+            .collect();
+        let past_view_events: Vec<EventViewModel> =
+            past_events.into_iter().map(EventViewModel::from).collect();
 
         let template = EventsContentTemplate {
             session: auth_session,
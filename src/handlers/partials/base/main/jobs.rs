@@ -0,0 +1 @@
+pub mod instructions_preview;
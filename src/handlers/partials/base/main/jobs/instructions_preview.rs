@@ -0,0 +1,16 @@
+pub mod post {
+    use axum::{response::Html, Form};
+    use serde::Deserialize;
+
+    use crate::rich_text::render_markdown;
+
+    #[derive(Deserialize)]
+    pub struct Params {
+        instructions: String,
+    }
+
+    /// Renders the instructions the way workers will see them, while the organizer types.
+    pub async fn instructions_preview(params: Form<Params>) -> Html<String> {
+        Html(render_markdown(&params.instructions))
+    }
+}
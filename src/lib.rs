@@ -1,4 +1,17 @@
+pub mod app;
+pub mod auth;
+pub mod csrf;
 pub mod error;
+mod handlers;
+mod middleware;
 pub mod models;
+pub mod policy;
 pub mod repositories;
-pub mod regex;
\ No newline at end of file
+pub mod regex;
+pub mod rich_text;
+mod scheduler;
+pub mod security_headers;
+pub mod storage;
+mod templates;
+mod utils;
+mod view_models;
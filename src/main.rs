@@ -1,19 +1,12 @@
-use crate::app::App;
 use anyhow::Result;
-
-mod app;
-mod auth;
-mod error;
-mod handlers;
-mod middleware;
-mod models;
-mod regex;
-mod repositories;
-mod templates;
-mod utils;
-mod view_models;
+use pv281_giglog::app::App;
+use std::env;
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    App::new().await?.serve().await
+    let app = App::new().await?;
+    match env::args().nth(1).as_deref() {
+        Some("sanitize-instructions") => app.sanitize_instructions().await,
+        _ => app.serve().await,
+    }
 }
@@ -1 +1,2 @@
+pub mod error_pages;
 pub mod global;
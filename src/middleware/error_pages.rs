@@ -0,0 +1,69 @@
+use askama::Template;
+use axum::{
+    extract::Request,
+    http::{header, HeaderMap},
+    middleware::Next,
+    response::{Html, IntoResponse, Response},
+};
+
+use crate::{
+    error::{ApiError, ErrorDetails},
+    templates::{
+        ErrorTemplate, PageNotFoundTemplate, ToastTemplate, ToastType, UnauthorizedTemplate,
+    },
+};
+
+/// How the client expects errors to be presented.
+enum ErrorFormat {
+    Json,
+    Toast,
+    Page,
+}
+
+impl ErrorFormat {
+    fn of(headers: &HeaderMap) -> Self {
+        let accept = headers
+            .get(header::ACCEPT)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or_default();
+        if accept.contains("application/json") && !accept.contains("text/html") {
+            return ErrorFormat::Json;
+        }
+        // Boosted links and forms replace the whole page, other HTMX requests only a part of it.
+        if headers.contains_key("hx-request") && !headers.contains_key("hx-boosted") {
+            return ErrorFormat::Toast;
+        }
+        ErrorFormat::Page
+    }
+}
+
+/// Renders errors returned by handlers as HTML, API requests keep the JSON body.
+pub async fn render_error_pages(request: Request, next: Next) -> Response {
+    let format = ErrorFormat::of(request.headers());
+    let response = next.run(request).await;
+    let Some(details) = response.extensions().get::<ErrorDetails>().copied() else {
+        return response;
+    };
+
+    let status = response.status();
+    let html = match (format, details.error) {
+        (ErrorFormat::Json, _) => return response,
+        (ErrorFormat::Toast, error) => ToastTemplate {
+            toast_type: ToastType::Error,
+            message: format!("{} Reference: {}", error.message(), details.correlation_id),
+        }
+        .render(),
+        (ErrorFormat::Page, ApiError::NotFound) => PageNotFoundTemplate {}.render(),
+        (ErrorFormat::Page, ApiError::Unauthorized) => UnauthorizedTemplate {}.render(),
+        (ErrorFormat::Page, error) => ErrorTemplate {
+            status: status.as_u16(),
+            message: error.message().to_string(),
+            correlation_id: details.correlation_id.to_string(),
+        }
+        .render(),
+    };
+    match html {
+        Ok(html) => (status, Html(html)).into_response(),
+        Err(_) => response,
+    }
+}
@@ -1,15 +1,113 @@
+use async_trait::async_trait;
 use axum::{
-    extract::{Request, State},
-    http::StatusCode,
+    extract::{FromRequestParts, Request, State},
+    http::{request::Parts, StatusCode},
     middleware::Next,
-    response::Response,
+    response::{IntoResponse, Redirect, Response},
 };
+use tower_sessions::Session;
 
 use crate::{
-    app::AppState, handlers::app::auth::AuthSession, models::user::UserRole,
-    utils::response_utils::generate_unauthorized_response,
+    app::AppState,
+    error::RepositoryError,
+    handlers::app::auth::AuthSession,
+    models::{
+        organization::{ActiveOrganization, OrganizationRole},
+        user::{User, UserRole},
+    },
+    utils::response_utils::{generate_htmx_redirect, generate_unauthorized_response},
 };
 
+/// Session key holding the id of the organization the user switched to.
+pub const ACTIVE_ORGANIZATION_KEY: &str = "active_organization_id";
+
+async fn find_active_organization(
+    session: &Session,
+    app_state: &AppState,
+    user: &User,
+) -> Result<Option<ActiveOrganization>, RepositoryError> {
+    // Admins look after the whole deployment and may step into any organization.
+    let organizations = if user.role == UserRole::Admin {
+        app_state
+            .organization_repository
+            .list_organizations()
+            .await?
+    } else {
+        app_state
+            .organization_repository
+            .list_user_organizations(user.id)
+            .await?
+    };
+
+    let selected_id = session
+        .get::<i32>(ACTIVE_ORGANIZATION_KEY)
+        .await
+        .unwrap_or(None);
+    let organization = match organizations
+        .iter()
+        .find(|organization| Some(organization.id) == selected_id)
+        .or(organizations.first())
+    {
+        Some(organization) => organization.clone(),
+        None => return Ok(None),
+    };
+
+    let role = if user.role == UserRole::Admin {
+        OrganizationRole::Owner
+    } else {
+        app_state
+            .organization_repository
+            .get_membership(organization.id, user.id)
+            .await?
+            .role
+    };
+
+    Ok(Some(ActiveOrganization {
+        id: organization.id,
+        name: organization.name,
+        role,
+    }))
+}
+
+/// Makes the organization the logged in user works in available to handlers.
+pub async fn resolve_organization(
+    auth_session: AuthSession,
+    session: Session,
+    State(app_state): State<AppState>,
+    mut request: Request,
+    next: Next,
+) -> Result<Response, StatusCode> {
+    if let Some(user) = auth_session.user.as_ref() {
+        let active_organization = find_active_organization(&session, &app_state, user)
+            .await
+            .map_err(|err| {
+                eprintln!("Failed to resolve active organization: {:?}", err);
+                StatusCode::INTERNAL_SERVER_ERROR
+            })?;
+        if let Some(active_organization) = active_organization {
+            request.extensions_mut().insert(active_organization);
+        }
+    }
+
+    Ok(next.run(request).await)
+}
+
+/// Users belonging to no organization are sent to the organization selection instead.
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for ActiveOrganization {
+    type Rejection = Response;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        match parts.extensions.get::<ActiveOrganization>() {
+            Some(active_organization) => Ok(active_organization.clone()),
+            None if parts.headers.contains_key("hx-request") => {
+                Err(generate_htmx_redirect("/organizations"))
+            }
+            None => Err(Redirect::to("/organizations").into_response()),
+        }
+    }
+}
+
 pub async fn check_organizer(
     auth_session: AuthSession,
     State(_app_state): State<AppState>,
@@ -21,12 +119,17 @@ pub async fn check_organizer(
         .user
         .expect("User should be logged in.");
 
-    match current_user.role {
-        UserRole::Admin | UserRole::Organizer => {
-            let response = next.run(request).await;
-            Ok(response)
-        }
-        _ => Ok(generate_unauthorized_response()),
+    // Organizing is decided by the role within the active organization.
+    let organizes = request
+        .extensions()
+        .get::<ActiveOrganization>()
+        .is_some_and(|organization| organization.role.can_organize());
+
+    if current_user.role == UserRole::Admin || organizes {
+        let response = next.run(request).await;
+        Ok(response)
+    } else {
+        Ok(generate_unauthorized_response())
     }
 }
 
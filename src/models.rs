@@ -1,8 +1,17 @@
+pub mod attendance;
+pub mod audit_log;
 pub mod employment;
 pub mod event;
 pub mod event_manager_relation;
 pub mod job_position;
+pub mod notification;
+pub mod organization;
+pub mod pagination;
 pub mod position_category;
+pub mod read_model;
+pub mod review;
+pub mod staffing;
+pub mod trash;
 pub mod user;
 pub mod venue;
 pub mod worked_hours;
@@ -0,0 +1,137 @@
+use serde::{Deserialize, Serialize};
+use sqlx::types::time::{Date, OffsetDateTime};
+use sqlx::Type;
+use std::env;
+use std::fmt::Display;
+use time::Duration;
+use validator::Validate;
+
+use crate::models::employment::WithdrawalStats;
+
+/// How an accepted worker showed up on one day of the event.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Type, PartialEq, Eq)]
+#[sqlx(type_name = "attendance_outcome", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum AttendanceOutcome {
+    Attended,
+    Late,
+    NoShow,
+    LeftEarly,
+}
+
+impl AttendanceOutcome {
+    /// Share of a full attendance the outcome counts for in the reliability score.
+    pub fn reliability_weight(&self) -> f32 {
+        match self {
+            AttendanceOutcome::Attended => 1.0,
+            AttendanceOutcome::Late => 0.7,
+            AttendanceOutcome::LeftEarly => 0.6,
+            AttendanceOutcome::NoShow => 0.0,
+        }
+    }
+}
+
+impl Display for AttendanceOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            AttendanceOutcome::Attended => "Attended",
+            AttendanceOutcome::Late => "Late",
+            AttendanceOutcome::NoShow => "No-show",
+            AttendanceOutcome::LeftEarly => "Left early",
+        };
+        write!(f, "{}", str)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AttendanceRecord {
+    pub id: i32,
+    pub employment_id: i32,
+    pub date: Date,
+    pub outcome: AttendanceOutcome,
+    pub note: Option<String>,
+    pub recorded_by: Option<i32>,
+    pub created_at: OffsetDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct RecordAttendance {
+    pub employment_id: i32,
+    pub date: Date,
+    pub outcome: AttendanceOutcome,
+    #[validate(length(max = 300, message = "Note is too long. Maximum is 300 characters."))]
+    pub note: Option<String>,
+}
+
+/// Recorded outcomes of all of an employee's jobs.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AttendanceSummary {
+    pub attended: i64,
+    pub late: i64,
+    pub no_shows: i64,
+    pub left_early: i64,
+}
+
+impl AttendanceSummary {
+    /// Percentage of the employee's commitments that were kept, `None` without any history.
+    /// On-time withdrawals count for a little less than a kept commitment, late ones
+    /// for much less.
+    pub fn reliability_score(&self, withdrawals: &WithdrawalStats) -> Option<i32> {
+        let on_time_withdrawals = withdrawals.total - withdrawals.late;
+        let total = self.attended + self.late + self.no_shows + self.left_early + withdrawals.total;
+        if total == 0 {
+            return None;
+        }
+        let points = self.attended as f32 * AttendanceOutcome::Attended.reliability_weight()
+            + self.late as f32 * AttendanceOutcome::Late.reliability_weight()
+            + self.left_early as f32 * AttendanceOutcome::LeftEarly.reliability_weight()
+            + on_time_withdrawals as f32 * 0.8
+            + withdrawals.late as f32 * 0.3;
+        Some((points * 100.0 / total as f32).round() as i32)
+    }
+}
+
+/// Optional ban on applying for jobs after too many recent no-shows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NoShowBlock {
+    /// No-shows within the period that block applying, `None` disables the block.
+    pub limit: Option<i64>,
+    pub period_days: i64,
+}
+
+impl Default for NoShowBlock {
+    fn default() -> Self {
+        Self {
+            limit: None,
+            period_days: 90,
+        }
+    }
+}
+
+impl NoShowBlock {
+    /// Reads `NO_SHOW_BLOCK_LIMIT` and `NO_SHOW_BLOCK_PERIOD_DAYS`, the block stays off
+    /// unless a positive limit is set.
+    pub fn from_env() -> Self {
+        let default = Self::default();
+        Self {
+            limit: env::var("NO_SHOW_BLOCK_LIMIT")
+                .ok()
+                .and_then(|value| value.parse::<i64>().ok())
+                .filter(|limit| *limit > 0),
+            period_days: env::var("NO_SHOW_BLOCK_PERIOD_DAYS")
+                .ok()
+                .and_then(|value| value.parse::<i64>().ok())
+                .filter(|days| *days > 0)
+                .unwrap_or(default.period_days),
+        }
+    }
+
+    /// First day of the period no-shows are counted in.
+    pub fn period_start(&self, today: Date) -> Date {
+        today - Duration::days(self.period_days)
+    }
+
+    pub fn is_blocked(&self, recent_no_shows: i64) -> bool {
+        self.limit.is_some_and(|limit| recent_no_shows >= limit)
+    }
+}
@@ -0,0 +1,153 @@
+use crate::models::pagination::{self, SortDirection};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::types::time::{Date, OffsetDateTime};
+use sqlx::Type;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SelectManyFilter {
+    pub actor_id: Option<i32>,
+    pub entity: Option<AuditEntity>,
+    pub entity_id: Option<i32>,
+    pub event_id: Option<i32>,
+    pub action: Option<AuditAction>,
+    pub date_from: Option<Date>,
+    pub date_to: Option<Date>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortColumn {
+    #[default]
+    CreatedAt,
+}
+
+impl pagination::SortColumn for SortColumn {
+    fn sql(&self) -> &'static str {
+        match self {
+            SortColumn::CreatedAt => r#""created_at""#,
+        }
+    }
+
+    fn default_direction(&self) -> SortDirection {
+        SortDirection::Desc
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditLog {
+    pub id: i32,
+    pub actor_id: Option<i32>,
+    pub entity: AuditEntity,
+    pub entity_id: i32,
+    pub event_id: Option<i32>,
+    pub action: AuditAction,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+    pub created_at: OffsetDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateAuditLog {
+    pub actor_id: Option<i32>,
+    pub entity: AuditEntity,
+    pub entity_id: i32,
+    pub event_id: Option<i32>,
+    pub action: AuditAction,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[sqlx(type_name = "audit_action", rename_all = "lowercase")]
+pub enum AuditAction {
+    Create,
+    Update,
+    Delete,
+    Restore,
+    Purge,
+}
+
+pub fn all_audit_actions() -> Vec<AuditAction> {
+    vec![
+        AuditAction::Create,
+        AuditAction::Update,
+        AuditAction::Delete,
+        AuditAction::Restore,
+        AuditAction::Purge,
+    ]
+}
+
+impl fmt::Display for AuditAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AuditAction::Create => write!(f, "Create"),
+            AuditAction::Update => write!(f, "Update"),
+            AuditAction::Delete => write!(f, "Delete"),
+            AuditAction::Restore => write!(f, "Restore"),
+            AuditAction::Purge => write!(f, "Purge"),
+        }
+    }
+}
+
+impl FromStr for AuditAction {
+    type Err = ();
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "Create" => Ok(AuditAction::Create),
+            "Update" => Ok(AuditAction::Update),
+            "Delete" => Ok(AuditAction::Delete),
+            "Restore" => Ok(AuditAction::Restore),
+            "Purge" => Ok(AuditAction::Purge),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[sqlx(type_name = "audit_entity", rename_all = "snake_case")]
+pub enum AuditEntity {
+    Event,
+    Employment,
+    JobPosition,
+    User,
+    Venue,
+}
+
+pub fn all_audit_entities() -> Vec<AuditEntity> {
+    vec![
+        AuditEntity::Event,
+        AuditEntity::Employment,
+        AuditEntity::JobPosition,
+        AuditEntity::User,
+        AuditEntity::Venue,
+    ]
+}
+
+impl fmt::Display for AuditEntity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AuditEntity::Event => write!(f, "Event"),
+            AuditEntity::Employment => write!(f, "Employment"),
+            AuditEntity::JobPosition => write!(f, "Job"),
+            AuditEntity::User => write!(f, "User"),
+            AuditEntity::Venue => write!(f, "Venue"),
+        }
+    }
+}
+
+impl FromStr for AuditEntity {
+    type Err = ();
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "Event" => Ok(AuditEntity::Event),
+            "Employment" => Ok(AuditEntity::Employment),
+            "Job" => Ok(AuditEntity::JobPosition),
+            "User" => Ok(AuditEntity::User),
+            "Venue" => Ok(AuditEntity::Venue),
+            _ => Err(()),
+        }
+    }
+}
@@ -1,15 +1,142 @@
+use crate::models::event::ManagedBy;
+use crate::models::pagination;
 use serde::{Deserialize, Serialize};
+use sqlx::types::time::{Date, OffsetDateTime};
 use sqlx::Type;
 use std::fmt::Display;
 use std::str::FromStr;
+use time::Duration;
 use validator::Validate;
 
-#[derive(Serialize, Deserialize)]
+/// How long an employee has to answer an invitation, unless the event starts sooner.
+pub const INVITATION_VALIDITY: Duration = Duration::days(3);
+
+#[derive(Clone, Serialize, Deserialize)]
 pub struct SelectManyFilter {
+    pub organization_id: Option<i32>,
     pub position_id: Option<i32>,
     pub user_id: Option<i32>,
     pub state: Option<EmploymentState>,
     pub rating: Option<i32>,
+    pub event_name_contains: Option<String>,
+    pub job_name_contains: Option<String>,
+    pub employee_name_contains: Option<String>,
+    #[serde(skip)]
+    pub managed_by: Option<ManagedBy>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortColumn {
+    State,
+    JobName,
+    EmployeeName,
+    Event,
+    Capacity,
+    #[default]
+    Id,
+}
+
+/// Order of employment states on SQLite, the one of the declaration of the enum type.
+const SQLITE_STATE_ORDER: &str = r#"CASE "employment"."state"
+    WHEN 'pending' THEN 0 WHEN 'accepted' THEN 1 WHEN 'rejected' THEN 2
+    WHEN 'done' THEN 3 WHEN 'cancelled' THEN 4 WHEN 'invited' THEN 5
+    WHEN 'declined' THEN 6 WHEN 'expired' THEN 7 ELSE 8 END"#;
+
+impl pagination::SortColumn for SortColumn {
+    fn sql(&self) -> &'static str {
+        match self {
+            SortColumn::State => r#""employment"."state""#,
+            SortColumn::JobName => r#""job_position"."name""#,
+            SortColumn::EmployeeName => r#""user"."first_name" || ' ' || "user"."last_name""#,
+            SortColumn::Event => r#""event"."name""#,
+            SortColumn::Capacity => {
+                r#"(SELECT COUNT(*) FROM "employment" AS "taken"
+                    WHERE "taken"."position_id" = "employment"."position_id"
+                        AND "taken"."state" IN ('accepted', 'done'))"#
+            }
+            SortColumn::Id => r#""employment"."id""#,
+        }
+    }
+
+    fn sqlite_sql(&self) -> &'static str {
+        match self {
+            SortColumn::State => SQLITE_STATE_ORDER,
+            _ => self.sql(),
+        }
+    }
+}
+
+/// Filter of the jobs of one employee in an organization.
+#[derive(Debug, Clone)]
+pub struct JobsFilter {
+    pub organization_id: i32,
+    pub user_id: i32,
+    pub state: Option<EmploymentState>,
+    pub starts_from: Option<Date>,
+    pub ends_until: Option<Date>,
+    pub event_name_contains: Option<String>,
+    pub venue_name_contains: Option<String>,
+    pub job_name_contains: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobsSortColumn {
+    State,
+    JobName,
+    Event,
+    #[default]
+    DateStart,
+    Venue,
+    HoursWorked,
+    SalaryTaxFree,
+    SalaryTaxed,
+}
+
+impl pagination::SortColumn for JobsSortColumn {
+    fn sql(&self) -> &'static str {
+        match self {
+            JobsSortColumn::State => r#""employment"."state""#,
+            JobsSortColumn::JobName => r#""job_position"."name""#,
+            JobsSortColumn::Event => r#""event"."name""#,
+            JobsSortColumn::DateStart => r#""event"."date_start""#,
+            JobsSortColumn::Venue => r#""venue"."name""#,
+            JobsSortColumn::HoursWorked => r#""hours"."total""#,
+            // The tax rate is the employee's, so both salaries sort the same.
+            JobsSortColumn::SalaryTaxFree | JobsSortColumn::SalaryTaxed => {
+                r#""hours"."total" * "job_position"."salary""#
+            }
+        }
+    }
+
+    fn sqlite_sql(&self) -> &'static str {
+        match self {
+            JobsSortColumn::State => SQLITE_STATE_ORDER,
+            _ => self.sql(),
+        }
+    }
+
+    fn default_direction(&self) -> pagination::SortDirection {
+        match self {
+            JobsSortColumn::DateStart => pagination::SortDirection::Desc,
+            _ => pagination::SortDirection::Asc,
+        }
+    }
+}
+
+/// Totals over all jobs matching a [`JobsFilter`], salaries before tax.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct JobStats {
+    pub total_jobs: i64,
+    pub unique_events: i64,
+    pub unique_venues: i64,
+    pub hours_worked: f32,
+    pub salary: f32,
+    pub earliest_date: Option<Date>,
+    pub latest_date: Option<Date>,
+    /// Average of the ratings given so far, unrated jobs are left out.
+    pub average_rating: Option<f32>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -19,6 +146,28 @@ pub struct Employment {
     pub state: EmploymentState,
     pub user_id: i32,
     pub position_id: i32,
+    pub invitation_expires_at: Option<OffsetDateTime>,
+}
+
+impl Employment {
+    /// Whether the employment is an invitation nobody answered until `now`.
+    pub fn is_invitation_expired_at(&self, now: OffsetDateTime) -> bool {
+        self.state == EmploymentState::Invited
+            && self
+                .invitation_expires_at
+                .is_some_and(|expires_at| expires_at <= now)
+    }
+}
+
+/// Deadline for an invitation sent at `now` to a job of an event starting on `event_start`.
+pub fn invitation_deadline(now: OffsetDateTime, event_start: Date) -> OffsetDateTime {
+    let deadline = now + INVITATION_VALIDITY;
+    let event_start = event_start.midnight().assume_utc();
+    if now < event_start && event_start < deadline {
+        event_start
+    } else {
+        deadline
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Validate)]
@@ -28,6 +177,7 @@ pub struct CreateEmployment {
     pub state: EmploymentState,
     pub user_id: i32,
     pub position_id: i32,
+    pub invitation_expires_at: Option<OffsetDateTime>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Validate)]
@@ -46,6 +196,110 @@ pub enum EmploymentState {
     Done,
     Pending,
     Rejected,
+    Cancelled,
+    Invited,
+    Declined,
+    Expired,
+    Withdrawn,
+}
+
+impl EmploymentState {
+    /// States the employment may move to from this one.
+    pub fn next_states(&self) -> Vec<EmploymentState> {
+        match self {
+            EmploymentState::Pending => vec![
+                EmploymentState::Accepted,
+                EmploymentState::Rejected,
+                EmploymentState::Withdrawn,
+                EmploymentState::Cancelled,
+            ],
+            EmploymentState::Invited => vec![
+                EmploymentState::Accepted,
+                EmploymentState::Declined,
+                EmploymentState::Expired,
+                EmploymentState::Cancelled,
+            ],
+            EmploymentState::Accepted => vec![
+                EmploymentState::Done,
+                EmploymentState::Withdrawn,
+                EmploymentState::Cancelled,
+            ],
+            EmploymentState::Done
+            | EmploymentState::Rejected
+            | EmploymentState::Cancelled
+            | EmploymentState::Declined
+            | EmploymentState::Expired
+            | EmploymentState::Withdrawn => vec![],
+        }
+    }
+
+    pub fn can_transition_to(&self, next: &EmploymentState) -> bool {
+        self.next_states().contains(next)
+    }
+    /// Whether the employee may still withdraw from an employment in this state.
+    pub fn is_withdrawable(&self) -> bool {
+        matches!(self, EmploymentState::Pending | EmploymentState::Accepted)
+    }
+
+    /// Whether an employment in this state is listed among the staff of a job position,
+    /// i.e. the employee works there or has been invited by an organizer.
+    pub fn is_staffing(&self) -> bool {
+        matches!(
+            self,
+            EmploymentState::Accepted
+                | EmploymentState::Invited
+                | EmploymentState::Declined
+                | EmploymentState::Expired
+        )
+    }
+}
+
+/// Order in which a bulk accept fills the remaining capacity of job positions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BulkOrder {
+    Rating,
+    ApplicationTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkippedEmployment {
+    pub employment_id: i32,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BulkUpdateResult {
+    pub updated: Vec<Employment>,
+    pub skipped: Vec<SkippedEmployment>,
+}
+
+/// One entry of an employment's state timeline. `from_state` is empty for the initial state.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmploymentStateChange {
+    pub id: i32,
+    pub employment_id: i32,
+    pub from_state: Option<EmploymentState>,
+    pub to_state: EmploymentState,
+    pub changed_by: Option<i32>,
+    pub changed_by_name: Option<String>,
+    pub changed_at: OffsetDateTime,
+}
+
+/// Record of an employee withdrawing from a job they applied for or were accepted to.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmploymentWithdrawal {
+    pub id: i32,
+    pub employment_id: i32,
+    pub user_id: i32,
+    pub reason: Option<String>,
+    pub is_late: bool,
+    pub created_at: OffsetDateTime,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WithdrawalStats {
+    pub total: i64,
+    pub late: i64,
 }
 
 pub fn all_employment_states() -> Vec<EmploymentState> {
@@ -54,6 +308,11 @@ pub fn all_employment_states() -> Vec<EmploymentState> {
         EmploymentState::Done,
         EmploymentState::Pending,
         EmploymentState::Rejected,
+        EmploymentState::Cancelled,
+        EmploymentState::Invited,
+        EmploymentState::Declined,
+        EmploymentState::Expired,
+        EmploymentState::Withdrawn,
     ]
 }
 
@@ -64,6 +323,11 @@ impl Display for EmploymentState {
             EmploymentState::Accepted => "Accepted".to_string(),
             EmploymentState::Rejected => "Rejected".to_string(),
             EmploymentState::Done => "Done".to_string(),
+            EmploymentState::Cancelled => "Cancelled".to_string(),
+            EmploymentState::Invited => "Invited".to_string(),
+            EmploymentState::Declined => "Declined".to_string(),
+            EmploymentState::Expired => "Expired".to_string(),
+            EmploymentState::Withdrawn => "Withdrawn".to_string(),
         };
         write!(f, "{}", str)
     }
@@ -77,6 +341,11 @@ impl FromStr for EmploymentState {
             "Accepted" => Ok(EmploymentState::Accepted),
             "Rejected" => Ok(EmploymentState::Rejected),
             "Done" => Ok(EmploymentState::Done),
+            "Cancelled" => Ok(EmploymentState::Cancelled),
+            "Invited" => Ok(EmploymentState::Invited),
+            "Declined" => Ok(EmploymentState::Declined),
+            "Expired" => Ok(EmploymentState::Expired),
+            "Withdrawn" => Ok(EmploymentState::Withdrawn),
             _ => Err(()),
         }
     }
@@ -1,17 +1,72 @@
 use serde::{Deserialize, Serialize};
-use sqlx::types::time::Date;
+use sqlx::types::time::{Date, OffsetDateTime};
+use sqlx::Type;
+use std::fmt::Display;
+use std::str::FromStr;
+use time::Duration;
 use validator::Validate;
 
+use crate::models::pagination;
+use crate::policy::EventAction;
+use crate::storage::validate_image_url;
+
+/// Hours before the start of an event from which employees need a reason to withdraw.
+pub const DEFAULT_WITHDRAWAL_CUTOFF_HOURS: i32 = 48;
+
 #[derive(Clone, Serialize, Deserialize)]
 pub struct SelectManyFilter {
+    pub organization_id: Option<i32>,
     pub date_from: Option<Date>,
     pub date_to: Option<Date>,
-    pub is_draft: Option<bool>,
+    pub status: Option<EventStatus>,
     pub venue_id: Option<i32>,
     pub owner_id: Option<i32>,
     pub city: Option<String>,
     pub state: Option<String>,
     pub name: Option<String>,
+    #[serde(skip)]
+    pub managed_by: Option<ManagedBy>,
+}
+
+/// Limits a list to the events a user owns or manages. Managers need a permission for one of
+/// `actions`, any manager relation is enough when there are none.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManagedBy {
+    pub user_id: i32,
+    pub actions: Vec<EventAction>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortColumn {
+    Name,
+    #[default]
+    DateStart,
+    Status,
+    Id,
+}
+
+impl pagination::SortColumn for SortColumn {
+    fn sql(&self) -> &'static str {
+        match self {
+            SortColumn::Name => r#""event"."name""#,
+            SortColumn::DateStart => r#""event"."date_start""#,
+            SortColumn::Status => r#""event"."status""#,
+            SortColumn::Id => r#""event"."id""#,
+        }
+    }
+
+    fn sqlite_sql(&self) -> &'static str {
+        match self {
+            SortColumn::Status => {
+                r#"CASE "event"."status"
+                    WHEN 'draft' THEN 0 WHEN 'published' THEN 1 WHEN 'registration_closed' THEN 2
+                    WHEN 'in_progress' THEN 3 WHEN 'completed' THEN 4 WHEN 'archived' THEN 5
+                    ELSE 6 END"#
+            }
+            _ => self.sql(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -22,9 +77,30 @@ pub struct Event {
     pub date_end: Date,
     pub img_url: String,
     pub description: Option<String>,
-    pub is_draft: bool,
+    pub status: EventStatus,
     pub venue_id: i32,
     pub owner_id: i32,
+    pub cancelled_at: Option<OffsetDateTime>,
+    pub cancellation_reason: Option<String>,
+    pub publish_at: Option<OffsetDateTime>,
+    pub organization_id: i32,
+    pub withdrawal_cutoff_hours: i32,
+}
+
+impl Event {
+    pub fn is_cancelled(&self) -> bool {
+        self.status == EventStatus::Cancelled
+    }
+
+    pub fn is_draft(&self) -> bool {
+        self.status == EventStatus::Draft
+    }
+
+    /// Moment after which withdrawing from a job of the event requires a reason.
+    pub fn withdrawal_cutoff(&self) -> OffsetDateTime {
+        self.date_start.midnight().assume_utc()
+            - Duration::hours(self.withdrawal_cutoff_hours as i64)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Validate)]
@@ -33,12 +109,23 @@ pub struct CreateEvent {
     pub name: String,
     pub date_start: Date,
     pub date_end: Date,
-    #[validate(url(message = "Event image URL is not in the correct format."))]
+    #[validate(custom(
+        function = "validate_image_url",
+        message = "Event image URL is not in the correct format."
+    ))]
     pub img_url: String,
     pub description: String,
-    pub is_draft: bool,
+    pub status: EventStatus,
     pub venue_id: i32,
     pub owner_id: i32,
+    pub publish_at: Option<OffsetDateTime>,
+    pub organization_id: i32,
+    #[validate(range(
+        min = 0,
+        max = 720,
+        message = "Withdrawal cutoff has to be between 0 and 720 hours."
+    ))]
+    pub withdrawal_cutoff_hours: i32,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Validate)]
@@ -47,10 +134,119 @@ pub struct PartialEvent {
     pub name: Option<String>,
     pub date_start: Option<Date>,
     pub date_end: Option<Date>,
-    #[validate(url(message = "Event image URL is not in the correct format."))]
+    #[validate(custom(
+        function = "validate_image_url",
+        message = "Event image URL is not in the correct format."
+    ))]
     pub img_url: Option<String>,
     pub description: Option<String>,
-    pub is_draft: Option<bool>,
     pub venue_id: Option<i32>,
     pub owner_id: Option<i32>,
+    // `Some(None)` clears the scheduled publish date.
+    pub publish_at: Option<Option<OffsetDateTime>>,
+    #[validate(range(
+        min = 0,
+        max = 720,
+        message = "Withdrawal cutoff has to be between 0 and 720 hours."
+    ))]
+    pub withdrawal_cutoff_hours: Option<i32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct CancelEvent {
+    #[validate(length(min = 3, message = "Cancellation reason is too short."))]
+    pub reason: String,
+    #[validate(range(
+        min = 0.0,
+        max = 24.0,
+        message = "Compensation hours must be between 0 and 24."
+    ))]
+    pub compensation_hours: Option<f32>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Type, PartialEq, Eq, PartialOrd, Ord)]
+#[sqlx(type_name = "event_status", rename_all = "snake_case")]
+pub enum EventStatus {
+    Draft,
+    Published,
+    RegistrationClosed,
+    InProgress,
+    Completed,
+    Archived,
+    Cancelled,
+}
+
+impl EventStatus {
+    /// Statuses the event may move to from this one.
+    pub fn next_statuses(&self) -> Vec<EventStatus> {
+        match self {
+            EventStatus::Draft => vec![EventStatus::Published, EventStatus::Cancelled],
+            EventStatus::Published => vec![
+                EventStatus::Draft,
+                EventStatus::RegistrationClosed,
+                EventStatus::InProgress,
+                EventStatus::Cancelled,
+            ],
+            EventStatus::RegistrationClosed => vec![
+                EventStatus::Published,
+                EventStatus::InProgress,
+                EventStatus::Cancelled,
+            ],
+            EventStatus::InProgress => vec![EventStatus::Completed, EventStatus::Cancelled],
+            EventStatus::Completed => vec![EventStatus::Archived],
+            EventStatus::Archived => vec![],
+            EventStatus::Cancelled => vec![EventStatus::Archived],
+        }
+    }
+
+    pub fn can_transition_to(&self, next: &EventStatus) -> bool {
+        self.next_statuses().contains(next)
+    }
+
+    pub fn can_be_cancelled(&self) -> bool {
+        self.can_transition_to(&EventStatus::Cancelled)
+    }
+}
+
+pub fn all_event_statuses() -> Vec<EventStatus> {
+    vec![
+        EventStatus::Draft,
+        EventStatus::Published,
+        EventStatus::RegistrationClosed,
+        EventStatus::InProgress,
+        EventStatus::Completed,
+        EventStatus::Archived,
+        EventStatus::Cancelled,
+    ]
+}
+
+impl Display for EventStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            EventStatus::Draft => "Draft".to_string(),
+            EventStatus::Published => "Published".to_string(),
+            EventStatus::RegistrationClosed => "Registration closed".to_string(),
+            EventStatus::InProgress => "In progress".to_string(),
+            EventStatus::Completed => "Completed".to_string(),
+            EventStatus::Archived => "Archived".to_string(),
+            EventStatus::Cancelled => "Cancelled".to_string(),
+        };
+        write!(f, "{}", str)
+    }
+}
+
+impl FromStr for EventStatus {
+    type Err = ();
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "Draft" => Ok(EventStatus::Draft),
+            "Published" => Ok(EventStatus::Published),
+            "Registration closed" => Ok(EventStatus::RegistrationClosed),
+            "In progress" => Ok(EventStatus::InProgress),
+            "Completed" => Ok(EventStatus::Completed),
+            "Archived" => Ok(EventStatus::Archived),
+            "Cancelled" => Ok(EventStatus::Cancelled),
+            _ => Err(()),
+        }
+    }
 }
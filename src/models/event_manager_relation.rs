@@ -4,6 +4,25 @@ use serde::{Deserialize, Serialize};
 pub struct EventManagerRelation {
     pub user_id: i32,
     pub event_id: i32,
+    pub can_edit_event: bool,
+    pub can_manage_jobs: bool,
+    pub can_manage_applicants: bool,
+    pub can_approve_hours: bool,
+    pub can_view_payroll: bool,
+    pub can_manage_managers: bool,
+}
+
+impl EventManagerRelation {
+    pub fn permissions(&self) -> EventManagerPermissions {
+        EventManagerPermissions {
+            can_edit_event: self.can_edit_event,
+            can_manage_jobs: self.can_manage_jobs,
+            can_manage_applicants: self.can_manage_applicants,
+            can_approve_hours: self.can_approve_hours,
+            can_view_payroll: self.can_view_payroll,
+            can_manage_managers: self.can_manage_managers,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -11,3 +30,26 @@ pub struct CreateEventManagerRelation {
     pub user_id: i32,
     pub event_id: i32,
 }
+
+/// What a manager may do on top of seeing the event. The owner and admins may do everything.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventManagerPermissions {
+    pub can_edit_event: bool,
+    pub can_manage_jobs: bool,
+    pub can_manage_applicants: bool,
+    pub can_approve_hours: bool,
+    pub can_view_payroll: bool,
+    pub can_manage_managers: bool,
+}
+
+impl EventManagerPermissions {
+    /// Returns `true` when every permission granted here is also granted by `other`.
+    pub fn is_subset_of(&self, other: &EventManagerPermissions) -> bool {
+        (!self.can_edit_event || other.can_edit_event)
+            && (!self.can_manage_jobs || other.can_manage_jobs)
+            && (!self.can_manage_applicants || other.can_manage_applicants)
+            && (!self.can_approve_hours || other.can_approve_hours)
+            && (!self.can_view_payroll || other.can_view_payroll)
+            && (!self.can_manage_managers || other.can_manage_managers)
+    }
+}
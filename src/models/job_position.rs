@@ -1,16 +1,45 @@
-use super::{employment::EmploymentState, position_category::PositionCategory};
+use super::{
+    employment::EmploymentState, event::ManagedBy, pagination, position_category::PositionCategory,
+};
 use serde::{Deserialize, Serialize};
+use sqlx::types::time::OffsetDateTime;
 use sqlx::Type;
 use validator::Validate;
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct SelectManyFilter {
+    pub organization_id: Option<i32>,
     pub event_id: Option<i32>,
     pub position_category_id: Option<i32>,
     pub salary: Option<f32>,
     pub currency: Option<SalaryCurrency>,
     pub capacity: Option<i32>,
     pub is_opened_for_registration: Option<bool>,
+    #[serde(skip)]
+    pub managed_by: Option<ManagedBy>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortColumn {
+    Name,
+    Event,
+    Salary,
+    Capacity,
+    #[default]
+    Id,
+}
+
+impl pagination::SortColumn for SortColumn {
+    fn sql(&self) -> &'static str {
+        match self {
+            SortColumn::Name => r#""job_position"."name""#,
+            SortColumn::Event => r#""event"."name""#,
+            SortColumn::Salary => r#""job_position"."salary""#,
+            SortColumn::Capacity => r#""job_position"."capacity""#,
+            SortColumn::Id => r#""job_position"."id""#,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -20,11 +49,29 @@ pub struct JobPosition {
     pub description: Option<String>,
     pub salary: f32,
     pub capacity: i32,
+    /// Sanitized rendering of `instructions_markdown`, safe to embed into pages.
     pub instructions_html: String,
+    pub instructions_markdown: String,
     pub is_opened_for_registration: bool,
     pub currency: SalaryCurrency,
     pub event_id: i32,
     pub position_category_id: i32,
+    pub registration_opens_at: Option<OffsetDateTime>,
+    pub registration_closes_at: Option<OffsetDateTime>,
+}
+
+impl JobPosition {
+    /// Whether workers can apply at `now`: registration has to be opened and `now` has to
+    /// fall into the registration window, if one is set.
+    pub fn is_registration_open_at(&self, now: OffsetDateTime) -> bool {
+        self.is_opened_for_registration
+            && self
+                .registration_opens_at
+                .is_none_or(|opens_at| opens_at <= now)
+            && self
+                .registration_closes_at
+                .is_none_or(|closes_at| now < closes_at)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Validate)]
@@ -37,10 +84,13 @@ pub struct CreateJobPosition {
     pub currency: SalaryCurrency,
     #[validate(range(min = 1, message = "Job must have at least one space."))]
     pub capacity: i32,
-    pub instructions_html: String,
+    /// Markdown source, the repository stores its sanitized HTML rendering alongside.
+    pub instructions_markdown: String,
     pub is_opened_for_registration: bool,
     pub event_id: i32,
     pub position_category_id: i32,
+    pub registration_opens_at: Option<OffsetDateTime>,
+    pub registration_closes_at: Option<OffsetDateTime>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Validate)]
@@ -53,10 +103,13 @@ pub struct PartialJobPosition {
     pub currency: Option<SalaryCurrency>,
     #[validate(range(min = 1, message = "Job must have at least one space."))]
     pub capacity: Option<i32>,
-    pub instructions_html: Option<String>,
+    pub instructions_markdown: Option<String>,
     pub is_opened_for_registration: Option<bool>,
     pub event_id: Option<i32>,
     pub position_category_id: Option<i32>,
+    // `Some(None)` clears the window boundary.
+    pub registration_opens_at: Option<Option<OffsetDateTime>>,
+    pub registration_closes_at: Option<Option<OffsetDateTime>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
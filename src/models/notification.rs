@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+use sqlx::types::time::OffsetDateTime;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Notification {
+    pub id: i32,
+    pub user_id: i32,
+    pub message: String,
+    pub created_at: OffsetDateTime,
+    pub read_at: Option<OffsetDateTime>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateNotification {
+    pub user_id: i32,
+    pub message: String,
+}
@@ -0,0 +1,80 @@
+use serde::{Deserialize, Serialize};
+use sqlx::types::time::OffsetDateTime;
+use sqlx::Type;
+use std::fmt;
+use validator::Validate;
+
+use crate::models::user::UserRole;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Organization {
+    pub id: i32,
+    pub name: String,
+    pub invite_code: String,
+    pub created_at: OffsetDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct CreateOrganization {
+    #[validate(length(
+        min = 3,
+        message = "Organization name must be at least 3 characters long."
+    ))]
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrganizationMembership {
+    pub organization_id: i32,
+    pub user_id: i32,
+    pub role: OrganizationRole,
+    pub created_at: OffsetDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateOrganizationMembership {
+    pub organization_id: i32,
+    pub user_id: i32,
+    pub role: OrganizationRole,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Type, PartialEq, Eq)]
+#[sqlx(type_name = "organization_role", rename_all = "lowercase")]
+pub enum OrganizationRole {
+    Owner,
+    Organizer,
+    Employee,
+}
+
+impl OrganizationRole {
+    /// Role a user with the global `role` gets when joining an organization.
+    pub fn from_user_role(role: &UserRole) -> Self {
+        match role {
+            UserRole::Admin => OrganizationRole::Owner,
+            UserRole::Organizer => OrganizationRole::Organizer,
+            UserRole::Employee => OrganizationRole::Employee,
+        }
+    }
+
+    pub fn can_organize(&self) -> bool {
+        matches!(self, OrganizationRole::Owner | OrganizationRole::Organizer)
+    }
+}
+
+impl fmt::Display for OrganizationRole {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            OrganizationRole::Owner => write!(f, "Owner"),
+            OrganizationRole::Organizer => write!(f, "Organizer"),
+            OrganizationRole::Employee => write!(f, "Employee"),
+        }
+    }
+}
+
+/// Organization the current request operates in, resolved from the session.
+#[derive(Debug, Clone)]
+pub struct ActiveOrganization {
+    pub id: i32,
+    pub name: String,
+    pub role: OrganizationRole,
+}
@@ -0,0 +1,283 @@
+use serde::{Deserialize, Serialize};
+use sqlx::{Postgres, QueryBuilder, Sqlite};
+
+pub const DEFAULT_PAGE_SIZE: i64 = 20;
+pub const MAX_PAGE_SIZE: i64 = 100;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortDirection {
+    Asc,
+    #[default]
+    Desc,
+}
+
+impl SortDirection {
+    fn sql(&self) -> &'static str {
+        match self {
+            SortDirection::Asc => "ASC",
+            SortDirection::Desc => "DESC",
+        }
+    }
+}
+
+/// A column a list can be sorted by. Only the SQL returned by `sql` ever gets into a query,
+/// so the implementing enum is the whitelist of sortable columns.
+pub trait SortColumn: Copy + Default {
+    /// Expression over the tables of the list query, it must not be `NULL` for cursors to work.
+    fn sql(&self) -> &'static str;
+
+    /// [`SortColumn::sql`] on SQLite, which stores enums as text and so has to spell out the
+    /// order Postgres takes from the declaration of the enum type.
+    fn sqlite_sql(&self) -> &'static str {
+        self.sql()
+    }
+
+    /// Direction used when the request does not ask for one.
+    fn default_direction(&self) -> SortDirection {
+        SortDirection::Asc
+    }
+}
+
+/// Which part of a list to return, either a numbered page or the rows after a cursor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PagePosition {
+    /// Numbered from 1.
+    Page(i64),
+    /// Rows following the row with this id, in the order of the list.
+    After(i32),
+}
+
+/// How a list is sorted and which page of it is wanted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ListSpec<C> {
+    pub sort_by: C,
+    pub sort_direction: SortDirection,
+    pub page_size: i64,
+    pub position: PagePosition,
+}
+
+impl<C: SortColumn> Default for ListSpec<C> {
+    fn default() -> Self {
+        Self {
+            sort_by: C::default(),
+            sort_direction: C::default().default_direction(),
+            page_size: DEFAULT_PAGE_SIZE,
+            position: PagePosition::Page(1),
+        }
+    }
+}
+
+impl<C: SortColumn> ListSpec<C> {
+    /// Selects the sort key of a row, to be pushed into the column list of the list query.
+    pub fn push_sort_key(&self, query_builder: &mut QueryBuilder<'_, Postgres>) {
+        query_builder.push(self.sort_by.sql());
+        query_builder.push(r#" AS "sort_key""#);
+    }
+
+    /// Orders rows the way the list is sorted, `alias` names a relation with the `"id"` and
+    /// `"sort_key"` columns of the list query, such as a page of it joined to other tables.
+    pub fn push_order(&self, query_builder: &mut QueryBuilder<'_, Postgres>, alias: &str) {
+        query_builder.push(self.order_sql(alias));
+    }
+
+    /// Closes the list query, which has to be opened with `WITH "list" AS (` and select the
+    /// `"id"` of the rows and their sort key, and selects the requested page of it together
+    /// with the total number of rows.
+    pub fn push_page(&self, query_builder: &mut QueryBuilder<'_, Postgres>) {
+        query_builder.push(PAGE_SQL);
+        if let PagePosition::After(cursor) = self.position {
+            query_builder.push(self.cursor_sql());
+            query_builder.push_bind(cursor);
+            query_builder.push(")");
+        }
+        self.push_order(query_builder, "list");
+        query_builder.push(" LIMIT ");
+        query_builder.push_bind(self.page_size);
+        if let PagePosition::Page(page) = self.position {
+            query_builder.push(" OFFSET ");
+            query_builder.push_bind((page - 1) * self.page_size);
+        }
+    }
+
+    /// [`ListSpec::push_sort_key`] on SQLite.
+    pub fn push_sqlite_sort_key(&self, query_builder: &mut QueryBuilder<'_, Sqlite>) {
+        query_builder.push(self.sort_by.sqlite_sql());
+        query_builder.push(r#" AS "sort_key""#);
+    }
+
+    /// [`ListSpec::push_order`] on SQLite.
+    pub fn push_sqlite_order(&self, query_builder: &mut QueryBuilder<'_, Sqlite>, alias: &str) {
+        query_builder.push(self.order_sql(alias));
+    }
+
+    /// [`ListSpec::push_page`] on SQLite.
+    pub fn push_sqlite_page(&self, query_builder: &mut QueryBuilder<'_, Sqlite>) {
+        query_builder.push(PAGE_SQL);
+        if let PagePosition::After(cursor) = self.position {
+            query_builder.push(self.cursor_sql());
+            query_builder.push_bind(cursor);
+            query_builder.push(")");
+        }
+        self.push_sqlite_order(query_builder, "list");
+        query_builder.push(" LIMIT ");
+        query_builder.push_bind(self.page_size);
+        if let PagePosition::Page(page) = self.position {
+            query_builder.push(" OFFSET ");
+            query_builder.push_bind((page - 1) * self.page_size);
+        }
+    }
+
+    fn order_sql(&self, alias: &str) -> String {
+        let direction = self.sort_direction.sql();
+        format!(r#" ORDER BY "{alias}"."sort_key" {direction}, "{alias}"."id" {direction}"#)
+    }
+
+    /// Condition keeping the rows after the cursor, to be followed by its id and `)`.
+    fn cursor_sql(&self) -> String {
+        let comparison = match self.sort_direction {
+            SortDirection::Asc => ">",
+            SortDirection::Desc => "<",
+        };
+        format!(
+            r#" WHERE ("sort_key", "id") {comparison} (SELECT "sort_key", "id" FROM "list" WHERE "id" = "#
+        )
+    }
+}
+
+const PAGE_SQL: &str = r#") SELECT *, (SELECT COUNT(*) FROM "list") AS "total_count" FROM "list""#;
+
+/// Query parameters of a list view, turned into a [`ListSpec`] with sane bounds.
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct ListParams<C> {
+    pub page: Option<i64>,
+    pub after: Option<i32>,
+    pub page_size: Option<i64>,
+    pub sort_by: Option<C>,
+    pub sort_direction: Option<SortDirection>,
+}
+
+impl<C> Default for ListParams<C> {
+    fn default() -> Self {
+        Self {
+            page: None,
+            after: None,
+            page_size: None,
+            sort_by: None,
+            sort_direction: None,
+        }
+    }
+}
+
+impl<C: SortColumn> ListParams<C> {
+    pub fn spec(&self) -> ListSpec<C> {
+        let position = match (self.after, self.page) {
+            (Some(cursor), _) => PagePosition::After(cursor),
+            (None, page) => PagePosition::Page(page.unwrap_or(1).max(1)),
+        };
+        let sort_by = self.sort_by.unwrap_or_default();
+        ListSpec {
+            sort_by,
+            sort_direction: self
+                .sort_direction
+                .unwrap_or_else(|| sort_by.default_direction()),
+            page_size: self
+                .page_size
+                .unwrap_or(DEFAULT_PAGE_SIZE)
+                .clamp(1, MAX_PAGE_SIZE),
+            position,
+        }
+    }
+}
+
+impl<C: Serialize> ListParams<C> {
+    /// Sorting of the request as query parameters, to keep it in links to other pages.
+    pub fn sort_query(&self) -> String {
+        let mut query = Vec::new();
+        if let Some(sort_by) = self.sort_by.as_ref().and_then(query_value) {
+            query.push(format!("sort_by={sort_by}"));
+        }
+        if let Some(sort_direction) = self.sort_direction.as_ref().and_then(query_value) {
+            query.push(format!("sort_direction={sort_direction}"));
+        }
+        query.join("&")
+    }
+}
+
+fn query_value(value: &impl Serialize) -> Option<String> {
+    serde_json::to_value(value)
+        .ok()
+        .and_then(|value| value.as_str().map(str::to_owned))
+}
+
+/// One page of a list.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    /// Number of rows matching the filter on all pages.
+    pub total: i64,
+    pub position: PagePosition,
+    pub page_size: i64,
+    /// Cursor of the following page, if there may be one.
+    pub next_cursor: Option<i32>,
+}
+
+impl<T> Page<T> {
+    pub fn new<C>(
+        items: Vec<T>,
+        total: i64,
+        spec: &ListSpec<C>,
+        id_of: impl Fn(&T) -> i32,
+    ) -> Self {
+        let next_cursor = if items.len() as i64 == spec.page_size {
+            items.last().map(id_of)
+        } else {
+            None
+        };
+        Self {
+            items,
+            total,
+            position: spec.position,
+            page_size: spec.page_size,
+            next_cursor,
+        }
+    }
+
+    pub fn total_pages(&self) -> i64 {
+        (self.total + self.page_size - 1) / self.page_size
+    }
+
+    /// Number of the page, cursor based pages have none.
+    pub fn page(&self) -> Option<i64> {
+        match self.position {
+            PagePosition::Page(page) => Some(page),
+            PagePosition::After(_) => None,
+        }
+    }
+}
+
+/// Adds a case-insensitive substring condition on `column`.
+pub fn push_contains(query_builder: &mut QueryBuilder<'_, Postgres>, column: &str, value: String) {
+    query_builder.push(format!(" AND {column} ILIKE "));
+    query_builder.push_bind(contains_pattern(&value));
+}
+
+/// [`push_contains`] on SQLite, whose `LIKE` ignores the case of ASCII letters only.
+pub fn push_sqlite_contains(
+    query_builder: &mut QueryBuilder<'_, Sqlite>,
+    column: &str,
+    value: String,
+) {
+    query_builder.push(format!(" AND {column} LIKE "));
+    query_builder.push_bind(contains_pattern(&value));
+    query_builder.push(r" ESCAPE '\'");
+}
+
+/// `LIKE` pattern matching values that contain `value`, with its wildcards escaped.
+fn contains_pattern(value: &str) -> String {
+    let pattern = value
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+    format!("%{pattern}%")
+}
@@ -5,6 +5,7 @@ use validator::Validate;
 pub struct PositionCategory {
     pub id: i32,
     pub name: String,
+    pub organization_id: i32,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Validate)]
@@ -14,6 +15,7 @@ pub struct CreatePositionCategory {
         message = "Position category name must be at least 3 characters long."
     ))]
     pub name: String,
+    pub organization_id: i32,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Validate)]
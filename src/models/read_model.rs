@@ -0,0 +1,40 @@
+//! Rows of list views, each read by a single query joining everything the view shows.
+
+use crate::models::{
+    employment::Employment, event::Event, job_position::JobPosition,
+    position_category::PositionCategory, user::User, venue::Venue,
+};
+
+/// An event with its venue and owner, as event cards and tables show it.
+#[derive(Debug, Clone)]
+pub struct EventListing {
+    pub event: Event,
+    pub venue: Venue,
+    pub owner: User,
+}
+
+/// An employment with its job, event, venue and employee.
+#[derive(Debug, Clone)]
+pub struct EmploymentListing {
+    pub employment: Employment,
+    pub position: JobPosition,
+    pub event: Event,
+    pub venue: Venue,
+    pub employee: User,
+    /// Workers accepted to the job position, finished ones included.
+    pub current_capacity: i64,
+    /// Sum of the hours logged on the employment.
+    pub hours_worked: f32,
+    pub reviewed_by_worker: bool,
+    pub reviewed_by_organizer: bool,
+}
+
+/// A job position with its event, category and the number of accepted workers.
+#[derive(Debug, Clone)]
+pub struct JobPositionListing {
+    pub position: JobPosition,
+    pub event: Event,
+    pub category: PositionCategory,
+    /// Workers accepted to the job position, finished ones included.
+    pub current_capacity: i64,
+}
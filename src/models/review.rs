@@ -0,0 +1,147 @@
+use crate::models::pagination;
+use serde::{Deserialize, Serialize};
+use sqlx::types::time::{Date, OffsetDateTime};
+use sqlx::Type;
+use time::Duration;
+use validator::Validate;
+
+/// Days after the end of an event during which its jobs can be reviewed. Reviews stay
+/// hidden until both sides have written theirs or this period is over.
+pub const REVIEW_WINDOW_DAYS: i32 = 14;
+
+/// Last day reviews of the event's jobs can be written on.
+pub fn review_deadline(event_end: Date) -> Date {
+    event_end + Duration::days(REVIEW_WINDOW_DAYS as i64)
+}
+
+/// Who wrote the review about whom.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Type, PartialEq, Eq)]
+#[sqlx(type_name = "review_direction", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ReviewDirection {
+    OrganizerToWorker,
+    WorkerToOrganizer,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Review {
+    pub id: i32,
+    pub employment_id: i32,
+    pub direction: ReviewDirection,
+    pub author_id: Option<i32>,
+    pub subject_id: i32,
+    pub event_id: i32,
+    pub overall: i32,
+    pub punctuality: Option<i32>,
+    pub skill: Option<i32>,
+    pub organization: Option<i32>,
+    pub communication: Option<i32>,
+    pub comment: String,
+    pub flagged_at: Option<OffsetDateTime>,
+    pub flagged_by: Option<i32>,
+    pub flag_reason: Option<String>,
+    pub is_hidden: bool,
+    pub created_at: OffsetDateTime,
+}
+
+/// Organizers score workers on punctuality and skill, workers score the event's
+/// organization and communication.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct CreateReview {
+    pub employment_id: i32,
+    pub direction: ReviewDirection,
+    pub author_id: i32,
+    #[validate(range(min = 1, max = 5, message = "Scores are out of bounds <1, 5>"))]
+    pub overall: i32,
+    #[validate(range(min = 1, max = 5, message = "Scores are out of bounds <1, 5>"))]
+    pub punctuality: Option<i32>,
+    #[validate(range(min = 1, max = 5, message = "Scores are out of bounds <1, 5>"))]
+    pub skill: Option<i32>,
+    #[validate(range(min = 1, max = 5, message = "Scores are out of bounds <1, 5>"))]
+    pub organization: Option<i32>,
+    #[validate(range(min = 1, max = 5, message = "Scores are out of bounds <1, 5>"))]
+    pub communication: Option<i32>,
+    #[validate(length(
+        max = 1000,
+        message = "Comment is too long. Maximum is 1000 characters."
+    ))]
+    pub comment: String,
+}
+
+/// A review that is already visible on profile and event pages.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PublishedReview {
+    pub id: i32,
+    pub direction: ReviewDirection,
+    pub author_name: Option<String>,
+    pub event_id: i32,
+    pub event_name: String,
+    pub overall: i32,
+    pub punctuality: Option<i32>,
+    pub skill: Option<i32>,
+    pub organization: Option<i32>,
+    pub communication: Option<i32>,
+    pub comment: String,
+    pub created_at: OffsetDateTime,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortColumn {
+    #[default]
+    FlaggedAt,
+}
+
+impl pagination::SortColumn for SortColumn {
+    fn sql(&self) -> &'static str {
+        match self {
+            SortColumn::FlaggedAt => r#""review"."flagged_at""#,
+        }
+    }
+}
+
+/// A review someone reported, waiting for an admin's decision.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FlaggedReview {
+    pub id: i32,
+    pub author_name: Option<String>,
+    pub subject_name: String,
+    pub event_name: String,
+    pub overall: i32,
+    pub comment: String,
+    pub flag_reason: Option<String>,
+    pub flagged_by_name: Option<String>,
+    pub flagged_at: OffsetDateTime,
+}
+
+/// Average scores of published reviews, `None` where nobody scored the category.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ReviewSummary {
+    pub count: usize,
+    pub overall: Option<f32>,
+    pub punctuality: Option<f32>,
+    pub skill: Option<f32>,
+    pub organization: Option<f32>,
+    pub communication: Option<f32>,
+}
+
+impl ReviewSummary {
+    pub fn from_reviews(reviews: &[PublishedReview]) -> Self {
+        fn average(scores: impl Iterator<Item = Option<i32>>) -> Option<f32> {
+            let scores: Vec<i32> = scores.flatten().collect();
+            if scores.is_empty() {
+                return None;
+            }
+            Some(scores.iter().sum::<i32>() as f32 / scores.len() as f32)
+        }
+
+        Self {
+            count: reviews.len(),
+            overall: average(reviews.iter().map(|review| Some(review.overall))),
+            punctuality: average(reviews.iter().map(|review| review.punctuality)),
+            skill: average(reviews.iter().map(|review| review.skill)),
+            organization: average(reviews.iter().map(|review| review.organization)),
+            communication: average(reviews.iter().map(|review| review.communication)),
+        }
+    }
+}
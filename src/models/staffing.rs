@@ -0,0 +1,91 @@
+use serde::{Deserialize, Serialize};
+use std::env;
+
+/// Everything the suggestion engine knows about one candidate for a job position.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CandidateStats {
+    pub user_id: i32,
+    pub first_name: String,
+    pub last_name: String,
+    pub username: String,
+    /// Average of the non-zero ratings of finished jobs, `None` for newcomers.
+    pub average_rating: Option<f32>,
+    pub done_in_category: i64,
+    /// Open applications, invitations and accepted jobs overlapping the event dates.
+    pub conflicts: i64,
+    /// Days the candidate was recorded as a no-show.
+    pub no_shows: i64,
+}
+
+/// Relative importance of the parts of a candidate's score.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct StaffingWeights {
+    pub rating: f32,
+    pub experience: f32,
+    pub availability: f32,
+    pub reliability: f32,
+}
+
+impl Default for StaffingWeights {
+    fn default() -> Self {
+        Self {
+            rating: 0.35,
+            experience: 0.25,
+            availability: 0.25,
+            reliability: 0.15,
+        }
+    }
+}
+
+impl StaffingWeights {
+    /// Reads the weights from `STAFFING_WEIGHT_*` variables, keeping defaults for missing ones.
+    pub fn from_env() -> Self {
+        let weight = |name: &str, default: f32| {
+            env::var(name)
+                .ok()
+                .and_then(|value| value.parse::<f32>().ok())
+                .filter(|value| *value >= 0.0)
+                .unwrap_or(default)
+        };
+        let default = Self::default();
+        Self {
+            rating: weight("STAFFING_WEIGHT_RATING", default.rating),
+            experience: weight("STAFFING_WEIGHT_EXPERIENCE", default.experience),
+            availability: weight("STAFFING_WEIGHT_AVAILABILITY", default.availability),
+            reliability: weight("STAFFING_WEIGHT_RELIABILITY", default.reliability),
+        }
+    }
+}
+
+/// Jobs in the same category after which more experience stops adding to the score.
+pub const EXPERIENCE_CAP: i64 = 5;
+
+/// Weighted parts of a candidate's score, they add up to `total`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScoreBreakdown {
+    pub rating: f32,
+    pub experience: f32,
+    pub availability: f32,
+    pub reliability: f32,
+    pub total: f32,
+}
+
+pub fn score(stats: &CandidateStats, weights: &StaffingWeights) -> ScoreBreakdown {
+    // Unrated candidates are neither rewarded nor punished.
+    let rating = stats.average_rating.map_or(0.5, |rating| rating / 5.0);
+    let experience = stats.done_in_category.min(EXPERIENCE_CAP) as f32 / EXPERIENCE_CAP as f32;
+    let availability = if stats.conflicts > 0 { 0.0 } else { 1.0 };
+    let reliability = 1.0 / (1.0 + stats.no_shows as f32);
+
+    let rating = rating * weights.rating;
+    let experience = experience * weights.experience;
+    let availability = availability * weights.availability;
+    let reliability = reliability * weights.reliability;
+    ScoreBreakdown {
+        rating,
+        experience,
+        availability,
+        reliability,
+        total: rating + experience + availability + reliability,
+    }
+}
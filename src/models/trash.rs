@@ -0,0 +1,22 @@
+use crate::models::pagination::{self, SortDirection};
+use serde::{Deserialize, Serialize};
+
+/// Order of soft-deleted records, the most recently deleted first.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortColumn {
+    #[default]
+    DeletedAt,
+}
+
+impl pagination::SortColumn for SortColumn {
+    fn sql(&self) -> &'static str {
+        match self {
+            SortColumn::DeletedAt => r#""deleted_at""#,
+        }
+    }
+
+    fn default_direction(&self) -> SortDirection {
+        SortDirection::Desc
+    }
+}
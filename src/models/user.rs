@@ -1,4 +1,6 @@
+use crate::models::pagination;
 use crate::regex::RE_PHONE_NUMBER;
+use crate::storage::validate_image_url;
 use serde::{Deserialize, Serialize};
 use sqlx::types::time::Date;
 use sqlx::Type;
@@ -6,14 +8,49 @@ use std::fmt;
 use std::str::FromStr;
 use validator::Validate;
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct SelectManyFilter {
+    pub organization_id: Option<i32>,
     pub first_name: Option<String>,
     pub last_name: Option<String>,
     pub username: Option<String>,
     pub gender: Option<Gender>,
     pub role: Option<UserRole>,
     pub tax_rate: Option<f32>,
+    pub last_name_contains: Option<String>,
+    pub username_contains: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortColumn {
+    #[default]
+    Name,
+    Username,
+    Role,
+    BirthDate,
+    Id,
+}
+
+impl pagination::SortColumn for SortColumn {
+    fn sql(&self) -> &'static str {
+        match self {
+            SortColumn::Name => r#""user"."last_name" || ' ' || "user"."first_name""#,
+            SortColumn::Username => r#""user"."username""#,
+            SortColumn::Role => r#""user"."role""#,
+            SortColumn::BirthDate => r#""user"."birth_date""#,
+            SortColumn::Id => r#""user"."id""#,
+        }
+    }
+
+    fn sqlite_sql(&self) -> &'static str {
+        match self {
+            SortColumn::Role => {
+                r#"CASE "user"."role" WHEN 'employee' THEN 0 WHEN 'organizer' THEN 1 ELSE 2 END"#
+            }
+            _ => self.sql(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -54,7 +91,10 @@ pub struct CreateUser {
         message = "Tax rate is out of bounds <0, 100>."
     ))]
     pub tax_rate: f32,
-    #[validate(url(message = "Avatar URL is not in the correct format."))]
+    #[validate(custom(
+        function = "validate_image_url",
+        message = "Avatar URL is not in the correct format."
+    ))]
     pub avatar_url: Option<String>,
 }
 
@@ -80,7 +120,10 @@ pub struct PartialUser {
         message = "Tax rate is out of bounds <0, 100>."
     ))]
     pub tax_rate: Option<f32>,
-    #[validate(url(message = "Avatar URL is not in the correct format."))]
+    #[validate(custom(
+        function = "validate_image_url",
+        message = "Avatar URL is not in the correct format."
+    ))]
     pub avatar_url: Option<String>,
 }
 
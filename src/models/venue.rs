@@ -1,9 +1,11 @@
+use crate::models::pagination;
 use crate::regex::{RE_POSTAL_CODE, RE_STREET_NUMBER};
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct SelectManyFilter {
+    pub organization_id: Option<i32>,
     pub name: Option<String>,
     pub description: Option<String>,
     pub state: Option<String>,
@@ -13,6 +15,25 @@ pub struct SelectManyFilter {
     pub street_number: Option<String>,
 }
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortColumn {
+    #[default]
+    Name,
+    State,
+    Town,
+}
+
+impl pagination::SortColumn for SortColumn {
+    fn sql(&self) -> &'static str {
+        match self {
+            SortColumn::Name => r#""name""#,
+            SortColumn::State => r#""state""#,
+            SortColumn::Town => r#""town""#,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Venue {
     pub id: i32,
@@ -24,6 +45,7 @@ pub struct Venue {
     pub street_name: String,
     pub street_number: String,
     pub address_url: Option<String>,
+    pub organization_id: i32,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Validate)]
@@ -43,6 +65,7 @@ pub struct CreateVenue {
     pub street_number: String,
     #[validate(url(message = "Address URL is not in the correct format."))]
     pub address_url: Option<String>,
+    pub organization_id: i32,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Validate)]
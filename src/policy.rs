@@ -0,0 +1,192 @@
+use crate::error::RepositoryError;
+use crate::models::event::Event;
+use crate::models::event_manager_relation::EventManagerPermissions;
+use crate::models::organization::OrganizationRole;
+use crate::models::user::{User, UserRole};
+use crate::repositories::database::{Database, Repositories};
+use crate::repositories::employment::EmploymentRepository;
+use crate::repositories::event::EventRepository;
+use crate::repositories::event_manager_relation::EventManagerRelationRepository;
+use crate::repositories::job_position::JobPositionRepository;
+use crate::repositories::organization::OrganizationRepository;
+use crate::repositories::pool_handler::PoolHandler;
+use std::sync::Arc;
+use strum::EnumIter;
+
+/// Identifies the event a request operates on, directly or through one of its children.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventScope {
+    Event(i32),
+    JobPosition(i32),
+    Employment(i32),
+}
+
+/// Things a user may want to do with an event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter)]
+pub enum EventAction {
+    ViewDraft,
+    EditEvent,
+    DeleteEvent,
+    ManageJobs,
+    ManageApplicants,
+    ApproveHours,
+    ViewPayroll,
+    ManageManagers,
+}
+
+/// How a user is related to an event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventRelation {
+    Owner,
+    Manager(EventManagerPermissions),
+    None,
+}
+
+impl EventAction {
+    /// SQL condition on an `event_manager_relation` row granting a manager this action, the
+    /// counterpart of [`is_allowed`] for filtering lists.
+    pub fn manager_condition(&self) -> &'static str {
+        match self {
+            EventAction::ViewDraft => "TRUE",
+            EventAction::EditEvent => r#""can_edit_event""#,
+            EventAction::DeleteEvent => "FALSE",
+            EventAction::ManageJobs => r#""can_manage_jobs""#,
+            EventAction::ManageApplicants => r#""can_manage_applicants""#,
+            EventAction::ApproveHours => r#""can_approve_hours""#,
+            EventAction::ViewPayroll => r#""can_view_payroll""#,
+            EventAction::ManageManagers => r#""can_manage_managers""#,
+        }
+    }
+}
+
+/// Managers act on the event as far as their permissions reach, only the owner may delete it.
+/// Employees never act on events as organizers, even when listed on one.
+pub fn is_allowed(role: &UserRole, relation: EventRelation, action: EventAction) -> bool {
+    match (role, relation) {
+        (UserRole::Admin, _) => true,
+        (UserRole::Organizer, EventRelation::Owner) => true,
+        (UserRole::Organizer, EventRelation::Manager(permissions)) => match action {
+            EventAction::ViewDraft => true,
+            EventAction::EditEvent => permissions.can_edit_event,
+            EventAction::DeleteEvent => false,
+            EventAction::ManageJobs => permissions.can_manage_jobs,
+            EventAction::ManageApplicants => permissions.can_manage_applicants,
+            EventAction::ApproveHours => permissions.can_approve_hours,
+            EventAction::ViewPayroll => permissions.can_view_payroll,
+            EventAction::ManageManagers => permissions.can_manage_managers,
+        },
+        _ => false,
+    }
+}
+
+/// Single place deciding who may act on an event.
+#[derive(Clone)]
+pub struct EventPolicy {
+    event_repository: Arc<dyn EventRepository>,
+    job_position_repository: Arc<dyn JobPositionRepository>,
+    employment_repository: Arc<dyn EmploymentRepository>,
+    event_manager_relation_repository: Arc<dyn EventManagerRelationRepository>,
+    organization_repository: Arc<dyn OrganizationRepository>,
+}
+
+impl EventPolicy {
+    pub fn new(pool_handler: PoolHandler) -> Self {
+        Self::from_repositories(&Database::Postgres(pool_handler).repositories())
+    }
+
+    pub fn from_repositories(repositories: &Repositories) -> Self {
+        Self {
+            event_repository: repositories.event.clone(),
+            job_position_repository: repositories.job_position.clone(),
+            employment_repository: repositories.employment.clone(),
+            event_manager_relation_repository: repositories.event_manager_relation.clone(),
+            organization_repository: repositories.organization.clone(),
+        }
+    }
+
+    pub async fn resolve_event(&self, scope: EventScope) -> Result<Event, RepositoryError> {
+        let event_id = match scope {
+            EventScope::Event(event_id) => event_id,
+            EventScope::JobPosition(job_position_id) => {
+                self.job_position_repository
+                    .get_job_position_by_id(job_position_id)
+                    .await?
+                    .event_id
+            }
+            EventScope::Employment(employment_id) => {
+                let employment = self
+                    .employment_repository
+                    .get_employment_by_id(employment_id)
+                    .await?;
+                self.job_position_repository
+                    .get_job_position_by_id(employment.position_id)
+                    .await?
+                    .event_id
+            }
+        };
+
+        self.event_repository.get_event_by_id(event_id).await
+    }
+
+    pub async fn relation(
+        &self,
+        user: &User,
+        event: &Event,
+    ) -> Result<EventRelation, RepositoryError> {
+        if event.owner_id == user.id {
+            return Ok(EventRelation::Owner);
+        }
+
+        match self
+            .event_manager_relation_repository
+            .get_relation(event.id, user.id)
+            .await
+        {
+            Ok(relation) => Ok(EventRelation::Manager(relation.permissions())),
+            Err(RepositoryError::NotFound) => Ok(EventRelation::None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Outside of admins, the role the user holds in the organization running the event counts
+    /// instead of the global one.
+    pub async fn is_authorized(
+        &self,
+        user: &User,
+        event: &Event,
+        action: EventAction,
+    ) -> Result<bool, RepositoryError> {
+        if user.role == UserRole::Admin {
+            return Ok(true);
+        }
+
+        let membership = match self
+            .organization_repository
+            .get_membership(event.organization_id, user.id)
+            .await
+        {
+            Ok(membership) => membership,
+            Err(RepositoryError::NotFound) => return Ok(false),
+            Err(err) => return Err(err),
+        };
+        let role = match membership.role {
+            OrganizationRole::Owner => return Ok(true),
+            OrganizationRole::Organizer => UserRole::Organizer,
+            OrganizationRole::Employee => UserRole::Employee,
+        };
+
+        let relation = self.relation(user, event).await?;
+        Ok(is_allowed(&role, relation, action))
+    }
+
+    /// Resolves the event behind `scope` and checks `action` against it.
+    pub async fn authorize(
+        &self,
+        user: &User,
+        scope: EventScope,
+        action: EventAction,
+    ) -> Result<bool, RepositoryError> {
+        let event = self.resolve_event(scope).await?;
+        self.is_authorized(user, &event, action).await
+    }
+}
@@ -1,9 +1,20 @@
+pub mod attendance;
+pub mod audit_log;
+pub(crate) mod columns;
+pub mod database;
 pub mod employment;
 pub mod event;
 pub mod event_manager_relation;
 pub mod job_position;
+pub mod memory;
+pub mod notification;
+pub mod organization;
 pub mod pool_handler;
 pub mod position_category;
+pub mod read_model;
+pub mod review;
+pub mod sqlite;
+pub mod unit_of_work;
 pub mod user;
 pub mod venue;
 pub mod worked_hours;
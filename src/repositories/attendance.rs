@@ -0,0 +1,215 @@
+use crate::error::RepositoryError;
+use crate::models::attendance::{
+    AttendanceOutcome, AttendanceRecord, AttendanceSummary, RecordAttendance,
+};
+use crate::models::employment::EmploymentState;
+use crate::repositories::pool_handler::PoolHandler;
+use anyhow::Result;
+use async_trait::async_trait;
+use sqlx::types::time::Date;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait AttendanceRepository: Send + Sync {
+    fn with_actor(&self, actor_id: i32) -> Arc<dyn AttendanceRepository>;
+    /// Records how the worker showed up on one day of the event, replacing an outcome
+    /// recorded for the same day before.
+    async fn record_attendance(
+        &self,
+        record: RecordAttendance,
+    ) -> Result<AttendanceRecord, RepositoryError>;
+    /// Recorded outcomes of the given employments, by day.
+    async fn list_attendance(
+        &self,
+        employment_ids: &[i32],
+    ) -> Result<Vec<AttendanceRecord>, RepositoryError>;
+    async fn attendance_summary(
+        &self,
+        user_id: i32,
+        organization_id: Option<i32>,
+    ) -> Result<AttendanceSummary, RepositoryError>;
+    async fn count_no_shows_since(
+        &self,
+        user_id: i32,
+        organization_id: Option<i32>,
+        since: Date,
+    ) -> Result<i64, RepositoryError>;
+}
+
+#[derive(Debug, Clone)]
+pub struct PgAttendanceRepository {
+    pub pool_handler: PoolHandler,
+    pub actor_id: Option<i32>,
+}
+
+impl PgAttendanceRepository {
+    pub fn new(pool_handler: PoolHandler) -> Self {
+        Self {
+            pool_handler,
+            actor_id: None,
+        }
+    }
+
+    /// Returns a copy of the repository recording outcomes on behalf of `actor_id`.
+    pub fn with_actor(&self, actor_id: i32) -> Self {
+        Self {
+            pool_handler: self.pool_handler.clone(),
+            actor_id: Some(actor_id),
+        }
+    }
+}
+
+#[async_trait]
+impl AttendanceRepository for PgAttendanceRepository {
+    fn with_actor(&self, actor_id: i32) -> Arc<dyn AttendanceRepository> {
+        Arc::new(PgAttendanceRepository::with_actor(self, actor_id))
+    }
+
+    async fn record_attendance(
+        &self,
+        record: RecordAttendance,
+    ) -> Result<AttendanceRecord, RepositoryError> {
+        let employment = sqlx::query!(
+            r#"SELECT
+                "employment"."state" AS "state: EmploymentState",
+                "event"."date_start",
+                "event"."date_end",
+                CURRENT_DATE AS "today!"
+            FROM "employment"
+            JOIN "job_position" ON "job_position"."id" = "employment"."position_id"
+            JOIN "event" ON "event"."id" = "job_position"."event_id"
+            WHERE "employment"."id" = $1"#,
+            record.employment_id
+        )
+        .fetch_optional(self.pool_handler.pool())
+        .await?
+        .ok_or(RepositoryError::NotFound)?;
+
+        if !matches!(
+            employment.state,
+            EmploymentState::Accepted | EmploymentState::Done
+        ) {
+            return Err(RepositoryError::GenericError(
+                "Attendance can only be recorded for accepted or finished jobs".to_string(),
+            ));
+        }
+        if record.date < employment.date_start || record.date > employment.date_end {
+            return Err(RepositoryError::GenericError(
+                "Attendance date is outside of the event's dates".to_string(),
+            ));
+        }
+        if record.date > employment.today {
+            return Err(RepositoryError::GenericError(
+                "Attendance cannot be recorded for future days".to_string(),
+            ));
+        }
+
+        let note = record
+            .note
+            .map(|note| note.trim().to_string())
+            .filter(|note| !note.is_empty());
+        let attendance = sqlx::query_as!(
+            AttendanceRecord,
+            r#"INSERT INTO "attendance_record" (
+                "employment_id", "date", "outcome", "note", "recorded_by"
+            )
+            VALUES ($1, $2, $3::attendance_outcome, $4, $5)
+            ON CONFLICT ("employment_id", "date") DO UPDATE SET
+                "outcome" = EXCLUDED."outcome",
+                "note" = EXCLUDED."note",
+                "recorded_by" = EXCLUDED."recorded_by",
+                "created_at" = NOW()
+            RETURNING
+                "id",
+                "employment_id",
+                "date",
+                "outcome" AS "outcome: AttendanceOutcome",
+                "note",
+                "recorded_by",
+                "created_at""#,
+            record.employment_id,
+            record.date,
+            record.outcome as _,
+            note,
+            self.actor_id
+        )
+        .fetch_one(self.pool_handler.pool())
+        .await?;
+        Ok(attendance)
+    }
+
+    async fn list_attendance(
+        &self,
+        employment_ids: &[i32],
+    ) -> Result<Vec<AttendanceRecord>, RepositoryError> {
+        let records = sqlx::query_as!(
+            AttendanceRecord,
+            r#"SELECT
+                "id",
+                "employment_id",
+                "date",
+                "outcome" AS "outcome: AttendanceOutcome",
+                "note",
+                "recorded_by",
+                "created_at"
+            FROM "attendance_record"
+            WHERE "employment_id" = ANY($1)
+            ORDER BY "date", "id""#,
+            employment_ids
+        )
+        .fetch_all(self.pool_handler.pool())
+        .await?;
+        Ok(records)
+    }
+
+    async fn attendance_summary(
+        &self,
+        user_id: i32,
+        organization_id: Option<i32>,
+    ) -> Result<AttendanceSummary, RepositoryError> {
+        let summary = sqlx::query_as!(
+            AttendanceSummary,
+            r#"SELECT
+                COUNT(*) FILTER (WHERE "attendance_record"."outcome" = 'attended') AS "attended!",
+                COUNT(*) FILTER (WHERE "attendance_record"."outcome" = 'late') AS "late!",
+                COUNT(*) FILTER (WHERE "attendance_record"."outcome" = 'no_show') AS "no_shows!",
+                COUNT(*) FILTER (WHERE "attendance_record"."outcome" = 'left_early') AS "left_early!"
+            FROM "attendance_record"
+            JOIN "employment" ON "employment"."id" = "attendance_record"."employment_id"
+            JOIN "job_position" ON "job_position"."id" = "employment"."position_id"
+            JOIN "event" ON "event"."id" = "job_position"."event_id"
+            WHERE "employment"."user_id" = $1
+                AND ($2::INT IS NULL OR "event"."organization_id" = $2)"#,
+            user_id,
+            organization_id
+        )
+        .fetch_one(self.pool_handler.pool())
+        .await?;
+        Ok(summary)
+    }
+
+    async fn count_no_shows_since(
+        &self,
+        user_id: i32,
+        organization_id: Option<i32>,
+        since: Date,
+    ) -> Result<i64, RepositoryError> {
+        let count = sqlx::query_scalar!(
+            r#"SELECT COUNT(*) AS "count!"
+            FROM "attendance_record"
+            JOIN "employment" ON "employment"."id" = "attendance_record"."employment_id"
+            JOIN "job_position" ON "job_position"."id" = "employment"."position_id"
+            JOIN "event" ON "event"."id" = "job_position"."event_id"
+            WHERE "employment"."user_id" = $1
+                AND ($2::INT IS NULL OR "event"."organization_id" = $2)
+                AND "attendance_record"."outcome" = 'no_show'
+                AND "attendance_record"."date" >= $3"#,
+            user_id,
+            organization_id,
+            since
+        )
+        .fetch_one(self.pool_handler.pool())
+        .await?;
+        Ok(count)
+    }
+}
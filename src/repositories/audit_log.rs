@@ -0,0 +1,211 @@
+use crate::error::RepositoryError;
+use crate::models::audit_log::{
+    AuditAction, AuditEntity, AuditLog, CreateAuditLog, SelectManyFilter, SortColumn,
+};
+use crate::models::pagination::{ListSpec, Page};
+use crate::repositories::pool_handler::PoolHandler;
+use anyhow::Result;
+use async_trait::async_trait;
+use serde::Serialize;
+use serde_json::Value;
+use sqlx::postgres::PgRow;
+use sqlx::{PgConnection, Postgres, QueryBuilder, Row};
+
+const AUDIT_LOG_COLUMNS: &str = r#""id",
+    "actor_id",
+    "entity",
+    "entity_id",
+    "event_id",
+    "action",
+    "before",
+    "after",
+    "created_at""#;
+
+/// Fields that must never end up in the audit trail.
+const REDACTED_FIELDS: [&str; 1] = ["password_hash"];
+
+#[async_trait]
+pub trait AuditLogRepository: Send + Sync {
+    async fn list_audit_logs(&self, filter: SelectManyFilter) -> Result<Vec<AuditLog>>;
+    async fn list_audit_logs_page(
+        &self,
+        filter: SelectManyFilter,
+        spec: &ListSpec<SortColumn>,
+    ) -> Result<Page<AuditLog>>;
+}
+
+#[derive(Debug, Clone)]
+pub struct PgAuditLogRepository {
+    pub pool_handler: PoolHandler,
+}
+
+impl PgAuditLogRepository {
+    pub fn new(pool_handler: PoolHandler) -> Self {
+        Self { pool_handler }
+    }
+
+    /// Appends an entry on `connection`, so that it is only kept if the change it describes is.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn record_in<T: Serialize + Sync>(
+        connection: &mut PgConnection,
+        actor_id: Option<i32>,
+        entity: AuditEntity,
+        entity_id: i32,
+        event_id: Option<i32>,
+        action: AuditAction,
+        before: Option<&T>,
+        after: Option<&T>,
+    ) -> Result<AuditLog, RepositoryError> {
+        Self::create_audit_log_in(
+            connection,
+            CreateAuditLog {
+                actor_id,
+                entity,
+                entity_id,
+                event_id,
+                action,
+                before: before.map(snapshot).transpose()?,
+                after: after.map(snapshot).transpose()?,
+            },
+        )
+        .await
+    }
+
+    async fn create_audit_log_in(
+        connection: &mut PgConnection,
+        new_log: CreateAuditLog,
+    ) -> Result<AuditLog, RepositoryError> {
+        let log = sqlx::query_as!(
+            AuditLog,
+            r#"INSERT INTO "audit_log"
+            ("actor_id", "entity", "entity_id", "event_id", "action", "before", "after")
+            VALUES ($1, $2::audit_entity, $3, $4, $5::audit_action, $6, $7)
+            RETURNING
+                "id",
+                "actor_id",
+                "entity" AS "entity: AuditEntity",
+                "entity_id",
+                "event_id",
+                "action" AS "action: AuditAction",
+                "before",
+                "after",
+                "created_at" AS "created_at: _""#,
+            new_log.actor_id,
+            new_log.entity as _,
+            new_log.entity_id,
+            new_log.event_id,
+            new_log.action as _,
+            new_log.before,
+            new_log.after,
+        )
+        .fetch_one(connection)
+        .await?;
+        Ok(log)
+    }
+
+    /// Adds the `FROM` and `WHERE` clauses of a list query.
+    fn push_filter(query_builder: &mut QueryBuilder<'_, Postgres>, filter: SelectManyFilter) {
+        query_builder.push(r#" FROM "audit_log" WHERE 1=1"#);
+
+        if let Some(actor_id) = filter.actor_id {
+            query_builder.push(r#" AND "actor_id" = "#);
+            query_builder.push_bind(actor_id);
+        }
+
+        if let Some(entity) = filter.entity {
+            query_builder.push(r#" AND "entity" = "#);
+            query_builder.push_bind(entity);
+        }
+
+        if let Some(entity_id) = filter.entity_id {
+            query_builder.push(r#" AND "entity_id" = "#);
+            query_builder.push_bind(entity_id);
+        }
+
+        if let Some(event_id) = filter.event_id {
+            query_builder.push(r#" AND "event_id" = "#);
+            query_builder.push_bind(event_id);
+        }
+
+        if let Some(action) = filter.action {
+            query_builder.push(r#" AND "action" = "#);
+            query_builder.push_bind(action);
+        }
+
+        if let Some(date_from) = filter.date_from {
+            query_builder.push(r#" AND "created_at"::date >= "#);
+            query_builder.push_bind(date_from);
+        }
+
+        if let Some(date_to) = filter.date_to {
+            query_builder.push(r#" AND "created_at"::date <= "#);
+            query_builder.push_bind(date_to);
+        }
+    }
+
+    fn audit_log_from_row(row: &PgRow) -> Result<AuditLog, sqlx::Error> {
+        Ok(AuditLog {
+            id: row.try_get("id")?,
+            actor_id: row.try_get("actor_id")?,
+            entity: row.try_get("entity")?,
+            entity_id: row.try_get("entity_id")?,
+            event_id: row.try_get("event_id")?,
+            action: row.try_get("action")?,
+            before: row.try_get("before")?,
+            after: row.try_get("after")?,
+            created_at: row.try_get("created_at")?,
+        })
+    }
+}
+
+/// Serializes a snapshot of an audited row, leaving out the [`REDACTED_FIELDS`].
+pub(crate) fn snapshot<T: Serialize>(value: &T) -> Result<Value, RepositoryError> {
+    let mut value = serde_json::to_value(value)
+        .map_err(|err| RepositoryError::GenericError(err.to_string()))?;
+    if let Value::Object(ref mut map) = value {
+        for field in REDACTED_FIELDS {
+            map.remove(field);
+        }
+    }
+    Ok(value)
+}
+
+#[async_trait]
+impl AuditLogRepository for PgAuditLogRepository {
+    async fn list_audit_logs(&self, filter: SelectManyFilter) -> Result<Vec<AuditLog>> {
+        let mut query_builder = QueryBuilder::new(format!("SELECT {AUDIT_LOG_COLUMNS}"));
+        Self::push_filter(&mut query_builder, filter);
+        query_builder.push(r#" ORDER BY "created_at" DESC, "id" DESC"#);
+
+        let query = query_builder.build();
+        let rows = query.fetch_all(self.pool_handler.pool()).await?;
+
+        let data: Result<Vec<AuditLog>, sqlx::Error> =
+            rows.iter().map(Self::audit_log_from_row).collect();
+        let data = data?;
+        Ok(data)
+    }
+
+    async fn list_audit_logs_page(
+        &self,
+        filter: SelectManyFilter,
+        spec: &ListSpec<SortColumn>,
+    ) -> Result<Page<AuditLog>> {
+        let mut query_builder =
+            QueryBuilder::new(format!(r#"WITH "list" AS (SELECT {AUDIT_LOG_COLUMNS}, "#));
+        spec.push_sort_key(&mut query_builder);
+        Self::push_filter(&mut query_builder, filter);
+        spec.push_page(&mut query_builder);
+
+        let query = query_builder.build();
+        let rows = query.fetch_all(self.pool_handler.pool()).await?;
+
+        let total = match rows.first() {
+            Some(row) => row.try_get("total_count")?,
+            None => 0,
+        };
+        let data: Result<Vec<AuditLog>, sqlx::Error> =
+            rows.iter().map(Self::audit_log_from_row).collect();
+        Ok(Page::new(data?, total, spec, |audit_log| audit_log.id))
+    }
+}
@@ -0,0 +1,14 @@
+/// Selects `columns` of `table`, each aliased with `prefix`, so that rows joining tables with
+/// clashing column names can be read back with the `*_from_row` functions of the repositories.
+pub(crate) fn select_columns(table: &str, columns: &[&str], prefix: &str) -> String {
+    columns
+        .iter()
+        .map(|column| format!(r#""{table}"."{column}" AS "{prefix}{column}""#))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Name of a column selected by [`select_columns`] with `prefix`.
+pub(crate) fn column(prefix: &str, name: &str) -> String {
+    format!("{prefix}{name}")
+}
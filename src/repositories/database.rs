@@ -0,0 +1,186 @@
+use crate::error::RepositoryError;
+use crate::repositories::attendance::{AttendanceRepository, PgAttendanceRepository};
+use crate::repositories::audit_log::{AuditLogRepository, PgAuditLogRepository};
+use crate::repositories::employment::{EmploymentRepository, PgEmploymentRepository};
+use crate::repositories::event::{EventRepository, PgEventRepository};
+use crate::repositories::event_manager_relation::{
+    EventManagerRelationRepository, PgEventManagerRelationRepository,
+};
+use crate::repositories::job_position::{JobPositionRepository, PgJobPositionRepository};
+use crate::repositories::memory::attendance::MemoryAttendanceRepository;
+use crate::repositories::memory::audit_log::MemoryAuditLogRepository;
+use crate::repositories::memory::employment::MemoryEmploymentRepository;
+use crate::repositories::memory::event::MemoryEventRepository;
+use crate::repositories::memory::event_manager_relation::MemoryEventManagerRelationRepository;
+use crate::repositories::memory::job_position::MemoryJobPositionRepository;
+use crate::repositories::memory::notification::MemoryNotificationRepository;
+use crate::repositories::memory::organization::MemoryOrganizationRepository;
+use crate::repositories::memory::position_category::MemoryPositionCategoryRepository;
+use crate::repositories::memory::read_model::MemoryReadModelRepository;
+use crate::repositories::memory::review::MemoryReviewRepository;
+use crate::repositories::memory::user::MemoryUserRepository;
+use crate::repositories::memory::venue::MemoryVenueRepository;
+use crate::repositories::memory::worked_hours::MemoryWorkedHoursRepository;
+use crate::repositories::memory::MemoryStore;
+use crate::repositories::notification::{NotificationRepository, PgNotificationRepository};
+use crate::repositories::organization::{OrganizationRepository, PgOrganizationRepository};
+use crate::repositories::pool_handler::PoolHandler;
+use crate::repositories::position_category::{
+    PgPositionCategoryRepository, PositionCategoryRepository,
+};
+use crate::repositories::read_model::{PgReadModelRepository, ReadModelRepository};
+use crate::repositories::review::{PgReviewRepository, ReviewRepository};
+use crate::repositories::sqlite::attendance::SqliteAttendanceRepository;
+use crate::repositories::sqlite::audit_log::SqliteAuditLogRepository;
+use crate::repositories::sqlite::employment::SqliteEmploymentRepository;
+use crate::repositories::sqlite::event::SqliteEventRepository;
+use crate::repositories::sqlite::event_manager_relation::SqliteEventManagerRelationRepository;
+use crate::repositories::sqlite::job_position::SqliteJobPositionRepository;
+use crate::repositories::sqlite::notification::SqliteNotificationRepository;
+use crate::repositories::sqlite::organization::SqliteOrganizationRepository;
+use crate::repositories::sqlite::position_category::SqlitePositionCategoryRepository;
+use crate::repositories::sqlite::read_model::SqliteReadModelRepository;
+use crate::repositories::sqlite::review::SqliteReviewRepository;
+use crate::repositories::sqlite::user::SqliteUserRepository;
+use crate::repositories::sqlite::venue::SqliteVenueRepository;
+use crate::repositories::sqlite::worked_hours::SqliteWorkedHoursRepository;
+use crate::repositories::unit_of_work::UnitOfWork;
+use crate::repositories::user::{PgUserRepository, UserRepository};
+use crate::repositories::venue::{PgVenueRepository, VenueRepository};
+use crate::repositories::worked_hours::{PgWorkedHoursRepository, WorkedHoursRepository};
+use sqlx::postgres::PgPoolOptions;
+use sqlx::sqlite::{SqliteConnectOptions, SqlitePoolOptions};
+use sqlx::SqlitePool;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Storage backend the repositories of the application run on.
+#[derive(Debug, Clone)]
+pub enum Database {
+    Postgres(PoolHandler),
+    Sqlite(SqlitePool),
+    Memory(MemoryStore),
+}
+
+/// One implementation of every repository trait, all sharing the same [`Database`].
+#[derive(Clone)]
+pub struct Repositories {
+    pub user: Arc<dyn UserRepository>,
+    pub employment: Arc<dyn EmploymentRepository>,
+    pub event: Arc<dyn EventRepository>,
+    pub venue: Arc<dyn VenueRepository>,
+    pub job_position: Arc<dyn JobPositionRepository>,
+    pub position_category: Arc<dyn PositionCategoryRepository>,
+    pub worked_hours: Arc<dyn WorkedHoursRepository>,
+    pub event_manager_relation: Arc<dyn EventManagerRelationRepository>,
+    pub audit_log: Arc<dyn AuditLogRepository>,
+    pub attendance: Arc<dyn AttendanceRepository>,
+    pub review: Arc<dyn ReviewRepository>,
+    pub notification: Arc<dyn NotificationRepository>,
+    pub organization: Arc<dyn OrganizationRepository>,
+    pub read_model: Arc<dyn ReadModelRepository>,
+}
+
+impl Database {
+    /// Connects to the backend `database_url` points to, picked by its scheme. SQLite databases
+    /// are created if they do not exist yet and migrated on connect, so that nothing but the
+    /// application binary is needed to run them.
+    pub async fn connect(database_url: &str) -> anyhow::Result<Self> {
+        if database_url.starts_with("sqlite:") {
+            let options = SqliteConnectOptions::from_str(database_url)?
+                .create_if_missing(true)
+                .foreign_keys(true);
+            let pool = SqlitePoolOptions::new()
+                .max_connections(5)
+                .connect_with(options)
+                .await?;
+            sqlx::migrate!("migrations/sqlite").run(&pool).await?;
+            return Ok(Database::Sqlite(pool));
+        }
+
+        let pool = PgPoolOptions::new()
+            .max_connections(5)
+            .connect(database_url)
+            .await?;
+        Ok(Database::Postgres(PoolHandler::new(Arc::new(pool))))
+    }
+
+    /// Starts a [`UnitOfWork`] on the backend.
+    pub async fn begin(&self) -> Result<UnitOfWork, RepositoryError> {
+        match self {
+            Database::Postgres(pool_handler) => UnitOfWork::begin(pool_handler).await,
+            Database::Sqlite(pool) => UnitOfWork::begin_sqlite(pool).await,
+            Database::Memory(store) => Ok(UnitOfWork::begin_in_memory(store)),
+        }
+    }
+
+    /// Closes the connections to the backend.
+    pub async fn close(&self) {
+        match self {
+            Database::Postgres(pool_handler) => pool_handler.pool().close().await,
+            Database::Sqlite(pool) => pool.close().await,
+            Database::Memory(_) => {}
+        }
+    }
+
+    pub fn repositories(&self) -> Repositories {
+        match self {
+            Database::Postgres(pool_handler) => Repositories {
+                user: Arc::new(PgUserRepository::new(pool_handler.clone())),
+                employment: Arc::new(PgEmploymentRepository::new(pool_handler.clone())),
+                event: Arc::new(PgEventRepository::new(pool_handler.clone())),
+                venue: Arc::new(PgVenueRepository::new(pool_handler.clone())),
+                job_position: Arc::new(PgJobPositionRepository::new(pool_handler.clone())),
+                position_category: Arc::new(PgPositionCategoryRepository::new(
+                    pool_handler.clone(),
+                )),
+                worked_hours: Arc::new(PgWorkedHoursRepository::new(pool_handler.clone())),
+                event_manager_relation: Arc::new(PgEventManagerRelationRepository::new(
+                    pool_handler.clone(),
+                )),
+                audit_log: Arc::new(PgAuditLogRepository::new(pool_handler.clone())),
+                attendance: Arc::new(PgAttendanceRepository::new(pool_handler.clone())),
+                review: Arc::new(PgReviewRepository::new(pool_handler.clone())),
+                notification: Arc::new(PgNotificationRepository::new(pool_handler.clone())),
+                organization: Arc::new(PgOrganizationRepository::new(pool_handler.clone())),
+                read_model: Arc::new(PgReadModelRepository::new(pool_handler.clone())),
+            },
+            Database::Sqlite(pool) => Repositories {
+                user: Arc::new(SqliteUserRepository::new(pool.clone())),
+                employment: Arc::new(SqliteEmploymentRepository::new(pool.clone())),
+                event: Arc::new(SqliteEventRepository::new(pool.clone())),
+                venue: Arc::new(SqliteVenueRepository::new(pool.clone())),
+                job_position: Arc::new(SqliteJobPositionRepository::new(pool.clone())),
+                position_category: Arc::new(SqlitePositionCategoryRepository::new(pool.clone())),
+                worked_hours: Arc::new(SqliteWorkedHoursRepository::new(pool.clone())),
+                event_manager_relation: Arc::new(SqliteEventManagerRelationRepository::new(
+                    pool.clone(),
+                )),
+                audit_log: Arc::new(SqliteAuditLogRepository::new(pool.clone())),
+                attendance: Arc::new(SqliteAttendanceRepository::new(pool.clone())),
+                review: Arc::new(SqliteReviewRepository::new(pool.clone())),
+                notification: Arc::new(SqliteNotificationRepository::new(pool.clone())),
+                organization: Arc::new(SqliteOrganizationRepository::new(pool.clone())),
+                read_model: Arc::new(SqliteReadModelRepository::new(pool.clone())),
+            },
+            Database::Memory(store) => Repositories {
+                user: Arc::new(MemoryUserRepository::new(store.clone())),
+                employment: Arc::new(MemoryEmploymentRepository::new(store.clone())),
+                event: Arc::new(MemoryEventRepository::new(store.clone())),
+                venue: Arc::new(MemoryVenueRepository::new(store.clone())),
+                job_position: Arc::new(MemoryJobPositionRepository::new(store.clone())),
+                position_category: Arc::new(MemoryPositionCategoryRepository::new(store.clone())),
+                worked_hours: Arc::new(MemoryWorkedHoursRepository::new(store.clone())),
+                event_manager_relation: Arc::new(MemoryEventManagerRelationRepository::new(
+                    store.clone(),
+                )),
+                audit_log: Arc::new(MemoryAuditLogRepository::new(store.clone())),
+                attendance: Arc::new(MemoryAttendanceRepository::new(store.clone())),
+                review: Arc::new(MemoryReviewRepository::new(store.clone())),
+                notification: Arc::new(MemoryNotificationRepository::new(store.clone())),
+                organization: Arc::new(MemoryOrganizationRepository::new(store.clone())),
+                read_model: Arc::new(MemoryReadModelRepository::new(store.clone())),
+            },
+        }
+    }
+}
@@ -1,19 +1,47 @@
 use crate::error::RepositoryError;
+use crate::models::audit_log::{AuditAction, AuditEntity};
 use crate::models::employment::{
-    CreateEmployment, Employment, EmploymentState, PartialEmployment, SelectManyFilter,
+    BulkOrder, BulkUpdateResult, CreateEmployment, Employment, EmploymentState,
+    EmploymentStateChange, JobStats, JobsFilter, PartialEmployment, SelectManyFilter,
+    SkippedEmployment, SortColumn, WithdrawalStats,
 };
-use crate::repositories::event::{EventRepository, PgEventRepository};
-use crate::repositories::job_position::{JobPositionRepository, PgJobPositionRepository};
+use crate::models::event::EventStatus;
+use crate::models::pagination::{push_contains, ListSpec, Page};
+use crate::models::staffing::CandidateStats;
+use crate::repositories::audit_log::PgAuditLogRepository;
+use crate::repositories::columns::{column, select_columns};
+use crate::repositories::event::{push_managed_by, PgEventRepository};
+use crate::repositories::job_position::PgJobPositionRepository;
 use crate::repositories::pool_handler::PoolHandler;
+use crate::repositories::unit_of_work::UnitOfWork;
 use anyhow::Result;
 use async_trait::async_trait;
+use sqlx::postgres::PgRow;
 use sqlx::types::time::OffsetDateTime;
-use sqlx::{QueryBuilder, Row};
+use sqlx::{PgConnection, Postgres, QueryBuilder, Row};
+use std::collections::HashMap;
 use std::sync::Arc;
 
+pub(crate) const EMPLOYMENT_COLUMNS: &[&str] = &[
+    "id",
+    "rating",
+    "state",
+    "user_id",
+    "position_id",
+    "invitation_expires_at",
+];
+
 #[async_trait]
-pub trait EmploymentRepository {
+#[allow(dead_code)]
+pub trait EmploymentRepository: Send + Sync {
+    fn with_actor(&self, actor_id: i32) -> Arc<dyn EmploymentRepository>;
     async fn list_employment(&self, filter: SelectManyFilter) -> Result<Vec<Employment>>;
+    async fn list_employment_page(
+        &self,
+        filter: SelectManyFilter,
+        spec: &ListSpec<SortColumn>,
+    ) -> Result<Page<Employment>>;
+    async fn job_stats(&self, filter: JobsFilter) -> Result<JobStats>;
     async fn get_employment_by_id(
         &self,
         log_position_id: i32,
@@ -22,37 +50,185 @@ pub trait EmploymentRepository {
         &self,
         new_log_position: CreateEmployment,
     ) -> Result<Employment, RepositoryError>;
+    async fn create_employment_in(
+        &self,
+        unit_of_work: &mut UnitOfWork,
+        new_employment: CreateEmployment,
+    ) -> Result<Employment, RepositoryError>;
     async fn delete_employment(&self, log_position_id: i32) -> Result<(), RepositoryError>;
     async fn update_employment(
         &self,
         log_position_id: i32,
         patch_log_position: PartialEmployment,
     ) -> Result<Employment, RepositoryError>;
+    /// Accepting an employment locks its job position until the unit of work ends, so that
+    /// concurrent acceptances cannot overfill it.
+    async fn update_employment_in(
+        &self,
+        unit_of_work: &mut UnitOfWork,
+        employment_id: i32,
+        patch_employment: PartialEmployment,
+    ) -> Result<Employment, RepositoryError>;
+    /// Accepts or declines an invitation still waiting for the employee's answer.
+    async fn respond_to_invitation(
+        &self,
+        employment_id: i32,
+        accept: bool,
+        now: OffsetDateTime,
+    ) -> Result<Employment, RepositoryError>;
+    /// Unlike [`EmploymentRepository::respond_to_invitation`], an expired invitation is
+    /// left for [`EmploymentRepository::expire_invitations`] to mark.
+    async fn respond_to_invitation_in(
+        &self,
+        unit_of_work: &mut UnitOfWork,
+        employment_id: i32,
+        accept: bool,
+        now: OffsetDateTime,
+    ) -> Result<Employment, RepositoryError>;
+    /// Marks invitations nobody answered before their deadline as expired.
+    async fn expire_invitations(
+        &self,
+        now: OffsetDateTime,
+    ) -> Result<Vec<Employment>, RepositoryError>;
+    /// Withdraws the employee from a pending or accepted job, releasing its capacity.
+    /// Withdrawals after the event's cutoff have to state a reason.
+    async fn withdraw_employment(
+        &self,
+        employment_id: i32,
+        reason: Option<String>,
+        now: OffsetDateTime,
+    ) -> Result<Employment, RepositoryError>;
+    async fn withdrawal_stats(
+        &self,
+        user_id: i32,
+        organization_id: Option<i32>,
+    ) -> Result<WithdrawalStats, RepositoryError>;
+    /// Moves all given employments to `state` in one transaction. Employments that cannot
+    /// make the transition, or would overfill their job position, are skipped.
+    async fn bulk_update_state(
+        &self,
+        employment_ids: &[i32],
+        state: EmploymentState,
+        order: BulkOrder,
+    ) -> Result<BulkUpdateResult, RepositoryError>;
+    /// Organization employees who could staff the job position, with the numbers
+    /// the staffing suggestions are scored on.
+    async fn list_staffing_candidates(
+        &self,
+        job_position_id: i32,
+    ) -> Result<Vec<CandidateStats>, RepositoryError>;
+    /// State timelines of the given employments, oldest change first.
+    async fn list_state_history(
+        &self,
+        employment_ids: &[i32],
+    ) -> Result<Vec<EmploymentStateChange>, RepositoryError>;
 }
 
 #[derive(Debug, Clone)]
 pub struct PgEmploymentRepository {
     pub pool_handler: PoolHandler,
+    pub actor_id: Option<i32>,
 }
 
 impl PgEmploymentRepository {
     pub fn new(pool_handler: PoolHandler) -> Self {
-        Self { pool_handler }
+        Self {
+            pool_handler,
+            actor_id: None,
+        }
     }
 
-    async fn check_add(&self, new_employment: CreateEmployment) -> Result<(), RepositoryError> {
-        let job_position_repository = PgJobPositionRepository::new(PoolHandler::new(Arc::new(
-            self.pool_handler.pool().clone(),
-        )));
-        let event_repository =
-            PgEventRepository::new(PoolHandler::new(Arc::new(self.pool_handler.pool().clone())));
+    pub fn with_actor(&self, actor_id: i32) -> Self {
+        Self {
+            pool_handler: self.pool_handler.clone(),
+            actor_id: Some(actor_id),
+        }
+    }
 
-        let job_position = job_position_repository
-            .get_job_position_by_id(new_employment.position_id)
-            .await?;
-        let event = event_repository
-            .get_event_by_id(job_position.event_id)
-            .await?;
+    async fn audit_in(
+        &self,
+        connection: &mut PgConnection,
+        action: AuditAction,
+        employment: &Employment,
+        before: Option<&Employment>,
+        after: Option<&Employment>,
+    ) -> Result<(), RepositoryError> {
+        let event = sqlx::query!(
+            r#"SELECT "event_id" FROM "job_position" WHERE "id" = $1"#,
+            employment.position_id
+        )
+        .fetch_optional(&mut *connection)
+        .await?;
+
+        PgAuditLogRepository::record_in(
+            connection,
+            self.actor_id,
+            AuditEntity::Employment,
+            employment.id,
+            event.map(|event| event.event_id),
+            action,
+            before,
+            after,
+        )
+        .await?;
+        Ok(())
+    }
+
+    pub async fn get_employment_by_id_in(
+        connection: &mut PgConnection,
+        employment_id: i32,
+    ) -> Result<Employment, RepositoryError> {
+        let employment = sqlx::query_as!(
+            Employment,
+            r#"SELECT
+                "id",
+                "rating",
+                "state" AS "state: EmploymentState",
+                "user_id",
+                "position_id",
+                "invitation_expires_at"
+            FROM "employment" WHERE "id" = $1"#,
+            employment_id
+        )
+        .fetch_optional(connection)
+        .await?;
+        if let Some(employment) = employment {
+            return Ok(employment);
+        }
+        Err(RepositoryError::NotFound)
+    }
+
+    async fn record_state_change_in(
+        &self,
+        connection: &mut PgConnection,
+        employment_id: i32,
+        from_state: Option<EmploymentState>,
+        to_state: EmploymentState,
+    ) -> Result<(), RepositoryError> {
+        sqlx::query!(
+            r#"INSERT INTO "employment_state_history" ("employment_id", "from_state", "to_state", "changed_by")
+            VALUES ($1, $2::employment_state, $3::employment_state, $4)"#,
+            employment_id,
+            from_state as _,
+            to_state as _,
+            self.actor_id
+        )
+        .execute(connection)
+        .await?;
+        Ok(())
+    }
+
+    async fn check_add(
+        connection: &mut PgConnection,
+        new_employment: &CreateEmployment,
+    ) -> Result<(), RepositoryError> {
+        let job_position = PgJobPositionRepository::get_job_position_by_id_in(
+            connection,
+            new_employment.position_id,
+        )
+        .await?;
+        let event =
+            PgEventRepository::get_event_by_id_in(connection, job_position.event_id).await?;
 
         if event.date_end < OffsetDateTime::now_utc().date() {
             return Err(RepositoryError::GenericError(
@@ -60,12 +236,43 @@ impl PgEmploymentRepository {
             ));
         }
 
+        if event.is_cancelled() {
+            return Err(RepositoryError::GenericError(
+                "The event has been cancelled".to_string(),
+            ));
+        }
+
+        if matches!(
+            event.status,
+            EventStatus::RegistrationClosed | EventStatus::Completed | EventStatus::Archived
+        ) {
+            return Err(RepositoryError::GenericError(
+                "Registration for the event is closed".to_string(),
+            ));
+        }
+
+        // Applications have to respect the registration window, organizers may still
+        // assign workers directly.
+        if new_employment.state == EmploymentState::Pending && event.status == EventStatus::Draft {
+            return Err(RepositoryError::GenericError(
+                "The event has not been published yet".to_string(),
+            ));
+        }
+
+        if new_employment.state == EmploymentState::Pending
+            && !job_position.is_registration_open_at(OffsetDateTime::now_utc())
+        {
+            return Err(RepositoryError::GenericError(
+                "The job position is not opened for registration".to_string(),
+            ));
+        }
+
         let existing_record = sqlx::query!(
             r#"SELECT "id" FROM "employment" WHERE "user_id" = $1 AND "position_id" = $2"#,
             new_employment.user_id,
             new_employment.position_id
         )
-        .fetch_optional(self.pool_handler.pool())
+        .fetch_optional(&mut *connection)
         .await?;
 
         if existing_record.is_some() {
@@ -74,20 +281,29 @@ impl PgEmploymentRepository {
             ));
         }
 
-        let count_records = sqlx::query!(
-            r#"SELECT COUNT(*) FROM "employment" WHERE "position_id" = $1 AND ("state" = 'accepted' OR "state" = 'done')"#,
-            new_employment.position_id
-        )
-            .fetch_one(self.pool_handler.pool())
-            .await?;
+        Self::check_capacity(connection, new_employment.position_id).await
+    }
 
+    /// Fails if the job position has no free place left. The position stays locked until
+    /// the transaction of `connection` ends.
+    async fn check_capacity(
+        connection: &mut PgConnection,
+        position_id: i32,
+    ) -> Result<(), RepositoryError> {
         let max_val = sqlx::query!(
-            r#"SELECT "capacity" FROM "job_position" WHERE "id" = $1"#,
-            new_employment.position_id
+            r#"SELECT "capacity" FROM "job_position" WHERE "id" = $1 FOR UPDATE"#,
+            position_id
         )
-        .fetch_one(self.pool_handler.pool())
+        .fetch_one(&mut *connection)
         .await?;
 
+        let count_records = sqlx::query!(
+            r#"SELECT COUNT(*) FROM "employment" WHERE "position_id" = $1 AND ("state" = 'accepted' OR "state" = 'done')"#,
+            position_id
+        )
+            .fetch_one(&mut *connection)
+            .await?;
+
         if (count_records.count.unwrap_or(0) as i32) >= max_val.capacity {
             return Err(RepositoryError::GenericError(
                 "Job position is already full".to_string(),
@@ -96,115 +312,297 @@ impl PgEmploymentRepository {
 
         Ok(())
     }
-}
 
-#[async_trait]
-impl EmploymentRepository for PgEmploymentRepository {
-    async fn list_employment(&self, filter: SelectManyFilter) -> Result<Vec<Employment>> {
-        let mut query_builder = QueryBuilder::new(
-            r#"SELECT
-                    "id",
-                    "rating",
-                    "state",
-                    "user_id",
-                    "position_id"
-                FROM "employment"
-                WHERE 1=1"#,
+    /// Adds the `FROM` and `WHERE` clauses of a list query. Employments of deleted users,
+    /// job positions or events are never listed.
+    pub(crate) fn push_filter(
+        query_builder: &mut QueryBuilder<'_, Postgres>,
+        filter: SelectManyFilter,
+    ) {
+        query_builder.push(
+            r#" FROM "employment"
+                JOIN "user" ON "user"."id" = "employment"."user_id"
+                JOIN "job_position" ON "job_position"."id" = "employment"."position_id"
+                JOIN "event" ON "event"."id" = "job_position"."event_id"
+                WHERE "user"."deleted_at" IS NULL
+                    AND "job_position"."deleted_at" IS NULL
+                    AND "event"."deleted_at" IS NULL"#,
         );
 
+        if let Some(organization_id) = filter.organization_id {
+            query_builder.push(r#" AND "event"."organization_id" = "#);
+            query_builder.push_bind(organization_id);
+        }
+
         if let Some(rating) = filter.rating {
-            query_builder.push(r#" AND "rating" = "#);
+            query_builder.push(r#" AND "employment"."rating" = "#);
             query_builder.push_bind(rating);
         }
 
         if let Some(user_id) = filter.user_id {
-            query_builder.push(r#" AND "user_id" = "#);
+            query_builder.push(r#" AND "employment"."user_id" = "#);
             query_builder.push_bind(user_id);
         }
 
         if let Some(state) = filter.state {
-            query_builder.push(r#" AND "state" = "#);
+            query_builder.push(r#" AND "employment"."state" = "#);
             query_builder.push_bind(state);
         }
 
         if let Some(position_id) = filter.position_id {
-            query_builder.push(r#" AND "position_id" = "#);
+            query_builder.push(r#" AND "employment"."position_id" = "#);
             query_builder.push_bind(position_id);
         }
 
+        if let Some(event_name) = filter.event_name_contains {
+            push_contains(query_builder, r#""event"."name""#, event_name);
+        }
+
+        if let Some(job_name) = filter.job_name_contains {
+            push_contains(query_builder, r#""job_position"."name""#, job_name);
+        }
+
+        if let Some(employee_name) = filter.employee_name_contains {
+            push_contains(
+                query_builder,
+                r#"("user"."first_name" || ' ' || "user"."last_name")"#,
+                employee_name,
+            );
+        }
+
+        if let Some(managed_by) = filter.managed_by {
+            push_managed_by(query_builder, r#""event"."id""#, managed_by);
+        }
+    }
+
+    /// Adds the `FROM` and `WHERE` clauses of a query over the jobs of an employee, with the
+    /// hours worked on each job as `"hours"."total"`.
+    pub(crate) fn push_jobs_filter(
+        query_builder: &mut QueryBuilder<'_, Postgres>,
+        filter: JobsFilter,
+    ) {
+        query_builder.push(
+            r#" FROM "employment"
+                JOIN "job_position" ON "job_position"."id" = "employment"."position_id"
+                JOIN "event" ON "event"."id" = "job_position"."event_id"
+                JOIN "venue" ON "venue"."id" = "event"."venue_id"
+                CROSS JOIN LATERAL (
+                    SELECT COALESCE(SUM("worked_hours"."hours_worked"), 0) AS "total"
+                    FROM "worked_hours"
+                    WHERE "worked_hours"."employment_id" = "employment"."id"
+                ) AS "hours"
+                WHERE "job_position"."deleted_at" IS NULL
+                    AND "event"."deleted_at" IS NULL
+                    AND "event"."organization_id" = "#,
+        );
+        query_builder.push_bind(filter.organization_id);
+        query_builder.push(r#" AND "employment"."user_id" = "#);
+        query_builder.push_bind(filter.user_id);
+
+        if let Some(state) = filter.state {
+            query_builder.push(r#" AND "employment"."state" = "#);
+            query_builder.push_bind(state);
+        }
+
+        if let Some(starts_from) = filter.starts_from {
+            query_builder.push(r#" AND "event"."date_start" >= "#);
+            query_builder.push_bind(starts_from);
+        }
+
+        if let Some(ends_until) = filter.ends_until {
+            query_builder.push(r#" AND "event"."date_end" <= "#);
+            query_builder.push_bind(ends_until);
+        }
+
+        if let Some(event_name) = filter.event_name_contains {
+            push_contains(query_builder, r#""event"."name""#, event_name);
+        }
+
+        if let Some(venue_name) = filter.venue_name_contains {
+            push_contains(query_builder, r#""venue"."name""#, venue_name);
+        }
+
+        if let Some(job_name) = filter.job_name_contains {
+            push_contains(query_builder, r#""job_position"."name""#, job_name);
+        }
+    }
+}
+
+/// Reads an employment selected with [`select_columns`] and `prefix`.
+pub(crate) fn employment_from_row(row: &PgRow, prefix: &str) -> Result<Employment, sqlx::Error> {
+    Ok(Employment {
+        id: row.try_get(&*column(prefix, "id"))?,
+        rating: row.try_get(&*column(prefix, "rating"))?,
+        state: row.try_get(&*column(prefix, "state"))?,
+        user_id: row.try_get(&*column(prefix, "user_id"))?,
+        position_id: row.try_get(&*column(prefix, "position_id"))?,
+        invitation_expires_at: row.try_get(&*column(prefix, "invitation_expires_at"))?,
+    })
+}
+
+#[async_trait]
+impl EmploymentRepository for PgEmploymentRepository {
+    fn with_actor(&self, actor_id: i32) -> Arc<dyn EmploymentRepository> {
+        Arc::new(PgEmploymentRepository::with_actor(self, actor_id))
+    }
+
+    async fn list_employment(&self, filter: SelectManyFilter) -> Result<Vec<Employment>> {
+        let mut query_builder = QueryBuilder::new(format!(
+            "SELECT {}",
+            select_columns("employment", EMPLOYMENT_COLUMNS, "")
+        ));
+        Self::push_filter(&mut query_builder, filter);
+
         let query = query_builder.build();
         let rows = query.fetch_all(self.pool_handler.pool()).await?;
 
         let data: Result<Vec<Employment>, sqlx::Error> = rows
-            .into_iter()
-            .map(|row| {
-                Ok(Employment {
-                    id: row.try_get("id")?,
-                    rating: row.try_get("rating")?,
-                    state: row.try_get("state")?,
-                    user_id: row.try_get("user_id")?,
-                    position_id: row.try_get("position_id")?,
-                })
-            })
+            .iter()
+            .map(|row| employment_from_row(row, ""))
             .collect();
-
         let data = data?;
         Ok(data)
     }
 
+    async fn list_employment_page(
+        &self,
+        filter: SelectManyFilter,
+        spec: &ListSpec<SortColumn>,
+    ) -> Result<Page<Employment>> {
+        let mut query_builder = QueryBuilder::new(format!(
+            r#"WITH "list" AS (SELECT {}, "#,
+            select_columns("employment", EMPLOYMENT_COLUMNS, "")
+        ));
+        spec.push_sort_key(&mut query_builder);
+        Self::push_filter(&mut query_builder, filter);
+        spec.push_page(&mut query_builder);
+
+        let query = query_builder.build();
+        let rows = query.fetch_all(self.pool_handler.pool()).await?;
+
+        let total = match rows.first() {
+            Some(row) => row.try_get("total_count")?,
+            None => 0,
+        };
+        let data: Result<Vec<Employment>, sqlx::Error> = rows
+            .iter()
+            .map(|row| employment_from_row(row, ""))
+            .collect();
+        Ok(Page::new(data?, total, spec, |employment| employment.id))
+    }
+
+    async fn job_stats(&self, filter: JobsFilter) -> Result<JobStats> {
+        let mut query_builder = QueryBuilder::new(
+            r#"SELECT
+                COUNT(*) AS "total_jobs",
+                COUNT(DISTINCT "event"."id") AS "unique_events",
+                COUNT(DISTINCT "venue"."id") AS "unique_venues",
+                COALESCE(SUM("hours"."total"), 0) AS "hours_worked",
+                COALESCE(SUM("hours"."total" * "job_position"."salary"), 0) AS "salary",
+                MIN("event"."date_start") AS "earliest_date",
+                MAX("event"."date_end") AS "latest_date",
+                AVG(NULLIF("employment"."rating", 0))::REAL AS "average_rating""#,
+        );
+        Self::push_jobs_filter(&mut query_builder, filter);
+
+        let row = query_builder
+            .build()
+            .fetch_one(self.pool_handler.pool())
+            .await?;
+
+        Ok(JobStats {
+            total_jobs: row.try_get("total_jobs")?,
+            unique_events: row.try_get("unique_events")?,
+            unique_venues: row.try_get("unique_venues")?,
+            hours_worked: row.try_get("hours_worked")?,
+            salary: row.try_get("salary")?,
+            earliest_date: row.try_get("earliest_date")?,
+            latest_date: row.try_get("latest_date")?,
+            average_rating: row.try_get("average_rating")?,
+        })
+    }
+
     async fn get_employment_by_id(
         &self,
         employment_id: i32,
     ) -> Result<Employment, RepositoryError> {
-        let employment = sqlx::query_as!(
-            Employment,
-            r#"SELECT
-                "id",
-                "rating",
-                "state" AS "state: EmploymentState",
-                "user_id",
-                "position_id"
-            FROM "employment" WHERE "id" = $1"#,
-            employment_id
-        )
-        .fetch_optional(self.pool_handler.pool())
-        .await?;
-        if let Some(employment) = employment {
-            return Ok(employment);
-        }
-        Err(RepositoryError::NotFound)
+        let mut connection = self.pool_handler.pool().acquire().await?;
+        Self::get_employment_by_id_in(&mut connection, employment_id).await
     }
 
     async fn create_employment(
         &self,
         new_employment: CreateEmployment,
     ) -> Result<Employment, RepositoryError> {
-        Self::check_add(self, new_employment.clone()).await?;
+        let mut unit_of_work = UnitOfWork::begin(&self.pool_handler).await?;
+        let employment = self
+            .create_employment_in(&mut unit_of_work, new_employment)
+            .await?;
+        unit_of_work.commit().await?;
+        Ok(employment)
+    }
+
+    async fn create_employment_in(
+        &self,
+        unit_of_work: &mut UnitOfWork,
+        new_employment: CreateEmployment,
+    ) -> Result<Employment, RepositoryError> {
+        let connection = unit_of_work.connection()?;
+        Self::check_add(connection, &new_employment).await?;
         let employment = sqlx::query_as!(
             Employment,
-            r#"INSERT INTO "employment" ("rating", "state", "user_id", "position_id")
-            VALUES ($1, $2::employment_state, $3, $4)
+            r#"INSERT INTO "employment" (
+                "rating", "state", "user_id", "position_id", "invitation_expires_at"
+            )
+            VALUES ($1, $2::employment_state, $3, $4, $5)
             RETURNING
-                "id", "rating", "state" as "state: EmploymentState", "user_id", "position_id""#,
+                "id",
+                "rating",
+                "state" as "state: EmploymentState",
+                "user_id",
+                "position_id",
+                "invitation_expires_at""#,
             new_employment.rating,
             new_employment.state as _,
             new_employment.user_id,
-            new_employment.position_id
+            new_employment.position_id,
+            new_employment.invitation_expires_at
+        )
+        .fetch_one(&mut *connection)
+        .await?;
+        self.audit_in(
+            connection,
+            AuditAction::Create,
+            &employment,
+            None,
+            Some(&employment),
         )
-        .fetch_one(self.pool_handler.pool())
         .await?;
+        self.record_state_change_in(connection, employment.id, None, employment.state.clone())
+            .await?;
         Ok(employment)
     }
 
     async fn delete_employment(&self, employment_id: i32) -> Result<(), RepositoryError> {
+        let mut transaction = self.pool_handler.pool().begin().await?;
+        let employment = Self::get_employment_by_id_in(&mut transaction, employment_id).await?;
         let result = sqlx::query!(r#"DELETE FROM "employment" WHERE "id" = $1"#, employment_id)
-            .execute(self.pool_handler.pool())
+            .execute(&mut *transaction)
             .await?;
 
         if result.rows_affected() == 0 {
             return Err(RepositoryError::NotFound);
         }
 
+        self.audit_in(
+            &mut transaction,
+            AuditAction::Delete,
+            &employment,
+            Some(&employment),
+            None,
+        )
+        .await?;
+        transaction.commit().await?;
         Ok(())
     }
 
@@ -213,7 +611,42 @@ impl EmploymentRepository for PgEmploymentRepository {
         employment_id: i32,
         patch_log_position: PartialEmployment,
     ) -> Result<Employment, RepositoryError> {
-        let employment = self.get_employment_by_id(employment_id).await?;
+        let mut unit_of_work = UnitOfWork::begin(&self.pool_handler).await?;
+        let employment = self
+            .update_employment_in(&mut unit_of_work, employment_id, patch_log_position)
+            .await?;
+        unit_of_work.commit().await?;
+        Ok(employment)
+    }
+
+    async fn update_employment_in(
+        &self,
+        unit_of_work: &mut UnitOfWork,
+        employment_id: i32,
+        patch_log_position: PartialEmployment,
+    ) -> Result<Employment, RepositoryError> {
+        let connection = unit_of_work.connection()?;
+        let employment = Self::get_employment_by_id_in(connection, employment_id).await?;
+        let before = employment.clone();
+
+        if let Some(state) = &patch_log_position.state {
+            if *state != employment.state && !employment.state.can_transition_to(state) {
+                return Err(RepositoryError::GenericError(format!(
+                    "An employment cannot move from {} to {}",
+                    employment.state, state
+                )));
+            }
+            if *state == EmploymentState::Accepted && employment.state != EmploymentState::Accepted
+            {
+                Self::check_capacity(
+                    connection,
+                    patch_log_position
+                        .position_id
+                        .unwrap_or(employment.position_id),
+                )
+                .await?;
+            }
+        }
 
         let rating = patch_log_position.rating.unwrap_or(employment.rating);
         let state = patch_log_position.state.unwrap_or(employment.state);
@@ -231,7 +664,12 @@ impl EmploymentRepository for PgEmploymentRepository {
                 "position_id" = $5 
             WHERE "id" = $1
             RETURNING
-                "id", "rating", "state" as "state: EmploymentState", "user_id", "position_id"
+                "id",
+                "rating",
+                "state" as "state: EmploymentState",
+                "user_id",
+                "position_id",
+                "invitation_expires_at"
             "#,
             employment_id,
             rating,
@@ -239,12 +677,475 @@ impl EmploymentRepository for PgEmploymentRepository {
             user_id,
             position_id
         )
-        .fetch_optional(self.pool_handler.pool())
+        .fetch_optional(&mut *connection)
         .await?;
 
         if let Some(employment) = employment {
+            self.audit_in(
+                connection,
+                AuditAction::Update,
+                &employment,
+                Some(&before),
+                Some(&employment),
+            )
+            .await?;
+            if employment.state != before.state {
+                self.record_state_change_in(
+                    connection,
+                    employment.id,
+                    Some(before.state.clone()),
+                    employment.state.clone(),
+                )
+                .await?;
+            }
             return Ok(employment);
         }
         Err(RepositoryError::NotFound)
     }
+
+    async fn respond_to_invitation(
+        &self,
+        employment_id: i32,
+        accept: bool,
+        now: OffsetDateTime,
+    ) -> Result<Employment, RepositoryError> {
+        let employment = self.get_employment_by_id(employment_id).await?;
+        if employment.state == EmploymentState::Invited && employment.is_invitation_expired_at(now)
+        {
+            self.expire_invitations(now).await?;
+            return Err(RepositoryError::GenericError(
+                "The invitation has expired".to_string(),
+            ));
+        }
+
+        let mut unit_of_work = UnitOfWork::begin(&self.pool_handler).await?;
+        let employment = self
+            .respond_to_invitation_in(&mut unit_of_work, employment_id, accept, now)
+            .await?;
+        unit_of_work.commit().await?;
+        Ok(employment)
+    }
+
+    async fn respond_to_invitation_in(
+        &self,
+        unit_of_work: &mut UnitOfWork,
+        employment_id: i32,
+        accept: bool,
+        now: OffsetDateTime,
+    ) -> Result<Employment, RepositoryError> {
+        let employment =
+            Self::get_employment_by_id_in(unit_of_work.connection()?, employment_id).await?;
+        if employment.state != EmploymentState::Invited {
+            return Err(RepositoryError::GenericError(
+                "The invitation is no longer open".to_string(),
+            ));
+        }
+        if employment.is_invitation_expired_at(now) {
+            return Err(RepositoryError::GenericError(
+                "The invitation has expired".to_string(),
+            ));
+        }
+
+        let state = if accept {
+            EmploymentState::Accepted
+        } else {
+            EmploymentState::Declined
+        };
+        self.update_employment_in(
+            unit_of_work,
+            employment_id,
+            PartialEmployment {
+                rating: None,
+                state: Some(state),
+                user_id: None,
+                position_id: None,
+            },
+        )
+        .await
+    }
+
+    async fn expire_invitations(
+        &self,
+        now: OffsetDateTime,
+    ) -> Result<Vec<Employment>, RepositoryError> {
+        let mut transaction = self.pool_handler.pool().begin().await?;
+        let expired = sqlx::query_as!(
+            Employment,
+            r#"UPDATE "employment" SET "state" = 'expired'
+            WHERE "state" = 'invited' AND "invitation_expires_at" <= $1
+            RETURNING
+                "id",
+                "rating",
+                "state" as "state: EmploymentState",
+                "user_id",
+                "position_id",
+                "invitation_expires_at""#,
+            now
+        )
+        .fetch_all(&mut *transaction)
+        .await?;
+
+        for employment in &expired {
+            let before = Employment {
+                state: EmploymentState::Invited,
+                ..employment.clone()
+            };
+            self.audit_in(
+                &mut transaction,
+                AuditAction::Update,
+                employment,
+                Some(&before),
+                Some(employment),
+            )
+            .await?;
+            self.record_state_change_in(
+                &mut transaction,
+                employment.id,
+                Some(EmploymentState::Invited),
+                EmploymentState::Expired,
+            )
+            .await?;
+        }
+        transaction.commit().await?;
+        Ok(expired)
+    }
+
+    async fn withdraw_employment(
+        &self,
+        employment_id: i32,
+        reason: Option<String>,
+        now: OffsetDateTime,
+    ) -> Result<Employment, RepositoryError> {
+        let mut transaction = self.pool_handler.pool().begin().await?;
+        let before = Self::get_employment_by_id_in(&mut transaction, employment_id).await?;
+        if !before.state.is_withdrawable() {
+            return Err(RepositoryError::GenericError(
+                "Only pending or accepted jobs can be withdrawn from".to_string(),
+            ));
+        }
+
+        let job_position = PgJobPositionRepository::get_job_position_by_id_in(
+            &mut transaction,
+            before.position_id,
+        )
+        .await?;
+        let event =
+            PgEventRepository::get_event_by_id_in(&mut transaction, job_position.event_id).await?;
+
+        if now.date() >= event.date_start {
+            return Err(RepositoryError::GenericError(
+                "The event has already started".to_string(),
+            ));
+        }
+
+        let reason = reason
+            .map(|reason| reason.trim().to_string())
+            .filter(|reason| !reason.is_empty());
+        let is_late = now >= event.withdrawal_cutoff();
+        if is_late && reason.is_none() {
+            return Err(RepositoryError::GenericError(format!(
+                "Withdrawing within {} hours of the event start requires a reason",
+                event.withdrawal_cutoff_hours
+            )));
+        }
+
+        let employment = sqlx::query_as!(
+            Employment,
+            r#"UPDATE "employment" SET "state" = 'withdrawn'
+            WHERE "id" = $1 AND "state" IN ('pending', 'accepted')
+            RETURNING
+                "id",
+                "rating",
+                "state" as "state: EmploymentState",
+                "user_id",
+                "position_id",
+                "invitation_expires_at""#,
+            employment_id
+        )
+        .fetch_optional(&mut *transaction)
+        .await?
+        .ok_or(RepositoryError::GenericError(
+            "Only pending or accepted jobs can be withdrawn from".to_string(),
+        ))?;
+
+        sqlx::query!(
+            r#"INSERT INTO "employment_withdrawal" ("employment_id", "user_id", "reason", "is_late", "created_at")
+            VALUES ($1, $2, $3, $4, $5)"#,
+            employment.id,
+            employment.user_id,
+            reason,
+            is_late,
+            now
+        )
+        .execute(&mut *transaction)
+        .await?;
+
+        self.audit_in(
+            &mut transaction,
+            AuditAction::Update,
+            &employment,
+            Some(&before),
+            Some(&employment),
+        )
+        .await?;
+        self.record_state_change_in(
+            &mut transaction,
+            employment.id,
+            Some(before.state),
+            EmploymentState::Withdrawn,
+        )
+        .await?;
+        transaction.commit().await?;
+        Ok(employment)
+    }
+
+    async fn withdrawal_stats(
+        &self,
+        user_id: i32,
+        organization_id: Option<i32>,
+    ) -> Result<WithdrawalStats, RepositoryError> {
+        let stats = sqlx::query_as!(
+            WithdrawalStats,
+            r#"SELECT
+                COUNT(*) AS "total!",
+                COUNT(*) FILTER (WHERE "employment_withdrawal"."is_late") AS "late!"
+            FROM "employment_withdrawal"
+            JOIN "employment" ON "employment"."id" = "employment_withdrawal"."employment_id"
+            JOIN "job_position" ON "job_position"."id" = "employment"."position_id"
+            JOIN "event" ON "event"."id" = "job_position"."event_id"
+            WHERE "employment_withdrawal"."user_id" = $1
+                AND ($2::INT IS NULL OR "event"."organization_id" = $2)"#,
+            user_id,
+            organization_id
+        )
+        .fetch_one(self.pool_handler.pool())
+        .await?;
+        Ok(stats)
+    }
+
+    async fn list_state_history(
+        &self,
+        employment_ids: &[i32],
+    ) -> Result<Vec<EmploymentStateChange>, RepositoryError> {
+        let history = sqlx::query_as!(
+            EmploymentStateChange,
+            r#"SELECT
+                "employment_state_history"."id",
+                "employment_state_history"."employment_id",
+                "employment_state_history"."from_state" AS "from_state: EmploymentState",
+                "employment_state_history"."to_state" AS "to_state: EmploymentState",
+                "employment_state_history"."changed_by",
+                "user"."first_name" || ' ' || "user"."last_name" AS "changed_by_name",
+                "employment_state_history"."changed_at"
+            FROM "employment_state_history"
+            LEFT JOIN "user" ON "user"."id" = "employment_state_history"."changed_by"
+            WHERE "employment_state_history"."employment_id" = ANY($1)
+            ORDER BY "employment_state_history"."changed_at", "employment_state_history"."id""#,
+            employment_ids
+        )
+        .fetch_all(self.pool_handler.pool())
+        .await?;
+        Ok(history)
+    }
+
+    async fn bulk_update_state(
+        &self,
+        employment_ids: &[i32],
+        state: EmploymentState,
+        order: BulkOrder,
+    ) -> Result<BulkUpdateResult, RepositoryError> {
+        let mut result = BulkUpdateResult::default();
+        let mut transaction = self.pool_handler.pool().begin().await?;
+
+        let employments = sqlx::query_as!(
+            Employment,
+            r#"SELECT
+                "id",
+                "rating",
+                "state" as "state: EmploymentState",
+                "user_id",
+                "position_id",
+                "invitation_expires_at"
+            FROM "employment"
+            WHERE "id" = ANY($1)
+            ORDER BY CASE WHEN $2 THEN "rating" END DESC NULLS LAST, "id"
+            FOR UPDATE"#,
+            employment_ids,
+            order == BulkOrder::Rating
+        )
+        .fetch_all(&mut *transaction)
+        .await?;
+
+        for employment_id in employment_ids {
+            if !employments
+                .iter()
+                .any(|employment| employment.id == *employment_id)
+            {
+                result.skipped.push(SkippedEmployment {
+                    employment_id: *employment_id,
+                    reason: "The employment does not exist".to_string(),
+                });
+            }
+        }
+
+        let position_ids: Vec<i32> = employments
+            .iter()
+            .map(|employment| employment.position_id)
+            .collect();
+        sqlx::query!(
+            r#"SELECT "id" FROM "job_position" WHERE "id" = ANY($1) FOR UPDATE"#,
+            &position_ids
+        )
+        .fetch_all(&mut *transaction)
+        .await?;
+        let mut free_capacity: HashMap<i32, i64> = sqlx::query!(
+            r#"SELECT
+                "job_position"."id",
+                "job_position"."capacity" - COUNT("employment"."id") FILTER (
+                    WHERE "employment"."state" IN ('accepted', 'done')
+                ) AS "free!"
+            FROM "job_position"
+            LEFT JOIN "employment" ON "employment"."position_id" = "job_position"."id"
+            WHERE "job_position"."id" = ANY($1)
+            GROUP BY "job_position"."id""#,
+            &position_ids
+        )
+        .fetch_all(&mut *transaction)
+        .await?
+        .into_iter()
+        .map(|row| (row.id, row.free))
+        .collect();
+
+        for employment in employments {
+            if !employment.state.can_transition_to(&state) {
+                result.skipped.push(SkippedEmployment {
+                    employment_id: employment.id,
+                    reason: format!(
+                        "An employment cannot move from {} to {}",
+                        employment.state, state
+                    ),
+                });
+                continue;
+            }
+            if state == EmploymentState::Accepted {
+                let free = free_capacity.entry(employment.position_id).or_insert(0);
+                if *free <= 0 {
+                    result.skipped.push(SkippedEmployment {
+                        employment_id: employment.id,
+                        reason: "Job position is already full".to_string(),
+                    });
+                    continue;
+                }
+                *free -= 1;
+            }
+
+            let updated = sqlx::query_as!(
+                Employment,
+                r#"UPDATE "employment" SET "state" = $2::employment_state
+                WHERE "id" = $1
+                RETURNING
+                    "id",
+                    "rating",
+                    "state" as "state: EmploymentState",
+                    "user_id",
+                    "position_id",
+                    "invitation_expires_at""#,
+                employment.id,
+                state.clone() as _
+            )
+            .fetch_one(&mut *transaction)
+            .await?;
+            self.audit_in(
+                &mut transaction,
+                AuditAction::Update,
+                &updated,
+                Some(&employment),
+                Some(&updated),
+            )
+            .await?;
+            self.record_state_change_in(
+                &mut transaction,
+                employment.id,
+                Some(employment.state.clone()),
+                state.clone(),
+            )
+            .await?;
+            result.updated.push(updated);
+        }
+        transaction.commit().await?;
+        Ok(result)
+    }
+
+    async fn list_staffing_candidates(
+        &self,
+        job_position_id: i32,
+    ) -> Result<Vec<CandidateStats>, RepositoryError> {
+        let candidates = sqlx::query_as!(
+            CandidateStats,
+            r#"WITH "target" AS (
+                SELECT
+                    "job_position"."id",
+                    "job_position"."position_category_id",
+                    "event"."date_start",
+                    "event"."date_end",
+                    "event"."organization_id"
+                FROM "job_position"
+                JOIN "event" ON "event"."id" = "job_position"."event_id"
+                WHERE "job_position"."id" = $1
+            )
+            SELECT
+                "user"."id" AS "user_id!",
+                "user"."first_name" AS "first_name!",
+                "user"."last_name" AS "last_name!",
+                "user"."username" AS "username!",
+                (
+                    SELECT AVG("employment"."rating")::REAL
+                    FROM "employment"
+                    WHERE "employment"."user_id" = "user"."id"
+                        AND "employment"."state" = 'done'
+                        AND "employment"."rating" > 0
+                ) AS "average_rating",
+                (
+                    SELECT COUNT(*)
+                    FROM "employment"
+                    JOIN "job_position" ON "job_position"."id" = "employment"."position_id"
+                    WHERE "employment"."user_id" = "user"."id"
+                        AND "employment"."state" = 'done'
+                        AND "job_position"."position_category_id" = "target"."position_category_id"
+                ) AS "done_in_category!",
+                (
+                    SELECT COUNT(*)
+                    FROM "employment"
+                    JOIN "job_position" ON "job_position"."id" = "employment"."position_id"
+                    JOIN "event" ON "event"."id" = "job_position"."event_id"
+                    WHERE "employment"."user_id" = "user"."id"
+                        AND "employment"."state" IN ('pending', 'invited', 'accepted')
+                        AND "event"."deleted_at" IS NULL
+                        AND "event"."date_start" <= "target"."date_end"
+                        AND "event"."date_end" >= "target"."date_start"
+                ) AS "conflicts!",
+                (
+                    SELECT COUNT(*)
+                    FROM "attendance_record"
+                    JOIN "employment" ON "employment"."id" = "attendance_record"."employment_id"
+                    WHERE "employment"."user_id" = "user"."id"
+                        AND "attendance_record"."outcome" = 'no_show'
+                ) AS "no_shows!"
+            FROM "target"
+            JOIN "organization_membership"
+                ON "organization_membership"."organization_id" = "target"."organization_id"
+            JOIN "user" ON "user"."id" = "organization_membership"."user_id"
+            WHERE "user"."role" = 'employee'
+                AND "user"."deleted_at" IS NULL
+                AND NOT EXISTS (
+                    SELECT 1 FROM "employment"
+                    WHERE "employment"."user_id" = "user"."id"
+                        AND "employment"."position_id" = "target"."id"
+                )"#,
+            job_position_id
+        )
+        .fetch_all(self.pool_handler.pool())
+        .await?;
+        Ok(candidates)
+    }
 }
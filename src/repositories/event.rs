@@ -1,13 +1,78 @@
 use crate::error::RepositoryError;
-use crate::models::event::{CreateEvent, Event, PartialEvent, SelectManyFilter};
+use crate::models::audit_log::{AuditAction, AuditEntity};
+use crate::models::employment::{Employment, EmploymentState};
+use crate::models::event::{
+    CancelEvent, CreateEvent, Event, EventStatus, ManagedBy, PartialEvent, SelectManyFilter,
+    SortColumn,
+};
+use crate::models::notification::CreateNotification;
+use crate::models::pagination::{ListSpec, Page};
+use crate::models::trash;
+use crate::repositories::audit_log::PgAuditLogRepository;
+use crate::repositories::columns::{column, select_columns};
+use crate::repositories::notification::{NotificationRepository, PgNotificationRepository};
 use crate::repositories::pool_handler::PoolHandler;
 use anyhow::Result;
 use async_trait::async_trait;
-use sqlx::{QueryBuilder, Row};
+use sqlx::postgres::PgRow;
+use sqlx::types::time::{Date, OffsetDateTime};
+use sqlx::{PgConnection, Postgres, QueryBuilder, Row};
+use std::sync::Arc;
+
+pub(crate) const EVENT_COLUMNS: &[&str] = &[
+    "id",
+    "name",
+    "date_start",
+    "date_end",
+    "img_url",
+    "description",
+    "status",
+    "venue_id",
+    "owner_id",
+    "cancelled_at",
+    "cancellation_reason",
+    "publish_at",
+    "organization_id",
+    "withdrawal_cutoff_hours",
+];
+
+/// Adds the condition of [`ManagedBy`] on the event whose id is in `event_id_column`.
+pub(crate) fn push_managed_by(
+    query_builder: &mut QueryBuilder<'_, Postgres>,
+    event_id_column: &str,
+    managed_by: ManagedBy,
+) {
+    let permissions = if managed_by.actions.is_empty() {
+        "TRUE".to_string()
+    } else {
+        managed_by
+            .actions
+            .iter()
+            .map(|action| action.manager_condition())
+            .collect::<Vec<_>>()
+            .join(" OR ")
+    };
+    query_builder.push(format!(
+        r#" AND ({event_id_column} IN (SELECT "id" FROM "event" WHERE "owner_id" = "#
+    ));
+    query_builder.push_bind(managed_by.user_id);
+    query_builder.push(format!(
+        r#") OR {event_id_column} IN (SELECT "event_id" FROM "event_manager_relation" WHERE "user_id" = "#
+    ));
+    query_builder.push_bind(managed_by.user_id);
+    query_builder.push(format!(" AND ({permissions})))"));
+}
 
 #[async_trait]
-pub trait EventRepository {
+#[allow(dead_code)]
+pub trait EventRepository: Send + Sync {
+    fn with_actor(&self, actor_id: i32) -> Arc<dyn EventRepository>;
     async fn list_events(&self, filter: SelectManyFilter) -> Result<Vec<Event>>;
+    async fn list_events_page(
+        &self,
+        filter: SelectManyFilter,
+        spec: &ListSpec<SortColumn>,
+    ) -> Result<Page<Event>>;
     async fn get_event_by_id(&self, event_id: i32) -> Result<Event, RepositoryError>;
     async fn create_event(&self, new_event: CreateEvent) -> Result<Event, RepositoryError>;
     async fn delete_event(&self, event_id: i32) -> Result<(), RepositoryError>;
@@ -16,18 +81,139 @@ pub trait EventRepository {
         event_id: i32,
         patch_event: PartialEvent,
     ) -> Result<Event, RepositoryError>;
-    async fn list_events_worked_by_user(&self, user_id: i32)
-        -> Result<Vec<Event>, RepositoryError>;
+    async fn list_events_worked_by_user(
+        &self,
+        user_id: i32,
+        organization_id: i32,
+    ) -> Result<Vec<Event>, RepositoryError>;
+    async fn cancel_event(
+        &self,
+        event_id: i32,
+        cancellation: CancelEvent,
+    ) -> Result<Event, RepositoryError>;
+    async fn transition_event(
+        &self,
+        event_id: i32,
+        status: EventStatus,
+    ) -> Result<Event, RepositoryError>;
+    async fn advance_event_statuses(&self, today: Date) -> Result<Vec<Event>, RepositoryError>;
+    async fn publish_scheduled_events(
+        &self,
+        now: OffsetDateTime,
+    ) -> Result<Vec<Event>, RepositoryError>;
+    async fn list_deleted_events(
+        &self,
+        organization_id: i32,
+        spec: &ListSpec<trash::SortColumn>,
+    ) -> Result<Page<Event>, RepositoryError>;
+    async fn restore_event(
+        &self,
+        event_id: i32,
+        organization_id: i32,
+    ) -> Result<Event, RepositoryError>;
+    async fn purge_event(
+        &self,
+        event_id: i32,
+        organization_id: i32,
+    ) -> Result<(), RepositoryError>;
 }
 
 #[derive(Debug, Clone)]
 pub struct PgEventRepository {
     pub pool_handler: PoolHandler,
+    pub actor_id: Option<i32>,
 }
 
 impl PgEventRepository {
     pub fn new(pool_handler: PoolHandler) -> Self {
-        Self { pool_handler }
+        Self {
+            pool_handler,
+            actor_id: None,
+        }
+    }
+
+    pub fn with_actor(&self, actor_id: i32) -> Self {
+        Self {
+            pool_handler: self.pool_handler.clone(),
+            actor_id: Some(actor_id),
+        }
+    }
+
+    async fn audit_in(
+        &self,
+        connection: &mut PgConnection,
+        action: AuditAction,
+        event_id: i32,
+        before: Option<&Event>,
+        after: Option<&Event>,
+    ) -> Result<(), RepositoryError> {
+        PgAuditLogRepository::record_in(
+            connection,
+            self.actor_id,
+            AuditEntity::Event,
+            event_id,
+            Some(event_id),
+            action,
+            before,
+            after,
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Trash actions only reach records of the organization they are taken in.
+    async fn check_in_organization(
+        connection: &mut PgConnection,
+        event_id: i32,
+        organization_id: i32,
+    ) -> Result<(), RepositoryError> {
+        let in_organization = sqlx::query_scalar!(
+            r#"SELECT EXISTS (
+                SELECT 1 FROM "event" WHERE "id" = $1 AND "organization_id" = $2
+            ) AS "exists!""#,
+            event_id,
+            organization_id
+        )
+        .fetch_one(connection)
+        .await?;
+
+        if !in_organization {
+            return Err(RepositoryError::NotFound);
+        }
+        Ok(())
+    }
+
+    pub async fn get_event_by_id_in(
+        connection: &mut PgConnection,
+        event_id: i32,
+    ) -> Result<Event, RepositoryError> {
+        let event = sqlx::query_as!(
+            Event,
+            r#"SELECT
+                "id",
+                "name", 
+                "date_start", 
+                "date_end", "img_url", 
+                "description", 
+                "status" AS "status: EventStatus", 
+                "venue_id", 
+                "owner_id",
+                "cancelled_at",
+                "cancellation_reason",
+                "publish_at",
+                "organization_id",
+                "withdrawal_cutoff_hours"
+            FROM "event" WHERE "id" = $1 AND "deleted_at" IS NULL"#,
+            event_id
+        )
+        .fetch_optional(connection)
+        .await?;
+
+        if let Some(event) = event {
+            return Ok(event);
+        }
+
+        Err(RepositoryError::NotFound)
     }
 
     fn filters_by_state(state: Option<String>, city: Option<String>) -> bool {
@@ -36,30 +222,24 @@ impl PgEventRepository {
         }
         true
     }
-}
 
-#[async_trait]
-impl EventRepository for PgEventRepository {
-    async fn list_events(&self, filter: SelectManyFilter) -> Result<Vec<Event>> {
-        let mut query_builder = QueryBuilder::new(
-            r#"SELECT
-                "event"."id",
-                "event"."name",
-                "event"."date_start",
-                "event"."date_end",
-                "event"."img_url",
-                "event"."description",
-                "event"."is_draft",
-                "event"."venue_id",
-                "event"."owner_id"
-            FROM "event""#,
-        );
+    /// Adds the `FROM` and `WHERE` clauses of a list query.
+    pub(crate) fn push_filter(
+        query_builder: &mut QueryBuilder<'_, Postgres>,
+        filter: SelectManyFilter,
+    ) {
+        query_builder.push(r#" FROM "event""#);
 
         if Self::filters_by_state(filter.state.clone(), filter.city.clone()) {
             query_builder.push(r#" JOIN "venue" ON "event"."venue_id" = "venue"."id""#);
         }
 
-        query_builder.push(r#" WHERE 1=1"#);
+        query_builder.push(r#" WHERE "event"."deleted_at" IS NULL"#);
+
+        if let Some(organization_id) = filter.organization_id {
+            query_builder.push(r#" AND "event"."organization_id" = "#);
+            query_builder.push_bind(organization_id);
+        }
 
         if let Some(venue_id) = filter.venue_id {
             query_builder.push(r#" AND "event"."venue_id" = "#);
@@ -71,9 +251,9 @@ impl EventRepository for PgEventRepository {
             query_builder.push_bind(owner_id);
         }
 
-        if let Some(is_draft) = filter.is_draft {
-            query_builder.push(r#" AND "event"."is_draft" = "#);
-            query_builder.push_bind(is_draft);
+        if let Some(status) = filter.status {
+            query_builder.push(r#" AND "event"."status" = "#);
+            query_builder.push_bind(status);
         }
 
         if let Some(date_from) = filter.date_from {
@@ -102,85 +282,150 @@ impl EventRepository for PgEventRepository {
             query_builder.push(r#" || '%'"#);
         }
 
+        if let Some(managed_by) = filter.managed_by {
+            push_managed_by(query_builder, r#""event"."id""#, managed_by);
+        }
+    }
+}
+
+/// Reads an event selected with [`select_columns`] and `prefix`.
+pub(crate) fn event_from_row(row: &PgRow, prefix: &str) -> Result<Event, sqlx::Error> {
+    Ok(Event {
+        id: row.try_get(&*column(prefix, "id"))?,
+        name: row.try_get(&*column(prefix, "name"))?,
+        date_start: row.try_get(&*column(prefix, "date_start"))?,
+        date_end: row.try_get(&*column(prefix, "date_end"))?,
+        img_url: row.try_get(&*column(prefix, "img_url"))?,
+        description: row.try_get(&*column(prefix, "description"))?,
+        status: row.try_get(&*column(prefix, "status"))?,
+        venue_id: row.try_get(&*column(prefix, "venue_id"))?,
+        owner_id: row.try_get(&*column(prefix, "owner_id"))?,
+        cancelled_at: row.try_get(&*column(prefix, "cancelled_at"))?,
+        cancellation_reason: row.try_get(&*column(prefix, "cancellation_reason"))?,
+        publish_at: row.try_get(&*column(prefix, "publish_at"))?,
+        organization_id: row.try_get(&*column(prefix, "organization_id"))?,
+        withdrawal_cutoff_hours: row.try_get(&*column(prefix, "withdrawal_cutoff_hours"))?,
+    })
+}
+
+#[async_trait]
+impl EventRepository for PgEventRepository {
+    fn with_actor(&self, actor_id: i32) -> Arc<dyn EventRepository> {
+        Arc::new(PgEventRepository::with_actor(self, actor_id))
+    }
+
+    async fn list_events(&self, filter: SelectManyFilter) -> Result<Vec<Event>> {
+        let mut query_builder = QueryBuilder::new(format!(
+            "SELECT {}",
+            select_columns("event", EVENT_COLUMNS, "")
+        ));
+        Self::push_filter(&mut query_builder, filter);
+
         let query = query_builder.build();
         let rows = query.fetch_all(self.pool_handler.pool()).await?;
 
-        let data: Result<Vec<Event>, sqlx::Error> = rows
-            .into_iter()
-            .map(|row| {
-                Ok(Event {
-                    id: row.try_get("id")?,
-                    name: row.try_get("name")?,
-                    date_start: row.try_get("date_start")?,
-                    date_end: row.try_get("date_end")?,
-                    img_url: row.try_get("img_url")?,
-                    description: row.try_get("description")?,
-                    is_draft: row.try_get("is_draft")?,
-                    venue_id: row.try_get("venue_id")?,
-                    owner_id: row.try_get("owner_id")?,
-                })
-            })
-            .collect();
-
+        let data: Result<Vec<Event>, sqlx::Error> =
+            rows.iter().map(|row| event_from_row(row, "")).collect();
         let data = data?;
         Ok(data)
     }
 
-    async fn get_event_by_id(&self, event_id: i32) -> Result<Event, RepositoryError> {
-        let event = sqlx::query_as!(
-            Event,
-            r#"SELECT
-                "id",
-                "name", 
-                "date_start", 
-                "date_end", "img_url", 
-                "description", 
-                "is_draft", 
-                "venue_id", 
-                "owner_id" 
-            FROM "event" WHERE "id" = $1"#,
-            event_id
-        )
-        .fetch_optional(self.pool_handler.pool())
-        .await?;
+    async fn list_events_page(
+        &self,
+        filter: SelectManyFilter,
+        spec: &ListSpec<SortColumn>,
+    ) -> Result<Page<Event>> {
+        let mut query_builder = QueryBuilder::new(format!(
+            r#"WITH "list" AS (SELECT {}, "#,
+            select_columns("event", EVENT_COLUMNS, "")
+        ));
+        spec.push_sort_key(&mut query_builder);
+        Self::push_filter(&mut query_builder, filter);
+        spec.push_page(&mut query_builder);
 
-        if let Some(event) = event {
-            return Ok(event);
-        }
+        let query = query_builder.build();
+        let rows = query.fetch_all(self.pool_handler.pool()).await?;
 
-        Err(RepositoryError::NotFound)
+        let total = match rows.first() {
+            Some(row) => row.try_get("total_count")?,
+            None => 0,
+        };
+        let data: Result<Vec<Event>, sqlx::Error> =
+            rows.iter().map(|row| event_from_row(row, "")).collect();
+        Ok(Page::new(data?, total, spec, |event| event.id))
+    }
+
+    async fn get_event_by_id(&self, event_id: i32) -> Result<Event, RepositoryError> {
+        let mut connection = self.pool_handler.pool().acquire().await?;
+        Self::get_event_by_id_in(&mut connection, event_id).await
     }
 
     async fn create_event(&self, new_event: CreateEvent) -> Result<Event, RepositoryError> {
+        if !matches!(
+            new_event.status,
+            EventStatus::Draft | EventStatus::Published
+        ) {
+            return Err(RepositoryError::GenericError(
+                "A new event has to be either a draft or published".to_string(),
+            ));
+        }
+
+        let mut transaction = self.pool_handler.pool().begin().await?;
         let event = sqlx::query_as!(
             Event,
             r#"INSERT INTO "event"
-            ("name", "date_start", "date_end", "img_url", "description", "is_draft", "venue_id", "owner_id")
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8) RETURNING
-            "id","name", "date_start", "date_end", "img_url", "description", "is_draft", "venue_id", "owner_id""#,
+            ("name", "date_start", "date_end", "img_url", "description", "status", "venue_id", "owner_id", "publish_at", "organization_id", "withdrawal_cutoff_hours")
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11) RETURNING
+            "id","name", "date_start", "date_end", "img_url", "description", "status" AS "status: EventStatus", "venue_id", "owner_id", "cancelled_at", "cancellation_reason", "publish_at", "organization_id", "withdrawal_cutoff_hours""#,
             new_event.name,
             new_event.date_start,
             new_event.date_end,
             new_event.img_url,
             new_event.description,
-            new_event.is_draft,
+            new_event.status as EventStatus,
             new_event.venue_id,
             new_event.owner_id,
+            new_event.publish_at,
+            new_event.organization_id,
+            new_event.withdrawal_cutoff_hours,
         )
-            .fetch_one(self.pool_handler.pool())
+            .fetch_one(&mut *transaction)
             .await?;
+        self.audit_in(
+            &mut transaction,
+            AuditAction::Create,
+            event.id,
+            None,
+            Some(&event),
+        )
+        .await?;
+        transaction.commit().await?;
         Ok(event)
     }
 
     async fn delete_event(&self, event_id: i32) -> Result<(), RepositoryError> {
-        let result = sqlx::query!(r#"DELETE FROM "event" WHERE "id" = $1"#, event_id)
-            .execute(self.pool_handler.pool())
-            .await?;
+        let mut transaction = self.pool_handler.pool().begin().await?;
+        let event = Self::get_event_by_id_in(&mut transaction, event_id).await?;
+        let result = sqlx::query!(
+            r#"UPDATE "event" SET "deleted_at" = NOW() WHERE "id" = $1 AND "deleted_at" IS NULL"#,
+            event_id
+        )
+        .execute(&mut *transaction)
+        .await?;
 
         if result.rows_affected() == 0 {
             return Err(RepositoryError::NotFound);
         }
 
+        self.audit_in(
+            &mut transaction,
+            AuditAction::Delete,
+            event_id,
+            Some(&event),
+            None,
+        )
+        .await?;
+        transaction.commit().await?;
         Ok(())
     }
 
@@ -189,16 +434,21 @@ impl EventRepository for PgEventRepository {
         event_id: i32,
         patch_event: PartialEvent,
     ) -> Result<Event, RepositoryError> {
-        let event = self.get_event_by_id(event_id).await?;
+        let mut transaction = self.pool_handler.pool().begin().await?;
+        let event = Self::get_event_by_id_in(&mut transaction, event_id).await?;
+        let before = event.clone();
 
         let name = patch_event.name.unwrap_or(event.name);
         let date_start = patch_event.date_start.unwrap_or(event.date_start);
         let date_end = patch_event.date_end.unwrap_or(event.date_end);
         let img_url = patch_event.img_url.unwrap_or(event.img_url);
         let description = patch_event.description.or(event.description);
-        let is_draft = patch_event.is_draft.unwrap_or(false);
         let venue_id = patch_event.venue_id.unwrap_or(event.venue_id);
         let owner_id = patch_event.owner_id.unwrap_or(event.owner_id);
+        let publish_at = patch_event.publish_at.unwrap_or(event.publish_at);
+        let withdrawal_cutoff_hours = patch_event
+            .withdrawal_cutoff_hours
+            .unwrap_or(event.withdrawal_cutoff_hours);
 
         let event = sqlx::query_as!(
             Event,
@@ -208,10 +458,11 @@ impl EventRepository for PgEventRepository {
                 "date_end" = $4, 
                 "img_url" = $5, 
                 "description" = $6, 
-                "is_draft" = $7, 
-                "venue_id" = $8, 
-                "owner_id" = $9 
-            WHERE "id" = $1                
+                "venue_id" = $7, 
+                "owner_id" = $8,
+                "publish_at" = $9,
+                "withdrawal_cutoff_hours" = $10
+            WHERE "id" = $1 AND "deleted_at" IS NULL
             RETURNING
                 "id", 
                 "name",
@@ -219,9 +470,14 @@ impl EventRepository for PgEventRepository {
                 "date_end",
                 "img_url",
                 "description",
-                "is_draft",
+                "status" AS "status: EventStatus",
                 "venue_id",
-                "owner_id"
+                "owner_id",
+                "cancelled_at",
+                "cancellation_reason",
+                "publish_at",
+                "organization_id",
+                "withdrawal_cutoff_hours"
             "#,
             event_id,
             name,
@@ -229,14 +485,24 @@ impl EventRepository for PgEventRepository {
             date_end,
             img_url,
             description,
-            is_draft,
             venue_id,
             owner_id,
+            publish_at,
+            withdrawal_cutoff_hours,
         )
-        .fetch_optional(self.pool_handler.pool())
+        .fetch_optional(&mut *transaction)
         .await?;
 
         if let Some(event) = event {
+            self.audit_in(
+                &mut transaction,
+                AuditAction::Update,
+                event_id,
+                Some(&before),
+                Some(&event),
+            )
+            .await?;
+            transaction.commit().await?;
             return Ok(event);
         }
         Err(RepositoryError::NotFound)
@@ -245,21 +511,500 @@ impl EventRepository for PgEventRepository {
     async fn list_events_worked_by_user(
         &self,
         user_id: i32,
+        organization_id: i32,
     ) -> Result<Vec<Event>, RepositoryError> {
         let events = sqlx::query_as!(
             Event,
             r#"
-            SELECT DISTINCT "event".*
+            SELECT DISTINCT
+                "event"."id",
+                "event"."name",
+                "event"."date_start",
+                "event"."date_end",
+                "event"."img_url",
+                "event"."description",
+                "event"."status" AS "status: EventStatus",
+                "event"."venue_id",
+                "event"."owner_id",
+                "event"."cancelled_at",
+                "event"."cancellation_reason",
+                "event"."publish_at",
+                "event"."organization_id",
+                "event"."withdrawal_cutoff_hours"
             FROM "event"
             JOIN "job_position" ON "job_position"."event_id"="event"."id"
             JOIN "employment" ON "employment"."position_id"="job_position"."id"
             WHERE "employment"."state"='accepted' AND "employment"."user_id"=$1
+                AND "event"."deleted_at" IS NULL AND "job_position"."deleted_at" IS NULL
+                AND "event"."organization_id"=$2
             ;"#,
-            user_id
+            user_id,
+            organization_id
         )
         .fetch_all(self.pool_handler.pool())
         .await?;
 
         Ok(events)
     }
+
+    /// Compensation hours are logged on the event's first day for accepted workers
+    /// who have no worked hours recorded on that day yet.
+    async fn cancel_event(
+        &self,
+        event_id: i32,
+        cancellation: CancelEvent,
+    ) -> Result<Event, RepositoryError> {
+        let mut transaction = self.pool_handler.pool().begin().await?;
+        let before = Self::get_event_by_id_in(&mut transaction, event_id).await?;
+        if before.is_cancelled() {
+            return Err(RepositoryError::GenericError(
+                "The event is already cancelled".to_string(),
+            ));
+        }
+        if !before.status.can_transition_to(&EventStatus::Cancelled) {
+            return Err(RepositoryError::GenericError(format!(
+                "A {} event cannot be cancelled",
+                before.status.to_string().to_lowercase()
+            )));
+        }
+
+        let event = sqlx::query_as!(
+            Event,
+            r#"UPDATE "event" SET
+                "status" = 'cancelled',
+                "cancelled_at" = NOW(),
+                "cancellation_reason" = $2
+            WHERE "id" = $1 AND "deleted_at" IS NULL AND "status" = $3
+            RETURNING
+                "id",
+                "name",
+                "date_start",
+                "date_end",
+                "img_url",
+                "description",
+                "status" AS "status: EventStatus",
+                "venue_id",
+                "owner_id",
+                "cancelled_at",
+                "cancellation_reason",
+                "publish_at",
+                "organization_id",
+                "withdrawal_cutoff_hours""#,
+            event_id,
+            cancellation.reason,
+            before.status as EventStatus
+        )
+        .fetch_optional(&mut *transaction)
+        .await?
+        .ok_or(RepositoryError::NotFound)?;
+
+        sqlx::query!(
+            r#"UPDATE "job_position" SET "is_opened_for_registration" = FALSE
+            WHERE "event_id" = $1"#,
+            event_id
+        )
+        .execute(&mut *transaction)
+        .await?;
+
+        let employments = sqlx::query_as!(
+            Employment,
+            r#"SELECT
+                "employment"."id",
+                "employment"."rating",
+                "employment"."state" AS "state: EmploymentState",
+                "employment"."user_id",
+                "employment"."position_id",
+                "employment"."invitation_expires_at"
+            FROM "employment"
+            JOIN "job_position" ON "job_position"."id" = "employment"."position_id"
+            WHERE "job_position"."event_id" = $1
+                AND "employment"."state" IN ('pending', 'invited', 'accepted')
+            FOR UPDATE OF "employment""#,
+            event_id
+        )
+        .fetch_all(&mut *transaction)
+        .await?;
+
+        if let Some(hours) = cancellation.compensation_hours.filter(|hours| *hours > 0.0) {
+            sqlx::query!(
+                r#"INSERT INTO "worked_hours" ("date", "hours_worked", "employment_id")
+                SELECT $2::DATE, $3::REAL, "employment"."id"
+                FROM "employment"
+                JOIN "job_position" ON "job_position"."id" = "employment"."position_id"
+                WHERE "job_position"."event_id" = $1
+                    AND "employment"."state" = 'accepted'
+                    AND NOT EXISTS (
+                        SELECT 1 FROM "worked_hours"
+                        WHERE "worked_hours"."employment_id" = "employment"."id"
+                            AND "worked_hours"."date" = $2
+                    )"#,
+                event_id,
+                event.date_start,
+                hours
+            )
+            .execute(&mut *transaction)
+            .await?;
+        }
+
+        let employment_ids: Vec<i32> = employments.iter().map(|employment| employment.id).collect();
+        sqlx::query!(
+            r#"UPDATE "employment" SET "state" = 'cancelled' WHERE "id" = ANY($1)"#,
+            &employment_ids
+        )
+        .execute(&mut *transaction)
+        .await?;
+
+        self.audit_in(
+            &mut transaction,
+            AuditAction::Update,
+            event_id,
+            Some(&before),
+            Some(&event),
+        )
+        .await?;
+        for employment in &employments {
+            let cancelled = Employment {
+                state: EmploymentState::Cancelled,
+                ..employment.clone()
+            };
+            PgAuditLogRepository::record_in(
+                &mut transaction,
+                self.actor_id,
+                AuditEntity::Employment,
+                employment.id,
+                Some(event_id),
+                AuditAction::Update,
+                Some(employment),
+                Some(&cancelled),
+            )
+            .await?;
+            sqlx::query!(
+                r#"INSERT INTO "employment_state_history" ("employment_id", "from_state", "to_state", "changed_by")
+                VALUES ($1, $2::employment_state, 'cancelled', $3)"#,
+                employment.id,
+                employment.state.clone() as _,
+                self.actor_id
+            )
+            .execute(&mut *transaction)
+            .await?;
+        }
+
+        transaction.commit().await?;
+
+        let notification_repository = PgNotificationRepository::new(PoolHandler::new(Arc::new(
+            self.pool_handler.pool().clone(),
+        )));
+        let mut notified_users: Vec<i32> = Vec::new();
+
+        for employment in employments {
+            if notified_users.contains(&employment.user_id) {
+                continue;
+            }
+            notified_users.push(employment.user_id);
+            notification_repository
+                .create_notification(CreateNotification {
+                    user_id: employment.user_id,
+                    message: format!("{} has been cancelled: {}", event.name, cancellation.reason),
+                })
+                .await?;
+        }
+
+        Ok(event)
+    }
+
+    /// Cancellation has to go through `cancel_event` so that employments are resolved as well.
+    async fn transition_event(
+        &self,
+        event_id: i32,
+        status: EventStatus,
+    ) -> Result<Event, RepositoryError> {
+        let mut transaction = self.pool_handler.pool().begin().await?;
+        let before = Self::get_event_by_id_in(&mut transaction, event_id).await?;
+        if status == EventStatus::Cancelled {
+            return Err(RepositoryError::GenericError(
+                "Events have to be cancelled with a reason".to_string(),
+            ));
+        }
+        if !before.status.can_transition_to(&status) {
+            return Err(RepositoryError::GenericError(format!(
+                "The event cannot move from {} to {}",
+                before.status.to_string().to_lowercase(),
+                status.to_string().to_lowercase()
+            )));
+        }
+
+        let event = sqlx::query_as!(
+            Event,
+            r#"UPDATE "event" SET
+                "status" = $2,
+                "publish_at" = CASE WHEN $2 = 'published'::event_status THEN NULL ELSE "publish_at" END
+            WHERE "id" = $1 AND "deleted_at" IS NULL AND "status" = $3
+            RETURNING
+                "id",
+                "name",
+                "date_start",
+                "date_end",
+                "img_url",
+                "description",
+                "status" AS "status: EventStatus",
+                "venue_id",
+                "owner_id",
+                "cancelled_at",
+                "cancellation_reason",
+                "publish_at",
+                "organization_id",
+                "withdrawal_cutoff_hours""#,
+            event_id,
+            status as EventStatus,
+            before.status as EventStatus
+        )
+        .fetch_optional(&mut *transaction)
+        .await?;
+
+        if let Some(event) = event {
+            self.audit_in(
+                &mut transaction,
+                AuditAction::Update,
+                event_id,
+                Some(&before),
+                Some(&event),
+            )
+            .await?;
+            transaction.commit().await?;
+            return Ok(event);
+        }
+        Err(RepositoryError::NotFound)
+    }
+
+    /// Drafts, cancelled and archived events are left alone.
+    async fn advance_event_statuses(&self, today: Date) -> Result<Vec<Event>, RepositoryError> {
+        let due = sqlx::query_as!(
+            Event,
+            r#"SELECT
+                "id",
+                "name",
+                "date_start",
+                "date_end",
+                "img_url",
+                "description",
+                "status" AS "status: EventStatus",
+                "venue_id",
+                "owner_id",
+                "cancelled_at",
+                "cancellation_reason",
+                "publish_at",
+                "organization_id",
+                "withdrawal_cutoff_hours"
+            FROM "event"
+            WHERE "deleted_at" IS NULL
+                AND (
+                    ("status" IN ('published', 'registration_closed') AND "date_start" <= $1)
+                    OR ("status" = 'in_progress' AND "date_end" < $1)
+                )
+            ORDER BY "id""#,
+            today
+        )
+        .fetch_all(self.pool_handler.pool())
+        .await?;
+
+        let mut advanced = Vec::new();
+        for event in due {
+            let mut event = event;
+            if event.status != EventStatus::InProgress {
+                event = self
+                    .transition_event(event.id, EventStatus::InProgress)
+                    .await?;
+            }
+            if event.date_end < today {
+                event = self
+                    .transition_event(event.id, EventStatus::Completed)
+                    .await?;
+            }
+            advanced.push(event);
+        }
+
+        Ok(advanced)
+    }
+
+    async fn publish_scheduled_events(
+        &self,
+        now: OffsetDateTime,
+    ) -> Result<Vec<Event>, RepositoryError> {
+        let due = sqlx::query_scalar!(
+            r#"SELECT "id" FROM "event"
+            WHERE "deleted_at" IS NULL AND "status" = 'draft' AND "publish_at" <= $1
+            ORDER BY "id""#,
+            now
+        )
+        .fetch_all(self.pool_handler.pool())
+        .await?;
+
+        let mut published = Vec::new();
+        for event_id in due {
+            published.push(
+                self.transition_event(event_id, EventStatus::Published)
+                    .await?,
+            );
+        }
+
+        Ok(published)
+    }
+
+    async fn list_deleted_events(
+        &self,
+        organization_id: i32,
+        spec: &ListSpec<trash::SortColumn>,
+    ) -> Result<Page<Event>, RepositoryError> {
+        let mut query_builder = QueryBuilder::new(format!(
+            r#"WITH "list" AS (SELECT {}, "#,
+            select_columns("event", EVENT_COLUMNS, "")
+        ));
+        spec.push_sort_key(&mut query_builder);
+        query_builder
+            .push(r#" FROM "event" WHERE "deleted_at" IS NOT NULL AND "organization_id" = "#);
+        query_builder.push_bind(organization_id);
+        spec.push_page(&mut query_builder);
+
+        let rows = query_builder
+            .build()
+            .fetch_all(self.pool_handler.pool())
+            .await?;
+
+        let total = match rows.first() {
+            Some(row) => row.try_get("total_count")?,
+            None => 0,
+        };
+        let data: Result<Vec<Event>, sqlx::Error> =
+            rows.iter().map(|row| event_from_row(row, "")).collect();
+        Ok(Page::new(data?, total, spec, |event| event.id))
+    }
+
+    async fn restore_event(
+        &self,
+        event_id: i32,
+        organization_id: i32,
+    ) -> Result<Event, RepositoryError> {
+        let mut transaction = self.pool_handler.pool().begin().await?;
+        Self::check_in_organization(&mut transaction, event_id, organization_id).await?;
+        let has_deleted_dependencies = sqlx::query_scalar!(
+            r#"SELECT EXISTS (
+                SELECT 1 FROM "event"
+                JOIN "venue" ON "venue"."id" = "event"."venue_id"
+                JOIN "user" ON "user"."id" = "event"."owner_id"
+                WHERE "event"."id" = $1
+                    AND ("venue"."deleted_at" IS NOT NULL OR "user"."deleted_at" IS NOT NULL)
+            ) AS "exists!""#,
+            event_id
+        )
+        .fetch_one(&mut *transaction)
+        .await?;
+
+        if has_deleted_dependencies {
+            return Err(RepositoryError::GenericError(
+                "The event's venue or owner has to be restored first".to_string(),
+            ));
+        }
+
+        let event = sqlx::query_as!(
+            Event,
+            r#"UPDATE "event" SET "deleted_at" = NULL
+            WHERE "id" = $1 AND "deleted_at" IS NOT NULL
+            RETURNING
+                "id",
+                "name",
+                "date_start",
+                "date_end",
+                "img_url",
+                "description",
+                "status" AS "status: EventStatus",
+                "venue_id",
+                "owner_id",
+                "cancelled_at",
+                "cancellation_reason",
+                "publish_at",
+                "organization_id",
+                "withdrawal_cutoff_hours""#,
+            event_id
+        )
+        .fetch_optional(&mut *transaction)
+        .await?;
+
+        if let Some(event) = event {
+            self.audit_in(
+                &mut transaction,
+                AuditAction::Restore,
+                event_id,
+                None,
+                Some(&event),
+            )
+            .await?;
+            transaction.commit().await?;
+            return Ok(event);
+        }
+        Err(RepositoryError::NotFound)
+    }
+
+    async fn purge_event(
+        &self,
+        event_id: i32,
+        organization_id: i32,
+    ) -> Result<(), RepositoryError> {
+        let mut transaction = self.pool_handler.pool().begin().await?;
+        Self::check_in_organization(&mut transaction, event_id, organization_id).await?;
+        let has_worked_hours = sqlx::query_scalar!(
+            r#"SELECT EXISTS (
+                SELECT 1 FROM "worked_hours"
+                JOIN "employment" ON "employment"."id" = "worked_hours"."employment_id"
+                JOIN "job_position" ON "job_position"."id" = "employment"."position_id"
+                WHERE "job_position"."event_id" = $1
+            ) AS "exists!""#,
+            event_id
+        )
+        .fetch_one(&mut *transaction)
+        .await?;
+
+        if has_worked_hours {
+            return Err(RepositoryError::GenericError(
+                "The event has logged worked hours and cannot be purged".to_string(),
+            ));
+        }
+
+        let event = sqlx::query_as!(
+            Event,
+            r#"DELETE FROM "event"
+            WHERE "id" = $1 AND "deleted_at" IS NOT NULL
+            RETURNING
+                "id",
+                "name",
+                "date_start",
+                "date_end",
+                "img_url",
+                "description",
+                "status" AS "status: EventStatus",
+                "venue_id",
+                "owner_id",
+                "cancelled_at",
+                "cancellation_reason",
+                "publish_at",
+                "organization_id",
+                "withdrawal_cutoff_hours""#,
+            event_id
+        )
+        .fetch_optional(&mut *transaction)
+        .await?;
+
+        if let Some(event) = event {
+            self.audit_in(
+                &mut transaction,
+                AuditAction::Purge,
+                event_id,
+                Some(&event),
+                None,
+            )
+            .await?;
+            transaction.commit().await?;
+            return Ok(());
+        }
+        Err(RepositoryError::NotFound)
+    }
 }
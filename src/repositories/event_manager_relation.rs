@@ -1,16 +1,29 @@
 use crate::error::RepositoryError;
-use crate::models::event_manager_relation::{CreateEventManagerRelation, EventManagerRelation};
+use crate::models::event_manager_relation::{
+    CreateEventManagerRelation, EventManagerPermissions, EventManagerRelation,
+};
 use crate::repositories::pool_handler::PoolHandler;
 use anyhow::Result;
 use async_trait::async_trait;
 
 #[async_trait]
-pub trait EventManagerRelationRepository {
+pub trait EventManagerRelationRepository: Send + Sync {
     async fn create_relation(
         &self,
         new_relation: CreateEventManagerRelation,
     ) -> Result<EventManagerRelation, RepositoryError>;
     async fn delete_relation(&self, relation: EventManagerRelation) -> Result<(), RepositoryError>;
+    async fn get_relation(
+        &self,
+        event_id: i32,
+        user_id: i32,
+    ) -> Result<EventManagerRelation, RepositoryError>;
+    async fn update_permissions(
+        &self,
+        event_id: i32,
+        user_id: i32,
+        permissions: EventManagerPermissions,
+    ) -> Result<EventManagerRelation, RepositoryError>;
     async fn list_event_managers(
         &self,
         event_id: i32,
@@ -18,6 +31,7 @@ pub trait EventManagerRelationRepository {
     async fn list_managers_events(
         &self,
         user_id: i32,
+        organization_id: i32,
     ) -> Result<Vec<EventManagerRelation>, RepositoryError>;
 }
 
@@ -64,9 +78,15 @@ impl EventManagerRelationRepository for PgEventManagerRelationRepository {
             EventManagerRelation,
             r#"INSERT INTO "event_manager_relation" ("user_id", "event_id")
             VALUES ($1, $2)
-            RETURNING 
+            RETURNING
                 "user_id",
-                "event_id"
+                "event_id",
+                "can_edit_event",
+                "can_manage_jobs",
+                "can_manage_applicants",
+                "can_approve_hours",
+                "can_view_payroll",
+                "can_manage_managers"
             "#,
             new_relation.user_id,
             new_relation.event_id
@@ -95,6 +115,74 @@ impl EventManagerRelationRepository for PgEventManagerRelationRepository {
         Ok(())
     }
 
+    async fn get_relation(
+        &self,
+        event_id: i32,
+        user_id: i32,
+    ) -> Result<EventManagerRelation, RepositoryError> {
+        let relation = sqlx::query_as!(
+            EventManagerRelation,
+            r#"SELECT
+                "user_id",
+                "event_id",
+                "can_edit_event",
+                "can_manage_jobs",
+                "can_manage_applicants",
+                "can_approve_hours",
+                "can_view_payroll",
+                "can_manage_managers"
+            FROM "event_manager_relation"
+            WHERE "event_id" = $1 AND "user_id" = $2"#,
+            event_id,
+            user_id
+        )
+        .fetch_optional(self.pool_handler.pool())
+        .await?;
+
+        relation.ok_or(RepositoryError::NotFound)
+    }
+
+    async fn update_permissions(
+        &self,
+        event_id: i32,
+        user_id: i32,
+        permissions: EventManagerPermissions,
+    ) -> Result<EventManagerRelation, RepositoryError> {
+        let relation = sqlx::query_as!(
+            EventManagerRelation,
+            r#"UPDATE "event_manager_relation" SET
+                "can_edit_event" = $3,
+                "can_manage_jobs" = $4,
+                "can_manage_applicants" = $5,
+                "can_approve_hours" = $6,
+                "can_view_payroll" = $7,
+                "can_manage_managers" = $8
+            WHERE "event_id" = $1 AND "user_id" = $2
+            RETURNING
+                "user_id",
+                "event_id",
+                "can_edit_event",
+                "can_manage_jobs",
+                "can_manage_applicants",
+                "can_approve_hours",
+                "can_view_payroll",
+                "can_manage_managers"
+            "#,
+            event_id,
+            user_id,
+            permissions.can_edit_event,
+            permissions.can_manage_jobs,
+            permissions.can_manage_applicants,
+            permissions.can_approve_hours,
+            permissions.can_view_payroll,
+            permissions.can_manage_managers
+        )
+        .fetch_optional(self.pool_handler.pool())
+        .await?;
+
+        relation.ok_or(RepositoryError::NotFound)
+    }
+
     async fn list_event_managers(
         &self,
         event_id: i32,
@@ -102,9 +190,17 @@ impl EventManagerRelationRepository for PgEventManagerRelationRepository {
         let relations = sqlx::query_as!(
             EventManagerRelation,
             r#"SELECT
-                "user_id", "event_id"
+                "user_id",
+                "event_id",
+                "can_edit_event",
+                "can_manage_jobs",
+                "can_manage_applicants",
+                "can_approve_hours",
+                "can_view_payroll",
+                "can_manage_managers"
             FROM "event_manager_relation"
-            WHERE "event_id" = $1"#,
+            WHERE "event_id" = $1
+                AND "user_id" IN (SELECT "id" FROM "user" WHERE "deleted_at" IS NULL)"#,
             event_id
         )
         .fetch_all(self.pool_handler.pool())
@@ -116,14 +212,27 @@ impl EventManagerRelationRepository for PgEventManagerRelationRepository {
     async fn list_managers_events(
         &self,
         user_id: i32,
+        organization_id: i32,
     ) -> Result<Vec<EventManagerRelation>, RepositoryError> {
         let relations = sqlx::query_as!(
             EventManagerRelation,
             r#"SELECT
-                "user_id", "event_id"
+                "user_id",
+                "event_id",
+                "can_edit_event",
+                "can_manage_jobs",
+                "can_manage_applicants",
+                "can_approve_hours",
+                "can_view_payroll",
+                "can_manage_managers"
             FROM "event_manager_relation"
-            WHERE "user_id" = $1"#,
-            user_id
+            WHERE "user_id" = $1
+                AND "event_id" IN (
+                    SELECT "id" FROM "event"
+                    WHERE "deleted_at" IS NULL AND "organization_id" = $2
+                )"#,
+            user_id,
+            organization_id
         )
         .fetch_all(self.pool_handler.pool())
         .await?;
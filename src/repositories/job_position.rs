@@ -1,18 +1,50 @@
 use crate::error::RepositoryError;
+use crate::models::audit_log::{AuditAction, AuditEntity};
 use crate::models::job_position::{
     CreateJobPosition, JobPosition, PartialJobPosition, SalaryCurrency, SelectManyFilter,
+    SortColumn,
 };
-use crate::repositories::event::{EventRepository, PgEventRepository};
+use crate::models::pagination::{ListSpec, Page};
+use crate::models::trash;
+use crate::repositories::event::{push_managed_by, PgEventRepository};
+use crate::repositories::audit_log::PgAuditLogRepository;
+use crate::repositories::columns::{column, select_columns};
 use crate::repositories::pool_handler::PoolHandler;
+use crate::repositories::unit_of_work::UnitOfWork;
+use crate::rich_text::{render_markdown, sanitize_html};
 use anyhow::Result;
+use sqlx::postgres::PgRow;
 use sqlx::types::time::OffsetDateTime;
-use sqlx::{QueryBuilder, Row};
-use std::sync::Arc;
+use sqlx::{PgConnection, Postgres, QueryBuilder, Row};
 use async_trait::async_trait;
+use std::sync::Arc;
+
+pub(crate) const JOB_POSITION_COLUMNS: &[&str] = &[
+    "id",
+    "name",
+    "description",
+    "salary",
+    "capacity",
+    "instructions_html",
+    "instructions_markdown",
+    "is_opened_for_registration",
+    "currency",
+    "event_id",
+    "position_category_id",
+    "registration_opens_at",
+    "registration_closes_at",
+];
 
 #[async_trait]
-pub trait JobPositionRepository {
+#[allow(dead_code)]
+pub trait JobPositionRepository: Send + Sync {
+    fn with_actor(&self, actor_id: i32) -> Arc<dyn JobPositionRepository>;
     async fn list_job_positions(&self, filter: SelectManyFilter) -> Result<Vec<JobPosition>>;
+    async fn list_job_positions_page(
+        &self,
+        filter: SelectManyFilter,
+        spec: &ListSpec<SortColumn>,
+    ) -> Result<Page<JobPosition>>;
     async fn list_job_positions_worked_by_user_on_event(
         &self,
         user_id: i32,
@@ -26,31 +58,189 @@ pub trait JobPositionRepository {
         &self,
         new_position: CreateJobPosition,
     ) -> Result<JobPosition, RepositoryError>;
+    async fn create_job_position_in(
+        &self,
+        unit_of_work: &mut UnitOfWork,
+        new_position: CreateJobPosition,
+    ) -> Result<JobPosition, RepositoryError>;
     async fn delete_job_position(&self, position_id: i32) -> Result<(), RepositoryError>;
     async fn update_job_position(
         &self,
         position_id: i32,
         patch_position: PartialJobPosition,
     ) -> Result<JobPosition, RepositoryError>;
+    async fn list_deleted_job_positions(
+        &self,
+        organization_id: i32,
+        spec: &ListSpec<trash::SortColumn>,
+    ) -> Result<Page<JobPosition>, RepositoryError>;
+    async fn restore_job_position(
+        &self,
+        position_id: i32,
+        organization_id: i32,
+    ) -> Result<JobPosition, RepositoryError>;
+    async fn purge_job_position(
+        &self,
+        position_id: i32,
+        organization_id: i32,
+    ) -> Result<(), RepositoryError>;
+    async fn apply_registration_windows(
+        &self,
+        now: OffsetDateTime,
+    ) -> Result<Vec<JobPosition>, RepositoryError>;
 }
 
 #[derive(Debug, Clone)]
 pub struct PgJobPositionRepository {
     pub pool_handler: PoolHandler,
+    pub actor_id: Option<i32>,
 }
 
 impl PgJobPositionRepository {
     pub fn new(pool_handler: PoolHandler) -> Self {
-        Self { pool_handler }
+        Self {
+            pool_handler,
+            actor_id: None,
+        }
+    }
+
+    pub fn with_actor(&self, actor_id: i32) -> Self {
+        Self {
+            pool_handler: self.pool_handler.clone(),
+            actor_id: Some(actor_id),
+        }
+    }
+
+    async fn audit_in(
+        &self,
+        connection: &mut PgConnection,
+        action: AuditAction,
+        job_position: &JobPosition,
+        before: Option<&JobPosition>,
+        after: Option<&JobPosition>,
+    ) -> Result<(), RepositoryError> {
+        PgAuditLogRepository::record_in(
+            connection,
+            self.actor_id,
+            AuditEntity::JobPosition,
+            job_position.id,
+            Some(job_position.event_id),
+            action,
+            before,
+            after,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn check_in_organization(
+        connection: &mut PgConnection,
+        position_id: i32,
+        organization_id: i32,
+    ) -> Result<(), RepositoryError> {
+        let in_organization = sqlx::query_scalar!(
+            r#"SELECT EXISTS (
+                SELECT 1 FROM "job_position"
+                JOIN "event" ON "event"."id" = "job_position"."event_id"
+                WHERE "job_position"."id" = $1 AND "event"."organization_id" = $2
+            ) AS "exists!""#,
+            position_id,
+            organization_id
+        )
+        .fetch_one(connection)
+        .await?;
+
+        if !in_organization {
+            return Err(RepositoryError::NotFound);
+        }
+        Ok(())
     }
 
-    async fn check_add(&self, new_position: CreateJobPosition) -> Result<(), RepositoryError> {
-        let event_repository =
-            PgEventRepository::new(PoolHandler::new(Arc::new(self.pool_handler.pool().clone())));
+    pub async fn get_job_position_by_id_in(
+        connection: &mut PgConnection,
+        position_id: i32,
+    ) -> Result<JobPosition, RepositoryError> {
+        let job_position = sqlx::query_as!(
+            JobPosition,
+            r#"SELECT
+                "id",
+                "name",
+                "description",
+                "salary",
+                "currency" AS "currency: SalaryCurrency",
+                "capacity",
+                "instructions_html",
+                "instructions_markdown",
+                "is_opened_for_registration",
+                "event_id",
+                "position_category_id",
+                "registration_opens_at",
+                "registration_closes_at"
+            FROM "job_position"
+            WHERE "id" = $1 AND "deleted_at" IS NULL"#,
+            position_id
+        )
+        .fetch_optional(connection)
+        .await?;
 
-        let event = event_repository
-            .get_event_by_id(new_position.event_id)
+        if let Some(job_position) = job_position {
+            return Ok(job_position);
+        }
+
+        Err(RepositoryError::NotFound)
+    }
+
+    /// Renders the instructions whose HTML was cleared by the migration that introduced
+    /// Markdown through the same sanitizer as new instructions. Returns how many job
+    /// positions were rendered.
+    pub async fn sanitize_legacy_instructions(&self) -> Result<usize, RepositoryError> {
+        let mut transaction = self.pool_handler.pool().begin().await?;
+        let pending = sqlx::query!(
+            r#"SELECT "id", "instructions_markdown" FROM "job_position"
+            WHERE "instructions_html" = '' AND "instructions_markdown" <> ''"#
+        )
+        .fetch_all(&mut *transaction)
+        .await?;
+
+        for job_position in &pending {
+            sqlx::query!(
+                r#"UPDATE "job_position" SET "instructions_html" = $1 WHERE "id" = $2"#,
+                sanitize_html(&job_position.instructions_markdown),
+                job_position.id
+            )
+            .execute(&mut *transaction)
             .await?;
+        }
+
+        transaction.commit().await?;
+        Ok(pending.len())
+    }
+
+    fn check_registration_window(
+        opens_at: Option<OffsetDateTime>,
+        closes_at: Option<OffsetDateTime>,
+    ) -> Result<(), RepositoryError> {
+        if let (Some(opens_at), Some(closes_at)) = (opens_at, closes_at) {
+            if closes_at <= opens_at {
+                return Err(RepositoryError::GenericError(
+                    "Registration has to close after it opens".to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    async fn check_add(
+        connection: &mut PgConnection,
+        new_position: &CreateJobPosition,
+    ) -> Result<(), RepositoryError> {
+        Self::check_registration_window(
+            new_position.registration_opens_at,
+            new_position.registration_closes_at,
+        )?;
+
+        let event =
+            PgEventRepository::get_event_by_id_in(connection, new_position.event_id).await?;
 
         if event.date_end < OffsetDateTime::now_utc().date() {
             return Err(RepositoryError::GenericError(
@@ -58,148 +248,215 @@ impl PgJobPositionRepository {
             ));
         }
 
+        if event.is_cancelled() {
+            return Err(RepositoryError::GenericError(
+                "The event has been cancelled".to_string(),
+            ));
+        }
+
         Ok(())
     }
-}
 
-#[async_trait]
-impl JobPositionRepository for PgJobPositionRepository {
-    async fn list_job_positions(&self, filter: SelectManyFilter) -> Result<Vec<JobPosition>> {
-        let mut query_builder = QueryBuilder::new(
-            r#"SELECT
-                    "id",
-                    "name",
-                    "description",
-                    "salary",
-                    "capacity",
-                    "instructions_html",
-                    "is_opened_for_registration",
-                    "currency",
-                    "event_id",
-                    "position_category_id"
-                FROM "job_position" WHERE 1=1"#,
+    /// Adds the `FROM` and `WHERE` clauses of a list query.
+    pub(crate) fn push_filter(
+        query_builder: &mut QueryBuilder<'_, Postgres>,
+        filter: SelectManyFilter,
+    ) {
+        query_builder.push(
+            r#" FROM "job_position"
+                JOIN "event" ON "event"."id" = "job_position"."event_id"
+                WHERE "job_position"."deleted_at" IS NULL AND "event"."deleted_at" IS NULL"#,
         );
 
+        if let Some(organization_id) = filter.organization_id {
+            query_builder.push(r#" AND "event"."organization_id" = "#);
+            query_builder.push_bind(organization_id);
+        }
+
         if let Some(capacity) = filter.capacity {
-            query_builder.push(r#" AND "capacity" = "#);
+            query_builder.push(r#" AND "job_position"."capacity" = "#);
             query_builder.push_bind(capacity);
         }
 
         if let Some(position_category_id) = filter.position_category_id {
-            query_builder.push(r#" AND "position_category_id" = "#);
+            query_builder.push(r#" AND "job_position"."position_category_id" = "#);
             query_builder.push_bind(position_category_id);
         }
 
         if let Some(event_id) = filter.event_id {
-            query_builder.push(r#" AND "event_id" = "#);
+            query_builder.push(r#" AND "job_position"."event_id" = "#);
             query_builder.push_bind(event_id);
         }
 
         if let Some(salary) = filter.salary {
-            query_builder.push(r#" AND "salary" = "#);
+            query_builder.push(r#" AND "job_position"."salary" = "#);
             query_builder.push_bind(salary);
         }
 
         if let Some(is_opened_for_registration) = filter.is_opened_for_registration {
-            query_builder.push(r#" AND "is_opened_for_registration" = "#);
+            query_builder.push(r#" AND "job_position"."is_opened_for_registration" = "#);
             query_builder.push_bind(is_opened_for_registration);
         }
 
+        if let Some(managed_by) = filter.managed_by {
+            push_managed_by(query_builder, r#""event"."id""#, managed_by);
+        }
+    }
+}
+
+/// Reads a job position selected with [`select_columns`] and `prefix`.
+pub(crate) fn job_position_from_row(row: &PgRow, prefix: &str) -> Result<JobPosition, sqlx::Error> {
+    Ok(JobPosition {
+        id: row.try_get(&*column(prefix, "id"))?,
+        name: row.try_get(&*column(prefix, "name"))?,
+        description: row.try_get(&*column(prefix, "description"))?,
+        salary: row.try_get(&*column(prefix, "salary"))?,
+        currency: row.try_get(&*column(prefix, "currency"))?,
+        capacity: row.try_get(&*column(prefix, "capacity"))?,
+        instructions_html: row.try_get(&*column(prefix, "instructions_html"))?,
+        instructions_markdown: row.try_get(&*column(prefix, "instructions_markdown"))?,
+        is_opened_for_registration: row.try_get(&*column(prefix, "is_opened_for_registration"))?,
+        event_id: row.try_get(&*column(prefix, "event_id"))?,
+        position_category_id: row.try_get(&*column(prefix, "position_category_id"))?,
+        registration_opens_at: row.try_get(&*column(prefix, "registration_opens_at"))?,
+        registration_closes_at: row.try_get(&*column(prefix, "registration_closes_at"))?,
+    })
+}
+
+#[async_trait]
+impl JobPositionRepository for PgJobPositionRepository {
+    fn with_actor(&self, actor_id: i32) -> Arc<dyn JobPositionRepository> {
+        Arc::new(PgJobPositionRepository::with_actor(self, actor_id))
+    }
+
+    async fn list_job_positions(&self, filter: SelectManyFilter) -> Result<Vec<JobPosition>> {
+        let mut query_builder = QueryBuilder::new(format!(
+            "SELECT {}",
+            select_columns("job_position", JOB_POSITION_COLUMNS, "")
+        ));
+        Self::push_filter(&mut query_builder, filter);
+
         let query = query_builder.build();
         let rows = query.fetch_all(self.pool_handler.pool()).await?;
 
         let data: Result<Vec<JobPosition>, sqlx::Error> = rows
-            .into_iter()
-            .map(|row| {
-                Ok(JobPosition {
-                    id: row.try_get("id")?,
-                    name: row.try_get("name")?,
-                    description: row.try_get("description")?,
-                    salary: row.try_get("salary")?,
-                    currency: row.try_get("currency")?,
-                    capacity: row.try_get("capacity")?,
-                    instructions_html: row.try_get("instructions_html")?,
-                    is_opened_for_registration: row.try_get("is_opened_for_registration")?,
-                    event_id: row.try_get("event_id")?,
-                    position_category_id: row.try_get("position_category_id")?,
-                })
-            })
+            .iter()
+            .map(|row| job_position_from_row(row, ""))
             .collect();
-
         let data = data?;
         Ok(data)
     }
 
+    async fn list_job_positions_page(
+        &self,
+        filter: SelectManyFilter,
+        spec: &ListSpec<SortColumn>,
+    ) -> Result<Page<JobPosition>> {
+        let mut query_builder = QueryBuilder::new(format!(
+            r#"WITH "list" AS (SELECT {}, "#,
+            select_columns("job_position", JOB_POSITION_COLUMNS, "")
+        ));
+        spec.push_sort_key(&mut query_builder);
+        Self::push_filter(&mut query_builder, filter);
+        spec.push_page(&mut query_builder);
+
+        let query = query_builder.build();
+        let rows = query.fetch_all(self.pool_handler.pool()).await?;
+
+        let total = match rows.first() {
+            Some(row) => row.try_get("total_count")?,
+            None => 0,
+        };
+        let data: Result<Vec<JobPosition>, sqlx::Error> = rows
+            .iter()
+            .map(|row| job_position_from_row(row, ""))
+            .collect();
+        Ok(Page::new(data?, total, spec, |position| position.id))
+    }
+
     async fn get_job_position_by_id(
         &self,
         position_id: i32,
     ) -> Result<JobPosition, RepositoryError> {
-        let job_position = sqlx::query_as!(
-            JobPosition,
-            r#"SELECT
-                "id",
-                "name",
-                "description",
-                "salary",
-                "currency" AS "currency: SalaryCurrency",
-                "capacity",
-                "instructions_html",
-                "is_opened_for_registration",
-                "event_id",
-                "position_category_id"
-            FROM "job_position"
-            WHERE "id" = $1"#,
-            position_id
-        )
-        .fetch_optional(self.pool_handler.pool())
-        .await?;
-
-        if let Some(job_position) = job_position {
-            return Ok(job_position);
-        }
-
-        Err(RepositoryError::NotFound)
+        let mut connection = self.pool_handler.pool().acquire().await?;
+        Self::get_job_position_by_id_in(&mut connection, position_id).await
     }
 
     async fn create_job_position(
         &self,
         new_position: CreateJobPosition,
     ) -> Result<JobPosition, RepositoryError> {
-        Self::check_add(self, new_position.clone()).await?;
+        let mut unit_of_work = UnitOfWork::begin(&self.pool_handler).await?;
+        let job_position = self
+            .create_job_position_in(&mut unit_of_work, new_position)
+            .await?;
+        unit_of_work.commit().await?;
+        Ok(job_position)
+    }
+
+    async fn create_job_position_in(
+        &self,
+        unit_of_work: &mut UnitOfWork,
+        new_position: CreateJobPosition,
+    ) -> Result<JobPosition, RepositoryError> {
+        let connection = unit_of_work.connection()?;
+        Self::check_add(connection, &new_position).await?;
         let job_position = sqlx::query_as!(
             JobPosition,
             r#"INSERT INTO "job_position"
-            ("name", "description", "salary", "capacity", "instructions_html", "is_opened_for_registration", "currency", "event_id", "position_category_id")
-            VALUES ($1, $2, $3, $4, $5, $6, $7::salary_currency, $8, $9)
-            RETURNING "id", "name", "description", "salary", "capacity", "instructions_html", "is_opened_for_registration", "currency" as "currency: SalaryCurrency", "event_id", "position_category_id";"#,
+            ("name", "description", "salary", "capacity", "instructions_html", "instructions_markdown", "is_opened_for_registration", "currency", "event_id", "position_category_id", "registration_opens_at", "registration_closes_at")
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8::salary_currency, $9, $10, $11, $12)
+            RETURNING "id", "name", "description", "salary", "capacity", "instructions_html", "instructions_markdown", "is_opened_for_registration", "currency" as "currency: SalaryCurrency", "event_id", "position_category_id", "registration_opens_at", "registration_closes_at";"#,
             new_position.name,
             new_position.description,
             new_position.salary,
             new_position.capacity,
-            new_position.instructions_html,
+            render_markdown(&new_position.instructions_markdown),
+            new_position.instructions_markdown,
             new_position.is_opened_for_registration,
             new_position.currency as _,
             new_position.event_id,
-            new_position.position_category_id
+            new_position.position_category_id,
+            new_position.registration_opens_at,
+            new_position.registration_closes_at
         )
-            .fetch_one(self.pool_handler.pool())
+            .fetch_one(&mut *connection)
             .await?;
+        self.audit_in(
+            connection,
+            AuditAction::Create,
+            &job_position,
+            None,
+            Some(&job_position),
+        )
+        .await?;
         Ok(job_position)
     }
 
     async fn delete_job_position(&self, position_id: i32) -> Result<(), RepositoryError> {
-        let result = sqlx::query_as!(
-            JobPosition,
-            r#"DELETE FROM "job_position" WHERE "id" = $1"#,
+        let mut transaction = self.pool_handler.pool().begin().await?;
+        let job_position = Self::get_job_position_by_id_in(&mut transaction, position_id).await?;
+        let result = sqlx::query!(
+            r#"UPDATE "job_position" SET "deleted_at" = NOW()
+            WHERE "id" = $1 AND "deleted_at" IS NULL"#,
             position_id
         )
-        .execute(self.pool_handler.pool())
+        .execute(&mut *transaction)
         .await?;
 
         if result.rows_affected() == 0 {
             return Err(RepositoryError::NotFound);
         }
 
+        self.audit_in(
+            &mut transaction,
+            AuditAction::Delete,
+            &job_position,
+            Some(&job_position),
+            None,
+        )
+        .await?;
+        transaction.commit().await?;
         Ok(())
     }
 
@@ -208,16 +465,23 @@ impl JobPositionRepository for PgJobPositionRepository {
         position_id: i32,
         patch_position: PartialJobPosition,
     ) -> Result<JobPosition, RepositoryError> {
-        let job_position = self.get_job_position_by_id(position_id).await?;
+        let mut transaction = self.pool_handler.pool().begin().await?;
+        let job_position = Self::get_job_position_by_id_in(&mut transaction, position_id).await?;
+        let before = job_position.clone();
 
         let name = patch_position.name.unwrap_or(job_position.name);
         let description = patch_position.description.or(job_position.description);
         let salary = patch_position.salary.unwrap_or(job_position.salary);
         let capacity = patch_position.capacity.unwrap_or(job_position.capacity);
         let currency = patch_position.currency.unwrap_or(job_position.currency);
-        let instructions_html = patch_position
-            .instructions_html
-            .unwrap_or(job_position.instructions_html);
+        let (instructions_html, instructions_markdown) = match patch_position.instructions_markdown
+        {
+            Some(markdown) => (render_markdown(&markdown), markdown),
+            None => (
+                job_position.instructions_html,
+                job_position.instructions_markdown,
+            ),
+        };
         let is_opened_for_registration = patch_position
             .is_opened_for_registration
             .or(Some(job_position.is_opened_for_registration));
@@ -225,6 +489,13 @@ impl JobPositionRepository for PgJobPositionRepository {
         let position_category_id = patch_position
             .position_category_id
             .unwrap_or(job_position.position_category_id);
+        let registration_opens_at = patch_position
+            .registration_opens_at
+            .unwrap_or(job_position.registration_opens_at);
+        let registration_closes_at = patch_position
+            .registration_closes_at
+            .unwrap_or(job_position.registration_closes_at);
+        Self::check_registration_window(registration_opens_at, registration_closes_at)?;
 
         let job_position = sqlx::query_as!(
             JobPosition,
@@ -235,10 +506,13 @@ impl JobPositionRepository for PgJobPositionRepository {
                 "capacity" = $5,
                 "currency" = $6::salary_currency,
                 "instructions_html" = $7,
-                "is_opened_for_registration" = $8,
-                "event_id" = $9,
-                "position_category_id" = $10
-            WHERE id = $1
+                "instructions_markdown" = $8,
+                "is_opened_for_registration" = $9,
+                "event_id" = $10,
+                "position_category_id" = $11,
+                "registration_opens_at" = $12,
+                "registration_closes_at" = $13
+            WHERE "id" = $1 AND "deleted_at" IS NULL
             RETURNING 
                 "id", 
                 "name", 
@@ -246,10 +520,13 @@ impl JobPositionRepository for PgJobPositionRepository {
                 "salary", 
                 "capacity", 
                 "instructions_html", 
+                "instructions_markdown", 
                 "is_opened_for_registration", 
                 "currency" as "currency: SalaryCurrency", 
                 "event_id", 
-                "position_category_id""#,
+                "position_category_id",
+                "registration_opens_at",
+                "registration_closes_at""#,
             position_id,
             name,
             description,
@@ -257,14 +534,26 @@ impl JobPositionRepository for PgJobPositionRepository {
             capacity,
             currency as _,
             instructions_html,
+            instructions_markdown,
             is_opened_for_registration,
             event_id,
             position_category_id,
+            registration_opens_at,
+            registration_closes_at,
         )
-        .fetch_optional(self.pool_handler.pool())
+        .fetch_optional(&mut *transaction)
         .await?;
 
         if let Some(job_position) = job_position {
+            self.audit_in(
+                &mut transaction,
+                AuditAction::Update,
+                &job_position,
+                Some(&before),
+                Some(&job_position),
+            )
+            .await?;
+            transaction.commit().await?;
             return Ok(job_position);
         }
         Err(RepositoryError::NotFound)
@@ -286,13 +575,17 @@ impl JobPositionRepository for PgJobPositionRepository {
                 "job_position"."currency" AS "currency: SalaryCurrency",
                 "job_position"."capacity",
                 "job_position"."instructions_html",
+                "job_position"."instructions_markdown",
                 "job_position"."is_opened_for_registration",
                 "job_position"."event_id",
-                "job_position"."position_category_id"
+                "job_position"."position_category_id",
+                "job_position"."registration_opens_at",
+                "job_position"."registration_closes_at"
             FROM "job_position"
             JOIN "employment" ON "employment"."position_id"="job_position"."id"
             JOIN "event" ON "job_position"."event_id"="event"."id"
             WHERE "employment"."user_id"=$1 AND "event"."id"=$2
+                AND "job_position"."deleted_at" IS NULL AND "event"."deleted_at" IS NULL
             ;"#,
             user_id,
             event_id
@@ -302,4 +595,229 @@ impl JobPositionRepository for PgJobPositionRepository {
 
         Ok(jobs)
     }
+
+    async fn list_deleted_job_positions(
+        &self,
+        organization_id: i32,
+        spec: &ListSpec<trash::SortColumn>,
+    ) -> Result<Page<JobPosition>, RepositoryError> {
+        let mut query_builder = QueryBuilder::new(format!(
+            r#"WITH "list" AS (SELECT {}, "#,
+            select_columns("job_position", JOB_POSITION_COLUMNS, "")
+        ));
+        spec.push_sort_key(&mut query_builder);
+        query_builder.push(
+            r#" FROM "job_position"
+            WHERE "deleted_at" IS NOT NULL
+                AND "event_id" IN (SELECT "id" FROM "event" WHERE "organization_id" = "#,
+        );
+        query_builder.push_bind(organization_id);
+        query_builder.push(")");
+        spec.push_page(&mut query_builder);
+
+        let rows = query_builder
+            .build()
+            .fetch_all(self.pool_handler.pool())
+            .await?;
+
+        let total = match rows.first() {
+            Some(row) => row.try_get("total_count")?,
+            None => 0,
+        };
+        let data: Result<Vec<JobPosition>, sqlx::Error> = rows
+            .iter()
+            .map(|row| job_position_from_row(row, ""))
+            .collect();
+        Ok(Page::new(data?, total, spec, |job| job.id))
+    }
+
+    async fn restore_job_position(
+        &self,
+        position_id: i32,
+        organization_id: i32,
+    ) -> Result<JobPosition, RepositoryError> {
+        let mut transaction = self.pool_handler.pool().begin().await?;
+        Self::check_in_organization(&mut transaction, position_id, organization_id).await?;
+        let job_position = sqlx::query_as!(
+            JobPosition,
+            r#"UPDATE "job_position" SET "deleted_at" = NULL
+            WHERE "id" = $1 AND "deleted_at" IS NOT NULL
+            RETURNING
+                "id",
+                "name",
+                "description",
+                "salary",
+                "currency" AS "currency: SalaryCurrency",
+                "capacity",
+                "instructions_html",
+                "instructions_markdown",
+                "is_opened_for_registration",
+                "event_id",
+                "position_category_id",
+                "registration_opens_at",
+                "registration_closes_at""#,
+            position_id
+        )
+        .fetch_optional(&mut *transaction)
+        .await?;
+
+        if let Some(job_position) = job_position {
+            self.audit_in(
+                &mut transaction,
+                AuditAction::Restore,
+                &job_position,
+                None,
+                Some(&job_position),
+            )
+            .await?;
+            transaction.commit().await?;
+            return Ok(job_position);
+        }
+        Err(RepositoryError::NotFound)
+    }
+
+    async fn purge_job_position(
+        &self,
+        position_id: i32,
+        organization_id: i32,
+    ) -> Result<(), RepositoryError> {
+        let mut transaction = self.pool_handler.pool().begin().await?;
+        Self::check_in_organization(&mut transaction, position_id, organization_id).await?;
+        let has_worked_hours = sqlx::query_scalar!(
+            r#"SELECT EXISTS (
+                SELECT 1 FROM "worked_hours"
+                JOIN "employment" ON "employment"."id" = "worked_hours"."employment_id"
+                WHERE "employment"."position_id" = $1
+            ) AS "exists!""#,
+            position_id
+        )
+        .fetch_one(&mut *transaction)
+        .await?;
+
+        if has_worked_hours {
+            return Err(RepositoryError::GenericError(
+                "The job has logged worked hours and cannot be purged".to_string(),
+            ));
+        }
+
+        let job_position = sqlx::query_as!(
+            JobPosition,
+            r#"DELETE FROM "job_position"
+            WHERE "id" = $1 AND "deleted_at" IS NOT NULL
+            RETURNING
+                "id",
+                "name",
+                "description",
+                "salary",
+                "currency" AS "currency: SalaryCurrency",
+                "capacity",
+                "instructions_html",
+                "instructions_markdown",
+                "is_opened_for_registration",
+                "event_id",
+                "position_category_id",
+                "registration_opens_at",
+                "registration_closes_at""#,
+            position_id
+        )
+        .fetch_optional(&mut *transaction)
+        .await?;
+
+        if let Some(job_position) = job_position {
+            self.audit_in(
+                &mut transaction,
+                AuditAction::Purge,
+                &job_position,
+                Some(&job_position),
+                None,
+            )
+            .await?;
+            transaction.commit().await?;
+            return Ok(());
+        }
+        Err(RepositoryError::NotFound)
+    }
+
+    /// Positions are only flipped when a boundary is crossed since the previous run, so manual
+    /// changes made inside the window are kept.
+    async fn apply_registration_windows(
+        &self,
+        now: OffsetDateTime,
+    ) -> Result<Vec<JobPosition>, RepositoryError> {
+        let mut transaction = self.pool_handler.pool().begin().await?;
+        // Boundaries that passed while the application was down are applied on the next run.
+        let since = sqlx::query_scalar!(
+            r#"SELECT "last_run_at" FROM "scheduler_run"
+            WHERE "task" = 'registration_windows'
+            FOR UPDATE"#
+        )
+        .fetch_one(&mut *transaction)
+        .await?;
+
+        let mut flipped = sqlx::query_as!(
+            JobPosition,
+            r#"UPDATE "job_position" SET
+                "is_opened_for_registration" = NOT "job_position"."is_opened_for_registration"
+            FROM "event"
+            WHERE "event"."id" = "job_position"."event_id"
+                AND "job_position"."deleted_at" IS NULL
+                AND "event"."deleted_at" IS NULL
+                AND "event"."status" IN ('draft', 'published')
+                AND (
+                    (NOT "job_position"."is_opened_for_registration"
+                        AND "job_position"."registration_opens_at" > $1
+                        AND "job_position"."registration_opens_at" <= $2
+                        AND ("job_position"."registration_closes_at" IS NULL
+                            OR "job_position"."registration_closes_at" > $2))
+                    OR ("job_position"."is_opened_for_registration"
+                        AND "job_position"."registration_closes_at" > $1
+                        AND "job_position"."registration_closes_at" <= $2)
+                )
+            RETURNING
+                "job_position"."id",
+                "job_position"."name",
+                "job_position"."description",
+                "job_position"."salary",
+                "job_position"."currency" AS "currency: SalaryCurrency",
+                "job_position"."capacity",
+                "job_position"."instructions_html",
+                "job_position"."instructions_markdown",
+                "job_position"."is_opened_for_registration",
+                "job_position"."event_id",
+                "job_position"."position_category_id",
+                "job_position"."registration_opens_at",
+                "job_position"."registration_closes_at""#,
+            since,
+            now
+        )
+        .fetch_all(&mut *transaction)
+        .await?;
+
+        for job_position in &flipped {
+            let before = JobPosition {
+                is_opened_for_registration: !job_position.is_opened_for_registration,
+                ..job_position.clone()
+            };
+            self.audit_in(
+                &mut transaction,
+                AuditAction::Update,
+                job_position,
+                Some(&before),
+                Some(job_position),
+            )
+            .await?;
+        }
+
+        sqlx::query!(
+            r#"UPDATE "scheduler_run" SET "last_run_at" = $1
+            WHERE "task" = 'registration_windows' AND "last_run_at" < $1"#,
+            now
+        )
+        .execute(&mut *transaction)
+        .await?;
+        transaction.commit().await?;
+
+        flipped.sort_by_key(|job_position| job_position.id);
+        Ok(flipped)
+    }
 }
@@ -0,0 +1,523 @@
+//! Repositories keeping their rows in memory, so that handlers can be tested without a
+//! database. They enforce the same rules and constraints as the Postgres repositories.
+
+pub mod attendance;
+pub mod audit_log;
+pub mod employment;
+pub mod event;
+pub mod event_manager_relation;
+pub mod job_position;
+pub mod notification;
+pub mod organization;
+pub mod position_category;
+pub mod read_model;
+pub mod review;
+pub mod user;
+pub mod venue;
+pub mod worked_hours;
+
+use crate::error::RepositoryError;
+use crate::models::attendance::AttendanceRecord;
+use crate::models::audit_log::{AuditAction, AuditEntity, AuditLog};
+use crate::models::employment::{
+    Employment, EmploymentState, EmploymentStateChange, EmploymentWithdrawal,
+};
+use crate::models::event::{Event, ManagedBy};
+use crate::models::event_manager_relation::EventManagerRelation;
+use crate::models::job_position::JobPosition;
+use crate::models::notification::Notification;
+use crate::models::organization::{Organization, OrganizationMembership};
+use crate::models::pagination::{ListSpec, Page, PagePosition, SortDirection};
+use crate::models::position_category::PositionCategory;
+use crate::models::review::Review;
+use crate::models::trash;
+use crate::models::user::{User, UserRole};
+use crate::models::venue::Venue;
+use crate::models::worked_hours::WorkedHours;
+use crate::policy::{is_allowed, EventRelation};
+use crate::repositories::audit_log::snapshot;
+use serde::Serialize;
+use sqlx::types::time::{Date, OffsetDateTime};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+/// Tables shared by the in-memory repositories created from it. Clones share the tables.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryStore {
+    tables: Arc<Mutex<Tables>>,
+}
+
+impl MemoryStore {
+    pub fn new() -> Self {
+        let store = Self::default();
+        store.lock().registration_windows_run_at = Some(OffsetDateTime::now_utc());
+        store
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Tables> {
+        self.tables.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    pub(crate) fn read<R>(&self, query: impl FnOnce(&Tables) -> R) -> R {
+        query(&self.lock())
+    }
+
+    /// Runs `statement` on a copy of the tables, which replaces them only if it succeeds, so
+    /// that a failed statement leaves no partial changes behind like a Postgres transaction.
+    pub(crate) fn write<R>(
+        &self,
+        statement: impl FnOnce(&mut Tables) -> Result<R, RepositoryError>,
+    ) -> Result<R, RepositoryError> {
+        let mut tables = self.lock();
+        let mut changed = tables.clone();
+        let result = statement(&mut changed)?;
+        *tables = changed;
+        Ok(result)
+    }
+}
+
+/// Changes made to a [`MemoryStore`] since a unit of work began, they are undone unless the
+/// unit of work commits. Changes other callers make meanwhile are undone as well, which is
+/// good enough for tests running one request at a time.
+pub(crate) struct MemoryTransaction {
+    store: MemoryStore,
+    snapshot: Option<Tables>,
+}
+
+impl MemoryTransaction {
+    pub(crate) fn begin(store: &MemoryStore) -> Self {
+        Self {
+            store: store.clone(),
+            snapshot: Some(store.lock().clone()),
+        }
+    }
+
+    pub(crate) fn commit(mut self) {
+        self.snapshot = None;
+    }
+}
+
+impl Drop for MemoryTransaction {
+    fn drop(&mut self) {
+        if let Some(snapshot) = self.snapshot.take() {
+            *self.store.lock() = snapshot;
+        }
+    }
+}
+
+/// A row of a table with soft deletes.
+#[derive(Debug, Clone)]
+pub(crate) struct Stored<T> {
+    pub(crate) row: T,
+    pub(crate) deleted_at: Option<OffsetDateTime>,
+}
+
+impl<T> Stored<T> {
+    fn new(row: T) -> Self {
+        Self {
+            row,
+            deleted_at: None,
+        }
+    }
+
+    pub(crate) fn is_deleted(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+}
+
+/// Rows that are not soft deleted.
+pub(crate) fn live<T>(rows: &[Stored<T>]) -> impl Iterator<Item = &T> {
+    rows.iter()
+        .filter(|stored| !stored.is_deleted())
+        .map(|stored| &stored.row)
+}
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Tables {
+    pub(crate) users: Vec<Stored<User>>,
+    pub(crate) organizations: Vec<Organization>,
+    pub(crate) memberships: Vec<OrganizationMembership>,
+    pub(crate) venues: Vec<Stored<Venue>>,
+    pub(crate) events: Vec<Stored<Event>>,
+    pub(crate) position_categories: Vec<PositionCategory>,
+    pub(crate) job_positions: Vec<Stored<JobPosition>>,
+    pub(crate) employments: Vec<Employment>,
+    /// Entries are stored without `changed_by_name`, it is filled in when read.
+    pub(crate) employment_history: Vec<EmploymentStateChange>,
+    pub(crate) withdrawals: Vec<EmploymentWithdrawal>,
+    pub(crate) worked_hours: Vec<WorkedHours>,
+    pub(crate) event_managers: Vec<EventManagerRelation>,
+    pub(crate) audit_logs: Vec<AuditLog>,
+    pub(crate) attendance: Vec<AttendanceRecord>,
+    pub(crate) reviews: Vec<Review>,
+    pub(crate) notifications: Vec<Notification>,
+    /// Last run of the registration windows task, the `scheduler_run` row of it.
+    pub(crate) registration_windows_run_at: Option<OffsetDateTime>,
+    sequences: HashMap<&'static str, i32>,
+}
+
+impl Tables {
+    /// Next value of the id sequence of `table`, starting from 1 like `SERIAL` columns.
+    pub(crate) fn next_id(&mut self, table: &'static str) -> i32 {
+        let id = self.sequences.entry(table).or_insert(0);
+        *id += 1;
+        *id
+    }
+
+    pub(crate) fn user(&self, user_id: i32) -> Result<&User, RepositoryError> {
+        live(&self.users)
+            .find(|user| user.id == user_id)
+            .ok_or(RepositoryError::NotFound)
+    }
+
+    pub(crate) fn venue(&self, venue_id: i32) -> Result<&Venue, RepositoryError> {
+        live(&self.venues)
+            .find(|venue| venue.id == venue_id)
+            .ok_or(RepositoryError::NotFound)
+    }
+
+    pub(crate) fn event(&self, event_id: i32) -> Result<&Event, RepositoryError> {
+        live(&self.events)
+            .find(|event| event.id == event_id)
+            .ok_or(RepositoryError::NotFound)
+    }
+
+    pub(crate) fn job_position(&self, position_id: i32) -> Result<&JobPosition, RepositoryError> {
+        live(&self.job_positions)
+            .find(|position| position.id == position_id)
+            .ok_or(RepositoryError::NotFound)
+    }
+
+    pub(crate) fn employment(&self, employment_id: i32) -> Result<&Employment, RepositoryError> {
+        self.employments
+            .iter()
+            .find(|employment| employment.id == employment_id)
+            .ok_or(RepositoryError::NotFound)
+    }
+
+    /// Any row of the table, soft deleted ones included, like a foreign key sees them.
+    pub(crate) fn any_user(&self, user_id: i32) -> Option<&User> {
+        any(&self.users, |user| user.id == user_id)
+    }
+
+    pub(crate) fn any_event(&self, event_id: i32) -> Option<&Event> {
+        any(&self.events, |event| event.id == event_id)
+    }
+
+    pub(crate) fn any_job_position(&self, position_id: i32) -> Option<&JobPosition> {
+        any(&self.job_positions, |position| position.id == position_id)
+    }
+
+    pub(crate) fn any_venue(&self, venue_id: i32) -> Option<&Venue> {
+        any(&self.venues, |venue| venue.id == venue_id)
+    }
+
+    /// Event the job position belongs to, soft deleted rows included.
+    pub(crate) fn position_event(&self, position_id: i32) -> Option<&Event> {
+        self.any_job_position(position_id)
+            .and_then(|position| self.any_event(position.event_id))
+    }
+
+    /// Event of the employment's job position, soft deleted rows included.
+    pub(crate) fn employment_event(&self, employment_id: i32) -> Option<&Event> {
+        self.employment(employment_id)
+            .ok()
+            .and_then(|employment| self.position_event(employment.position_id))
+    }
+
+    pub(crate) fn is_member(&self, organization_id: i32, user_id: i32) -> bool {
+        self.memberships.iter().any(|membership| {
+            membership.organization_id == organization_id && membership.user_id == user_id
+        })
+    }
+
+    /// Whether the event meets the condition of [`ManagedBy`].
+    pub(crate) fn is_managed_by(&self, event_id: i32, managed_by: &ManagedBy) -> bool {
+        let owns = self
+            .any_event(event_id)
+            .is_some_and(|event| event.owner_id == managed_by.user_id);
+        owns || self.event_managers.iter().any(|relation| {
+            relation.event_id == event_id
+                && relation.user_id == managed_by.user_id
+                && (managed_by.actions.is_empty()
+                    || managed_by.actions.iter().any(|action| {
+                        is_allowed(
+                            &UserRole::Organizer,
+                            EventRelation::Manager(relation.permissions()),
+                            *action,
+                        )
+                    }))
+        })
+    }
+
+    /// Accepted and finished workers of the job position.
+    pub(crate) fn taken_capacity(&self, position_id: i32) -> i64 {
+        self.employments
+            .iter()
+            .filter(|employment| {
+                employment.position_id == position_id
+                    && matches!(
+                        employment.state,
+                        EmploymentState::Accepted | EmploymentState::Done
+                    )
+            })
+            .count() as i64
+    }
+
+    /// Appends an entry to the employment's state timeline.
+    pub(crate) fn record_state_change(
+        &mut self,
+        employment_id: i32,
+        from_state: Option<EmploymentState>,
+        to_state: EmploymentState,
+        changed_by: Option<i32>,
+    ) {
+        let change = EmploymentStateChange {
+            id: self.next_id("employment_state_history"),
+            employment_id,
+            from_state,
+            to_state,
+            changed_by,
+            changed_by_name: None,
+            changed_at: OffsetDateTime::now_utc(),
+        };
+        self.employment_history.push(change);
+    }
+
+    pub(crate) fn notify(&mut self, user_id: i32, message: String) -> Notification {
+        let notification = Notification {
+            id: self.next_id("notification"),
+            user_id,
+            message,
+            created_at: OffsetDateTime::now_utc(),
+            read_at: None,
+        };
+        self.notifications.push(notification.clone());
+        notification
+    }
+
+    /// Deletes the user together with the rows referencing it, following the `ON DELETE`
+    /// rules of the schema.
+    pub(crate) fn remove_user(&mut self, user_id: i32) {
+        self.users.retain(|stored| stored.row.id != user_id);
+        let events: Vec<i32> = self
+            .events
+            .iter()
+            .filter(|stored| stored.row.owner_id == user_id)
+            .map(|stored| stored.row.id)
+            .collect();
+        for event_id in events {
+            self.remove_event(event_id);
+        }
+        let employments: Vec<i32> = self
+            .employments
+            .iter()
+            .filter(|employment| employment.user_id == user_id)
+            .map(|employment| employment.id)
+            .collect();
+        for employment_id in employments {
+            self.remove_employment(employment_id);
+        }
+        self.event_managers
+            .retain(|relation| relation.user_id != user_id);
+        self.memberships
+            .retain(|membership| membership.user_id != user_id);
+        self.notifications
+            .retain(|notification| notification.user_id != user_id);
+        self.withdrawals
+            .retain(|withdrawal| withdrawal.user_id != user_id);
+        self.reviews.retain(|review| review.subject_id != user_id);
+        for change in &mut self.employment_history {
+            change.changed_by = change.changed_by.filter(|id| *id != user_id);
+        }
+        for record in &mut self.attendance {
+            record.recorded_by = record.recorded_by.filter(|id| *id != user_id);
+        }
+        for review in &mut self.reviews {
+            review.author_id = review.author_id.filter(|id| *id != user_id);
+            review.flagged_by = review.flagged_by.filter(|id| *id != user_id);
+        }
+    }
+
+    /// Deletes the event together with the rows referencing it.
+    pub(crate) fn remove_event(&mut self, event_id: i32) {
+        self.events.retain(|stored| stored.row.id != event_id);
+        let positions: Vec<i32> = self
+            .job_positions
+            .iter()
+            .filter(|stored| stored.row.event_id == event_id)
+            .map(|stored| stored.row.id)
+            .collect();
+        for position_id in positions {
+            self.remove_job_position(position_id);
+        }
+        self.event_managers
+            .retain(|relation| relation.event_id != event_id);
+        self.reviews.retain(|review| review.event_id != event_id);
+    }
+
+    /// Deletes the job position together with the rows referencing it.
+    pub(crate) fn remove_job_position(&mut self, position_id: i32) {
+        self.job_positions
+            .retain(|stored| stored.row.id != position_id);
+        let employments: Vec<i32> = self
+            .employments
+            .iter()
+            .filter(|employment| employment.position_id == position_id)
+            .map(|employment| employment.id)
+            .collect();
+        for employment_id in employments {
+            self.remove_employment(employment_id);
+        }
+    }
+
+    /// Deletes the employment together with the rows referencing it.
+    pub(crate) fn remove_employment(&mut self, employment_id: i32) {
+        self.employments
+            .retain(|employment| employment.id != employment_id);
+        self.worked_hours
+            .retain(|hours| hours.employment_id != employment_id);
+        self.withdrawals
+            .retain(|withdrawal| withdrawal.employment_id != employment_id);
+        self.employment_history
+            .retain(|change| change.employment_id != employment_id);
+        self.attendance
+            .retain(|record| record.employment_id != employment_id);
+        self.reviews
+            .retain(|review| review.employment_id != employment_id);
+    }
+
+    /// Appends an audit log entry like [`PgAuditLogRepository::record_in`] does.
+    ///
+    /// [`PgAuditLogRepository::record_in`]: crate::repositories::audit_log::PgAuditLogRepository::record_in
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn audit<T: Serialize>(
+        &mut self,
+        actor_id: Option<i32>,
+        entity: AuditEntity,
+        entity_id: i32,
+        event_id: Option<i32>,
+        action: AuditAction,
+        before: Option<&T>,
+        after: Option<&T>,
+    ) -> Result<(), RepositoryError> {
+        let log = AuditLog {
+            id: self.next_id("audit_log"),
+            actor_id,
+            entity,
+            entity_id,
+            event_id,
+            action,
+            before: before.map(snapshot).transpose()?,
+            after: after.map(snapshot).transpose()?,
+            created_at: OffsetDateTime::now_utc(),
+        };
+        self.audit_logs.push(log);
+        Ok(())
+    }
+}
+
+fn any<T>(rows: &[Stored<T>], predicate: impl Fn(&T) -> bool) -> Option<&T> {
+    rows.iter()
+        .map(|stored| &stored.row)
+        .find(|row| predicate(row))
+}
+
+/// Value a list is sorted by, the in-memory counterpart of [`SortColumn::sql`].
+///
+/// [`SortColumn::sql`]: crate::models::pagination::SortColumn::sql
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub(crate) enum SortKey {
+    Number(f64),
+    Text(String),
+    Date(Date),
+    Timestamp(OffsetDateTime),
+}
+
+/// Sorts `rows` the way [`ListSpec::push_order`] does, ties broken by id, and cuts out the
+/// page of them `spec` asks for.
+pub(crate) fn page<T, C>(
+    mut rows: Vec<T>,
+    spec: &ListSpec<C>,
+    id_of: impl Fn(&T) -> i32,
+    sort_key: impl Fn(&T) -> SortKey,
+) -> Page<T> {
+    rows.sort_by(|a, b| {
+        let ordering = sort_key(a)
+            .partial_cmp(&sort_key(b))
+            .unwrap_or(Ordering::Equal)
+            .then(id_of(a).cmp(&id_of(b)));
+        match spec.sort_direction {
+            SortDirection::Asc => ordering,
+            SortDirection::Desc => ordering.reverse(),
+        }
+    });
+    let total = rows.len() as i64;
+    let start = match spec.position {
+        PagePosition::Page(page) => ((page - 1) * spec.page_size).max(0) as usize,
+        // Like the row comparison in SQL, an unknown cursor matches no rows.
+        PagePosition::After(cursor) => rows
+            .iter()
+            .position(|row| id_of(row) == cursor)
+            .map_or(rows.len(), |index| index + 1),
+    };
+    let items = rows
+        .into_iter()
+        .skip(start)
+        .take(spec.page_size as usize)
+        .collect();
+    Page::new(items, total, spec, id_of)
+}
+
+/// Page of the soft-deleted `rows` that `keep` accepts, in the order of the trash.
+pub(crate) fn deleted_page<T: Clone>(
+    rows: &[Stored<T>],
+    spec: &ListSpec<trash::SortColumn>,
+    id_of: impl Fn(&T) -> i32,
+    keep: impl Fn(&T) -> bool,
+) -> Page<T> {
+    let deleted = rows
+        .iter()
+        .filter(|stored| stored.is_deleted() && keep(&stored.row))
+        .cloned()
+        .collect();
+    let page = page(
+        deleted,
+        spec,
+        |stored: &Stored<T>| id_of(&stored.row),
+        |stored| SortKey::Timestamp(stored.deleted_at.unwrap_or(OffsetDateTime::UNIX_EPOCH)),
+    );
+    Page {
+        items: page.items.into_iter().map(|stored| stored.row).collect(),
+        total: page.total,
+        position: page.position,
+        page_size: page.page_size,
+        next_cursor: page.next_cursor,
+    }
+}
+
+/// Case-insensitive substring match, the in-memory counterpart of [`push_contains`].
+///
+/// [`push_contains`]: crate::models::pagination::push_contains
+pub(crate) fn contains(value: &str, pattern: &str) -> bool {
+    value.to_lowercase().contains(&pattern.to_lowercase())
+}
+
+/// Error Postgres reports when an insert or update breaks the unique `constraint`.
+pub(crate) fn unique_violation(constraint: &str) -> RepositoryError {
+    RepositoryError::Conflict(format!(
+        "duplicate key value violates unique constraint \"{constraint}\""
+    ))
+}
+
+/// Error Postgres reports when a row refers to a missing one through `constraint`.
+pub(crate) fn foreign_key_violation(constraint: &str) -> RepositoryError {
+    RepositoryError::ConnectionError(format!(
+        "insert or update violates foreign key constraint \"{constraint}\""
+    ))
+}
+
+pub(crate) fn generic_error(message: impl Into<String>) -> RepositoryError {
+    RepositoryError::GenericError(message.into())
+}
@@ -0,0 +1,169 @@
+use crate::error::RepositoryError;
+use crate::models::attendance::{
+    AttendanceOutcome, AttendanceRecord, AttendanceSummary, RecordAttendance,
+};
+use crate::models::employment::EmploymentState;
+use crate::repositories::attendance::AttendanceRepository;
+use crate::repositories::memory::{generic_error, MemoryStore, Tables};
+use anyhow::Result;
+use async_trait::async_trait;
+use sqlx::types::time::{Date, OffsetDateTime};
+use std::sync::Arc;
+
+#[derive(Debug, Clone)]
+pub struct MemoryAttendanceRepository {
+    pub store: MemoryStore,
+    pub actor_id: Option<i32>,
+}
+
+impl MemoryAttendanceRepository {
+    pub fn new(store: MemoryStore) -> Self {
+        Self {
+            store,
+            actor_id: None,
+        }
+    }
+
+    /// Recorded outcomes of the user's jobs, of one organization if given.
+    fn records_of(&self, user_id: i32, organization_id: Option<i32>) -> Vec<AttendanceRecord> {
+        self.store.read(|tables| {
+            tables
+                .attendance
+                .iter()
+                .filter(|record| is_of(tables, record, user_id, organization_id))
+                .cloned()
+                .collect()
+        })
+    }
+}
+
+fn is_of(
+    tables: &Tables,
+    record: &AttendanceRecord,
+    user_id: i32,
+    organization_id: Option<i32>,
+) -> bool {
+    tables
+        .employment(record.employment_id)
+        .is_ok_and(|employment| employment.user_id == user_id)
+        && tables
+            .employment_event(record.employment_id)
+            .is_some_and(|event| organization_id.is_none_or(|id| event.organization_id == id))
+}
+
+#[async_trait]
+impl AttendanceRepository for MemoryAttendanceRepository {
+    fn with_actor(&self, actor_id: i32) -> Arc<dyn AttendanceRepository> {
+        Arc::new(Self {
+            store: self.store.clone(),
+            actor_id: Some(actor_id),
+        })
+    }
+
+    async fn record_attendance(
+        &self,
+        record: RecordAttendance,
+    ) -> Result<AttendanceRecord, RepositoryError> {
+        self.store.write(|tables| {
+            let employment = tables.employment(record.employment_id)?;
+            let event = tables
+                .employment_event(record.employment_id)
+                .ok_or(RepositoryError::NotFound)?;
+
+            if !matches!(
+                employment.state,
+                EmploymentState::Accepted | EmploymentState::Done
+            ) {
+                return Err(generic_error(
+                    "Attendance can only be recorded for accepted or finished jobs",
+                ));
+            }
+            if record.date < event.date_start || record.date > event.date_end {
+                return Err(generic_error(
+                    "Attendance date is outside of the event's dates",
+                ));
+            }
+            if record.date > OffsetDateTime::now_utc().date() {
+                return Err(generic_error(
+                    "Attendance cannot be recorded for future days",
+                ));
+            }
+
+            let note = record
+                .note
+                .map(|note| note.trim().to_string())
+                .filter(|note| !note.is_empty());
+            let now = OffsetDateTime::now_utc();
+            if let Some(existing) = tables.attendance.iter_mut().find(|existing| {
+                existing.employment_id == record.employment_id && existing.date == record.date
+            }) {
+                existing.outcome = record.outcome;
+                existing.note = note;
+                existing.recorded_by = self.actor_id;
+                existing.created_at = now;
+                return Ok(existing.clone());
+            }
+
+            let attendance = AttendanceRecord {
+                id: tables.next_id("attendance_record"),
+                employment_id: record.employment_id,
+                date: record.date,
+                outcome: record.outcome,
+                note,
+                recorded_by: self.actor_id,
+                created_at: now,
+            };
+            tables.attendance.push(attendance.clone());
+            Ok(attendance)
+        })
+    }
+
+    async fn list_attendance(
+        &self,
+        employment_ids: &[i32],
+    ) -> Result<Vec<AttendanceRecord>, RepositoryError> {
+        let mut records: Vec<AttendanceRecord> = self.store.read(|tables| {
+            tables
+                .attendance
+                .iter()
+                .filter(|record| employment_ids.contains(&record.employment_id))
+                .cloned()
+                .collect()
+        });
+        records.sort_by(|a, b| a.date.cmp(&b.date).then(a.id.cmp(&b.id)));
+        Ok(records)
+    }
+
+    async fn attendance_summary(
+        &self,
+        user_id: i32,
+        organization_id: Option<i32>,
+    ) -> Result<AttendanceSummary, RepositoryError> {
+        let records = self.records_of(user_id, organization_id);
+        let count = |outcome: AttendanceOutcome| {
+            records
+                .iter()
+                .filter(|record| record.outcome == outcome)
+                .count() as i64
+        };
+        Ok(AttendanceSummary {
+            attended: count(AttendanceOutcome::Attended),
+            late: count(AttendanceOutcome::Late),
+            no_shows: count(AttendanceOutcome::NoShow),
+            left_early: count(AttendanceOutcome::LeftEarly),
+        })
+    }
+
+    async fn count_no_shows_since(
+        &self,
+        user_id: i32,
+        organization_id: Option<i32>,
+        since: Date,
+    ) -> Result<i64, RepositoryError> {
+        Ok(self
+            .records_of(user_id, organization_id)
+            .iter()
+            .filter(|record| record.outcome == AttendanceOutcome::NoShow && record.date >= since)
+            .count() as i64)
+    }
+}
@@ -0,0 +1,79 @@
+use crate::models::audit_log::{AuditLog, SelectManyFilter, SortColumn};
+use crate::models::pagination::{ListSpec, Page};
+use crate::repositories::audit_log::AuditLogRepository;
+use crate::repositories::memory::{page, MemoryStore, SortKey};
+use anyhow::Result;
+use async_trait::async_trait;
+
+#[derive(Debug, Clone)]
+pub struct MemoryAuditLogRepository {
+    pub store: MemoryStore,
+}
+
+impl MemoryAuditLogRepository {
+    pub fn new(store: MemoryStore) -> Self {
+        Self { store }
+    }
+}
+
+fn matches(log: &AuditLog, filter: &SelectManyFilter) -> bool {
+    let date = log.created_at.date();
+    filter
+        .actor_id
+        .is_none_or(|actor_id| log.actor_id == Some(actor_id))
+        && filter
+            .entity
+            .as_ref()
+            .is_none_or(|entity| &log.entity == entity)
+        && filter
+            .entity_id
+            .is_none_or(|entity_id| log.entity_id == entity_id)
+        && filter
+            .event_id
+            .is_none_or(|event_id| log.event_id == Some(event_id))
+        && filter
+            .action
+            .as_ref()
+            .is_none_or(|action| &log.action == action)
+        && filter.date_from.is_none_or(|date_from| date >= date_from)
+        && filter.date_to.is_none_or(|date_to| date <= date_to)
+}
+
+#[async_trait]
+impl AuditLogRepository for MemoryAuditLogRepository {
+    async fn list_audit_logs(&self, filter: SelectManyFilter) -> Result<Vec<AuditLog>> {
+        let mut logs: Vec<AuditLog> = self.store.read(|tables| {
+            tables
+                .audit_logs
+                .iter()
+                .filter(|log| matches(log, &filter))
+                .cloned()
+                .collect()
+        });
+        logs.sort_by(|a, b| b.created_at.cmp(&a.created_at).then(b.id.cmp(&a.id)));
+        Ok(logs)
+    }
+
+    async fn list_audit_logs_page(
+        &self,
+        filter: SelectManyFilter,
+        spec: &ListSpec<SortColumn>,
+    ) -> Result<Page<AuditLog>> {
+        let logs: Vec<AuditLog> = self.store.read(|tables| {
+            tables
+                .audit_logs
+                .iter()
+                .filter(|log| matches(log, &filter))
+                .cloned()
+                .collect()
+        });
+        Ok(page(
+            logs,
+            spec,
+            |log| log.id,
+            |log| match spec.sort_by {
+                SortColumn::CreatedAt => SortKey::Timestamp(log.created_at),
+            },
+        ))
+    }
+}
@@ -0,0 +1,823 @@
+use crate::error::RepositoryError;
+use crate::models::attendance::AttendanceOutcome;
+use crate::models::audit_log::{AuditAction, AuditEntity};
+use crate::models::employment::{
+    BulkOrder, BulkUpdateResult, CreateEmployment, Employment, EmploymentState,
+    EmploymentStateChange, EmploymentWithdrawal, JobStats, JobsFilter, PartialEmployment,
+    SelectManyFilter, SkippedEmployment, SortColumn, WithdrawalStats,
+};
+use crate::models::event::{Event, EventStatus};
+use crate::models::job_position::JobPosition;
+use crate::models::pagination::{ListSpec, Page};
+use crate::models::staffing::CandidateStats;
+use crate::models::user::UserRole;
+use crate::repositories::employment::EmploymentRepository;
+use crate::repositories::memory::{
+    contains, foreign_key_violation, generic_error, page, MemoryStore, SortKey, Tables,
+};
+use crate::repositories::unit_of_work::UnitOfWork;
+use anyhow::Result;
+use async_trait::async_trait;
+use sqlx::types::time::OffsetDateTime;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+#[derive(Debug, Clone)]
+pub struct MemoryEmploymentRepository {
+    pub store: MemoryStore,
+    pub actor_id: Option<i32>,
+}
+
+impl MemoryEmploymentRepository {
+    pub fn new(store: MemoryStore) -> Self {
+        Self {
+            store,
+            actor_id: None,
+        }
+    }
+
+    fn list(&self, filter: SelectManyFilter) -> Vec<Employment> {
+        self.store.read(|tables| {
+            tables
+                .employments
+                .iter()
+                .filter(|employment| matches(tables, employment, &filter))
+                .cloned()
+                .collect()
+        })
+    }
+
+    fn audit(
+        &self,
+        tables: &mut Tables,
+        action: AuditAction,
+        employment: &Employment,
+        before: Option<&Employment>,
+        after: Option<&Employment>,
+    ) -> Result<(), RepositoryError> {
+        let event_id = tables
+            .any_job_position(employment.position_id)
+            .map(|position| position.event_id);
+        tables.audit(
+            self.actor_id,
+            AuditEntity::Employment,
+            employment.id,
+            event_id,
+            action,
+            before,
+            after,
+        )
+    }
+
+    /// Stores the new state of the employment, auditing it and recording the state change.
+    fn save(
+        &self,
+        tables: &mut Tables,
+        before: &Employment,
+        employment: Employment,
+    ) -> Result<Employment, RepositoryError> {
+        if let Some(stored) = tables
+            .employments
+            .iter_mut()
+            .find(|stored| stored.id == employment.id)
+        {
+            *stored = employment.clone();
+        }
+        self.audit(
+            tables,
+            AuditAction::Update,
+            &employment,
+            Some(before),
+            Some(&employment),
+        )?;
+        if employment.state != before.state {
+            tables.record_state_change(
+                employment.id,
+                Some(before.state.clone()),
+                employment.state.clone(),
+                self.actor_id,
+            );
+        }
+        Ok(employment)
+    }
+
+    /// [`EmploymentRepository::update_employment`] within a write to the store.
+    fn update(
+        &self,
+        tables: &mut Tables,
+        employment_id: i32,
+        patch_employment: PartialEmployment,
+    ) -> Result<Employment, RepositoryError> {
+        let before = tables.employment(employment_id)?.clone();
+
+        if let Some(state) = &patch_employment.state {
+            if *state != before.state && !before.state.can_transition_to(state) {
+                return Err(generic_error(format!(
+                    "An employment cannot move from {} to {}",
+                    before.state, state
+                )));
+            }
+            if *state == EmploymentState::Accepted && before.state != EmploymentState::Accepted {
+                check_capacity(
+                    tables,
+                    patch_employment.position_id.unwrap_or(before.position_id),
+                )?;
+            }
+        }
+
+        let employment = Employment {
+            rating: patch_employment.rating.unwrap_or(before.rating),
+            state: patch_employment.state.unwrap_or(before.state.clone()),
+            user_id: patch_employment.user_id.unwrap_or(before.user_id),
+            position_id: patch_employment.position_id.unwrap_or(before.position_id),
+            ..before.clone()
+        };
+        self.save(tables, &before, employment)
+    }
+}
+
+pub(crate) fn matches(tables: &Tables, employment: &Employment, filter: &SelectManyFilter) -> bool {
+    let (Ok(user), Ok(position)) = (
+        tables.user(employment.user_id),
+        tables.job_position(employment.position_id),
+    ) else {
+        return false;
+    };
+    let Ok(event) = tables.event(position.event_id) else {
+        return false;
+    };
+    filter
+        .organization_id
+        .is_none_or(|organization_id| event.organization_id == organization_id)
+        && filter
+            .rating
+            .is_none_or(|rating| employment.rating == rating)
+        && filter
+            .user_id
+            .is_none_or(|user_id| employment.user_id == user_id)
+        && filter
+            .state
+            .as_ref()
+            .is_none_or(|state| &employment.state == state)
+        && filter
+            .position_id
+            .is_none_or(|position_id| employment.position_id == position_id)
+        && filter
+            .event_name_contains
+            .as_ref()
+            .is_none_or(|name| contains(&event.name, name))
+        && filter
+            .job_name_contains
+            .as_ref()
+            .is_none_or(|name| contains(&position.name, name))
+        && filter
+            .employee_name_contains
+            .as_ref()
+            .is_none_or(|name| contains(&format!("{} {}", user.first_name, user.last_name), name))
+        && filter
+            .managed_by
+            .as_ref()
+            .is_none_or(|managed_by| tables.is_managed_by(event.id, managed_by))
+}
+
+/// Whether the job passes `filter`, the in-memory counterpart of
+/// [`PgEmploymentRepository::push_jobs_filter`](crate::repositories::employment::PgEmploymentRepository::push_jobs_filter).
+pub(crate) fn matches_jobs(tables: &Tables, employment: &Employment, filter: &JobsFilter) -> bool {
+    let Ok(position) = tables.job_position(employment.position_id) else {
+        return false;
+    };
+    let Ok(event) = tables.event(position.event_id) else {
+        return false;
+    };
+    let Some(venue) = tables.any_venue(event.venue_id) else {
+        return false;
+    };
+    event.organization_id == filter.organization_id
+        && employment.user_id == filter.user_id
+        && filter
+            .state
+            .as_ref()
+            .is_none_or(|state| &employment.state == state)
+        && filter
+            .starts_from
+            .is_none_or(|starts_from| event.date_start >= starts_from)
+        && filter
+            .ends_until
+            .is_none_or(|ends_until| event.date_end <= ends_until)
+        && filter
+            .event_name_contains
+            .as_ref()
+            .is_none_or(|name| contains(&event.name, name))
+        && filter
+            .venue_name_contains
+            .as_ref()
+            .is_none_or(|name| contains(&venue.name, name))
+        && filter
+            .job_name_contains
+            .as_ref()
+            .is_none_or(|name| contains(&position.name, name))
+}
+
+pub(crate) fn hours_worked(tables: &Tables, employment_id: i32) -> f32 {
+    tables
+        .worked_hours
+        .iter()
+        .filter(|worked| worked.employment_id == employment_id)
+        .map(|worked| worked.hours_worked)
+        .sum()
+}
+
+/// Position of the state in the `employment_state` enum of the database, which is what
+/// Postgres sorts by.
+pub(crate) fn state_order(state: &EmploymentState) -> f64 {
+    match state {
+        EmploymentState::Pending => 0.0,
+        EmploymentState::Accepted => 1.0,
+        EmploymentState::Rejected => 2.0,
+        EmploymentState::Done => 3.0,
+        EmploymentState::Cancelled => 4.0,
+        EmploymentState::Invited => 5.0,
+        EmploymentState::Declined => 6.0,
+        EmploymentState::Expired => 7.0,
+        EmploymentState::Withdrawn => 8.0,
+    }
+}
+
+pub(crate) fn sort_key(tables: &Tables, employment: &Employment, sort_by: SortColumn) -> SortKey {
+    let position = tables.any_job_position(employment.position_id);
+    match sort_by {
+        SortColumn::State => SortKey::Number(state_order(&employment.state)),
+        SortColumn::JobName => SortKey::Text(
+            position
+                .map(|position| position.name.clone())
+                .unwrap_or_default(),
+        ),
+        SortColumn::EmployeeName => SortKey::Text(
+            tables
+                .any_user(employment.user_id)
+                .map(|user| format!("{} {}", user.first_name, user.last_name))
+                .unwrap_or_default(),
+        ),
+        SortColumn::Event => SortKey::Text(
+            tables
+                .position_event(employment.position_id)
+                .map(|event| event.name.clone())
+                .unwrap_or_default(),
+        ),
+        SortColumn::Capacity => {
+            SortKey::Number(tables.taken_capacity(employment.position_id) as f64)
+        }
+        SortColumn::Id => SortKey::Number(employment.id as f64),
+    }
+}
+
+/// Fails if the job position has no free place left.
+fn check_capacity(tables: &Tables, position_id: i32) -> Result<(), RepositoryError> {
+    let capacity = tables
+        .any_job_position(position_id)
+        .ok_or(RepositoryError::NotFound)?
+        .capacity;
+    if tables.taken_capacity(position_id) as i32 >= capacity {
+        return Err(generic_error("Job position is already full"));
+    }
+    Ok(())
+}
+
+fn check_add(tables: &Tables, new_employment: &CreateEmployment) -> Result<(), RepositoryError> {
+    let job_position = tables.job_position(new_employment.position_id)?;
+    let event = tables.event(job_position.event_id)?;
+
+    if event.date_end < OffsetDateTime::now_utc().date() {
+        return Err(generic_error("The event has already ended"));
+    }
+    if event.is_cancelled() {
+        return Err(generic_error("The event has been cancelled"));
+    }
+    if matches!(
+        event.status,
+        EventStatus::RegistrationClosed | EventStatus::Completed | EventStatus::Archived
+    ) {
+        return Err(generic_error("Registration for the event is closed"));
+    }
+    if new_employment.state == EmploymentState::Pending
+        && !job_position.is_registration_open_at(OffsetDateTime::now_utc())
+    {
+        return Err(generic_error(
+            "The job position is not opened for registration",
+        ));
+    }
+    if tables.employments.iter().any(|employment| {
+        employment.user_id == new_employment.user_id
+            && employment.position_id == new_employment.position_id
+    }) {
+        return Err(generic_error("The event has already been registered"));
+    }
+
+    check_capacity(tables, new_employment.position_id)
+}
+
+#[async_trait]
+impl EmploymentRepository for MemoryEmploymentRepository {
+    fn with_actor(&self, actor_id: i32) -> Arc<dyn EmploymentRepository> {
+        Arc::new(Self {
+            store: self.store.clone(),
+            actor_id: Some(actor_id),
+        })
+    }
+
+    async fn list_employment(&self, filter: SelectManyFilter) -> Result<Vec<Employment>> {
+        Ok(self.list(filter))
+    }
+
+    async fn list_employment_page(
+        &self,
+        filter: SelectManyFilter,
+        spec: &ListSpec<SortColumn>,
+    ) -> Result<Page<Employment>> {
+        let employments = self.list(filter);
+        Ok(self.store.read(|tables| {
+            page(
+                employments,
+                spec,
+                |employment| employment.id,
+                |employment| sort_key(tables, employment, spec.sort_by),
+            )
+        }))
+    }
+
+    async fn job_stats(&self, filter: JobsFilter) -> Result<JobStats> {
+        Ok(self.store.read(|tables| {
+            let jobs: Vec<(&Employment, &JobPosition, &Event)> = tables
+                .employments
+                .iter()
+                .filter(|employment| matches_jobs(tables, employment, &filter))
+                .filter_map(|employment| {
+                    let position = tables.any_job_position(employment.position_id)?;
+                    Some((employment, position, tables.any_event(position.event_id)?))
+                })
+                .collect();
+            let ratings: Vec<i32> = jobs
+                .iter()
+                .map(|(employment, _, _)| employment.rating)
+                .filter(|rating| *rating != 0)
+                .collect();
+            JobStats {
+                total_jobs: jobs.len() as i64,
+                unique_events: jobs
+                    .iter()
+                    .map(|(_, _, event)| event.id)
+                    .collect::<HashSet<_>>()
+                    .len() as i64,
+                unique_venues: jobs
+                    .iter()
+                    .map(|(_, _, event)| event.venue_id)
+                    .collect::<HashSet<_>>()
+                    .len() as i64,
+                hours_worked: jobs
+                    .iter()
+                    .map(|(employment, _, _)| hours_worked(tables, employment.id))
+                    .sum(),
+                salary: jobs
+                    .iter()
+                    .map(|(employment, position, _)| {
+                        hours_worked(tables, employment.id) * position.salary
+                    })
+                    .sum(),
+                earliest_date: jobs.iter().map(|(_, _, event)| event.date_start).min(),
+                latest_date: jobs.iter().map(|(_, _, event)| event.date_end).max(),
+                average_rating: (!ratings.is_empty())
+                    .then(|| ratings.iter().sum::<i32>() as f32 / ratings.len() as f32),
+            }
+        }))
+    }
+
+    async fn get_employment_by_id(
+        &self,
+        employment_id: i32,
+    ) -> Result<Employment, RepositoryError> {
+        self.store
+            .read(|tables| tables.employment(employment_id).cloned())
+    }
+
+    async fn create_employment(
+        &self,
+        new_employment: CreateEmployment,
+    ) -> Result<Employment, RepositoryError> {
+        self.store.write(|tables| {
+            check_add(tables, &new_employment)?;
+            if tables.any_user(new_employment.user_id).is_none() {
+                return Err(foreign_key_violation("employment_user_id_fkey"));
+            }
+
+            let employment = Employment {
+                id: tables.next_id("employment"),
+                rating: new_employment.rating,
+                state: new_employment.state,
+                user_id: new_employment.user_id,
+                position_id: new_employment.position_id,
+                invitation_expires_at: new_employment.invitation_expires_at,
+            };
+            tables.employments.push(employment.clone());
+            self.audit(
+                tables,
+                AuditAction::Create,
+                &employment,
+                None,
+                Some(&employment),
+            )?;
+            tables.record_state_change(
+                employment.id,
+                None,
+                employment.state.clone(),
+                self.actor_id,
+            );
+            Ok(employment)
+        })
+    }
+
+    async fn create_employment_in(
+        &self,
+        _unit_of_work: &mut UnitOfWork,
+        new_employment: CreateEmployment,
+    ) -> Result<Employment, RepositoryError> {
+        self.create_employment(new_employment).await
+    }
+
+    async fn delete_employment(&self, employment_id: i32) -> Result<(), RepositoryError> {
+        self.store.write(|tables| {
+            let employment = tables.employment(employment_id)?.clone();
+            tables.remove_employment(employment_id);
+            self.audit(
+                tables,
+                AuditAction::Delete,
+                &employment,
+                Some(&employment),
+                None,
+            )
+        })
+    }
+
+    async fn update_employment(
+        &self,
+        employment_id: i32,
+        patch_employment: PartialEmployment,
+    ) -> Result<Employment, RepositoryError> {
+        self.store
+            .write(|tables| self.update(tables, employment_id, patch_employment))
+    }
+
+    async fn update_employment_in(
+        &self,
+        _unit_of_work: &mut UnitOfWork,
+        employment_id: i32,
+        patch_employment: PartialEmployment,
+    ) -> Result<Employment, RepositoryError> {
+        self.update_employment(employment_id, patch_employment)
+            .await
+    }
+
+    async fn respond_to_invitation(
+        &self,
+        employment_id: i32,
+        accept: bool,
+        now: OffsetDateTime,
+    ) -> Result<Employment, RepositoryError> {
+        let employment = self.get_employment_by_id(employment_id).await?;
+        if employment.state == EmploymentState::Invited && employment.is_invitation_expired_at(now)
+        {
+            self.expire_invitations(now).await?;
+            return Err(generic_error("The invitation has expired"));
+        }
+
+        let mut unit_of_work = UnitOfWork::begin_in_memory(&self.store);
+        let employment = self
+            .respond_to_invitation_in(&mut unit_of_work, employment_id, accept, now)
+            .await?;
+        unit_of_work.commit().await?;
+        Ok(employment)
+    }
+
+    async fn respond_to_invitation_in(
+        &self,
+        unit_of_work: &mut UnitOfWork,
+        employment_id: i32,
+        accept: bool,
+        now: OffsetDateTime,
+    ) -> Result<Employment, RepositoryError> {
+        let employment = self.get_employment_by_id(employment_id).await?;
+        if employment.state != EmploymentState::Invited {
+            return Err(generic_error("The invitation is no longer open"));
+        }
+        if employment.is_invitation_expired_at(now) {
+            return Err(generic_error("The invitation has expired"));
+        }
+
+        let state = if accept {
+            EmploymentState::Accepted
+        } else {
+            EmploymentState::Declined
+        };
+        self.update_employment_in(
+            unit_of_work,
+            employment_id,
+            PartialEmployment {
+                rating: None,
+                state: Some(state),
+                user_id: None,
+                position_id: None,
+            },
+        )
+        .await
+    }
+
+    async fn expire_invitations(
+        &self,
+        now: OffsetDateTime,
+    ) -> Result<Vec<Employment>, RepositoryError> {
+        self.store.write(|tables| {
+            let due: Vec<Employment> = tables
+                .employments
+                .iter()
+                .filter(|employment| employment.is_invitation_expired_at(now))
+                .cloned()
+                .collect();
+
+            let mut expired = Vec::new();
+            for before in due {
+                let employment = Employment {
+                    state: EmploymentState::Expired,
+                    ..before.clone()
+                };
+                expired.push(self.save(tables, &before, employment)?);
+            }
+            Ok(expired)
+        })
+    }
+
+    async fn withdraw_employment(
+        &self,
+        employment_id: i32,
+        reason: Option<String>,
+        now: OffsetDateTime,
+    ) -> Result<Employment, RepositoryError> {
+        self.store.write(|tables| {
+            let before = tables.employment(employment_id)?.clone();
+            if !before.state.is_withdrawable() {
+                return Err(generic_error(
+                    "Only pending or accepted jobs can be withdrawn from",
+                ));
+            }
+
+            let job_position = tables.job_position(before.position_id)?;
+            let event = tables.event(job_position.event_id)?;
+            if now.date() >= event.date_start {
+                return Err(generic_error("The event has already started"));
+            }
+
+            let reason = reason
+                .map(|reason| reason.trim().to_string())
+                .filter(|reason| !reason.is_empty());
+            let is_late = now >= event.withdrawal_cutoff();
+            if is_late && reason.is_none() {
+                return Err(generic_error(format!(
+                    "Withdrawing within {} hours of the event start requires a reason",
+                    event.withdrawal_cutoff_hours
+                )));
+            }
+
+            let withdrawal = EmploymentWithdrawal {
+                id: tables.next_id("employment_withdrawal"),
+                employment_id,
+                user_id: before.user_id,
+                reason,
+                is_late,
+                created_at: now,
+            };
+            tables.withdrawals.push(withdrawal);
+
+            let employment = Employment {
+                state: EmploymentState::Withdrawn,
+                ..before.clone()
+            };
+            self.save(tables, &before, employment)
+        })
+    }
+
+    async fn withdrawal_stats(
+        &self,
+        user_id: i32,
+        organization_id: Option<i32>,
+    ) -> Result<WithdrawalStats, RepositoryError> {
+        self.store.read(|tables| {
+            let withdrawals: Vec<_> = tables
+                .withdrawals
+                .iter()
+                .filter(|withdrawal| {
+                    withdrawal.user_id == user_id
+                        && tables
+                            .employment_event(withdrawal.employment_id)
+                            .is_some_and(|event| {
+                                organization_id.is_none_or(|id| event.organization_id == id)
+                            })
+                })
+                .collect();
+            Ok(WithdrawalStats {
+                total: withdrawals.len() as i64,
+                late: withdrawals
+                    .iter()
+                    .filter(|withdrawal| withdrawal.is_late)
+                    .count() as i64,
+            })
+        })
+    }
+
+    async fn bulk_update_state(
+        &self,
+        employment_ids: &[i32],
+        state: EmploymentState,
+        order: BulkOrder,
+    ) -> Result<BulkUpdateResult, RepositoryError> {
+        self.store.write(|tables| {
+            let mut result = BulkUpdateResult::default();
+            let mut employments: Vec<Employment> = tables
+                .employments
+                .iter()
+                .filter(|employment| employment_ids.contains(&employment.id))
+                .cloned()
+                .collect();
+            employments.sort_by(|a, b| match order {
+                BulkOrder::Rating => b.rating.cmp(&a.rating).then(a.id.cmp(&b.id)),
+                BulkOrder::ApplicationTime => a.id.cmp(&b.id),
+            });
+
+            for employment_id in employment_ids {
+                if !employments
+                    .iter()
+                    .any(|employment| employment.id == *employment_id)
+                {
+                    result.skipped.push(SkippedEmployment {
+                        employment_id: *employment_id,
+                        reason: "The employment does not exist".to_string(),
+                    });
+                }
+            }
+
+            let mut free_capacity: HashMap<i32, i64> = HashMap::new();
+            for employment in &employments {
+                if let Some(position) = tables.any_job_position(employment.position_id) {
+                    free_capacity.insert(
+                        position.id,
+                        position.capacity as i64 - tables.taken_capacity(position.id),
+                    );
+                }
+            }
+
+            for employment in employments {
+                if !employment.state.can_transition_to(&state) {
+                    result.skipped.push(SkippedEmployment {
+                        employment_id: employment.id,
+                        reason: format!(
+                            "An employment cannot move from {} to {}",
+                            employment.state, state
+                        ),
+                    });
+                    continue;
+                }
+                if state == EmploymentState::Accepted {
+                    let free = free_capacity.entry(employment.position_id).or_insert(0);
+                    if *free <= 0 {
+                        result.skipped.push(SkippedEmployment {
+                            employment_id: employment.id,
+                            reason: "Job position is already full".to_string(),
+                        });
+                        continue;
+                    }
+                    *free -= 1;
+                }
+
+                let updated = Employment {
+                    state: state.clone(),
+                    ..employment.clone()
+                };
+                result
+                    .updated
+                    .push(self.save(tables, &employment, updated)?);
+            }
+            Ok(result)
+        })
+    }
+
+    async fn list_staffing_candidates(
+        &self,
+        job_position_id: i32,
+    ) -> Result<Vec<CandidateStats>, RepositoryError> {
+        self.store.read(|tables| {
+            let Some(target) = tables.any_job_position(job_position_id) else {
+                return Ok(Vec::new());
+            };
+            let Some(target_event) = tables.any_event(target.event_id) else {
+                return Ok(Vec::new());
+            };
+
+            let candidates = tables
+                .memberships
+                .iter()
+                .filter(|membership| membership.organization_id == target_event.organization_id)
+                .filter_map(|membership| tables.user(membership.user_id).ok())
+                .filter(|user| {
+                    user.role == UserRole::Employee
+                        && !tables.employments.iter().any(|employment| {
+                            employment.user_id == user.id && employment.position_id == target.id
+                        })
+                })
+                .map(|user| {
+                    let employments: Vec<&Employment> = tables
+                        .employments
+                        .iter()
+                        .filter(|employment| employment.user_id == user.id)
+                        .collect();
+                    let ratings: Vec<i32> = employments
+                        .iter()
+                        .filter(|employment| {
+                            employment.state == EmploymentState::Done && employment.rating > 0
+                        })
+                        .map(|employment| employment.rating)
+                        .collect();
+                    let done_in_category = employments
+                        .iter()
+                        .filter(|employment| {
+                            employment.state == EmploymentState::Done
+                                && tables.any_job_position(employment.position_id).is_some_and(
+                                    |position| {
+                                        position.position_category_id == target.position_category_id
+                                    },
+                                )
+                        })
+                        .count() as i64;
+                    let conflicts = employments
+                        .iter()
+                        .filter(|employment| {
+                            matches!(
+                                employment.state,
+                                EmploymentState::Pending
+                                    | EmploymentState::Invited
+                                    | EmploymentState::Accepted
+                            ) && tables.position_event(employment.position_id).is_some_and(
+                                |event| {
+                                    tables.event(event.id).is_ok()
+                                        && event.date_start <= target_event.date_end
+                                        && event.date_end >= target_event.date_start
+                                },
+                            )
+                        })
+                        .count() as i64;
+                    let no_shows = tables
+                        .attendance
+                        .iter()
+                        .filter(|record| {
+                            record.outcome == AttendanceOutcome::NoShow
+                                && employments
+                                    .iter()
+                                    .any(|employment| employment.id == record.employment_id)
+                        })
+                        .count() as i64;
+
+                    CandidateStats {
+                        user_id: user.id,
+                        first_name: user.first_name.clone(),
+                        last_name: user.last_name.clone(),
+                        username: user.username.clone(),
+                        average_rating: (!ratings.is_empty())
+                            .then(|| ratings.iter().sum::<i32>() as f32 / ratings.len() as f32),
+                        done_in_category,
+                        conflicts,
+                        no_shows,
+                    }
+                })
+                .collect();
+            Ok(candidates)
+        })
+    }
+
+    async fn list_state_history(
+        &self,
+        employment_ids: &[i32],
+    ) -> Result<Vec<EmploymentStateChange>, RepositoryError> {
+        self.store.read(|tables| {
+            let mut history: Vec<EmploymentStateChange> = tables
+                .employment_history
+                .iter()
+                .filter(|change| employment_ids.contains(&change.employment_id))
+                .map(|change| EmploymentStateChange {
+                    changed_by_name: change
+                        .changed_by
+                        .and_then(|user_id| tables.any_user(user_id))
+                        .map(|user| format!("{} {}", user.first_name, user.last_name)),
+                    ..change.clone()
+                })
+                .collect();
+            history.sort_by(|a, b| a.changed_at.cmp(&b.changed_at).then(a.id.cmp(&b.id)));
+            Ok(history)
+        })
+    }
+}
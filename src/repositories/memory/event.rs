@@ -0,0 +1,561 @@
+use crate::error::RepositoryError;
+use crate::models::audit_log::{AuditAction, AuditEntity};
+use crate::models::employment::{Employment, EmploymentState};
+use crate::models::event::{
+    CancelEvent, CreateEvent, Event, EventStatus, PartialEvent, SelectManyFilter, SortColumn,
+};
+use crate::models::pagination::{ListSpec, Page};
+use crate::models::trash;
+use crate::models::venue::Venue;
+use crate::models::worked_hours::WorkedHours;
+use crate::repositories::event::EventRepository;
+use crate::repositories::memory::{
+    contains, deleted_page, foreign_key_violation, generic_error, live, page, MemoryStore, SortKey,
+    Stored, Tables,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use sqlx::types::time::{Date, OffsetDateTime};
+use std::sync::Arc;
+
+#[derive(Debug, Clone)]
+pub struct MemoryEventRepository {
+    pub store: MemoryStore,
+    pub actor_id: Option<i32>,
+}
+
+impl MemoryEventRepository {
+    pub fn new(store: MemoryStore) -> Self {
+        Self {
+            store,
+            actor_id: None,
+        }
+    }
+
+    fn list(&self, filter: SelectManyFilter) -> Vec<Event> {
+        self.store.read(|tables| {
+            live(&tables.events)
+                .filter(|event| matches(tables, event, &filter))
+                .cloned()
+                .collect()
+        })
+    }
+
+    fn audit(
+        &self,
+        tables: &mut Tables,
+        action: AuditAction,
+        event_id: i32,
+        before: Option<&Event>,
+        after: Option<&Event>,
+    ) -> Result<(), RepositoryError> {
+        tables.audit(
+            self.actor_id,
+            AuditEntity::Event,
+            event_id,
+            Some(event_id),
+            action,
+            before,
+            after,
+        )
+    }
+
+    /// [`EventRepository::transition_event`] within a write to the store.
+    fn transition(
+        &self,
+        tables: &mut Tables,
+        event_id: i32,
+        status: EventStatus,
+    ) -> Result<Event, RepositoryError> {
+        let before = tables.event(event_id)?.clone();
+        if status == EventStatus::Cancelled {
+            return Err(generic_error("Events have to be cancelled with a reason"));
+        }
+        if !before.status.can_transition_to(&status) {
+            return Err(generic_error(format!(
+                "The event cannot move from {} to {}",
+                before.status.to_string().to_lowercase(),
+                status.to_string().to_lowercase()
+            )));
+        }
+
+        let event = Event {
+            status,
+            publish_at: if status == EventStatus::Published {
+                None
+            } else {
+                before.publish_at
+            },
+            ..before.clone()
+        };
+        replace(tables, event.clone());
+        self.audit(
+            tables,
+            AuditAction::Update,
+            event_id,
+            Some(&before),
+            Some(&event),
+        )?;
+        Ok(event)
+    }
+}
+
+fn replace(tables: &mut Tables, event: Event) {
+    if let Some(stored) = tables
+        .events
+        .iter_mut()
+        .find(|stored| stored.row.id == event.id)
+    {
+        stored.row = event;
+    }
+}
+
+/// Whether the event passes `filter`, the in-memory counterpart of
+/// [`PgEventRepository::push_filter`](crate::repositories::event::PgEventRepository::push_filter).
+pub(crate) fn matches(tables: &Tables, event: &Event, filter: &SelectManyFilter) -> bool {
+    let venue = tables.any_venue(event.venue_id);
+    let in_venue = |expected: &Option<String>, actual: fn(&Venue) -> &String| {
+        expected
+            .as_ref()
+            .is_none_or(|expected| venue.is_some_and(|venue| actual(venue) == expected))
+    };
+    filter
+        .organization_id
+        .is_none_or(|organization_id| event.organization_id == organization_id)
+        && filter
+            .venue_id
+            .is_none_or(|venue_id| event.venue_id == venue_id)
+        && filter
+            .owner_id
+            .is_none_or(|owner_id| event.owner_id == owner_id)
+        && filter.status.is_none_or(|status| event.status == status)
+        && filter
+            .date_from
+            .is_none_or(|date_from| event.date_start >= date_from)
+        && filter
+            .date_to
+            .is_none_or(|date_to| event.date_end <= date_to)
+        && in_venue(&filter.state, |venue| &venue.state)
+        && in_venue(&filter.city, |venue| &venue.town)
+        && filter
+            .name
+            .as_ref()
+            .is_none_or(|name| contains(&event.name, name))
+        && filter
+            .managed_by
+            .as_ref()
+            .is_none_or(|managed_by| tables.is_managed_by(event.id, managed_by))
+}
+
+pub(crate) fn sort_key(event: &Event, sort_by: SortColumn) -> SortKey {
+    match sort_by {
+        SortColumn::Name => SortKey::Text(event.name.clone()),
+        SortColumn::DateStart => SortKey::Date(event.date_start),
+        SortColumn::Status => SortKey::Number(event.status as i32 as f64),
+        SortColumn::Id => SortKey::Number(event.id as f64),
+    }
+}
+
+#[async_trait]
+impl EventRepository for MemoryEventRepository {
+    fn with_actor(&self, actor_id: i32) -> Arc<dyn EventRepository> {
+        Arc::new(Self {
+            store: self.store.clone(),
+            actor_id: Some(actor_id),
+        })
+    }
+
+    async fn list_events(&self, filter: SelectManyFilter) -> Result<Vec<Event>> {
+        Ok(self.list(filter))
+    }
+
+    async fn list_events_page(
+        &self,
+        filter: SelectManyFilter,
+        spec: &ListSpec<SortColumn>,
+    ) -> Result<Page<Event>> {
+        Ok(page(
+            self.list(filter),
+            spec,
+            |event| event.id,
+            |event| sort_key(event, spec.sort_by),
+        ))
+    }
+
+    async fn get_event_by_id(&self, event_id: i32) -> Result<Event, RepositoryError> {
+        self.store.read(|tables| tables.event(event_id).cloned())
+    }
+
+    async fn create_event(&self, new_event: CreateEvent) -> Result<Event, RepositoryError> {
+        if !matches!(
+            new_event.status,
+            EventStatus::Draft | EventStatus::Published
+        ) {
+            return Err(generic_error(
+                "A new event has to be either a draft or published",
+            ));
+        }
+
+        self.store.write(|tables| {
+            if tables.any_venue(new_event.venue_id).is_none() {
+                return Err(foreign_key_violation("event_venue_id_fkey"));
+            }
+            if tables.any_user(new_event.owner_id).is_none() {
+                return Err(foreign_key_violation("event_owner_id_fkey"));
+            }
+
+            let event = Event {
+                id: tables.next_id("event"),
+                name: new_event.name,
+                date_start: new_event.date_start,
+                date_end: new_event.date_end,
+                img_url: new_event.img_url,
+                description: Some(new_event.description),
+                status: new_event.status,
+                venue_id: new_event.venue_id,
+                owner_id: new_event.owner_id,
+                cancelled_at: None,
+                cancellation_reason: None,
+                publish_at: new_event.publish_at,
+                organization_id: new_event.organization_id,
+                withdrawal_cutoff_hours: new_event.withdrawal_cutoff_hours,
+            };
+            tables.events.push(Stored::new(event.clone()));
+            self.audit(tables, AuditAction::Create, event.id, None, Some(&event))?;
+            Ok(event)
+        })
+    }
+
+    async fn delete_event(&self, event_id: i32) -> Result<(), RepositoryError> {
+        self.store.write(|tables| {
+            let event = tables.event(event_id)?.clone();
+            if let Some(stored) = tables
+                .events
+                .iter_mut()
+                .find(|stored| stored.row.id == event_id)
+            {
+                stored.deleted_at = Some(OffsetDateTime::now_utc());
+            }
+            self.audit(tables, AuditAction::Delete, event_id, Some(&event), None)
+        })
+    }
+
+    async fn update_event(
+        &self,
+        event_id: i32,
+        patch_event: PartialEvent,
+    ) -> Result<Event, RepositoryError> {
+        self.store.write(|tables| {
+            let before = tables.event(event_id)?.clone();
+            let event = Event {
+                name: patch_event.name.unwrap_or(before.name.clone()),
+                date_start: patch_event.date_start.unwrap_or(before.date_start),
+                date_end: patch_event.date_end.unwrap_or(before.date_end),
+                img_url: patch_event.img_url.unwrap_or(before.img_url.clone()),
+                description: patch_event.description.or(before.description.clone()),
+                venue_id: patch_event.venue_id.unwrap_or(before.venue_id),
+                owner_id: patch_event.owner_id.unwrap_or(before.owner_id),
+                publish_at: patch_event.publish_at.unwrap_or(before.publish_at),
+                withdrawal_cutoff_hours: patch_event
+                    .withdrawal_cutoff_hours
+                    .unwrap_or(before.withdrawal_cutoff_hours),
+                ..before.clone()
+            };
+            replace(tables, event.clone());
+            self.audit(
+                tables,
+                AuditAction::Update,
+                event_id,
+                Some(&before),
+                Some(&event),
+            )?;
+            Ok(event)
+        })
+    }
+
+    async fn list_events_worked_by_user(
+        &self,
+        user_id: i32,
+        organization_id: i32,
+    ) -> Result<Vec<Event>, RepositoryError> {
+        self.store.read(|tables| {
+            let mut events: Vec<Event> = Vec::new();
+            for employment in tables.employments.iter().filter(|employment| {
+                employment.user_id == user_id && employment.state == EmploymentState::Accepted
+            }) {
+                let Ok(position) = tables.job_position(employment.position_id) else {
+                    continue;
+                };
+                let Ok(event) = tables.event(position.event_id) else {
+                    continue;
+                };
+                if event.organization_id == organization_id
+                    && !events.iter().any(|known| known.id == event.id)
+                {
+                    events.push(event.clone());
+                }
+            }
+            Ok(events)
+        })
+    }
+
+    async fn cancel_event(
+        &self,
+        event_id: i32,
+        cancellation: CancelEvent,
+    ) -> Result<Event, RepositoryError> {
+        self.store.write(|tables| {
+            let before = tables.event(event_id)?.clone();
+            if before.is_cancelled() {
+                return Err(generic_error("The event is already cancelled"));
+            }
+            if !before.status.can_transition_to(&EventStatus::Cancelled) {
+                return Err(generic_error(format!(
+                    "A {} event cannot be cancelled",
+                    before.status.to_string().to_lowercase()
+                )));
+            }
+
+            let event = Event {
+                status: EventStatus::Cancelled,
+                cancelled_at: Some(OffsetDateTime::now_utc()),
+                cancellation_reason: Some(cancellation.reason.clone()),
+                ..before.clone()
+            };
+            replace(tables, event.clone());
+
+            let positions: Vec<i32> = tables
+                .job_positions
+                .iter_mut()
+                .filter(|stored| stored.row.event_id == event_id)
+                .map(|stored| {
+                    stored.row.is_opened_for_registration = false;
+                    stored.row.id
+                })
+                .collect();
+
+            let employments: Vec<Employment> = tables
+                .employments
+                .iter()
+                .filter(|employment| {
+                    positions.contains(&employment.position_id)
+                        && matches!(
+                            employment.state,
+                            EmploymentState::Pending
+                                | EmploymentState::Invited
+                                | EmploymentState::Accepted
+                        )
+                })
+                .cloned()
+                .collect();
+
+            if let Some(hours) = cancellation.compensation_hours.filter(|hours| *hours > 0.0) {
+                for employment in &employments {
+                    let logged = tables.worked_hours.iter().any(|worked| {
+                        worked.employment_id == employment.id && worked.date == event.date_start
+                    });
+                    if employment.state == EmploymentState::Accepted && !logged {
+                        let worked = WorkedHours {
+                            id: tables.next_id("worked_hours"),
+                            date: event.date_start,
+                            hours_worked: hours,
+                            employment_id: employment.id,
+                        };
+                        tables.worked_hours.push(worked);
+                    }
+                }
+            }
+
+            for employment in tables
+                .employments
+                .iter_mut()
+                .filter(|employment| employments.iter().any(|known| known.id == employment.id))
+            {
+                employment.state = EmploymentState::Cancelled;
+            }
+
+            self.audit(
+                tables,
+                AuditAction::Update,
+                event_id,
+                Some(&before),
+                Some(&event),
+            )?;
+
+            let mut notified_users: Vec<i32> = Vec::new();
+            for employment in employments {
+                let cancelled = Employment {
+                    state: EmploymentState::Cancelled,
+                    ..employment.clone()
+                };
+                tables.audit(
+                    self.actor_id,
+                    AuditEntity::Employment,
+                    employment.id,
+                    Some(event_id),
+                    AuditAction::Update,
+                    Some(&employment),
+                    Some(&cancelled),
+                )?;
+                tables.record_state_change(
+                    employment.id,
+                    Some(employment.state.clone()),
+                    EmploymentState::Cancelled,
+                    self.actor_id,
+                );
+
+                if notified_users.contains(&employment.user_id) {
+                    continue;
+                }
+                notified_users.push(employment.user_id);
+                tables.notify(
+                    employment.user_id,
+                    format!("{} has been cancelled: {}", event.name, cancellation.reason),
+                );
+            }
+
+            Ok(event)
+        })
+    }
+
+    async fn transition_event(
+        &self,
+        event_id: i32,
+        status: EventStatus,
+    ) -> Result<Event, RepositoryError> {
+        self.store
+            .write(|tables| self.transition(tables, event_id, status))
+    }
+
+    async fn advance_event_statuses(&self, today: Date) -> Result<Vec<Event>, RepositoryError> {
+        self.store.write(|tables| {
+            let mut due: Vec<Event> = live(&tables.events)
+                .filter(|event| match event.status {
+                    EventStatus::Published | EventStatus::RegistrationClosed => {
+                        event.date_start <= today
+                    }
+                    EventStatus::InProgress => event.date_end < today,
+                    _ => false,
+                })
+                .cloned()
+                .collect();
+            due.sort_by_key(|event| event.id);
+
+            let mut advanced = Vec::new();
+            for mut event in due {
+                if event.status != EventStatus::InProgress {
+                    event = self.transition(tables, event.id, EventStatus::InProgress)?;
+                }
+                if event.date_end < today {
+                    event = self.transition(tables, event.id, EventStatus::Completed)?;
+                }
+                advanced.push(event);
+            }
+            Ok(advanced)
+        })
+    }
+
+    async fn publish_scheduled_events(
+        &self,
+        now: OffsetDateTime,
+    ) -> Result<Vec<Event>, RepositoryError> {
+        self.store.write(|tables| {
+            let mut due: Vec<i32> = live(&tables.events)
+                .filter(|event| {
+                    event.status == EventStatus::Draft
+                        && event.publish_at.is_some_and(|publish_at| publish_at <= now)
+                })
+                .map(|event| event.id)
+                .collect();
+            due.sort();
+
+            let mut published = Vec::new();
+            for event_id in due {
+                published.push(self.transition(tables, event_id, EventStatus::Published)?);
+            }
+            Ok(published)
+        })
+    }
+
+    async fn list_deleted_events(
+        &self,
+        organization_id: i32,
+        spec: &ListSpec<trash::SortColumn>,
+    ) -> Result<Page<Event>, RepositoryError> {
+        Ok(self.store.read(|tables| {
+            deleted_page(
+                &tables.events,
+                spec,
+                |event| event.id,
+                |event| event.organization_id == organization_id,
+            )
+        }))
+    }
+
+    async fn restore_event(
+        &self,
+        event_id: i32,
+        organization_id: i32,
+    ) -> Result<Event, RepositoryError> {
+        self.store.write(|tables| {
+            if tables
+                .any_event(event_id)
+                .is_none_or(|event| event.organization_id != organization_id)
+            {
+                return Err(RepositoryError::NotFound);
+            }
+            let has_deleted_dependencies = tables.any_event(event_id).is_some_and(|event| {
+                tables.venue(event.venue_id).is_err() || tables.user(event.owner_id).is_err()
+            });
+            if has_deleted_dependencies {
+                return Err(generic_error(
+                    "The event's venue or owner has to be restored first",
+                ));
+            }
+
+            let stored = tables
+                .events
+                .iter_mut()
+                .find(|stored| stored.row.id == event_id && stored.is_deleted())
+                .ok_or(RepositoryError::NotFound)?;
+            stored.deleted_at = None;
+            let event = stored.row.clone();
+            self.audit(tables, AuditAction::Restore, event_id, None, Some(&event))?;
+            Ok(event)
+        })
+    }
+
+    async fn purge_event(
+        &self,
+        event_id: i32,
+        organization_id: i32,
+    ) -> Result<(), RepositoryError> {
+        self.store.write(|tables| {
+            if tables
+                .any_event(event_id)
+                .is_none_or(|event| event.organization_id != organization_id)
+            {
+                return Err(RepositoryError::NotFound);
+            }
+            let has_worked_hours = tables.worked_hours.iter().any(|worked| {
+                tables
+                    .employment_event(worked.employment_id)
+                    .is_some_and(|event| event.id == event_id)
+            });
+            if has_worked_hours {
+                return Err(generic_error(
+                    "The event has logged worked hours and cannot be purged",
+                ));
+            }
+
+            let event = tables
+                .events
+                .iter()
+                .find(|stored| stored.row.id == event_id && stored.is_deleted())
+                .map(|stored| stored.row.clone())
+                .ok_or(RepositoryError::NotFound)?;
+            tables.remove_event(event_id);
+            self.audit(tables, AuditAction::Purge, event_id, Some(&event), None)
+        })
+    }
+}
@@ -0,0 +1,144 @@
+use crate::error::RepositoryError;
+use crate::models::event_manager_relation::{
+    CreateEventManagerRelation, EventManagerPermissions, EventManagerRelation,
+};
+use crate::repositories::event_manager_relation::EventManagerRelationRepository;
+use crate::repositories::memory::{foreign_key_violation, generic_error, MemoryStore};
+use anyhow::Result;
+use async_trait::async_trait;
+
+#[derive(Debug, Clone)]
+pub struct MemoryEventManagerRelationRepository {
+    pub store: MemoryStore,
+}
+
+impl MemoryEventManagerRelationRepository {
+    pub fn new(store: MemoryStore) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl EventManagerRelationRepository for MemoryEventManagerRelationRepository {
+    async fn create_relation(
+        &self,
+        new_relation: CreateEventManagerRelation,
+    ) -> Result<EventManagerRelation, RepositoryError> {
+        self.store.write(|tables| {
+            if tables.event_managers.iter().any(|relation| {
+                relation.event_id == new_relation.event_id
+                    && relation.user_id == new_relation.user_id
+            }) {
+                return Err(generic_error("The event has already been registered"));
+            }
+            if tables.any_user(new_relation.user_id).is_none() {
+                return Err(foreign_key_violation("event_manager_relation_user_id_fkey"));
+            }
+            if tables.any_event(new_relation.event_id).is_none() {
+                return Err(foreign_key_violation(
+                    "event_manager_relation_event_id_fkey",
+                ));
+            }
+
+            let permissions = EventManagerPermissions::default();
+            let relation = EventManagerRelation {
+                user_id: new_relation.user_id,
+                event_id: new_relation.event_id,
+                can_edit_event: permissions.can_edit_event,
+                can_manage_jobs: permissions.can_manage_jobs,
+                can_manage_applicants: permissions.can_manage_applicants,
+                can_approve_hours: permissions.can_approve_hours,
+                can_view_payroll: permissions.can_view_payroll,
+                can_manage_managers: permissions.can_manage_managers,
+            };
+            tables.event_managers.push(relation.clone());
+            Ok(relation)
+        })
+    }
+
+    async fn delete_relation(&self, relation: EventManagerRelation) -> Result<(), RepositoryError> {
+        self.store.write(|tables| {
+            let count = tables.event_managers.len();
+            tables.event_managers.retain(|stored| {
+                stored.user_id != relation.user_id || stored.event_id != relation.event_id
+            });
+            if tables.event_managers.len() == count {
+                return Err(RepositoryError::NotFound);
+            }
+            Ok(())
+        })
+    }
+
+    async fn get_relation(
+        &self,
+        event_id: i32,
+        user_id: i32,
+    ) -> Result<EventManagerRelation, RepositoryError> {
+        self.store.read(|tables| {
+            tables
+                .event_managers
+                .iter()
+                .find(|relation| relation.event_id == event_id && relation.user_id == user_id)
+                .cloned()
+                .ok_or(RepositoryError::NotFound)
+        })
+    }
+
+    async fn update_permissions(
+        &self,
+        event_id: i32,
+        user_id: i32,
+        permissions: EventManagerPermissions,
+    ) -> Result<EventManagerRelation, RepositoryError> {
+        self.store.write(|tables| {
+            let relation = tables
+                .event_managers
+                .iter_mut()
+                .find(|relation| relation.event_id == event_id && relation.user_id == user_id)
+                .ok_or(RepositoryError::NotFound)?;
+            relation.can_edit_event = permissions.can_edit_event;
+            relation.can_manage_jobs = permissions.can_manage_jobs;
+            relation.can_manage_applicants = permissions.can_manage_applicants;
+            relation.can_approve_hours = permissions.can_approve_hours;
+            relation.can_view_payroll = permissions.can_view_payroll;
+            relation.can_manage_managers = permissions.can_manage_managers;
+            Ok(relation.clone())
+        })
+    }
+
+    async fn list_event_managers(
+        &self,
+        event_id: i32,
+    ) -> Result<Vec<EventManagerRelation>, RepositoryError> {
+        Ok(self.store.read(|tables| {
+            tables
+                .event_managers
+                .iter()
+                .filter(|relation| {
+                    relation.event_id == event_id && tables.user(relation.user_id).is_ok()
+                })
+                .cloned()
+                .collect()
+        }))
+    }
+
+    async fn list_managers_events(
+        &self,
+        user_id: i32,
+        organization_id: i32,
+    ) -> Result<Vec<EventManagerRelation>, RepositoryError> {
+        Ok(self.store.read(|tables| {
+            tables
+                .event_managers
+                .iter()
+                .filter(|relation| {
+                    relation.user_id == user_id
+                        && tables
+                            .event(relation.event_id)
+                            .is_ok_and(|event| event.organization_id == organization_id)
+                })
+                .cloned()
+                .collect()
+        }))
+    }
+}
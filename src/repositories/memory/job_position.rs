@@ -0,0 +1,486 @@
+use crate::error::RepositoryError;
+use crate::models::audit_log::{AuditAction, AuditEntity};
+use crate::models::event::EventStatus;
+use crate::models::job_position::{
+    CreateJobPosition, JobPosition, PartialJobPosition, SelectManyFilter, SortColumn,
+};
+use crate::models::pagination::{ListSpec, Page};
+use crate::models::trash;
+use crate::repositories::job_position::JobPositionRepository;
+use crate::repositories::memory::{
+    deleted_page, foreign_key_violation, generic_error, live, page, MemoryStore, SortKey, Stored,
+    Tables,
+};
+use crate::repositories::unit_of_work::UnitOfWork;
+use crate::rich_text::render_markdown;
+use anyhow::Result;
+use async_trait::async_trait;
+use sqlx::types::time::OffsetDateTime;
+use std::sync::Arc;
+
+#[derive(Debug, Clone)]
+pub struct MemoryJobPositionRepository {
+    pub store: MemoryStore,
+    pub actor_id: Option<i32>,
+}
+
+impl MemoryJobPositionRepository {
+    pub fn new(store: MemoryStore) -> Self {
+        Self {
+            store,
+            actor_id: None,
+        }
+    }
+
+    fn list(&self, filter: SelectManyFilter) -> Vec<JobPosition> {
+        self.store.read(|tables| {
+            live(&tables.job_positions)
+                .filter(|position| matches(tables, position, &filter))
+                .cloned()
+                .collect()
+        })
+    }
+
+    fn audit(
+        &self,
+        tables: &mut Tables,
+        action: AuditAction,
+        job_position: &JobPosition,
+        before: Option<&JobPosition>,
+        after: Option<&JobPosition>,
+    ) -> Result<(), RepositoryError> {
+        tables.audit(
+            self.actor_id,
+            AuditEntity::JobPosition,
+            job_position.id,
+            Some(job_position.event_id),
+            action,
+            before,
+            after,
+        )
+    }
+
+    /// [`JobPositionRepository::update_job_position`] within a write to the store.
+    fn update(
+        &self,
+        tables: &mut Tables,
+        position_id: i32,
+        patch_position: PartialJobPosition,
+    ) -> Result<JobPosition, RepositoryError> {
+        let before = tables.job_position(position_id)?.clone();
+        let (instructions_html, instructions_markdown) = match patch_position.instructions_markdown
+        {
+            Some(markdown) => (render_markdown(&markdown), markdown),
+            None => (
+                before.instructions_html.clone(),
+                before.instructions_markdown.clone(),
+            ),
+        };
+        let job_position = JobPosition {
+            id: before.id,
+            name: patch_position.name.unwrap_or(before.name.clone()),
+            description: patch_position.description.or(before.description.clone()),
+            salary: patch_position.salary.unwrap_or(before.salary),
+            capacity: patch_position.capacity.unwrap_or(before.capacity),
+            instructions_html,
+            instructions_markdown,
+            is_opened_for_registration: patch_position
+                .is_opened_for_registration
+                .unwrap_or(before.is_opened_for_registration),
+            currency: patch_position.currency.unwrap_or(before.currency.clone()),
+            event_id: patch_position.event_id.unwrap_or(before.event_id),
+            position_category_id: patch_position
+                .position_category_id
+                .unwrap_or(before.position_category_id),
+            registration_opens_at: patch_position
+                .registration_opens_at
+                .unwrap_or(before.registration_opens_at),
+            registration_closes_at: patch_position
+                .registration_closes_at
+                .unwrap_or(before.registration_closes_at),
+        };
+        check_registration_window(
+            job_position.registration_opens_at,
+            job_position.registration_closes_at,
+        )?;
+        check_references(tables, &job_position)?;
+
+        if let Some(stored) = tables
+            .job_positions
+            .iter_mut()
+            .find(|stored| stored.row.id == position_id)
+        {
+            stored.row = job_position.clone();
+        }
+        self.audit(
+            tables,
+            AuditAction::Update,
+            &job_position,
+            Some(&before),
+            Some(&job_position),
+        )?;
+        Ok(job_position)
+    }
+}
+
+pub(crate) fn matches(tables: &Tables, position: &JobPosition, filter: &SelectManyFilter) -> bool {
+    let Ok(event) = tables.event(position.event_id) else {
+        return false;
+    };
+    filter
+        .organization_id
+        .is_none_or(|organization_id| event.organization_id == organization_id)
+        && filter
+            .capacity
+            .is_none_or(|capacity| position.capacity == capacity)
+        && filter
+            .position_category_id
+            .is_none_or(|category_id| position.position_category_id == category_id)
+        && filter
+            .event_id
+            .is_none_or(|event_id| position.event_id == event_id)
+        && filter.salary.is_none_or(|salary| position.salary == salary)
+        && filter
+            .is_opened_for_registration
+            .is_none_or(|is_opened| position.is_opened_for_registration == is_opened)
+        && filter
+            .managed_by
+            .as_ref()
+            .is_none_or(|managed_by| tables.is_managed_by(event.id, managed_by))
+}
+
+pub(crate) fn sort_key(tables: &Tables, position: &JobPosition, sort_by: SortColumn) -> SortKey {
+    match sort_by {
+        SortColumn::Name => SortKey::Text(position.name.clone()),
+        SortColumn::Event => SortKey::Text(
+            tables
+                .any_event(position.event_id)
+                .map(|event| event.name.clone())
+                .unwrap_or_default(),
+        ),
+        SortColumn::Salary => SortKey::Number(position.salary as f64),
+        SortColumn::Capacity => SortKey::Number(position.capacity as f64),
+        SortColumn::Id => SortKey::Number(position.id as f64),
+    }
+}
+
+fn check_registration_window(
+    opens_at: Option<OffsetDateTime>,
+    closes_at: Option<OffsetDateTime>,
+) -> Result<(), RepositoryError> {
+    if let (Some(opens_at), Some(closes_at)) = (opens_at, closes_at) {
+        if closes_at <= opens_at {
+            return Err(generic_error("Registration has to close after it opens"));
+        }
+    }
+    Ok(())
+}
+
+fn check_references(tables: &Tables, job_position: &JobPosition) -> Result<(), RepositoryError> {
+    if tables.any_event(job_position.event_id).is_none() {
+        return Err(foreign_key_violation("job_position_event_id_fkey"));
+    }
+    if !tables
+        .position_categories
+        .iter()
+        .any(|category| category.id == job_position.position_category_id)
+    {
+        return Err(foreign_key_violation(
+            "job_position_position_category_id_fkey",
+        ));
+    }
+    Ok(())
+}
+
+fn check_add(tables: &Tables, new_position: &CreateJobPosition) -> Result<(), RepositoryError> {
+    check_registration_window(
+        new_position.registration_opens_at,
+        new_position.registration_closes_at,
+    )?;
+
+    let event = tables.event(new_position.event_id)?;
+    if event.date_end < OffsetDateTime::now_utc().date() {
+        return Err(generic_error("The event has already ended"));
+    }
+    if event.is_cancelled() {
+        return Err(generic_error("The event has been cancelled"));
+    }
+    Ok(())
+}
+
+#[async_trait]
+impl JobPositionRepository for MemoryJobPositionRepository {
+    fn with_actor(&self, actor_id: i32) -> Arc<dyn JobPositionRepository> {
+        Arc::new(Self {
+            store: self.store.clone(),
+            actor_id: Some(actor_id),
+        })
+    }
+
+    async fn list_job_positions(&self, filter: SelectManyFilter) -> Result<Vec<JobPosition>> {
+        Ok(self.list(filter))
+    }
+
+    async fn list_job_positions_page(
+        &self,
+        filter: SelectManyFilter,
+        spec: &ListSpec<SortColumn>,
+    ) -> Result<Page<JobPosition>> {
+        let positions = self.list(filter);
+        Ok(self.store.read(|tables| {
+            page(
+                positions,
+                spec,
+                |position| position.id,
+                |position| sort_key(tables, position, spec.sort_by),
+            )
+        }))
+    }
+
+    async fn list_job_positions_worked_by_user_on_event(
+        &self,
+        user_id: i32,
+        event_id: i32,
+    ) -> Result<Vec<JobPosition>> {
+        Ok(self.store.read(|tables| {
+            if tables.event(event_id).is_err() {
+                return Vec::new();
+            }
+            live(&tables.job_positions)
+                .filter(|position| {
+                    position.event_id == event_id
+                        && tables.employments.iter().any(|employment| {
+                            employment.position_id == position.id && employment.user_id == user_id
+                        })
+                })
+                .cloned()
+                .collect()
+        }))
+    }
+
+    async fn get_job_position_by_id(
+        &self,
+        position_id: i32,
+    ) -> Result<JobPosition, RepositoryError> {
+        self.store
+            .read(|tables| tables.job_position(position_id).cloned())
+    }
+
+    async fn create_job_position(
+        &self,
+        new_position: CreateJobPosition,
+    ) -> Result<JobPosition, RepositoryError> {
+        self.store.write(|tables| {
+            check_add(tables, &new_position)?;
+            let job_position = JobPosition {
+                id: tables.next_id("job_position"),
+                name: new_position.name,
+                description: Some(new_position.description),
+                salary: new_position.salary,
+                capacity: new_position.capacity,
+                instructions_html: render_markdown(&new_position.instructions_markdown),
+                instructions_markdown: new_position.instructions_markdown,
+                is_opened_for_registration: new_position.is_opened_for_registration,
+                currency: new_position.currency,
+                event_id: new_position.event_id,
+                position_category_id: new_position.position_category_id,
+                registration_opens_at: new_position.registration_opens_at,
+                registration_closes_at: new_position.registration_closes_at,
+            };
+            check_references(tables, &job_position)?;
+            tables.job_positions.push(Stored::new(job_position.clone()));
+            self.audit(
+                tables,
+                AuditAction::Create,
+                &job_position,
+                None,
+                Some(&job_position),
+            )?;
+            Ok(job_position)
+        })
+    }
+
+    async fn create_job_position_in(
+        &self,
+        _unit_of_work: &mut UnitOfWork,
+        new_position: CreateJobPosition,
+    ) -> Result<JobPosition, RepositoryError> {
+        self.create_job_position(new_position).await
+    }
+
+    async fn delete_job_position(&self, position_id: i32) -> Result<(), RepositoryError> {
+        self.store.write(|tables| {
+            let job_position = tables.job_position(position_id)?.clone();
+            if let Some(stored) = tables
+                .job_positions
+                .iter_mut()
+                .find(|stored| stored.row.id == position_id)
+            {
+                stored.deleted_at = Some(OffsetDateTime::now_utc());
+            }
+            self.audit(
+                tables,
+                AuditAction::Delete,
+                &job_position,
+                Some(&job_position),
+                None,
+            )
+        })
+    }
+
+    async fn update_job_position(
+        &self,
+        position_id: i32,
+        patch_position: PartialJobPosition,
+    ) -> Result<JobPosition, RepositoryError> {
+        self.store
+            .write(|tables| self.update(tables, position_id, patch_position))
+    }
+
+    async fn list_deleted_job_positions(
+        &self,
+        organization_id: i32,
+        spec: &ListSpec<trash::SortColumn>,
+    ) -> Result<Page<JobPosition>, RepositoryError> {
+        Ok(self.store.read(|tables| {
+            deleted_page(
+                &tables.job_positions,
+                spec,
+                |position| position.id,
+                |position| {
+                    tables
+                        .any_event(position.event_id)
+                        .is_some_and(|event| event.organization_id == organization_id)
+                },
+            )
+        }))
+    }
+
+    async fn restore_job_position(
+        &self,
+        position_id: i32,
+        organization_id: i32,
+    ) -> Result<JobPosition, RepositoryError> {
+        self.store.write(|tables| {
+            if tables
+                .position_event(position_id)
+                .is_none_or(|event| event.organization_id != organization_id)
+            {
+                return Err(RepositoryError::NotFound);
+            }
+            let stored = tables
+                .job_positions
+                .iter_mut()
+                .find(|stored| stored.row.id == position_id && stored.is_deleted())
+                .ok_or(RepositoryError::NotFound)?;
+            stored.deleted_at = None;
+            let job_position = stored.row.clone();
+            self.audit(
+                tables,
+                AuditAction::Restore,
+                &job_position,
+                None,
+                Some(&job_position),
+            )?;
+            Ok(job_position)
+        })
+    }
+
+    async fn purge_job_position(
+        &self,
+        position_id: i32,
+        organization_id: i32,
+    ) -> Result<(), RepositoryError> {
+        self.store.write(|tables| {
+            if tables
+                .position_event(position_id)
+                .is_none_or(|event| event.organization_id != organization_id)
+            {
+                return Err(RepositoryError::NotFound);
+            }
+            let has_worked_hours = tables.worked_hours.iter().any(|hours| {
+                tables
+                    .employment(hours.employment_id)
+                    .is_ok_and(|employment| employment.position_id == position_id)
+            });
+            if has_worked_hours {
+                return Err(generic_error(
+                    "The job has logged worked hours and cannot be purged",
+                ));
+            }
+
+            let job_position = tables
+                .job_positions
+                .iter()
+                .find(|stored| stored.row.id == position_id && stored.is_deleted())
+                .map(|stored| stored.row.clone())
+                .ok_or(RepositoryError::NotFound)?;
+            tables.remove_job_position(position_id);
+            self.audit(
+                tables,
+                AuditAction::Purge,
+                &job_position,
+                Some(&job_position),
+                None,
+            )
+        })
+    }
+
+    async fn apply_registration_windows(
+        &self,
+        now: OffsetDateTime,
+    ) -> Result<Vec<JobPosition>, RepositoryError> {
+        self.store.write(|tables| {
+            let since = tables
+                .registration_windows_run_at
+                .unwrap_or(OffsetDateTime::UNIX_EPOCH);
+            let mut due: Vec<JobPosition> = live(&tables.job_positions)
+                .filter(|position| {
+                    let is_scheduled = tables.event(position.event_id).is_ok_and(|event| {
+                        matches!(event.status, EventStatus::Draft | EventStatus::Published)
+                    });
+                    let opens = !position.is_opened_for_registration
+                        && position
+                            .registration_opens_at
+                            .is_some_and(|opens_at| since < opens_at && opens_at <= now)
+                        && position
+                            .registration_closes_at
+                            .is_none_or(|closes_at| now < closes_at);
+                    let closes = position.is_opened_for_registration
+                        && position
+                            .registration_closes_at
+                            .is_some_and(|closes_at| since < closes_at && closes_at <= now);
+                    is_scheduled && (opens || closes)
+                })
+                .cloned()
+                .collect();
+            due.sort_by_key(|position| position.id);
+
+            let mut flipped = Vec::new();
+            for job_position in due {
+                let updated = self.update(
+                    tables,
+                    job_position.id,
+                    PartialJobPosition {
+                        name: None,
+                        description: None,
+                        salary: None,
+                        currency: None,
+                        capacity: None,
+                        instructions_markdown: None,
+                        is_opened_for_registration: Some(!job_position.is_opened_for_registration),
+                        event_id: None,
+                        position_category_id: None,
+                        registration_opens_at: None,
+                        registration_closes_at: None,
+                    },
+                )?;
+                flipped.push(updated);
+            }
+            if since < now {
+                tables.registration_windows_run_at = Some(now);
+            }
+            Ok(flipped)
+        })
+    }
+}
@@ -0,0 +1,66 @@
+use crate::error::RepositoryError;
+use crate::models::notification::{CreateNotification, Notification};
+use crate::repositories::memory::{foreign_key_violation, MemoryStore};
+use crate::repositories::notification::NotificationRepository;
+use crate::repositories::unit_of_work::UnitOfWork;
+use async_trait::async_trait;
+use sqlx::types::time::OffsetDateTime;
+
+#[derive(Debug, Clone)]
+pub struct MemoryNotificationRepository {
+    pub store: MemoryStore,
+}
+
+impl MemoryNotificationRepository {
+    pub fn new(store: MemoryStore) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl NotificationRepository for MemoryNotificationRepository {
+    async fn list_notifications(&self, user_id: i32) -> Result<Vec<Notification>, RepositoryError> {
+        let mut notifications: Vec<Notification> = self.store.read(|tables| {
+            tables
+                .notifications
+                .iter()
+                .filter(|notification| notification.user_id == user_id)
+                .cloned()
+                .collect()
+        });
+        notifications.sort_by(|a, b| b.created_at.cmp(&a.created_at).then(b.id.cmp(&a.id)));
+        Ok(notifications)
+    }
+
+    async fn create_notification(
+        &self,
+        new_notification: CreateNotification,
+    ) -> Result<Notification, RepositoryError> {
+        self.store.write(|tables| {
+            if tables.any_user(new_notification.user_id).is_none() {
+                return Err(foreign_key_violation("notification_user_id_fkey"));
+            }
+            Ok(tables.notify(new_notification.user_id, new_notification.message))
+        })
+    }
+
+    async fn create_notification_in(
+        &self,
+        _unit_of_work: &mut UnitOfWork,
+        new_notification: CreateNotification,
+    ) -> Result<Notification, RepositoryError> {
+        self.create_notification(new_notification).await
+    }
+
+    async fn mark_notifications_read(&self, user_id: i32) -> Result<(), RepositoryError> {
+        self.store.write(|tables| {
+            let now = OffsetDateTime::now_utc();
+            for notification in tables.notifications.iter_mut().filter(|notification| {
+                notification.user_id == user_id && notification.read_at.is_none()
+            }) {
+                notification.read_at = Some(now);
+            }
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,184 @@
+use crate::error::RepositoryError;
+use crate::models::organization::{
+    CreateOrganization, CreateOrganizationMembership, Organization, OrganizationMembership,
+};
+use crate::repositories::memory::{foreign_key_violation, generic_error, MemoryStore};
+use crate::repositories::organization::OrganizationRepository;
+use crate::repositories::unit_of_work::UnitOfWork;
+use async_trait::async_trait;
+use sqlx::types::time::OffsetDateTime;
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct MemoryOrganizationRepository {
+    pub store: MemoryStore,
+}
+
+impl MemoryOrganizationRepository {
+    pub fn new(store: MemoryStore) -> Self {
+        Self { store }
+    }
+}
+
+fn sorted_by_name(mut organizations: Vec<Organization>) -> Vec<Organization> {
+    organizations.sort_by(|a, b| a.name.cmp(&b.name));
+    organizations
+}
+
+#[async_trait]
+impl OrganizationRepository for MemoryOrganizationRepository {
+    async fn list_organizations(&self) -> Result<Vec<Organization>, RepositoryError> {
+        let organizations = self.store.read(|tables| tables.organizations.clone());
+        Ok(sorted_by_name(organizations))
+    }
+
+    async fn list_user_organizations(
+        &self,
+        user_id: i32,
+    ) -> Result<Vec<Organization>, RepositoryError> {
+        let organizations = self.store.read(|tables| {
+            tables
+                .organizations
+                .iter()
+                .filter(|organization| tables.is_member(organization.id, user_id))
+                .cloned()
+                .collect()
+        });
+        Ok(sorted_by_name(organizations))
+    }
+
+    async fn get_organization_by_id(
+        &self,
+        organization_id: i32,
+    ) -> Result<Organization, RepositoryError> {
+        self.store.read(|tables| {
+            tables
+                .organizations
+                .iter()
+                .find(|organization| organization.id == organization_id)
+                .cloned()
+                .ok_or(RepositoryError::NotFound)
+        })
+    }
+
+    async fn get_organization_by_invite_code(
+        &self,
+        invite_code: &str,
+    ) -> Result<Organization, RepositoryError> {
+        self.store.read(|tables| {
+            tables
+                .organizations
+                .iter()
+                .find(|organization| organization.invite_code == invite_code)
+                .cloned()
+                .ok_or(RepositoryError::NotFound)
+        })
+    }
+
+    async fn create_organization(
+        &self,
+        new_organization: CreateOrganization,
+    ) -> Result<Organization, RepositoryError> {
+        self.store.write(|tables| {
+            if tables
+                .organizations
+                .iter()
+                .any(|organization| organization.name == new_organization.name)
+            {
+                return Err(generic_error(
+                    "An organization with this name already exists",
+                ));
+            }
+
+            let organization = Organization {
+                id: tables.next_id("organization"),
+                name: new_organization.name,
+                invite_code: Uuid::new_v4().to_string(),
+                created_at: OffsetDateTime::now_utc(),
+            };
+            tables.organizations.push(organization.clone());
+            Ok(organization)
+        })
+    }
+
+    async fn get_membership(
+        &self,
+        organization_id: i32,
+        user_id: i32,
+    ) -> Result<OrganizationMembership, RepositoryError> {
+        self.store.read(|tables| {
+            tables
+                .memberships
+                .iter()
+                .find(|membership| {
+                    membership.organization_id == organization_id && membership.user_id == user_id
+                })
+                .cloned()
+                .ok_or(RepositoryError::NotFound)
+        })
+    }
+
+    async fn create_membership(
+        &self,
+        new_membership: CreateOrganizationMembership,
+    ) -> Result<OrganizationMembership, RepositoryError> {
+        self.store.write(|tables| {
+            if !tables
+                .organizations
+                .iter()
+                .any(|organization| organization.id == new_membership.organization_id)
+            {
+                return Err(foreign_key_violation(
+                    "organization_membership_organization_id_fkey",
+                ));
+            }
+            if tables.any_user(new_membership.user_id).is_none() {
+                return Err(foreign_key_violation(
+                    "organization_membership_user_id_fkey",
+                ));
+            }
+
+            if let Some(membership) = tables.memberships.iter_mut().find(|membership| {
+                membership.organization_id == new_membership.organization_id
+                    && membership.user_id == new_membership.user_id
+            }) {
+                membership.role = new_membership.role;
+                return Ok(membership.clone());
+            }
+
+            let membership = OrganizationMembership {
+                organization_id: new_membership.organization_id,
+                user_id: new_membership.user_id,
+                role: new_membership.role,
+                created_at: OffsetDateTime::now_utc(),
+            };
+            tables.memberships.push(membership.clone());
+            Ok(membership)
+        })
+    }
+
+    async fn create_membership_in(
+        &self,
+        _unit_of_work: &mut UnitOfWork,
+        new_membership: CreateOrganizationMembership,
+    ) -> Result<OrganizationMembership, RepositoryError> {
+        self.create_membership(new_membership).await
+    }
+
+    async fn delete_membership(
+        &self,
+        organization_id: i32,
+        user_id: i32,
+    ) -> Result<(), RepositoryError> {
+        self.store.write(|tables| {
+            let count = tables.memberships.len();
+            tables.memberships.retain(|membership| {
+                membership.organization_id != organization_id || membership.user_id != user_id
+            });
+            if tables.memberships.len() == count {
+                return Err(RepositoryError::NotFound);
+            }
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,128 @@
+use crate::error::RepositoryError;
+use crate::models::position_category::{
+    CreatePositionCategory, PartialPositionCategory, PositionCategory,
+};
+use crate::repositories::memory::{foreign_key_violation, MemoryStore};
+use crate::repositories::position_category::PositionCategoryRepository;
+use crate::repositories::unit_of_work::UnitOfWork;
+use anyhow::Result;
+use async_trait::async_trait;
+
+#[derive(Debug, Clone)]
+pub struct MemoryPositionCategoryRepository {
+    pub store: MemoryStore,
+}
+
+impl MemoryPositionCategoryRepository {
+    pub fn new(store: MemoryStore) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl PositionCategoryRepository for MemoryPositionCategoryRepository {
+    async fn list_position_categories(
+        &self,
+        organization_id: i32,
+    ) -> Result<Vec<PositionCategory>> {
+        Ok(self.store.read(|tables| {
+            tables
+                .position_categories
+                .iter()
+                .filter(|category| category.organization_id == organization_id)
+                .cloned()
+                .collect()
+        }))
+    }
+
+    async fn get_position_category_by_id(
+        &self,
+        position_category_id: i32,
+    ) -> Result<PositionCategory, RepositoryError> {
+        self.store.read(|tables| {
+            tables
+                .position_categories
+                .iter()
+                .find(|category| category.id == position_category_id)
+                .cloned()
+                .ok_or(RepositoryError::NotFound)
+        })
+    }
+
+    async fn get_position_category_by_id_in(
+        &self,
+        _unit_of_work: &mut UnitOfWork,
+        position_category_id: i32,
+    ) -> Result<PositionCategory, RepositoryError> {
+        self.get_position_category_by_id(position_category_id).await
+    }
+
+    async fn create_position_category(
+        &self,
+        new_position_category: CreatePositionCategory,
+    ) -> Result<PositionCategory, RepositoryError> {
+        self.store.write(|tables| {
+            if !tables
+                .organizations
+                .iter()
+                .any(|organization| organization.id == new_position_category.organization_id)
+            {
+                return Err(foreign_key_violation(
+                    "position_category_organization_id_fkey",
+                ));
+            }
+
+            let position_category = PositionCategory {
+                id: tables.next_id("position_category"),
+                name: new_position_category.name,
+                organization_id: new_position_category.organization_id,
+            };
+            tables.position_categories.push(position_category.clone());
+            Ok(position_category)
+        })
+    }
+
+    async fn delete_position_category(
+        &self,
+        position_category_id: i32,
+    ) -> Result<(), RepositoryError> {
+        self.store.write(|tables| {
+            let count = tables.position_categories.len();
+            tables
+                .position_categories
+                .retain(|category| category.id != position_category_id);
+            if tables.position_categories.len() == count {
+                return Err(RepositoryError::NotFound);
+            }
+
+            let positions: Vec<i32> = tables
+                .job_positions
+                .iter()
+                .filter(|stored| stored.row.position_category_id == position_category_id)
+                .map(|stored| stored.row.id)
+                .collect();
+            for position_id in positions {
+                tables.remove_job_position(position_id);
+            }
+            Ok(())
+        })
+    }
+
+    async fn update_position_category(
+        &self,
+        position_category_id: i32,
+        patch_position_category: PartialPositionCategory,
+    ) -> Result<PositionCategory, RepositoryError> {
+        self.store.write(|tables| {
+            let position_category = tables
+                .position_categories
+                .iter_mut()
+                .find(|category| category.id == position_category_id)
+                .ok_or(RepositoryError::NotFound)?;
+            if let Some(name) = patch_position_category.name {
+                position_category.name = name;
+            }
+            Ok(position_category.clone())
+        })
+    }
+}
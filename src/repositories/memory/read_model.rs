@@ -0,0 +1,230 @@
+use crate::models::event::Event;
+use crate::models::organization::OrganizationRole;
+use crate::models::pagination::{ListSpec, Page};
+use crate::models::read_model::{EmploymentListing, EventListing, JobPositionListing};
+use crate::models::review::ReviewDirection;
+use crate::models::user::{User, UserRole};
+use crate::models::{employment, event, job_position};
+use crate::repositories::memory::{self, live, page, MemoryStore, SortKey, Tables};
+use crate::repositories::read_model::ReadModelRepository;
+use anyhow::Result;
+use async_trait::async_trait;
+
+#[derive(Debug, Clone)]
+pub struct MemoryReadModelRepository {
+    pub store: MemoryStore,
+}
+
+impl MemoryReadModelRepository {
+    pub fn new(store: MemoryStore) -> Self {
+        Self { store }
+    }
+
+    /// Listings of the events matching `filter` that `is_listed` keeps.
+    fn event_listings(
+        &self,
+        filter: event::SelectManyFilter,
+        is_listed: impl Fn(&Tables, &Event) -> bool,
+    ) -> Vec<EventListing> {
+        self.store.read(|tables| {
+            live(&tables.events)
+                .filter(|event| {
+                    memory::event::matches(tables, event, &filter) && is_listed(tables, event)
+                })
+                .filter_map(|event| event_listing(tables, event))
+                .collect()
+        })
+    }
+
+    /// Listings of the employments `is_listed` keeps.
+    fn employment_listings(
+        &self,
+        is_listed: impl Fn(&Tables, &employment::Employment) -> bool,
+    ) -> Vec<EmploymentListing> {
+        self.store.read(|tables| {
+            tables
+                .employments
+                .iter()
+                .filter(|employment| is_listed(tables, employment))
+                .filter_map(|employment| employment_listing(tables, employment))
+                .collect()
+        })
+    }
+}
+
+/// Leaves out drafts `viewer` may not see, like `push_draft_visibility` of the Postgres
+/// read model.
+fn is_visible(tables: &Tables, event: &Event, viewer: Option<&User>) -> bool {
+    let user_id = match viewer {
+        Some(user) if user.role == UserRole::Admin => return true,
+        Some(user) => user.id,
+        None => return !event.is_draft(),
+    };
+    !event.is_draft()
+        || tables.memberships.iter().any(|membership| {
+            membership.organization_id == event.organization_id
+                && membership.user_id == user_id
+                && (membership.role == OrganizationRole::Owner
+                    || (membership.role == OrganizationRole::Organizer
+                        && (event.owner_id == user_id
+                            || tables.event_managers.iter().any(|relation| {
+                                relation.event_id == event.id && relation.user_id == user_id
+                            }))))
+        })
+}
+
+fn event_listing(tables: &Tables, event: &Event) -> Option<EventListing> {
+    Some(EventListing {
+        event: event.clone(),
+        venue: tables.any_venue(event.venue_id)?.clone(),
+        owner: tables.any_user(event.owner_id)?.clone(),
+    })
+}
+
+fn employment_listing(
+    tables: &Tables,
+    employment: &employment::Employment,
+) -> Option<EmploymentListing> {
+    let position = tables.any_job_position(employment.position_id)?;
+    let event = tables.any_event(position.event_id)?;
+    let is_reviewed = |direction: ReviewDirection| {
+        tables
+            .reviews
+            .iter()
+            .any(|review| review.employment_id == employment.id && review.direction == direction)
+    };
+    Some(EmploymentListing {
+        employment: employment.clone(),
+        position: position.clone(),
+        event: event.clone(),
+        venue: tables.any_venue(event.venue_id)?.clone(),
+        employee: tables.any_user(employment.user_id)?.clone(),
+        current_capacity: tables.taken_capacity(position.id),
+        hours_worked: memory::employment::hours_worked(tables, employment.id),
+        reviewed_by_worker: is_reviewed(ReviewDirection::WorkerToOrganizer),
+        reviewed_by_organizer: is_reviewed(ReviewDirection::OrganizerToWorker),
+    })
+}
+
+fn job_sort_key(listing: &EmploymentListing, sort_by: employment::JobsSortColumn) -> SortKey {
+    match sort_by {
+        employment::JobsSortColumn::State => {
+            SortKey::Number(memory::employment::state_order(&listing.employment.state))
+        }
+        employment::JobsSortColumn::JobName => SortKey::Text(listing.position.name.clone()),
+        employment::JobsSortColumn::Event => SortKey::Text(listing.event.name.clone()),
+        employment::JobsSortColumn::DateStart => SortKey::Date(listing.event.date_start),
+        employment::JobsSortColumn::Venue => SortKey::Text(listing.venue.name.clone()),
+        employment::JobsSortColumn::HoursWorked => SortKey::Number(listing.hours_worked as f64),
+        employment::JobsSortColumn::SalaryTaxFree | employment::JobsSortColumn::SalaryTaxed => {
+            SortKey::Number((listing.hours_worked * listing.position.salary) as f64)
+        }
+    }
+}
+
+#[async_trait]
+impl ReadModelRepository for MemoryReadModelRepository {
+    async fn list_event_listings(
+        &self,
+        filter: event::SelectManyFilter,
+        viewer: Option<&User>,
+    ) -> Result<Vec<EventListing>> {
+        let mut listings =
+            self.event_listings(filter, |tables, event| is_visible(tables, event, viewer));
+        listings.sort_by(|a, b| {
+            a.event
+                .date_start
+                .cmp(&b.event.date_start)
+                .then(a.event.id.cmp(&b.event.id))
+        });
+        Ok(listings)
+    }
+
+    async fn list_event_listings_page(
+        &self,
+        filter: event::SelectManyFilter,
+        spec: &ListSpec<event::SortColumn>,
+    ) -> Result<Page<EventListing>> {
+        Ok(page(
+            self.event_listings(filter, |_, _| true),
+            spec,
+            |listing| listing.event.id,
+            |listing| memory::event::sort_key(&listing.event, spec.sort_by),
+        ))
+    }
+
+    async fn list_employment_listings(
+        &self,
+        filter: employment::SelectManyFilter,
+    ) -> Result<Vec<EmploymentListing>> {
+        let mut listings = self.employment_listings(|tables, employment| {
+            memory::employment::matches(tables, employment, &filter)
+        });
+        listings.sort_by_key(|listing| listing.employment.id);
+        Ok(listings)
+    }
+
+    async fn list_employment_listings_page(
+        &self,
+        filter: employment::SelectManyFilter,
+        spec: &ListSpec<employment::SortColumn>,
+    ) -> Result<Page<EmploymentListing>> {
+        let listings = self.employment_listings(|tables, employment| {
+            memory::employment::matches(tables, employment, &filter)
+        });
+        Ok(self.store.read(|tables| {
+            page(
+                listings,
+                spec,
+                |listing| listing.employment.id,
+                |listing| memory::employment::sort_key(tables, &listing.employment, spec.sort_by),
+            )
+        }))
+    }
+
+    async fn list_job_listings_page(
+        &self,
+        filter: employment::JobsFilter,
+        spec: &ListSpec<employment::JobsSortColumn>,
+    ) -> Result<Page<EmploymentListing>> {
+        let listings = self.employment_listings(|tables, employment| {
+            memory::employment::matches_jobs(tables, employment, &filter)
+        });
+        Ok(page(
+            listings,
+            spec,
+            |listing| listing.employment.id,
+            |listing| job_sort_key(listing, spec.sort_by),
+        ))
+    }
+
+    async fn list_job_position_listings_page(
+        &self,
+        filter: job_position::SelectManyFilter,
+        spec: &ListSpec<job_position::SortColumn>,
+    ) -> Result<Page<JobPositionListing>> {
+        Ok(self.store.read(|tables| {
+            let listings = live(&tables.job_positions)
+                .filter(|position| memory::job_position::matches(tables, position, &filter))
+                .filter_map(|position| {
+                    Some(JobPositionListing {
+                        position: position.clone(),
+                        event: tables.any_event(position.event_id)?.clone(),
+                        category: tables
+                            .position_categories
+                            .iter()
+                            .find(|category| category.id == position.position_category_id)?
+                            .clone(),
+                        current_capacity: tables.taken_capacity(position.id),
+                    })
+                })
+                .collect();
+            page(
+                listings,
+                spec,
+                |listing| listing.position.id,
+                |listing| memory::job_position::sort_key(tables, &listing.position, spec.sort_by),
+            )
+        }))
+    }
+}
@@ -0,0 +1,249 @@
+use crate::error::RepositoryError;
+use crate::models::employment::EmploymentState;
+use crate::models::pagination::{ListSpec, Page};
+use crate::models::review::{
+    review_deadline, CreateReview, FlaggedReview, PublishedReview, Review, ReviewDirection,
+    SortColumn, REVIEW_WINDOW_DAYS,
+};
+use crate::repositories::memory::{generic_error, page, MemoryStore, SortKey, Tables};
+use crate::repositories::review::ReviewRepository;
+use anyhow::Result;
+use async_trait::async_trait;
+use sqlx::types::time::{Date, OffsetDateTime};
+use std::cmp::Reverse;
+use time::Duration;
+
+#[derive(Debug, Clone)]
+pub struct MemoryReviewRepository {
+    pub store: MemoryStore,
+}
+
+impl MemoryReviewRepository {
+    pub fn new(store: MemoryStore) -> Self {
+        Self { store }
+    }
+
+    /// Published reviews passing `filter`, newest first.
+    fn published(
+        &self,
+        today: Date,
+        filter: impl Fn(&Tables, &Review) -> bool,
+    ) -> Vec<PublishedReview> {
+        let mut reviews: Vec<PublishedReview> = self.store.read(|tables| {
+            tables
+                .reviews
+                .iter()
+                .filter(|review| is_published(tables, review, today) && filter(tables, review))
+                .filter_map(|review| {
+                    let event = tables.any_event(review.event_id)?;
+                    Some(PublishedReview {
+                        id: review.id,
+                        direction: review.direction,
+                        author_name: full_name(tables, review.author_id),
+                        event_id: event.id,
+                        event_name: event.name.clone(),
+                        overall: review.overall,
+                        punctuality: review.punctuality,
+                        skill: review.skill,
+                        organization: review.organization,
+                        communication: review.communication,
+                        comment: review.comment.clone(),
+                        created_at: review.created_at,
+                    })
+                })
+                .collect()
+        });
+        reviews.sort_by_key(|review| Reverse(review.created_at));
+        reviews
+    }
+
+    fn update(
+        &self,
+        review_id: i32,
+        change: impl FnOnce(&mut Review) -> bool,
+    ) -> Result<Review, RepositoryError> {
+        self.store.write(|tables| {
+            let review = tables
+                .reviews
+                .iter_mut()
+                .find(|review| review.id == review_id)
+                .ok_or(RepositoryError::NotFound)?;
+            if !change(review) {
+                return Err(RepositoryError::NotFound);
+            }
+            Ok(review.clone())
+        })
+    }
+}
+
+fn full_name(tables: &Tables, user_id: Option<i32>) -> Option<String> {
+    user_id
+        .and_then(|user_id| tables.any_user(user_id))
+        .map(|user| format!("{} {}", user.first_name, user.last_name))
+}
+
+/// Reviews are published once both sides have written theirs or the review period is over,
+/// unless an admin hid them.
+fn is_published(tables: &Tables, review: &Review, today: Date) -> bool {
+    let has_counterpart = tables.reviews.iter().any(|counterpart| {
+        counterpart.employment_id == review.employment_id
+            && counterpart.direction != review.direction
+    });
+    let window_passed = tables
+        .any_event(review.event_id)
+        .is_some_and(|event| event.date_end + Duration::days(REVIEW_WINDOW_DAYS as i64) < today);
+    !review.is_hidden && (has_counterpart || window_passed)
+}
+
+#[async_trait]
+impl ReviewRepository for MemoryReviewRepository {
+    async fn create_review(
+        &self,
+        new_review: CreateReview,
+        today: Date,
+    ) -> Result<Review, RepositoryError> {
+        self.store.write(|tables| {
+            let employment = tables.employment(new_review.employment_id)?.clone();
+            let event = tables
+                .employment_event(employment.id)
+                .ok_or(RepositoryError::NotFound)?
+                .clone();
+
+            if employment.state != EmploymentState::Done {
+                return Err(generic_error("Only finished jobs can be reviewed"));
+            }
+            if today > review_deadline(event.date_end) {
+                return Err(generic_error("The review period for this job has ended"));
+            }
+            let subject_id = match new_review.direction {
+                ReviewDirection::OrganizerToWorker => employment.user_id,
+                ReviewDirection::WorkerToOrganizer => event.owner_id,
+            };
+            if tables.reviews.iter().any(|review| {
+                review.employment_id == new_review.employment_id
+                    && review.direction == new_review.direction
+            }) {
+                return Err(generic_error("This job has already been reviewed"));
+            }
+
+            let review = Review {
+                id: tables.next_id("review"),
+                employment_id: new_review.employment_id,
+                direction: new_review.direction,
+                author_id: Some(new_review.author_id),
+                subject_id,
+                event_id: event.id,
+                overall: new_review.overall,
+                punctuality: new_review.punctuality,
+                skill: new_review.skill,
+                organization: new_review.organization,
+                communication: new_review.communication,
+                comment: new_review.comment.trim().to_string(),
+                flagged_at: None,
+                flagged_by: None,
+                flag_reason: None,
+                is_hidden: false,
+                created_at: OffsetDateTime::now_utc(),
+            };
+            tables.reviews.push(review.clone());
+            Ok(review)
+        })
+    }
+
+    async fn list_published_reviews(
+        &self,
+        subject_id: i32,
+        direction: ReviewDirection,
+        organization_id: Option<i32>,
+        today: Date,
+    ) -> Result<Vec<PublishedReview>, RepositoryError> {
+        Ok(self.published(today, |tables, review| {
+            review.subject_id == subject_id
+                && review.direction == direction
+                && organization_id.is_none_or(|organization_id| {
+                    tables
+                        .any_event(review.event_id)
+                        .is_some_and(|event| event.organization_id == organization_id)
+                })
+        }))
+    }
+
+    async fn list_event_reviews(
+        &self,
+        event_id: i32,
+        today: Date,
+    ) -> Result<Vec<PublishedReview>, RepositoryError> {
+        Ok(self.published(today, |_, review| {
+            review.event_id == event_id && review.direction == ReviewDirection::WorkerToOrganizer
+        }))
+    }
+
+    async fn flag_review(
+        &self,
+        review_id: i32,
+        flagged_by: i32,
+        reason: String,
+    ) -> Result<Review, RepositoryError> {
+        let reason = reason.trim();
+        if reason.is_empty() {
+            return Err(generic_error(
+                "Please tell the admins what is wrong with the review",
+            ));
+        }
+        self.update(review_id, |review| {
+            if review.is_hidden {
+                return false;
+            }
+            review.flagged_at = Some(OffsetDateTime::now_utc());
+            review.flagged_by = Some(flagged_by);
+            review.flag_reason = Some(reason.to_string());
+            true
+        })
+    }
+
+    async fn list_flagged_reviews_page(
+        &self,
+        spec: &ListSpec<SortColumn>,
+    ) -> Result<Page<FlaggedReview>, RepositoryError> {
+        let reviews: Vec<FlaggedReview> = self.store.read(|tables| {
+            tables
+                .reviews
+                .iter()
+                .filter_map(|review| {
+                    let flagged_at = review.flagged_at?;
+                    let event = tables.any_event(review.event_id)?;
+                    let subject_name = full_name(tables, Some(review.subject_id))?;
+                    Some(FlaggedReview {
+                        id: review.id,
+                        author_name: full_name(tables, review.author_id),
+                        subject_name,
+                        event_name: event.name.clone(),
+                        overall: review.overall,
+                        comment: review.comment.clone(),
+                        flag_reason: review.flag_reason.clone(),
+                        flagged_by_name: full_name(tables, review.flagged_by),
+                        flagged_at,
+                    })
+                })
+                .collect()
+        });
+        Ok(page(
+            reviews,
+            spec,
+            |review| review.id,
+            |review| match spec.sort_by {
+                SortColumn::FlaggedAt => SortKey::Timestamp(review.flagged_at),
+            },
+        ))
+    }
+
+    async fn moderate_review(&self, review_id: i32, hide: bool) -> Result<Review, RepositoryError> {
+        self.update(review_id, |review| {
+            review.is_hidden = hide;
+            review.flagged_at = None;
+            review.flagged_by = None;
+            review.flag_reason = None;
+            true
+        })
+    }
+}
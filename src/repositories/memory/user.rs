@@ -0,0 +1,335 @@
+use crate::error::RepositoryError;
+use crate::models::audit_log::{AuditAction, AuditEntity};
+use crate::models::pagination::{ListSpec, Page};
+use crate::models::trash;
+use crate::models::user::{CreateUser, PartialUser, SelectManyFilter, SortColumn, User};
+use crate::repositories::memory::{
+    contains, deleted_page, generic_error, live, page, unique_violation, MemoryStore, SortKey,
+    Stored, Tables,
+};
+use crate::repositories::unit_of_work::UnitOfWork;
+use crate::repositories::user::UserRepository;
+use anyhow::Result;
+use async_trait::async_trait;
+use sqlx::types::time::OffsetDateTime;
+use std::sync::Arc;
+
+#[derive(Debug, Clone)]
+pub struct MemoryUserRepository {
+    pub store: MemoryStore,
+    pub actor_id: Option<i32>,
+}
+
+impl MemoryUserRepository {
+    pub fn new(store: MemoryStore) -> Self {
+        Self {
+            store,
+            actor_id: None,
+        }
+    }
+
+    fn list(&self, filter: SelectManyFilter) -> Vec<User> {
+        self.store.read(|tables| {
+            live(&tables.users)
+                .filter(|user| matches(tables, user, &filter))
+                .cloned()
+                .collect()
+        })
+    }
+}
+
+fn matches(tables: &Tables, user: &User, filter: &SelectManyFilter) -> bool {
+    filter
+        .organization_id
+        .is_none_or(|organization_id| tables.is_member(organization_id, user.id))
+        && filter
+            .username
+            .as_ref()
+            .is_none_or(|username| &user.username == username)
+        && filter.role.as_ref().is_none_or(|role| &user.role == role)
+        && filter
+            .gender
+            .as_ref()
+            .is_none_or(|gender| &user.gender == gender)
+        && filter
+            .first_name
+            .as_ref()
+            .is_none_or(|first_name| &user.first_name == first_name)
+        && filter
+            .last_name
+            .as_ref()
+            .is_none_or(|last_name| &user.last_name == last_name)
+        && filter
+            .tax_rate
+            .is_none_or(|tax_rate| user.tax_rate == tax_rate)
+        && filter
+            .last_name_contains
+            .as_ref()
+            .is_none_or(|last_name| contains(&user.last_name, last_name))
+        && filter
+            .username_contains
+            .as_ref()
+            .is_none_or(|username| contains(&user.username, username))
+}
+
+fn sort_key(user: &User, sort_by: SortColumn) -> SortKey {
+    match sort_by {
+        SortColumn::Name => SortKey::Text(format!("{} {}", user.last_name, user.first_name)),
+        SortColumn::Username => SortKey::Text(user.username.clone()),
+        SortColumn::Role => SortKey::Number(user.role.clone() as i32 as f64),
+        SortColumn::BirthDate => SortKey::Date(user.birth_date),
+        SortColumn::Id => SortKey::Number(user.id as f64),
+    }
+}
+
+/// Checks the unique constraints of the `user` table, which soft deleted users still take part in.
+pub(crate) fn check_unique(
+    tables: &Tables,
+    user_id: Option<i32>,
+    username: &str,
+    email: &str,
+) -> Result<(), RepositoryError> {
+    let others: Vec<&User> = tables
+        .users
+        .iter()
+        .map(|stored| &stored.row)
+        .filter(|user| Some(user.id) != user_id)
+        .collect();
+    if others.iter().any(|user| user.username == username) {
+        return Err(unique_violation("user_username_key"));
+    }
+    if others.iter().any(|user| user.email == email) {
+        return Err(unique_violation("user_email_key"));
+    }
+    Ok(())
+}
+
+#[async_trait]
+impl UserRepository for MemoryUserRepository {
+    fn with_actor(&self, actor_id: i32) -> Arc<dyn UserRepository> {
+        Arc::new(Self {
+            store: self.store.clone(),
+            actor_id: Some(actor_id),
+        })
+    }
+
+    async fn list_users(&self, filter: SelectManyFilter) -> Result<Vec<User>> {
+        Ok(self.list(filter))
+    }
+
+    async fn list_users_page(
+        &self,
+        filter: SelectManyFilter,
+        spec: &ListSpec<SortColumn>,
+    ) -> Result<Page<User>> {
+        Ok(page(
+            self.list(filter),
+            spec,
+            |user| user.id,
+            |user| sort_key(user, spec.sort_by),
+        ))
+    }
+
+    async fn get_user_by_id(&self, user_id: i32) -> Result<User, RepositoryError> {
+        self.store.read(|tables| tables.user(user_id).cloned())
+    }
+
+    async fn get_user_by_username(&self, username: String) -> Result<User, RepositoryError> {
+        self.store.read(|tables| {
+            live(&tables.users)
+                .find(|user| user.username == username)
+                .cloned()
+                .ok_or(RepositoryError::NotFound)
+        })
+    }
+
+    async fn create_user(&self, new_user: CreateUser) -> Result<User, RepositoryError> {
+        self.store.write(|tables| {
+            check_unique(tables, None, &new_user.username, &new_user.email)?;
+            let user = User {
+                id: tables.next_id("user"),
+                first_name: new_user.first_name,
+                last_name: new_user.last_name,
+                username: new_user.username,
+                gender: new_user.gender,
+                birth_date: new_user.birth_date,
+                email: new_user.email,
+                phone: new_user.phone,
+                password_hash: new_user.password_hash,
+                role: new_user.role,
+                tax_rate: new_user.tax_rate,
+                avatar_url: new_user.avatar_url,
+            };
+            tables.users.push(Stored::new(user.clone()));
+            tables.audit(
+                self.actor_id,
+                AuditEntity::User,
+                user.id,
+                None,
+                AuditAction::Create,
+                None,
+                Some(&user),
+            )?;
+            Ok(user)
+        })
+    }
+
+    async fn create_user_in(
+        &self,
+        _unit_of_work: &mut UnitOfWork,
+        new_user: CreateUser,
+    ) -> Result<User, RepositoryError> {
+        self.create_user(new_user).await
+    }
+
+    async fn delete_user(&self, user_id: i32) -> Result<(), RepositoryError> {
+        self.store.write(|tables| {
+            let user = tables.user(user_id)?.clone();
+            if live(&tables.events).any(|event| event.owner_id == user_id) {
+                return Err(generic_error("The user still owns events"));
+            }
+            if let Some(stored) = tables
+                .users
+                .iter_mut()
+                .find(|stored| stored.row.id == user_id)
+            {
+                stored.deleted_at = Some(OffsetDateTime::now_utc());
+            }
+            tables.audit(
+                self.actor_id,
+                AuditEntity::User,
+                user.id,
+                None,
+                AuditAction::Delete,
+                Some(&user),
+                None,
+            )
+        })
+    }
+
+    async fn update_user(
+        &self,
+        user_id: i32,
+        patch_user: PartialUser,
+    ) -> Result<User, RepositoryError> {
+        self.store.write(|tables| {
+            let before = tables.user(user_id)?.clone();
+            let user = User {
+                id: before.id,
+                first_name: patch_user.first_name.unwrap_or(before.first_name.clone()),
+                last_name: patch_user.last_name.unwrap_or(before.last_name.clone()),
+                username: patch_user.username.unwrap_or(before.username.clone()),
+                gender: patch_user.gender.unwrap_or(before.gender.clone()),
+                birth_date: patch_user.birth_date.unwrap_or(before.birth_date),
+                email: patch_user.email.unwrap_or(before.email.clone()),
+                phone: patch_user.phone.unwrap_or(before.phone.clone()),
+                password_hash: patch_user
+                    .password_hash
+                    .unwrap_or(before.password_hash.clone()),
+                role: patch_user.role.unwrap_or(before.role.clone()),
+                tax_rate: patch_user.tax_rate.unwrap_or(before.tax_rate),
+                avatar_url: patch_user.avatar_url.or(before.avatar_url.clone()),
+            };
+            check_unique(tables, Some(user_id), &user.username, &user.email)?;
+            if let Some(stored) = tables
+                .users
+                .iter_mut()
+                .find(|stored| stored.row.id == user_id)
+            {
+                stored.row = user.clone();
+            }
+            tables.audit(
+                self.actor_id,
+                AuditEntity::User,
+                user.id,
+                None,
+                AuditAction::Update,
+                Some(&before),
+                Some(&user),
+            )?;
+            Ok(user)
+        })
+    }
+
+    async fn list_deleted_users(
+        &self,
+        organization_id: i32,
+        spec: &ListSpec<trash::SortColumn>,
+    ) -> Result<Page<User>, RepositoryError> {
+        Ok(self.store.read(|tables| {
+            deleted_page(
+                &tables.users,
+                spec,
+                |user| user.id,
+                |user| tables.is_member(organization_id, user.id),
+            )
+        }))
+    }
+
+    async fn restore_user(
+        &self,
+        user_id: i32,
+        organization_id: i32,
+    ) -> Result<User, RepositoryError> {
+        self.store.write(|tables| {
+            if !tables.is_member(organization_id, user_id) {
+                return Err(RepositoryError::NotFound);
+            }
+            let stored = tables
+                .users
+                .iter_mut()
+                .find(|stored| stored.row.id == user_id && stored.is_deleted())
+                .ok_or(RepositoryError::NotFound)?;
+            stored.deleted_at = None;
+            let user = stored.row.clone();
+            tables.audit(
+                self.actor_id,
+                AuditEntity::User,
+                user.id,
+                None,
+                AuditAction::Restore,
+                None,
+                Some(&user),
+            )?;
+            Ok(user)
+        })
+    }
+
+    async fn purge_user(&self, user_id: i32, organization_id: i32) -> Result<(), RepositoryError> {
+        self.store.write(|tables| {
+            if !tables.is_member(organization_id, user_id) {
+                return Err(RepositoryError::NotFound);
+            }
+            let has_history = tables.worked_hours.iter().any(|hours| {
+                tables
+                    .employment(hours.employment_id)
+                    .is_ok_and(|employment| employment.user_id == user_id)
+            }) || tables
+                .events
+                .iter()
+                .any(|stored| stored.row.owner_id == user_id);
+            if has_history {
+                return Err(generic_error(
+                    "The user has logged worked hours or owns events and cannot be purged",
+                ));
+            }
+
+            let user = tables
+                .users
+                .iter()
+                .find(|stored| stored.row.id == user_id && stored.is_deleted())
+                .map(|stored| stored.row.clone())
+                .ok_or(RepositoryError::NotFound)?;
+            tables.remove_user(user_id);
+            tables.audit(
+                self.actor_id,
+                AuditEntity::User,
+                user.id,
+                None,
+                AuditAction::Purge,
+                Some(&user),
+                None,
+            )
+        })
+    }
+}
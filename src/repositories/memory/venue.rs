@@ -0,0 +1,292 @@
+use crate::error::RepositoryError;
+use crate::models::audit_log::{AuditAction, AuditEntity};
+use crate::models::pagination::{ListSpec, Page};
+use crate::models::trash;
+use crate::models::venue::{CreateVenue, PartialVenue, SelectManyFilter, SortColumn, Venue};
+use crate::repositories::memory::{
+    deleted_page, generic_error, live, page, MemoryStore, SortKey, Stored,
+};
+use crate::repositories::venue::VenueRepository;
+use anyhow::Result;
+use async_trait::async_trait;
+use sqlx::types::time::OffsetDateTime;
+use std::sync::Arc;
+
+#[derive(Debug, Clone)]
+pub struct MemoryVenueRepository {
+    pub store: MemoryStore,
+    pub actor_id: Option<i32>,
+}
+
+impl MemoryVenueRepository {
+    pub fn new(store: MemoryStore) -> Self {
+        Self {
+            store,
+            actor_id: None,
+        }
+    }
+
+    /// Distinct values of a column of the live venues, of one organization if given.
+    fn distinct(&self, organization_id: Option<i32>, value: fn(&Venue) -> &String) -> Vec<String> {
+        let mut values: Vec<String> = self.store.read(|tables| {
+            live(&tables.venues)
+                .filter(|venue| organization_id.is_none_or(|id| venue.organization_id == id))
+                .map(|venue| value(venue).clone())
+                .collect()
+        });
+        values.sort();
+        values.dedup();
+        values
+    }
+}
+
+fn matches(venue: &Venue, filter: &SelectManyFilter) -> bool {
+    let equals = |expected: &Option<String>, actual: &String| {
+        expected.as_ref().is_none_or(|expected| expected == actual)
+    };
+    filter
+        .organization_id
+        .is_none_or(|organization_id| venue.organization_id == organization_id)
+        && equals(&filter.name, &venue.name)
+        && equals(&filter.state, &venue.state)
+        && equals(&filter.street_name, &venue.street_name)
+        && equals(&filter.postal_code, &venue.postal_code)
+        && equals(&filter.town, &venue.town)
+        && equals(&filter.street_number, &venue.street_number)
+}
+
+fn sort_key(venue: &Venue, sort_by: SortColumn) -> SortKey {
+    match sort_by {
+        SortColumn::Name => SortKey::Text(venue.name.clone()),
+        SortColumn::State => SortKey::Text(venue.state.clone()),
+        SortColumn::Town => SortKey::Text(venue.town.clone()),
+    }
+}
+
+#[async_trait]
+impl VenueRepository for MemoryVenueRepository {
+    fn with_actor(&self, actor_id: i32) -> Arc<dyn VenueRepository> {
+        Arc::new(Self {
+            store: self.store.clone(),
+            actor_id: Some(actor_id),
+        })
+    }
+
+    async fn list_venues(&self, filter: SelectManyFilter) -> Result<Vec<Venue>> {
+        Ok(self.store.read(|tables| {
+            live(&tables.venues)
+                .filter(|venue| matches(venue, &filter))
+                .cloned()
+                .collect()
+        }))
+    }
+
+    async fn list_venues_page(
+        &self,
+        filter: SelectManyFilter,
+        spec: &ListSpec<SortColumn>,
+    ) -> Result<Page<Venue>> {
+        Ok(page(
+            self.list_venues(filter).await?,
+            spec,
+            |venue| venue.id,
+            |venue| sort_key(venue, spec.sort_by),
+        ))
+    }
+
+    async fn get_venue_by_id(&self, venue_id: i32) -> Result<Venue, RepositoryError> {
+        self.store.read(|tables| tables.venue(venue_id).cloned())
+    }
+
+    async fn create_venue(&self, new_location: CreateVenue) -> Result<Venue, RepositoryError> {
+        self.store.write(|tables| {
+            let venue = Venue {
+                id: tables.next_id("venue"),
+                name: new_location.name,
+                description: new_location.description,
+                state: new_location.state,
+                postal_code: new_location.postal_code,
+                town: new_location.town,
+                street_name: new_location.street_name,
+                street_number: new_location.street_number,
+                address_url: new_location.address_url,
+                organization_id: new_location.organization_id,
+            };
+            tables.venues.push(Stored::new(venue.clone()));
+            tables.audit(
+                self.actor_id,
+                AuditEntity::Venue,
+                venue.id,
+                None,
+                AuditAction::Create,
+                None,
+                Some(&venue),
+            )?;
+            Ok(venue)
+        })
+    }
+
+    async fn delete_venue(&self, location_id: i32) -> Result<(), RepositoryError> {
+        self.store.write(|tables| {
+            let venue = tables.venue(location_id)?.clone();
+            if live(&tables.events).any(|event| event.venue_id == location_id) {
+                return Err(generic_error("The venue is associated with events"));
+            }
+            if let Some(stored) = tables
+                .venues
+                .iter_mut()
+                .find(|stored| stored.row.id == location_id)
+            {
+                stored.deleted_at = Some(OffsetDateTime::now_utc());
+            }
+            tables.audit(
+                self.actor_id,
+                AuditEntity::Venue,
+                venue.id,
+                None,
+                AuditAction::Delete,
+                Some(&venue),
+                None,
+            )
+        })
+    }
+
+    async fn update_venue(
+        &self,
+        venue_id: i32,
+        patch_venue: PartialVenue,
+    ) -> Result<Venue, RepositoryError> {
+        self.store.write(|tables| {
+            let before = tables.venue(venue_id)?.clone();
+            let venue = Venue {
+                id: before.id,
+                name: patch_venue.name.unwrap_or(before.name.clone()),
+                description: patch_venue.description.or(before.description.clone()),
+                state: patch_venue.state.unwrap_or(before.state.clone()),
+                postal_code: patch_venue
+                    .postal_code
+                    .unwrap_or(before.postal_code.clone()),
+                town: patch_venue.town.unwrap_or(before.town.clone()),
+                street_name: patch_venue
+                    .street_name
+                    .unwrap_or(before.street_name.clone()),
+                street_number: patch_venue
+                    .street_number
+                    .unwrap_or(before.street_number.clone()),
+                address_url: patch_venue.address_url.or(before.address_url.clone()),
+                organization_id: before.organization_id,
+            };
+            if let Some(stored) = tables
+                .venues
+                .iter_mut()
+                .find(|stored| stored.row.id == venue_id)
+            {
+                stored.row = venue.clone();
+            }
+            tables.audit(
+                self.actor_id,
+                AuditEntity::Venue,
+                venue.id,
+                None,
+                AuditAction::Update,
+                Some(&before),
+                Some(&venue),
+            )?;
+            Ok(venue)
+        })
+    }
+
+    async fn list_states(&self, organization_id: Option<i32>) -> Result<Vec<String>> {
+        Ok(self.distinct(organization_id, |venue| &venue.state))
+    }
+
+    async fn list_cities(&self, organization_id: Option<i32>) -> Result<Vec<String>> {
+        Ok(self.distinct(organization_id, |venue| &venue.town))
+    }
+
+    async fn list_deleted_venues(
+        &self,
+        organization_id: i32,
+        spec: &ListSpec<trash::SortColumn>,
+    ) -> Result<Page<Venue>, RepositoryError> {
+        Ok(self.store.read(|tables| {
+            deleted_page(
+                &tables.venues,
+                spec,
+                |venue| venue.id,
+                |venue| venue.organization_id == organization_id,
+            )
+        }))
+    }
+
+    async fn restore_venue(
+        &self,
+        venue_id: i32,
+        organization_id: i32,
+    ) -> Result<Venue, RepositoryError> {
+        self.store.write(|tables| {
+            if tables
+                .any_venue(venue_id)
+                .is_none_or(|venue| venue.organization_id != organization_id)
+            {
+                return Err(RepositoryError::NotFound);
+            }
+            let stored = tables
+                .venues
+                .iter_mut()
+                .find(|stored| stored.row.id == venue_id && stored.is_deleted())
+                .ok_or(RepositoryError::NotFound)?;
+            stored.deleted_at = None;
+            let venue = stored.row.clone();
+            tables.audit(
+                self.actor_id,
+                AuditEntity::Venue,
+                venue.id,
+                None,
+                AuditAction::Restore,
+                None,
+                Some(&venue),
+            )?;
+            Ok(venue)
+        })
+    }
+
+    async fn purge_venue(
+        &self,
+        venue_id: i32,
+        organization_id: i32,
+    ) -> Result<(), RepositoryError> {
+        self.store.write(|tables| {
+            if tables
+                .any_venue(venue_id)
+                .is_none_or(|venue| venue.organization_id != organization_id)
+            {
+                return Err(RepositoryError::NotFound);
+            }
+            if tables
+                .events
+                .iter()
+                .any(|stored| stored.row.venue_id == venue_id)
+            {
+                return Err(generic_error(
+                    "The venue is associated with events and cannot be purged",
+                ));
+            }
+            let index = tables
+                .venues
+                .iter()
+                .position(|stored| stored.row.id == venue_id && stored.is_deleted())
+                .ok_or(RepositoryError::NotFound)?;
+            let venue = tables.venues.remove(index).row;
+            tables.audit(
+                self.actor_id,
+                AuditEntity::Venue,
+                venue.id,
+                None,
+                AuditAction::Purge,
+                Some(&venue),
+                None,
+            )
+        })
+    }
+}
@@ -0,0 +1,128 @@
+use crate::error::RepositoryError;
+use crate::models::employment::EmploymentState;
+use crate::models::worked_hours::{
+    CreateWorkedHours, PartialWorkedHours, SelectManyFilter, WorkedHours,
+};
+use crate::repositories::memory::{foreign_key_violation, generic_error, MemoryStore, Tables};
+use crate::repositories::worked_hours::WorkedHoursRepository;
+use async_trait::async_trait;
+
+#[derive(Debug, Clone)]
+pub struct MemoryWorkedHoursRepository {
+    pub store: MemoryStore,
+}
+
+impl MemoryWorkedHoursRepository {
+    pub fn new(store: MemoryStore) -> Self {
+        Self { store }
+    }
+}
+
+fn check_add(tables: &Tables, new_worked: &CreateWorkedHours) -> Result<(), RepositoryError> {
+    let employment = tables.employment(new_worked.employment_id)?;
+    let job_position = tables.job_position(employment.position_id)?;
+    let event = tables.event(job_position.event_id)?;
+
+    if employment.state != EmploymentState::Accepted {
+        return Err(generic_error("logging hours job in wrong state"));
+    }
+    if event.date_start > new_worked.date || event.date_end < new_worked.date {
+        return Err(generic_error("logging hours date outside of events date"));
+    }
+    if tables.worked_hours.iter().any(|worked| {
+        worked.employment_id == new_worked.employment_id && worked.date == new_worked.date
+    }) {
+        return Err(generic_error(
+            "cannot log worked hours for one job in same day twice",
+        ));
+    }
+    Ok(())
+}
+
+#[async_trait]
+impl WorkedHoursRepository for MemoryWorkedHoursRepository {
+    async fn list_worked_hours(
+        &self,
+        filter: SelectManyFilter,
+    ) -> Result<Vec<WorkedHours>, RepositoryError> {
+        Ok(self.store.read(|tables| {
+            tables
+                .worked_hours
+                .iter()
+                .filter(|worked| {
+                    filter
+                        .employment_id
+                        .is_none_or(|employment_id| worked.employment_id == employment_id)
+                        && filter
+                            .hours_worked
+                            .is_none_or(|hours_worked| worked.hours_worked == hours_worked)
+                        && filter.date.is_none_or(|date| worked.date == date)
+                })
+                .cloned()
+                .collect()
+        }))
+    }
+
+    async fn get_worked_hours_by_id(&self, worked_id: i32) -> Result<WorkedHours, RepositoryError> {
+        self.store.read(|tables| {
+            tables
+                .worked_hours
+                .iter()
+                .find(|worked| worked.id == worked_id)
+                .cloned()
+                .ok_or(RepositoryError::NotFound)
+        })
+    }
+
+    async fn create_worked_hours(
+        &self,
+        new_worked: CreateWorkedHours,
+    ) -> Result<WorkedHours, RepositoryError> {
+        self.store.write(|tables| {
+            check_add(tables, &new_worked)?;
+            let worked = WorkedHours {
+                id: tables.next_id("worked_hours"),
+                date: new_worked.date,
+                hours_worked: new_worked.hours_worked,
+                employment_id: new_worked.employment_id,
+            };
+            tables.worked_hours.push(worked.clone());
+            Ok(worked)
+        })
+    }
+
+    async fn delete_worked_hours(&self, worked_id: i32) -> Result<(), RepositoryError> {
+        self.store.write(|tables| {
+            let count = tables.worked_hours.len();
+            tables.worked_hours.retain(|worked| worked.id != worked_id);
+            if tables.worked_hours.len() == count {
+                return Err(RepositoryError::NotFound);
+            }
+            Ok(())
+        })
+    }
+
+    async fn update_worked_hours(
+        &self,
+        worked_id: i32,
+        patch_worked: PartialWorkedHours,
+    ) -> Result<WorkedHours, RepositoryError> {
+        self.store.write(|tables| {
+            if let Some(employment_id) = patch_worked.employment_id {
+                if tables.employment(employment_id).is_err() {
+                    return Err(foreign_key_violation("worked_hours_employment_id_fkey"));
+                }
+            }
+
+            let worked = tables
+                .worked_hours
+                .iter_mut()
+                .find(|worked| worked.id == worked_id)
+                .ok_or(RepositoryError::NotFound)?;
+            worked.hours_worked = patch_worked.hours_worked.unwrap_or(worked.hours_worked);
+            worked.date = patch_worked.date.unwrap_or(worked.date);
+            worked.employment_id = patch_worked.employment_id.unwrap_or(worked.employment_id);
+            Ok(worked.clone())
+        })
+    }
+}
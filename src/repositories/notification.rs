@@ -0,0 +1,102 @@
+use crate::error::RepositoryError;
+use crate::models::notification::{CreateNotification, Notification};
+use crate::repositories::pool_handler::PoolHandler;
+use crate::repositories::unit_of_work::UnitOfWork;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait NotificationRepository: Send + Sync {
+    async fn list_notifications(&self, user_id: i32) -> Result<Vec<Notification>, RepositoryError>;
+    async fn create_notification(
+        &self,
+        new_notification: CreateNotification,
+    ) -> Result<Notification, RepositoryError>;
+    async fn create_notification_in(
+        &self,
+        unit_of_work: &mut UnitOfWork,
+        new_notification: CreateNotification,
+    ) -> Result<Notification, RepositoryError>;
+    async fn mark_notifications_read(&self, user_id: i32) -> Result<(), RepositoryError>;
+}
+
+#[derive(Debug, Clone)]
+pub struct PgNotificationRepository {
+    pub pool_handler: PoolHandler,
+}
+
+impl PgNotificationRepository {
+    pub fn new(pool_handler: PoolHandler) -> Self {
+        Self { pool_handler }
+    }
+}
+
+#[async_trait]
+impl NotificationRepository for PgNotificationRepository {
+    async fn list_notifications(&self, user_id: i32) -> Result<Vec<Notification>, RepositoryError> {
+        let notifications = sqlx::query_as!(
+            Notification,
+            r#"SELECT
+                "id",
+                "user_id",
+                "message",
+                "created_at",
+                "read_at"
+            FROM "notification"
+            WHERE "user_id" = $1
+            ORDER BY "created_at" DESC, "id" DESC"#,
+            user_id
+        )
+        .fetch_all(self.pool_handler.pool())
+        .await?;
+
+        Ok(notifications)
+    }
+
+    async fn create_notification(
+        &self,
+        new_notification: CreateNotification,
+    ) -> Result<Notification, RepositoryError> {
+        let mut unit_of_work = UnitOfWork::begin(&self.pool_handler).await?;
+        let notification = self
+            .create_notification_in(&mut unit_of_work, new_notification)
+            .await?;
+        unit_of_work.commit().await?;
+        Ok(notification)
+    }
+
+    async fn create_notification_in(
+        &self,
+        unit_of_work: &mut UnitOfWork,
+        new_notification: CreateNotification,
+    ) -> Result<Notification, RepositoryError> {
+        let notification = sqlx::query_as!(
+            Notification,
+            r#"INSERT INTO "notification" ("user_id", "message")
+            VALUES ($1, $2)
+            RETURNING
+                "id",
+                "user_id",
+                "message",
+                "created_at",
+                "read_at""#,
+            new_notification.user_id,
+            new_notification.message
+        )
+        .fetch_one(unit_of_work.connection()?)
+        .await?;
+
+        Ok(notification)
+    }
+
+    async fn mark_notifications_read(&self, user_id: i32) -> Result<(), RepositoryError> {
+        sqlx::query!(
+            r#"UPDATE "notification" SET "read_at" = NOW()
+            WHERE "user_id" = $1 AND "read_at" IS NULL"#,
+            user_id
+        )
+        .execute(self.pool_handler.pool())
+        .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,260 @@
+use crate::error::RepositoryError;
+use crate::models::organization::{
+    CreateOrganization, CreateOrganizationMembership, Organization, OrganizationMembership,
+    OrganizationRole,
+};
+use crate::repositories::pool_handler::PoolHandler;
+use crate::repositories::unit_of_work::UnitOfWork;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait OrganizationRepository: Send + Sync {
+    async fn list_organizations(&self) -> Result<Vec<Organization>, RepositoryError>;
+    async fn list_user_organizations(
+        &self,
+        user_id: i32,
+    ) -> Result<Vec<Organization>, RepositoryError>;
+    async fn get_organization_by_id(
+        &self,
+        organization_id: i32,
+    ) -> Result<Organization, RepositoryError>;
+    async fn get_organization_by_invite_code(
+        &self,
+        invite_code: &str,
+    ) -> Result<Organization, RepositoryError>;
+    async fn create_organization(
+        &self,
+        new_organization: CreateOrganization,
+    ) -> Result<Organization, RepositoryError>;
+    async fn get_membership(
+        &self,
+        organization_id: i32,
+        user_id: i32,
+    ) -> Result<OrganizationMembership, RepositoryError>;
+    async fn create_membership(
+        &self,
+        new_membership: CreateOrganizationMembership,
+    ) -> Result<OrganizationMembership, RepositoryError>;
+    async fn create_membership_in(
+        &self,
+        unit_of_work: &mut UnitOfWork,
+        new_membership: CreateOrganizationMembership,
+    ) -> Result<OrganizationMembership, RepositoryError>;
+    async fn delete_membership(
+        &self,
+        organization_id: i32,
+        user_id: i32,
+    ) -> Result<(), RepositoryError>;
+}
+
+#[derive(Debug, Clone)]
+pub struct PgOrganizationRepository {
+    pub pool_handler: PoolHandler,
+}
+
+impl PgOrganizationRepository {
+    pub fn new(pool_handler: PoolHandler) -> Self {
+        Self { pool_handler }
+    }
+}
+
+#[async_trait]
+impl OrganizationRepository for PgOrganizationRepository {
+    async fn list_organizations(&self) -> Result<Vec<Organization>, RepositoryError> {
+        let organizations = sqlx::query_as!(
+            Organization,
+            r#"SELECT
+                "id",
+                "name",
+                "invite_code",
+                "created_at"
+            FROM "organization"
+            ORDER BY "name""#
+        )
+        .fetch_all(self.pool_handler.pool())
+        .await?;
+
+        Ok(organizations)
+    }
+
+    async fn list_user_organizations(
+        &self,
+        user_id: i32,
+    ) -> Result<Vec<Organization>, RepositoryError> {
+        let organizations = sqlx::query_as!(
+            Organization,
+            r#"SELECT
+                "organization"."id",
+                "organization"."name",
+                "organization"."invite_code",
+                "organization"."created_at"
+            FROM "organization"
+            JOIN "organization_membership"
+                ON "organization_membership"."organization_id" = "organization"."id"
+            WHERE "organization_membership"."user_id" = $1
+            ORDER BY "organization"."name""#,
+            user_id
+        )
+        .fetch_all(self.pool_handler.pool())
+        .await?;
+
+        Ok(organizations)
+    }
+
+    async fn get_organization_by_id(
+        &self,
+        organization_id: i32,
+    ) -> Result<Organization, RepositoryError> {
+        let organization = sqlx::query_as!(
+            Organization,
+            r#"SELECT
+                "id",
+                "name",
+                "invite_code",
+                "created_at"
+            FROM "organization"
+            WHERE "id" = $1"#,
+            organization_id
+        )
+        .fetch_optional(self.pool_handler.pool())
+        .await?;
+
+        organization.ok_or(RepositoryError::NotFound)
+    }
+
+    async fn get_organization_by_invite_code(
+        &self,
+        invite_code: &str,
+    ) -> Result<Organization, RepositoryError> {
+        let organization = sqlx::query_as!(
+            Organization,
+            r#"SELECT
+                "id",
+                "name",
+                "invite_code",
+                "created_at"
+            FROM "organization"
+            WHERE "invite_code" = $1"#,
+            invite_code
+        )
+        .fetch_optional(self.pool_handler.pool())
+        .await?;
+
+        organization.ok_or(RepositoryError::NotFound)
+    }
+
+    async fn create_organization(
+        &self,
+        new_organization: CreateOrganization,
+    ) -> Result<Organization, RepositoryError> {
+        let existing = sqlx::query_scalar!(
+            r#"SELECT EXISTS (SELECT 1 FROM "organization" WHERE "name" = $1) AS "exists!""#,
+            new_organization.name
+        )
+        .fetch_one(self.pool_handler.pool())
+        .await?;
+
+        if existing {
+            return Err(RepositoryError::GenericError(
+                "An organization with this name already exists".to_string(),
+            ));
+        }
+
+        let organization = sqlx::query_as!(
+            Organization,
+            r#"INSERT INTO "organization" ("name")
+            VALUES ($1)
+            RETURNING
+                "id",
+                "name",
+                "invite_code",
+                "created_at""#,
+            new_organization.name
+        )
+        .fetch_one(self.pool_handler.pool())
+        .await?;
+
+        Ok(organization)
+    }
+
+    async fn get_membership(
+        &self,
+        organization_id: i32,
+        user_id: i32,
+    ) -> Result<OrganizationMembership, RepositoryError> {
+        let membership = sqlx::query_as!(
+            OrganizationMembership,
+            r#"SELECT
+                "organization_id",
+                "user_id",
+                "role" AS "role: OrganizationRole",
+                "created_at"
+            FROM "organization_membership"
+            WHERE "organization_id" = $1 AND "user_id" = $2"#,
+            organization_id,
+            user_id
+        )
+        .fetch_optional(self.pool_handler.pool())
+        .await?;
+
+        membership.ok_or(RepositoryError::NotFound)
+    }
+
+    async fn create_membership(
+        &self,
+        new_membership: CreateOrganizationMembership,
+    ) -> Result<OrganizationMembership, RepositoryError> {
+        let mut unit_of_work = UnitOfWork::begin(&self.pool_handler).await?;
+        let membership = self
+            .create_membership_in(&mut unit_of_work, new_membership)
+            .await?;
+        unit_of_work.commit().await?;
+        Ok(membership)
+    }
+
+    async fn create_membership_in(
+        &self,
+        unit_of_work: &mut UnitOfWork,
+        new_membership: CreateOrganizationMembership,
+    ) -> Result<OrganizationMembership, RepositoryError> {
+        let membership = sqlx::query_as!(
+            OrganizationMembership,
+            r#"INSERT INTO "organization_membership" ("organization_id", "user_id", "role")
+            VALUES ($1, $2, $3)
+            ON CONFLICT ("organization_id", "user_id") DO UPDATE SET "role" = EXCLUDED."role"
+            RETURNING
+                "organization_id",
+                "user_id",
+                "role" AS "role: OrganizationRole",
+                "created_at""#,
+            new_membership.organization_id,
+            new_membership.user_id,
+            new_membership.role as OrganizationRole
+        )
+        .fetch_one(unit_of_work.connection()?)
+        .await?;
+
+        Ok(membership)
+    }
+
+    async fn delete_membership(
+        &self,
+        organization_id: i32,
+        user_id: i32,
+    ) -> Result<(), RepositoryError> {
+        let result = sqlx::query!(
+            r#"DELETE FROM "organization_membership"
+            WHERE "organization_id" = $1 AND "user_id" = $2"#,
+            organization_id,
+            user_id
+        )
+        .execute(self.pool_handler.pool())
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+}
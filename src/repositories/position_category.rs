@@ -2,18 +2,31 @@ use crate::error::RepositoryError;
 use crate::models::position_category::{
     CreatePositionCategory, PartialPositionCategory, PositionCategory,
 };
+use crate::repositories::columns::column;
 use crate::repositories::pool_handler::PoolHandler;
+use crate::repositories::unit_of_work::UnitOfWork;
 use anyhow::Result;
 use async_trait::async_trait;
+use sqlx::postgres::PgRow;
+use sqlx::Row;
+
+pub(crate) const POSITION_CATEGORY_COLUMNS: &[&str] = &["id", "name", "organization_id"];
 
 #[async_trait]
 #[allow(dead_code)]
-pub trait PositionCategoryRepository {
-    async fn list_position_categories(&self) -> Result<Vec<PositionCategory>>;
+pub trait PositionCategoryRepository: Send + Sync {
+    async fn list_position_categories(&self, organization_id: i32)
+        -> Result<Vec<PositionCategory>>;
     async fn get_position_category_by_id(
         &self,
         position_category_id: i32,
     ) -> Result<PositionCategory, RepositoryError>;
+    /// Reads the category and keeps it from changing until the unit of work ends.
+    async fn get_position_category_by_id_in(
+        &self,
+        unit_of_work: &mut UnitOfWork,
+        position_category_id: i32,
+    ) -> Result<PositionCategory, RepositoryError>;
     async fn create_position_category(
         &self,
         new_position_category: CreatePositionCategory,
@@ -40,15 +53,34 @@ impl PgPositionCategoryRepository {
     }
 }
 
+/// Reads a position category selected with
+/// [`select_columns`](crate::repositories::columns::select_columns) and `prefix`.
+pub(crate) fn position_category_from_row(
+    row: &PgRow,
+    prefix: &str,
+) -> Result<PositionCategory, sqlx::Error> {
+    Ok(PositionCategory {
+        id: row.try_get(&*column(prefix, "id"))?,
+        name: row.try_get(&*column(prefix, "name"))?,
+        organization_id: row.try_get(&*column(prefix, "organization_id"))?,
+    })
+}
+
 #[async_trait]
 impl PositionCategoryRepository for PgPositionCategoryRepository {
-    async fn list_position_categories(&self) -> Result<Vec<PositionCategory>> {
+    async fn list_position_categories(
+        &self,
+        organization_id: i32,
+    ) -> Result<Vec<PositionCategory>> {
         let position_categories = sqlx::query_as!(
             PositionCategory,
             r#"SELECT 
                 "id",
-                "name"
-            FROM "position_category""#
+                "name",
+                "organization_id"
+            FROM "position_category"
+            WHERE "organization_id" = $1"#,
+            organization_id
         )
         .fetch_all(self.pool_handler.pool())
         .await?;
@@ -63,7 +95,8 @@ impl PositionCategoryRepository for PgPositionCategoryRepository {
             PositionCategory,
             r#"SELECT 
                 "id",
-                "name" 
+                "name",
+                "organization_id"
             FROM "position_category" 
             WHERE "id" = $1"#,
             position_category_id
@@ -76,16 +109,41 @@ impl PositionCategoryRepository for PgPositionCategoryRepository {
         Err(RepositoryError::NotFound)
     }
 
+    async fn get_position_category_by_id_in(
+        &self,
+        unit_of_work: &mut UnitOfWork,
+        position_category_id: i32,
+    ) -> Result<PositionCategory, RepositoryError> {
+        let position_category = sqlx::query_as!(
+            PositionCategory,
+            r#"SELECT
+                "id",
+                "name",
+                "organization_id"
+            FROM "position_category"
+            WHERE "id" = $1
+            FOR SHARE"#,
+            position_category_id
+        )
+        .fetch_optional(unit_of_work.connection()?)
+        .await?;
+        if let Some(position_category) = position_category {
+            return Ok(position_category);
+        }
+        Err(RepositoryError::NotFound)
+    }
+
     async fn create_position_category(
         &self,
         new_position_category: CreatePositionCategory,
     ) -> Result<PositionCategory, RepositoryError> {
         let position_category = sqlx::query_as!(
             PositionCategory,
-            r#"INSERT INTO "position_category" (name)
-            VALUES ($1) 
-            RETURNING "id", "name""#,
-            new_position_category.name
+            r#"INSERT INTO "position_category" ("name", "organization_id")
+            VALUES ($1, $2) 
+            RETURNING "id", "name", "organization_id""#,
+            new_position_category.name,
+            new_position_category.organization_id
         )
         .fetch_one(self.pool_handler.pool())
         .await?;
@@ -128,7 +186,7 @@ impl PositionCategoryRepository for PgPositionCategoryRepository {
             r#"UPDATE "position_category" 
             SET "name" = $1 
             WHERE "id" = $2 
-            RETURNING "id", "name""#,
+            RETURNING "id", "name", "organization_id""#,
             name,
             position_category.id
         )
@@ -0,0 +1,364 @@
+use crate::models::pagination::{ListSpec, Page, SortColumn};
+use crate::models::read_model::{EmploymentListing, EventListing, JobPositionListing};
+use crate::models::user::{User, UserRole};
+use crate::models::{employment, event, job_position};
+use crate::repositories::columns::select_columns;
+use crate::repositories::employment::{
+    employment_from_row, PgEmploymentRepository, EMPLOYMENT_COLUMNS,
+};
+use crate::repositories::event::{event_from_row, PgEventRepository, EVENT_COLUMNS};
+use crate::repositories::job_position::{
+    job_position_from_row, PgJobPositionRepository, JOB_POSITION_COLUMNS,
+};
+use crate::repositories::pool_handler::PoolHandler;
+use crate::repositories::position_category::{
+    position_category_from_row, POSITION_CATEGORY_COLUMNS,
+};
+use crate::repositories::user::{user_from_row, USER_COLUMNS};
+use crate::repositories::venue::{venue_from_row, VENUE_COLUMNS};
+use anyhow::Result;
+use async_trait::async_trait;
+use sqlx::postgres::PgRow;
+use sqlx::{Postgres, QueryBuilder, Row};
+
+const EVENT_LISTING_JOINS: &str = r#"
+    JOIN "venue" ON "venue"."id" = "event"."venue_id"
+    JOIN "user" AS "owner" ON "owner"."id" = "event"."owner_id""#;
+
+const EMPLOYMENT_LISTING_JOINS: &str = r#"
+    JOIN "job_position" ON "job_position"."id" = "employment"."position_id"
+    JOIN "event" ON "event"."id" = "job_position"."event_id"
+    JOIN "venue" ON "venue"."id" = "event"."venue_id"
+    JOIN "user" AS "employee" ON "employee"."id" = "employment"."user_id""#;
+
+const JOB_POSITION_LISTING_JOINS: &str = r#"
+    JOIN "event" ON "event"."id" = "job_position"."event_id"
+    JOIN "position_category" ON "position_category"."id" = "job_position"."position_category_id""#;
+
+/// Workers accepted to the job position of the row, finished ones included.
+const CURRENT_CAPACITY: &str = r#"(SELECT COUNT(*) FROM "employment" AS "taken"
+    WHERE "taken"."position_id" = "job_position"."id"
+        AND "taken"."state" IN ('accepted', 'done')) AS "current_capacity""#;
+
+/// Reads list views in one query each, joining the rows every item of the view refers to
+/// instead of loading them one by one.
+#[async_trait]
+pub trait ReadModelRepository: Send + Sync {
+    /// Lists events matching `filter`, leaving out drafts `viewer` may not see.
+    async fn list_event_listings(
+        &self,
+        filter: event::SelectManyFilter,
+        viewer: Option<&User>,
+    ) -> Result<Vec<EventListing>>;
+    async fn list_event_listings_page(
+        &self,
+        filter: event::SelectManyFilter,
+        spec: &ListSpec<event::SortColumn>,
+    ) -> Result<Page<EventListing>>;
+    async fn list_employment_listings(
+        &self,
+        filter: employment::SelectManyFilter,
+    ) -> Result<Vec<EmploymentListing>>;
+    async fn list_employment_listings_page(
+        &self,
+        filter: employment::SelectManyFilter,
+        spec: &ListSpec<employment::SortColumn>,
+    ) -> Result<Page<EmploymentListing>>;
+    async fn list_job_listings_page(
+        &self,
+        filter: employment::JobsFilter,
+        spec: &ListSpec<employment::JobsSortColumn>,
+    ) -> Result<Page<EmploymentListing>>;
+    async fn list_job_position_listings_page(
+        &self,
+        filter: job_position::SelectManyFilter,
+        spec: &ListSpec<job_position::SortColumn>,
+    ) -> Result<Page<JobPositionListing>>;
+}
+
+#[derive(Debug, Clone)]
+pub struct PgReadModelRepository {
+    pub pool_handler: PoolHandler,
+}
+
+impl PgReadModelRepository {
+    pub fn new(pool_handler: PoolHandler) -> Self {
+        Self { pool_handler }
+    }
+
+    fn event_listing_columns() -> String {
+        [
+            select_columns("event", EVENT_COLUMNS, "event."),
+            select_columns("venue", VENUE_COLUMNS, "venue."),
+            select_columns("owner", USER_COLUMNS, "owner."),
+        ]
+        .join(", ")
+    }
+
+    fn employment_listing_columns() -> String {
+        [
+            select_columns("employment", EMPLOYMENT_COLUMNS, "employment."),
+            select_columns("job_position", JOB_POSITION_COLUMNS, "position."),
+            select_columns("event", EVENT_COLUMNS, "event."),
+            select_columns("venue", VENUE_COLUMNS, "venue."),
+            select_columns("employee", USER_COLUMNS, "employee."),
+            CURRENT_CAPACITY.to_string(),
+            r#"(SELECT COALESCE(SUM("worked_hours"."hours_worked"), 0) FROM "worked_hours"
+                WHERE "worked_hours"."employment_id" = "employment"."id") AS "hours_worked""#
+                .to_string(),
+            r#"EXISTS (SELECT 1 FROM "review" WHERE "review"."employment_id" = "employment"."id"
+                AND "review"."direction" = 'worker_to_organizer') AS "reviewed_by_worker""#
+                .to_string(),
+            r#"EXISTS (SELECT 1 FROM "review" WHERE "review"."employment_id" = "employment"."id"
+                AND "review"."direction" = 'organizer_to_worker') AS "reviewed_by_organizer""#
+                .to_string(),
+        ]
+        .join(", ")
+    }
+
+    fn job_position_listing_columns() -> String {
+        [
+            select_columns("job_position", JOB_POSITION_COLUMNS, "position."),
+            select_columns("event", EVENT_COLUMNS, "event."),
+            select_columns("position_category", POSITION_CATEGORY_COLUMNS, "category."),
+            CURRENT_CAPACITY.to_string(),
+        ]
+        .join(", ")
+    }
+
+    /// Opens a query selecting `columns` of the page of `table` the list query picks, which
+    /// `push_filter` completes with its `FROM` and `WHERE` clauses. Rows of the page are
+    /// joined back to `table` and through `joins` to everything else the listing shows.
+    fn page_query<'a, C: SortColumn>(
+        columns: &str,
+        table: &str,
+        joins: &str,
+        spec: &ListSpec<C>,
+        push_filter: impl FnOnce(&mut QueryBuilder<'a, Postgres>),
+    ) -> QueryBuilder<'a, Postgres> {
+        let mut query_builder = QueryBuilder::new(format!(
+            r#"SELECT {columns}, "page"."total_count"
+                FROM (WITH "list" AS (SELECT "{table}"."id", "#
+        ));
+        spec.push_sort_key(&mut query_builder);
+        push_filter(&mut query_builder);
+        spec.push_page(&mut query_builder);
+        query_builder.push(format!(
+            r#") AS "page" JOIN "{table}" ON "{table}"."id" = "page"."id"{joins}"#
+        ));
+        spec.push_order(&mut query_builder, "page");
+        query_builder
+    }
+
+    fn page_from_rows<T, C>(
+        rows: &[PgRow],
+        spec: &ListSpec<C>,
+        from_row: impl Fn(&PgRow) -> Result<T, sqlx::Error>,
+        id_of: impl Fn(&T) -> i32,
+    ) -> Result<Page<T>> {
+        let total = match rows.first() {
+            Some(row) => row.try_get("total_count")?,
+            None => 0,
+        };
+        let items: Result<Vec<T>, sqlx::Error> = rows.iter().map(from_row).collect();
+        Ok(Page::new(items?, total, spec, id_of))
+    }
+}
+
+/// Leaves out drafts `viewer` may not see, mirroring [`crate::policy::EventPolicy`] for
+/// [`crate::policy::EventAction::ViewDraft`]: admins see every draft, owners of the
+/// organization its drafts, organizers drafts of events they own or manage.
+fn push_draft_visibility(query_builder: &mut QueryBuilder<'_, Postgres>, viewer: Option<&User>) {
+    let user_id = match viewer {
+        Some(user) if user.role == UserRole::Admin => return,
+        Some(user) => user.id,
+        None => {
+            query_builder.push(r#" AND "event"."status" <> 'draft'"#);
+            return;
+        }
+    };
+
+    query_builder.push(
+        r#" AND ("event"."status" <> 'draft' OR EXISTS (SELECT 1 FROM "organization_membership"
+            WHERE "organization_membership"."organization_id" = "event"."organization_id"
+                AND "organization_membership"."user_id" = "#,
+    );
+    query_builder.push_bind(user_id);
+    query_builder.push(
+        r#" AND ("organization_membership"."role" = 'owner'
+                OR ("organization_membership"."role" = 'organizer'
+                    AND ("event"."owner_id" = "#,
+    );
+    query_builder.push_bind(user_id);
+    query_builder.push(
+        r#" OR "event"."id" IN (SELECT "event_id" FROM "event_manager_relation" WHERE "user_id" = "#,
+    );
+    query_builder.push_bind(user_id);
+    query_builder.push("))))))");
+}
+
+fn event_listing_from_row(row: &PgRow) -> Result<EventListing, sqlx::Error> {
+    Ok(EventListing {
+        event: event_from_row(row, "event.")?,
+        venue: venue_from_row(row, "venue.")?,
+        owner: user_from_row(row, "owner.")?,
+    })
+}
+
+fn employment_listing_from_row(row: &PgRow) -> Result<EmploymentListing, sqlx::Error> {
+    Ok(EmploymentListing {
+        employment: employment_from_row(row, "employment.")?,
+        position: job_position_from_row(row, "position.")?,
+        event: event_from_row(row, "event.")?,
+        venue: venue_from_row(row, "venue.")?,
+        employee: user_from_row(row, "employee.")?,
+        current_capacity: row.try_get("current_capacity")?,
+        hours_worked: row.try_get("hours_worked")?,
+        reviewed_by_worker: row.try_get("reviewed_by_worker")?,
+        reviewed_by_organizer: row.try_get("reviewed_by_organizer")?,
+    })
+}
+
+fn job_position_listing_from_row(row: &PgRow) -> Result<JobPositionListing, sqlx::Error> {
+    Ok(JobPositionListing {
+        position: job_position_from_row(row, "position.")?,
+        event: event_from_row(row, "event.")?,
+        category: position_category_from_row(row, "category.")?,
+        current_capacity: row.try_get("current_capacity")?,
+    })
+}
+
+#[async_trait]
+impl ReadModelRepository for PgReadModelRepository {
+    async fn list_event_listings(
+        &self,
+        filter: event::SelectManyFilter,
+        viewer: Option<&User>,
+    ) -> Result<Vec<EventListing>> {
+        let mut query_builder = QueryBuilder::new(format!(
+            r#"SELECT {} FROM "event"{EVENT_LISTING_JOINS}
+                WHERE "event"."id" IN (SELECT "event"."id""#,
+            Self::event_listing_columns()
+        ));
+        PgEventRepository::push_filter(&mut query_builder, filter);
+        push_draft_visibility(&mut query_builder, viewer);
+        query_builder.push(r#") ORDER BY "event"."date_start", "event"."id""#);
+
+        let rows = query_builder
+            .build()
+            .fetch_all(self.pool_handler.pool())
+            .await?;
+        let listings: Result<Vec<EventListing>, sqlx::Error> =
+            rows.iter().map(event_listing_from_row).collect();
+        Ok(listings?)
+    }
+
+    async fn list_event_listings_page(
+        &self,
+        filter: event::SelectManyFilter,
+        spec: &ListSpec<event::SortColumn>,
+    ) -> Result<Page<EventListing>> {
+        let mut query_builder = Self::page_query(
+            &Self::event_listing_columns(),
+            "event",
+            EVENT_LISTING_JOINS,
+            spec,
+            |query_builder| PgEventRepository::push_filter(query_builder, filter),
+        );
+
+        let rows = query_builder
+            .build()
+            .fetch_all(self.pool_handler.pool())
+            .await?;
+        Self::page_from_rows(&rows, spec, event_listing_from_row, |listing| {
+            listing.event.id
+        })
+    }
+
+    async fn list_employment_listings(
+        &self,
+        filter: employment::SelectManyFilter,
+    ) -> Result<Vec<EmploymentListing>> {
+        let mut query_builder = QueryBuilder::new(format!(
+            r#"SELECT {} FROM "employment"{EMPLOYMENT_LISTING_JOINS}
+                WHERE "employment"."id" IN (SELECT "employment"."id""#,
+            Self::employment_listing_columns()
+        ));
+        PgEmploymentRepository::push_filter(&mut query_builder, filter);
+        query_builder.push(r#") ORDER BY "employment"."id""#);
+
+        let rows = query_builder
+            .build()
+            .fetch_all(self.pool_handler.pool())
+            .await?;
+        let listings: Result<Vec<EmploymentListing>, sqlx::Error> =
+            rows.iter().map(employment_listing_from_row).collect();
+        Ok(listings?)
+    }
+
+    async fn list_employment_listings_page(
+        &self,
+        filter: employment::SelectManyFilter,
+        spec: &ListSpec<employment::SortColumn>,
+    ) -> Result<Page<EmploymentListing>> {
+        let mut query_builder = Self::page_query(
+            &Self::employment_listing_columns(),
+            "employment",
+            EMPLOYMENT_LISTING_JOINS,
+            spec,
+            |query_builder| PgEmploymentRepository::push_filter(query_builder, filter),
+        );
+
+        let rows = query_builder
+            .build()
+            .fetch_all(self.pool_handler.pool())
+            .await?;
+        Self::page_from_rows(&rows, spec, employment_listing_from_row, |listing| {
+            listing.employment.id
+        })
+    }
+
+    async fn list_job_listings_page(
+        &self,
+        filter: employment::JobsFilter,
+        spec: &ListSpec<employment::JobsSortColumn>,
+    ) -> Result<Page<EmploymentListing>> {
+        let mut query_builder = Self::page_query(
+            &Self::employment_listing_columns(),
+            "employment",
+            EMPLOYMENT_LISTING_JOINS,
+            spec,
+            |query_builder| PgEmploymentRepository::push_jobs_filter(query_builder, filter),
+        );
+
+        let rows = query_builder
+            .build()
+            .fetch_all(self.pool_handler.pool())
+            .await?;
+        Self::page_from_rows(&rows, spec, employment_listing_from_row, |listing| {
+            listing.employment.id
+        })
+    }
+
+    async fn list_job_position_listings_page(
+        &self,
+        filter: job_position::SelectManyFilter,
+        spec: &ListSpec<job_position::SortColumn>,
+    ) -> Result<Page<JobPositionListing>> {
+        let mut query_builder = Self::page_query(
+            &Self::job_position_listing_columns(),
+            "job_position",
+            JOB_POSITION_LISTING_JOINS,
+            spec,
+            |query_builder| PgJobPositionRepository::push_filter(query_builder, filter),
+        );
+
+        let rows = query_builder
+            .build()
+            .fetch_all(self.pool_handler.pool())
+            .await?;
+        Self::page_from_rows(&rows, spec, job_position_listing_from_row, |listing| {
+            listing.position.id
+        })
+    }
+}
@@ -0,0 +1,383 @@
+use crate::error::RepositoryError;
+use crate::models::employment::EmploymentState;
+use crate::models::pagination::{ListSpec, Page};
+use crate::models::review::{
+    review_deadline, CreateReview, FlaggedReview, PublishedReview, Review, ReviewDirection,
+    SortColumn, REVIEW_WINDOW_DAYS,
+};
+use crate::repositories::pool_handler::PoolHandler;
+use anyhow::Result;
+use async_trait::async_trait;
+use sqlx::types::time::Date;
+use sqlx::{QueryBuilder, Row};
+
+#[async_trait]
+pub trait ReviewRepository: Send + Sync {
+    /// Reviews a finished job, either the worker by the organizer or the other way round.
+    async fn create_review(
+        &self,
+        new_review: CreateReview,
+        today: Date,
+    ) -> Result<Review, RepositoryError>;
+    /// Published reviews about the user, newest first.
+    async fn list_published_reviews(
+        &self,
+        subject_id: i32,
+        direction: ReviewDirection,
+        organization_id: Option<i32>,
+        today: Date,
+    ) -> Result<Vec<PublishedReview>, RepositoryError>;
+    /// Published reviews workers wrote about the event, newest first.
+    async fn list_event_reviews(
+        &self,
+        event_id: i32,
+        today: Date,
+    ) -> Result<Vec<PublishedReview>, RepositoryError>;
+    async fn flag_review(
+        &self,
+        review_id: i32,
+        flagged_by: i32,
+        reason: String,
+    ) -> Result<Review, RepositoryError>;
+    async fn list_flagged_reviews_page(
+        &self,
+        spec: &ListSpec<SortColumn>,
+    ) -> Result<Page<FlaggedReview>, RepositoryError>;
+    /// Resolves a flag, hiding the review for good or keeping it published.
+    async fn moderate_review(&self, review_id: i32, hide: bool) -> Result<Review, RepositoryError>;
+}
+
+#[derive(Debug, Clone)]
+pub struct PgReviewRepository {
+    pub pool_handler: PoolHandler,
+}
+
+impl PgReviewRepository {
+    pub fn new(pool_handler: PoolHandler) -> Self {
+        Self { pool_handler }
+    }
+}
+
+#[async_trait]
+impl ReviewRepository for PgReviewRepository {
+    async fn create_review(
+        &self,
+        new_review: CreateReview,
+        today: Date,
+    ) -> Result<Review, RepositoryError> {
+        let employment = sqlx::query!(
+            r#"SELECT
+                "employment"."state" AS "state: EmploymentState",
+                "employment"."user_id",
+                "event"."id" AS "event_id",
+                "event"."owner_id",
+                "event"."date_end"
+            FROM "employment"
+            JOIN "job_position" ON "job_position"."id" = "employment"."position_id"
+            JOIN "event" ON "event"."id" = "job_position"."event_id"
+            WHERE "employment"."id" = $1"#,
+            new_review.employment_id
+        )
+        .fetch_optional(self.pool_handler.pool())
+        .await?
+        .ok_or(RepositoryError::NotFound)?;
+
+        if employment.state != EmploymentState::Done {
+            return Err(RepositoryError::GenericError(
+                "Only finished jobs can be reviewed".to_string(),
+            ));
+        }
+        if today > review_deadline(employment.date_end) {
+            return Err(RepositoryError::GenericError(
+                "The review period for this job has ended".to_string(),
+            ));
+        }
+        let subject_id = match new_review.direction {
+            ReviewDirection::OrganizerToWorker => employment.user_id,
+            ReviewDirection::WorkerToOrganizer => employment.owner_id,
+        };
+
+        let already_reviewed = sqlx::query_scalar!(
+            r#"SELECT EXISTS (
+                SELECT 1 FROM "review"
+                WHERE "employment_id" = $1 AND "direction" = $2::review_direction
+            ) AS "exists!""#,
+            new_review.employment_id,
+            new_review.direction as _
+        )
+        .fetch_one(self.pool_handler.pool())
+        .await?;
+        if already_reviewed {
+            return Err(RepositoryError::GenericError(
+                "This job has already been reviewed".to_string(),
+            ));
+        }
+
+        let review = sqlx::query_as!(
+            Review,
+            r#"INSERT INTO "review" (
+                "employment_id", "direction", "author_id", "subject_id", "event_id", "overall",
+                "punctuality", "skill", "organization", "communication", "comment"
+            )
+            VALUES ($1, $2::review_direction, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+            RETURNING
+                "id",
+                "employment_id",
+                "direction" AS "direction: ReviewDirection",
+                "author_id",
+                "subject_id",
+                "event_id",
+                "overall",
+                "punctuality",
+                "skill",
+                "organization",
+                "communication",
+                "comment",
+                "flagged_at",
+                "flagged_by",
+                "flag_reason",
+                "is_hidden",
+                "created_at""#,
+            new_review.employment_id,
+            new_review.direction as _,
+            new_review.author_id,
+            subject_id,
+            employment.event_id,
+            new_review.overall,
+            new_review.punctuality,
+            new_review.skill,
+            new_review.organization,
+            new_review.communication,
+            new_review.comment.trim()
+        )
+        .fetch_one(self.pool_handler.pool())
+        .await?;
+        Ok(review)
+    }
+
+    async fn list_published_reviews(
+        &self,
+        subject_id: i32,
+        direction: ReviewDirection,
+        organization_id: Option<i32>,
+        today: Date,
+    ) -> Result<Vec<PublishedReview>, RepositoryError> {
+        let reviews = sqlx::query_as!(
+            PublishedReview,
+            r#"SELECT
+                "review"."id",
+                "review"."direction" AS "direction: ReviewDirection",
+                "author"."first_name" || ' ' || "author"."last_name" AS "author_name",
+                "event"."id" AS "event_id",
+                "event"."name" AS "event_name",
+                "review"."overall",
+                "review"."punctuality",
+                "review"."skill",
+                "review"."organization",
+                "review"."communication",
+                "review"."comment",
+                "review"."created_at"
+            FROM "review"
+            JOIN "event" ON "event"."id" = "review"."event_id"
+            LEFT JOIN "user" AS "author" ON "author"."id" = "review"."author_id"
+            WHERE "review"."subject_id" = $1
+                AND "review"."direction" = $2::review_direction
+                AND ($3::INT IS NULL OR "event"."organization_id" = $3)
+                AND NOT "review"."is_hidden"
+                AND (
+                    EXISTS (
+                        SELECT 1 FROM "review" AS "counterpart"
+                        WHERE "counterpart"."employment_id" = "review"."employment_id"
+                            AND "counterpart"."direction" <> "review"."direction"
+                    )
+                    OR "event"."date_end" + $4::INT < $5::DATE
+                )
+            ORDER BY "review"."created_at" DESC"#,
+            subject_id,
+            direction as _,
+            organization_id,
+            REVIEW_WINDOW_DAYS,
+            today
+        )
+        .fetch_all(self.pool_handler.pool())
+        .await?;
+        Ok(reviews)
+    }
+
+    async fn list_event_reviews(
+        &self,
+        event_id: i32,
+        today: Date,
+    ) -> Result<Vec<PublishedReview>, RepositoryError> {
+        let reviews = sqlx::query_as!(
+            PublishedReview,
+            r#"SELECT
+                "review"."id",
+                "review"."direction" AS "direction: ReviewDirection",
+                "author"."first_name" || ' ' || "author"."last_name" AS "author_name",
+                "event"."id" AS "event_id",
+                "event"."name" AS "event_name",
+                "review"."overall",
+                "review"."punctuality",
+                "review"."skill",
+                "review"."organization",
+                "review"."communication",
+                "review"."comment",
+                "review"."created_at"
+            FROM "review"
+            JOIN "event" ON "event"."id" = "review"."event_id"
+            LEFT JOIN "user" AS "author" ON "author"."id" = "review"."author_id"
+            WHERE "review"."event_id" = $1
+                AND "review"."direction" = 'worker_to_organizer'
+                AND NOT "review"."is_hidden"
+                AND (
+                    EXISTS (
+                        SELECT 1 FROM "review" AS "counterpart"
+                        WHERE "counterpart"."employment_id" = "review"."employment_id"
+                            AND "counterpart"."direction" <> "review"."direction"
+                    )
+                    OR "event"."date_end" + $2::INT < $3::DATE
+                )
+            ORDER BY "review"."created_at" DESC"#,
+            event_id,
+            REVIEW_WINDOW_DAYS,
+            today
+        )
+        .fetch_all(self.pool_handler.pool())
+        .await?;
+        Ok(reviews)
+    }
+
+    async fn flag_review(
+        &self,
+        review_id: i32,
+        flagged_by: i32,
+        reason: String,
+    ) -> Result<Review, RepositoryError> {
+        let reason = reason.trim();
+        if reason.is_empty() {
+            return Err(RepositoryError::GenericError(
+                "Please tell the admins what is wrong with the review".to_string(),
+            ));
+        }
+        let review = sqlx::query_as!(
+            Review,
+            r#"UPDATE "review"
+            SET "flagged_at" = NOW(), "flagged_by" = $2, "flag_reason" = $3
+            WHERE "id" = $1 AND NOT "is_hidden"
+            RETURNING
+                "id",
+                "employment_id",
+                "direction" AS "direction: ReviewDirection",
+                "author_id",
+                "subject_id",
+                "event_id",
+                "overall",
+                "punctuality",
+                "skill",
+                "organization",
+                "communication",
+                "comment",
+                "flagged_at",
+                "flagged_by",
+                "flag_reason",
+                "is_hidden",
+                "created_at""#,
+            review_id,
+            flagged_by,
+            reason
+        )
+        .fetch_optional(self.pool_handler.pool())
+        .await?
+        .ok_or(RepositoryError::NotFound)?;
+        Ok(review)
+    }
+
+    async fn list_flagged_reviews_page(
+        &self,
+        spec: &ListSpec<SortColumn>,
+    ) -> Result<Page<FlaggedReview>, RepositoryError> {
+        let mut query_builder = QueryBuilder::new(
+            r#"WITH "list" AS (SELECT
+                "review"."id",
+                "author"."first_name" || ' ' || "author"."last_name" AS "author_name",
+                "subject"."first_name" || ' ' || "subject"."last_name" AS "subject_name",
+                "event"."name" AS "event_name",
+                "review"."overall",
+                "review"."comment",
+                "review"."flag_reason",
+                "flagger"."first_name" || ' ' || "flagger"."last_name" AS "flagged_by_name",
+                "review"."flagged_at", "#,
+        );
+        spec.push_sort_key(&mut query_builder);
+        query_builder.push(
+            r#" FROM "review"
+            JOIN "event" ON "event"."id" = "review"."event_id"
+            JOIN "user" AS "subject" ON "subject"."id" = "review"."subject_id"
+            LEFT JOIN "user" AS "author" ON "author"."id" = "review"."author_id"
+            LEFT JOIN "user" AS "flagger" ON "flagger"."id" = "review"."flagged_by"
+            WHERE "review"."flagged_at" IS NOT NULL"#,
+        );
+        spec.push_page(&mut query_builder);
+
+        let rows = query_builder
+            .build()
+            .fetch_all(self.pool_handler.pool())
+            .await?;
+
+        let total = match rows.first() {
+            Some(row) => row.try_get("total_count")?,
+            None => 0,
+        };
+        let reviews: Result<Vec<FlaggedReview>, sqlx::Error> = rows
+            .iter()
+            .map(|row| {
+                Ok(FlaggedReview {
+                    id: row.try_get("id")?,
+                    author_name: row.try_get("author_name")?,
+                    subject_name: row.try_get("subject_name")?,
+                    event_name: row.try_get("event_name")?,
+                    overall: row.try_get("overall")?,
+                    comment: row.try_get("comment")?,
+                    flag_reason: row.try_get("flag_reason")?,
+                    flagged_by_name: row.try_get("flagged_by_name")?,
+                    flagged_at: row.try_get("flagged_at")?,
+                })
+            })
+            .collect();
+        Ok(Page::new(reviews?, total, spec, |review| review.id))
+    }
+
+    async fn moderate_review(&self, review_id: i32, hide: bool) -> Result<Review, RepositoryError> {
+        let review = sqlx::query_as!(
+            Review,
+            r#"UPDATE "review"
+            SET "is_hidden" = $2, "flagged_at" = NULL, "flagged_by" = NULL, "flag_reason" = NULL
+            WHERE "id" = $1
+            RETURNING
+                "id",
+                "employment_id",
+                "direction" AS "direction: ReviewDirection",
+                "author_id",
+                "subject_id",
+                "event_id",
+                "overall",
+                "punctuality",
+                "skill",
+                "organization",
+                "communication",
+                "comment",
+                "flagged_at",
+                "flagged_by",
+                "flag_reason",
+                "is_hidden",
+                "created_at""#,
+            review_id,
+            hide
+        )
+        .fetch_optional(self.pool_handler.pool())
+        .await?
+        .ok_or(RepositoryError::NotFound)?;
+        Ok(review)
+    }
+}
@@ -0,0 +1,33 @@
+//! Repositories keeping their rows in SQLite, so that Giglog can run on a single machine
+//! without a Postgres server. The schema is the one of `migrations/sqlite`, which stores enums
+//! as text and timestamps as RFC 3339 text in UTC.
+
+pub mod attendance;
+pub mod audit_log;
+pub mod employment;
+pub mod event;
+pub mod event_manager_relation;
+pub mod job_position;
+pub mod notification;
+pub mod organization;
+pub mod position_category;
+pub mod read_model;
+pub mod review;
+pub mod user;
+pub mod venue;
+pub mod worked_hours;
+
+use sqlx::{QueryBuilder, Sqlite};
+
+/// Adds `(value, ...)` for the right-hand side of an `IN`, which SQLite has no arrays for.
+pub(crate) fn push_in_list<T>(query_builder: &mut QueryBuilder<'_, Sqlite>, values: &[T])
+where
+    T: for<'q> sqlx::Encode<'q, Sqlite> + sqlx::Type<Sqlite> + Clone + Send + 'static,
+{
+    query_builder.push("(");
+    let mut separated = query_builder.separated(", ");
+    for value in values {
+        separated.push_bind(value.clone());
+    }
+    query_builder.push(")");
+}
@@ -0,0 +1,194 @@
+use crate::error::RepositoryError;
+use crate::models::attendance::{AttendanceRecord, AttendanceSummary, RecordAttendance};
+use crate::models::employment::EmploymentState;
+use crate::repositories::attendance::AttendanceRepository;
+use crate::repositories::sqlite::push_in_list;
+use anyhow::Result;
+use async_trait::async_trait;
+use sqlx::sqlite::SqliteRow;
+use sqlx::types::time::Date;
+use sqlx::{QueryBuilder, Row, SqlitePool};
+use std::sync::Arc;
+
+const ATTENDANCE_COLUMNS: &str =
+    r#""id", "employment_id", "date", "outcome", "note", "recorded_by", "created_at""#;
+
+#[derive(Debug, Clone)]
+pub struct SqliteAttendanceRepository {
+    pub pool: SqlitePool,
+    pub actor_id: Option<i32>,
+}
+
+impl SqliteAttendanceRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self {
+            pool,
+            actor_id: None,
+        }
+    }
+
+    /// Returns a copy of the repository recording outcomes on behalf of `actor_id`.
+    pub fn with_actor(&self, actor_id: i32) -> Self {
+        Self {
+            pool: self.pool.clone(),
+            actor_id: Some(actor_id),
+        }
+    }
+}
+
+fn attendance_from_row(row: &SqliteRow) -> Result<AttendanceRecord, sqlx::Error> {
+    Ok(AttendanceRecord {
+        id: row.try_get("id")?,
+        employment_id: row.try_get("employment_id")?,
+        date: row.try_get("date")?,
+        outcome: row.try_get("outcome")?,
+        note: row.try_get("note")?,
+        recorded_by: row.try_get("recorded_by")?,
+        created_at: row.try_get("created_at")?,
+    })
+}
+
+#[async_trait]
+impl AttendanceRepository for SqliteAttendanceRepository {
+    fn with_actor(&self, actor_id: i32) -> Arc<dyn AttendanceRepository> {
+        Arc::new(SqliteAttendanceRepository::with_actor(self, actor_id))
+    }
+
+    async fn record_attendance(
+        &self,
+        record: RecordAttendance,
+    ) -> Result<AttendanceRecord, RepositoryError> {
+        let employment = sqlx::query(
+            r#"SELECT
+                "employment"."state",
+                "event"."date_start",
+                "event"."date_end",
+                date('now') AS "today"
+            FROM "employment"
+            JOIN "job_position" ON "job_position"."id" = "employment"."position_id"
+            JOIN "event" ON "event"."id" = "job_position"."event_id"
+            WHERE "employment"."id" = ?"#,
+        )
+        .bind(record.employment_id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(RepositoryError::NotFound)?;
+        let state: EmploymentState = employment.try_get("state")?;
+        let date_start: Date = employment.try_get("date_start")?;
+        let date_end: Date = employment.try_get("date_end")?;
+        let today: Date = employment.try_get("today")?;
+
+        if !matches!(state, EmploymentState::Accepted | EmploymentState::Done) {
+            return Err(RepositoryError::GenericError(
+                "Attendance can only be recorded for accepted or finished jobs".to_string(),
+            ));
+        }
+        if record.date < date_start || record.date > date_end {
+            return Err(RepositoryError::GenericError(
+                "Attendance date is outside of the event's dates".to_string(),
+            ));
+        }
+        if record.date > today {
+            return Err(RepositoryError::GenericError(
+                "Attendance cannot be recorded for future days".to_string(),
+            ));
+        }
+
+        let note = record
+            .note
+            .map(|note| note.trim().to_string())
+            .filter(|note| !note.is_empty());
+        let row = sqlx::query(&format!(
+            r#"INSERT INTO "attendance_record" (
+                "employment_id", "date", "outcome", "note", "recorded_by"
+            )
+            VALUES (?, ?, ?, ?, ?)
+            ON CONFLICT ("employment_id", "date") DO UPDATE SET
+                "outcome" = "excluded"."outcome",
+                "note" = "excluded"."note",
+                "recorded_by" = "excluded"."recorded_by",
+                "created_at" = strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+            RETURNING {ATTENDANCE_COLUMNS}"#
+        ))
+        .bind(record.employment_id)
+        .bind(record.date)
+        .bind(record.outcome)
+        .bind(note)
+        .bind(self.actor_id)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(attendance_from_row(&row)?)
+    }
+
+    async fn list_attendance(
+        &self,
+        employment_ids: &[i32],
+    ) -> Result<Vec<AttendanceRecord>, RepositoryError> {
+        let mut query_builder = QueryBuilder::new(format!(
+            r#"SELECT {ATTENDANCE_COLUMNS} FROM "attendance_record" WHERE "employment_id" IN "#
+        ));
+        push_in_list(&mut query_builder, employment_ids);
+        query_builder.push(r#" ORDER BY "date", "id""#);
+
+        let rows = query_builder.build().fetch_all(&self.pool).await?;
+        let records: Result<Vec<AttendanceRecord>, sqlx::Error> =
+            rows.iter().map(attendance_from_row).collect();
+        Ok(records?)
+    }
+
+    async fn attendance_summary(
+        &self,
+        user_id: i32,
+        organization_id: Option<i32>,
+    ) -> Result<AttendanceSummary, RepositoryError> {
+        let row = sqlx::query(
+            r#"SELECT
+                COUNT(CASE WHEN "attendance_record"."outcome" = 'attended' THEN 1 END) AS "attended",
+                COUNT(CASE WHEN "attendance_record"."outcome" = 'late' THEN 1 END) AS "late",
+                COUNT(CASE WHEN "attendance_record"."outcome" = 'no_show' THEN 1 END) AS "no_shows",
+                COUNT(CASE WHEN "attendance_record"."outcome" = 'left_early' THEN 1 END) AS "left_early"
+            FROM "attendance_record"
+            JOIN "employment" ON "employment"."id" = "attendance_record"."employment_id"
+            JOIN "job_position" ON "job_position"."id" = "employment"."position_id"
+            JOIN "event" ON "event"."id" = "job_position"."event_id"
+            WHERE "employment"."user_id" = ?1
+                AND (?2 IS NULL OR "event"."organization_id" = ?2)"#,
+        )
+        .bind(user_id)
+        .bind(organization_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(AttendanceSummary {
+            attended: row.try_get("attended")?,
+            late: row.try_get("late")?,
+            no_shows: row.try_get("no_shows")?,
+            left_early: row.try_get("left_early")?,
+        })
+    }
+
+    async fn count_no_shows_since(
+        &self,
+        user_id: i32,
+        organization_id: Option<i32>,
+        since: Date,
+    ) -> Result<i64, RepositoryError> {
+        let count = sqlx::query_scalar(
+            r#"SELECT COUNT(*)
+            FROM "attendance_record"
+            JOIN "employment" ON "employment"."id" = "attendance_record"."employment_id"
+            JOIN "job_position" ON "job_position"."id" = "employment"."position_id"
+            JOIN "event" ON "event"."id" = "job_position"."event_id"
+            WHERE "employment"."user_id" = ?1
+                AND (?2 IS NULL OR "event"."organization_id" = ?2)
+                AND "attendance_record"."outcome" = 'no_show'
+                AND "attendance_record"."date" >= ?3"#,
+        )
+        .bind(user_id)
+        .bind(organization_id)
+        .bind(since)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(count)
+    }
+}
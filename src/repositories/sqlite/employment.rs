@@ -0,0 +1,1049 @@
+use crate::error::RepositoryError;
+use crate::models::audit_log::{AuditAction, AuditEntity};
+use crate::models::employment::{
+    BulkOrder, BulkUpdateResult, CreateEmployment, Employment, EmploymentState,
+    EmploymentStateChange, JobStats, JobsFilter, PartialEmployment, SelectManyFilter,
+    SkippedEmployment, SortColumn, WithdrawalStats,
+};
+use crate::models::event::EventStatus;
+use crate::models::pagination::{push_sqlite_contains, ListSpec, Page};
+use crate::models::staffing::CandidateStats;
+use crate::repositories::columns::{column, select_columns};
+use crate::repositories::employment::{EmploymentRepository, EMPLOYMENT_COLUMNS};
+use crate::repositories::sqlite::audit_log::SqliteAuditLogRepository;
+use crate::repositories::sqlite::event::{push_managed_by, SqliteEventRepository};
+use crate::repositories::sqlite::job_position::SqliteJobPositionRepository;
+use crate::repositories::sqlite::push_in_list;
+use crate::repositories::unit_of_work::UnitOfWork;
+use anyhow::Result;
+use async_trait::async_trait;
+use sqlx::sqlite::SqliteRow;
+use sqlx::types::time::OffsetDateTime;
+use sqlx::{QueryBuilder, Row, Sqlite, SqliteConnection, SqlitePool};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+#[derive(Debug, Clone)]
+pub struct SqliteEmploymentRepository {
+    pub pool: SqlitePool,
+    pub actor_id: Option<i32>,
+}
+
+impl SqliteEmploymentRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self {
+            pool,
+            actor_id: None,
+        }
+    }
+
+    pub fn with_actor(&self, actor_id: i32) -> Self {
+        Self {
+            pool: self.pool.clone(),
+            actor_id: Some(actor_id),
+        }
+    }
+
+    async fn audit_in(
+        &self,
+        connection: &mut SqliteConnection,
+        action: AuditAction,
+        employment: &Employment,
+        before: Option<&Employment>,
+        after: Option<&Employment>,
+    ) -> Result<(), RepositoryError> {
+        let event_id: Option<i32> =
+            sqlx::query_scalar(r#"SELECT "event_id" FROM "job_position" WHERE "id" = ?"#)
+                .bind(employment.position_id)
+                .fetch_optional(&mut *connection)
+                .await?;
+
+        SqliteAuditLogRepository::record_in(
+            connection,
+            self.actor_id,
+            AuditEntity::Employment,
+            employment.id,
+            event_id,
+            action,
+            before,
+            after,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn record_state_change_in(
+        &self,
+        connection: &mut SqliteConnection,
+        employment_id: i32,
+        from_state: Option<EmploymentState>,
+        to_state: EmploymentState,
+    ) -> Result<(), RepositoryError> {
+        sqlx::query(
+            r#"INSERT INTO "employment_state_history" ("employment_id", "from_state", "to_state", "changed_by")
+            VALUES (?, ?, ?, ?)"#,
+        )
+        .bind(employment_id)
+        .bind(from_state)
+        .bind(to_state)
+        .bind(self.actor_id)
+        .execute(connection)
+        .await?;
+        Ok(())
+    }
+
+    async fn check_add(
+        connection: &mut SqliteConnection,
+        new_employment: &CreateEmployment,
+    ) -> Result<(), RepositoryError> {
+        let job_position = SqliteJobPositionRepository::get_job_position_by_id_in(
+            connection,
+            new_employment.position_id,
+        )
+        .await?;
+        let event =
+            SqliteEventRepository::get_event_by_id_in(connection, job_position.event_id).await?;
+
+        if event.date_end < OffsetDateTime::now_utc().date() {
+            return Err(RepositoryError::GenericError(
+                "The event has already ended".to_string(),
+            ));
+        }
+
+        if event.is_cancelled() {
+            return Err(RepositoryError::GenericError(
+                "The event has been cancelled".to_string(),
+            ));
+        }
+
+        if matches!(
+            event.status,
+            EventStatus::RegistrationClosed | EventStatus::Completed | EventStatus::Archived
+        ) {
+            return Err(RepositoryError::GenericError(
+                "Registration for the event is closed".to_string(),
+            ));
+        }
+
+        // Applications have to respect the registration window, organizers may still
+        // assign workers directly.
+        if new_employment.state == EmploymentState::Pending && event.status == EventStatus::Draft {
+            return Err(RepositoryError::GenericError(
+                "The event has not been published yet".to_string(),
+            ));
+        }
+
+        if new_employment.state == EmploymentState::Pending
+            && !job_position.is_registration_open_at(OffsetDateTime::now_utc())
+        {
+            return Err(RepositoryError::GenericError(
+                "The job position is not opened for registration".to_string(),
+            ));
+        }
+
+        let existing_record = sqlx::query(
+            r#"SELECT "id" FROM "employment" WHERE "user_id" = ? AND "position_id" = ?"#,
+        )
+        .bind(new_employment.user_id)
+        .bind(new_employment.position_id)
+        .fetch_optional(&mut *connection)
+        .await?;
+
+        if existing_record.is_some() {
+            return Err(RepositoryError::GenericError(
+                "The event has already been registered".to_string(),
+            ));
+        }
+
+        Self::check_capacity(connection, new_employment.position_id).await
+    }
+
+    /// Fails if the job position has no free place left. SQLite lets a single transaction
+    /// write at a time, so the count holds until the transaction of `connection` ends.
+    async fn check_capacity(
+        connection: &mut SqliteConnection,
+        position_id: i32,
+    ) -> Result<(), RepositoryError> {
+        let capacity: i32 =
+            sqlx::query_scalar(r#"SELECT "capacity" FROM "job_position" WHERE "id" = ?"#)
+                .bind(position_id)
+                .fetch_one(&mut *connection)
+                .await?;
+
+        let taken: i64 = sqlx::query_scalar(
+            r#"SELECT COUNT(*) FROM "employment" WHERE "position_id" = ? AND ("state" = 'accepted' OR "state" = 'done')"#,
+        )
+        .bind(position_id)
+        .fetch_one(&mut *connection)
+        .await?;
+
+        if taken >= capacity as i64 {
+            return Err(RepositoryError::GenericError(
+                "Job position is already full".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// [`EmploymentRepository::get_employment_by_id`] on `connection`, such as the one of
+    /// a [`UnitOfWork`].
+    pub async fn get_employment_by_id_in(
+        connection: &mut SqliteConnection,
+        employment_id: i32,
+    ) -> Result<Employment, RepositoryError> {
+        let row = sqlx::query(&format!(
+            r#"SELECT {} FROM "employment" WHERE "id" = ?"#,
+            select_columns("employment", EMPLOYMENT_COLUMNS, "")
+        ))
+        .bind(employment_id)
+        .fetch_optional(connection)
+        .await?;
+
+        match row {
+            Some(row) => Ok(employment_from_row(&row, "")?),
+            None => Err(RepositoryError::NotFound),
+        }
+    }
+
+    pub(crate) fn push_filter(
+        query_builder: &mut QueryBuilder<'_, Sqlite>,
+        filter: SelectManyFilter,
+    ) {
+        query_builder.push(
+            r#" FROM "employment"
+                JOIN "user" ON "user"."id" = "employment"."user_id"
+                JOIN "job_position" ON "job_position"."id" = "employment"."position_id"
+                JOIN "event" ON "event"."id" = "job_position"."event_id"
+                WHERE "user"."deleted_at" IS NULL
+                    AND "job_position"."deleted_at" IS NULL
+                    AND "event"."deleted_at" IS NULL"#,
+        );
+
+        if let Some(organization_id) = filter.organization_id {
+            query_builder.push(r#" AND "event"."organization_id" = "#);
+            query_builder.push_bind(organization_id);
+        }
+
+        if let Some(rating) = filter.rating {
+            query_builder.push(r#" AND "employment"."rating" = "#);
+            query_builder.push_bind(rating);
+        }
+
+        if let Some(user_id) = filter.user_id {
+            query_builder.push(r#" AND "employment"."user_id" = "#);
+            query_builder.push_bind(user_id);
+        }
+
+        if let Some(state) = filter.state {
+            query_builder.push(r#" AND "employment"."state" = "#);
+            query_builder.push_bind(state);
+        }
+
+        if let Some(position_id) = filter.position_id {
+            query_builder.push(r#" AND "employment"."position_id" = "#);
+            query_builder.push_bind(position_id);
+        }
+
+        if let Some(event_name) = filter.event_name_contains {
+            push_sqlite_contains(query_builder, r#""event"."name""#, event_name);
+        }
+
+        if let Some(job_name) = filter.job_name_contains {
+            push_sqlite_contains(query_builder, r#""job_position"."name""#, job_name);
+        }
+
+        if let Some(employee_name) = filter.employee_name_contains {
+            push_sqlite_contains(
+                query_builder,
+                r#"("user"."first_name" || ' ' || "user"."last_name")"#,
+                employee_name,
+            );
+        }
+
+        if let Some(managed_by) = filter.managed_by {
+            push_managed_by(query_builder, r#""event"."id""#, managed_by);
+        }
+    }
+
+    pub(crate) fn push_jobs_filter(
+        query_builder: &mut QueryBuilder<'_, Sqlite>,
+        filter: JobsFilter,
+    ) {
+        query_builder.push(
+            r#" FROM "employment"
+                JOIN "job_position" ON "job_position"."id" = "employment"."position_id"
+                JOIN "event" ON "event"."id" = "job_position"."event_id"
+                JOIN "venue" ON "venue"."id" = "event"."venue_id"
+                JOIN (
+                    SELECT "id" AS "employment_id", (
+                        SELECT CAST(COALESCE(SUM("worked_hours"."hours_worked"), 0) AS REAL)
+                        FROM "worked_hours"
+                        WHERE "worked_hours"."employment_id" = "employment"."id"
+                    ) AS "total"
+                    FROM "employment"
+                ) AS "hours" ON "hours"."employment_id" = "employment"."id"
+                WHERE "job_position"."deleted_at" IS NULL
+                    AND "event"."deleted_at" IS NULL
+                    AND "event"."organization_id" = "#,
+        );
+        query_builder.push_bind(filter.organization_id);
+        query_builder.push(r#" AND "employment"."user_id" = "#);
+        query_builder.push_bind(filter.user_id);
+
+        if let Some(state) = filter.state {
+            query_builder.push(r#" AND "employment"."state" = "#);
+            query_builder.push_bind(state);
+        }
+
+        if let Some(starts_from) = filter.starts_from {
+            query_builder.push(r#" AND "event"."date_start" >= "#);
+            query_builder.push_bind(starts_from);
+        }
+
+        if let Some(ends_until) = filter.ends_until {
+            query_builder.push(r#" AND "event"."date_end" <= "#);
+            query_builder.push_bind(ends_until);
+        }
+
+        if let Some(event_name) = filter.event_name_contains {
+            push_sqlite_contains(query_builder, r#""event"."name""#, event_name);
+        }
+
+        if let Some(venue_name) = filter.venue_name_contains {
+            push_sqlite_contains(query_builder, r#""venue"."name""#, venue_name);
+        }
+
+        if let Some(job_name) = filter.job_name_contains {
+            push_sqlite_contains(query_builder, r#""job_position"."name""#, job_name);
+        }
+    }
+}
+
+/// Reads an employment selected with [`select_columns`] and `prefix`.
+pub(crate) fn employment_from_row(
+    row: &SqliteRow,
+    prefix: &str,
+) -> Result<Employment, sqlx::Error> {
+    Ok(Employment {
+        id: row.try_get(&*column(prefix, "id"))?,
+        rating: row.try_get(&*column(prefix, "rating"))?,
+        state: row.try_get(&*column(prefix, "state"))?,
+        user_id: row.try_get(&*column(prefix, "user_id"))?,
+        position_id: row.try_get(&*column(prefix, "position_id"))?,
+        invitation_expires_at: row.try_get(&*column(prefix, "invitation_expires_at"))?,
+    })
+}
+
+#[async_trait]
+impl EmploymentRepository for SqliteEmploymentRepository {
+    fn with_actor(&self, actor_id: i32) -> Arc<dyn EmploymentRepository> {
+        Arc::new(SqliteEmploymentRepository::with_actor(self, actor_id))
+    }
+
+    async fn list_employment(&self, filter: SelectManyFilter) -> Result<Vec<Employment>> {
+        let mut query_builder = QueryBuilder::new(format!(
+            "SELECT {}",
+            select_columns("employment", EMPLOYMENT_COLUMNS, "")
+        ));
+        Self::push_filter(&mut query_builder, filter);
+
+        let query = query_builder.build();
+        let rows = query.fetch_all(&self.pool).await?;
+
+        let data: Result<Vec<Employment>, sqlx::Error> = rows
+            .iter()
+            .map(|row| employment_from_row(row, ""))
+            .collect();
+        Ok(data?)
+    }
+
+    async fn list_employment_page(
+        &self,
+        filter: SelectManyFilter,
+        spec: &ListSpec<SortColumn>,
+    ) -> Result<Page<Employment>> {
+        let mut query_builder = QueryBuilder::new(format!(
+            r#"WITH "list" AS (SELECT {}, "#,
+            select_columns("employment", EMPLOYMENT_COLUMNS, "")
+        ));
+        spec.push_sqlite_sort_key(&mut query_builder);
+        Self::push_filter(&mut query_builder, filter);
+        spec.push_sqlite_page(&mut query_builder);
+
+        let query = query_builder.build();
+        let rows = query.fetch_all(&self.pool).await?;
+
+        let total = match rows.first() {
+            Some(row) => row.try_get("total_count")?,
+            None => 0,
+        };
+        let data: Result<Vec<Employment>, sqlx::Error> = rows
+            .iter()
+            .map(|row| employment_from_row(row, ""))
+            .collect();
+        Ok(Page::new(data?, total, spec, |employment| employment.id))
+    }
+
+    async fn job_stats(&self, filter: JobsFilter) -> Result<JobStats> {
+        let mut query_builder = QueryBuilder::new(
+            r#"SELECT
+                COUNT(*) AS "total_jobs",
+                COUNT(DISTINCT "event"."id") AS "unique_events",
+                COUNT(DISTINCT "venue"."id") AS "unique_venues",
+                CAST(COALESCE(SUM("hours"."total"), 0) AS REAL) AS "hours_worked",
+                CAST(COALESCE(SUM("hours"."total" * "job_position"."salary"), 0) AS REAL)
+                    AS "salary",
+                MIN("event"."date_start") AS "earliest_date",
+                MAX("event"."date_end") AS "latest_date",
+                AVG(NULLIF("employment"."rating", 0)) AS "average_rating""#,
+        );
+        Self::push_jobs_filter(&mut query_builder, filter);
+
+        let row = query_builder.build().fetch_one(&self.pool).await?;
+
+        Ok(JobStats {
+            total_jobs: row.try_get("total_jobs")?,
+            unique_events: row.try_get("unique_events")?,
+            unique_venues: row.try_get("unique_venues")?,
+            hours_worked: row.try_get("hours_worked")?,
+            salary: row.try_get("salary")?,
+            earliest_date: row.try_get("earliest_date")?,
+            latest_date: row.try_get("latest_date")?,
+            average_rating: row.try_get("average_rating")?,
+        })
+    }
+
+    async fn get_employment_by_id(
+        &self,
+        employment_id: i32,
+    ) -> Result<Employment, RepositoryError> {
+        let mut connection = self.pool.acquire().await?;
+        Self::get_employment_by_id_in(&mut connection, employment_id).await
+    }
+
+    async fn create_employment(
+        &self,
+        new_employment: CreateEmployment,
+    ) -> Result<Employment, RepositoryError> {
+        let mut unit_of_work = UnitOfWork::begin_sqlite(&self.pool).await?;
+        let employment = self
+            .create_employment_in(&mut unit_of_work, new_employment)
+            .await?;
+        unit_of_work.commit().await?;
+        Ok(employment)
+    }
+
+    async fn create_employment_in(
+        &self,
+        unit_of_work: &mut UnitOfWork,
+        new_employment: CreateEmployment,
+    ) -> Result<Employment, RepositoryError> {
+        let connection = unit_of_work.sqlite_connection()?;
+        Self::check_add(connection, &new_employment).await?;
+        let row = sqlx::query(&format!(
+            r#"INSERT INTO "employment" (
+                "rating", "state", "user_id", "position_id", "invitation_expires_at"
+            )
+            VALUES (?, ?, ?, ?, ?)
+            RETURNING {}"#,
+            select_columns("employment", EMPLOYMENT_COLUMNS, "")
+        ))
+        .bind(new_employment.rating)
+        .bind(new_employment.state)
+        .bind(new_employment.user_id)
+        .bind(new_employment.position_id)
+        .bind(new_employment.invitation_expires_at)
+        .fetch_one(&mut *connection)
+        .await?;
+        let employment = employment_from_row(&row, "")?;
+
+        self.audit_in(
+            connection,
+            AuditAction::Create,
+            &employment,
+            None,
+            Some(&employment),
+        )
+        .await?;
+        self.record_state_change_in(connection, employment.id, None, employment.state.clone())
+            .await?;
+        Ok(employment)
+    }
+
+    async fn delete_employment(&self, employment_id: i32) -> Result<(), RepositoryError> {
+        let mut transaction = self.pool.begin().await?;
+        let employment = Self::get_employment_by_id_in(&mut transaction, employment_id).await?;
+        let result = sqlx::query(r#"DELETE FROM "employment" WHERE "id" = ?"#)
+            .bind(employment_id)
+            .execute(&mut *transaction)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        self.audit_in(
+            &mut transaction,
+            AuditAction::Delete,
+            &employment,
+            Some(&employment),
+            None,
+        )
+        .await?;
+        transaction.commit().await?;
+        Ok(())
+    }
+
+    async fn update_employment(
+        &self,
+        employment_id: i32,
+        patch_log_position: PartialEmployment,
+    ) -> Result<Employment, RepositoryError> {
+        let mut unit_of_work = UnitOfWork::begin_sqlite(&self.pool).await?;
+        let employment = self
+            .update_employment_in(&mut unit_of_work, employment_id, patch_log_position)
+            .await?;
+        unit_of_work.commit().await?;
+        Ok(employment)
+    }
+
+    async fn update_employment_in(
+        &self,
+        unit_of_work: &mut UnitOfWork,
+        employment_id: i32,
+        patch_log_position: PartialEmployment,
+    ) -> Result<Employment, RepositoryError> {
+        let connection = unit_of_work.sqlite_connection()?;
+        let employment = Self::get_employment_by_id_in(connection, employment_id).await?;
+        let before = employment.clone();
+
+        if let Some(state) = &patch_log_position.state {
+            if *state != employment.state && !employment.state.can_transition_to(state) {
+                return Err(RepositoryError::GenericError(format!(
+                    "An employment cannot move from {} to {}",
+                    employment.state, state
+                )));
+            }
+            if *state == EmploymentState::Accepted && employment.state != EmploymentState::Accepted
+            {
+                Self::check_capacity(
+                    connection,
+                    patch_log_position
+                        .position_id
+                        .unwrap_or(employment.position_id),
+                )
+                .await?;
+            }
+        }
+
+        let rating = patch_log_position.rating.unwrap_or(employment.rating);
+        let state = patch_log_position.state.unwrap_or(employment.state);
+        let user_id = patch_log_position.user_id.unwrap_or(employment.user_id);
+        let position_id = patch_log_position
+            .position_id
+            .unwrap_or(employment.position_id);
+
+        let row = sqlx::query(&format!(
+            r#"UPDATE "employment" SET
+                "rating" = ?,
+                "state" = ?,
+                "user_id" = ?,
+                "position_id" = ?
+            WHERE "id" = ?
+            RETURNING {}"#,
+            select_columns("employment", EMPLOYMENT_COLUMNS, "")
+        ))
+        .bind(rating)
+        .bind(state)
+        .bind(user_id)
+        .bind(position_id)
+        .bind(employment_id)
+        .fetch_optional(&mut *connection)
+        .await?;
+
+        if let Some(row) = row {
+            let employment = employment_from_row(&row, "")?;
+            self.audit_in(
+                connection,
+                AuditAction::Update,
+                &employment,
+                Some(&before),
+                Some(&employment),
+            )
+            .await?;
+            if employment.state != before.state {
+                self.record_state_change_in(
+                    connection,
+                    employment.id,
+                    Some(before.state.clone()),
+                    employment.state.clone(),
+                )
+                .await?;
+            }
+            return Ok(employment);
+        }
+        Err(RepositoryError::NotFound)
+    }
+
+    async fn respond_to_invitation(
+        &self,
+        employment_id: i32,
+        accept: bool,
+        now: OffsetDateTime,
+    ) -> Result<Employment, RepositoryError> {
+        let employment = self.get_employment_by_id(employment_id).await?;
+        if employment.state == EmploymentState::Invited && employment.is_invitation_expired_at(now)
+        {
+            self.expire_invitations(now).await?;
+            return Err(RepositoryError::GenericError(
+                "The invitation has expired".to_string(),
+            ));
+        }
+
+        let mut unit_of_work = UnitOfWork::begin_sqlite(&self.pool).await?;
+        let employment = self
+            .respond_to_invitation_in(&mut unit_of_work, employment_id, accept, now)
+            .await?;
+        unit_of_work.commit().await?;
+        Ok(employment)
+    }
+
+    async fn respond_to_invitation_in(
+        &self,
+        unit_of_work: &mut UnitOfWork,
+        employment_id: i32,
+        accept: bool,
+        now: OffsetDateTime,
+    ) -> Result<Employment, RepositoryError> {
+        let employment =
+            Self::get_employment_by_id_in(unit_of_work.sqlite_connection()?, employment_id).await?;
+        if employment.state != EmploymentState::Invited {
+            return Err(RepositoryError::GenericError(
+                "The invitation is no longer open".to_string(),
+            ));
+        }
+        if employment.is_invitation_expired_at(now) {
+            return Err(RepositoryError::GenericError(
+                "The invitation has expired".to_string(),
+            ));
+        }
+
+        let state = if accept {
+            EmploymentState::Accepted
+        } else {
+            EmploymentState::Declined
+        };
+        self.update_employment_in(
+            unit_of_work,
+            employment_id,
+            PartialEmployment {
+                rating: None,
+                state: Some(state),
+                user_id: None,
+                position_id: None,
+            },
+        )
+        .await
+    }
+
+    async fn expire_invitations(
+        &self,
+        now: OffsetDateTime,
+    ) -> Result<Vec<Employment>, RepositoryError> {
+        let mut transaction = self.pool.begin().await?;
+        let rows = sqlx::query(&format!(
+            r#"UPDATE "employment" SET "state" = 'expired'
+            WHERE "state" = 'invited' AND julianday("invitation_expires_at") <= julianday(?)
+            RETURNING {}"#,
+            select_columns("employment", EMPLOYMENT_COLUMNS, "")
+        ))
+        .bind(now)
+        .fetch_all(&mut *transaction)
+        .await?;
+        let expired: Result<Vec<Employment>, sqlx::Error> = rows
+            .iter()
+            .map(|row| employment_from_row(row, ""))
+            .collect();
+        let expired = expired?;
+
+        for employment in &expired {
+            let before = Employment {
+                state: EmploymentState::Invited,
+                ..employment.clone()
+            };
+            self.audit_in(
+                &mut transaction,
+                AuditAction::Update,
+                employment,
+                Some(&before),
+                Some(employment),
+            )
+            .await?;
+            self.record_state_change_in(
+                &mut transaction,
+                employment.id,
+                Some(EmploymentState::Invited),
+                EmploymentState::Expired,
+            )
+            .await?;
+        }
+        transaction.commit().await?;
+        Ok(expired)
+    }
+
+    async fn withdraw_employment(
+        &self,
+        employment_id: i32,
+        reason: Option<String>,
+        now: OffsetDateTime,
+    ) -> Result<Employment, RepositoryError> {
+        let mut transaction = self.pool.begin().await?;
+        let before = Self::get_employment_by_id_in(&mut transaction, employment_id).await?;
+        if !before.state.is_withdrawable() {
+            return Err(RepositoryError::GenericError(
+                "Only pending or accepted jobs can be withdrawn from".to_string(),
+            ));
+        }
+
+        let job_position = SqliteJobPositionRepository::get_job_position_by_id_in(
+            &mut transaction,
+            before.position_id,
+        )
+        .await?;
+        let event =
+            SqliteEventRepository::get_event_by_id_in(&mut transaction, job_position.event_id)
+                .await?;
+
+        if now.date() >= event.date_start {
+            return Err(RepositoryError::GenericError(
+                "The event has already started".to_string(),
+            ));
+        }
+
+        let reason = reason
+            .map(|reason| reason.trim().to_string())
+            .filter(|reason| !reason.is_empty());
+        let is_late = now >= event.withdrawal_cutoff();
+        if is_late && reason.is_none() {
+            return Err(RepositoryError::GenericError(format!(
+                "Withdrawing within {} hours of the event start requires a reason",
+                event.withdrawal_cutoff_hours
+            )));
+        }
+
+        let row = sqlx::query(&format!(
+            r#"UPDATE "employment" SET "state" = 'withdrawn'
+            WHERE "id" = ? AND "state" IN ('pending', 'accepted')
+            RETURNING {}"#,
+            select_columns("employment", EMPLOYMENT_COLUMNS, "")
+        ))
+        .bind(employment_id)
+        .fetch_optional(&mut *transaction)
+        .await?
+        .ok_or(RepositoryError::GenericError(
+            "Only pending or accepted jobs can be withdrawn from".to_string(),
+        ))?;
+        let employment = employment_from_row(&row, "")?;
+
+        sqlx::query(
+            r#"INSERT INTO "employment_withdrawal" ("employment_id", "user_id", "reason", "is_late", "created_at")
+            VALUES (?, ?, ?, ?, ?)"#,
+        )
+        .bind(employment.id)
+        .bind(employment.user_id)
+        .bind(reason)
+        .bind(is_late)
+        .bind(now)
+        .execute(&mut *transaction)
+        .await?;
+
+        self.audit_in(
+            &mut transaction,
+            AuditAction::Update,
+            &employment,
+            Some(&before),
+            Some(&employment),
+        )
+        .await?;
+        self.record_state_change_in(
+            &mut transaction,
+            employment.id,
+            Some(before.state),
+            EmploymentState::Withdrawn,
+        )
+        .await?;
+        transaction.commit().await?;
+        Ok(employment)
+    }
+
+    async fn withdrawal_stats(
+        &self,
+        user_id: i32,
+        organization_id: Option<i32>,
+    ) -> Result<WithdrawalStats, RepositoryError> {
+        let row = sqlx::query(
+            r#"SELECT
+                COUNT(*) AS "total",
+                COUNT(CASE WHEN "employment_withdrawal"."is_late" THEN 1 END) AS "late"
+            FROM "employment_withdrawal"
+            JOIN "employment" ON "employment"."id" = "employment_withdrawal"."employment_id"
+            JOIN "job_position" ON "job_position"."id" = "employment"."position_id"
+            JOIN "event" ON "event"."id" = "job_position"."event_id"
+            WHERE "employment_withdrawal"."user_id" = ?1
+                AND (?2 IS NULL OR "event"."organization_id" = ?2)"#,
+        )
+        .bind(user_id)
+        .bind(organization_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(WithdrawalStats {
+            total: row.try_get("total")?,
+            late: row.try_get("late")?,
+        })
+    }
+
+    async fn list_state_history(
+        &self,
+        employment_ids: &[i32],
+    ) -> Result<Vec<EmploymentStateChange>, RepositoryError> {
+        let mut query_builder = QueryBuilder::new(
+            r#"SELECT
+                "employment_state_history"."id",
+                "employment_state_history"."employment_id",
+                "employment_state_history"."from_state",
+                "employment_state_history"."to_state",
+                "employment_state_history"."changed_by",
+                "user"."first_name" || ' ' || "user"."last_name" AS "changed_by_name",
+                "employment_state_history"."changed_at"
+            FROM "employment_state_history"
+            LEFT JOIN "user" ON "user"."id" = "employment_state_history"."changed_by"
+            WHERE "employment_state_history"."employment_id" IN "#,
+        );
+        push_in_list(&mut query_builder, employment_ids);
+        query_builder.push(
+            r#" ORDER BY julianday("employment_state_history"."changed_at"), "employment_state_history"."id""#,
+        );
+
+        let rows = query_builder.build().fetch_all(&self.pool).await?;
+        let history: Result<Vec<EmploymentStateChange>, sqlx::Error> = rows
+            .iter()
+            .map(|row| {
+                Ok(EmploymentStateChange {
+                    id: row.try_get("id")?,
+                    employment_id: row.try_get("employment_id")?,
+                    from_state: row.try_get("from_state")?,
+                    to_state: row.try_get("to_state")?,
+                    changed_by: row.try_get("changed_by")?,
+                    changed_by_name: row.try_get("changed_by_name")?,
+                    changed_at: row.try_get("changed_at")?,
+                })
+            })
+            .collect();
+        Ok(history?)
+    }
+
+    async fn bulk_update_state(
+        &self,
+        employment_ids: &[i32],
+        state: EmploymentState,
+        order: BulkOrder,
+    ) -> Result<BulkUpdateResult, RepositoryError> {
+        let mut result = BulkUpdateResult::default();
+        let mut transaction = self.pool.begin().await?;
+
+        let mut query_builder = QueryBuilder::new(format!(
+            r#"SELECT {} FROM "employment" WHERE "id" IN "#,
+            select_columns("employment", EMPLOYMENT_COLUMNS, "")
+        ));
+        push_in_list(&mut query_builder, employment_ids);
+        query_builder.push(r#" ORDER BY CASE WHEN "#);
+        query_builder.push_bind(order == BulkOrder::Rating);
+        query_builder.push(r#" THEN "rating" END DESC NULLS LAST, "id""#);
+        let rows = query_builder.build().fetch_all(&mut *transaction).await?;
+        let employments: Result<Vec<Employment>, sqlx::Error> = rows
+            .iter()
+            .map(|row| employment_from_row(row, ""))
+            .collect();
+        let employments = employments?;
+
+        for employment_id in employment_ids {
+            if !employments
+                .iter()
+                .any(|employment| employment.id == *employment_id)
+            {
+                result.skipped.push(SkippedEmployment {
+                    employment_id: *employment_id,
+                    reason: "The employment does not exist".to_string(),
+                });
+            }
+        }
+
+        let position_ids: Vec<i32> = employments
+            .iter()
+            .map(|employment| employment.position_id)
+            .collect();
+        let mut query_builder = QueryBuilder::new(
+            r#"SELECT
+                "job_position"."id",
+                "job_position"."capacity" - COUNT(
+                    CASE WHEN "employment"."state" IN ('accepted', 'done') THEN 1 END
+                ) AS "free"
+            FROM "job_position"
+            LEFT JOIN "employment" ON "employment"."position_id" = "job_position"."id"
+            WHERE "job_position"."id" IN "#,
+        );
+        push_in_list(&mut query_builder, &position_ids);
+        query_builder.push(r#" GROUP BY "job_position"."id""#);
+        let rows = query_builder.build().fetch_all(&mut *transaction).await?;
+        let mut free_capacity = HashMap::new();
+        for row in rows {
+            let id: i32 = row.try_get("id")?;
+            let free: i64 = row.try_get("free")?;
+            free_capacity.insert(id, free);
+        }
+
+        for employment in employments {
+            if !employment.state.can_transition_to(&state) {
+                result.skipped.push(SkippedEmployment {
+                    employment_id: employment.id,
+                    reason: format!(
+                        "An employment cannot move from {} to {}",
+                        employment.state, state
+                    ),
+                });
+                continue;
+            }
+            if state == EmploymentState::Accepted {
+                let free = free_capacity.entry(employment.position_id).or_insert(0);
+                if *free <= 0 {
+                    result.skipped.push(SkippedEmployment {
+                        employment_id: employment.id,
+                        reason: "Job position is already full".to_string(),
+                    });
+                    continue;
+                }
+                *free -= 1;
+            }
+
+            let row = sqlx::query(&format!(
+                r#"UPDATE "employment" SET "state" = ?
+                WHERE "id" = ?
+                RETURNING {}"#,
+                select_columns("employment", EMPLOYMENT_COLUMNS, "")
+            ))
+            .bind(state.clone())
+            .bind(employment.id)
+            .fetch_one(&mut *transaction)
+            .await?;
+            let updated = employment_from_row(&row, "")?;
+            self.record_state_change_in(
+                &mut transaction,
+                employment.id,
+                Some(employment.state.clone()),
+                state.clone(),
+            )
+            .await?;
+            self.audit_in(
+                &mut transaction,
+                AuditAction::Update,
+                &updated,
+                Some(&employment),
+                Some(&updated),
+            )
+            .await?;
+            result.updated.push(updated);
+        }
+        transaction.commit().await?;
+        Ok(result)
+    }
+
+    async fn list_staffing_candidates(
+        &self,
+        job_position_id: i32,
+    ) -> Result<Vec<CandidateStats>, RepositoryError> {
+        let rows = sqlx::query(
+            r#"WITH "target" AS (
+                SELECT
+                    "job_position"."id",
+                    "job_position"."position_category_id",
+                    "event"."date_start",
+                    "event"."date_end",
+                    "event"."organization_id"
+                FROM "job_position"
+                JOIN "event" ON "event"."id" = "job_position"."event_id"
+                WHERE "job_position"."id" = ?
+            )
+            SELECT
+                "user"."id" AS "user_id",
+                "user"."first_name",
+                "user"."last_name",
+                "user"."username",
+                (
+                    SELECT CAST(AVG("employment"."rating") AS REAL)
+                    FROM "employment"
+                    WHERE "employment"."user_id" = "user"."id"
+                        AND "employment"."state" = 'done'
+                        AND "employment"."rating" > 0
+                ) AS "average_rating",
+                (
+                    SELECT COUNT(*)
+                    FROM "employment"
+                    JOIN "job_position" ON "job_position"."id" = "employment"."position_id"
+                    WHERE "employment"."user_id" = "user"."id"
+                        AND "employment"."state" = 'done'
+                        AND "job_position"."position_category_id" = "target"."position_category_id"
+                ) AS "done_in_category",
+                (
+                    SELECT COUNT(*)
+                    FROM "employment"
+                    JOIN "job_position" ON "job_position"."id" = "employment"."position_id"
+                    JOIN "event" ON "event"."id" = "job_position"."event_id"
+                    WHERE "employment"."user_id" = "user"."id"
+                        AND "employment"."state" IN ('pending', 'invited', 'accepted')
+                        AND "event"."deleted_at" IS NULL
+                        AND "event"."date_start" <= "target"."date_end"
+                        AND "event"."date_end" >= "target"."date_start"
+                ) AS "conflicts",
+                (
+                    SELECT COUNT(*)
+                    FROM "attendance_record"
+                    JOIN "employment" ON "employment"."id" = "attendance_record"."employment_id"
+                    WHERE "employment"."user_id" = "user"."id"
+                        AND "attendance_record"."outcome" = 'no_show'
+                ) AS "no_shows"
+            FROM "target"
+            JOIN "organization_membership"
+                ON "organization_membership"."organization_id" = "target"."organization_id"
+            JOIN "user" ON "user"."id" = "organization_membership"."user_id"
+            WHERE "user"."role" = 'employee'
+                AND "user"."deleted_at" IS NULL
+                AND NOT EXISTS (
+                    SELECT 1 FROM "employment"
+                    WHERE "employment"."user_id" = "user"."id"
+                        AND "employment"."position_id" = "target"."id"
+                )"#,
+        )
+        .bind(job_position_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let candidates: Result<Vec<CandidateStats>, sqlx::Error> = rows
+            .iter()
+            .map(|row| {
+                Ok(CandidateStats {
+                    user_id: row.try_get("user_id")?,
+                    first_name: row.try_get("first_name")?,
+                    last_name: row.try_get("last_name")?,
+                    username: row.try_get("username")?,
+                    average_rating: row.try_get("average_rating")?,
+                    done_in_category: row.try_get("done_in_category")?,
+                    conflicts: row.try_get("conflicts")?,
+                    no_shows: row.try_get("no_shows")?,
+                })
+            })
+            .collect();
+        Ok(candidates?)
+    }
+}
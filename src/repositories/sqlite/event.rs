@@ -0,0 +1,844 @@
+use crate::error::RepositoryError;
+use crate::models::audit_log::{AuditAction, AuditEntity};
+use crate::models::employment::{Employment, EmploymentState};
+use crate::models::event::{
+    CancelEvent, CreateEvent, Event, EventStatus, ManagedBy, PartialEvent, SelectManyFilter,
+    SortColumn,
+};
+use crate::models::notification::CreateNotification;
+use crate::models::pagination::{ListSpec, Page};
+use crate::models::trash;
+use crate::repositories::columns::{column, select_columns};
+use crate::repositories::employment::EMPLOYMENT_COLUMNS;
+use crate::repositories::event::{EventRepository, EVENT_COLUMNS};
+use crate::repositories::notification::NotificationRepository;
+use crate::repositories::sqlite::audit_log::SqliteAuditLogRepository;
+use crate::repositories::sqlite::employment::employment_from_row;
+use crate::repositories::sqlite::notification::SqliteNotificationRepository;
+use crate::repositories::sqlite::push_in_list;
+use anyhow::Result;
+use async_trait::async_trait;
+use sqlx::sqlite::SqliteRow;
+use sqlx::types::time::{Date, OffsetDateTime};
+use sqlx::{QueryBuilder, Row, Sqlite, SqliteConnection, SqlitePool};
+use std::sync::Arc;
+
+/// Adds the condition of [`ManagedBy`] on the event whose id is in `event_id_column`.
+pub(crate) fn push_managed_by(
+    query_builder: &mut QueryBuilder<'_, Sqlite>,
+    event_id_column: &str,
+    managed_by: ManagedBy,
+) {
+    let permissions = if managed_by.actions.is_empty() {
+        "TRUE".to_string()
+    } else {
+        managed_by
+            .actions
+            .iter()
+            .map(|action| action.manager_condition())
+            .collect::<Vec<_>>()
+            .join(" OR ")
+    };
+    query_builder.push(format!(
+        r#" AND ({event_id_column} IN (SELECT "id" FROM "event" WHERE "owner_id" = "#
+    ));
+    query_builder.push_bind(managed_by.user_id);
+    query_builder.push(format!(
+        r#") OR {event_id_column} IN (SELECT "event_id" FROM "event_manager_relation" WHERE "user_id" = "#
+    ));
+    query_builder.push_bind(managed_by.user_id);
+    query_builder.push(format!(" AND ({permissions})))"));
+}
+
+#[derive(Debug, Clone)]
+pub struct SqliteEventRepository {
+    pub pool: SqlitePool,
+    pub actor_id: Option<i32>,
+}
+
+impl SqliteEventRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self {
+            pool,
+            actor_id: None,
+        }
+    }
+
+    pub fn with_actor(&self, actor_id: i32) -> Self {
+        Self {
+            pool: self.pool.clone(),
+            actor_id: Some(actor_id),
+        }
+    }
+
+    async fn audit_in(
+        &self,
+        connection: &mut SqliteConnection,
+        action: AuditAction,
+        event_id: i32,
+        before: Option<&Event>,
+        after: Option<&Event>,
+    ) -> Result<(), RepositoryError> {
+        SqliteAuditLogRepository::record_in(
+            connection,
+            self.actor_id,
+            AuditEntity::Event,
+            event_id,
+            Some(event_id),
+            action,
+            before,
+            after,
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Trash actions only reach records of the organization they are taken in.
+    async fn check_in_organization(
+        connection: &mut SqliteConnection,
+        event_id: i32,
+        organization_id: i32,
+    ) -> Result<(), RepositoryError> {
+        let in_organization: bool = sqlx::query_scalar(
+            r#"SELECT EXISTS (
+                SELECT 1 FROM "event" WHERE "id" = ? AND "organization_id" = ?
+            )"#,
+        )
+        .bind(event_id)
+        .bind(organization_id)
+        .fetch_one(connection)
+        .await?;
+
+        if !in_organization {
+            return Err(RepositoryError::NotFound);
+        }
+        Ok(())
+    }
+
+    async fn fetch_event_in(
+        connection: &mut SqliteConnection,
+        query: &str,
+        event_id: i32,
+    ) -> Result<Option<Event>, RepositoryError> {
+        let row = sqlx::query(query)
+            .bind(event_id)
+            .fetch_optional(connection)
+            .await?;
+        Ok(row.map(|row| event_from_row(&row, "")).transpose()?)
+    }
+
+    fn filters_by_state(state: Option<String>, city: Option<String>) -> bool {
+        if state.is_none() && city.is_none() {
+            return false;
+        }
+        true
+    }
+
+    pub(crate) fn push_filter(
+        query_builder: &mut QueryBuilder<'_, Sqlite>,
+        filter: SelectManyFilter,
+    ) {
+        query_builder.push(r#" FROM "event""#);
+
+        if Self::filters_by_state(filter.state.clone(), filter.city.clone()) {
+            query_builder.push(r#" JOIN "venue" ON "event"."venue_id" = "venue"."id""#);
+        }
+
+        query_builder.push(r#" WHERE "event"."deleted_at" IS NULL"#);
+
+        if let Some(organization_id) = filter.organization_id {
+            query_builder.push(r#" AND "event"."organization_id" = "#);
+            query_builder.push_bind(organization_id);
+        }
+
+        if let Some(venue_id) = filter.venue_id {
+            query_builder.push(r#" AND "event"."venue_id" = "#);
+            query_builder.push_bind(venue_id);
+        }
+
+        if let Some(owner_id) = filter.owner_id {
+            query_builder.push(r#" AND "event"."owner_id" = "#);
+            query_builder.push_bind(owner_id);
+        }
+
+        if let Some(status) = filter.status {
+            query_builder.push(r#" AND "event"."status" = "#);
+            query_builder.push_bind(status);
+        }
+
+        if let Some(date_from) = filter.date_from {
+            query_builder.push(r#" AND "event"."date_start" >= "#);
+            query_builder.push_bind(date_from);
+        }
+
+        if let Some(date_to) = filter.date_to {
+            query_builder.push(r#" AND "event"."date_end" <= "#);
+            query_builder.push_bind(date_to);
+        }
+
+        if let Some(state) = filter.state {
+            query_builder.push(r#" AND "venue"."state" = "#);
+            query_builder.push_bind(state);
+        }
+
+        if let Some(city) = filter.city {
+            query_builder.push(r#" AND "venue"."town" = "#);
+            query_builder.push_bind(city);
+        }
+
+        if let Some(name) = filter.name {
+            query_builder.push(r#" AND "event"."name" LIKE '%' || "#);
+            query_builder.push_bind(name);
+            query_builder.push(r#" || '%'"#);
+        }
+
+        if let Some(managed_by) = filter.managed_by {
+            push_managed_by(query_builder, r#""event"."id""#, managed_by);
+        }
+    }
+
+    /// [`EventRepository::get_event_by_id`] on `connection`, such as the one of a
+    /// [`UnitOfWork`](crate::repositories::unit_of_work::UnitOfWork).
+    pub async fn get_event_by_id_in(
+        connection: &mut SqliteConnection,
+        event_id: i32,
+    ) -> Result<Event, RepositoryError> {
+        let row = sqlx::query(&format!(
+            r#"SELECT {} FROM "event" WHERE "id" = ? AND "deleted_at" IS NULL"#,
+            select_columns("event", EVENT_COLUMNS, "")
+        ))
+        .bind(event_id)
+        .fetch_optional(connection)
+        .await?;
+
+        match row {
+            Some(row) => Ok(event_from_row(&row, "")?),
+            None => Err(RepositoryError::NotFound),
+        }
+    }
+}
+
+/// Reads an event selected with [`select_columns`] and `prefix`.
+pub(crate) fn event_from_row(row: &SqliteRow, prefix: &str) -> Result<Event, sqlx::Error> {
+    Ok(Event {
+        id: row.try_get(&*column(prefix, "id"))?,
+        name: row.try_get(&*column(prefix, "name"))?,
+        date_start: row.try_get(&*column(prefix, "date_start"))?,
+        date_end: row.try_get(&*column(prefix, "date_end"))?,
+        img_url: row.try_get(&*column(prefix, "img_url"))?,
+        description: row.try_get(&*column(prefix, "description"))?,
+        status: row.try_get(&*column(prefix, "status"))?,
+        venue_id: row.try_get(&*column(prefix, "venue_id"))?,
+        owner_id: row.try_get(&*column(prefix, "owner_id"))?,
+        cancelled_at: row.try_get(&*column(prefix, "cancelled_at"))?,
+        cancellation_reason: row.try_get(&*column(prefix, "cancellation_reason"))?,
+        publish_at: row.try_get(&*column(prefix, "publish_at"))?,
+        organization_id: row.try_get(&*column(prefix, "organization_id"))?,
+        withdrawal_cutoff_hours: row.try_get(&*column(prefix, "withdrawal_cutoff_hours"))?,
+    })
+}
+
+#[async_trait]
+impl EventRepository for SqliteEventRepository {
+    fn with_actor(&self, actor_id: i32) -> Arc<dyn EventRepository> {
+        Arc::new(SqliteEventRepository::with_actor(self, actor_id))
+    }
+
+    async fn list_events(&self, filter: SelectManyFilter) -> Result<Vec<Event>> {
+        let mut query_builder = QueryBuilder::new(format!(
+            "SELECT {}",
+            select_columns("event", EVENT_COLUMNS, "")
+        ));
+        Self::push_filter(&mut query_builder, filter);
+
+        let query = query_builder.build();
+        let rows = query.fetch_all(&self.pool).await?;
+
+        let data: Result<Vec<Event>, sqlx::Error> =
+            rows.iter().map(|row| event_from_row(row, "")).collect();
+        Ok(data?)
+    }
+
+    async fn list_events_page(
+        &self,
+        filter: SelectManyFilter,
+        spec: &ListSpec<SortColumn>,
+    ) -> Result<Page<Event>> {
+        let mut query_builder = QueryBuilder::new(format!(
+            r#"WITH "list" AS (SELECT {}, "#,
+            select_columns("event", EVENT_COLUMNS, "")
+        ));
+        spec.push_sqlite_sort_key(&mut query_builder);
+        Self::push_filter(&mut query_builder, filter);
+        spec.push_sqlite_page(&mut query_builder);
+
+        let query = query_builder.build();
+        let rows = query.fetch_all(&self.pool).await?;
+
+        let total = match rows.first() {
+            Some(row) => row.try_get("total_count")?,
+            None => 0,
+        };
+        let data: Result<Vec<Event>, sqlx::Error> =
+            rows.iter().map(|row| event_from_row(row, "")).collect();
+        Ok(Page::new(data?, total, spec, |event| event.id))
+    }
+
+    async fn get_event_by_id(&self, event_id: i32) -> Result<Event, RepositoryError> {
+        let mut connection = self.pool.acquire().await?;
+        Self::get_event_by_id_in(&mut connection, event_id).await
+    }
+
+    async fn create_event(&self, new_event: CreateEvent) -> Result<Event, RepositoryError> {
+        if !matches!(
+            new_event.status,
+            EventStatus::Draft | EventStatus::Published
+        ) {
+            return Err(RepositoryError::GenericError(
+                "A new event has to be either a draft or published".to_string(),
+            ));
+        }
+
+        let mut transaction = self.pool.begin().await?;
+        let row = sqlx::query(&format!(
+            r#"INSERT INTO "event"
+            ("name", "date_start", "date_end", "img_url", "description", "status", "venue_id", "owner_id", "publish_at", "organization_id", "withdrawal_cutoff_hours")
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            RETURNING {}"#,
+            select_columns("event", EVENT_COLUMNS, "")
+        ))
+        .bind(new_event.name)
+        .bind(new_event.date_start)
+        .bind(new_event.date_end)
+        .bind(new_event.img_url)
+        .bind(new_event.description)
+        .bind(new_event.status)
+        .bind(new_event.venue_id)
+        .bind(new_event.owner_id)
+        .bind(new_event.publish_at)
+        .bind(new_event.organization_id)
+        .bind(new_event.withdrawal_cutoff_hours)
+        .fetch_one(&mut *transaction)
+        .await?;
+        let event = event_from_row(&row, "")?;
+
+        self.audit_in(
+            &mut transaction,
+            AuditAction::Create,
+            event.id,
+            None,
+            Some(&event),
+        )
+        .await?;
+        transaction.commit().await?;
+        Ok(event)
+    }
+
+    async fn delete_event(&self, event_id: i32) -> Result<(), RepositoryError> {
+        let mut transaction = self.pool.begin().await?;
+        let event = Self::get_event_by_id_in(&mut transaction, event_id).await?;
+        let result = sqlx::query(
+            r#"UPDATE "event" SET "deleted_at" = strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+            WHERE "id" = ? AND "deleted_at" IS NULL"#,
+        )
+        .bind(event_id)
+        .execute(&mut *transaction)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        self.audit_in(
+            &mut transaction,
+            AuditAction::Delete,
+            event_id,
+            Some(&event),
+            None,
+        )
+        .await?;
+        transaction.commit().await?;
+        Ok(())
+    }
+
+    async fn update_event(
+        &self,
+        event_id: i32,
+        patch_event: PartialEvent,
+    ) -> Result<Event, RepositoryError> {
+        let event = self.get_event_by_id(event_id).await?;
+        let before = event.clone();
+
+        let name = patch_event.name.unwrap_or(event.name);
+        let date_start = patch_event.date_start.unwrap_or(event.date_start);
+        let date_end = patch_event.date_end.unwrap_or(event.date_end);
+        let img_url = patch_event.img_url.unwrap_or(event.img_url);
+        let description = patch_event.description.or(event.description);
+        let venue_id = patch_event.venue_id.unwrap_or(event.venue_id);
+        let owner_id = patch_event.owner_id.unwrap_or(event.owner_id);
+        let publish_at = patch_event.publish_at.unwrap_or(event.publish_at);
+        let withdrawal_cutoff_hours = patch_event
+            .withdrawal_cutoff_hours
+            .unwrap_or(event.withdrawal_cutoff_hours);
+
+        let mut transaction = self.pool.begin().await?;
+        let row = sqlx::query(&format!(
+            r#"UPDATE "event" SET
+                "name" = ?,
+                "date_start" = ?,
+                "date_end" = ?,
+                "img_url" = ?,
+                "description" = ?,
+                "venue_id" = ?,
+                "owner_id" = ?,
+                "publish_at" = ?,
+                "withdrawal_cutoff_hours" = ?
+            WHERE "id" = ? AND "deleted_at" IS NULL
+            RETURNING {}"#,
+            select_columns("event", EVENT_COLUMNS, "")
+        ))
+        .bind(name)
+        .bind(date_start)
+        .bind(date_end)
+        .bind(img_url)
+        .bind(description)
+        .bind(venue_id)
+        .bind(owner_id)
+        .bind(publish_at)
+        .bind(withdrawal_cutoff_hours)
+        .bind(event_id)
+        .fetch_optional(&mut *transaction)
+        .await?;
+
+        if let Some(row) = row {
+            let event = event_from_row(&row, "")?;
+            self.audit_in(
+                &mut transaction,
+                AuditAction::Update,
+                event_id,
+                Some(&before),
+                Some(&event),
+            )
+            .await?;
+            transaction.commit().await?;
+            return Ok(event);
+        }
+        Err(RepositoryError::NotFound)
+    }
+
+    async fn list_events_worked_by_user(
+        &self,
+        user_id: i32,
+        organization_id: i32,
+    ) -> Result<Vec<Event>, RepositoryError> {
+        let rows = sqlx::query(&format!(
+            r#"SELECT DISTINCT {}
+            FROM "event"
+            JOIN "job_position" ON "job_position"."event_id" = "event"."id"
+            JOIN "employment" ON "employment"."position_id" = "job_position"."id"
+            WHERE "employment"."state" = 'accepted' AND "employment"."user_id" = ?
+                AND "event"."deleted_at" IS NULL AND "job_position"."deleted_at" IS NULL
+                AND "event"."organization_id" = ?"#,
+            select_columns("event", EVENT_COLUMNS, "")
+        ))
+        .bind(user_id)
+        .bind(organization_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let events: Result<Vec<Event>, sqlx::Error> =
+            rows.iter().map(|row| event_from_row(row, "")).collect();
+        Ok(events?)
+    }
+
+    /// Compensation hours are logged on the event's first day for accepted workers
+    /// who have no worked hours recorded on that day yet.
+    async fn cancel_event(
+        &self,
+        event_id: i32,
+        cancellation: CancelEvent,
+    ) -> Result<Event, RepositoryError> {
+        let before = self.get_event_by_id(event_id).await?;
+        if before.is_cancelled() {
+            return Err(RepositoryError::GenericError(
+                "The event is already cancelled".to_string(),
+            ));
+        }
+        if !before.status.can_transition_to(&EventStatus::Cancelled) {
+            return Err(RepositoryError::GenericError(format!(
+                "A {} event cannot be cancelled",
+                before.status.to_string().to_lowercase()
+            )));
+        }
+
+        let mut transaction = self.pool.begin().await?;
+
+        let row = sqlx::query(&format!(
+            r#"UPDATE "event" SET
+                "status" = 'cancelled',
+                "cancelled_at" = strftime('%Y-%m-%dT%H:%M:%fZ', 'now'),
+                "cancellation_reason" = ?
+            WHERE "id" = ? AND "deleted_at" IS NULL AND "status" = ?
+            RETURNING {}"#,
+            select_columns("event", EVENT_COLUMNS, "")
+        ))
+        .bind(cancellation.reason.clone())
+        .bind(event_id)
+        .bind(before.status)
+        .fetch_optional(&mut *transaction)
+        .await?
+        .ok_or(RepositoryError::NotFound)?;
+        let event = event_from_row(&row, "")?;
+
+        sqlx::query(
+            r#"UPDATE "job_position" SET "is_opened_for_registration" = FALSE
+            WHERE "event_id" = ?"#,
+        )
+        .bind(event_id)
+        .execute(&mut *transaction)
+        .await?;
+
+        let rows = sqlx::query(&format!(
+            r#"SELECT {}
+            FROM "employment"
+            JOIN "job_position" ON "job_position"."id" = "employment"."position_id"
+            WHERE "job_position"."event_id" = ?
+                AND "employment"."state" IN ('pending', 'invited', 'accepted')"#,
+            select_columns("employment", EMPLOYMENT_COLUMNS, "")
+        ))
+        .bind(event_id)
+        .fetch_all(&mut *transaction)
+        .await?;
+        let employments: Result<Vec<Employment>, sqlx::Error> = rows
+            .iter()
+            .map(|row| employment_from_row(row, ""))
+            .collect();
+        let employments = employments?;
+
+        if let Some(hours) = cancellation.compensation_hours.filter(|hours| *hours > 0.0) {
+            sqlx::query(
+                r#"INSERT INTO "worked_hours" ("date", "hours_worked", "employment_id")
+                SELECT ?2, ?3, "employment"."id"
+                FROM "employment"
+                JOIN "job_position" ON "job_position"."id" = "employment"."position_id"
+                WHERE "job_position"."event_id" = ?1
+                    AND "employment"."state" = 'accepted'
+                    AND NOT EXISTS (
+                        SELECT 1 FROM "worked_hours"
+                        WHERE "worked_hours"."employment_id" = "employment"."id"
+                            AND "worked_hours"."date" = ?2
+                    )"#,
+            )
+            .bind(event_id)
+            .bind(event.date_start)
+            .bind(hours)
+            .execute(&mut *transaction)
+            .await?;
+        }
+
+        let employment_ids: Vec<i32> = employments.iter().map(|employment| employment.id).collect();
+        let mut query_builder =
+            QueryBuilder::new(r#"UPDATE "employment" SET "state" = 'cancelled' WHERE "id" IN "#);
+        push_in_list(&mut query_builder, &employment_ids);
+        query_builder.build().execute(&mut *transaction).await?;
+
+        self.audit_in(
+            &mut transaction,
+            AuditAction::Update,
+            event_id,
+            Some(&before),
+            Some(&event),
+        )
+        .await?;
+        for employment in &employments {
+            let cancelled = Employment {
+                state: EmploymentState::Cancelled,
+                ..employment.clone()
+            };
+            SqliteAuditLogRepository::record_in(
+                &mut transaction,
+                self.actor_id,
+                AuditEntity::Employment,
+                employment.id,
+                Some(event_id),
+                AuditAction::Update,
+                Some(employment),
+                Some(&cancelled),
+            )
+            .await?;
+            sqlx::query(
+                r#"INSERT INTO "employment_state_history" ("employment_id", "from_state", "to_state", "changed_by")
+                VALUES (?, ?, 'cancelled', ?)"#,
+            )
+            .bind(employment.id)
+            .bind(employment.state.clone())
+            .bind(self.actor_id)
+            .execute(&mut *transaction)
+            .await?;
+        }
+
+        transaction.commit().await?;
+
+        let notification_repository = SqliteNotificationRepository::new(self.pool.clone());
+        let mut notified_users: Vec<i32> = Vec::new();
+
+        for employment in employments {
+            if notified_users.contains(&employment.user_id) {
+                continue;
+            }
+            notified_users.push(employment.user_id);
+            notification_repository
+                .create_notification(CreateNotification {
+                    user_id: employment.user_id,
+                    message: format!("{} has been cancelled: {}", event.name, cancellation.reason),
+                })
+                .await?;
+        }
+
+        Ok(event)
+    }
+
+    /// Cancellation has to go through `cancel_event` so that employments are resolved as well.
+    async fn transition_event(
+        &self,
+        event_id: i32,
+        status: EventStatus,
+    ) -> Result<Event, RepositoryError> {
+        let before = self.get_event_by_id(event_id).await?;
+        if status == EventStatus::Cancelled {
+            return Err(RepositoryError::GenericError(
+                "Events have to be cancelled with a reason".to_string(),
+            ));
+        }
+        if !before.status.can_transition_to(&status) {
+            return Err(RepositoryError::GenericError(format!(
+                "The event cannot move from {} to {}",
+                before.status.to_string().to_lowercase(),
+                status.to_string().to_lowercase()
+            )));
+        }
+
+        let mut transaction = self.pool.begin().await?;
+        let row = sqlx::query(&format!(
+            r#"UPDATE "event" SET
+                "status" = ?1,
+                "publish_at" = CASE WHEN ?1 = 'published' THEN NULL ELSE "publish_at" END
+            WHERE "id" = ?2 AND "deleted_at" IS NULL AND "status" = ?3
+            RETURNING {}"#,
+            select_columns("event", EVENT_COLUMNS, "")
+        ))
+        .bind(status)
+        .bind(event_id)
+        .bind(before.status)
+        .fetch_optional(&mut *transaction)
+        .await?;
+
+        if let Some(row) = row {
+            let event = event_from_row(&row, "")?;
+            self.audit_in(
+                &mut transaction,
+                AuditAction::Update,
+                event_id,
+                Some(&before),
+                Some(&event),
+            )
+            .await?;
+            transaction.commit().await?;
+            return Ok(event);
+        }
+        Err(RepositoryError::NotFound)
+    }
+
+    /// Drafts, cancelled and archived events are left alone.
+    async fn advance_event_statuses(&self, today: Date) -> Result<Vec<Event>, RepositoryError> {
+        let rows = sqlx::query(&format!(
+            r#"SELECT {} FROM "event"
+            WHERE "deleted_at" IS NULL
+                AND (
+                    ("status" IN ('published', 'registration_closed') AND "date_start" <= ?1)
+                    OR ("status" = 'in_progress' AND "date_end" < ?1)
+                )
+            ORDER BY "id""#,
+            select_columns("event", EVENT_COLUMNS, "")
+        ))
+        .bind(today)
+        .fetch_all(&self.pool)
+        .await?;
+        let due: Result<Vec<Event>, sqlx::Error> =
+            rows.iter().map(|row| event_from_row(row, "")).collect();
+
+        let mut advanced = Vec::new();
+        for event in due? {
+            let mut event = event;
+            if event.status != EventStatus::InProgress {
+                event = self
+                    .transition_event(event.id, EventStatus::InProgress)
+                    .await?;
+            }
+            if event.date_end < today {
+                event = self
+                    .transition_event(event.id, EventStatus::Completed)
+                    .await?;
+            }
+            advanced.push(event);
+        }
+
+        Ok(advanced)
+    }
+
+    async fn publish_scheduled_events(
+        &self,
+        now: OffsetDateTime,
+    ) -> Result<Vec<Event>, RepositoryError> {
+        let due: Vec<i32> = sqlx::query_scalar(
+            r#"SELECT "id" FROM "event"
+            WHERE "deleted_at" IS NULL AND "status" = 'draft'
+                AND julianday("publish_at") <= julianday(?)
+            ORDER BY "id""#,
+        )
+        .bind(now)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut published = Vec::new();
+        for event_id in due {
+            published.push(
+                self.transition_event(event_id, EventStatus::Published)
+                    .await?,
+            );
+        }
+
+        Ok(published)
+    }
+
+    async fn list_deleted_events(
+        &self,
+        organization_id: i32,
+        spec: &ListSpec<trash::SortColumn>,
+    ) -> Result<Page<Event>, RepositoryError> {
+        let mut query_builder = QueryBuilder::new(format!(
+            r#"WITH "list" AS (SELECT {}, "#,
+            select_columns("event", EVENT_COLUMNS, "")
+        ));
+        spec.push_sqlite_sort_key(&mut query_builder);
+        query_builder
+            .push(r#" FROM "event" WHERE "deleted_at" IS NOT NULL AND "organization_id" = "#);
+        query_builder.push_bind(organization_id);
+        spec.push_sqlite_page(&mut query_builder);
+
+        let rows = query_builder.build().fetch_all(&self.pool).await?;
+
+        let total = match rows.first() {
+            Some(row) => row.try_get("total_count")?,
+            None => 0,
+        };
+        let data: Result<Vec<Event>, sqlx::Error> =
+            rows.iter().map(|row| event_from_row(row, "")).collect();
+        Ok(Page::new(data?, total, spec, |event| event.id))
+    }
+
+    async fn restore_event(
+        &self,
+        event_id: i32,
+        organization_id: i32,
+    ) -> Result<Event, RepositoryError> {
+        let mut transaction = self.pool.begin().await?;
+        Self::check_in_organization(&mut transaction, event_id, organization_id).await?;
+        let has_deleted_dependencies: bool = sqlx::query_scalar(
+            r#"SELECT EXISTS (
+                SELECT 1 FROM "event"
+                JOIN "venue" ON "venue"."id" = "event"."venue_id"
+                JOIN "user" ON "user"."id" = "event"."owner_id"
+                WHERE "event"."id" = ?
+                    AND ("venue"."deleted_at" IS NOT NULL OR "user"."deleted_at" IS NOT NULL)
+            )"#,
+        )
+        .bind(event_id)
+        .fetch_one(&mut *transaction)
+        .await?;
+
+        if has_deleted_dependencies {
+            return Err(RepositoryError::GenericError(
+                "The event's venue or owner has to be restored first".to_string(),
+            ));
+        }
+
+        let event = Self::fetch_event_in(
+            &mut transaction,
+            &format!(
+                r#"UPDATE "event" SET "deleted_at" = NULL
+                WHERE "id" = ? AND "deleted_at" IS NOT NULL
+                RETURNING {}"#,
+                select_columns("event", EVENT_COLUMNS, "")
+            ),
+            event_id,
+        )
+        .await?;
+
+        if let Some(event) = event {
+            self.audit_in(
+                &mut transaction,
+                AuditAction::Restore,
+                event_id,
+                None,
+                Some(&event),
+            )
+            .await?;
+            transaction.commit().await?;
+            return Ok(event);
+        }
+        Err(RepositoryError::NotFound)
+    }
+
+    async fn purge_event(
+        &self,
+        event_id: i32,
+        organization_id: i32,
+    ) -> Result<(), RepositoryError> {
+        let mut transaction = self.pool.begin().await?;
+        Self::check_in_organization(&mut transaction, event_id, organization_id).await?;
+        let has_worked_hours: bool = sqlx::query_scalar(
+            r#"SELECT EXISTS (
+                SELECT 1 FROM "worked_hours"
+                JOIN "employment" ON "employment"."id" = "worked_hours"."employment_id"
+                JOIN "job_position" ON "job_position"."id" = "employment"."position_id"
+                WHERE "job_position"."event_id" = ?
+            )"#,
+        )
+        .bind(event_id)
+        .fetch_one(&mut *transaction)
+        .await?;
+
+        if has_worked_hours {
+            return Err(RepositoryError::GenericError(
+                "The event has logged worked hours and cannot be purged".to_string(),
+            ));
+        }
+
+        let event = Self::fetch_event_in(
+            &mut transaction,
+            &format!(
+                r#"DELETE FROM "event"
+                WHERE "id" = ? AND "deleted_at" IS NOT NULL
+                RETURNING {}"#,
+                select_columns("event", EVENT_COLUMNS, "")
+            ),
+            event_id,
+        )
+        .await?;
+
+        if let Some(event) = event {
+            self.audit_in(
+                &mut transaction,
+                AuditAction::Purge,
+                event_id,
+                Some(&event),
+                None,
+            )
+            .await?;
+            transaction.commit().await?;
+            return Ok(());
+        }
+        Err(RepositoryError::NotFound)
+    }
+}
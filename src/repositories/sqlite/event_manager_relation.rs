@@ -0,0 +1,200 @@
+use crate::error::RepositoryError;
+use crate::models::event_manager_relation::{
+    CreateEventManagerRelation, EventManagerPermissions, EventManagerRelation,
+};
+use crate::repositories::event_manager_relation::EventManagerRelationRepository;
+use anyhow::Result;
+use async_trait::async_trait;
+use sqlx::sqlite::SqliteRow;
+use sqlx::{Row, SqliteConnection, SqlitePool};
+
+const RELATION_COLUMNS: &str = r#""user_id",
+    "event_id",
+    "can_edit_event",
+    "can_manage_jobs",
+    "can_manage_applicants",
+    "can_approve_hours",
+    "can_view_payroll",
+    "can_manage_managers""#;
+
+#[derive(Debug, Clone)]
+pub struct SqliteEventManagerRelationRepository {
+    pub pool: SqlitePool,
+}
+
+impl SqliteEventManagerRelationRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    async fn check_add(
+        connection: &mut SqliteConnection,
+        new_relation: &CreateEventManagerRelation,
+    ) -> Result<(), RepositoryError> {
+        let existing_record = sqlx::query(
+            r#"SELECT 1 FROM "event_manager_relation" WHERE "event_id" = ? AND "user_id" = ?"#,
+        )
+        .bind(new_relation.event_id)
+        .bind(new_relation.user_id)
+        .fetch_optional(connection)
+        .await?;
+
+        if existing_record.is_some() {
+            return Err(RepositoryError::GenericError(
+                "The event has already been registered".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+fn relation_from_row(row: &SqliteRow) -> Result<EventManagerRelation, sqlx::Error> {
+    Ok(EventManagerRelation {
+        user_id: row.try_get("user_id")?,
+        event_id: row.try_get("event_id")?,
+        can_edit_event: row.try_get("can_edit_event")?,
+        can_manage_jobs: row.try_get("can_manage_jobs")?,
+        can_manage_applicants: row.try_get("can_manage_applicants")?,
+        can_approve_hours: row.try_get("can_approve_hours")?,
+        can_view_payroll: row.try_get("can_view_payroll")?,
+        can_manage_managers: row.try_get("can_manage_managers")?,
+    })
+}
+
+#[async_trait]
+impl EventManagerRelationRepository for SqliteEventManagerRelationRepository {
+    async fn create_relation(
+        &self,
+        new_relation: CreateEventManagerRelation,
+    ) -> Result<EventManagerRelation, RepositoryError> {
+        let mut connection = self.pool.acquire().await?;
+        Self::check_add(&mut connection, &new_relation).await?;
+        let row = sqlx::query(&format!(
+            r#"INSERT INTO "event_manager_relation" ("user_id", "event_id")
+            VALUES (?, ?)
+            RETURNING {RELATION_COLUMNS}"#
+        ))
+        .bind(new_relation.user_id)
+        .bind(new_relation.event_id)
+        .fetch_one(&mut *connection)
+        .await?;
+
+        Ok(relation_from_row(&row)?)
+    }
+
+    async fn delete_relation(&self, relation: EventManagerRelation) -> Result<(), RepositoryError> {
+        let result = sqlx::query(
+            r#"DELETE FROM "event_manager_relation"
+            WHERE "user_id" = ? AND "event_id" = ?"#,
+        )
+        .bind(relation.user_id)
+        .bind(relation.event_id)
+        .execute(&self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn get_relation(
+        &self,
+        event_id: i32,
+        user_id: i32,
+    ) -> Result<EventManagerRelation, RepositoryError> {
+        let row = sqlx::query(&format!(
+            r#"SELECT {RELATION_COLUMNS}
+            FROM "event_manager_relation"
+            WHERE "event_id" = ? AND "user_id" = ?"#
+        ))
+        .bind(event_id)
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        match row {
+            Some(row) => Ok(relation_from_row(&row)?),
+            None => Err(RepositoryError::NotFound),
+        }
+    }
+
+    async fn update_permissions(
+        &self,
+        event_id: i32,
+        user_id: i32,
+        permissions: EventManagerPermissions,
+    ) -> Result<EventManagerRelation, RepositoryError> {
+        let row = sqlx::query(&format!(
+            r#"UPDATE "event_manager_relation" SET
+                "can_edit_event" = ?,
+                "can_manage_jobs" = ?,
+                "can_manage_applicants" = ?,
+                "can_approve_hours" = ?,
+                "can_view_payroll" = ?,
+                "can_manage_managers" = ?
+            WHERE "event_id" = ? AND "user_id" = ?
+            RETURNING {RELATION_COLUMNS}"#
+        ))
+        .bind(permissions.can_edit_event)
+        .bind(permissions.can_manage_jobs)
+        .bind(permissions.can_manage_applicants)
+        .bind(permissions.can_approve_hours)
+        .bind(permissions.can_view_payroll)
+        .bind(permissions.can_manage_managers)
+        .bind(event_id)
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        match row {
+            Some(row) => Ok(relation_from_row(&row)?),
+            None => Err(RepositoryError::NotFound),
+        }
+    }
+
+    async fn list_event_managers(
+        &self,
+        event_id: i32,
+    ) -> Result<Vec<EventManagerRelation>, RepositoryError> {
+        let rows = sqlx::query(&format!(
+            r#"SELECT {RELATION_COLUMNS}
+            FROM "event_manager_relation"
+            WHERE "event_id" = ?
+                AND "user_id" IN (SELECT "id" FROM "user" WHERE "deleted_at" IS NULL)"#
+        ))
+        .bind(event_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let relations: Result<Vec<EventManagerRelation>, sqlx::Error> =
+            rows.iter().map(relation_from_row).collect();
+        Ok(relations?)
+    }
+
+    async fn list_managers_events(
+        &self,
+        user_id: i32,
+        organization_id: i32,
+    ) -> Result<Vec<EventManagerRelation>, RepositoryError> {
+        let rows = sqlx::query(&format!(
+            r#"SELECT {RELATION_COLUMNS}
+            FROM "event_manager_relation"
+            WHERE "user_id" = ?
+                AND "event_id" IN (
+                    SELECT "id" FROM "event"
+                    WHERE "deleted_at" IS NULL AND "organization_id" = ?
+                )"#
+        ))
+        .bind(user_id)
+        .bind(organization_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let relations: Result<Vec<EventManagerRelation>, sqlx::Error> =
+            rows.iter().map(relation_from_row).collect();
+        Ok(relations?)
+    }
+}
@@ -0,0 +1,694 @@
+use crate::error::RepositoryError;
+use crate::models::audit_log::{AuditAction, AuditEntity};
+use crate::models::job_position::{
+    CreateJobPosition, JobPosition, PartialJobPosition, SelectManyFilter, SortColumn,
+};
+use crate::models::pagination::{ListSpec, Page};
+use crate::models::trash;
+use crate::repositories::columns::{column, select_columns};
+use crate::repositories::job_position::{JobPositionRepository, JOB_POSITION_COLUMNS};
+use crate::repositories::sqlite::audit_log::SqliteAuditLogRepository;
+use crate::repositories::sqlite::event::{push_managed_by, SqliteEventRepository};
+use crate::repositories::unit_of_work::UnitOfWork;
+use crate::rich_text::{render_markdown, sanitize_html};
+use anyhow::Result;
+use async_trait::async_trait;
+use sqlx::sqlite::SqliteRow;
+use sqlx::types::time::OffsetDateTime;
+use sqlx::{QueryBuilder, Row, Sqlite, SqliteConnection, SqlitePool};
+use std::sync::Arc;
+
+#[derive(Debug, Clone)]
+pub struct SqliteJobPositionRepository {
+    pub pool: SqlitePool,
+    pub actor_id: Option<i32>,
+}
+
+impl SqliteJobPositionRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self {
+            pool,
+            actor_id: None,
+        }
+    }
+
+    pub fn with_actor(&self, actor_id: i32) -> Self {
+        Self {
+            pool: self.pool.clone(),
+            actor_id: Some(actor_id),
+        }
+    }
+
+    /// Renders the instructions whose HTML was cleared by the migration to Markdown.
+    /// Returns how many job positions were rendered.
+    pub async fn sanitize_legacy_instructions(&self) -> Result<usize, RepositoryError> {
+        let mut transaction = self.pool.begin().await?;
+        let pending: Vec<(i32, String)> = sqlx::query_as(
+            r#"SELECT "id", "instructions_markdown" FROM "job_position"
+            WHERE "instructions_html" = '' AND "instructions_markdown" <> ''"#,
+        )
+        .fetch_all(&mut *transaction)
+        .await?;
+
+        for (id, instructions_markdown) in &pending {
+            sqlx::query(r#"UPDATE "job_position" SET "instructions_html" = ? WHERE "id" = ?"#)
+                .bind(sanitize_html(instructions_markdown))
+                .bind(id)
+                .execute(&mut *transaction)
+                .await?;
+        }
+
+        transaction.commit().await?;
+        Ok(pending.len())
+    }
+
+    async fn audit_in(
+        &self,
+        connection: &mut SqliteConnection,
+        action: AuditAction,
+        job_position: &JobPosition,
+        before: Option<&JobPosition>,
+        after: Option<&JobPosition>,
+    ) -> Result<(), RepositoryError> {
+        SqliteAuditLogRepository::record_in(
+            connection,
+            self.actor_id,
+            AuditEntity::JobPosition,
+            job_position.id,
+            Some(job_position.event_id),
+            action,
+            before,
+            after,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn check_in_organization(
+        connection: &mut SqliteConnection,
+        position_id: i32,
+        organization_id: i32,
+    ) -> Result<(), RepositoryError> {
+        let in_organization: bool = sqlx::query_scalar(
+            r#"SELECT EXISTS (
+                SELECT 1 FROM "job_position"
+                JOIN "event" ON "event"."id" = "job_position"."event_id"
+                WHERE "job_position"."id" = ? AND "event"."organization_id" = ?
+            )"#,
+        )
+        .bind(position_id)
+        .bind(organization_id)
+        .fetch_one(connection)
+        .await?;
+
+        if !in_organization {
+            return Err(RepositoryError::NotFound);
+        }
+        Ok(())
+    }
+
+    fn check_registration_window(
+        opens_at: Option<OffsetDateTime>,
+        closes_at: Option<OffsetDateTime>,
+    ) -> Result<(), RepositoryError> {
+        if let (Some(opens_at), Some(closes_at)) = (opens_at, closes_at) {
+            if closes_at <= opens_at {
+                return Err(RepositoryError::GenericError(
+                    "Registration has to close after it opens".to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    async fn check_add(
+        connection: &mut SqliteConnection,
+        new_position: &CreateJobPosition,
+    ) -> Result<(), RepositoryError> {
+        Self::check_registration_window(
+            new_position.registration_opens_at,
+            new_position.registration_closes_at,
+        )?;
+
+        let event =
+            SqliteEventRepository::get_event_by_id_in(connection, new_position.event_id).await?;
+
+        if event.date_end < OffsetDateTime::now_utc().date() {
+            return Err(RepositoryError::GenericError(
+                "The event has already ended".to_string(),
+            ));
+        }
+
+        if event.is_cancelled() {
+            return Err(RepositoryError::GenericError(
+                "The event has been cancelled".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    async fn fetch_job_position_in(
+        connection: &mut SqliteConnection,
+        query: &str,
+        position_id: i32,
+    ) -> Result<Option<JobPosition>, RepositoryError> {
+        let row = sqlx::query(query)
+            .bind(position_id)
+            .fetch_optional(connection)
+            .await?;
+        Ok(row.map(|row| job_position_from_row(&row, "")).transpose()?)
+    }
+
+    /// [`JobPositionRepository::get_job_position_by_id`] on `connection`, such as the one of
+    /// a [`UnitOfWork`].
+    pub async fn get_job_position_by_id_in(
+        connection: &mut SqliteConnection,
+        position_id: i32,
+    ) -> Result<JobPosition, RepositoryError> {
+        let row = sqlx::query(&format!(
+            r#"SELECT {} FROM "job_position" WHERE "id" = ? AND "deleted_at" IS NULL"#,
+            select_columns("job_position", JOB_POSITION_COLUMNS, "")
+        ))
+        .bind(position_id)
+        .fetch_optional(connection)
+        .await?;
+
+        match row {
+            Some(row) => Ok(job_position_from_row(&row, "")?),
+            None => Err(RepositoryError::NotFound),
+        }
+    }
+
+    pub(crate) fn push_filter(
+        query_builder: &mut QueryBuilder<'_, Sqlite>,
+        filter: SelectManyFilter,
+    ) {
+        query_builder.push(
+            r#" FROM "job_position"
+                JOIN "event" ON "event"."id" = "job_position"."event_id"
+                WHERE "job_position"."deleted_at" IS NULL AND "event"."deleted_at" IS NULL"#,
+        );
+
+        if let Some(organization_id) = filter.organization_id {
+            query_builder.push(r#" AND "event"."organization_id" = "#);
+            query_builder.push_bind(organization_id);
+        }
+
+        if let Some(capacity) = filter.capacity {
+            query_builder.push(r#" AND "job_position"."capacity" = "#);
+            query_builder.push_bind(capacity);
+        }
+
+        if let Some(position_category_id) = filter.position_category_id {
+            query_builder.push(r#" AND "job_position"."position_category_id" = "#);
+            query_builder.push_bind(position_category_id);
+        }
+
+        if let Some(event_id) = filter.event_id {
+            query_builder.push(r#" AND "job_position"."event_id" = "#);
+            query_builder.push_bind(event_id);
+        }
+
+        if let Some(salary) = filter.salary {
+            query_builder.push(r#" AND "job_position"."salary" = "#);
+            query_builder.push_bind(salary);
+        }
+
+        if let Some(is_opened_for_registration) = filter.is_opened_for_registration {
+            query_builder.push(r#" AND "job_position"."is_opened_for_registration" = "#);
+            query_builder.push_bind(is_opened_for_registration);
+        }
+
+        if let Some(managed_by) = filter.managed_by {
+            push_managed_by(query_builder, r#""event"."id""#, managed_by);
+        }
+    }
+}
+
+/// Reads a job position selected with [`select_columns`] and `prefix`.
+pub(crate) fn job_position_from_row(
+    row: &SqliteRow,
+    prefix: &str,
+) -> Result<JobPosition, sqlx::Error> {
+    Ok(JobPosition {
+        id: row.try_get(&*column(prefix, "id"))?,
+        name: row.try_get(&*column(prefix, "name"))?,
+        description: row.try_get(&*column(prefix, "description"))?,
+        salary: row.try_get(&*column(prefix, "salary"))?,
+        currency: row.try_get(&*column(prefix, "currency"))?,
+        capacity: row.try_get(&*column(prefix, "capacity"))?,
+        instructions_html: row.try_get(&*column(prefix, "instructions_html"))?,
+        instructions_markdown: row.try_get(&*column(prefix, "instructions_markdown"))?,
+        is_opened_for_registration: row.try_get(&*column(prefix, "is_opened_for_registration"))?,
+        event_id: row.try_get(&*column(prefix, "event_id"))?,
+        position_category_id: row.try_get(&*column(prefix, "position_category_id"))?,
+        registration_opens_at: row.try_get(&*column(prefix, "registration_opens_at"))?,
+        registration_closes_at: row.try_get(&*column(prefix, "registration_closes_at"))?,
+    })
+}
+
+#[async_trait]
+impl JobPositionRepository for SqliteJobPositionRepository {
+    fn with_actor(&self, actor_id: i32) -> Arc<dyn JobPositionRepository> {
+        Arc::new(SqliteJobPositionRepository::with_actor(self, actor_id))
+    }
+
+    async fn list_job_positions(&self, filter: SelectManyFilter) -> Result<Vec<JobPosition>> {
+        let mut query_builder = QueryBuilder::new(format!(
+            "SELECT {}",
+            select_columns("job_position", JOB_POSITION_COLUMNS, "")
+        ));
+        Self::push_filter(&mut query_builder, filter);
+
+        let query = query_builder.build();
+        let rows = query.fetch_all(&self.pool).await?;
+
+        let data: Result<Vec<JobPosition>, sqlx::Error> = rows
+            .iter()
+            .map(|row| job_position_from_row(row, ""))
+            .collect();
+        Ok(data?)
+    }
+
+    async fn list_job_positions_page(
+        &self,
+        filter: SelectManyFilter,
+        spec: &ListSpec<SortColumn>,
+    ) -> Result<Page<JobPosition>> {
+        let mut query_builder = QueryBuilder::new(format!(
+            r#"WITH "list" AS (SELECT {}, "#,
+            select_columns("job_position", JOB_POSITION_COLUMNS, "")
+        ));
+        spec.push_sqlite_sort_key(&mut query_builder);
+        Self::push_filter(&mut query_builder, filter);
+        spec.push_sqlite_page(&mut query_builder);
+
+        let query = query_builder.build();
+        let rows = query.fetch_all(&self.pool).await?;
+
+        let total = match rows.first() {
+            Some(row) => row.try_get("total_count")?,
+            None => 0,
+        };
+        let data: Result<Vec<JobPosition>, sqlx::Error> = rows
+            .iter()
+            .map(|row| job_position_from_row(row, ""))
+            .collect();
+        Ok(Page::new(data?, total, spec, |position| position.id))
+    }
+
+    async fn get_job_position_by_id(
+        &self,
+        position_id: i32,
+    ) -> Result<JobPosition, RepositoryError> {
+        let mut connection = self.pool.acquire().await?;
+        Self::get_job_position_by_id_in(&mut connection, position_id).await
+    }
+
+    async fn create_job_position(
+        &self,
+        new_position: CreateJobPosition,
+    ) -> Result<JobPosition, RepositoryError> {
+        let mut unit_of_work = UnitOfWork::begin_sqlite(&self.pool).await?;
+        let job_position = self
+            .create_job_position_in(&mut unit_of_work, new_position)
+            .await?;
+        unit_of_work.commit().await?;
+        Ok(job_position)
+    }
+
+    async fn create_job_position_in(
+        &self,
+        unit_of_work: &mut UnitOfWork,
+        new_position: CreateJobPosition,
+    ) -> Result<JobPosition, RepositoryError> {
+        let connection = unit_of_work.sqlite_connection()?;
+        Self::check_add(connection, &new_position).await?;
+        let row = sqlx::query(&format!(
+            r#"INSERT INTO "job_position"
+            ("name", "description", "salary", "capacity", "instructions_html", "instructions_markdown", "is_opened_for_registration", "currency", "event_id", "position_category_id", "registration_opens_at", "registration_closes_at")
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            RETURNING {}"#,
+            select_columns("job_position", JOB_POSITION_COLUMNS, "")
+        ))
+        .bind(new_position.name)
+        .bind(new_position.description)
+        .bind(new_position.salary)
+        .bind(new_position.capacity)
+        .bind(render_markdown(&new_position.instructions_markdown))
+        .bind(new_position.instructions_markdown)
+        .bind(new_position.is_opened_for_registration)
+        .bind(new_position.currency)
+        .bind(new_position.event_id)
+        .bind(new_position.position_category_id)
+        .bind(new_position.registration_opens_at)
+        .bind(new_position.registration_closes_at)
+        .fetch_one(&mut *connection)
+        .await?;
+        let job_position = job_position_from_row(&row, "")?;
+
+        self.audit_in(
+            connection,
+            AuditAction::Create,
+            &job_position,
+            None,
+            Some(&job_position),
+        )
+        .await?;
+        Ok(job_position)
+    }
+
+    async fn delete_job_position(&self, position_id: i32) -> Result<(), RepositoryError> {
+        let mut transaction = self.pool.begin().await?;
+        let job_position = Self::get_job_position_by_id_in(&mut transaction, position_id).await?;
+        let result = sqlx::query(
+            r#"UPDATE "job_position" SET "deleted_at" = strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+            WHERE "id" = ? AND "deleted_at" IS NULL"#,
+        )
+        .bind(position_id)
+        .execute(&mut *transaction)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        self.audit_in(
+            &mut transaction,
+            AuditAction::Delete,
+            &job_position,
+            Some(&job_position),
+            None,
+        )
+        .await?;
+        transaction.commit().await?;
+        Ok(())
+    }
+
+    async fn update_job_position(
+        &self,
+        position_id: i32,
+        patch_position: PartialJobPosition,
+    ) -> Result<JobPosition, RepositoryError> {
+        let mut transaction = self.pool.begin().await?;
+        let job_position = Self::get_job_position_by_id_in(&mut transaction, position_id).await?;
+        let before = job_position.clone();
+
+        let name = patch_position.name.unwrap_or(job_position.name);
+        let description = patch_position.description.or(job_position.description);
+        let salary = patch_position.salary.unwrap_or(job_position.salary);
+        let capacity = patch_position.capacity.unwrap_or(job_position.capacity);
+        let currency = patch_position.currency.unwrap_or(job_position.currency);
+        let (instructions_html, instructions_markdown) = match patch_position.instructions_markdown
+        {
+            Some(markdown) => (render_markdown(&markdown), markdown),
+            None => (
+                job_position.instructions_html,
+                job_position.instructions_markdown,
+            ),
+        };
+        let is_opened_for_registration = patch_position
+            .is_opened_for_registration
+            .unwrap_or(job_position.is_opened_for_registration);
+        let event_id = patch_position.event_id.unwrap_or(job_position.event_id);
+        let position_category_id = patch_position
+            .position_category_id
+            .unwrap_or(job_position.position_category_id);
+        let registration_opens_at = patch_position
+            .registration_opens_at
+            .unwrap_or(job_position.registration_opens_at);
+        let registration_closes_at = patch_position
+            .registration_closes_at
+            .unwrap_or(job_position.registration_closes_at);
+        Self::check_registration_window(registration_opens_at, registration_closes_at)?;
+
+        let row = sqlx::query(&format!(
+            r#"UPDATE "job_position" SET
+                "name" = ?,
+                "description" = ?,
+                "salary" = ?,
+                "capacity" = ?,
+                "currency" = ?,
+                "instructions_html" = ?,
+                "instructions_markdown" = ?,
+                "is_opened_for_registration" = ?,
+                "event_id" = ?,
+                "position_category_id" = ?,
+                "registration_opens_at" = ?,
+                "registration_closes_at" = ?
+            WHERE "id" = ? AND "deleted_at" IS NULL
+            RETURNING {}"#,
+            select_columns("job_position", JOB_POSITION_COLUMNS, "")
+        ))
+        .bind(name)
+        .bind(description)
+        .bind(salary)
+        .bind(capacity)
+        .bind(currency)
+        .bind(instructions_html)
+        .bind(instructions_markdown)
+        .bind(is_opened_for_registration)
+        .bind(event_id)
+        .bind(position_category_id)
+        .bind(registration_opens_at)
+        .bind(registration_closes_at)
+        .bind(position_id)
+        .fetch_optional(&mut *transaction)
+        .await?;
+
+        if let Some(row) = row {
+            let job_position = job_position_from_row(&row, "")?;
+            self.audit_in(
+                &mut transaction,
+                AuditAction::Update,
+                &job_position,
+                Some(&before),
+                Some(&job_position),
+            )
+            .await?;
+            transaction.commit().await?;
+            return Ok(job_position);
+        }
+        Err(RepositoryError::NotFound)
+    }
+
+    async fn list_job_positions_worked_by_user_on_event(
+        &self,
+        user_id: i32,
+        event_id: i32,
+    ) -> Result<Vec<JobPosition>> {
+        let rows = sqlx::query(&format!(
+            r#"SELECT DISTINCT {}
+            FROM "job_position"
+            JOIN "employment" ON "employment"."position_id" = "job_position"."id"
+            JOIN "event" ON "job_position"."event_id" = "event"."id"
+            WHERE "employment"."user_id" = ? AND "event"."id" = ?
+                AND "job_position"."deleted_at" IS NULL AND "event"."deleted_at" IS NULL"#,
+            select_columns("job_position", JOB_POSITION_COLUMNS, "")
+        ))
+        .bind(user_id)
+        .bind(event_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let jobs: Result<Vec<JobPosition>, sqlx::Error> = rows
+            .iter()
+            .map(|row| job_position_from_row(row, ""))
+            .collect();
+        Ok(jobs?)
+    }
+
+    async fn list_deleted_job_positions(
+        &self,
+        organization_id: i32,
+        spec: &ListSpec<trash::SortColumn>,
+    ) -> Result<Page<JobPosition>, RepositoryError> {
+        let mut query_builder = QueryBuilder::new(format!(
+            r#"WITH "list" AS (SELECT {}, "#,
+            select_columns("job_position", JOB_POSITION_COLUMNS, "")
+        ));
+        spec.push_sqlite_sort_key(&mut query_builder);
+        query_builder.push(
+            r#" FROM "job_position"
+            WHERE "deleted_at" IS NOT NULL
+                AND "event_id" IN (SELECT "id" FROM "event" WHERE "organization_id" = "#,
+        );
+        query_builder.push_bind(organization_id);
+        query_builder.push(")");
+        spec.push_sqlite_page(&mut query_builder);
+
+        let rows = query_builder.build().fetch_all(&self.pool).await?;
+
+        let total = match rows.first() {
+            Some(row) => row.try_get("total_count")?,
+            None => 0,
+        };
+        let data: Result<Vec<JobPosition>, sqlx::Error> = rows
+            .iter()
+            .map(|row| job_position_from_row(row, ""))
+            .collect();
+        Ok(Page::new(data?, total, spec, |job_position| {
+            job_position.id
+        }))
+    }
+
+    async fn restore_job_position(
+        &self,
+        position_id: i32,
+        organization_id: i32,
+    ) -> Result<JobPosition, RepositoryError> {
+        let mut transaction = self.pool.begin().await?;
+        Self::check_in_organization(&mut transaction, position_id, organization_id).await?;
+        let job_position = Self::fetch_job_position_in(
+            &mut transaction,
+            &format!(
+                r#"UPDATE "job_position" SET "deleted_at" = NULL
+                WHERE "id" = ? AND "deleted_at" IS NOT NULL
+                RETURNING {}"#,
+                select_columns("job_position", JOB_POSITION_COLUMNS, "")
+            ),
+            position_id,
+        )
+        .await?;
+
+        if let Some(job_position) = job_position {
+            self.audit_in(
+                &mut transaction,
+                AuditAction::Restore,
+                &job_position,
+                None,
+                Some(&job_position),
+            )
+            .await?;
+            transaction.commit().await?;
+            return Ok(job_position);
+        }
+        Err(RepositoryError::NotFound)
+    }
+
+    async fn purge_job_position(
+        &self,
+        position_id: i32,
+        organization_id: i32,
+    ) -> Result<(), RepositoryError> {
+        let mut transaction = self.pool.begin().await?;
+        Self::check_in_organization(&mut transaction, position_id, organization_id).await?;
+        let has_worked_hours: bool = sqlx::query_scalar(
+            r#"SELECT EXISTS (
+                SELECT 1 FROM "worked_hours"
+                JOIN "employment" ON "employment"."id" = "worked_hours"."employment_id"
+                WHERE "employment"."position_id" = ?
+            )"#,
+        )
+        .bind(position_id)
+        .fetch_one(&mut *transaction)
+        .await?;
+
+        if has_worked_hours {
+            return Err(RepositoryError::GenericError(
+                "The job has logged worked hours and cannot be purged".to_string(),
+            ));
+        }
+
+        let job_position = Self::fetch_job_position_in(
+            &mut transaction,
+            &format!(
+                r#"DELETE FROM "job_position"
+                WHERE "id" = ? AND "deleted_at" IS NOT NULL
+                RETURNING {}"#,
+                select_columns("job_position", JOB_POSITION_COLUMNS, "")
+            ),
+            position_id,
+        )
+        .await?;
+
+        if let Some(job_position) = job_position {
+            self.audit_in(
+                &mut transaction,
+                AuditAction::Purge,
+                &job_position,
+                Some(&job_position),
+                None,
+            )
+            .await?;
+            transaction.commit().await?;
+            return Ok(());
+        }
+        Err(RepositoryError::NotFound)
+    }
+
+    async fn apply_registration_windows(
+        &self,
+        now: OffsetDateTime,
+    ) -> Result<Vec<JobPosition>, RepositoryError> {
+        let mut transaction = self.pool.begin().await?;
+        // Boundaries that passed while the application was down are applied on the next run.
+        let since: OffsetDateTime = sqlx::query_scalar(
+            r#"SELECT "last_run_at" FROM "scheduler_run" WHERE "task" = 'registration_windows'"#,
+        )
+        .fetch_one(&mut *transaction)
+        .await?;
+
+        let rows = sqlx::query(&format!(
+            r#"UPDATE "job_position" SET
+                "is_opened_for_registration" = NOT "is_opened_for_registration"
+            WHERE "id" IN (
+                SELECT "job_position"."id"
+                FROM "job_position"
+                JOIN "event" ON "event"."id" = "job_position"."event_id"
+                WHERE "job_position"."deleted_at" IS NULL
+                    AND "event"."deleted_at" IS NULL
+                    AND "event"."status" IN ('draft', 'published')
+                    AND (
+                        (NOT "job_position"."is_opened_for_registration"
+                            AND julianday("job_position"."registration_opens_at") > julianday(?1)
+                            AND julianday("job_position"."registration_opens_at") <= julianday(?2)
+                            AND ("job_position"."registration_closes_at" IS NULL
+                                OR julianday("job_position"."registration_closes_at") > julianday(?2)))
+                        OR ("job_position"."is_opened_for_registration"
+                            AND julianday("job_position"."registration_closes_at") > julianday(?1)
+                            AND julianday("job_position"."registration_closes_at") <= julianday(?2))
+                    )
+            )
+            RETURNING {}"#,
+            select_columns("job_position", JOB_POSITION_COLUMNS, "")
+        ))
+        .bind(since)
+        .bind(now)
+        .fetch_all(&mut *transaction)
+        .await?;
+        let flipped: Result<Vec<JobPosition>, sqlx::Error> = rows
+            .iter()
+            .map(|row| job_position_from_row(row, ""))
+            .collect();
+        let mut flipped = flipped?;
+
+        for job_position in &flipped {
+            let before = JobPosition {
+                is_opened_for_registration: !job_position.is_opened_for_registration,
+                ..job_position.clone()
+            };
+            self.audit_in(
+                &mut transaction,
+                AuditAction::Update,
+                job_position,
+                Some(&before),
+                Some(job_position),
+            )
+            .await?;
+        }
+
+        sqlx::query(
+            r#"UPDATE "scheduler_run" SET "last_run_at" = ?1
+            WHERE "task" = 'registration_windows' AND julianday("last_run_at") < julianday(?1)"#,
+        )
+        .bind(now)
+        .execute(&mut *transaction)
+        .await?;
+        transaction.commit().await?;
+
+        flipped.sort_by_key(|job_position| job_position.id);
+        Ok(flipped)
+    }
+}
@@ -0,0 +1,89 @@
+use crate::error::RepositoryError;
+use crate::models::notification::{CreateNotification, Notification};
+use crate::repositories::notification::NotificationRepository;
+use crate::repositories::unit_of_work::UnitOfWork;
+use async_trait::async_trait;
+use sqlx::sqlite::SqliteRow;
+use sqlx::{Row, SqlitePool};
+
+#[derive(Debug, Clone)]
+pub struct SqliteNotificationRepository {
+    pub pool: SqlitePool,
+}
+
+impl SqliteNotificationRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+fn notification_from_row(row: &SqliteRow) -> Result<Notification, sqlx::Error> {
+    Ok(Notification {
+        id: row.try_get("id")?,
+        user_id: row.try_get("user_id")?,
+        message: row.try_get("message")?,
+        created_at: row.try_get("created_at")?,
+        read_at: row.try_get("read_at")?,
+    })
+}
+
+#[async_trait]
+impl NotificationRepository for SqliteNotificationRepository {
+    async fn list_notifications(&self, user_id: i32) -> Result<Vec<Notification>, RepositoryError> {
+        let rows = sqlx::query(
+            r#"SELECT "id", "user_id", "message", "created_at", "read_at"
+            FROM "notification"
+            WHERE "user_id" = ?
+            ORDER BY "created_at" DESC, "id" DESC"#,
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let notifications: Result<Vec<Notification>, sqlx::Error> =
+            rows.iter().map(notification_from_row).collect();
+        Ok(notifications?)
+    }
+
+    async fn create_notification(
+        &self,
+        new_notification: CreateNotification,
+    ) -> Result<Notification, RepositoryError> {
+        let mut unit_of_work = UnitOfWork::begin_sqlite(&self.pool).await?;
+        let notification = self
+            .create_notification_in(&mut unit_of_work, new_notification)
+            .await?;
+        unit_of_work.commit().await?;
+        Ok(notification)
+    }
+
+    async fn create_notification_in(
+        &self,
+        unit_of_work: &mut UnitOfWork,
+        new_notification: CreateNotification,
+    ) -> Result<Notification, RepositoryError> {
+        let row = sqlx::query(
+            r#"INSERT INTO "notification" ("user_id", "message")
+            VALUES (?, ?)
+            RETURNING "id", "user_id", "message", "created_at", "read_at""#,
+        )
+        .bind(new_notification.user_id)
+        .bind(new_notification.message)
+        .fetch_one(unit_of_work.sqlite_connection()?)
+        .await?;
+
+        Ok(notification_from_row(&row)?)
+    }
+
+    async fn mark_notifications_read(&self, user_id: i32) -> Result<(), RepositoryError> {
+        sqlx::query(
+            r#"UPDATE "notification" SET "read_at" = strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+            WHERE "user_id" = ? AND "read_at" IS NULL"#,
+        )
+        .bind(user_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,214 @@
+use crate::error::RepositoryError;
+use crate::models::organization::{
+    CreateOrganization, CreateOrganizationMembership, Organization, OrganizationMembership,
+};
+use crate::repositories::organization::OrganizationRepository;
+use crate::repositories::unit_of_work::UnitOfWork;
+use async_trait::async_trait;
+use sqlx::sqlite::SqliteRow;
+use sqlx::{Row, SqlitePool};
+
+#[derive(Debug, Clone)]
+pub struct SqliteOrganizationRepository {
+    pub pool: SqlitePool,
+}
+
+impl SqliteOrganizationRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+fn organization_from_row(row: &SqliteRow) -> Result<Organization, sqlx::Error> {
+    Ok(Organization {
+        id: row.try_get("id")?,
+        name: row.try_get("name")?,
+        invite_code: row.try_get("invite_code")?,
+        created_at: row.try_get("created_at")?,
+    })
+}
+
+fn membership_from_row(row: &SqliteRow) -> Result<OrganizationMembership, sqlx::Error> {
+    Ok(OrganizationMembership {
+        organization_id: row.try_get("organization_id")?,
+        user_id: row.try_get("user_id")?,
+        role: row.try_get("role")?,
+        created_at: row.try_get("created_at")?,
+    })
+}
+
+#[async_trait]
+impl OrganizationRepository for SqliteOrganizationRepository {
+    async fn list_organizations(&self) -> Result<Vec<Organization>, RepositoryError> {
+        let rows = sqlx::query(
+            r#"SELECT "id", "name", "invite_code", "created_at"
+            FROM "organization"
+            ORDER BY "name""#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        let organizations: Result<Vec<Organization>, sqlx::Error> =
+            rows.iter().map(organization_from_row).collect();
+        Ok(organizations?)
+    }
+
+    async fn list_user_organizations(
+        &self,
+        user_id: i32,
+    ) -> Result<Vec<Organization>, RepositoryError> {
+        let rows = sqlx::query(
+            r#"SELECT
+                "organization"."id",
+                "organization"."name",
+                "organization"."invite_code",
+                "organization"."created_at"
+            FROM "organization"
+            JOIN "organization_membership"
+                ON "organization_membership"."organization_id" = "organization"."id"
+            WHERE "organization_membership"."user_id" = ?
+            ORDER BY "organization"."name""#,
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let organizations: Result<Vec<Organization>, sqlx::Error> =
+            rows.iter().map(organization_from_row).collect();
+        Ok(organizations?)
+    }
+
+    async fn get_organization_by_id(
+        &self,
+        organization_id: i32,
+    ) -> Result<Organization, RepositoryError> {
+        let row = sqlx::query(
+            r#"SELECT "id", "name", "invite_code", "created_at"
+            FROM "organization"
+            WHERE "id" = ?"#,
+        )
+        .bind(organization_id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(RepositoryError::NotFound)?;
+
+        Ok(organization_from_row(&row)?)
+    }
+
+    async fn get_organization_by_invite_code(
+        &self,
+        invite_code: &str,
+    ) -> Result<Organization, RepositoryError> {
+        let row = sqlx::query(
+            r#"SELECT "id", "name", "invite_code", "created_at"
+            FROM "organization"
+            WHERE "invite_code" = ?"#,
+        )
+        .bind(invite_code)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(RepositoryError::NotFound)?;
+
+        Ok(organization_from_row(&row)?)
+    }
+
+    async fn create_organization(
+        &self,
+        new_organization: CreateOrganization,
+    ) -> Result<Organization, RepositoryError> {
+        let existing: bool =
+            sqlx::query_scalar(r#"SELECT EXISTS (SELECT 1 FROM "organization" WHERE "name" = ?)"#)
+                .bind(&new_organization.name)
+                .fetch_one(&self.pool)
+                .await?;
+
+        if existing {
+            return Err(RepositoryError::GenericError(
+                "An organization with this name already exists".to_string(),
+            ));
+        }
+
+        let row = sqlx::query(
+            r#"INSERT INTO "organization" ("name")
+            VALUES (?)
+            RETURNING "id", "name", "invite_code", "created_at""#,
+        )
+        .bind(new_organization.name)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(organization_from_row(&row)?)
+    }
+
+    async fn get_membership(
+        &self,
+        organization_id: i32,
+        user_id: i32,
+    ) -> Result<OrganizationMembership, RepositoryError> {
+        let row = sqlx::query(
+            r#"SELECT "organization_id", "user_id", "role", "created_at"
+            FROM "organization_membership"
+            WHERE "organization_id" = ? AND "user_id" = ?"#,
+        )
+        .bind(organization_id)
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(RepositoryError::NotFound)?;
+
+        Ok(membership_from_row(&row)?)
+    }
+
+    async fn create_membership(
+        &self,
+        new_membership: CreateOrganizationMembership,
+    ) -> Result<OrganizationMembership, RepositoryError> {
+        let mut unit_of_work = UnitOfWork::begin_sqlite(&self.pool).await?;
+        let membership = self
+            .create_membership_in(&mut unit_of_work, new_membership)
+            .await?;
+        unit_of_work.commit().await?;
+        Ok(membership)
+    }
+
+    async fn create_membership_in(
+        &self,
+        unit_of_work: &mut UnitOfWork,
+        new_membership: CreateOrganizationMembership,
+    ) -> Result<OrganizationMembership, RepositoryError> {
+        let row = sqlx::query(
+            r#"INSERT INTO "organization_membership" ("organization_id", "user_id", "role")
+            VALUES (?, ?, ?)
+            ON CONFLICT ("organization_id", "user_id") DO UPDATE SET "role" = "excluded"."role"
+            RETURNING "organization_id", "user_id", "role", "created_at""#,
+        )
+        .bind(new_membership.organization_id)
+        .bind(new_membership.user_id)
+        .bind(new_membership.role)
+        .fetch_one(unit_of_work.sqlite_connection()?)
+        .await?;
+
+        Ok(membership_from_row(&row)?)
+    }
+
+    async fn delete_membership(
+        &self,
+        organization_id: i32,
+        user_id: i32,
+    ) -> Result<(), RepositoryError> {
+        let result = sqlx::query(
+            r#"DELETE FROM "organization_membership"
+            WHERE "organization_id" = ? AND "user_id" = ?"#,
+        )
+        .bind(organization_id)
+        .bind(user_id)
+        .execute(&self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,160 @@
+use crate::error::RepositoryError;
+use crate::models::position_category::{
+    CreatePositionCategory, PartialPositionCategory, PositionCategory,
+};
+use crate::repositories::columns::column;
+use crate::repositories::position_category::PositionCategoryRepository;
+use crate::repositories::unit_of_work::UnitOfWork;
+use anyhow::Result;
+use async_trait::async_trait;
+use sqlx::sqlite::SqliteRow;
+use sqlx::{Row, SqlitePool};
+
+#[derive(Debug, Clone)]
+pub struct SqlitePositionCategoryRepository {
+    pub pool: SqlitePool,
+}
+
+impl SqlitePositionCategoryRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+/// Reads a position category selected with
+/// [`select_columns`](crate::repositories::columns::select_columns) and `prefix`.
+pub(crate) fn position_category_from_row(
+    row: &SqliteRow,
+    prefix: &str,
+) -> Result<PositionCategory, sqlx::Error> {
+    Ok(PositionCategory {
+        id: row.try_get(&*column(prefix, "id"))?,
+        name: row.try_get(&*column(prefix, "name"))?,
+        organization_id: row.try_get(&*column(prefix, "organization_id"))?,
+    })
+}
+
+#[async_trait]
+impl PositionCategoryRepository for SqlitePositionCategoryRepository {
+    async fn list_position_categories(
+        &self,
+        organization_id: i32,
+    ) -> Result<Vec<PositionCategory>> {
+        let rows = sqlx::query(
+            r#"SELECT "id", "name", "organization_id"
+            FROM "position_category"
+            WHERE "organization_id" = ?"#,
+        )
+        .bind(organization_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let position_categories: Result<Vec<PositionCategory>, sqlx::Error> = rows
+            .iter()
+            .map(|row| position_category_from_row(row, ""))
+            .collect();
+        Ok(position_categories?)
+    }
+
+    async fn get_position_category_by_id(
+        &self,
+        position_category_id: i32,
+    ) -> Result<PositionCategory, RepositoryError> {
+        let row = sqlx::query(
+            r#"SELECT "id", "name", "organization_id"
+            FROM "position_category"
+            WHERE "id" = ?"#,
+        )
+        .bind(position_category_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        match row {
+            Some(row) => Ok(position_category_from_row(&row, "")?),
+            None => Err(RepositoryError::NotFound),
+        }
+    }
+
+    async fn get_position_category_by_id_in(
+        &self,
+        unit_of_work: &mut UnitOfWork,
+        position_category_id: i32,
+    ) -> Result<PositionCategory, RepositoryError> {
+        let row = sqlx::query(
+            r#"SELECT "id", "name", "organization_id"
+            FROM "position_category"
+            WHERE "id" = ?"#,
+        )
+        .bind(position_category_id)
+        .fetch_optional(unit_of_work.sqlite_connection()?)
+        .await?;
+
+        match row {
+            Some(row) => Ok(position_category_from_row(&row, "")?),
+            None => Err(RepositoryError::NotFound),
+        }
+    }
+
+    async fn create_position_category(
+        &self,
+        new_position_category: CreatePositionCategory,
+    ) -> Result<PositionCategory, RepositoryError> {
+        let row = sqlx::query(
+            r#"INSERT INTO "position_category" ("name", "organization_id")
+            VALUES (?, ?)
+            RETURNING "id", "name", "organization_id""#,
+        )
+        .bind(new_position_category.name)
+        .bind(new_position_category.organization_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(position_category_from_row(&row, "")?)
+    }
+
+    async fn delete_position_category(
+        &self,
+        position_category_id: i32,
+    ) -> Result<(), RepositoryError> {
+        let result = sqlx::query(r#"DELETE FROM "position_category" WHERE "id" = ?"#)
+            .bind(position_category_id)
+            .execute(&self.pool)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn update_position_category(
+        &self,
+        position_category_id: i32,
+        patch_position_category: PartialPositionCategory,
+    ) -> Result<PositionCategory, RepositoryError> {
+        let position_category = self
+            .get_position_category_by_id(position_category_id)
+            .await?;
+
+        let name = patch_position_category
+            .name
+            .unwrap_or(position_category.name);
+
+        let row = sqlx::query(
+            r#"UPDATE "position_category"
+            SET "name" = ?
+            WHERE "id" = ?
+            RETURNING "id", "name", "organization_id""#,
+        )
+        .bind(name)
+        .bind(position_category.id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        match row {
+            Some(row) => Ok(position_category_from_row(&row, "")?),
+            None => Err(RepositoryError::NotFound),
+        }
+    }
+}
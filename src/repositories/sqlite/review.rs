@@ -0,0 +1,348 @@
+use crate::error::RepositoryError;
+use crate::models::employment::EmploymentState;
+use crate::models::pagination::{ListSpec, Page};
+use crate::models::review::{
+    review_deadline, CreateReview, FlaggedReview, PublishedReview, Review, ReviewDirection,
+    SortColumn, REVIEW_WINDOW_DAYS,
+};
+use crate::repositories::review::ReviewRepository;
+use anyhow::Result;
+use async_trait::async_trait;
+use sqlx::query::Query;
+use sqlx::sqlite::{SqliteArguments, SqliteRow};
+use sqlx::types::time::Date;
+use sqlx::{QueryBuilder, Row, Sqlite, SqlitePool};
+
+const REVIEW_COLUMNS: &str = r#""id",
+    "employment_id",
+    "direction",
+    "author_id",
+    "subject_id",
+    "event_id",
+    "overall",
+    "punctuality",
+    "skill",
+    "organization",
+    "communication",
+    "comment",
+    "flagged_at",
+    "flagged_by",
+    "flag_reason",
+    "is_hidden",
+    "created_at""#;
+
+const PUBLISHED_REVIEW_COLUMNS: &str = r#""review"."id",
+    "review"."direction",
+    "author"."first_name" || ' ' || "author"."last_name" AS "author_name",
+    "event"."id" AS "event_id",
+    "event"."name" AS "event_name",
+    "review"."overall",
+    "review"."punctuality",
+    "review"."skill",
+    "review"."organization",
+    "review"."communication",
+    "review"."comment",
+    "review"."created_at""#;
+
+/// Keeps the reviews whose counterpart has been written or whose review window has passed,
+/// binding the length of the window and today.
+const PUBLISHED_CONDITION: &str = r#"NOT "review"."is_hidden"
+    AND (
+        EXISTS (
+            SELECT 1 FROM "review" AS "counterpart"
+            WHERE "counterpart"."employment_id" = "review"."employment_id"
+                AND "counterpart"."direction" <> "review"."direction"
+        )
+        OR julianday("event"."date_end") + ? < julianday(?)
+    )"#;
+
+#[derive(Debug, Clone)]
+pub struct SqliteReviewRepository {
+    pub pool: SqlitePool,
+}
+
+impl SqliteReviewRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    async fn fetch_review<'q>(
+        &self,
+        query: Query<'q, Sqlite, SqliteArguments<'q>>,
+    ) -> Result<Review, RepositoryError> {
+        let row = query
+            .fetch_optional(&self.pool)
+            .await?
+            .ok_or(RepositoryError::NotFound)?;
+        Ok(review_from_row(&row)?)
+    }
+}
+
+fn review_from_row(row: &SqliteRow) -> Result<Review, sqlx::Error> {
+    Ok(Review {
+        id: row.try_get("id")?,
+        employment_id: row.try_get("employment_id")?,
+        direction: row.try_get("direction")?,
+        author_id: row.try_get("author_id")?,
+        subject_id: row.try_get("subject_id")?,
+        event_id: row.try_get("event_id")?,
+        overall: row.try_get("overall")?,
+        punctuality: row.try_get("punctuality")?,
+        skill: row.try_get("skill")?,
+        organization: row.try_get("organization")?,
+        communication: row.try_get("communication")?,
+        comment: row.try_get("comment")?,
+        flagged_at: row.try_get("flagged_at")?,
+        flagged_by: row.try_get("flagged_by")?,
+        flag_reason: row.try_get("flag_reason")?,
+        is_hidden: row.try_get("is_hidden")?,
+        created_at: row.try_get("created_at")?,
+    })
+}
+
+fn published_review_from_row(row: &SqliteRow) -> Result<PublishedReview, sqlx::Error> {
+    Ok(PublishedReview {
+        id: row.try_get("id")?,
+        direction: row.try_get("direction")?,
+        author_name: row.try_get("author_name")?,
+        event_id: row.try_get("event_id")?,
+        event_name: row.try_get("event_name")?,
+        overall: row.try_get("overall")?,
+        punctuality: row.try_get("punctuality")?,
+        skill: row.try_get("skill")?,
+        organization: row.try_get("organization")?,
+        communication: row.try_get("communication")?,
+        comment: row.try_get("comment")?,
+        created_at: row.try_get("created_at")?,
+    })
+}
+
+#[async_trait]
+impl ReviewRepository for SqliteReviewRepository {
+    async fn create_review(
+        &self,
+        new_review: CreateReview,
+        today: Date,
+    ) -> Result<Review, RepositoryError> {
+        let employment = sqlx::query(
+            r#"SELECT
+                "employment"."state",
+                "employment"."user_id",
+                "event"."id" AS "event_id",
+                "event"."owner_id",
+                "event"."date_end"
+            FROM "employment"
+            JOIN "job_position" ON "job_position"."id" = "employment"."position_id"
+            JOIN "event" ON "event"."id" = "job_position"."event_id"
+            WHERE "employment"."id" = ?"#,
+        )
+        .bind(new_review.employment_id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(RepositoryError::NotFound)?;
+        let state: EmploymentState = employment.try_get("state")?;
+        let date_end: Date = employment.try_get("date_end")?;
+        let event_id: i32 = employment.try_get("event_id")?;
+
+        if state != EmploymentState::Done {
+            return Err(RepositoryError::GenericError(
+                "Only finished jobs can be reviewed".to_string(),
+            ));
+        }
+        if today > review_deadline(date_end) {
+            return Err(RepositoryError::GenericError(
+                "The review period for this job has ended".to_string(),
+            ));
+        }
+        let subject_id: i32 = match new_review.direction {
+            ReviewDirection::OrganizerToWorker => employment.try_get("user_id")?,
+            ReviewDirection::WorkerToOrganizer => employment.try_get("owner_id")?,
+        };
+
+        let already_reviewed: bool = sqlx::query_scalar(
+            r#"SELECT EXISTS (
+                SELECT 1 FROM "review" WHERE "employment_id" = ? AND "direction" = ?
+            )"#,
+        )
+        .bind(new_review.employment_id)
+        .bind(new_review.direction)
+        .fetch_one(&self.pool)
+        .await?;
+        if already_reviewed {
+            return Err(RepositoryError::GenericError(
+                "This job has already been reviewed".to_string(),
+            ));
+        }
+
+        let row = sqlx::query(&format!(
+            r#"INSERT INTO "review" (
+                "employment_id", "direction", "author_id", "subject_id", "event_id", "overall",
+                "punctuality", "skill", "organization", "communication", "comment"
+            )
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            RETURNING {REVIEW_COLUMNS}"#
+        ))
+        .bind(new_review.employment_id)
+        .bind(new_review.direction)
+        .bind(new_review.author_id)
+        .bind(subject_id)
+        .bind(event_id)
+        .bind(new_review.overall)
+        .bind(new_review.punctuality)
+        .bind(new_review.skill)
+        .bind(new_review.organization)
+        .bind(new_review.communication)
+        .bind(new_review.comment.trim().to_string())
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(review_from_row(&row)?)
+    }
+
+    async fn list_published_reviews(
+        &self,
+        subject_id: i32,
+        direction: ReviewDirection,
+        organization_id: Option<i32>,
+        today: Date,
+    ) -> Result<Vec<PublishedReview>, RepositoryError> {
+        let rows = sqlx::query(&format!(
+            r#"SELECT {PUBLISHED_REVIEW_COLUMNS}
+            FROM "review"
+            JOIN "event" ON "event"."id" = "review"."event_id"
+            LEFT JOIN "user" AS "author" ON "author"."id" = "review"."author_id"
+            WHERE "review"."subject_id" = ?
+                AND "review"."direction" = ?
+                AND (? IS NULL OR "event"."organization_id" = ?)
+                AND {PUBLISHED_CONDITION}
+            ORDER BY "review"."created_at" DESC"#
+        ))
+        .bind(subject_id)
+        .bind(direction)
+        .bind(organization_id)
+        .bind(organization_id)
+        .bind(REVIEW_WINDOW_DAYS)
+        .bind(today)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let reviews: Result<Vec<PublishedReview>, sqlx::Error> =
+            rows.iter().map(published_review_from_row).collect();
+        Ok(reviews?)
+    }
+
+    async fn list_event_reviews(
+        &self,
+        event_id: i32,
+        today: Date,
+    ) -> Result<Vec<PublishedReview>, RepositoryError> {
+        let rows = sqlx::query(&format!(
+            r#"SELECT {PUBLISHED_REVIEW_COLUMNS}
+            FROM "review"
+            JOIN "event" ON "event"."id" = "review"."event_id"
+            LEFT JOIN "user" AS "author" ON "author"."id" = "review"."author_id"
+            WHERE "review"."event_id" = ?
+                AND "review"."direction" = 'worker_to_organizer'
+                AND {PUBLISHED_CONDITION}
+            ORDER BY "review"."created_at" DESC"#
+        ))
+        .bind(event_id)
+        .bind(REVIEW_WINDOW_DAYS)
+        .bind(today)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let reviews: Result<Vec<PublishedReview>, sqlx::Error> =
+            rows.iter().map(published_review_from_row).collect();
+        Ok(reviews?)
+    }
+
+    async fn flag_review(
+        &self,
+        review_id: i32,
+        flagged_by: i32,
+        reason: String,
+    ) -> Result<Review, RepositoryError> {
+        let reason = reason.trim();
+        if reason.is_empty() {
+            return Err(RepositoryError::GenericError(
+                "Please tell the admins what is wrong with the review".to_string(),
+            ));
+        }
+        let query = format!(
+            r#"UPDATE "review"
+            SET "flagged_at" = strftime('%Y-%m-%dT%H:%M:%fZ', 'now'), "flagged_by" = ?, "flag_reason" = ?
+            WHERE "id" = ? AND NOT "is_hidden"
+            RETURNING {REVIEW_COLUMNS}"#
+        );
+        self.fetch_review(
+            sqlx::query(&query)
+                .bind(flagged_by)
+                .bind(reason.to_string())
+                .bind(review_id),
+        )
+        .await
+    }
+
+    async fn list_flagged_reviews_page(
+        &self,
+        spec: &ListSpec<SortColumn>,
+    ) -> Result<Page<FlaggedReview>, RepositoryError> {
+        let mut query_builder = QueryBuilder::new(
+            r#"WITH "list" AS (SELECT
+                "review"."id",
+                "author"."first_name" || ' ' || "author"."last_name" AS "author_name",
+                "subject"."first_name" || ' ' || "subject"."last_name" AS "subject_name",
+                "event"."name" AS "event_name",
+                "review"."overall",
+                "review"."comment",
+                "review"."flag_reason",
+                "flagger"."first_name" || ' ' || "flagger"."last_name" AS "flagged_by_name",
+                "review"."flagged_at", "#,
+        );
+        spec.push_sqlite_sort_key(&mut query_builder);
+        query_builder.push(
+            r#" FROM "review"
+            JOIN "event" ON "event"."id" = "review"."event_id"
+            JOIN "user" AS "subject" ON "subject"."id" = "review"."subject_id"
+            LEFT JOIN "user" AS "author" ON "author"."id" = "review"."author_id"
+            LEFT JOIN "user" AS "flagger" ON "flagger"."id" = "review"."flagged_by"
+            WHERE "review"."flagged_at" IS NOT NULL"#,
+        );
+        spec.push_sqlite_page(&mut query_builder);
+
+        let rows = query_builder.build().fetch_all(&self.pool).await?;
+
+        let total = match rows.first() {
+            Some(row) => row.try_get("total_count")?,
+            None => 0,
+        };
+        let reviews: Result<Vec<FlaggedReview>, sqlx::Error> = rows
+            .iter()
+            .map(|row| {
+                Ok(FlaggedReview {
+                    id: row.try_get("id")?,
+                    author_name: row.try_get("author_name")?,
+                    subject_name: row.try_get("subject_name")?,
+                    event_name: row.try_get("event_name")?,
+                    overall: row.try_get("overall")?,
+                    comment: row.try_get("comment")?,
+                    flag_reason: row.try_get("flag_reason")?,
+                    flagged_by_name: row.try_get("flagged_by_name")?,
+                    flagged_at: row.try_get("flagged_at")?,
+                })
+            })
+            .collect();
+        Ok(Page::new(reviews?, total, spec, |review| review.id))
+    }
+
+    async fn moderate_review(&self, review_id: i32, hide: bool) -> Result<Review, RepositoryError> {
+        let query = format!(
+            r#"UPDATE "review"
+            SET "is_hidden" = ?, "flagged_at" = NULL, "flagged_by" = NULL, "flag_reason" = NULL
+            WHERE "id" = ?
+            RETURNING {REVIEW_COLUMNS}"#
+        );
+        self.fetch_review(sqlx::query(&query).bind(hide).bind(review_id))
+            .await
+    }
+}
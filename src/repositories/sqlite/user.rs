@@ -0,0 +1,507 @@
+use crate::error::RepositoryError;
+use crate::models::audit_log::{AuditAction, AuditEntity};
+use crate::models::pagination::{push_sqlite_contains, ListSpec, Page};
+use crate::models::trash;
+use crate::models::user::{CreateUser, PartialUser, SelectManyFilter, SortColumn, User};
+use crate::repositories::columns::{column, select_columns};
+use crate::repositories::sqlite::audit_log::SqliteAuditLogRepository;
+use crate::repositories::unit_of_work::UnitOfWork;
+use crate::repositories::user::{UserRepository, USER_COLUMNS};
+use anyhow::Result;
+use async_trait::async_trait;
+use sqlx::sqlite::SqliteRow;
+use sqlx::{QueryBuilder, Row, Sqlite, SqliteConnection, SqlitePool};
+use std::sync::Arc;
+
+#[derive(Debug, Clone)]
+pub struct SqliteUserRepository {
+    pub pool: SqlitePool,
+    pub actor_id: Option<i32>,
+}
+
+impl SqliteUserRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self {
+            pool,
+            actor_id: None,
+        }
+    }
+
+    pub fn with_actor(&self, actor_id: i32) -> Self {
+        Self {
+            pool: self.pool.clone(),
+            actor_id: Some(actor_id),
+        }
+    }
+
+    async fn audit_in(
+        &self,
+        connection: &mut SqliteConnection,
+        action: AuditAction,
+        user: &User,
+        before: Option<&User>,
+        after: Option<&User>,
+    ) -> Result<(), RepositoryError> {
+        SqliteAuditLogRepository::record_in(
+            connection,
+            self.actor_id,
+            AuditEntity::User,
+            user.id,
+            None,
+            action,
+            before,
+            after,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn check_in_organization(
+        connection: &mut SqliteConnection,
+        user_id: i32,
+        organization_id: i32,
+    ) -> Result<(), RepositoryError> {
+        let in_organization: bool = sqlx::query_scalar(
+            r#"SELECT EXISTS (
+                SELECT 1 FROM "organization_membership"
+                WHERE "user_id" = ? AND "organization_id" = ?
+            )"#,
+        )
+        .bind(user_id)
+        .bind(organization_id)
+        .fetch_one(connection)
+        .await?;
+
+        if !in_organization {
+            return Err(RepositoryError::NotFound);
+        }
+        Ok(())
+    }
+
+    async fn fetch_user_in(
+        connection: &mut SqliteConnection,
+        query: &str,
+        user_id: i32,
+    ) -> Result<Option<User>, RepositoryError> {
+        let row = sqlx::query(query)
+            .bind(user_id)
+            .fetch_optional(connection)
+            .await?;
+        Ok(row.map(|row| user_from_row(&row, "")).transpose()?)
+    }
+
+    /// Adds the `WHERE` clause of a list query.
+    pub(crate) fn push_filter(
+        query_builder: &mut QueryBuilder<'_, Sqlite>,
+        filter: SelectManyFilter,
+    ) {
+        query_builder.push(r#" WHERE "deleted_at" IS NULL"#);
+
+        if let Some(organization_id) = filter.organization_id {
+            query_builder.push(
+                r#" AND "id" IN (SELECT "user_id" FROM "organization_membership" WHERE "organization_id" = "#,
+            );
+            query_builder.push_bind(organization_id);
+            query_builder.push(")");
+        }
+
+        if let Some(username) = filter.username {
+            query_builder.push(" AND username = ");
+            query_builder.push_bind(username);
+        }
+
+        if let Some(role) = filter.role {
+            query_builder.push(" AND role = ");
+            query_builder.push_bind(role);
+        }
+
+        if let Some(gender) = filter.gender {
+            query_builder.push(" AND gender = ");
+            query_builder.push_bind(gender);
+        }
+
+        if let Some(first_name) = filter.first_name {
+            query_builder.push(" AND first_name = ");
+            query_builder.push_bind(first_name);
+        }
+
+        if let Some(last_name) = filter.last_name {
+            query_builder.push(" AND last_name = ");
+            query_builder.push_bind(last_name);
+        }
+
+        if let Some(tax_rate) = filter.tax_rate {
+            query_builder.push(" AND tax_rate = ");
+            query_builder.push_bind(tax_rate);
+        }
+
+        if let Some(last_name) = filter.last_name_contains {
+            push_sqlite_contains(query_builder, r#""last_name""#, last_name);
+        }
+
+        if let Some(username) = filter.username_contains {
+            push_sqlite_contains(query_builder, r#""username""#, username);
+        }
+    }
+}
+
+/// Reads a user selected with [`select_columns`] and `prefix`.
+pub(crate) fn user_from_row(row: &SqliteRow, prefix: &str) -> Result<User, sqlx::Error> {
+    Ok(User {
+        id: row.try_get(&*column(prefix, "id"))?,
+        first_name: row.try_get(&*column(prefix, "first_name"))?,
+        last_name: row.try_get(&*column(prefix, "last_name"))?,
+        username: row.try_get(&*column(prefix, "username"))?,
+        gender: row.try_get(&*column(prefix, "gender"))?,
+        birth_date: row.try_get(&*column(prefix, "birth_date"))?,
+        email: row.try_get(&*column(prefix, "email"))?,
+        phone: row.try_get(&*column(prefix, "phone"))?,
+        password_hash: row.try_get(&*column(prefix, "password_hash"))?,
+        role: row.try_get(&*column(prefix, "role"))?,
+        tax_rate: row.try_get(&*column(prefix, "tax_rate"))?,
+        avatar_url: row.try_get(&*column(prefix, "avatar_url"))?,
+    })
+}
+
+#[async_trait]
+impl UserRepository for SqliteUserRepository {
+    fn with_actor(&self, actor_id: i32) -> Arc<dyn UserRepository> {
+        Arc::new(SqliteUserRepository::with_actor(self, actor_id))
+    }
+
+    async fn list_users(&self, filter: SelectManyFilter) -> Result<Vec<User>> {
+        let mut query_builder = QueryBuilder::new(r#"SELECT * FROM "user""#);
+        Self::push_filter(&mut query_builder, filter);
+
+        let query = query_builder.build();
+        let rows = query.fetch_all(&self.pool).await?;
+
+        let data: Result<Vec<User>, sqlx::Error> =
+            rows.iter().map(|row| user_from_row(row, "")).collect();
+        Ok(data?)
+    }
+
+    async fn list_users_page(
+        &self,
+        filter: SelectManyFilter,
+        spec: &ListSpec<SortColumn>,
+    ) -> Result<Page<User>> {
+        let mut query_builder = QueryBuilder::new(r#"WITH "list" AS (SELECT "user".*, "#);
+        spec.push_sqlite_sort_key(&mut query_builder);
+        query_builder.push(r#" FROM "user""#);
+        Self::push_filter(&mut query_builder, filter);
+        spec.push_sqlite_page(&mut query_builder);
+
+        let query = query_builder.build();
+        let rows = query.fetch_all(&self.pool).await?;
+
+        let total = match rows.first() {
+            Some(row) => row.try_get("total_count")?,
+            None => 0,
+        };
+        let data: Result<Vec<User>, sqlx::Error> =
+            rows.iter().map(|row| user_from_row(row, "")).collect();
+        Ok(Page::new(data?, total, spec, |user| user.id))
+    }
+
+    async fn get_user_by_id(&self, user_id: i32) -> Result<User, RepositoryError> {
+        let mut connection = self.pool.acquire().await?;
+        Self::fetch_user_in(
+            &mut connection,
+            &format!(
+                r#"SELECT {} FROM "user" WHERE "id" = ? AND "deleted_at" IS NULL"#,
+                select_columns("user", USER_COLUMNS, "")
+            ),
+            user_id,
+        )
+        .await?
+        .ok_or(RepositoryError::NotFound)
+    }
+
+    async fn get_user_by_username(&self, username: String) -> Result<User, RepositoryError> {
+        let row = sqlx::query(&format!(
+            r#"SELECT {} FROM "user" WHERE "username" = ? AND "deleted_at" IS NULL"#,
+            select_columns("user", USER_COLUMNS, "")
+        ))
+        .bind(username)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        match row {
+            Some(row) => Ok(user_from_row(&row, "")?),
+            None => Err(RepositoryError::NotFound),
+        }
+    }
+
+    async fn create_user(&self, new_user: CreateUser) -> Result<User, RepositoryError> {
+        let mut unit_of_work = UnitOfWork::begin_sqlite(&self.pool).await?;
+        let user = self.create_user_in(&mut unit_of_work, new_user).await?;
+        unit_of_work.commit().await?;
+        Ok(user)
+    }
+
+    async fn create_user_in(
+        &self,
+        unit_of_work: &mut UnitOfWork,
+        new_user: CreateUser,
+    ) -> Result<User, RepositoryError> {
+        let connection = unit_of_work.sqlite_connection()?;
+        let row = sqlx::query(&format!(
+            r#"INSERT INTO "user" (
+                "first_name", "last_name", "username", "gender", "birth_date", "email", "phone", "password_hash", "role", "tax_rate", "avatar_url"
+            )
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            RETURNING {}"#,
+            select_columns("user", USER_COLUMNS, "")
+        ))
+        .bind(new_user.first_name)
+        .bind(new_user.last_name)
+        .bind(new_user.username)
+        .bind(new_user.gender)
+        .bind(new_user.birth_date)
+        .bind(new_user.email)
+        .bind(new_user.phone)
+        .bind(new_user.password_hash)
+        .bind(new_user.role)
+        .bind(new_user.tax_rate)
+        .bind(new_user.avatar_url)
+        .fetch_one(&mut *connection)
+        .await?;
+        let user = user_from_row(&row, "")?;
+
+        self.audit_in(connection, AuditAction::Create, &user, None, Some(&user))
+            .await?;
+        Ok(user)
+    }
+
+    async fn delete_user(&self, user_id: i32) -> Result<(), RepositoryError> {
+        let user = self.get_user_by_id(user_id).await?;
+        let mut transaction = self.pool.begin().await?;
+
+        let owns_events: bool = sqlx::query_scalar(
+            r#"SELECT EXISTS (
+                SELECT 1 FROM "event" WHERE "owner_id" = ? AND "deleted_at" IS NULL
+            )"#,
+        )
+        .bind(user_id)
+        .fetch_one(&mut *transaction)
+        .await?;
+
+        if owns_events {
+            return Err(RepositoryError::GenericError(
+                "The user still owns events".to_string(),
+            ));
+        }
+
+        let result = sqlx::query(
+            r#"UPDATE "user" SET "deleted_at" = strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+            WHERE "id" = ? AND "deleted_at" IS NULL"#,
+        )
+        .bind(user_id)
+        .execute(&mut *transaction)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        self.audit_in(
+            &mut transaction,
+            AuditAction::Delete,
+            &user,
+            Some(&user),
+            None,
+        )
+        .await?;
+        transaction.commit().await?;
+        Ok(())
+    }
+
+    async fn update_user(
+        &self,
+        user_id: i32,
+        patch_user: PartialUser,
+    ) -> Result<User, RepositoryError> {
+        let user = self.get_user_by_id(user_id).await?;
+        let mut transaction = self.pool.begin().await?;
+        let before = user.clone();
+
+        let first_name = patch_user.first_name.unwrap_or(user.first_name);
+        let last_name = patch_user.last_name.unwrap_or(user.last_name);
+        let username = patch_user.username.unwrap_or(user.username);
+        let gender = patch_user.gender.unwrap_or(user.gender);
+        let birth_date = patch_user.birth_date.unwrap_or(user.birth_date);
+        let email = patch_user.email.unwrap_or(user.email);
+        let phone = patch_user.phone.unwrap_or(user.phone);
+        let password_hash = patch_user.password_hash.unwrap_or(user.password_hash);
+        let role = patch_user.role.unwrap_or(user.role);
+        let tax_rate = patch_user.tax_rate.unwrap_or(user.tax_rate);
+        let avatar_url = patch_user.avatar_url.or(user.avatar_url);
+
+        let row = sqlx::query(&format!(
+            r#"UPDATE "user" SET
+                "first_name" = ?,
+                "last_name" = ?,
+                "username" = ?,
+                "gender" = ?,
+                "birth_date" = ?,
+                "email" = ?,
+                "phone" = ?,
+                "password_hash" = ?,
+                "role" = ?,
+                "tax_rate" = ?,
+                "avatar_url" = ?
+            WHERE "id" = ? AND "deleted_at" IS NULL
+            RETURNING {}"#,
+            select_columns("user", USER_COLUMNS, "")
+        ))
+        .bind(first_name)
+        .bind(last_name)
+        .bind(username)
+        .bind(gender)
+        .bind(birth_date)
+        .bind(email)
+        .bind(phone)
+        .bind(password_hash)
+        .bind(role)
+        .bind(tax_rate)
+        .bind(avatar_url)
+        .bind(user_id)
+        .fetch_optional(&mut *transaction)
+        .await?;
+
+        if let Some(row) = row {
+            let user = user_from_row(&row, "")?;
+            self.audit_in(
+                &mut transaction,
+                AuditAction::Update,
+                &user,
+                Some(&before),
+                Some(&user),
+            )
+            .await?;
+            transaction.commit().await?;
+            return Ok(user);
+        }
+
+        Err(RepositoryError::NotFound)
+    }
+
+    async fn list_deleted_users(
+        &self,
+        organization_id: i32,
+        spec: &ListSpec<trash::SortColumn>,
+    ) -> Result<Page<User>, RepositoryError> {
+        let mut query_builder = QueryBuilder::new(format!(
+            r#"WITH "list" AS (SELECT {}, "#,
+            select_columns("user", USER_COLUMNS, "")
+        ));
+        spec.push_sqlite_sort_key(&mut query_builder);
+        query_builder.push(
+            r#" FROM "user"
+            WHERE "deleted_at" IS NOT NULL
+                AND "id" IN (
+                    SELECT "user_id" FROM "organization_membership" WHERE "organization_id" = "#,
+        );
+        query_builder.push_bind(organization_id);
+        query_builder.push(")");
+        spec.push_sqlite_page(&mut query_builder);
+
+        let rows = query_builder.build().fetch_all(&self.pool).await?;
+
+        let total = match rows.first() {
+            Some(row) => row.try_get("total_count")?,
+            None => 0,
+        };
+        let data: Result<Vec<User>, sqlx::Error> =
+            rows.iter().map(|row| user_from_row(row, "")).collect();
+        Ok(Page::new(data?, total, spec, |user| user.id))
+    }
+
+    async fn restore_user(
+        &self,
+        user_id: i32,
+        organization_id: i32,
+    ) -> Result<User, RepositoryError> {
+        let mut transaction = self.pool.begin().await?;
+        Self::check_in_organization(&mut transaction, user_id, organization_id).await?;
+        let user = Self::fetch_user_in(
+            &mut transaction,
+            &format!(
+                r#"UPDATE "user" SET "deleted_at" = NULL
+                WHERE "id" = ? AND "deleted_at" IS NOT NULL
+                RETURNING {}"#,
+                select_columns("user", USER_COLUMNS, "")
+            ),
+            user_id,
+        )
+        .await?;
+
+        if let Some(user) = user {
+            self.audit_in(
+                &mut transaction,
+                AuditAction::Restore,
+                &user,
+                None,
+                Some(&user),
+            )
+            .await?;
+            transaction.commit().await?;
+            return Ok(user);
+        }
+
+        Err(RepositoryError::NotFound)
+    }
+
+    async fn purge_user(&self, user_id: i32, organization_id: i32) -> Result<(), RepositoryError> {
+        let mut transaction = self.pool.begin().await?;
+        Self::check_in_organization(&mut transaction, user_id, organization_id).await?;
+        let has_history: bool = sqlx::query_scalar(
+            r#"SELECT (
+                EXISTS (
+                    SELECT 1 FROM "worked_hours"
+                    JOIN "employment" ON "employment"."id" = "worked_hours"."employment_id"
+                    WHERE "employment"."user_id" = ?1
+                ) OR EXISTS (
+                    SELECT 1 FROM "event" WHERE "owner_id" = ?1
+                )
+            )"#,
+        )
+        .bind(user_id)
+        .fetch_one(&mut *transaction)
+        .await?;
+
+        if has_history {
+            return Err(RepositoryError::GenericError(
+                "The user has logged worked hours or owns events and cannot be purged".to_string(),
+            ));
+        }
+
+        let user = Self::fetch_user_in(
+            &mut transaction,
+            &format!(
+                r#"DELETE FROM "user"
+                WHERE "id" = ? AND "deleted_at" IS NOT NULL
+                RETURNING {}"#,
+                select_columns("user", USER_COLUMNS, "")
+            ),
+            user_id,
+        )
+        .await?;
+
+        if let Some(user) = user {
+            self.audit_in(
+                &mut transaction,
+                AuditAction::Purge,
+                &user,
+                Some(&user),
+                None,
+            )
+            .await?;
+            transaction.commit().await?;
+            return Ok(());
+        }
+
+        Err(RepositoryError::NotFound)
+    }
+}
@@ -0,0 +1,475 @@
+use crate::error::RepositoryError;
+use crate::models::audit_log::{AuditAction, AuditEntity};
+use crate::models::pagination::{ListSpec, Page};
+use crate::models::trash;
+use crate::models::venue::{CreateVenue, PartialVenue, SelectManyFilter, SortColumn, Venue};
+use crate::repositories::columns::{column, select_columns};
+use crate::repositories::sqlite::audit_log::SqliteAuditLogRepository;
+use crate::repositories::venue::{VenueRepository, VENUE_COLUMNS};
+use anyhow::Result;
+use async_trait::async_trait;
+use sqlx::sqlite::SqliteRow;
+use sqlx::{QueryBuilder, Row, Sqlite, SqliteConnection, SqlitePool};
+use std::sync::Arc;
+
+#[derive(Debug, Clone)]
+pub struct SqliteVenueRepository {
+    pub pool: SqlitePool,
+    pub actor_id: Option<i32>,
+}
+
+impl SqliteVenueRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self {
+            pool,
+            actor_id: None,
+        }
+    }
+
+    pub fn with_actor(&self, actor_id: i32) -> Self {
+        Self {
+            pool: self.pool.clone(),
+            actor_id: Some(actor_id),
+        }
+    }
+
+    async fn audit_in(
+        &self,
+        connection: &mut SqliteConnection,
+        action: AuditAction,
+        venue: &Venue,
+        before: Option<&Venue>,
+        after: Option<&Venue>,
+    ) -> Result<(), RepositoryError> {
+        SqliteAuditLogRepository::record_in(
+            connection,
+            self.actor_id,
+            AuditEntity::Venue,
+            venue.id,
+            None,
+            action,
+            before,
+            after,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn check_in_organization(
+        connection: &mut SqliteConnection,
+        venue_id: i32,
+        organization_id: i32,
+    ) -> Result<(), RepositoryError> {
+        let in_organization: bool = sqlx::query_scalar(
+            r#"SELECT EXISTS (
+                SELECT 1 FROM "venue" WHERE "id" = ? AND "organization_id" = ?
+            )"#,
+        )
+        .bind(venue_id)
+        .bind(organization_id)
+        .fetch_one(connection)
+        .await?;
+
+        if !in_organization {
+            return Err(RepositoryError::NotFound);
+        }
+        Ok(())
+    }
+
+    async fn fetch_venue_in(
+        connection: &mut SqliteConnection,
+        query: &str,
+        venue_id: i32,
+    ) -> Result<Option<Venue>, RepositoryError> {
+        let row = sqlx::query(query)
+            .bind(venue_id)
+            .fetch_optional(connection)
+            .await?;
+        Ok(row.map(|row| venue_from_row(&row, "")).transpose()?)
+    }
+
+    fn push_filter(query_builder: &mut QueryBuilder<'_, Sqlite>, filter: SelectManyFilter) {
+        query_builder.push(r#" FROM "venue" WHERE "deleted_at" IS NULL"#);
+
+        if let Some(organization_id) = filter.organization_id {
+            query_builder.push(r#" AND "organization_id" = "#);
+            query_builder.push_bind(organization_id);
+        }
+
+        if let Some(name) = filter.name {
+            query_builder.push(r#" AND "name" = "#);
+            query_builder.push_bind(name);
+        }
+
+        if let Some(state) = filter.state {
+            query_builder.push(r#" AND "state" = "#);
+            query_builder.push_bind(state);
+        }
+
+        if let Some(street_name) = filter.street_name {
+            query_builder.push(r#" AND "street_name" = "#);
+            query_builder.push_bind(street_name);
+        }
+
+        if let Some(postal_code) = filter.postal_code {
+            query_builder.push(r#" AND "postal_code" = "#);
+            query_builder.push_bind(postal_code);
+        }
+
+        if let Some(town) = filter.town {
+            query_builder.push(r#" AND "town" = "#);
+            query_builder.push_bind(town);
+        }
+
+        if let Some(street_number) = filter.street_number {
+            query_builder.push(r#" AND "street_number" = "#);
+            query_builder.push_bind(street_number);
+        }
+    }
+}
+
+/// Reads a venue selected with [`select_columns`] and `prefix`.
+pub(crate) fn venue_from_row(row: &SqliteRow, prefix: &str) -> Result<Venue, sqlx::Error> {
+    Ok(Venue {
+        id: row.try_get(&*column(prefix, "id"))?,
+        name: row.try_get(&*column(prefix, "name"))?,
+        description: row.try_get(&*column(prefix, "description"))?,
+        state: row.try_get(&*column(prefix, "state"))?,
+        postal_code: row.try_get(&*column(prefix, "postal_code"))?,
+        town: row.try_get(&*column(prefix, "town"))?,
+        street_name: row.try_get(&*column(prefix, "street_name"))?,
+        street_number: row.try_get(&*column(prefix, "street_number"))?,
+        address_url: row.try_get(&*column(prefix, "address_url"))?,
+        organization_id: row.try_get(&*column(prefix, "organization_id"))?,
+    })
+}
+
+#[async_trait]
+impl VenueRepository for SqliteVenueRepository {
+    fn with_actor(&self, actor_id: i32) -> Arc<dyn VenueRepository> {
+        Arc::new(SqliteVenueRepository::with_actor(self, actor_id))
+    }
+
+    async fn list_venues(&self, filter: SelectManyFilter) -> Result<Vec<Venue>> {
+        let mut query_builder = QueryBuilder::new(format!(
+            "SELECT {}",
+            select_columns("venue", VENUE_COLUMNS, "")
+        ));
+        Self::push_filter(&mut query_builder, filter);
+
+        let query = query_builder.build();
+        let rows = query.fetch_all(&self.pool).await?;
+
+        let data: Result<Vec<Venue>, sqlx::Error> =
+            rows.iter().map(|row| venue_from_row(row, "")).collect();
+        Ok(data?)
+    }
+
+    async fn list_venues_page(
+        &self,
+        filter: SelectManyFilter,
+        spec: &ListSpec<SortColumn>,
+    ) -> Result<Page<Venue>> {
+        let mut query_builder = QueryBuilder::new(format!(
+            r#"WITH "list" AS (SELECT {}, "#,
+            select_columns("venue", VENUE_COLUMNS, "")
+        ));
+        spec.push_sqlite_sort_key(&mut query_builder);
+        Self::push_filter(&mut query_builder, filter);
+        spec.push_sqlite_page(&mut query_builder);
+
+        let query = query_builder.build();
+        let rows = query.fetch_all(&self.pool).await?;
+
+        let total = match rows.first() {
+            Some(row) => row.try_get("total_count")?,
+            None => 0,
+        };
+        let data: Result<Vec<Venue>, sqlx::Error> =
+            rows.iter().map(|row| venue_from_row(row, "")).collect();
+        Ok(Page::new(data?, total, spec, |venue| venue.id))
+    }
+
+    async fn get_venue_by_id(&self, venue_id: i32) -> Result<Venue, RepositoryError> {
+        let mut connection = self.pool.acquire().await?;
+        Self::fetch_venue_in(
+            &mut connection,
+            &format!(
+                r#"SELECT {} FROM "venue" WHERE "id" = ? AND "deleted_at" IS NULL"#,
+                select_columns("venue", VENUE_COLUMNS, "")
+            ),
+            venue_id,
+        )
+        .await?
+        .ok_or(RepositoryError::NotFound)
+    }
+
+    async fn create_venue(&self, new_location: CreateVenue) -> Result<Venue, RepositoryError> {
+        let mut transaction = self.pool.begin().await?;
+        let row = sqlx::query(&format!(
+            r#"INSERT INTO "venue" (
+                "name", "description", "state", "postal_code", "town", "street_name", "street_number", "address_url", "organization_id"
+            )
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+            RETURNING {}"#,
+            select_columns("venue", VENUE_COLUMNS, "")
+        ))
+        .bind(new_location.name)
+        .bind(new_location.description)
+        .bind(new_location.state)
+        .bind(new_location.postal_code)
+        .bind(new_location.town)
+        .bind(new_location.street_name)
+        .bind(new_location.street_number)
+        .bind(new_location.address_url)
+        .bind(new_location.organization_id)
+        .fetch_one(&mut *transaction)
+        .await?;
+        let venue = venue_from_row(&row, "")?;
+
+        self.audit_in(
+            &mut transaction,
+            AuditAction::Create,
+            &venue,
+            None,
+            Some(&venue),
+        )
+        .await?;
+        transaction.commit().await?;
+        Ok(venue)
+    }
+
+    async fn delete_venue(&self, location_id: i32) -> Result<(), RepositoryError> {
+        let venue = self.get_venue_by_id(location_id).await?;
+        let mut transaction = self.pool.begin().await?;
+
+        let has_events: bool = sqlx::query_scalar(
+            r#"SELECT EXISTS (
+                SELECT 1 FROM "event" WHERE "venue_id" = ? AND "deleted_at" IS NULL
+            )"#,
+        )
+        .bind(location_id)
+        .fetch_one(&mut *transaction)
+        .await?;
+
+        if has_events {
+            return Err(RepositoryError::GenericError(
+                "The venue is associated with events".to_string(),
+            ));
+        }
+
+        let result = sqlx::query(
+            r#"UPDATE "venue" SET "deleted_at" = strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+            WHERE "id" = ? AND "deleted_at" IS NULL"#,
+        )
+        .bind(location_id)
+        .execute(&mut *transaction)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        self.audit_in(
+            &mut transaction,
+            AuditAction::Delete,
+            &venue,
+            Some(&venue),
+            None,
+        )
+        .await?;
+        transaction.commit().await?;
+        Ok(())
+    }
+
+    async fn update_venue(
+        &self,
+        venue_id: i32,
+        patch_venue: PartialVenue,
+    ) -> Result<Venue, RepositoryError> {
+        let venue = self.get_venue_by_id(venue_id).await?;
+        let mut transaction = self.pool.begin().await?;
+        let before = venue.clone();
+
+        let state = patch_venue.state.unwrap_or(venue.state);
+        let postal_code = patch_venue.postal_code.unwrap_or(venue.postal_code);
+        let town = patch_venue.town.unwrap_or(venue.town);
+        let street_name = patch_venue.street_name.unwrap_or(venue.street_name);
+        let street_number = patch_venue.street_number.unwrap_or(venue.street_number);
+        let name = patch_venue.name.unwrap_or(venue.name);
+        let description = patch_venue.description.or(venue.description);
+        let address_url = patch_venue.address_url.or(venue.address_url);
+
+        let row = sqlx::query(&format!(
+            r#"UPDATE "venue" SET
+                "state" = ?,
+                "postal_code" = ?,
+                "town" = ?,
+                "street_name" = ?,
+                "street_number" = ?,
+                "name" = ?,
+                "description" = ?,
+                "address_url" = ?
+            WHERE "id" = ? AND "deleted_at" IS NULL
+            RETURNING {}"#,
+            select_columns("venue", VENUE_COLUMNS, "")
+        ))
+        .bind(state)
+        .bind(postal_code)
+        .bind(town)
+        .bind(street_name)
+        .bind(street_number)
+        .bind(name)
+        .bind(description)
+        .bind(address_url)
+        .bind(venue_id)
+        .fetch_optional(&mut *transaction)
+        .await?;
+
+        if let Some(row) = row {
+            let venue = venue_from_row(&row, "")?;
+            self.audit_in(
+                &mut transaction,
+                AuditAction::Update,
+                &venue,
+                Some(&before),
+                Some(&venue),
+            )
+            .await?;
+            transaction.commit().await?;
+            return Ok(venue);
+        }
+
+        Err(RepositoryError::NotFound)
+    }
+
+    async fn list_states(&self, organization_id: Option<i32>) -> Result<Vec<String>> {
+        let states = sqlx::query_scalar(
+            r#"SELECT DISTINCT "state" FROM "venue"
+            WHERE "deleted_at" IS NULL AND (?1 IS NULL OR "organization_id" = ?1)"#,
+        )
+        .bind(organization_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(states)
+    }
+
+    async fn list_cities(&self, organization_id: Option<i32>) -> Result<Vec<String>> {
+        let cities = sqlx::query_scalar(
+            r#"SELECT DISTINCT "town" FROM "venue"
+            WHERE "deleted_at" IS NULL AND (?1 IS NULL OR "organization_id" = ?1)"#,
+        )
+        .bind(organization_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(cities)
+    }
+
+    async fn list_deleted_venues(
+        &self,
+        organization_id: i32,
+        spec: &ListSpec<trash::SortColumn>,
+    ) -> Result<Page<Venue>, RepositoryError> {
+        let mut query_builder = QueryBuilder::new(format!(
+            r#"WITH "list" AS (SELECT {}, "#,
+            select_columns("venue", VENUE_COLUMNS, "")
+        ));
+        spec.push_sqlite_sort_key(&mut query_builder);
+        query_builder
+            .push(r#" FROM "venue" WHERE "deleted_at" IS NOT NULL AND "organization_id" = "#);
+        query_builder.push_bind(organization_id);
+        spec.push_sqlite_page(&mut query_builder);
+
+        let rows = query_builder.build().fetch_all(&self.pool).await?;
+
+        let total = match rows.first() {
+            Some(row) => row.try_get("total_count")?,
+            None => 0,
+        };
+        let data: Result<Vec<Venue>, sqlx::Error> =
+            rows.iter().map(|row| venue_from_row(row, "")).collect();
+        Ok(Page::new(data?, total, spec, |venue| venue.id))
+    }
+
+    async fn restore_venue(
+        &self,
+        venue_id: i32,
+        organization_id: i32,
+    ) -> Result<Venue, RepositoryError> {
+        let mut transaction = self.pool.begin().await?;
+        Self::check_in_organization(&mut transaction, venue_id, organization_id).await?;
+        let venue = Self::fetch_venue_in(
+            &mut transaction,
+            &format!(
+                r#"UPDATE "venue" SET "deleted_at" = NULL
+                WHERE "id" = ? AND "deleted_at" IS NOT NULL
+                RETURNING {}"#,
+                select_columns("venue", VENUE_COLUMNS, "")
+            ),
+            venue_id,
+        )
+        .await?;
+
+        if let Some(venue) = venue {
+            self.audit_in(
+                &mut transaction,
+                AuditAction::Restore,
+                &venue,
+                None,
+                Some(&venue),
+            )
+            .await?;
+            transaction.commit().await?;
+            return Ok(venue);
+        }
+
+        Err(RepositoryError::NotFound)
+    }
+
+    async fn purge_venue(
+        &self,
+        venue_id: i32,
+        organization_id: i32,
+    ) -> Result<(), RepositoryError> {
+        let mut transaction = self.pool.begin().await?;
+        Self::check_in_organization(&mut transaction, venue_id, organization_id).await?;
+        let has_events: bool =
+            sqlx::query_scalar(r#"SELECT EXISTS (SELECT 1 FROM "event" WHERE "venue_id" = ?)"#)
+                .bind(venue_id)
+                .fetch_one(&mut *transaction)
+                .await?;
+
+        if has_events {
+            return Err(RepositoryError::GenericError(
+                "The venue is associated with events and cannot be purged".to_string(),
+            ));
+        }
+
+        let venue = Self::fetch_venue_in(
+            &mut transaction,
+            &format!(
+                r#"DELETE FROM "venue"
+                WHERE "id" = ? AND "deleted_at" IS NOT NULL
+                RETURNING {}"#,
+                select_columns("venue", VENUE_COLUMNS, "")
+            ),
+            venue_id,
+        )
+        .await?;
+
+        if let Some(venue) = venue {
+            self.audit_in(
+                &mut transaction,
+                AuditAction::Purge,
+                &venue,
+                Some(&venue),
+                None,
+            )
+            .await?;
+            transaction.commit().await?;
+            return Ok(());
+        }
+
+        Err(RepositoryError::NotFound)
+    }
+}
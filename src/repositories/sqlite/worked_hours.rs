@@ -0,0 +1,195 @@
+use crate::error::RepositoryError;
+use crate::models::employment::EmploymentState;
+use crate::models::worked_hours::{
+    CreateWorkedHours, PartialWorkedHours, SelectManyFilter, WorkedHours,
+};
+use crate::repositories::sqlite::employment::SqliteEmploymentRepository;
+use crate::repositories::sqlite::event::SqliteEventRepository;
+use crate::repositories::sqlite::job_position::SqliteJobPositionRepository;
+use crate::repositories::worked_hours::WorkedHoursRepository;
+use anyhow::Result;
+use async_trait::async_trait;
+use sqlx::sqlite::SqliteRow;
+use sqlx::{QueryBuilder, Row, SqliteConnection, SqlitePool};
+
+#[derive(Debug, Clone)]
+pub struct SqliteWorkedHoursRepository {
+    pub pool: SqlitePool,
+}
+
+impl SqliteWorkedHoursRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    async fn check_add(
+        connection: &mut SqliteConnection,
+        new_worked: &CreateWorkedHours,
+    ) -> Result<(), RepositoryError> {
+        let employment = SqliteEmploymentRepository::get_employment_by_id_in(
+            connection,
+            new_worked.employment_id,
+        )
+        .await?;
+        let job_position = SqliteJobPositionRepository::get_job_position_by_id_in(
+            connection,
+            employment.position_id,
+        )
+        .await?;
+        let event =
+            SqliteEventRepository::get_event_by_id_in(connection, job_position.event_id).await?;
+
+        if employment.state != EmploymentState::Accepted {
+            return Err(RepositoryError::GenericError(
+                "logging hours job in wrong state".to_string(),
+            ));
+        }
+
+        if event.date_start > new_worked.date || event.date_end < new_worked.date {
+            return Err(RepositoryError::GenericError(
+                "logging hours date outside of events date".to_string(),
+            ));
+        }
+
+        let result =
+            sqlx::query(r#"SELECT 1 FROM "worked_hours" WHERE "employment_id" = ? AND "date" = ?"#)
+                .bind(new_worked.employment_id)
+                .bind(new_worked.date)
+                .fetch_optional(connection)
+                .await?;
+
+        if result.is_some() {
+            return Err(RepositoryError::GenericError(
+                "cannot log worked hours for one job in same day twice".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+fn worked_hours_from_row(row: &SqliteRow) -> Result<WorkedHours, sqlx::Error> {
+    Ok(WorkedHours {
+        id: row.try_get("id")?,
+        date: row.try_get("date")?,
+        hours_worked: row.try_get("hours_worked")?,
+        employment_id: row.try_get("employment_id")?,
+    })
+}
+
+#[async_trait]
+impl WorkedHoursRepository for SqliteWorkedHoursRepository {
+    async fn list_worked_hours(
+        &self,
+        filter: SelectManyFilter,
+    ) -> Result<Vec<WorkedHours>, RepositoryError> {
+        let mut query_builder = QueryBuilder::new(
+            r#"SELECT "id", "date", "hours_worked", "employment_id" FROM "worked_hours" WHERE 1=1"#,
+        );
+
+        if let Some(registration_id) = filter.employment_id {
+            query_builder.push(r#" AND "employment_id" = "#);
+            query_builder.push_bind(registration_id);
+        }
+
+        if let Some(hours_worked) = filter.hours_worked {
+            query_builder.push(r#" AND "hours_worked" = "#);
+            query_builder.push_bind(hours_worked);
+        }
+
+        if let Some(date) = filter.date {
+            query_builder.push(r#" AND "date" = "#);
+            query_builder.push_bind(date);
+        }
+
+        let query = query_builder.build();
+        let rows = query.fetch_all(&self.pool).await?;
+
+        let data: Result<Vec<WorkedHours>, sqlx::Error> =
+            rows.iter().map(worked_hours_from_row).collect();
+        Ok(data?)
+    }
+
+    async fn get_worked_hours_by_id(&self, worked_id: i32) -> Result<WorkedHours, RepositoryError> {
+        let row = sqlx::query(
+            r#"SELECT "id", "date", "hours_worked", "employment_id"
+            FROM "worked_hours"
+            WHERE "id" = ?"#,
+        )
+        .bind(worked_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        match row {
+            Some(row) => Ok(worked_hours_from_row(&row)?),
+            None => Err(RepositoryError::NotFound),
+        }
+    }
+
+    async fn create_worked_hours(
+        &self,
+        new_worked: CreateWorkedHours,
+    ) -> Result<WorkedHours, RepositoryError> {
+        let mut connection = self.pool.acquire().await?;
+        Self::check_add(&mut connection, &new_worked).await?;
+        let row = sqlx::query(
+            r#"INSERT INTO "worked_hours" ("date", "hours_worked", "employment_id")
+            VALUES (?, ?, ?)
+            RETURNING "id", "date", "hours_worked", "employment_id""#,
+        )
+        .bind(new_worked.date)
+        .bind(new_worked.hours_worked)
+        .bind(new_worked.employment_id)
+        .fetch_one(&mut *connection)
+        .await?;
+
+        Ok(worked_hours_from_row(&row)?)
+    }
+
+    async fn delete_worked_hours(&self, worked_id: i32) -> Result<(), RepositoryError> {
+        let result = sqlx::query(r#"DELETE FROM "worked_hours" WHERE "id" = ?"#)
+            .bind(worked_id)
+            .execute(&self.pool)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn update_worked_hours(
+        &self,
+        worked_id: i32,
+        patch_worked: PartialWorkedHours,
+    ) -> Result<WorkedHours, RepositoryError> {
+        let worked_hours = self.get_worked_hours_by_id(worked_id).await?;
+
+        let hours_worked = patch_worked
+            .hours_worked
+            .unwrap_or(worked_hours.hours_worked);
+        let date = patch_worked.date.unwrap_or(worked_hours.date);
+        let employment_id = patch_worked
+            .employment_id
+            .unwrap_or(worked_hours.employment_id);
+
+        let row = sqlx::query(
+            r#"UPDATE "worked_hours"
+            SET "employment_id" = ?, "hours_worked" = ?, "date" = ?
+            WHERE "id" = ?
+            RETURNING "id", "date", "hours_worked", "employment_id""#,
+        )
+        .bind(employment_id)
+        .bind(hours_worked)
+        .bind(date)
+        .bind(worked_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        match row {
+            Some(row) => Ok(worked_hours_from_row(&row)?),
+            None => Err(RepositoryError::NotFound),
+        }
+    }
+}
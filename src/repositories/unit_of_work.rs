@@ -0,0 +1,79 @@
+use crate::error::RepositoryError;
+use crate::repositories::memory::{MemoryStore, MemoryTransaction};
+use crate::repositories::pool_handler::PoolHandler;
+use sqlx::{PgConnection, Postgres, Sqlite, SqliteConnection, SqlitePool, Transaction};
+
+/// One database transaction shared by several repository calls.
+///
+/// The `*_in` methods of repositories run on the transaction, so a handler can compose
+/// them and have all of their changes take effect on [`UnitOfWork::commit`] or none of
+/// them. Dropping a unit of work without committing rolls it back.
+pub struct UnitOfWork {
+    transaction: Backend,
+}
+
+enum Backend {
+    Postgres(Transaction<'static, Postgres>),
+    Sqlite(Transaction<'static, Sqlite>),
+    Memory(MemoryTransaction),
+}
+
+impl UnitOfWork {
+    pub async fn begin(pool_handler: &PoolHandler) -> Result<Self, RepositoryError> {
+        Ok(Self {
+            transaction: Backend::Postgres(pool_handler.pool().begin().await?),
+        })
+    }
+
+    pub async fn begin_sqlite(pool: &SqlitePool) -> Result<Self, RepositoryError> {
+        Ok(Self {
+            transaction: Backend::Sqlite(pool.begin().await?),
+        })
+    }
+
+    /// Unit of work of the in-memory repositories sharing `store`.
+    pub fn begin_in_memory(store: &MemoryStore) -> Self {
+        Self {
+            transaction: Backend::Memory(MemoryTransaction::begin(store)),
+        }
+    }
+
+    /// Connection the statements of the unit of work run on.
+    pub fn connection(&mut self) -> Result<&mut PgConnection, RepositoryError> {
+        match &mut self.transaction {
+            Backend::Postgres(transaction) => Ok(&mut **transaction),
+            _ => Err(RepositoryError::GenericError(
+                "The unit of work does not run on Postgres".to_string(),
+            )),
+        }
+    }
+
+    /// [`UnitOfWork::connection`] of a unit of work running on SQLite.
+    pub fn sqlite_connection(&mut self) -> Result<&mut SqliteConnection, RepositoryError> {
+        match &mut self.transaction {
+            Backend::Sqlite(transaction) => Ok(&mut **transaction),
+            _ => Err(RepositoryError::GenericError(
+                "The unit of work does not run on SQLite".to_string(),
+            )),
+        }
+    }
+
+    pub async fn commit(self) -> Result<(), RepositoryError> {
+        match self.transaction {
+            Backend::Postgres(transaction) => transaction.commit().await?,
+            Backend::Sqlite(transaction) => transaction.commit().await?,
+            Backend::Memory(transaction) => transaction.commit(),
+        }
+        Ok(())
+    }
+
+    #[allow(dead_code)]
+    pub async fn rollback(self) -> Result<(), RepositoryError> {
+        match self.transaction {
+            Backend::Postgres(transaction) => transaction.rollback().await?,
+            Backend::Sqlite(transaction) => transaction.rollback().await?,
+            Backend::Memory(transaction) => drop(transaction),
+        }
+        Ok(())
+    }
+}
@@ -1,39 +1,190 @@
 use crate::error::RepositoryError;
-use crate::models::user::{CreateUser, Gender, PartialUser, SelectManyFilter, User, UserRole};
+use crate::models::audit_log::{AuditAction, AuditEntity};
+use crate::models::pagination::{push_contains, ListSpec, Page};
+use crate::models::trash;
+use crate::models::user::{
+    CreateUser, Gender, PartialUser, SelectManyFilter, SortColumn, User, UserRole,
+};
+use crate::repositories::audit_log::PgAuditLogRepository;
+use crate::repositories::columns::column;
 use crate::repositories::pool_handler::PoolHandler;
+use crate::repositories::unit_of_work::UnitOfWork;
 use anyhow::Result;
 use async_trait::async_trait;
-use sqlx::{QueryBuilder, Row};
+use sqlx::postgres::PgRow;
+use sqlx::{PgConnection, Postgres, QueryBuilder, Row};
+use std::sync::Arc;
+
+pub(crate) const USER_COLUMNS: &[&str] = &[
+    "id",
+    "first_name",
+    "last_name",
+    "username",
+    "gender",
+    "birth_date",
+    "email",
+    "phone",
+    "password_hash",
+    "role",
+    "tax_rate",
+    "avatar_url",
+];
 
 #[async_trait]
-pub trait UserRepository {
+#[allow(dead_code)]
+pub trait UserRepository: Send + Sync {
+    fn with_actor(&self, actor_id: i32) -> Arc<dyn UserRepository>;
     async fn list_users(&self, filter: SelectManyFilter) -> Result<Vec<User>>;
+    async fn list_users_page(
+        &self,
+        filter: SelectManyFilter,
+        spec: &ListSpec<SortColumn>,
+    ) -> Result<Page<User>>;
     async fn get_user_by_id(&self, user_id: i32) -> Result<User, RepositoryError>;
     async fn get_user_by_username(&self, username: String) -> Result<User, RepositoryError>;
     async fn create_user(&self, new_user: CreateUser) -> Result<User, RepositoryError>;
+    async fn create_user_in(
+        &self,
+        unit_of_work: &mut UnitOfWork,
+        new_user: CreateUser,
+    ) -> Result<User, RepositoryError>;
     async fn delete_user(&self, user_id: i32) -> Result<(), RepositoryError>;
     async fn update_user(
         &self,
         user_id: i32,
         patch_user: PartialUser,
     ) -> Result<User, RepositoryError>;
+    async fn list_deleted_users(
+        &self,
+        organization_id: i32,
+        spec: &ListSpec<trash::SortColumn>,
+    ) -> Result<Page<User>, RepositoryError>;
+    async fn restore_user(
+        &self,
+        user_id: i32,
+        organization_id: i32,
+    ) -> Result<User, RepositoryError>;
+    async fn purge_user(
+        &self,
+        user_id: i32,
+        organization_id: i32,
+    ) -> Result<(), RepositoryError>;
 }
 
 #[derive(Debug, Clone)]
 pub struct PgUserRepository {
     pub pool_handler: PoolHandler,
+    pub actor_id: Option<i32>,
 }
 
 impl PgUserRepository {
     pub fn new(pool_handler: PoolHandler) -> Self {
-        Self { pool_handler }
+        Self {
+            pool_handler,
+            actor_id: None,
+        }
     }
-}
 
-#[async_trait]
-impl UserRepository for PgUserRepository {
-    async fn list_users(&self, filter: SelectManyFilter) -> Result<Vec<User>> {
-        let mut query_builder = QueryBuilder::new(r#"SELECT * FROM "user" WHERE 1=1"#);
+    pub fn with_actor(&self, actor_id: i32) -> Self {
+        Self {
+            pool_handler: self.pool_handler.clone(),
+            actor_id: Some(actor_id),
+        }
+    }
+
+    async fn audit_in(
+        &self,
+        connection: &mut PgConnection,
+        action: AuditAction,
+        user: &User,
+        before: Option<&User>,
+        after: Option<&User>,
+    ) -> Result<(), RepositoryError> {
+        PgAuditLogRepository::record_in(
+            connection,
+            self.actor_id,
+            AuditEntity::User,
+            user.id,
+            None,
+            action,
+            before,
+            after,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn check_in_organization(
+        connection: &mut PgConnection,
+        user_id: i32,
+        organization_id: i32,
+    ) -> Result<(), RepositoryError> {
+        let in_organization = sqlx::query_scalar!(
+            r#"SELECT EXISTS (
+                SELECT 1 FROM "organization_membership"
+                WHERE "user_id" = $1 AND "organization_id" = $2
+            ) AS "exists!""#,
+            user_id,
+            organization_id
+        )
+        .fetch_one(connection)
+        .await?;
+
+        if !in_organization {
+            return Err(RepositoryError::NotFound);
+        }
+        Ok(())
+    }
+
+    pub async fn get_user_by_id_in(
+        connection: &mut PgConnection,
+        user_id: i32,
+    ) -> Result<User, RepositoryError> {
+        let user = sqlx::query_as!(
+            User,
+            r#"
+            SELECT
+                "id",
+                "first_name",
+                "last_name",
+                "username",
+                "gender" AS "gender: Gender",
+                "role" AS "role: UserRole",
+                "birth_date",
+                "tax_rate",
+                "email",
+                "phone",
+                "password_hash",
+                "avatar_url"
+            FROM "user"
+            WHERE "id" = $1 AND "deleted_at" IS NULL;
+            "#,
+            user_id
+        )
+        .fetch_optional(connection)
+        .await?;
+
+        if let Some(user) = user {
+            return Ok(user);
+        }
+
+        Err(RepositoryError::NotFound)
+    }
+
+    /// Adds the `WHERE` clause of a list query.
+    pub(crate) fn push_filter(
+        query_builder: &mut QueryBuilder<'_, Postgres>,
+        filter: SelectManyFilter,
+    ) {
+        query_builder.push(r#" WHERE "deleted_at" IS NULL"#);
+
+        if let Some(organization_id) = filter.organization_id {
+            query_builder.push(
+                r#" AND "id" IN (SELECT "user_id" FROM "organization_membership" WHERE "organization_id" = "#,
+            );
+            query_builder.push_bind(organization_id);
+            query_builder.push(")");
+        }
 
         if let Some(username) = filter.username {
             query_builder.push(" AND username = ");
@@ -65,62 +216,80 @@ impl UserRepository for PgUserRepository {
             query_builder.push_bind(tax_rate);
         }
 
+        if let Some(last_name) = filter.last_name_contains {
+            push_contains(query_builder, r#""last_name""#, last_name);
+        }
+
+        if let Some(username) = filter.username_contains {
+            push_contains(query_builder, r#""username""#, username);
+        }
+    }
+}
+
+/// Reads a user selected with [`select_columns`](crate::repositories::columns::select_columns)
+/// and `prefix`.
+pub(crate) fn user_from_row(row: &PgRow, prefix: &str) -> Result<User, sqlx::Error> {
+    Ok(User {
+        id: row.try_get(&*column(prefix, "id"))?,
+        first_name: row.try_get(&*column(prefix, "first_name"))?,
+        last_name: row.try_get(&*column(prefix, "last_name"))?,
+        username: row.try_get(&*column(prefix, "username"))?,
+        gender: row.try_get(&*column(prefix, "gender"))?,
+        birth_date: row.try_get(&*column(prefix, "birth_date"))?,
+        email: row.try_get(&*column(prefix, "email"))?,
+        phone: row.try_get(&*column(prefix, "phone"))?,
+        password_hash: row.try_get(&*column(prefix, "password_hash"))?,
+        role: row.try_get(&*column(prefix, "role"))?,
+        tax_rate: row.try_get(&*column(prefix, "tax_rate"))?,
+        avatar_url: row.try_get(&*column(prefix, "avatar_url"))?,
+    })
+}
+
+#[async_trait]
+impl UserRepository for PgUserRepository {
+    fn with_actor(&self, actor_id: i32) -> Arc<dyn UserRepository> {
+        Arc::new(PgUserRepository::with_actor(self, actor_id))
+    }
+
+    async fn list_users(&self, filter: SelectManyFilter) -> Result<Vec<User>> {
+        let mut query_builder = QueryBuilder::new(r#"SELECT * FROM "user""#);
+        Self::push_filter(&mut query_builder, filter);
+
         let query = query_builder.build();
         let rows = query.fetch_all(self.pool_handler.pool()).await?;
 
-        let data: Result<Vec<User>, sqlx::Error> = rows
-            .into_iter()
-            .map(|row| {
-                Ok(User {
-                    id: row.try_get("id")?,
-                    first_name: row.try_get("first_name")?,
-                    last_name: row.try_get("last_name")?,
-                    username: row.try_get("username")?,
-                    gender: row.try_get("gender")?,
-                    birth_date: row.try_get("birth_date")?,
-                    email: row.try_get("email")?,
-                    phone: row.try_get("phone")?,
-                    password_hash: row.try_get("password_hash")?,
-                    role: row.try_get("role")?,
-                    tax_rate: row.try_get("tax_rate")?,
-                    avatar_url: row.try_get("avatar_url")?,
-                })
-            })
-            .collect();
+        let data: Result<Vec<User>, sqlx::Error> =
+            rows.iter().map(|row| user_from_row(row, "")).collect();
         let data = data?;
         Ok(data)
     }
 
-    async fn get_user_by_id(&self, user_id: i32) -> Result<User, RepositoryError> {
-        let user = sqlx::query_as!(
-            User,
-            r#"
-            SELECT
-                "id",
-                "first_name",
-                "last_name",
-                "username",
-                "gender" AS "gender: Gender",
-                "role" AS "role: UserRole",
-                "birth_date",
-                "tax_rate",
-                "email",
-                "phone",
-                "password_hash",
-                "avatar_url"
-            FROM "user"
-            WHERE "id" = $1;
-            "#,
-            user_id
-        )
-        .fetch_optional(self.pool_handler.pool())
-        .await?;
+    async fn list_users_page(
+        &self,
+        filter: SelectManyFilter,
+        spec: &ListSpec<SortColumn>,
+    ) -> Result<Page<User>> {
+        let mut query_builder = QueryBuilder::new(r#"WITH "list" AS (SELECT "user".*, "#);
+        spec.push_sort_key(&mut query_builder);
+        query_builder.push(r#" FROM "user""#);
+        Self::push_filter(&mut query_builder, filter);
+        spec.push_page(&mut query_builder);
 
-        if let Some(user) = user {
-            return Ok(user);
-        }
+        let query = query_builder.build();
+        let rows = query.fetch_all(self.pool_handler.pool()).await?;
 
-        Err(RepositoryError::NotFound)
+        let total = match rows.first() {
+            Some(row) => row.try_get("total_count")?,
+            None => 0,
+        };
+        let data: Result<Vec<User>, sqlx::Error> =
+            rows.iter().map(|row| user_from_row(row, "")).collect();
+        Ok(Page::new(data?, total, spec, |user| user.id))
+    }
+
+    async fn get_user_by_id(&self, user_id: i32) -> Result<User, RepositoryError> {
+        let mut connection = self.pool_handler.pool().acquire().await?;
+        Self::get_user_by_id_in(&mut connection, user_id).await
     }
 
     async fn get_user_by_username(&self, username: String) -> Result<User, RepositoryError> {
@@ -141,7 +310,7 @@ impl UserRepository for PgUserRepository {
                 "password_hash",
                 "avatar_url"
             FROM "user"
-            WHERE "username" = $1"#,
+            WHERE "username" = $1 AND "deleted_at" IS NULL"#,
             username
         )
         .fetch_optional(self.pool_handler.pool())
@@ -155,6 +324,18 @@ impl UserRepository for PgUserRepository {
     }
 
     async fn create_user(&self, new_user: CreateUser) -> Result<User, RepositoryError> {
+        let mut unit_of_work = UnitOfWork::begin(&self.pool_handler).await?;
+        let user = self.create_user_in(&mut unit_of_work, new_user).await?;
+        unit_of_work.commit().await?;
+        Ok(user)
+    }
+
+    async fn create_user_in(
+        &self,
+        unit_of_work: &mut UnitOfWork,
+        new_user: CreateUser,
+    ) -> Result<User, RepositoryError> {
+        let connection = unit_of_work.connection()?;
         let user = sqlx::query_as!(
             User,
             r#"INSERT INTO "user" (
@@ -176,20 +357,52 @@ impl UserRepository for PgUserRepository {
             new_user.tax_rate,
             new_user.avatar_url
         )
-            .fetch_one(self.pool_handler.pool())
+            .fetch_one(&mut *connection)
+            .await?;
+        self.audit_in(connection, AuditAction::Create, &user, None, Some(&user))
             .await?;
         Ok(user)
     }
 
     async fn delete_user(&self, user_id: i32) -> Result<(), RepositoryError> {
-        let result = sqlx::query!(r#"DELETE FROM "user" WHERE "id" = $1"#, user_id)
-            .execute(self.pool_handler.pool())
-            .await?;
+        let mut transaction = self.pool_handler.pool().begin().await?;
+        let user = Self::get_user_by_id_in(&mut transaction, user_id).await?;
+
+        let owns_events = sqlx::query_scalar!(
+            r#"SELECT EXISTS (
+                SELECT 1 FROM "event" WHERE "owner_id" = $1 AND "deleted_at" IS NULL
+            ) AS "exists!""#,
+            user_id
+        )
+        .fetch_one(&mut *transaction)
+        .await?;
+
+        if owns_events {
+            return Err(RepositoryError::GenericError(
+                "The user still owns events".to_string(),
+            ));
+        }
+
+        let result = sqlx::query!(
+            r#"UPDATE "user" SET "deleted_at" = NOW() WHERE "id" = $1 AND "deleted_at" IS NULL"#,
+            user_id
+        )
+        .execute(&mut *transaction)
+        .await?;
 
         if result.rows_affected() == 0 {
             return Err(RepositoryError::NotFound);
         }
 
+        self.audit_in(
+            &mut transaction,
+            AuditAction::Delete,
+            &user,
+            Some(&user),
+            None,
+        )
+        .await?;
+        transaction.commit().await?;
         Ok(())
     }
 
@@ -198,7 +411,9 @@ impl UserRepository for PgUserRepository {
         user_id: i32,
         patch_user: PartialUser,
     ) -> Result<User, RepositoryError> {
-        let user = self.get_user_by_id(user_id).await?;
+        let mut transaction = self.pool_handler.pool().begin().await?;
+        let user = Self::get_user_by_id_in(&mut transaction, user_id).await?;
+        let before = user.clone();
 
         let first_name = patch_user.first_name.unwrap_or(user.first_name);
         let last_name = patch_user.last_name.unwrap_or(user.last_name);
@@ -226,7 +441,7 @@ impl UserRepository for PgUserRepository {
                 "role" = $10::user_role, 
                 "tax_rate" = $11 ,
                 "avatar_url" = $12
-            WHERE "id" = $1
+            WHERE "id" = $1 AND "deleted_at" IS NULL
             RETURNING
                 "id", 
                 "first_name", 
@@ -254,13 +469,164 @@ impl UserRepository for PgUserRepository {
             tax_rate,
             avatar_url
         )
-        .fetch_optional(self.pool_handler.pool())
+        .fetch_optional(&mut *transaction)
         .await?;
 
         if let Some(user) = user {
+            self.audit_in(
+                &mut transaction,
+                AuditAction::Update,
+                &user,
+                Some(&before),
+                Some(&user),
+            )
+            .await?;
+            transaction.commit().await?;
             return Ok(user);
         }
 
         Err(RepositoryError::NotFound)
     }
+
+    async fn list_deleted_users(
+        &self,
+        organization_id: i32,
+        spec: &ListSpec<trash::SortColumn>,
+    ) -> Result<Page<User>, RepositoryError> {
+        let mut query_builder = QueryBuilder::new(r#"WITH "list" AS (SELECT "user".*, "#);
+        spec.push_sort_key(&mut query_builder);
+        query_builder.push(
+            r#" FROM "user"
+            WHERE "deleted_at" IS NOT NULL
+                AND "id" IN (
+                    SELECT "user_id" FROM "organization_membership" WHERE "organization_id" = "#,
+        );
+        query_builder.push_bind(organization_id);
+        query_builder.push(")");
+        spec.push_page(&mut query_builder);
+
+        let rows = query_builder
+            .build()
+            .fetch_all(self.pool_handler.pool())
+            .await?;
+
+        let total = match rows.first() {
+            Some(row) => row.try_get("total_count")?,
+            None => 0,
+        };
+        let data: Result<Vec<User>, sqlx::Error> =
+            rows.iter().map(|row| user_from_row(row, "")).collect();
+        Ok(Page::new(data?, total, spec, |user| user.id))
+    }
+
+    async fn restore_user(
+        &self,
+        user_id: i32,
+        organization_id: i32,
+    ) -> Result<User, RepositoryError> {
+        let mut transaction = self.pool_handler.pool().begin().await?;
+        Self::check_in_organization(&mut transaction, user_id, organization_id).await?;
+        let user = sqlx::query_as!(
+            User,
+            r#"UPDATE "user" SET "deleted_at" = NULL
+            WHERE "id" = $1 AND "deleted_at" IS NOT NULL
+            RETURNING
+                "id",
+                "first_name",
+                "last_name",
+                "username",
+                "gender" AS "gender: Gender",
+                "role" AS "role: UserRole",
+                "birth_date",
+                "tax_rate",
+                "email",
+                "phone",
+                "password_hash",
+                "avatar_url""#,
+            user_id
+        )
+        .fetch_optional(&mut *transaction)
+        .await?;
+
+        if let Some(user) = user {
+            self.audit_in(
+                &mut transaction,
+                AuditAction::Restore,
+                &user,
+                None,
+                Some(&user),
+            )
+            .await?;
+            transaction.commit().await?;
+            return Ok(user);
+        }
+
+        Err(RepositoryError::NotFound)
+    }
+
+    async fn purge_user(
+        &self,
+        user_id: i32,
+        organization_id: i32,
+    ) -> Result<(), RepositoryError> {
+        let mut transaction = self.pool_handler.pool().begin().await?;
+        Self::check_in_organization(&mut transaction, user_id, organization_id).await?;
+        let has_history = sqlx::query_scalar!(
+            r#"SELECT (
+                EXISTS (
+                    SELECT 1 FROM "worked_hours"
+                    JOIN "employment" ON "employment"."id" = "worked_hours"."employment_id"
+                    WHERE "employment"."user_id" = $1
+                ) OR EXISTS (
+                    SELECT 1 FROM "event" WHERE "owner_id" = $1
+                )
+            ) AS "exists!""#,
+            user_id
+        )
+        .fetch_one(&mut *transaction)
+        .await?;
+
+        if has_history {
+            return Err(RepositoryError::GenericError(
+                "The user has logged worked hours or owns events and cannot be purged".to_string(),
+            ));
+        }
+
+        let user = sqlx::query_as!(
+            User,
+            r#"DELETE FROM "user"
+            WHERE "id" = $1 AND "deleted_at" IS NOT NULL
+            RETURNING
+                "id",
+                "first_name",
+                "last_name",
+                "username",
+                "gender" AS "gender: Gender",
+                "role" AS "role: UserRole",
+                "birth_date",
+                "tax_rate",
+                "email",
+                "phone",
+                "password_hash",
+                "avatar_url""#,
+            user_id
+        )
+        .fetch_optional(&mut *transaction)
+        .await?;
+
+        if let Some(user) = user {
+            self.audit_in(
+                &mut transaction,
+                AuditAction::Purge,
+                &user,
+                Some(&user),
+                None,
+            )
+            .await?;
+            transaction.commit().await?;
+            return Ok(());
+        }
+
+        Err(RepositoryError::NotFound)
+    }
 }
@@ -1,13 +1,39 @@
 use crate::error::RepositoryError;
-use crate::models::venue::{CreateVenue, PartialVenue, SelectManyFilter, Venue};
+use crate::models::audit_log::{AuditAction, AuditEntity};
+use crate::models::pagination::{ListSpec, Page};
+use crate::models::trash;
+use crate::models::venue::{CreateVenue, PartialVenue, SelectManyFilter, SortColumn, Venue};
+use crate::repositories::audit_log::PgAuditLogRepository;
+use crate::repositories::columns::{column, select_columns};
 use crate::repositories::pool_handler::PoolHandler;
 use anyhow::Result;
 use async_trait::async_trait;
-use sqlx::{QueryBuilder, Row};
+use sqlx::postgres::PgRow;
+use sqlx::{PgConnection, Postgres, QueryBuilder, Row};
+use std::sync::Arc;
+
+pub(crate) const VENUE_COLUMNS: &[&str] = &[
+    "id",
+    "name",
+    "description",
+    "state",
+    "postal_code",
+    "town",
+    "street_name",
+    "street_number",
+    "address_url",
+    "organization_id",
+];
 
 #[async_trait]
-pub trait VenueRepository {
+pub trait VenueRepository: Send + Sync {
+    fn with_actor(&self, actor_id: i32) -> Arc<dyn VenueRepository>;
     async fn list_venues(&self, filter: SelectManyFilter) -> Result<Vec<Venue>>;
+    async fn list_venues_page(
+        &self,
+        filter: SelectManyFilter,
+        spec: &ListSpec<SortColumn>,
+    ) -> Result<Page<Venue>>;
     async fn get_venue_by_id(&self, location_id: i32) -> Result<Venue, RepositoryError>;
     async fn create_venue(&self, new_location: CreateVenue) -> Result<Venue, RepositoryError>;
     async fn delete_venue(&self, location_id: i32) -> Result<(), RepositoryError>;
@@ -16,37 +42,127 @@ pub trait VenueRepository {
         location_id: i32,
         patch_location: PartialVenue,
     ) -> Result<Venue, RepositoryError>;
-    async fn list_states(&self) -> Result<Vec<String>>;
-    async fn list_cities(&self) -> Result<Vec<String>>;
+    async fn list_states(&self, organization_id: Option<i32>) -> Result<Vec<String>>;
+    async fn list_cities(&self, organization_id: Option<i32>) -> Result<Vec<String>>;
+    async fn list_deleted_venues(
+        &self,
+        organization_id: i32,
+        spec: &ListSpec<trash::SortColumn>,
+    ) -> Result<Page<Venue>, RepositoryError>;
+    async fn restore_venue(
+        &self,
+        venue_id: i32,
+        organization_id: i32,
+    ) -> Result<Venue, RepositoryError>;
+    async fn purge_venue(
+        &self,
+        venue_id: i32,
+        organization_id: i32,
+    ) -> Result<(), RepositoryError>;
 }
 
 #[derive(Debug, Clone)]
 pub struct PgVenueRepository {
     pub pool_handler: PoolHandler,
+    pub actor_id: Option<i32>,
 }
 
 impl PgVenueRepository {
     pub fn new(pool_handler: PoolHandler) -> Self {
-        Self { pool_handler }
+        Self {
+            pool_handler,
+            actor_id: None,
+        }
     }
-}
 
-#[async_trait]
-impl VenueRepository for PgVenueRepository {
-    async fn list_venues(&self, filter: SelectManyFilter) -> Result<Vec<Venue>> {
-        let mut query_builder = QueryBuilder::new(
+    pub fn with_actor(&self, actor_id: i32) -> Self {
+        Self {
+            pool_handler: self.pool_handler.clone(),
+            actor_id: Some(actor_id),
+        }
+    }
+
+    async fn audit_in(
+        &self,
+        connection: &mut PgConnection,
+        action: AuditAction,
+        venue: &Venue,
+        before: Option<&Venue>,
+        after: Option<&Venue>,
+    ) -> Result<(), RepositoryError> {
+        PgAuditLogRepository::record_in(
+            connection,
+            self.actor_id,
+            AuditEntity::Venue,
+            venue.id,
+            None,
+            action,
+            before,
+            after,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn check_in_organization(
+        connection: &mut PgConnection,
+        venue_id: i32,
+        organization_id: i32,
+    ) -> Result<(), RepositoryError> {
+        let in_organization = sqlx::query_scalar!(
+            r#"SELECT EXISTS (
+                SELECT 1 FROM "venue" WHERE "id" = $1 AND "organization_id" = $2
+            ) AS "exists!""#,
+            venue_id,
+            organization_id
+        )
+        .fetch_one(connection)
+        .await?;
+
+        if !in_organization {
+            return Err(RepositoryError::NotFound);
+        }
+        Ok(())
+    }
+
+    pub async fn get_venue_by_id_in(
+        connection: &mut PgConnection,
+        venue_id: i32,
+    ) -> Result<Venue, RepositoryError> {
+        let venue = sqlx::query_as!(
+            Venue,
             r#"SELECT 
-                    "id",
-                    "name",
-                    "description",
-                    "state",
-                    "postal_code",
-                    "town",
-                    "street_name",
-                    "street_number",
-                    "address_url"
-                FROM "venue" WHERE 1=1"#,
-        );
+                "id",
+                "name",
+                "description",
+                "state",
+                "postal_code",
+                "town",
+                "street_name",
+                "street_number",
+                "address_url",
+                "organization_id"
+            FROM "venue" WHERE "id" = $1 AND "deleted_at" IS NULL"#,
+            venue_id
+        )
+        .fetch_optional(connection)
+        .await?;
+
+        if let Some(venue) = venue {
+            return Ok(venue);
+        }
+
+        Err(RepositoryError::NotFound)
+    }
+
+    /// Adds the `FROM` and `WHERE` clauses of a list query.
+    fn push_filter(query_builder: &mut QueryBuilder<'_, Postgres>, filter: SelectManyFilter) {
+        query_builder.push(r#" FROM "venue" WHERE "deleted_at" IS NULL"#);
+
+        if let Some(organization_id) = filter.organization_id {
+            query_builder.push(r#" AND "organization_id" = "#);
+            query_builder.push_bind(organization_id);
+        }
 
         if let Some(name) = filter.name {
             query_builder.push(r#" AND "name" = "#);
@@ -77,64 +193,86 @@ impl VenueRepository for PgVenueRepository {
             query_builder.push(r#" AND "street_number" = "#);
             query_builder.push_bind(street_number);
         }
+    }
+}
+
+/// Reads a venue selected with [`select_columns`] and `prefix`.
+pub(crate) fn venue_from_row(row: &PgRow, prefix: &str) -> Result<Venue, sqlx::Error> {
+    Ok(Venue {
+        id: row.try_get(&*column(prefix, "id"))?,
+        name: row.try_get(&*column(prefix, "name"))?,
+        description: row.try_get(&*column(prefix, "description"))?,
+        state: row.try_get(&*column(prefix, "state"))?,
+        postal_code: row.try_get(&*column(prefix, "postal_code"))?,
+        town: row.try_get(&*column(prefix, "town"))?,
+        street_name: row.try_get(&*column(prefix, "street_name"))?,
+        street_number: row.try_get(&*column(prefix, "street_number"))?,
+        address_url: row.try_get(&*column(prefix, "address_url"))?,
+        organization_id: row.try_get(&*column(prefix, "organization_id"))?,
+    })
+}
+
+#[async_trait]
+impl VenueRepository for PgVenueRepository {
+    fn with_actor(&self, actor_id: i32) -> Arc<dyn VenueRepository> {
+        Arc::new(PgVenueRepository::with_actor(self, actor_id))
+    }
+
+    async fn list_venues(&self, filter: SelectManyFilter) -> Result<Vec<Venue>> {
+        let mut query_builder = QueryBuilder::new(format!(
+            "SELECT {}",
+            select_columns("venue", VENUE_COLUMNS, "")
+        ));
+        Self::push_filter(&mut query_builder, filter);
 
         let query = query_builder.build();
         let rows = query.fetch_all(self.pool_handler.pool()).await?;
 
-        let data: Result<Vec<Venue>, sqlx::Error> = rows
-            .into_iter()
-            .map(|row| {
-                Ok(Venue {
-                    id: row.try_get("id")?,
-                    name: row.try_get("name")?,
-                    description: row.try_get("description")?,
-                    state: row.try_get("state")?,
-                    postal_code: row.try_get("postal_code")?,
-                    town: row.try_get("town")?,
-                    street_name: row.try_get("street_name")?,
-                    street_number: row.try_get("street_number")?,
-                    address_url: row.try_get("address_url")?,
-                })
-            })
-            .collect();
+        let data: Result<Vec<Venue>, sqlx::Error> =
+            rows.iter().map(|row| venue_from_row(row, "")).collect();
 
         let data = data?;
         Ok(data)
     }
 
-    async fn get_venue_by_id(&self, venue_id: i32) -> Result<Venue, RepositoryError> {
-        let venue = sqlx::query_as!(
-            Venue,
-            r#"SELECT 
-                "id",
-                "name",
-                "description",
-                "state",
-                "postal_code",
-                "town",
-                "street_name",
-                "street_number",
-                "address_url"
-            FROM "venue" WHERE "id" = $1"#,
-            venue_id
-        )
-        .fetch_optional(self.pool_handler.pool())
-        .await?;
+    async fn list_venues_page(
+        &self,
+        filter: SelectManyFilter,
+        spec: &ListSpec<SortColumn>,
+    ) -> Result<Page<Venue>> {
+        let mut query_builder = QueryBuilder::new(format!(
+            r#"WITH "list" AS (SELECT {}, "#,
+            select_columns("venue", VENUE_COLUMNS, "")
+        ));
+        spec.push_sort_key(&mut query_builder);
+        Self::push_filter(&mut query_builder, filter);
+        spec.push_page(&mut query_builder);
 
-        if let Some(venue) = venue {
-            return Ok(venue);
-        }
+        let query = query_builder.build();
+        let rows = query.fetch_all(self.pool_handler.pool()).await?;
 
-        Err(RepositoryError::NotFound)
+        let total = match rows.first() {
+            Some(row) => row.try_get("total_count")?,
+            None => 0,
+        };
+        let data: Result<Vec<Venue>, sqlx::Error> =
+            rows.iter().map(|row| venue_from_row(row, "")).collect();
+        Ok(Page::new(data?, total, spec, |venue| venue.id))
+    }
+
+    async fn get_venue_by_id(&self, venue_id: i32) -> Result<Venue, RepositoryError> {
+        let mut connection = self.pool_handler.pool().acquire().await?;
+        Self::get_venue_by_id_in(&mut connection, venue_id).await
     }
 
     async fn create_venue(&self, new_location: CreateVenue) -> Result<Venue, RepositoryError> {
+        let mut transaction = self.pool_handler.pool().begin().await?;
         let venue = sqlx::query_as!(
             Venue,
             r#"INSERT INTO "venue" (
-                "name", "description", "state", "postal_code", "town", "street_name", "street_number", "address_url"
+                "name", "description", "state", "postal_code", "town", "street_name", "street_number", "address_url", "organization_id"
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
             RETURNING 
                 "id",
                 "name",
@@ -144,7 +282,8 @@ impl VenueRepository for PgVenueRepository {
                 "town",
                 "street_name",
                 "street_number",
-                "address_url"
+                "address_url",
+                "organization_id"
             "#,
             new_location.name,
             new_location.description,
@@ -153,23 +292,63 @@ impl VenueRepository for PgVenueRepository {
             new_location.town,
             new_location.street_name,
             new_location.street_number,
-            new_location.address_url
+            new_location.address_url,
+            new_location.organization_id
         )
-            .fetch_one(self.pool_handler.pool())
+            .fetch_one(&mut *transaction)
             .await?;
 
+        self.audit_in(
+            &mut transaction,
+            AuditAction::Create,
+            &venue,
+            None,
+            Some(&venue),
+        )
+        .await?;
+        transaction.commit().await?;
         Ok(venue)
     }
 
     async fn delete_venue(&self, location_id: i32) -> Result<(), RepositoryError> {
-        let result = sqlx::query!(r#"DELETE FROM "venue" WHERE "id" = $1"#, location_id)
-            .execute(self.pool_handler.pool())
-            .await?;
+        let mut transaction = self.pool_handler.pool().begin().await?;
+        let venue = Self::get_venue_by_id_in(&mut transaction, location_id).await?;
+
+        let has_events = sqlx::query_scalar!(
+            r#"SELECT EXISTS (
+                SELECT 1 FROM "event" WHERE "venue_id" = $1 AND "deleted_at" IS NULL
+            ) AS "exists!""#,
+            location_id
+        )
+        .fetch_one(&mut *transaction)
+        .await?;
+
+        if has_events {
+            return Err(RepositoryError::GenericError(
+                "The venue is associated with events".to_string(),
+            ));
+        }
+
+        let result = sqlx::query!(
+            r#"UPDATE "venue" SET "deleted_at" = NOW() WHERE "id" = $1 AND "deleted_at" IS NULL"#,
+            location_id
+        )
+        .execute(&mut *transaction)
+        .await?;
 
         if result.rows_affected() == 0 {
             return Err(RepositoryError::NotFound);
         }
 
+        self.audit_in(
+            &mut transaction,
+            AuditAction::Delete,
+            &venue,
+            Some(&venue),
+            None,
+        )
+        .await?;
+        transaction.commit().await?;
         Ok(())
     }
 
@@ -178,7 +357,9 @@ impl VenueRepository for PgVenueRepository {
         venue_id: i32,
         patch_venue: PartialVenue,
     ) -> Result<Venue, RepositoryError> {
-        let venue = self.get_venue_by_id(venue_id).await?;
+        let mut transaction = self.pool_handler.pool().begin().await?;
+        let venue = Self::get_venue_by_id_in(&mut transaction, venue_id).await?;
+        let before = venue.clone();
 
         let state = patch_venue.state.unwrap_or(venue.state);
         let postal_code = patch_venue.postal_code.unwrap_or(venue.postal_code);
@@ -200,7 +381,7 @@ impl VenueRepository for PgVenueRepository {
                 "name" = $7,
                 "description" = $8,
                 "address_url" = $9
-            WHERE "id" = $1
+            WHERE "id" = $1 AND "deleted_at" IS NULL
             RETURNING 
                 "id",
                 "name",
@@ -210,7 +391,8 @@ impl VenueRepository for PgVenueRepository {
                 "town",
                 "street_name",
                 "street_number",
-                "address_url"
+                "address_url",
+                "organization_id"
             "#,
             venue_id,
             state,
@@ -222,29 +404,174 @@ impl VenueRepository for PgVenueRepository {
             description,
             address_url
         )
-        .fetch_optional(self.pool_handler.pool())
+        .fetch_optional(&mut *transaction)
         .await?;
 
         if let Some(venue) = venue {
+            self.audit_in(
+                &mut transaction,
+                AuditAction::Update,
+                &venue,
+                Some(&before),
+                Some(&venue),
+            )
+            .await?;
+            transaction.commit().await?;
             return Ok(venue);
         }
 
         Err(RepositoryError::NotFound)
     }
 
-    async fn list_states(&self) -> Result<Vec<String>> {
-        let states = sqlx::query!(r#"SELECT DISTINCT "state" FROM "venue""#)
-            .fetch_all(self.pool_handler.pool())
-            .await?;
+    async fn list_states(&self, organization_id: Option<i32>) -> Result<Vec<String>> {
+        let states = sqlx::query!(
+            r#"SELECT DISTINCT "state" FROM "venue"
+            WHERE "deleted_at" IS NULL AND ($1::INT IS NULL OR "organization_id" = $1)"#,
+            organization_id
+        )
+        .fetch_all(self.pool_handler.pool())
+        .await?;
         let states = states.into_iter().map(|row| row.state).collect();
         Ok(states)
     }
 
-    async fn list_cities(&self) -> Result<Vec<String>> {
-        let cities = sqlx::query!(r#"SELECT DISTINCT "town" FROM "venue""#)
-            .fetch_all(self.pool_handler.pool())
-            .await?;
+    async fn list_cities(&self, organization_id: Option<i32>) -> Result<Vec<String>> {
+        let cities = sqlx::query!(
+            r#"SELECT DISTINCT "town" FROM "venue"
+            WHERE "deleted_at" IS NULL AND ($1::INT IS NULL OR "organization_id" = $1)"#,
+            organization_id
+        )
+        .fetch_all(self.pool_handler.pool())
+        .await?;
         let cities = cities.into_iter().map(|row| row.town).collect();
         Ok(cities)
     }
+
+    async fn list_deleted_venues(
+        &self,
+        organization_id: i32,
+        spec: &ListSpec<trash::SortColumn>,
+    ) -> Result<Page<Venue>, RepositoryError> {
+        let mut query_builder = QueryBuilder::new(format!(
+            r#"WITH "list" AS (SELECT {}, "#,
+            select_columns("venue", VENUE_COLUMNS, "")
+        ));
+        spec.push_sort_key(&mut query_builder);
+        query_builder
+            .push(r#" FROM "venue" WHERE "deleted_at" IS NOT NULL AND "organization_id" = "#);
+        query_builder.push_bind(organization_id);
+        spec.push_page(&mut query_builder);
+
+        let rows = query_builder
+            .build()
+            .fetch_all(self.pool_handler.pool())
+            .await?;
+
+        let total = match rows.first() {
+            Some(row) => row.try_get("total_count")?,
+            None => 0,
+        };
+        let data: Result<Vec<Venue>, sqlx::Error> =
+            rows.iter().map(|row| venue_from_row(row, "")).collect();
+        Ok(Page::new(data?, total, spec, |venue| venue.id))
+    }
+
+    async fn restore_venue(
+        &self,
+        venue_id: i32,
+        organization_id: i32,
+    ) -> Result<Venue, RepositoryError> {
+        let mut transaction = self.pool_handler.pool().begin().await?;
+        Self::check_in_organization(&mut transaction, venue_id, organization_id).await?;
+        let venue = sqlx::query_as!(
+            Venue,
+            r#"UPDATE "venue" SET "deleted_at" = NULL
+            WHERE "id" = $1 AND "deleted_at" IS NOT NULL
+            RETURNING
+                "id",
+                "name",
+                "description",
+                "state",
+                "postal_code",
+                "town",
+                "street_name",
+                "street_number",
+                "address_url",
+                "organization_id""#,
+            venue_id
+        )
+        .fetch_optional(&mut *transaction)
+        .await?;
+
+        if let Some(venue) = venue {
+            self.audit_in(
+                &mut transaction,
+                AuditAction::Restore,
+                &venue,
+                None,
+                Some(&venue),
+            )
+            .await?;
+            transaction.commit().await?;
+            return Ok(venue);
+        }
+
+        Err(RepositoryError::NotFound)
+    }
+
+    async fn purge_venue(
+        &self,
+        venue_id: i32,
+        organization_id: i32,
+    ) -> Result<(), RepositoryError> {
+        let mut transaction = self.pool_handler.pool().begin().await?;
+        Self::check_in_organization(&mut transaction, venue_id, organization_id).await?;
+        let has_events = sqlx::query_scalar!(
+            r#"SELECT EXISTS (SELECT 1 FROM "event" WHERE "venue_id" = $1) AS "exists!""#,
+            venue_id
+        )
+        .fetch_one(&mut *transaction)
+        .await?;
+
+        if has_events {
+            return Err(RepositoryError::GenericError(
+                "The venue is associated with events and cannot be purged".to_string(),
+            ));
+        }
+
+        let venue = sqlx::query_as!(
+            Venue,
+            r#"DELETE FROM "venue"
+            WHERE "id" = $1 AND "deleted_at" IS NOT NULL
+            RETURNING
+                "id",
+                "name",
+                "description",
+                "state",
+                "postal_code",
+                "town",
+                "street_name",
+                "street_number",
+                "address_url",
+                "organization_id""#,
+            venue_id
+        )
+        .fetch_optional(&mut *transaction)
+        .await?;
+
+        if let Some(venue) = venue {
+            self.audit_in(
+                &mut transaction,
+                AuditAction::Purge,
+                &venue,
+                Some(&venue),
+                None,
+            )
+            .await?;
+            transaction.commit().await?;
+            return Ok(());
+        }
+
+        Err(RepositoryError::NotFound)
+    }
 }
@@ -14,7 +14,7 @@ use std::sync::Arc;
 
 #[async_trait]
 #[allow(dead_code)]
-pub trait WorkedHoursRepository {
+pub trait WorkedHoursRepository: Send + Sync {
     async fn list_worked_hours(
         &self,
         filter: SelectManyFilter,
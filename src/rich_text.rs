@@ -0,0 +1,26 @@
+use ammonia::Builder;
+use pulldown_cmark::{html, Options, Parser};
+
+/// Renders Markdown written by organizers into HTML that is safe to show to other users.
+/// Raw HTML in the source is kept only if it passes the same allow-list as the rendered
+/// Markdown.
+pub fn render_markdown(source: &str) -> String {
+    let parser = Parser::new_ext(
+        source,
+        Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TABLES,
+    );
+    let mut rendered = String::new();
+    html::push_html(&mut rendered, parser);
+    sanitize_html(&rendered)
+}
+
+/// Drops scripts, event handlers, unsafe URL schemes and any tag or attribute that is not
+/// on ammonia's allow-list. Images are removed as well, so that instructions cannot load
+/// content from third parties.
+pub fn sanitize_html(html: &str) -> String {
+    Builder::default()
+        .rm_tags(["img"])
+        .link_rel(Some("noopener noreferrer nofollow"))
+        .clean(html)
+        .to_string()
+}
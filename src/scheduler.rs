@@ -0,0 +1,61 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use sqlx::types::time::OffsetDateTime;
+
+use crate::repositories::{
+    employment::EmploymentRepository, event::EventRepository, job_position::JobPositionRepository,
+};
+
+const SCHEDULER_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Periodically applies the date driven changes to events, job positions and invitations.
+pub fn spawn_scheduler(
+    event_repository: Arc<dyn EventRepository>,
+    job_position_repository: Arc<dyn JobPositionRepository>,
+    employment_repository: Arc<dyn EmploymentRepository>,
+) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(SCHEDULER_INTERVAL);
+        loop {
+            interval.tick().await;
+            let now = OffsetDateTime::now_utc();
+
+            match event_repository.publish_scheduled_events(now).await {
+                Ok(events) if !events.is_empty() => {
+                    tracing::info!("Published {} scheduled event(s)", events.len())
+                }
+                Ok(_) => (),
+                Err(err) => tracing::error!("Failed to publish scheduled events: {:?}", err),
+            }
+
+            match event_repository.advance_event_statuses(now.date()).await {
+                Ok(events) if !events.is_empty() => {
+                    tracing::info!("Advanced the status of {} event(s)", events.len())
+                }
+                Ok(_) => (),
+                Err(err) => tracing::error!("Failed to advance event statuses: {:?}", err),
+            }
+
+            match job_position_repository
+                .apply_registration_windows(now)
+                .await
+            {
+                Ok(job_positions) if !job_positions.is_empty() => tracing::info!(
+                    "Updated registration of {} job position(s)",
+                    job_positions.len()
+                ),
+                Ok(_) => (),
+                Err(err) => tracing::error!("Failed to apply registration windows: {:?}", err),
+            }
+
+            match employment_repository.expire_invitations(now).await {
+                Ok(employments) if !employments.is_empty() => {
+                    tracing::info!("Expired {} invitation(s)", employments.len())
+                }
+                Ok(_) => (),
+                Err(err) => tracing::error!("Failed to expire invitations: {:?}", err),
+            }
+        }
+    });
+}
@@ -0,0 +1,135 @@
+use std::env;
+
+use axum::{
+    extract::{Request, State},
+    http::{header, HeaderName, HeaderValue},
+    middleware::Next,
+    response::Response,
+};
+use uuid::Uuid;
+
+tokio::task_local! {
+    static CSP_NONCE: String;
+}
+
+/// Nonce of the request being handled, templates put it on their `<script>` tags.
+///
+/// Empty outside of a request going through [`security_headers`].
+pub fn csp_nonce() -> String {
+    CSP_NONCE.try_with(Clone::clone).unwrap_or_default()
+}
+
+/// How the Content-Security-Policy is sent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CspMode {
+    /// The browser blocks everything the policy does not allow.
+    Enforce,
+    /// The browser only reports violations, used while rolling the policy out.
+    ReportOnly,
+    Off,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SecurityHeaders {
+    pub csp_mode: CspMode,
+    /// Where browsers send violation reports to.
+    pub csp_report_uri: Option<String>,
+    /// Whether the app is served over HTTPS, Strict-Transport-Security is only sent then.
+    pub tls: bool,
+}
+
+impl Default for SecurityHeaders {
+    fn default() -> Self {
+        Self {
+            csp_mode: CspMode::ReportOnly,
+            csp_report_uri: None,
+            tls: false,
+        }
+    }
+}
+
+impl SecurityHeaders {
+    /// Reads `CSP_MODE` (`enforce`, `report-only` or `off`, report-only by default),
+    /// `CSP_REPORT_URI` and `TLS_ENABLED`.
+    pub fn from_env() -> Self {
+        let csp_mode = match env::var("CSP_MODE").as_deref() {
+            Ok("enforce") => CspMode::Enforce,
+            Ok("off") => CspMode::Off,
+            _ => CspMode::ReportOnly,
+        };
+        Self {
+            csp_mode,
+            csp_report_uri: env::var("CSP_REPORT_URI")
+                .ok()
+                .filter(|uri| !uri.is_empty()),
+            tls: env::var("TLS_ENABLED").is_ok_and(|value| value == "true"),
+        }
+    }
+
+    /// The policy for a response whose inline scripts carry the given nonce.
+    ///
+    /// HTMX evaluates `hx-on` attributes with `Function`, hence `'unsafe-eval'`. Styles stay
+    /// inline-friendly as the templates use `style` attributes and HTMX injects its own.
+    pub fn content_security_policy(&self, nonce: &str) -> String {
+        let mut policy = format!(
+            "default-src 'self'; \
+             script-src 'self' 'nonce-{nonce}' 'unsafe-eval' https://unpkg.com; \
+             style-src 'self' 'unsafe-inline'; \
+             img-src 'self' https: data:; \
+             font-src 'self' https://homework2storage.blob.core.windows.net; \
+             connect-src 'self'; \
+             object-src 'none'; \
+             base-uri 'self'; \
+             form-action 'self'; \
+             frame-ancestors 'none'"
+        );
+        if let Some(report_uri) = &self.csp_report_uri {
+            policy.push_str(&format!("; report-uri {report_uri}"));
+        }
+        policy
+    }
+}
+
+/// Adds the security headers to every response and provides the CSP nonce to the templates.
+pub async fn security_headers(
+    State(config): State<SecurityHeaders>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let nonce = Uuid::new_v4().simple().to_string();
+    // Templates are rendered inside the handler, so they see the nonce through the task local.
+    let mut response = CSP_NONCE.scope(nonce.clone(), next.run(request)).await;
+
+    let headers = response.headers_mut();
+    headers.insert(header::X_FRAME_OPTIONS, HeaderValue::from_static("DENY"));
+    headers.insert(
+        header::X_CONTENT_TYPE_OPTIONS,
+        HeaderValue::from_static("nosniff"),
+    );
+    headers.insert(
+        header::REFERRER_POLICY,
+        HeaderValue::from_static("strict-origin-when-cross-origin"),
+    );
+    headers.insert(
+        HeaderName::from_static("permissions-policy"),
+        HeaderValue::from_static("camera=(), microphone=(), geolocation=(), payment=(), usb=()"),
+    );
+    if config.tls {
+        headers.insert(
+            header::STRICT_TRANSPORT_SECURITY,
+            HeaderValue::from_static("max-age=31536000; includeSubDomains"),
+        );
+    }
+
+    let csp_header = match config.csp_mode {
+        CspMode::Enforce => Some(header::CONTENT_SECURITY_POLICY),
+        CspMode::ReportOnly => Some(header::CONTENT_SECURITY_POLICY_REPORT_ONLY),
+        CspMode::Off => None,
+    };
+    if let Some(csp_header) = csp_header {
+        if let Ok(policy) = HeaderValue::from_str(&config.content_security_policy(&nonce)) {
+            headers.insert(csp_header, policy);
+        }
+    }
+    response
+}
@@ -0,0 +1,89 @@
+pub mod local;
+pub mod s3;
+pub mod upload;
+
+use std::{env, sync::Arc};
+
+use async_trait::async_trait;
+use validator::{ValidateUrl, ValidationError};
+
+use crate::error::StorageError;
+use crate::storage::{local::LocalBlobStore, s3::S3BlobStore};
+
+/// Path uploaded blobs are served under, followed by their key.
+pub const UPLOADS_ROUTE: &str = "/uploads";
+
+/// Suffix of the key of an image's thumbnail, put in front of the file extension.
+const THUMBNAIL_SUFFIX: &str = "_thumb";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Blob {
+    pub content_type: String,
+    pub bytes: Vec<u8>,
+}
+
+/// Storage of uploaded files, addressed by slash separated keys such as `avatars/<uuid>.jpg`.
+#[async_trait]
+pub trait BlobStore: Send + Sync {
+    /// Stores the blob under the key, replacing a blob stored there before.
+    async fn put(&self, key: &str, blob: Blob) -> Result<(), StorageError>;
+    async fn get(&self, key: &str) -> Result<Blob, StorageError>;
+    /// Deletes the blob, deleting a missing blob is not an error.
+    async fn delete(&self, key: &str) -> Result<(), StorageError>;
+}
+
+/// Builds the store selected by `BLOB_STORE`, `local` (the default) or `s3`.
+pub fn blob_store_from_env() -> anyhow::Result<Arc<dyn BlobStore>> {
+    match env::var("BLOB_STORE").as_deref() {
+        Ok("s3") => Ok(Arc::new(S3BlobStore::from_env()?)),
+        Ok("local") | Err(_) => Ok(Arc::new(LocalBlobStore::from_env())),
+        Ok(other) => Err(anyhow::anyhow!("Unknown BLOB_STORE '{other}'")),
+    }
+}
+
+/// Checks the key cannot escape the store, keys are generated by the app but served from user
+/// supplied paths.
+pub fn is_valid_key(key: &str) -> bool {
+    !key.is_empty()
+        && !key.starts_with('/')
+        && key.split('/').all(|segment| {
+            !segment.is_empty()
+                && segment != "."
+                && segment != ".."
+                && segment
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        })
+}
+
+pub fn public_url(key: &str) -> String {
+    format!("{UPLOADS_ROUTE}/{key}")
+}
+
+pub fn thumbnail_key(key: &str) -> String {
+    match key.rsplit_once('.') {
+        Some((stem, extension)) => format!("{stem}{THUMBNAIL_SUFFIX}.{extension}"),
+        None => format!("{key}{THUMBNAIL_SUFFIX}"),
+    }
+}
+
+/// Thumbnail of an uploaded image, images hosted elsewhere have no thumbnail and are
+/// returned as they are.
+pub fn thumbnail_url(url: &str) -> String {
+    match url.strip_prefix(&format!("{UPLOADS_ROUTE}/")) {
+        Some(key) => public_url(&thumbnail_key(key)),
+        None => url.to_string(),
+    }
+}
+
+/// Accepts absolute URLs of images hosted elsewhere as well as paths of uploaded ones.
+pub fn validate_image_url(url: &str) -> Result<(), ValidationError> {
+    if let Some(key) = url.strip_prefix(&format!("{UPLOADS_ROUTE}/")) {
+        if is_valid_key(key) {
+            return Ok(());
+        }
+    } else if url.validate_url() {
+        return Ok(());
+    }
+    Err(ValidationError::new("image_url"))
+}
@@ -0,0 +1,74 @@
+use std::{
+    env,
+    path::{Path, PathBuf},
+};
+
+use async_trait::async_trait;
+use tokio::fs;
+
+use crate::error::StorageError;
+use crate::storage::{is_valid_key, Blob, BlobStore};
+
+const DEFAULT_UPLOADS_DIR: &str = "uploads";
+
+/// Keeps blobs as files in a directory, the content type is derived from the file extension.
+#[derive(Debug, Clone)]
+pub struct LocalBlobStore {
+    root: PathBuf,
+}
+
+impl LocalBlobStore {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Stores blobs in `UPLOADS_DIR`, `uploads` in the working directory by default.
+    pub fn from_env() -> Self {
+        Self::new(env::var("UPLOADS_DIR").unwrap_or(DEFAULT_UPLOADS_DIR.to_string()))
+    }
+
+    fn path(&self, key: &str) -> Result<PathBuf, StorageError> {
+        if !is_valid_key(key) {
+            return Err(StorageError::NotFound);
+        }
+        Ok(self.root.join(key))
+    }
+}
+
+fn content_type(path: &Path) -> &'static str {
+    match path.extension().and_then(|extension| extension.to_str()) {
+        Some("jpg") | Some("jpeg") => "image/jpeg",
+        Some("png") => "image/png",
+        Some("webp") => "image/webp",
+        _ => "application/octet-stream",
+    }
+}
+
+#[async_trait]
+impl BlobStore for LocalBlobStore {
+    async fn put(&self, key: &str, blob: Blob) -> Result<(), StorageError> {
+        let path = self.path(key)?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).await?;
+        }
+        fs::write(path, blob.bytes).await?;
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> Result<Blob, StorageError> {
+        let path = self.path(key)?;
+        let bytes = fs::read(&path).await?;
+        Ok(Blob {
+            content_type: content_type(&path).to_string(),
+            bytes,
+        })
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), StorageError> {
+        let path = self.path(key)?;
+        match fs::remove_file(path).await {
+            Err(err) if err.kind() != std::io::ErrorKind::NotFound => Err(err.into()),
+            _ => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use std::env;
+
+use anyhow::Context;
+use async_trait::async_trait;
+use s3::{creds::Credentials, error::S3Error, Bucket, Region};
+
+use crate::error::StorageError;
+use crate::storage::{is_valid_key, Blob, BlobStore};
+
+/// Keeps blobs in a bucket of an S3 compatible service, such as MinIO in development.
+#[derive(Debug, Clone)]
+pub struct S3BlobStore {
+    bucket: Box<Bucket>,
+}
+
+impl S3BlobStore {
+    pub fn new(
+        endpoint: String,
+        region: String,
+        bucket: &str,
+        access_key: &str,
+        secret_key: &str,
+    ) -> anyhow::Result<Self> {
+        let credentials = Credentials::new(Some(access_key), Some(secret_key), None, None, None)?;
+        // Path style addressing works with MinIO and other self-hosted services.
+        let bucket = Bucket::new(bucket, Region::Custom { region, endpoint }, credentials)?
+            .with_path_style();
+        Ok(Self { bucket })
+    }
+
+    /// Reads `S3_ENDPOINT`, `S3_REGION`, `S3_BUCKET`, `S3_ACCESS_KEY` and `S3_SECRET_KEY`.
+    pub fn from_env() -> anyhow::Result<Self> {
+        let var = |name: &str| {
+            env::var(name).with_context(|| format!("{name} was not found in .env file."))
+        };
+        Self::new(
+            var("S3_ENDPOINT")?,
+            env::var("S3_REGION").unwrap_or("us-east-1".to_string()),
+            &var("S3_BUCKET")?,
+            &var("S3_ACCESS_KEY")?,
+            &var("S3_SECRET_KEY")?,
+        )
+    }
+}
+
+impl From<S3Error> for StorageError {
+    fn from(err: S3Error) -> Self {
+        match err {
+            S3Error::HttpFailWithBody(404, _) => StorageError::NotFound,
+            err => StorageError::BackendError(err.to_string()),
+        }
+    }
+}
+
+#[async_trait]
+impl BlobStore for S3BlobStore {
+    async fn put(&self, key: &str, blob: Blob) -> Result<(), StorageError> {
+        if !is_valid_key(key) {
+            return Err(StorageError::NotFound);
+        }
+        self.bucket
+            .put_object_with_content_type(key, &blob.bytes, &blob.content_type)
+            .await?;
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> Result<Blob, StorageError> {
+        if !is_valid_key(key) {
+            return Err(StorageError::NotFound);
+        }
+        let response = self.bucket.get_object(key).await?;
+        let content_type = response
+            .headers()
+            .get("content-type")
+            .cloned()
+            .unwrap_or("application/octet-stream".to_string());
+        Ok(Blob {
+            content_type,
+            bytes: response.bytes().to_vec(),
+        })
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), StorageError> {
+        if !is_valid_key(key) {
+            return Err(StorageError::NotFound);
+        }
+        match self.bucket.delete_object(key).await {
+            Err(S3Error::HttpFailWithBody(404, _)) | Ok(_) => Ok(()),
+            Err(err) => Err(err.into()),
+        }
+    }
+}
@@ -0,0 +1,171 @@
+use std::env;
+
+use image::{codecs::jpeg::JpegEncoder, imageops::FilterType, DynamicImage, ImageFormat};
+use uuid::Uuid;
+
+use crate::error::{StorageError, UploadError};
+use crate::storage::{public_url, thumbnail_key, Blob, BlobStore};
+
+const DEFAULT_MAX_UPLOAD_BYTES: usize = 5 * 1024 * 1024;
+const JPEG_QUALITY: u8 = 85;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UploadLimits {
+    pub max_bytes: usize,
+}
+
+impl Default for UploadLimits {
+    fn default() -> Self {
+        Self {
+            max_bytes: DEFAULT_MAX_UPLOAD_BYTES,
+        }
+    }
+}
+
+impl UploadLimits {
+    /// Reads `UPLOAD_MAX_BYTES`, keeping the default of 5 MB when it is missing.
+    pub fn from_env() -> Self {
+        Self {
+            max_bytes: env::var("UPLOAD_MAX_BYTES")
+                .ok()
+                .and_then(|value| value.parse::<usize>().ok())
+                .filter(|max_bytes| *max_bytes > 0)
+                .unwrap_or(DEFAULT_MAX_UPLOAD_BYTES),
+        }
+    }
+
+    /// Request body limit of upload forms, leaving room for the multipart framing.
+    pub fn body_limit(&self) -> usize {
+        self.max_bytes + 64 * 1024
+    }
+
+    pub fn max_megabytes(&self) -> usize {
+        self.max_bytes.div_ceil(1024 * 1024)
+    }
+}
+
+/// What the uploaded image is used for, decides where it is stored and how it is resized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageKind {
+    Avatar,
+    EventHero,
+}
+
+impl ImageKind {
+    fn prefix(&self) -> &'static str {
+        match self {
+            ImageKind::Avatar => "avatars",
+            ImageKind::EventHero => "events",
+        }
+    }
+
+    /// Longest side of the stored image, avatars are cropped to a square.
+    fn max_size(&self) -> u32 {
+        match self {
+            ImageKind::Avatar => 512,
+            ImageKind::EventHero => 1920,
+        }
+    }
+
+    fn thumbnail_size(&self) -> u32 {
+        match self {
+            ImageKind::Avatar => 128,
+            ImageKind::EventHero => 480,
+        }
+    }
+}
+
+/// Checks the size and type of an upload, the declared content type has to match the
+/// actual contents.
+pub fn validate_upload(
+    content_type: Option<&str>,
+    bytes: &[u8],
+    limits: &UploadLimits,
+) -> Result<ImageFormat, UploadError> {
+    if bytes.is_empty() {
+        return Err(UploadError::MissingFile);
+    }
+    if bytes.len() > limits.max_bytes {
+        return Err(UploadError::TooLarge(limits.max_megabytes()));
+    }
+    let format = match content_type {
+        Some("image/png") => ImageFormat::Png,
+        Some("image/jpeg") => ImageFormat::Jpeg,
+        Some("image/webp") => ImageFormat::WebP,
+        _ => return Err(UploadError::UnsupportedType),
+    };
+    match image::guess_format(bytes) {
+        Ok(guessed) if guessed == format => Ok(format),
+        _ => Err(UploadError::InvalidImage),
+    }
+}
+
+/// An upload resized for the page and for thumbnails, both encoded as JPEG.
+#[derive(Debug, Clone)]
+pub struct ProcessedImage {
+    pub image: Vec<u8>,
+    pub thumbnail: Vec<u8>,
+}
+
+/// Decodes the upload and resizes it, CPU heavy so it should run on a blocking thread.
+pub fn process_image(
+    bytes: &[u8],
+    format: ImageFormat,
+    kind: ImageKind,
+) -> Result<ProcessedImage, UploadError> {
+    let image = image::load_from_memory_with_format(bytes, format)
+        .map_err(|_| UploadError::InvalidImage)?;
+    let image = match kind {
+        ImageKind::Avatar => {
+            let side = kind.max_size().min(image.width()).min(image.height());
+            image.resize_to_fill(side, side, FilterType::Lanczos3)
+        }
+        ImageKind::EventHero if image.width().max(image.height()) > kind.max_size() => {
+            image.resize(kind.max_size(), kind.max_size(), FilterType::Lanczos3)
+        }
+        ImageKind::EventHero => image,
+    };
+    let thumbnail = image.thumbnail(kind.thumbnail_size(), kind.thumbnail_size());
+
+    Ok(ProcessedImage {
+        image: encode_jpeg(&image)?,
+        thumbnail: encode_jpeg(&thumbnail)?,
+    })
+}
+
+fn encode_jpeg(image: &DynamicImage) -> Result<Vec<u8>, UploadError> {
+    let mut bytes = Vec::new();
+    // JPEG has no alpha channel, the encoder only takes images without one.
+    DynamicImage::ImageRgb8(image.to_rgb8())
+        .write_with_encoder(JpegEncoder::new_with_quality(&mut bytes, JPEG_QUALITY))
+        .map_err(|_| UploadError::InvalidImage)?;
+    Ok(bytes)
+}
+
+/// Stores the image together with its thumbnail under a fresh key and returns its URL.
+pub async fn store_image(
+    store: &dyn BlobStore,
+    kind: ImageKind,
+    processed: ProcessedImage,
+) -> Result<String, StorageError> {
+    let key = format!("{}/{}.jpg", kind.prefix(), Uuid::new_v4());
+    store
+        .put(
+            &key,
+            Blob {
+                content_type: "image/jpeg".to_string(),
+                bytes: processed.image,
+            },
+        )
+        .await?;
+    let thumbnail = Blob {
+        content_type: "image/jpeg".to_string(),
+        bytes: processed.thumbnail,
+    };
+    if let Err(err) = store.put(&thumbnail_key(&key), thumbnail).await {
+        // Do not leave an image without its thumbnail behind.
+        store.delete(&key).await?;
+        return Err(err);
+    }
+    Ok(public_url(&key))
+}
@@ -4,24 +4,36 @@ use askama::Template;
 use sqlx::types::time::Date;
 use validator::{ValidationErrors, ValidationErrorsKind};
 
+use crate::models::attendance::{AttendanceOutcome, AttendanceSummary};
+use crate::models::audit_log::{AuditAction, AuditEntity};
 use crate::models::employment::Employment;
 use crate::models::job_position::{JobPosition, JobPositionViewModel};
+use crate::models::organization::{ActiveOrganization, OrganizationRole};
 use crate::models::position_category::PositionCategory;
+use crate::models::review::ReviewSummary;
 use crate::models::venue::Venue;
 use crate::models::worked_hours::WorkedHours;
-use crate::view_models::employments::EmploymentViewModel;
-use crate::view_models::event::{EventDetailViewModel, EventViewModel, ManageEventViewModel};
+use crate::view_models::audit_log::AuditLogViewModel;
+use crate::view_models::employments::{EmploymentAttendanceViewModel, EmploymentViewModel};
+use crate::view_models::event::{
+    EventDetailViewModel, EventManagerViewModel, EventViewModel, ManageEventViewModel,
+};
 use crate::view_models::jobs::{
     ManageJobEmployeeViewModel, ManageJobPositionViewModel, ManageJobPositionsViewModel,
-    PastJobsViewModel,
+    PastJobsViewModel, StaffSuggestionViewModel,
 };
-use crate::view_models::my_jobs::{JobSummary, MyJobsViewModel};
+use crate::view_models::my_jobs::{InvitationViewModel, JobSummary, MyJobsViewModel};
+use crate::view_models::notification::NotificationViewModel;
+use crate::view_models::organization::OrganizationViewModel;
+use crate::view_models::pagination::PaginationViewModel;
+use crate::view_models::review::{FlaggedReviewViewModel, ReviewViewModel};
+use crate::view_models::trash::TrashItemViewModel;
 use crate::view_models::user::UserViewModel;
 use crate::{
     handlers::app::auth::AuthSession,
     models::{
-        employment::EmploymentState,
-        event::Event,
+        employment::{EmploymentState, WithdrawalStats},
+        event::{Event, EventStatus},
         user::{Gender, User, UserRole},
     },
 };
@@ -34,6 +46,7 @@ pub enum ActiveRoute {
     Manage,
     AdminPanel,
     Employments,
+    Notifications,
 }
 
 pub enum ToastType {
@@ -75,6 +88,7 @@ pub struct EventsTemplate {
     pub past_events: Vec<EventViewModel>,
     pub states: Vec<String>,
     pub cities: Vec<String>,
+    pub event_statuses: Vec<EventStatus>,
 }
 
 #[derive(Template)]
@@ -92,6 +106,9 @@ pub struct ManageEventsTemplate {
     pub session: AuthSession,
     pub active_route: Option<ActiveRoute>,
     pub events: Vec<EventViewModel>,
+    pub event_statuses: Vec<EventStatus>,
+    pub selected_status: Option<EventStatus>,
+    pub pagination: PaginationViewModel,
 }
 
 #[derive(Template)]
@@ -100,6 +117,7 @@ pub struct ManageJobsTemplate {
     pub session: AuthSession,
     pub active_route: Option<ActiveRoute>,
     pub jobs: Vec<ManageJobPositionsViewModel>,
+    pub pagination: PaginationViewModel,
 }
 
 #[derive(Template)]
@@ -129,6 +147,7 @@ pub struct ManageVenuesTemplate {
     pub session: AuthSession,
     pub active_route: Option<ActiveRoute>,
     pub venues: Vec<Venue>,
+    pub pagination: PaginationViewModel,
 }
 
 #[derive(Template)]
@@ -153,6 +172,10 @@ pub struct EventTemplate {
     pub active_route: Option<ActiveRoute>,
     pub event: EventDetailViewModel,
     pub is_in_past: bool,
+    pub review_summary: ReviewSummary,
+    /// Reviews of the event owner across all of their events.
+    pub organizer_review_summary: ReviewSummary,
+    pub reviews: Vec<ReviewViewModel>,
 }
 
 #[derive(Template)]
@@ -171,14 +194,16 @@ pub struct ManageEventTemplate {
     pub event: ManageEventViewModel,
     pub venues: Vec<Venue>,
     pub possible_managers: Vec<User>,
-    pub managers: Vec<User>,
+    pub managers: Vec<EventManagerViewModel>,
+    pub can_manage_managers: bool,
+    pub audit_logs: Vec<AuditLogViewModel>,
 }
 
 #[derive(Template)]
 #[template(path = "partials/manage_event_managers.html")]
 pub struct EventManagersTemplate {
     pub possible_managers: Vec<User>,
-    pub managers: Vec<User>,
+    pub managers: Vec<EventManagerViewModel>,
     pub event_id: i32,
 }
 
@@ -191,6 +216,13 @@ pub struct JobEmployeesTemplate {
     pub job_id: i32,
 }
 
+#[derive(Template)]
+#[template(path = "partials/job_suggestions.html")]
+pub struct JobSuggestionsTemplate {
+    pub suggestions: Vec<StaffSuggestionViewModel>,
+    pub job_id: i32,
+}
+
 #[derive(Template)]
 #[template(path = "views/base/main/create_event.html")]
 pub struct CreateEventTemplate {
@@ -205,6 +237,7 @@ pub struct EmployeesTemplate {
     pub session: AuthSession,
     pub active_route: Option<ActiveRoute>,
     pub employees: Vec<UserViewModel>,
+    pub pagination: PaginationViewModel,
 }
 
 #[derive(Template)]
@@ -214,6 +247,14 @@ pub struct EmployeeTemplate {
     pub active_route: Option<ActiveRoute>,
     pub employee: UserViewModel,
     pub past_jobs: Vec<PastJobsViewModel>,
+    pub pagination: PaginationViewModel,
+    pub withdrawals: WithdrawalStats,
+    pub attendance: AttendanceSummary,
+    pub reliability_score: Option<i32>,
+    /// Set when too many recent no-shows keep the employee from applying for jobs.
+    pub blocked_no_shows: Option<i64>,
+    pub review_summary: ReviewSummary,
+    pub reviews: Vec<ReviewViewModel>,
 }
 
 #[derive(Template)]
@@ -233,6 +274,26 @@ pub struct AttendanceTemplate {
     // pub date_range: Vec<Date>,
 }
 
+#[derive(Template)]
+#[template(path = "views/base/main/notifications.html")]
+pub struct NotificationsTemplate {
+    pub session: AuthSession,
+    pub active_route: Option<ActiveRoute>,
+    pub notifications: Vec<NotificationViewModel>,
+}
+
+#[derive(Template)]
+#[template(path = "views/base/main/organizations.html")]
+pub struct OrganizationsTemplate {
+    pub session: AuthSession,
+    pub active_route: Option<ActiveRoute>,
+    pub active_organization: Option<ActiveOrganization>,
+    pub organizations: Vec<OrganizationViewModel>,
+    pub organization_roles: Vec<OrganizationRole>,
+    pub is_admin: bool,
+    pub can_manage_members: bool,
+}
+
 #[derive(Template)]
 #[template(path = "htmx/base/main/attendance/attendance_log.html")]
 pub struct AttendanceLogTemplate {
@@ -264,6 +325,14 @@ pub struct PageNotFoundTemplate {}
 #[template(path = "views/base/401.html")]
 pub struct UnauthorizedTemplate {}
 
+#[derive(Template)]
+#[template(path = "views/base/error.html")]
+pub struct ErrorTemplate {
+    pub status: u16,
+    pub message: String,
+    pub correlation_id: String,
+}
+
 #[derive(Template)]
 #[template(path = "views/base/main/settings/details.html")]
 pub struct SettingsDetailsTemplate {
@@ -284,8 +353,10 @@ pub struct JobsTemplate {
     pub session: AuthSession,
     pub active_route: Option<ActiveRoute>,
     pub my_jobs: Vec<MyJobsViewModel>,
+    pub invitations: Vec<InvitationViewModel>,
     pub employment_states: Vec<EmploymentState>,
     pub job_summary: JobSummary,
+    pub pagination: PaginationViewModel,
 }
 
 #[derive(Template)]
@@ -293,6 +364,7 @@ pub struct JobsTemplate {
 pub struct JobsTableTemplate {
     pub my_jobs: Vec<MyJobsViewModel>,
     pub job_summary: JobSummary,
+    pub pagination: PaginationViewModel,
 }
 
 #[derive(Template)]
@@ -316,17 +388,27 @@ pub struct EmploymentsTemplate {
     pub active_route: Option<ActiveRoute>,
     pub employments: Vec<EmploymentViewModel>,
     pub employment_states: Vec<EmploymentState>,
+    pub pagination: PaginationViewModel,
 }
 
 #[derive(Template)]
 #[template(path = "views/base/main/employments_table_body.html")]
 pub struct EmploymentsTableTemplate {
     pub employments: Vec<EmploymentViewModel>,
+    pub pagination: PaginationViewModel,
+}
+
+#[derive(Template)]
+#[template(path = "partials/employment_attendance.html")]
+pub struct EmploymentAttendanceTemplate {
+    pub attendance: EmploymentAttendanceViewModel,
 }
 
 #[derive(Template)]
 #[template(path = "htmx/base/auth/register_success.html")]
-pub struct RegisterSuccessTemplate {}
+pub struct RegisterSuccessTemplate {
+    pub organization_name: Option<String>,
+}
 
 #[derive(Template)]
 #[template(path = "partials/form_errors.html")]
@@ -340,6 +422,7 @@ pub struct AdminUsersTemplate {
     pub session: AuthSession,
     pub active_route: Option<ActiveRoute>,
     pub users: Vec<UserViewModel>,
+    pub pagination: PaginationViewModel,
 }
 
 #[derive(Template)]
@@ -361,4 +444,43 @@ pub struct AdminUserCreateTemplate {
 #[template(path = "views/base/main/admin_users_table.html")]
 pub struct AdminUsersTableTemplate {
     pub users: Vec<UserViewModel>,
+    pub pagination: PaginationViewModel,
+}
+
+#[derive(Template)]
+#[template(path = "views/base/main/admin_audit.html")]
+pub struct AdminAuditTemplate {
+    pub session: AuthSession,
+    pub active_route: Option<ActiveRoute>,
+    pub audit_logs: Vec<AuditLogViewModel>,
+    pub entities: Vec<AuditEntity>,
+    pub actions: Vec<AuditAction>,
+    pub pagination: PaginationViewModel,
+}
+
+#[derive(Template)]
+#[template(path = "views/base/main/admin_audit_table_body.html")]
+pub struct AdminAuditTableTemplate {
+    pub audit_logs: Vec<AuditLogViewModel>,
+    pub pagination: PaginationViewModel,
+}
+
+#[derive(Template)]
+#[template(path = "views/base/main/admin_reviews.html")]
+pub struct AdminReviewsTemplate {
+    pub session: AuthSession,
+    pub active_route: Option<ActiveRoute>,
+    pub reviews: Vec<FlaggedReviewViewModel>,
+    pub pagination: PaginationViewModel,
+}
+
+#[derive(Template)]
+#[template(path = "views/base/main/admin_trash.html")]
+pub struct AdminTrashTemplate {
+    pub session: AuthSession,
+    pub active_route: Option<ActiveRoute>,
+    /// Kind of records shown, as in the restore and purge paths.
+    pub entity_path: String,
+    pub items: Vec<TrashItemViewModel>,
+    pub pagination: PaginationViewModel,
 }
@@ -1,11 +1,11 @@
 use chrono::{DateTime, Datelike, Local, NaiveDate};
-use sqlx::types::time::Date;
+use sqlx::types::time::{Date, OffsetDateTime};
 use tower_sessions::cookie::time::Month;
 
 /// Parses a date string in format "YYYY-MM-DD" into a Date type.
 ///
 /// # Examples
-/// ```rust
+/// ```ignore
 /// let result = parse_date("2024-02-04");
 /// assert!(result.is_ok());
 ///
@@ -31,7 +31,7 @@ pub fn parse_date(date_str: &str) -> Result<Date, Box<dyn std::error::Error>> {
 /// Converts DateTime<Local> to Date type.
 ///
 /// # Examples
-/// ```rust
+/// ```ignore
 /// let dt = Local.with_ymd_and_hms(2024, 2, 4, 15, 30, 0).unwrap();
 /// let date = convert_date_time_to_date(dt);
 ///
@@ -66,7 +66,7 @@ pub fn convert_date_time_to_date(dt: DateTime<Local>) -> Date {
 /// Creates a vector of consecutive dates between start and end dates (inclusive).
 ///
 /// # Examples
-/// ```rust
+/// ```ignore
 /// let start = Date::from_calendar_date(2024, Month::February, 1).unwrap();
 /// let end = Date::from_calendar_date(2024, Month::February, 3).unwrap();
 ///
@@ -99,3 +99,49 @@ pub fn is_date_in_past(dt: Date) -> bool {
     let current_date = convert_date_time_to_date(current_date_time);
     dt < current_date
 }
+
+/// Formats a timestamp as "YYYY-MM-DD HH:MM" for display in tables.
+pub fn format_timestamp(timestamp: OffsetDateTime) -> String {
+    format!(
+        "{} {:02}:{:02}",
+        timestamp.date(),
+        timestamp.hour(),
+        timestamp.minute()
+    )
+}
+
+/// Parses a `datetime-local` input value in format "YYYY-MM-DDTHH:MM" as a UTC timestamp.
+pub fn parse_date_time(date_time_str: &str) -> Result<OffsetDateTime, Box<dyn std::error::Error>> {
+    let (date_str, time_str) = date_time_str
+        .split_once('T')
+        .ok_or("Invalid date time format")?;
+    let date = parse_date(date_str)?;
+
+    let parts: Vec<&str> = time_str.split(':').collect();
+    if parts.len() < 2 {
+        return Err("Invalid time format".into());
+    }
+    let hour: u8 = parts[0].parse()?;
+    let minute: u8 = parts[1].parse()?;
+
+    Ok(date.with_hms(hour, minute, 0)?.assume_utc())
+}
+
+/// Parses an optional `datetime-local` input value, treating an empty value as not set.
+pub fn parse_optional_date_time(
+    date_time_str: &str,
+) -> Result<Option<OffsetDateTime>, Box<dyn std::error::Error>> {
+    if date_time_str.is_empty() {
+        return Ok(None);
+    }
+    parse_date_time(date_time_str).map(Some)
+}
+
+pub fn format_date_time_input(timestamp: OffsetDateTime) -> String {
+    format!(
+        "{}T{:02}:{:02}",
+        timestamp.date(),
+        timestamp.hour(),
+        timestamp.minute()
+    )
+}
@@ -1,4 +1,5 @@
-use crate::templates::{FormErrorsTemplate, ToastTemplate, ToastType, UnauthorizedTemplate};
+use crate::error::ApiError;
+use crate::templates::{FormErrorsTemplate, ToastTemplate, ToastType};
 use askama::Template;
 use askama_axum::IntoResponse;
 use axum::response::Response;
@@ -47,14 +48,9 @@ pub fn generate_htmx_redirect(to: &str) -> axum::http::Response<axum::body::Body
         .into_response()
 }
 
+/// Rendered as the 401 page or an error toast by the error pages layer.
 pub fn generate_unauthorized_response() -> axum::http::Response<axum::body::Body> {
-    let template = UnauthorizedTemplate {};
-    let html = template.render().unwrap();
-    Response::builder()
-        .status(401)
-        .body(html)
-        .unwrap()
-        .into_response()
+    ApiError::Unauthorized.into_response()
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
@@ -1,6 +1,5 @@
 use crate::error::ApiError;
 use crate::utils::date_utils::parse_date;
-use serde::{Deserialize, Serialize};
 use sqlx::types::time::Date;
 
 pub fn optional_filter(value: String) -> Option<String> {
@@ -33,17 +32,3 @@ where
         parse_fn(value).map(Some)
     }
 }
-
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
-pub enum SortDirection {
-    Asc,
-    Desc,
-}
-
-#[allow(warnings)]
-impl Default for SortDirection {
-    fn default() -> Self {
-        SortDirection::Desc
-    }
-}
@@ -1,6 +1,12 @@
+pub mod audit_log;
 pub mod employments;
 pub mod event;
 pub mod jobs;
 pub mod my_jobs;
+pub mod notification;
+pub mod organization;
+pub mod pagination;
+pub mod review;
+pub mod trash;
 pub mod user;
 pub mod venue;
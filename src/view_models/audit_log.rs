@@ -0,0 +1,68 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::models::audit_log::{AuditAction, AuditEntity, AuditLog};
+use crate::utils::date_utils::format_timestamp;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditChangeViewModel {
+    pub field: String,
+    pub before: String,
+    pub after: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditLogViewModel {
+    pub id: i32,
+    pub actor_name: String,
+    pub entity: AuditEntity,
+    pub entity_id: i32,
+    pub action: AuditAction,
+    pub created_at: String,
+    pub changes: Vec<AuditChangeViewModel>,
+}
+
+impl AuditLogViewModel {
+    pub fn new(log: AuditLog, actor_name: String) -> Self {
+        Self {
+            id: log.id,
+            actor_name,
+            entity: log.entity,
+            entity_id: log.entity_id,
+            action: log.action,
+            created_at: format_timestamp(log.created_at),
+            changes: diff_snapshots(log.before.as_ref(), log.after.as_ref()),
+        }
+    }
+}
+
+fn format_value(value: Option<&Value>) -> String {
+    match value {
+        None | Some(Value::Null) => "-".to_string(),
+        Some(Value::String(value)) => value.clone(),
+        Some(value) => value.to_string(),
+    }
+}
+
+/// Lists the fields whose values differ between the two snapshots.
+/// For created or deleted records every field of the existing snapshot is listed.
+fn diff_snapshots(before: Option<&Value>, after: Option<&Value>) -> Vec<AuditChangeViewModel> {
+    let empty = serde_json::Map::new();
+    let before_fields = before.and_then(Value::as_object).unwrap_or(&empty);
+    let after_fields = after.and_then(Value::as_object).unwrap_or(&empty);
+
+    let mut fields: Vec<&String> = before_fields.keys().chain(after_fields.keys()).collect();
+    fields.sort();
+    fields.dedup();
+
+    fields
+        .into_iter()
+        .filter(|field| field.as_str() != "id")
+        .filter(|field| before_fields.get(*field) != after_fields.get(*field))
+        .map(|field| AuditChangeViewModel {
+            field: field.clone(),
+            before: format_value(before_fields.get(field)),
+            after: format_value(after_fields.get(field)),
+        })
+        .collect()
+}
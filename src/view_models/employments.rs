@@ -1,5 +1,8 @@
 use serde::{Deserialize, Serialize};
-use crate::models::employment::EmploymentState;
+use sqlx::types::time::Date;
+use crate::models::attendance::{AttendanceOutcome, AttendanceRecord};
+use crate::models::employment::{EmploymentState, EmploymentStateChange};
+use crate::utils::date_utils::format_timestamp;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EmploymentViewModel {
@@ -13,4 +16,55 @@ pub struct EmploymentViewModel {
     pub max_capacity: i32,
     pub current_capacity: i32,
     pub rating: i32,
-}
\ No newline at end of file
+    pub history: Vec<EmploymentStateChangeViewModel>,
+    pub attendance: EmploymentAttendanceViewModel,
+    /// The organizer can still write a review of the worker.
+    pub can_review: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmploymentStateChangeViewModel {
+    pub from_state: Option<EmploymentState>,
+    pub to_state: EmploymentState,
+    pub changed_by: String,
+    pub changed_at: String,
+}
+
+impl From<EmploymentStateChange> for EmploymentStateChangeViewModel {
+    fn from(change: EmploymentStateChange) -> Self {
+        Self {
+            from_state: change.from_state,
+            to_state: change.to_state,
+            changed_by: change
+                .changed_by_name
+                .unwrap_or_else(|| "System".to_string()),
+            changed_at: format_timestamp(change.changed_at),
+        }
+    }
+}
+
+/// Attendance outcomes of one employment, recorded per event day.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmploymentAttendanceViewModel {
+    pub employment_id: i32,
+    pub date_start: Date,
+    pub date_end: Date,
+    pub records: Vec<AttendanceRecordViewModel>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AttendanceRecordViewModel {
+    pub date: Date,
+    pub outcome: AttendanceOutcome,
+    pub note: Option<String>,
+}
+
+impl From<AttendanceRecord> for AttendanceRecordViewModel {
+    fn from(record: AttendanceRecord) -> Self {
+        Self {
+            date: record.date,
+            outcome: record.outcome,
+            note: record.note,
+        }
+    }
+}
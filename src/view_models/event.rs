@@ -1,7 +1,15 @@
 use serde::{Deserialize, Serialize};
 use sqlx::types::time::Date;
 
-use crate::models::{event::Event, job_position::JobPositionViewModel, user::User, venue::Venue};
+use crate::models::{
+    event::{Event, EventStatus},
+    event_manager_relation::EventManagerPermissions,
+    job_position::JobPositionViewModel,
+    read_model::EventListing,
+    user::User,
+    venue::Venue,
+};
+use crate::storage::thumbnail_url;
 
 pub trait IEventViewModel {
     fn new(event: Event, venue: Venue, owner: User) -> Self;
@@ -14,11 +22,19 @@ pub struct EventViewModel {
     pub date_start: Date,
     pub date_end: Date,
     pub img_url: String,
-    pub is_draft: bool,
+    pub status: EventStatus,
+    pub cancellation_reason: Option<String>,
     pub venue: Venue,
     pub owner: User,
 }
 
+impl EventViewModel {
+    /// Smaller version of the hero image for event cards.
+    pub fn thumbnail_url(&self) -> String {
+        thumbnail_url(&self.img_url)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ManageEventViewModel {
     pub id: i32,
@@ -26,12 +42,77 @@ pub struct ManageEventViewModel {
     pub date_start: Date,
     pub date_end: Date,
     pub img_url: String,
-    pub is_draft: bool,
+    pub status: EventStatus,
+    pub cancellation_reason: Option<String>,
+    pub publish_at: Option<String>,
+    pub withdrawal_cutoff_hours: i32,
     pub venue: Venue,
     pub owner: User,
     pub description: Option<String>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventManagerViewModel {
+    pub id: i32,
+    pub first_name: String,
+    pub last_name: String,
+    pub username: String,
+    pub avatar_url: Option<String>,
+    pub permissions: EventManagerPermissions,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventManagerPermissionViewModel {
+    pub name: String,
+    pub label: String,
+    pub enabled: bool,
+}
+
+impl EventManagerViewModel {
+    pub fn permission_list(&self) -> Vec<EventManagerPermissionViewModel> {
+        let permissions = [
+            (
+                "edit_event",
+                "Edit event",
+                self.permissions.can_edit_event,
+            ),
+            (
+                "manage_jobs",
+                "Manage jobs",
+                self.permissions.can_manage_jobs,
+            ),
+            (
+                "manage_applicants",
+                "Accept applicants",
+                self.permissions.can_manage_applicants,
+            ),
+            (
+                "approve_hours",
+                "Approve hours",
+                self.permissions.can_approve_hours,
+            ),
+            (
+                "view_payroll",
+                "View payroll",
+                self.permissions.can_view_payroll,
+            ),
+            (
+                "manage_managers",
+                "Manage managers",
+                self.permissions.can_manage_managers,
+            ),
+        ];
+        permissions
+            .into_iter()
+            .map(|(name, label, enabled)| EventManagerPermissionViewModel {
+                name: name.to_string(),
+                label: label.to_string(),
+                enabled,
+            })
+            .collect()
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EventDetailViewModel {
     pub id: i32,
@@ -40,7 +121,8 @@ pub struct EventDetailViewModel {
     pub date_end: Date,
     pub img_url: String,
     pub description: Option<String>,
-    pub is_draft: bool,
+    pub status: EventStatus,
+    pub cancellation_reason: Option<String>,
     pub venue: Venue,
     pub owner: User,
     pub job_positions: Vec<JobPositionViewModel>,
@@ -54,9 +136,16 @@ impl IEventViewModel for EventViewModel {
             date_start: event.date_start,
             date_end: event.date_end,
             img_url: event.img_url,
-            is_draft: event.is_draft,
+            status: event.status,
+            cancellation_reason: event.cancellation_reason,
             venue,
             owner,
         }
     }
 }
+
+impl From<EventListing> for EventViewModel {
+    fn from(listing: EventListing) -> Self {
+        Self::new(listing.event, listing.venue, listing.owner)
+    }
+}
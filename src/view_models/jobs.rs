@@ -6,6 +6,8 @@ use crate::models::{
     event::Event,
     job_position::SalaryCurrency,
     position_category::PositionCategory,
+    read_model::JobPositionListing,
+    staffing::ScoreBreakdown,
     user::{Gender, UserRole},
     venue::Venue,
 };
@@ -23,7 +25,7 @@ pub struct PastJobsViewModel {
     pub date_start: Date,
     pub date_end: Date,
     pub venue: Venue,
-    pub hours_worked: i32,
+    pub hours_worked: Option<i32>,
     pub rating: i32,
 }
 
@@ -40,6 +42,22 @@ pub struct ManageJobPositionsViewModel {
     pub category: PositionCategory,
 }
 
+impl From<JobPositionListing> for ManageJobPositionsViewModel {
+    fn from(listing: JobPositionListing) -> Self {
+        Self {
+            id: listing.position.id,
+            name: listing.position.name,
+            salary: listing.position.salary,
+            current_capacity: listing.current_capacity as usize,
+            max_capacity: listing.position.capacity,
+            is_opened_for_registration: listing.position.is_opened_for_registration,
+            currency: listing.position.currency,
+            event: listing.event,
+            category: listing.category,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ManageJobPositionViewModel {
     pub id: i32,
@@ -47,6 +65,8 @@ pub struct ManageJobPositionViewModel {
     pub salary: f32,
     pub capacity: i32,
     pub is_opened_for_registration: bool,
+    pub registration_opens_at: Option<String>,
+    pub registration_closes_at: Option<String>,
     pub currency: SalaryCurrency,
     pub instructions: String,
     pub description: Option<String>,
@@ -70,3 +90,16 @@ pub struct ManageJobEmployeeViewModel {
     pub avatar_url: Option<String>,
     pub employment: Employment,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StaffSuggestionViewModel {
+    pub user_id: i32,
+    pub first_name: String,
+    pub last_name: String,
+    pub username: String,
+    pub average_rating: Option<f32>,
+    pub done_in_category: i64,
+    pub conflicts: i64,
+    pub no_shows: i64,
+    pub score: ScoreBreakdown,
+}
@@ -4,6 +4,7 @@ use sqlx::types::time::Date;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MyJobsViewModel {
+    pub employment_id: i32,
     pub state: EmploymentState,
     pub job_name: String,
     pub job_id: i32,
@@ -18,12 +19,32 @@ pub struct MyJobsViewModel {
     pub salary_taxed: String,
     pub job_instructions: String,
     pub rating: i32,
+    pub can_withdraw: bool,
+    pub withdrawal_requires_reason: bool,
+    pub withdrawal_cutoff_hours: i32,
+    /// The employee can still review the event and its organizer.
+    pub can_review: bool,
+}
+
+/// An invitation from an organizer the employee still has to accept or decline.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InvitationViewModel {
+    pub employment_id: i32,
+    pub job_name: String,
+    pub job_id: i32,
+    pub event_name: String,
+    pub event_id: i32,
+    pub date_from: Date,
+    pub date_to: Date,
+    pub salary: f32,
+    pub currency: String,
+    pub expires_at: String,
 }
 
 pub struct JobSummary {
-    pub total_jobs: usize,
-    pub unique_events: usize,
-    pub unique_venues: usize,
+    pub total_jobs: i64,
+    pub unique_events: i64,
+    pub unique_venues: i64,
     pub total_hours_worked: String,
     pub total_salary_tax_free: String,
     pub total_salary_taxed: String,
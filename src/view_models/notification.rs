@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::notification::Notification;
+use crate::utils::date_utils::format_timestamp;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationViewModel {
+    pub id: i32,
+    pub message: String,
+    pub created_at: String,
+    pub is_unread: bool,
+}
+
+impl From<Notification> for NotificationViewModel {
+    fn from(notification: Notification) -> Self {
+        Self {
+            id: notification.id,
+            message: notification.message,
+            created_at: format_timestamp(notification.created_at),
+            is_unread: notification.read_at.is_none(),
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::organization::{Organization, OrganizationRole};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrganizationViewModel {
+    pub id: i32,
+    pub name: String,
+    pub role: OrganizationRole,
+    pub is_active: bool,
+    // Only shown to owners, who hand it out to new employees.
+    pub invite_code: Option<String>,
+}
+
+impl OrganizationViewModel {
+    pub fn new(organization: Organization, role: OrganizationRole, active_id: Option<i32>) -> Self {
+        Self {
+            id: organization.id,
+            name: organization.name,
+            is_active: Some(organization.id) == active_id,
+            invite_code: (role == OrganizationRole::Owner).then_some(organization.invite_code),
+            role,
+        }
+    }
+}
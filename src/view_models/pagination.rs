@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::pagination::Page;
+
+/// Controls below a paginated table.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaginationViewModel {
+    pub page: i64,
+    pub total_pages: i64,
+    pub total: i64,
+    /// Path the page links lead to, with the current filters and sorting. Without it the pages
+    /// are loaded through the filter navbar of the view, which keeps its filters.
+    pub link_path: Option<String>,
+}
+
+impl PaginationViewModel {
+    pub fn new<T>(page: &Page<T>, link_path: Option<String>) -> Self {
+        Self {
+            page: page.page().unwrap_or(1),
+            total_pages: page.total_pages(),
+            total: page.total,
+            link_path,
+        }
+    }
+
+    pub fn has_previous(&self) -> bool {
+        self.page > 1
+    }
+
+    pub fn has_next(&self) -> bool {
+        self.page < self.total_pages
+    }
+
+    pub fn previous_page(&self) -> i64 {
+        self.page - 1
+    }
+
+    pub fn next_page(&self) -> i64 {
+        self.page + 1
+    }
+
+    pub fn previous_link(&self) -> String {
+        self.link(self.previous_page())
+    }
+
+    pub fn next_link(&self) -> String {
+        self.link(self.next_page())
+    }
+
+    fn link(&self, page: i64) -> String {
+        let path = self.link_path.as_deref().unwrap_or_default();
+        let separator = if path.contains('?') { '&' } else { '?' };
+        format!("{path}{separator}page={page}")
+    }
+}
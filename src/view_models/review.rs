@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::review::{FlaggedReview, PublishedReview};
+use crate::utils::date_utils::format_timestamp;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewViewModel {
+    pub id: i32,
+    pub author_name: String,
+    pub event_id: i32,
+    pub event_name: String,
+    pub overall: i32,
+    /// Category scores that were given, e.g. ("Punctuality", 4).
+    pub scores: Vec<(String, i32)>,
+    pub comment: String,
+    pub created_at: String,
+}
+
+impl From<PublishedReview> for ReviewViewModel {
+    fn from(review: PublishedReview) -> Self {
+        let scores = [
+            ("Punctuality", review.punctuality),
+            ("Skill", review.skill),
+            ("Organization", review.organization),
+            ("Communication", review.communication),
+        ]
+        .into_iter()
+        .filter_map(|(name, score)| score.map(|score| (name.to_string(), score)))
+        .collect();
+        Self {
+            id: review.id,
+            author_name: review
+                .author_name
+                .unwrap_or_else(|| "Deleted user".to_string()),
+            event_id: review.event_id,
+            event_name: review.event_name,
+            overall: review.overall,
+            scores,
+            comment: review.comment,
+            created_at: format_timestamp(review.created_at),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FlaggedReviewViewModel {
+    pub id: i32,
+    pub author_name: String,
+    pub subject_name: String,
+    pub event_name: String,
+    pub overall: i32,
+    pub comment: String,
+    pub flag_reason: String,
+    pub flagged_by: String,
+    pub flagged_at: String,
+}
+
+impl From<FlaggedReview> for FlaggedReviewViewModel {
+    fn from(review: FlaggedReview) -> Self {
+        Self {
+            id: review.id,
+            author_name: review
+                .author_name
+                .unwrap_or_else(|| "Deleted user".to_string()),
+            subject_name: review.subject_name,
+            event_name: review.event_name,
+            overall: review.overall,
+            comment: review.comment,
+            flag_reason: review.flag_reason.unwrap_or_default(),
+            flagged_by: review
+                .flagged_by_name
+                .unwrap_or_else(|| "Deleted user".to_string()),
+            flagged_at: format_timestamp(review.flagged_at),
+        }
+    }
+}
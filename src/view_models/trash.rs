@@ -0,0 +1,64 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::audit_log::AuditEntity;
+use crate::models::event::Event;
+use crate::models::job_position::JobPosition;
+use crate::models::user::User;
+use crate::models::venue::Venue;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrashItemViewModel {
+    pub entity: AuditEntity,
+    pub entity_path: String,
+    pub id: i32,
+    pub name: String,
+    pub detail: String,
+}
+
+impl From<Event> for TrashItemViewModel {
+    fn from(event: Event) -> Self {
+        Self {
+            entity: AuditEntity::Event,
+            entity_path: "events".to_string(),
+            id: event.id,
+            name: event.name,
+            detail: format!("{} - {}", event.date_start, event.date_end),
+        }
+    }
+}
+
+impl From<JobPosition> for TrashItemViewModel {
+    fn from(job: JobPosition) -> Self {
+        Self {
+            entity: AuditEntity::JobPosition,
+            entity_path: "jobs".to_string(),
+            id: job.id,
+            name: job.name,
+            detail: format!("Event #{}", job.event_id),
+        }
+    }
+}
+
+impl From<User> for TrashItemViewModel {
+    fn from(user: User) -> Self {
+        Self {
+            entity: AuditEntity::User,
+            entity_path: "users".to_string(),
+            id: user.id,
+            name: user.first_name + " " + user.last_name.as_str(),
+            detail: user.username,
+        }
+    }
+}
+
+impl From<Venue> for TrashItemViewModel {
+    fn from(venue: Venue) -> Self {
+        Self {
+            entity: AuditEntity::Venue,
+            entity_path: "venues".to_string(),
+            id: venue.id,
+            name: venue.name,
+            detail: venue.town,
+        }
+    }
+}
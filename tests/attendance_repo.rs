@@ -0,0 +1,175 @@
+#[macro_use]
+mod common;
+
+#[cfg(test)]
+pub mod attendance_repo_tests {
+    use anyhow::Result;
+    use pv281_giglog::error::RepositoryError;
+    use pv281_giglog::models::attendance::{
+        AttendanceOutcome, AttendanceSummary, NoShowBlock, RecordAttendance,
+    };
+    use pv281_giglog::models::employment::WithdrawalStats;
+    use pv281_giglog::repositories::attendance::AttendanceRepository;
+    use sqlx::types::time::Date;
+    use time::Month;
+
+    database_test! {
+        #[fixtures("employment")]
+        async fn test_record_attendance(database: Database) -> Result<()> {
+            let repository = database.repositories().attendance.with_actor(1);
+            let first_day = Date::from_calendar_date(2026, Month::January, 1)?;
+            let second_day = Date::from_calendar_date(2026, Month::January, 2)?;
+
+            let record = repository
+                .record_attendance(RecordAttendance {
+                    employment_id: 3,
+                    date: first_day,
+                    outcome: AttendanceOutcome::Late,
+                    note: Some("  Missed the bus  ".to_string()),
+                })
+                .await?;
+            assert_eq!(record.outcome, AttendanceOutcome::Late);
+            assert_eq!(record.note, Some("Missed the bus".to_string()));
+            assert_eq!(record.recorded_by, Some(1));
+
+            // Recording the same day again replaces the outcome.
+            repository
+                .record_attendance(RecordAttendance {
+                    employment_id: 3,
+                    date: first_day,
+                    outcome: AttendanceOutcome::Attended,
+                    note: None,
+                })
+                .await?;
+            repository
+                .record_attendance(RecordAttendance {
+                    employment_id: 3,
+                    date: second_day,
+                    outcome: AttendanceOutcome::NoShow,
+                    note: Some("".to_string()),
+                })
+                .await?;
+
+            let records = repository.list_attendance(&[3]).await?;
+            assert_eq!(records.len(), 2);
+            assert_eq!(records[0].date, first_day);
+            assert_eq!(records[0].outcome, AttendanceOutcome::Attended);
+            assert_eq!(records[1].outcome, AttendanceOutcome::NoShow);
+            assert_eq!(records[1].note, None);
+
+            let pending = repository
+                .record_attendance(RecordAttendance {
+                    employment_id: 1,
+                    date: first_day,
+                    outcome: AttendanceOutcome::Attended,
+                    note: None,
+                })
+                .await;
+            assert!(
+                matches!(pending, Err(RepositoryError::GenericError(ref msg)) if msg.contains("accepted or finished"))
+            );
+
+            let outside = repository
+                .record_attendance(RecordAttendance {
+                    employment_id: 3,
+                    date: Date::from_calendar_date(2026, Month::January, 3)?,
+                    outcome: AttendanceOutcome::Attended,
+                    note: None,
+                })
+                .await;
+            assert!(
+                matches!(outside, Err(RepositoryError::GenericError(ref msg)) if msg.contains("outside of the event"))
+            );
+
+            let missing = repository
+                .record_attendance(RecordAttendance {
+                    employment_id: 999,
+                    date: first_day,
+                    outcome: AttendanceOutcome::Attended,
+                    note: None,
+                })
+                .await;
+            assert!(matches!(missing, Err(RepositoryError::NotFound)));
+
+            database.close().await;
+            Ok(())
+        }
+    }
+
+    database_test! {
+        #[fixtures("employment")]
+        async fn test_attendance_summary(database: Database) -> Result<()> {
+            let repository = database.repositories().attendance;
+            let first_day = Date::from_calendar_date(2026, Month::January, 1)?;
+            let second_day = Date::from_calendar_date(2026, Month::January, 2)?;
+
+            for (date, outcome) in [
+                (first_day, AttendanceOutcome::NoShow),
+                (second_day, AttendanceOutcome::LeftEarly),
+            ] {
+                repository
+                    .record_attendance(RecordAttendance {
+                        employment_id: 3,
+                        date,
+                        outcome,
+                        note: None,
+                    })
+                    .await?;
+            }
+
+            let summary = repository.attendance_summary(3, Some(1)).await?;
+            assert_eq!(summary.attended, 0);
+            assert_eq!(summary.no_shows, 1);
+            assert_eq!(summary.left_early, 1);
+
+            let other_organization = repository.attendance_summary(3, Some(999)).await?;
+            assert_eq!(other_organization.no_shows, 0);
+
+            assert_eq!(
+                repository.count_no_shows_since(3, None, first_day).await?,
+                1
+            );
+            assert_eq!(
+                repository.count_no_shows_since(3, None, second_day).await?,
+                0
+            );
+
+            database.close().await;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_reliability_score() {
+        let no_history = AttendanceSummary::default();
+        assert_eq!(no_history.reliability_score(&WithdrawalStats::default()), None);
+
+        let summary = AttendanceSummary {
+            attended: 2,
+            late: 0,
+            no_shows: 1,
+            left_early: 0,
+        };
+        let withdrawals = WithdrawalStats { total: 1, late: 0 };
+        // (2 * 1.0 + 0.8) / 4
+        assert_eq!(summary.reliability_score(&withdrawals), Some(70));
+    }
+
+    #[test]
+    fn test_no_show_block() -> Result<()> {
+        let disabled = NoShowBlock::default();
+        assert!(!disabled.is_blocked(100));
+
+        let block = NoShowBlock {
+            limit: Some(2),
+            period_days: 30,
+        };
+        assert!(!block.is_blocked(1));
+        assert!(block.is_blocked(2));
+        assert_eq!(
+            block.period_start(Date::from_calendar_date(2026, Month::March, 31)?),
+            Date::from_calendar_date(2026, Month::March, 1)?
+        );
+        Ok(())
+    }
+}
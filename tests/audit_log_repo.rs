@@ -0,0 +1,228 @@
+#[macro_use]
+mod common;
+
+#[cfg(test)]
+pub mod audit_log_repo_tests {
+    use anyhow::Result;
+
+    use pv281_giglog::models::audit_log::{AuditAction, AuditEntity, SelectManyFilter};
+    use pv281_giglog::models::employment::{EmploymentState, PartialEmployment};
+    use pv281_giglog::models::event::PartialEvent;
+    use pv281_giglog::models::pagination::ListSpec;
+    use pv281_giglog::models::user::{PartialUser, UserRole};
+    use pv281_giglog::repositories::audit_log::AuditLogRepository;
+    use pv281_giglog::repositories::employment::EmploymentRepository;
+    use pv281_giglog::repositories::event::EventRepository;
+    use pv281_giglog::repositories::user::UserRepository;
+
+    fn empty_filter() -> SelectManyFilter {
+        SelectManyFilter {
+            actor_id: None,
+            entity: None,
+            entity_id: None,
+            event_id: None,
+            action: None,
+            date_from: None,
+            date_to: None,
+        }
+    }
+
+    database_test! {
+        #[fixtures("events")]
+        async fn test_update_event_is_audited(database: Database) -> Result<()> {
+            let event_repository = database.repositories().event;
+            let repository = database.repositories().audit_log;
+
+            event_repository
+                .with_actor(1)
+                .update_event(
+                    1,
+                    PartialEvent {
+                        name: Some("Traunterberg".to_string()),
+                        date_start: None,
+                        date_end: None,
+                        img_url: None,
+                        description: None,
+                        venue_id: None,
+                        owner_id: None,
+                        publish_at: None,
+                        withdrawal_cutoff_hours: None,
+                    },
+                )
+                .await
+                .expect("Repository call should succeed");
+
+            let logs = repository
+                .list_audit_logs(empty_filter())
+                .await
+                .expect("Repository call should succeed");
+            assert_eq!(logs.len(), 1);
+
+            let log = &logs[0];
+            assert_eq!(log.actor_id, Some(1));
+            assert_eq!(log.entity, AuditEntity::Event);
+            assert_eq!(log.entity_id, 1);
+            assert_eq!(log.event_id, Some(1));
+            assert_eq!(log.action, AuditAction::Update);
+            assert_eq!(log.after.clone().unwrap()["name"], "Traunterberg");
+            assert_ne!(log.before.clone().unwrap()["name"], "Traunterberg");
+
+            database.close().await;
+            Ok(())
+        }
+    }
+
+    database_test! {
+        #[fixtures("events")]
+        async fn test_list_audit_logs_page(database: Database) -> Result<()> {
+            let event_repository = database.repositories().event.with_actor(1);
+            let repository = database.repositories().audit_log;
+
+            event_repository.delete_event(1).await?;
+            event_repository.delete_event(2).await?;
+
+            let spec = ListSpec {
+                page_size: 1,
+                ..ListSpec::default()
+            };
+            let newest = repository
+                .list_audit_logs_page(empty_filter(), &spec)
+                .await
+                .expect("Repository call should succeed");
+            assert_eq!(newest.total, 2);
+            assert_eq!(newest.items.len(), 1);
+            assert_eq!(newest.items[0].entity_id, 2);
+
+            let first_event = SelectManyFilter {
+                entity_id: Some(1),
+                ..empty_filter()
+            };
+            let first_event = repository.list_audit_logs_page(first_event, &spec).await?;
+            assert_eq!(first_event.total, 1);
+            assert_eq!(first_event.items[0].action, AuditAction::Delete);
+
+            database.close().await;
+            Ok(())
+        }
+    }
+
+    database_test! {
+        #[fixtures("employment")]
+        async fn test_employment_changes_are_scoped_to_event(database: Database) -> Result<()> {
+            let employment_repository = database.repositories().employment;
+            let repository = database.repositories().audit_log;
+
+            employment_repository
+                .with_actor(1)
+                .update_employment(
+                    1,
+                    PartialEmployment {
+                        rating: None,
+                        state: Some(EmploymentState::Rejected),
+                        user_id: None,
+                        position_id: None,
+                    },
+                )
+                .await
+                .expect("Repository call should succeed");
+
+            let mut filter = empty_filter();
+            filter.event_id = Some(1);
+            let logs = repository
+                .list_audit_logs(filter.clone())
+                .await
+                .expect("Repository call should succeed");
+            assert_eq!(logs.len(), 1);
+            assert_eq!(logs[0].entity, AuditEntity::Employment);
+            assert_eq!(logs[0].after.clone().unwrap()["state"], "Rejected");
+
+            filter.event_id = Some(2);
+            let logs = repository
+                .list_audit_logs(filter)
+                .await
+                .expect("Repository call should succeed");
+            assert_eq!(logs.len(), 0);
+
+            database.close().await;
+            Ok(())
+        }
+    }
+
+    database_test! {
+        #[fixtures("users")]
+        async fn test_user_snapshot_omits_password_hash(database: Database) -> Result<()> {
+            let user_repository = database.repositories().user;
+            let repository = database.repositories().audit_log;
+
+            user_repository
+                .with_actor(1)
+                .update_user(
+                    2,
+                    PartialUser {
+                        first_name: None,
+                        last_name: None,
+                        username: None,
+                        gender: None,
+                        birth_date: None,
+                        email: None,
+                        phone: None,
+                        password_hash: None,
+                        role: Some(UserRole::Organizer),
+                        tax_rate: Some(0.2),
+                        avatar_url: None,
+                    },
+                )
+                .await
+                .expect("Repository call should succeed");
+
+            let mut filter = empty_filter();
+            filter.entity = Some(AuditEntity::User);
+            filter.actor_id = Some(1);
+            let logs = repository
+                .list_audit_logs(filter)
+                .await
+                .expect("Repository call should succeed");
+            assert_eq!(logs.len(), 1);
+
+            let after = logs[0].after.clone().unwrap();
+            assert_eq!(after["role"], "Organizer");
+            assert!(after.get("password_hash").is_none());
+            assert!(logs[0]
+                .before
+                .clone()
+                .unwrap()
+                .get("password_hash")
+                .is_none());
+
+            database.close().await;
+            Ok(())
+        }
+    }
+
+    database_test! {
+        #[fixtures("events")]
+        async fn test_audit_log_is_append_only(database: Database) -> Result<()> {
+            let event_repository = database.repositories().event;
+            let repository = database.repositories().audit_log;
+
+            event_repository
+                .delete_event(1)
+                .await
+                .expect("Repository call should succeed");
+
+            let logs = repository
+                .list_audit_logs(empty_filter())
+                .await
+                .expect("Repository call should succeed");
+            assert_eq!(logs.len(), 1);
+            assert_eq!(logs[0].action, AuditAction::Delete);
+            assert_eq!(logs[0].actor_id, None);
+
+            let result = crate::common::execute(&database, r#"DELETE FROM "audit_log""#).await;
+            assert!(result.is_err());
+
+            database.close().await;
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,69 @@
+/// Defines a repository test that runs once on Postgres and once on SQLite, loading the
+/// fixtures of the same name from `fixtures` and `fixtures/sqlite` respectively. The body
+/// gets the backend as a [`Database`](pv281_giglog::repositories::database::Database).
+macro_rules! database_test {
+    (
+        #[fixtures($($fixture:literal),*)]
+        async fn $name:ident($database:ident: Database $(,)?) -> $result:ty $body:block
+    ) => {
+        mod $name {
+            use super::*;
+            use pv281_giglog::repositories::database::Database;
+            use pv281_giglog::repositories::pool_handler::PoolHandler;
+
+            async fn run($database: Database) -> $result $body
+
+            #[sqlx::test(fixtures($($fixture),*))]
+            async fn postgres(pool: sqlx::PgPool) -> $result {
+                run(Database::Postgres(PoolHandler::new(std::sync::Arc::new(pool)))).await
+            }
+
+            #[sqlx::test(
+                migrations = "migrations/sqlite",
+                fixtures(path = "fixtures/sqlite", scripts($($fixture),*))
+            )]
+            async fn sqlite(pool: sqlx::SqlitePool) -> $result {
+                run(Database::Sqlite(pool)).await
+            }
+        }
+    };
+}
+
+/// Runs `sql` on `database` directly, for tests that set up rows or check constraints the
+/// repositories do not reach.
+#[allow(dead_code)]
+pub async fn execute(
+    database: &pv281_giglog::repositories::database::Database,
+    sql: &str,
+) -> Result<(), sqlx::Error> {
+    use pv281_giglog::repositories::database::Database;
+
+    match database {
+        Database::Postgres(pool_handler) => {
+            sqlx::query(sql).execute(pool_handler.pool()).await?;
+        }
+        Database::Sqlite(pool) => {
+            sqlx::query(sql).execute(pool).await?;
+        }
+        Database::Memory(_) => panic!("The in-memory database does not run SQL"),
+    }
+    Ok(())
+}
+
+/// Moves the event `event_id` of the fixtures a year ahead, so that it has neither started
+/// nor ended whenever the tests run.
+#[allow(dead_code)]
+pub async fn reschedule_to_next_year(
+    database: &pv281_giglog::repositories::database::Database,
+    event_id: i32,
+) -> Result<(), sqlx::Error> {
+    let date_start = time::OffsetDateTime::now_utc().date() + time::Duration::days(365);
+    let date_end = date_start + time::Duration::days(1);
+    execute(
+        database,
+        &format!(
+            r#"UPDATE "event" SET "date_start" = '{date_start}', "date_end" = '{date_end}' WHERE "id" = {event_id}"#
+        ),
+    )
+    .await
+}
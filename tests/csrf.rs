@@ -0,0 +1,208 @@
+#[cfg(test)]
+pub mod csrf_tests {
+    use anyhow::Result;
+    use axum::{
+        body::{to_bytes, Body},
+        http::{header, Method, Request, StatusCode},
+        response::Response,
+        Router,
+    };
+    use axum_login::AuthManagerLayerBuilder;
+    use pv281_giglog::app::{router, AppState};
+    use pv281_giglog::auth::Backend;
+    use pv281_giglog::csrf::CSRF_HEADER;
+    use pv281_giglog::repositories::database::Database;
+    use pv281_giglog::repositories::pool_handler::PoolHandler;
+    use pv281_giglog::storage::local::LocalBlobStore;
+    use sqlx::PgPool;
+    use std::sync::Arc;
+    use tower::Service;
+    use tower_sessions::SessionManagerLayer;
+    use tower_sessions_sqlx_store::PostgresStore;
+
+    const REJECTION: &str =
+        "The request could not be verified. Please reload the page and try again.";
+
+    /// Every state-changing route registered in `app.rs`.
+    const MUTATING_ROUTES: &[(&str, &str)] = &[
+        ("POST", "/admin/users"),
+        ("DELETE", "/admin/user/1"),
+        ("POST", "/admin/user"),
+        ("PATCH", "/admin/user"),
+        ("POST", "/admin/audit"),
+        ("PATCH", "/admin/reviews/1"),
+        ("PATCH", "/admin/trash/event/1"),
+        ("DELETE", "/admin/trash/event/1"),
+        ("POST", "/employments"),
+        ("POST", "/employments/action"),
+        ("POST", "/employments/bulk-action"),
+        ("POST", "/employments/1/attendance"),
+        ("POST", "/employments/1/review"),
+        ("POST", "/manage/events/1/cancel"),
+        ("PATCH", "/manage/events/1/status"),
+        ("POST", "/manage/events/1/hero"),
+        ("PATCH", "/manage/jobs"),
+        ("DELETE", "/manage/jobs/1"),
+        ("PATCH", "/manage/venues"),
+        ("DELETE", "/manage/venues/1"),
+        ("POST", "/create/venues"),
+        ("POST", "/create/jobs"),
+        ("POST", "/partials/base/main/jobs/instructions-preview"),
+        ("PATCH", "/partials/base/main/attendance/attendance-log"),
+        ("POST", "/jobs"),
+        ("POST", "/jobs/invitations/1"),
+        ("POST", "/jobs/withdrawals/1"),
+        ("POST", "/jobs/reviews/1"),
+        ("POST", "/reviews/1/flag"),
+        ("POST", "/partials/base/main/events/event/job-state/1"),
+        ("POST", "/events"),
+        ("PATCH", "/events"),
+        ("DELETE", "/events/1"),
+        ("POST", "/employment"),
+        ("DELETE", "/employment"),
+        ("POST", "/event-manager-relation"),
+        ("PATCH", "/event-manager-relation"),
+        ("DELETE", "/event-manager-relation"),
+        ("PATCH", "/settings/details"),
+        ("PATCH", "/settings/password"),
+        ("POST", "/settings/avatar"),
+        ("POST", "/organizations"),
+        ("POST", "/organizations/active"),
+        ("POST", "/organizations/members"),
+        ("DELETE", "/organizations/members"),
+        ("POST", "/login"),
+        ("POST", "/register"),
+    ];
+
+    /// The router of the application, with sessions kept in `pool` as when it is served.
+    async fn app(pool: PgPool) -> Result<Router> {
+        let session_store = PostgresStore::new(pool.clone());
+        session_store.migrate().await?;
+        let app_state = AppState::new(
+            Database::Postgres(PoolHandler::new(Arc::new(pool))),
+            Arc::new(LocalBlobStore::new(std::env::temp_dir())),
+        );
+        let backend = Backend::new(app_state.user_repository.clone());
+        let session_layer = SessionManagerLayer::new(session_store).with_secure(false);
+        let auth_layer = AuthManagerLayerBuilder::new(backend, session_layer).build();
+        Ok(router(app_state).layer(auth_layer))
+    }
+
+    async fn send(
+        router: &Router,
+        method: &str,
+        path: &str,
+        cookies: Option<&str>,
+        token: Option<&str>,
+    ) -> Result<Response> {
+        let mut request = Request::builder()
+            .method(Method::from_bytes(method.as_bytes())?)
+            .uri(path);
+        if let Some(cookies) = cookies {
+            request = request.header(header::COOKIE, cookies);
+        }
+        if let Some(token) = token {
+            request = request.header(CSRF_HEADER, token);
+        }
+        Ok(router.clone().call(request.body(Body::empty())?).await?)
+    }
+
+    async fn body(response: Response) -> Result<String> {
+        let bytes = to_bytes(response.into_body(), usize::MAX).await?;
+        Ok(String::from_utf8(bytes.to_vec())?)
+    }
+
+    /// Opens a session, returns its cookies and the CSRF token the base layout renders.
+    async fn open_session(router: &Router) -> Result<(String, String)> {
+        let response = send(router, "GET", "/login", None, None).await?;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let cookies: Vec<String> = response
+            .headers()
+            .get_all(header::SET_COOKIE)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .filter_map(|cookie| cookie.split(';').next())
+            .map(|cookie| cookie.to_string())
+            .collect();
+        let html = body(response).await?;
+        let token = html
+            .split_once(r#"id="csrf-token" value=""#)
+            .and_then(|(_, rest)| rest.split_once('"'))
+            .map(|(token, _)| token.to_string())
+            .expect("The CSRF token should be rendered");
+        Ok((cookies.join("; "), token))
+    }
+
+    #[sqlx::test]
+    async fn test_safe_requests_pass_without_token(pool: PgPool) -> Result<()> {
+        let router = app(pool).await?;
+
+        for method in ["GET", "HEAD", "OPTIONS"] {
+            let response = send(&router, method, "/login", None, None).await?;
+            assert_ne!(response.status(), StatusCode::FORBIDDEN, "{method}");
+        }
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn test_mutating_routes_require_token(pool: PgPool) -> Result<()> {
+        let router = app(pool).await?;
+        let (cookies, token) = open_session(&router).await?;
+
+        for (method, path) in MUTATING_ROUTES {
+            let missing = send(&router, method, path, Some(&cookies), None).await?;
+            assert_eq!(missing.status(), StatusCode::FORBIDDEN, "{method} {path}");
+            assert_eq!(body(missing).await?, REJECTION, "{method} {path}");
+
+            let mismatched = send(
+                &router,
+                method,
+                path,
+                Some(&cookies),
+                Some("0123456789abcdef"),
+            )
+            .await?;
+            assert_eq!(
+                mismatched.status(),
+                StatusCode::FORBIDDEN,
+                "{method} {path}"
+            );
+
+            // Past the check the route's own handling takes over, such as the login redirect.
+            let accepted = send(&router, method, path, Some(&cookies), Some(&token)).await?;
+            assert_ne!(body(accepted).await?, REJECTION, "{method} {path}");
+        }
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn test_token_is_bound_to_session(pool: PgPool) -> Result<()> {
+        let router = app(pool).await?;
+        let (_, token) = open_session(&router).await?;
+        let (other_cookies, other_token) = open_session(&router).await?;
+        assert_ne!(token, other_token);
+
+        // Another session's token does not work, nor does a token without a session.
+        let response = send(&router, "POST", "/jobs", Some(&other_cookies), Some(&token)).await?;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        let response = send(&router, "POST", "/jobs", None, Some(&token)).await?;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+        // The token stays the same for the whole session.
+        let response = send(&router, "GET", "/login", Some(&other_cookies), None).await?;
+        assert!(body(response)
+            .await?
+            .contains(&format!(r#"id="csrf-token" value="{other_token}""#)));
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn test_static_files_are_not_checked(pool: PgPool) -> Result<()> {
+        let router = app(pool).await?;
+
+        let response = send(&router, "POST", "/public/svg/no_rating.svg", None, None).await?;
+        assert_ne!(response.status(), StatusCode::FORBIDDEN);
+        Ok(())
+    }
+}
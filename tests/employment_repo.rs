@@ -1,238 +1,843 @@
+#[macro_use]
+mod common;
+
 #[cfg(test)]
 pub mod employment_repo_tests {
     use anyhow::Result;
-    use sqlx::PgPool;
-    use std::sync::Arc;
+    use sqlx::types::time::{Date, OffsetDateTime};
+    use time::Month;
 
     use pv281_giglog::error::RepositoryError;
     use pv281_giglog::models::employment::{
-        CreateEmployment, EmploymentState, PartialEmployment, SelectManyFilter,
+        invitation_deadline, BulkOrder, CreateEmployment, EmploymentState, JobsFilter,
+        PartialEmployment, SelectManyFilter, SortColumn, INVITATION_VALIDITY,
     };
+    use pv281_giglog::models::event::ManagedBy;
+    use pv281_giglog::models::pagination::{ListSpec, PagePosition, SortDirection};
+    use pv281_giglog::models::staffing::{score, CandidateStats, StaffingWeights};
+    use pv281_giglog::policy::EventAction;
     use pv281_giglog::repositories::employment::EmploymentRepository;
-    use pv281_giglog::repositories::employment::PgEmploymentRepository;
-    use pv281_giglog::repositories::pool_handler::PoolHandler;
-
-    #[sqlx::test(fixtures("employment"))]
-    async fn test_create_employment(pool: PgPool) -> Result<()> {
-        let mut repository = PgEmploymentRepository::new(PoolHandler::new(Arc::new(pool)));
-
-        let new = CreateEmployment {
-            user_id: 4,
-            position_id: 1,
-            rating: 5,
-            state: EmploymentState::Pending,
-        };
-
-        let new_employment = repository
-            .create_employment(new.clone())
-            .await
-            .expect("Repository call should succeed");
+    use pv281_giglog::repositories::unit_of_work::UnitOfWork;
 
-        assert_eq!(new_employment.user_id, new_employment.user_id);
-        assert_eq!(new_employment.position_id, new_employment.position_id);
-        assert_eq!(new_employment.rating, new_employment.rating);
-        assert_eq!(new_employment.state, new_employment.state);
-
-        repository.pool_handler.disconnect().await;
-        Ok(())
+    fn utc(year: i32, month: Month, day: u8, hour: u8) -> Result<OffsetDateTime> {
+        Ok(Date::from_calendar_date(year, month, day)?
+            .with_hms(hour, 0, 0)?
+            .assume_utc())
     }
 
-    #[sqlx::test(fixtures("employment"))]
-    async fn test_create_employment_fail_exists(pool: PgPool) -> Result<()> {
-        let mut repository = PgEmploymentRepository::new(PoolHandler::new(Arc::new(pool)));
-
-        let new = CreateEmployment {
-            user_id: 3,
-            position_id: 1,
-            rating: 5,
-            state: EmploymentState::Pending,
-        };
-
-        let result = repository
-            .create_employment(new.clone())
-            .await;
-
-        assert!(matches!(result, Err(RepositoryError::GenericError(ref msg)) if msg.contains("The event has already been registered")));
+    database_test! {
+        #[fixtures("employment")]
+        async fn test_create_employment(database: Database) -> Result<()> {
+            crate::common::reschedule_to_next_year(&database, 1).await?;
+            let repository = database.repositories().employment;
+
+            let new = CreateEmployment {
+                user_id: 4,
+                position_id: 1,
+                rating: 5,
+                state: EmploymentState::Pending,
+                invitation_expires_at: None,
+            };
+
+            let new_employment = repository
+                .create_employment(new.clone())
+                .await
+                .expect("Repository call should succeed");
+
+            assert_eq!(new_employment.user_id, new_employment.user_id);
+            assert_eq!(new_employment.position_id, new_employment.position_id);
+            assert_eq!(new_employment.rating, new_employment.rating);
+            assert_eq!(new_employment.state, new_employment.state);
+
+            database.close().await;
+            Ok(())
+        }
+    }
 
-        repository.pool_handler.disconnect().await;
-        Ok(())
+    database_test! {
+        #[fixtures("employment")]
+        async fn test_create_employment_fail_exists(database: Database) -> Result<()> {
+            crate::common::reschedule_to_next_year(&database, 1).await?;
+            let repository = database.repositories().employment;
+
+            let new = CreateEmployment {
+                user_id: 3,
+                position_id: 1,
+                rating: 5,
+                state: EmploymentState::Pending,
+                invitation_expires_at: None,
+            };
+
+            let result = repository.create_employment(new.clone()).await;
+
+            assert!(
+                matches!(result, Err(RepositoryError::GenericError(ref msg)) if msg.contains("The event has already been registered"))
+            );
+
+            database.close().await;
+            Ok(())
+        }
     }
 
-    #[sqlx::test(fixtures("employment"))]
-    async fn test_create_employment_fail_event_after_end(pool: PgPool) -> Result<()> {
-        let mut repository = PgEmploymentRepository::new(PoolHandler::new(Arc::new(pool)));
+    database_test! {
+        #[fixtures("employment")]
+        async fn test_create_employment_fail_event_after_end(database: Database) -> Result<()> {
+            let repository = database.repositories().employment;
 
-        let new = CreateEmployment {
-            user_id: 3,
-            position_id: 2,
-            rating: 5,
-            state: EmploymentState::Pending,
-        };
+            let new = CreateEmployment {
+                user_id: 3,
+                position_id: 2,
+                rating: 5,
+                state: EmploymentState::Pending,
+                invitation_expires_at: None,
+            };
 
-        let result = repository
-            .create_employment(new.clone())
-            .await;
+            let result = repository.create_employment(new.clone()).await;
 
-        assert!(matches!(result, Err(RepositoryError::GenericError(ref msg)) if msg.contains("The event has already ended")));
+            assert!(
+                matches!(result, Err(RepositoryError::GenericError(ref msg)) if msg.contains("The event has already ended"))
+            );
 
-        repository.pool_handler.disconnect().await;
-        Ok(())
+            database.close().await;
+            Ok(())
+        }
     }
 
-    #[sqlx::test(fixtures("employment"))]
-    async fn test_create_employment_fail_full_job_position(pool: PgPool) -> Result<()> {
-        let mut repository = PgEmploymentRepository::new(PoolHandler::new(Arc::new(pool)));
+    database_test! {
+        #[fixtures("employment")]
+        async fn test_create_employment_fail_registration_closed(database: Database) -> Result<()> {
+            crate::common::reschedule_to_next_year(&database, 1).await?;
+            let repository = database.repositories().employment;
 
-        let mut new = CreateEmployment {
-            user_id: 4,
-            position_id: 1,
-            rating: 5,
-            state: EmploymentState::Accepted,
-        };
+            let mut new = CreateEmployment {
+                user_id: 4,
+                position_id: 3,
+                rating: 5,
+                state: EmploymentState::Pending,
+                invitation_expires_at: None,
+            };
 
-        let result = repository
-            .create_employment(new.clone())
-            .await
-            .expect("Repository call should succeed");
+            let result = repository.create_employment(new.clone()).await;
 
-        assert_eq!(result.user_id, new.user_id);
-        assert_eq!(result.position_id, new.position_id);
-        assert_eq!(result.rating, new.rating);
-        assert_eq!(result.state, new.state);
+            assert!(
+                matches!(result, Err(RepositoryError::GenericError(ref msg)) if msg.contains("The job position is not opened for registration"))
+            );
 
-        new.user_id = 5;
+            new.state = EmploymentState::Accepted;
 
-        let created = repository
-            .create_employment(new.clone())
-            .await;
+            let assigned = repository
+                .create_employment(new.clone())
+                .await
+                .expect("Repository call should succeed");
 
-        assert!(matches!(created, Err(RepositoryError::GenericError(ref msg)) if msg.contains("Job position is already full")));
+            assert_eq!(assigned.state, EmploymentState::Accepted);
 
-        repository.pool_handler.disconnect().await;
-        Ok(())
+            database.close().await;
+            Ok(())
+        }
     }
 
-    #[sqlx::test(fixtures("employment"))]
-    async fn test_list_employment(pool: PgPool) -> Result<()> {
-        let mut repository = PgEmploymentRepository::new(PoolHandler::new(Arc::new(pool)));
+    database_test! {
+        #[fixtures("employment")]
+        async fn test_create_employment_fail_draft_event(database: Database) -> Result<()> {
+            crate::common::reschedule_to_next_year(&database, 1).await?;
+            crate::common::execute(
+                &database,
+                r#"UPDATE "event" SET "status" = 'draft' WHERE "id" = 1"#,
+            )
+            .await?;
+            let repository = database.repositories().employment;
+
+            let new = CreateEmployment {
+                user_id: 4,
+                position_id: 1,
+                rating: 5,
+                state: EmploymentState::Pending,
+                invitation_expires_at: None,
+            };
+
+            let result = repository.create_employment(new.clone()).await;
+
+            assert!(
+                matches!(result, Err(RepositoryError::GenericError(ref msg)) if msg.contains("The event has not been published yet"))
+            );
+
+            database.close().await;
+            Ok(())
+        }
+    }
 
-        let empty_filter = SelectManyFilter {
-            user_id: None,
-            position_id: None,
-            rating: None,
-            state: None,
-        };
+    database_test! {
+        #[fixtures("employment")]
+        async fn test_create_employment_fail_full_job_position(database: Database) -> Result<()> {
+            crate::common::reschedule_to_next_year(&database, 1).await?;
+            let repository = database.repositories().employment;
+
+            let mut new = CreateEmployment {
+                user_id: 4,
+                position_id: 1,
+                rating: 5,
+                state: EmploymentState::Accepted,
+                invitation_expires_at: None,
+            };
+
+            let result = repository
+                .create_employment(new.clone())
+                .await
+                .expect("Repository call should succeed");
+
+            assert_eq!(result.user_id, new.user_id);
+            assert_eq!(result.position_id, new.position_id);
+            assert_eq!(result.rating, new.rating);
+            assert_eq!(result.state, new.state);
+
+            new.user_id = 5;
+
+            let created = repository.create_employment(new.clone()).await;
+
+            assert!(
+                matches!(created, Err(RepositoryError::GenericError(ref msg)) if msg.contains("Job position is already full"))
+            );
+
+            database.close().await;
+            Ok(())
+        }
+    }
 
-        let list_all = repository
-            .list_employment(empty_filter)
-            .await
-            .expect("Repository call should succeed");
-        assert_eq!(list_all.len(), 3);
-
-        let accepted_filter = SelectManyFilter {
-            user_id: None,
-            position_id: None,
-            rating: None,
-            state: Some(EmploymentState::Accepted),
-        };
+    database_test! {
+        #[fixtures("employment")]
+        async fn test_unit_of_work_rollback(database: Database) -> Result<()> {
+            crate::common::reschedule_to_next_year(&database, 1).await?;
+            let repository = database.repositories().employment.with_actor(1);
+
+            // Job position 1 has a capacity of two and one accepted employment.
+            let mut unit_of_work = database.begin().await?;
+            let assigned = repository
+                .create_employment_in(
+                    &mut unit_of_work,
+                    CreateEmployment {
+                        user_id: 4,
+                        position_id: 1,
+                        rating: 5,
+                        state: EmploymentState::Accepted,
+                        invitation_expires_at: None,
+                    },
+                )
+                .await?;
+            let result = repository
+                .update_employment_in(
+                    &mut unit_of_work,
+                    1,
+                    PartialEmployment {
+                        user_id: None,
+                        position_id: None,
+                        rating: None,
+                        state: Some(EmploymentState::Accepted),
+                    },
+                )
+                .await;
+            assert!(
+                matches!(result, Err(RepositoryError::GenericError(ref msg)) if msg.contains("Job position is already full"))
+            );
+            drop(unit_of_work);
+
+            assert!(matches!(
+                repository.get_employment_by_id(assigned.id).await,
+                Err(RepositoryError::NotFound)
+            ));
+            assert!(repository
+                .list_state_history(&[assigned.id])
+                .await?
+                .is_empty());
+
+            // With the assignment rolled back, the place is free again.
+            let accepted = repository
+                .update_employment(
+                    1,
+                    PartialEmployment {
+                        user_id: None,
+                        position_id: None,
+                        rating: None,
+                        state: Some(EmploymentState::Accepted),
+                    },
+                )
+                .await?;
+            assert_eq!(accepted.state, EmploymentState::Accepted);
+
+            database.close().await;
+            Ok(())
+        }
+    }
 
-        let list = repository
-            .list_employment(accepted_filter)
-            .await
-            .expect("Repository call should succeed");
-        assert_eq!(list.len(), 1);
-
-        let user_filter = SelectManyFilter {
-            user_id: Some(2),
-            position_id: None,
-            rating: None,
-            state: None,
-        };
+    database_test! {
+        #[fixtures("employment")]
+        async fn test_list_employment(database: Database) -> Result<()> {
+            let repository = database.repositories().employment;
+
+            let empty_filter = SelectManyFilter {
+                organization_id: None,
+                user_id: None,
+                position_id: None,
+                rating: None,
+                state: None,
+                event_name_contains: None,
+                job_name_contains: None,
+                employee_name_contains: None,
+                managed_by: None,
+            };
+
+            let list_all = repository
+                .list_employment(empty_filter)
+                .await
+                .expect("Repository call should succeed");
+            assert_eq!(list_all.len(), 3);
+
+            let accepted_filter = SelectManyFilter {
+                organization_id: None,
+                user_id: None,
+                position_id: None,
+                rating: None,
+                state: Some(EmploymentState::Accepted),
+                event_name_contains: None,
+                job_name_contains: None,
+                employee_name_contains: None,
+                managed_by: None,
+            };
+
+            let list = repository
+                .list_employment(accepted_filter)
+                .await
+                .expect("Repository call should succeed");
+            assert_eq!(list.len(), 1);
+
+            let user_filter = SelectManyFilter {
+                organization_id: None,
+                user_id: Some(2),
+                position_id: None,
+                rating: None,
+                state: None,
+                event_name_contains: None,
+                job_name_contains: None,
+                employee_name_contains: None,
+                managed_by: None,
+            };
+
+            let list = repository
+                .list_employment(user_filter)
+                .await
+                .expect("Repository call should succeed");
+            assert_eq!(list.len(), 1);
+
+            database.close().await;
+            Ok(())
+        }
+    }
 
-        let list = repository
-            .list_employment(user_filter)
-            .await
-            .expect("Repository call should succeed");
-        assert_eq!(list.len(), 1);
+    database_test! {
+        #[fixtures("employment")]
+        async fn test_get_employment_by_id(database: Database) -> Result<()> {
+            let repository = database.repositories().employment;
+
+            let existing = repository
+                .get_employment_by_id(1)
+                .await
+                .expect("Repository call should succeed");
+            assert_eq!(existing.user_id, 1);
+            assert_eq!(existing.position_id, 1);
+            assert_eq!(existing.rating, 8);
+            assert_eq!(existing.state, EmploymentState::Pending);
+
+            let result = repository.get_employment_by_id(999).await;
+            assert!(matches!(result, Err(RepositoryError::NotFound)));
+
+            database.close().await;
+            Ok(())
+        }
+    }
 
-        repository.pool_handler.disconnect().await;
-        Ok(())
+    database_test! {
+        #[fixtures("employment")]
+        async fn test_delete_employment(database: Database) -> Result<()> {
+            let repository = database.repositories().employment;
+
+            repository
+                .delete_employment(1)
+                .await
+                .expect("Repository call should succeed");
+            repository
+                .delete_employment(2)
+                .await
+                .expect("Repository call should succeed");
+            repository
+                .delete_employment(3)
+                .await
+                .expect("Repository call should succeed");
+
+            let result = repository.get_employment_by_id(999).await;
+            assert!(matches!(result, Err(RepositoryError::NotFound)));
+
+            database.close().await;
+            Ok(())
+        }
     }
 
-    #[sqlx::test(fixtures("employment"))]
-    async fn test_get_employment_by_id(pool: PgPool) -> Result<()> {
-        let mut repository = PgEmploymentRepository::new(PoolHandler::new(Arc::new(pool)));
+    database_test! {
+        #[fixtures("employment")]
+        async fn test_(database: Database) -> Result<()> {
+            let repository = database.repositories().employment;
+
+            let old = repository
+                .get_employment_by_id(2)
+                .await
+                .expect("Repository call should succeed");
+
+            let partial = PartialEmployment {
+                user_id: Some(4),
+                position_id: None,
+                rating: Some(10),
+                state: None,
+            };
+
+            let updated = repository
+                .update_employment(2, partial.clone())
+                .await
+                .expect("Repository call should succeed");
+
+            assert_eq!(partial.user_id.unwrap(), updated.user_id);
+            assert_eq!(old.position_id, updated.position_id);
+            assert_eq!(partial.rating.unwrap(), updated.rating);
+            assert_eq!(old.state, updated.state);
+
+            let result = repository.update_employment(999, partial).await;
+            assert!(matches!(result, Err(RepositoryError::NotFound)));
+
+            database.close().await;
+            Ok(())
+        }
+    }
 
-        let existing = repository
-            .get_employment_by_id(1)
-            .await
-            .expect("Repository call should succeed");
-        assert_eq!(existing.user_id, 1);
-        assert_eq!(existing.position_id, 1);
-        assert_eq!(existing.rating, 8);
-        assert_eq!(existing.state, EmploymentState::Pending);
+    database_test! {
+        #[fixtures("employment", "invitations")]
+        async fn test_respond_to_invitation(database: Database) -> Result<()> {
+            let repository = database.repositories().employment;
+            let now = utc(2025, Month::June, 1, 12)?;
+
+            let accepted = repository
+                .respond_to_invitation(4, true, now)
+                .await
+                .expect("Repository call should succeed");
+            assert_eq!(accepted.state, EmploymentState::Accepted);
+
+            let result = repository.respond_to_invitation(4, false, now).await;
+            assert!(
+                matches!(result, Err(RepositoryError::GenericError(ref msg)) if msg.contains("The invitation is no longer open"))
+            );
+
+            let result = repository.respond_to_invitation(6, true, now).await;
+            assert!(
+                matches!(result, Err(RepositoryError::GenericError(ref msg)) if msg.contains("Job position is already full"))
+            );
+
+            let declined = repository
+                .respond_to_invitation(6, false, now)
+                .await
+                .expect("Repository call should succeed");
+            assert_eq!(declined.state, EmploymentState::Declined);
+
+            let result = repository.respond_to_invitation(5, true, now).await;
+            assert!(
+                matches!(result, Err(RepositoryError::GenericError(ref msg)) if msg.contains("The invitation has expired"))
+            );
+            let expired = repository
+                .get_employment_by_id(5)
+                .await
+                .expect("Repository call should succeed");
+            assert_eq!(expired.state, EmploymentState::Expired);
+
+            database.close().await;
+            Ok(())
+        }
+    }
 
-        let result = repository.get_employment_by_id(999).await;
-        assert!(matches!(result, Err(RepositoryError::NotFound)));
+    database_test! {
+        #[fixtures("employment", "invitations")]
+        async fn test_expire_invitations(database: Database) -> Result<()> {
+            let repository = database.repositories().employment;
+
+            let expired = repository
+                .expire_invitations(utc(2025, Month::June, 1, 12)?)
+                .await
+                .expect("Repository call should succeed");
+            assert_eq!(expired.len(), 1);
+            assert_eq!(expired[0].id, 5);
+            assert_eq!(expired[0].state, EmploymentState::Expired);
+
+            let expired = repository
+                .expire_invitations(utc(2030, Month::January, 1, 0)?)
+                .await
+                .expect("Repository call should succeed");
+            assert_eq!(expired.len(), 2);
+
+            database.close().await;
+            Ok(())
+        }
+    }
 
-        repository.pool_handler.disconnect().await;
+    #[test]
+    fn test_invitation_deadline() -> Result<()> {
+        let now = utc(2025, Month::June, 1, 12)?;
+
+        assert_eq!(
+            invitation_deadline(now, Date::from_calendar_date(2025, Month::July, 1)?),
+            now + INVITATION_VALIDITY
+        );
+        assert_eq!(
+            invitation_deadline(now, Date::from_calendar_date(2025, Month::June, 2)?),
+            utc(2025, Month::June, 2, 0)?
+        );
+        assert_eq!(
+            invitation_deadline(now, Date::from_calendar_date(2025, Month::May, 1)?),
+            now + INVITATION_VALIDITY
+        );
         Ok(())
     }
 
-    #[sqlx::test(fixtures("employment"))]
-    async fn test_delete_employment(pool: PgPool) -> Result<()> {
-        let mut repository = PgEmploymentRepository::new(PoolHandler::new(Arc::new(pool)));
-
-        repository
-            .delete_employment(1)
-            .await
-            .expect("Repository call should succeed");
-        repository
-            .delete_employment(2)
-            .await
-            .expect("Repository call should succeed");
-        repository
-            .delete_employment(3)
-            .await
-            .expect("Repository call should succeed");
-
-        let result = repository.get_employment_by_id(999).await;
-        assert!(matches!(result, Err(RepositoryError::NotFound)));
+    database_test! {
+        #[fixtures("employment")]
+        async fn test_withdraw_employment(database: Database) -> Result<()> {
+            let repository = database.repositories().employment;
+
+            let withdrawn = repository
+                .withdraw_employment(1, None, utc(2025, Month::December, 1, 12)?)
+                .await
+                .expect("Repository call should succeed");
+            assert_eq!(withdrawn.state, EmploymentState::Withdrawn);
+
+            let result = repository
+                .withdraw_employment(2, None, utc(2025, Month::December, 1, 12)?)
+                .await;
+            assert!(
+                matches!(result, Err(RepositoryError::GenericError(ref msg)) if msg.contains("Only pending or accepted jobs can be withdrawn from"))
+            );
+
+            let late = utc(2025, Month::December, 31, 12)?;
+            let result = repository.withdraw_employment(3, None, late).await;
+            assert!(
+                matches!(result, Err(RepositoryError::GenericError(ref msg)) if msg.contains("requires a reason"))
+            );
+
+            let withdrawn = repository
+                .withdraw_employment(3, Some("I am sick".to_string()), late)
+                .await
+                .expect("Repository call should succeed");
+            assert_eq!(withdrawn.state, EmploymentState::Withdrawn);
+
+            let stats = repository
+                .withdrawal_stats(1, None)
+                .await
+                .expect("Repository call should succeed");
+            assert_eq!((stats.total, stats.late), (1, 0));
+            let stats = repository
+                .withdrawal_stats(3, Some(1))
+                .await
+                .expect("Repository call should succeed");
+            assert_eq!((stats.total, stats.late), (1, 1));
+
+            database.close().await;
+            Ok(())
+        }
+    }
 
-        repository.pool_handler.disconnect().await;
-        Ok(())
+    database_test! {
+        #[fixtures("employment")]
+        async fn test_state_transitions(database: Database) -> Result<()> {
+            let repository = database.repositories().employment.with_actor(1);
+            let to_state = |state| PartialEmployment {
+                user_id: None,
+                position_id: None,
+                rating: None,
+                state: Some(state),
+            };
+
+            let result = repository
+                .update_employment(2, to_state(EmploymentState::Accepted))
+                .await;
+            assert!(
+                matches!(result, Err(RepositoryError::GenericError(ref msg)) if msg.contains("cannot move from Rejected to Accepted"))
+            );
+
+            let result = repository
+                .update_employment(1, to_state(EmploymentState::Done))
+                .await;
+            assert!(
+                matches!(result, Err(RepositoryError::GenericError(ref msg)) if msg.contains("cannot move from Pending to Done"))
+            );
+
+            repository
+                .update_employment(1, to_state(EmploymentState::Accepted))
+                .await
+                .expect("Repository call should succeed");
+            repository
+                .update_employment(1, to_state(EmploymentState::Done))
+                .await
+                .expect("Repository call should succeed");
+
+            let history = repository
+                .list_state_history(&[1, 2])
+                .await
+                .expect("Repository call should succeed");
+            assert_eq!(history.len(), 2);
+            assert_eq!(history[0].from_state, Some(EmploymentState::Pending));
+            assert_eq!(history[0].to_state, EmploymentState::Accepted);
+            assert_eq!(history[1].from_state, Some(EmploymentState::Accepted));
+            assert_eq!(history[1].to_state, EmploymentState::Done);
+            assert_eq!(history[1].changed_by, Some(1));
+            assert_eq!(history[1].changed_by_name.as_deref(), Some("Josefka Buba"));
+
+            database.close().await;
+            Ok(())
+        }
     }
 
-    #[sqlx::test(fixtures("employment"))]
-    async fn test_(pool: PgPool) -> Result<()> {
-        let mut repository = PgEmploymentRepository::new(PoolHandler::new(Arc::new(pool)));
+    database_test! {
+        #[fixtures("employment", "invitations")]
+        async fn test_bulk_update_state(database: Database) -> Result<()> {
+            let repository = database.repositories().employment.with_actor(1);
+
+            let result = repository
+                .bulk_update_state(&[2, 1, 999], EmploymentState::Accepted, BulkOrder::Rating)
+                .await
+                .expect("Repository call should succeed");
+            assert_eq!(result.updated.len(), 1);
+            assert_eq!(result.updated[0].id, 1);
+            assert_eq!(result.updated[0].state, EmploymentState::Accepted);
+            let mut skipped: Vec<i32> = result
+                .skipped
+                .iter()
+                .map(|skipped| skipped.employment_id)
+                .collect();
+            skipped.sort();
+            assert_eq!(skipped, vec![2, 999]);
+
+            let result = repository
+                .bulk_update_state(
+                    &[4, 6],
+                    EmploymentState::Accepted,
+                    BulkOrder::ApplicationTime,
+                )
+                .await
+                .expect("Repository call should succeed");
+            assert!(result.updated.is_empty());
+            assert!(result
+                .skipped
+                .iter()
+                .all(|skipped| skipped.reason.contains("Job position is already full")));
+
+            let result = repository
+                .bulk_update_state(&[1, 3], EmploymentState::Done, BulkOrder::ApplicationTime)
+                .await
+                .expect("Repository call should succeed");
+            assert_eq!(result.updated.len(), 2);
+            assert!(result.skipped.is_empty());
+
+            let history = repository
+                .list_state_history(&[1, 3])
+                .await
+                .expect("Repository call should succeed");
+            assert_eq!(history.len(), 3);
+
+            database.close().await;
+            Ok(())
+        }
+    }
 
-        let old = repository
-            .get_employment_by_id(2)
-            .await
-            .expect("Repository call should succeed");
+    database_test! {
+        #[fixtures("employment", "staffing")]
+        async fn test_list_staffing_candidates(database: Database) -> Result<()> {
+            let repository = database.repositories().employment;
+
+            let mut candidates = repository.list_staffing_candidates(3).await?;
+            candidates.sort_by_key(|candidate| candidate.user_id);
+            assert_eq!(candidates.len(), 2);
+
+            let experienced = &candidates[0];
+            assert_eq!(experienced.user_id, 2);
+            assert_eq!(experienced.average_rating, Some(4.0));
+            assert_eq!(experienced.done_in_category, 1);
+            assert_eq!(experienced.conflicts, 0);
+            assert_eq!(experienced.no_shows, 0);
+
+            let busy = &candidates[1];
+            assert_eq!(busy.user_id, 5);
+            assert_eq!(busy.average_rating, None);
+            assert_eq!(busy.done_in_category, 0);
+            assert_eq!(busy.conflicts, 1);
+            assert_eq!(busy.no_shows, 1);
+
+            let weights = StaffingWeights::default();
+            assert!(score(experienced, &weights).total > score(busy, &weights).total);
+
+            // Users already staffed on the position are not suggested again.
+            let candidates = repository.list_staffing_candidates(1).await?;
+            assert!(candidates.iter().all(|candidate| candidate.user_id != 5));
+            assert!(candidates.iter().all(|candidate| candidate.user_id != 2));
+
+            Ok(())
+        }
+    }
 
-        let partial = PartialEmployment {
-            user_id: Some(4),
-            position_id: None,
-            rating: Some(10),
-            state: None,
+    #[test]
+    fn test_staffing_score() {
+        let weights = StaffingWeights {
+            rating: 1.0,
+            experience: 1.0,
+            availability: 1.0,
+            reliability: 1.0,
         };
+        let mut stats = CandidateStats {
+            user_id: 1,
+            first_name: "A".to_string(),
+            last_name: "B".to_string(),
+            username: "ab".to_string(),
+            average_rating: Some(5.0),
+            done_in_category: 10,
+            conflicts: 0,
+            no_shows: 0,
+        };
+        let breakdown = score(&stats, &weights);
+        assert_eq!(breakdown.rating, 1.0);
+        assert_eq!(breakdown.experience, 1.0);
+        assert_eq!(breakdown.availability, 1.0);
+        assert_eq!(breakdown.reliability, 1.0);
+        assert_eq!(breakdown.total, 4.0);
+
+        stats.average_rating = None;
+        stats.done_in_category = 0;
+        stats.conflicts = 2;
+        stats.no_shows = 1;
+        let breakdown = score(&stats, &weights);
+        assert_eq!(breakdown.rating, 0.5);
+        assert_eq!(breakdown.experience, 0.0);
+        assert_eq!(breakdown.availability, 0.0);
+        assert_eq!(breakdown.reliability, 0.5);
+        assert_eq!(breakdown.total, 1.0);
+    }
 
-        let updated = repository
-            .update_employment(2, partial.clone())
-            .await
-            .expect("Repository call should succeed");
-
-        assert_eq!(partial.user_id.unwrap(), updated.user_id);
-        assert_eq!(old.position_id, updated.position_id);
-        assert_eq!(partial.rating.unwrap(), updated.rating);
-        assert_eq!(old.state, updated.state);
-
-        let result = repository.update_employment(999, partial).await;
-        assert!(matches!(result, Err(RepositoryError::NotFound)));
+    database_test! {
+        #[fixtures("employment")]
+        async fn test_list_employment_page(database: Database) -> Result<()> {
+            let repository = database.repositories().employment;
+
+            let filter = SelectManyFilter {
+                organization_id: Some(1),
+                position_id: None,
+                user_id: None,
+                state: None,
+                rating: None,
+                event_name_contains: None,
+                job_name_contains: None,
+                employee_name_contains: None,
+                managed_by: Some(ManagedBy {
+                    user_id: 1,
+                    actions: vec![EventAction::ManageApplicants, EventAction::ApproveHours],
+                }),
+            };
+            let spec = ListSpec {
+                sort_by: SortColumn::EmployeeName,
+                sort_direction: SortDirection::Desc,
+                page_size: 2,
+                position: PagePosition::Page(1),
+            };
+
+            let first = repository
+                .list_employment_page(filter.clone(), &spec)
+                .await
+                .expect("Repository call should succeed");
+            let ids: Vec<i32> = first.items.iter().map(|employment| employment.id).collect();
+            assert_eq!(ids, vec![2, 3]);
+            assert_eq!(first.total, 3);
+            assert_eq!(first.total_pages(), 2);
+
+            let by_employee = SelectManyFilter {
+                employee_name_contains: Some("srej".to_string()),
+                ..filter.clone()
+            };
+            let matching = repository.list_employment_page(by_employee, &spec).await?;
+            assert_eq!(matching.total, 1);
+            assert_eq!(matching.items[0].id, 2);
+
+            let accepted = SelectManyFilter {
+                state: Some(EmploymentState::Accepted),
+                ..filter.clone()
+            };
+            let accepted = repository.list_employment_page(accepted, &spec).await?;
+            assert_eq!(accepted.items.len(), 1);
+            assert_eq!(accepted.items[0].id, 3);
+
+            // Someone without any relation to the event sees none of its employments.
+            let foreign = SelectManyFilter {
+                managed_by: Some(ManagedBy {
+                    user_id: 5,
+                    actions: vec![EventAction::ManageApplicants],
+                }),
+                ..filter
+            };
+            assert_eq!(
+                repository.list_employment_page(foreign, &spec).await?.total,
+                0
+            );
+
+            database.close().await;
+            Ok(())
+        }
+    }
 
-        repository.pool_handler.disconnect().await;
-        Ok(())
+    database_test! {
+        #[fixtures("employment")]
+        async fn test_job_stats(database: Database) -> Result<()> {
+            crate::common::execute(
+                &database,
+                r#"INSERT INTO "employment" ("id", "user_id", "position_id", "rating", "state")
+                VALUES (4, 1, 2, 0, 'done')"#,
+            )
+            .await?;
+            crate::common::execute(
+                &database,
+                r#"INSERT INTO "worked_hours" ("employment_id", "hours_worked", "date")
+                VALUES (1, 3, '2026-01-01'), (4, 2, '2024-01-01')"#,
+            )
+            .await?;
+            let repository = database.repositories().employment;
+
+            let filter = JobsFilter {
+                organization_id: 1,
+                user_id: 1,
+                state: None,
+                starts_from: None,
+                ends_until: None,
+                event_name_contains: None,
+                venue_name_contains: None,
+                job_name_contains: None,
+            };
+
+            let stats = repository.job_stats(filter).await?;
+            assert_eq!(stats.total_jobs, 2);
+            assert_eq!(stats.unique_events, 2);
+            assert_eq!(stats.unique_venues, 1);
+            assert_eq!(stats.hours_worked, 5.0);
+            assert_eq!(stats.salary, 730.0);
+            assert_eq!(
+                stats.earliest_date,
+                Some(Date::from_calendar_date(2024, Month::January, 1)?)
+            );
+            assert_eq!(
+                stats.latest_date,
+                Some(Date::from_calendar_date(2026, Month::January, 2)?)
+            );
+            assert_eq!(stats.average_rating, Some(8.0));
+
+            database.close().await;
+            Ok(())
+        }
     }
 }
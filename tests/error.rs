@@ -0,0 +1,85 @@
+#[cfg(test)]
+pub mod error_tests {
+    use anyhow::{anyhow, Context, Result};
+    use axum::{body::to_bytes, http::StatusCode, response::IntoResponse};
+    use pv281_giglog::error::{
+        ApiError, AppError, ErrorDetails, RepositoryError, StorageError, UploadError,
+    };
+    use validator::ValidationErrors;
+
+    #[test]
+    fn test_api_error_mapping() {
+        let cases = [
+            (
+                AppError::from(RepositoryError::NotFound),
+                ApiError::NotFound,
+            ),
+            (
+                AppError::from(RepositoryError::Conflict("username".to_string())),
+                ApiError::Conflict,
+            ),
+            (
+                AppError::from(RepositoryError::ConnectionError("pool".to_string())),
+                ApiError::InternalServerError,
+            ),
+            (AppError::from(StorageError::NotFound), ApiError::NotFound),
+            (
+                AppError::from(ValidationErrors::new()),
+                ApiError::BadRequest,
+            ),
+            (
+                AppError::from(UploadError::InvalidImage),
+                ApiError::BadRequest,
+            ),
+            (
+                AppError::from(ApiError::Unauthorized),
+                ApiError::Unauthorized,
+            ),
+            (
+                AppError::from(anyhow!("boom")),
+                ApiError::InternalServerError,
+            ),
+        ];
+        for (error, expected) in cases {
+            assert_eq!(error.api_error(), expected);
+        }
+
+        // Context added on the way up does not hide the cause.
+        let error: Result<(), RepositoryError> = Err(RepositoryError::NotFound);
+        let error = AppError::from(error.context("loading the event").unwrap_err());
+        assert_eq!(error.api_error(), ApiError::NotFound);
+    }
+
+    #[test]
+    fn test_sqlx_error_mapping() {
+        assert!(matches!(
+            RepositoryError::from(sqlx::Error::RowNotFound),
+            RepositoryError::NotFound
+        ));
+        assert!(matches!(
+            RepositoryError::from(sqlx::Error::PoolTimedOut),
+            RepositoryError::ConnectionError(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_response_hides_details() -> Result<()> {
+        let response = AppError::from(anyhow!("password=hunter2")).into_response();
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+        let details = *response
+            .extensions()
+            .get::<ErrorDetails>()
+            .expect("Error responses should carry their details");
+        assert_eq!(details.error, ApiError::InternalServerError);
+
+        let body = to_bytes(response.into_body(), usize::MAX).await?;
+        let body: serde_json::Value = serde_json::from_slice(&body)?;
+        assert_eq!(body["error"], "internal server error");
+        assert_eq!(body["correlation_id"], details.correlation_id.to_string());
+        assert!(!body.to_string().contains("hunter2"));
+
+        let response = AppError::from(RepositoryError::NotFound).into_response();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        Ok(())
+    }
+}
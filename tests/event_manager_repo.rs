@@ -1,104 +1,165 @@
+#[macro_use]
+mod common;
+
 #[cfg(test)]
 pub mod event_manager_relation_repo_tests {
     use anyhow::Result;
-    use sqlx::PgPool;
-    use std::sync::Arc;
     use pv281_giglog::error::RepositoryError;
-    use pv281_giglog::models::event_manager_relation::CreateEventManagerRelation;
+    use pv281_giglog::models::event_manager_relation::{
+        CreateEventManagerRelation, EventManagerPermissions,
+    };
     use pv281_giglog::repositories::event_manager_relation::EventManagerRelationRepository;
-    use pv281_giglog::repositories::event_manager_relation::PgEventManagerRelationRepository;
-    use pv281_giglog::repositories::pool_handler::PoolHandler;
 
-    #[sqlx::test(fixtures("event_manager_relation"))]
-    async fn test_create_event_manager_relation(pool: PgPool) -> Result<()> {
-        let mut repository =
-            PgEventManagerRelationRepository::new(PoolHandler::new(Arc::new(pool)));
+    database_test! {
+        #[fixtures("event_manager_relation")]
+        async fn test_create_event_manager_relation(database: Database) -> Result<()> {
+            let repository = database.repositories().event_manager_relation;
+
+            let new_relation = CreateEventManagerRelation {
+                event_id: 1,
+                user_id: 3,
+            };
 
-        let new_relation = CreateEventManagerRelation {
-            event_id: 1,
-            user_id: 3,
-        };
+            let result = repository
+                .create_relation(new_relation.clone())
+                .await
+                .expect("Repository call should succeed");
 
-        let result = repository
-            .create_relation(new_relation.clone())
-            .await
-            .expect("Repository call should succeed");
+            assert_eq!(result.event_id, 1);
+            assert_eq!(result.user_id, 3);
 
-        assert_eq!(result.event_id, 1);
-        assert_eq!(result.user_id, 3);
+            let result = repository.create_relation(new_relation).await;
+            assert!(
+                matches!(result, Err(RepositoryError::GenericError(ref msg)) if msg.contains("The event has already been registered"))
+            );
 
-        let result = repository.create_relation(new_relation).await;
-        assert!(matches!(result, Err(RepositoryError::GenericError(ref msg)) if msg.contains("The event has already been registered")));
+            database.close().await;
+            Ok(())
+        }
+    }
+
+    database_test! {
+        #[fixtures("event_manager_relation")]
+        async fn test_list_event_managers(database: Database) -> Result<()> {
+            let repository = database.repositories().event_manager_relation;
+
+            let result = repository
+                .list_event_managers(2)
+                .await
+                .expect("Repository call should succeed");
+            assert_eq!(result.len(), 2);
+
+            let result = repository
+                .list_event_managers(999)
+                .await
+                .expect("Repository call should succeed");
+            assert_eq!(result.len(), 0);
+
+            database.close().await;
+            Ok(())
+        }
+    }
 
-        repository.pool_handler.disconnect().await;
-        Ok(())
+    database_test! {
+        #[fixtures("event_manager_relation")]
+        async fn test_list_managers_events(database: Database) -> Result<()> {
+            let repository = database.repositories().event_manager_relation;
+
+            let result = repository
+                .list_managers_events(3, 1)
+                .await
+                .expect("Repository call should succeed");
+            assert_eq!(result.len(), 1);
+
+            let result = repository
+                .list_managers_events(999, 1)
+                .await
+                .expect("Repository call should succeed");
+            assert_eq!(result.len(), 0);
+
+            database.close().await;
+            Ok(())
+        }
     }
 
-    #[sqlx::test(fixtures("event_manager_relation"))]
-    async fn test_list_event_managers(pool: PgPool) -> Result<()> {
-        let mut repository =
-            PgEventManagerRelationRepository::new(PoolHandler::new(Arc::new(pool)));
-
-        let result = repository
-            .list_event_managers(2)
-            .await
-            .expect("Repository call should succeed");
-        assert_eq!(result.len(), 2);
-
-        let result = repository
-            .list_event_managers(999)
-            .await
-            .expect("Repository call should succeed");
-        assert_eq!(result.len(), 0);
-
-        repository.pool_handler.disconnect().await;
-        Ok(())
+    database_test! {
+        #[fixtures("event_manager_relation")]
+        async fn test_delete_relation(database: Database) -> Result<()> {
+            let repository = database.repositories().event_manager_relation;
+
+            let result = repository
+                .list_managers_events(3, 1)
+                .await
+                .expect("Repository call should succeed");
+            assert_eq!(result.len(), 1);
+
+            repository
+                .delete_relation(result[0].clone())
+                .await
+                .expect("Repository call should succeed");
+
+            let result = repository
+                .list_managers_events(3, 1)
+                .await
+                .expect("Repository call should succeed");
+            assert_eq!(result.len(), 0);
+
+            database.close().await;
+            Ok(())
+        }
     }
 
-    #[sqlx::test(fixtures("event_manager_relation"))]
-    async fn test_list_managers_events(pool: PgPool) -> Result<()> {
-        let mut repository =
-            PgEventManagerRelationRepository::new(PoolHandler::new(Arc::new(pool)));
-
-        let result = repository
-            .list_managers_events(3)
-            .await
-            .expect("Repository call should succeed");
-        assert_eq!(result.len(), 1);
-
-        let result = repository
-            .list_managers_events(999)
-            .await
-            .expect("Repository call should succeed");
-        assert_eq!(result.len(), 0);
-
-        repository.pool_handler.disconnect().await;
-        Ok(())
+    database_test! {
+        #[fixtures("event_manager_relation")]
+        async fn test_get_relation(database: Database) -> Result<()> {
+            let repository = database.repositories().event_manager_relation;
+
+            let result = repository
+                .get_relation(2, 3)
+                .await
+                .expect("Repository call should succeed");
+            assert_eq!(result.event_id, 2);
+            assert_eq!(result.user_id, 3);
+            assert_eq!(result.permissions(), EventManagerPermissions::default());
+
+            let result = repository.get_relation(1, 3).await;
+            assert!(matches!(result, Err(RepositoryError::NotFound)));
+
+            database.close().await;
+            Ok(())
+        }
     }
 
-    #[sqlx::test(fixtures("event_manager_relation"))]
-    async fn test_delete_relation(pool: PgPool) -> Result<()> {
-        let mut repository =
-            PgEventManagerRelationRepository::new(PoolHandler::new(Arc::new(pool)));
-
-        let result = repository
-            .list_managers_events(3)
-            .await
-            .expect("Repository call should succeed");
-        assert_eq!(result.len(), 1);
-
-        repository
-            .delete_relation(result[0].clone())
-            .await
-            .expect("Repository call should succeed");
-
-        let result = repository
-            .list_managers_events(3)
-            .await
-            .expect("Repository call should succeed");
-        assert_eq!(result.len(), 0);
-
-        repository.pool_handler.disconnect().await;
-        Ok(())
+    database_test! {
+        #[fixtures("event_manager_relation")]
+        async fn test_update_permissions(database: Database) -> Result<()> {
+            let repository = database.repositories().event_manager_relation;
+
+            let permissions = EventManagerPermissions {
+                can_manage_jobs: true,
+                can_view_payroll: true,
+                ..EventManagerPermissions::default()
+            };
+            let result = repository
+                .update_permissions(2, 3, permissions)
+                .await
+                .expect("Repository call should succeed");
+            assert_eq!(result.permissions(), permissions);
+
+            let result = repository
+                .get_relation(2, 3)
+                .await
+                .expect("Repository call should succeed");
+            assert!(result.can_manage_jobs);
+            assert!(result.can_view_payroll);
+            assert!(!result.can_edit_event);
+            assert!(!result.can_manage_managers);
+
+            let result = repository.update_permissions(1, 3, permissions).await;
+            assert!(matches!(result, Err(RepositoryError::NotFound)));
+
+            database.close().await;
+            Ok(())
+        }
     }
 }
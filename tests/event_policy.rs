@@ -0,0 +1,246 @@
+#[cfg(test)]
+pub mod event_policy_tests {
+    use anyhow::Result;
+    use sqlx::PgPool;
+    use std::sync::Arc;
+
+    use pv281_giglog::error::RepositoryError;
+    use pv281_giglog::models::event_manager_relation::EventManagerPermissions;
+    use pv281_giglog::models::organization::{CreateOrganizationMembership, OrganizationRole};
+    use pv281_giglog::models::user::UserRole;
+    use pv281_giglog::policy::{is_allowed, EventAction, EventPolicy, EventRelation, EventScope};
+    use pv281_giglog::repositories::organization::{
+        OrganizationRepository, PgOrganizationRepository,
+    };
+    use pv281_giglog::repositories::pool_handler::PoolHandler;
+    use pv281_giglog::repositories::user::{PgUserRepository, UserRepository};
+    use strum::IntoEnumIterator;
+
+    fn all_permissions() -> EventManagerPermissions {
+        EventManagerPermissions {
+            can_edit_event: true,
+            can_manage_jobs: true,
+            can_manage_applicants: true,
+            can_approve_hours: true,
+            can_view_payroll: true,
+            can_manage_managers: true,
+        }
+    }
+
+    /// The owner, a manager holding no permission, each single one or all of them and a stranger.
+    fn all_event_relations() -> Vec<EventRelation> {
+        let none = EventManagerPermissions::default();
+        vec![
+            EventRelation::Owner,
+            EventRelation::Manager(none),
+            EventRelation::Manager(EventManagerPermissions {
+                can_edit_event: true,
+                ..none
+            }),
+            EventRelation::Manager(EventManagerPermissions {
+                can_manage_jobs: true,
+                ..none
+            }),
+            EventRelation::Manager(EventManagerPermissions {
+                can_manage_applicants: true,
+                ..none
+            }),
+            EventRelation::Manager(EventManagerPermissions {
+                can_approve_hours: true,
+                ..none
+            }),
+            EventRelation::Manager(EventManagerPermissions {
+                can_view_payroll: true,
+                ..none
+            }),
+            EventRelation::Manager(EventManagerPermissions {
+                can_manage_managers: true,
+                ..none
+            }),
+            EventRelation::Manager(all_permissions()),
+            EventRelation::None,
+        ]
+    }
+
+    fn manager_may(permissions: &EventManagerPermissions, action: EventAction) -> bool {
+        match action {
+            EventAction::ViewDraft => true,
+            EventAction::EditEvent => permissions.can_edit_event,
+            EventAction::DeleteEvent => false,
+            EventAction::ManageJobs => permissions.can_manage_jobs,
+            EventAction::ManageApplicants => permissions.can_manage_applicants,
+            EventAction::ApproveHours => permissions.can_approve_hours,
+            EventAction::ViewPayroll => permissions.can_view_payroll,
+            EventAction::ManageManagers => permissions.can_manage_managers,
+        }
+    }
+
+    #[test]
+    fn test_is_allowed_matrix() {
+        for action in EventAction::iter() {
+            for relation in all_event_relations() {
+                assert!(
+                    is_allowed(&UserRole::Admin, relation, action),
+                    "admin, {:?}, {:?}",
+                    relation,
+                    action
+                );
+                let organizer_allowed = match relation {
+                    EventRelation::Owner => true,
+                    EventRelation::Manager(ref permissions) => manager_may(permissions, action),
+                    EventRelation::None => false,
+                };
+                assert_eq!(
+                    is_allowed(&UserRole::Organizer, relation, action),
+                    organizer_allowed,
+                    "organizer, {:?}, {:?}",
+                    relation,
+                    action
+                );
+                assert!(
+                    !is_allowed(&UserRole::Employee, relation, action),
+                    "employee, {:?}, {:?}",
+                    relation,
+                    action
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_manager_cannot_delete_event() {
+        let relation = EventRelation::Manager(all_permissions());
+        assert!(!is_allowed(
+            &UserRole::Organizer,
+            relation,
+            EventAction::DeleteEvent
+        ));
+        assert!(is_allowed(
+            &UserRole::Organizer,
+            relation,
+            EventAction::ManageManagers
+        ));
+    }
+
+    #[sqlx::test(fixtures("event_policy"))]
+    async fn test_relation(pool: PgPool) -> Result<()> {
+        let pool = Arc::new(pool);
+        let policy = EventPolicy::new(PoolHandler::new(pool.clone()));
+        let mut user_repository = PgUserRepository::new(PoolHandler::new(pool));
+
+        let event = policy.resolve_event(EventScope::Event(1)).await?;
+        let permissions = EventManagerPermissions {
+            can_manage_applicants: true,
+            can_approve_hours: true,
+            ..EventManagerPermissions::default()
+        };
+        let expected = [
+            (1, EventRelation::None),
+            (2, EventRelation::Owner),
+            (3, EventRelation::Manager(permissions)),
+            (4, EventRelation::None),
+            (5, EventRelation::Manager(permissions)),
+        ];
+        for (user_id, relation) in expected {
+            let user = user_repository.get_user_by_id(user_id).await?;
+            assert_eq!(policy.relation(&user, &event).await?, relation);
+        }
+
+        user_repository.pool_handler.disconnect().await;
+        Ok(())
+    }
+
+    #[sqlx::test(fixtures("event_policy"))]
+    async fn test_authorize_scopes(pool: PgPool) -> Result<()> {
+        let pool = Arc::new(pool);
+        let policy = EventPolicy::new(PoolHandler::new(pool.clone()));
+        let mut user_repository = PgUserRepository::new(PoolHandler::new(pool));
+
+        let scopes = [
+            EventScope::Event(1),
+            EventScope::JobPosition(1),
+            EventScope::Employment(1),
+        ];
+        for user_id in 1..=5 {
+            let user = user_repository.get_user_by_id(user_id).await?;
+            for scope in scopes {
+                for action in EventAction::iter() {
+                    // admin, owner, manager handling applicants and hours, unrelated organizer
+                    // and an employee listed as manager
+                    let allowed = match user_id {
+                        1 | 2 => true,
+                        3 => matches!(
+                            action,
+                            EventAction::ViewDraft
+                                | EventAction::ManageApplicants
+                                | EventAction::ApproveHours
+                        ),
+                        _ => false,
+                    };
+                    assert_eq!(
+                        policy.authorize(&user, scope, action).await?,
+                        allowed,
+                        "user {}, {:?}, {:?}",
+                        user_id,
+                        scope,
+                        action
+                    );
+                }
+            }
+        }
+
+        let owner = user_repository.get_user_by_id(2).await?;
+        for scope in [
+            EventScope::Event(999),
+            EventScope::JobPosition(999),
+            EventScope::Employment(999),
+        ] {
+            let result = policy
+                .authorize(&owner, scope, EventAction::EditEvent)
+                .await;
+            assert!(matches!(result, Err(RepositoryError::NotFound)));
+        }
+
+        user_repository.pool_handler.disconnect().await;
+        Ok(())
+    }
+
+    #[sqlx::test(fixtures("event_policy"))]
+    async fn test_organization_roles(pool: PgPool) -> Result<()> {
+        let pool = Arc::new(pool);
+        let policy = EventPolicy::new(PoolHandler::new(pool.clone()));
+        let organization_repository = PgOrganizationRepository::new(PoolHandler::new(pool.clone()));
+        let mut user_repository = PgUserRepository::new(PoolHandler::new(pool));
+
+        // An unrelated organizer promoted to owner of the organization may do anything.
+        organization_repository
+            .create_membership(CreateOrganizationMembership {
+                organization_id: 1,
+                user_id: 4,
+                role: OrganizationRole::Owner,
+            })
+            .await?;
+        let organization_owner = user_repository.get_user_by_id(4).await?;
+        for action in EventAction::iter() {
+            assert!(
+                policy
+                    .authorize(&organization_owner, EventScope::Event(1), action)
+                    .await?
+            );
+        }
+
+        // The event owner loses every right once they leave the organization.
+        organization_repository.delete_membership(1, 2).await?;
+        let event_owner = user_repository.get_user_by_id(2).await?;
+        for action in EventAction::iter() {
+            assert!(
+                !policy
+                    .authorize(&event_owner, EventScope::Event(1), action)
+                    .await?
+            );
+        }
+
+        user_repository.pool_handler.disconnect().await;
+        Ok(())
+    }
+}
@@ -1,320 +1,713 @@
+#[macro_use]
+mod common;
+
 #[cfg(test)]
 pub mod event_repo_tests {
     use anyhow::Result;
-    use sqlx::types::time::Date;
-    use sqlx::PgPool;
+    use sqlx::types::time::{Date, OffsetDateTime};
     use std::sync::Arc;
+    use time::Duration;
 
     use pv281_giglog::error::RepositoryError;
-    use pv281_giglog::models::event::{CreateEvent, PartialEvent, SelectManyFilter};
+    use pv281_giglog::models::employment::EmploymentState;
+    use pv281_giglog::models::event::{
+        CancelEvent, CreateEvent, EventStatus, ManagedBy, PartialEvent, SelectManyFilter,
+        SortColumn,
+    };
+    use pv281_giglog::models::pagination::{ListSpec, PagePosition, SortDirection};
+    use pv281_giglog::models::worked_hours;
+    use pv281_giglog::repositories::employment::EmploymentRepository;
     use pv281_giglog::repositories::event::EventRepository;
-    use pv281_giglog::repositories::event::PgEventRepository;
-    use pv281_giglog::repositories::pool_handler::PoolHandler;
+    use pv281_giglog::repositories::job_position::JobPositionRepository;
+    use pv281_giglog::repositories::notification::NotificationRepository;
+    use pv281_giglog::repositories::worked_hours::WorkedHoursRepository;
     use tower_sessions::cookie::time::Month;
 
-    #[sqlx::test(fixtures("events"))]
-    async fn test_create_event(pool: PgPool) -> Result<()> {
-        let mut repository = PgEventRepository::new(PoolHandler::new(Arc::new(pool)));
-
-        let new = CreateEvent {
-            name: "Superfest <3".to_string(),
-            date_start: Date::from_calendar_date(2025, Month::May, 13)?,
-            date_end: Date::from_calendar_date(2025, Month::May, 14)?,
-            img_url: "https://url.com".to_string(),
-            description: "Simple description".to_string(),
-            is_draft: true,
-            venue_id: 1,
-            owner_id: 1,
-        };
-
-        let event = repository
-            .create_event(new.clone())
-            .await
-            .expect("Repository call should succeed");
-
-        assert_eq!(event.name, new.name);
-        assert_eq!(event.date_start, new.date_start);
-        assert_eq!(event.date_end, new.date_end);
-        assert_eq!(event.img_url, new.img_url);
-        assert_eq!(event.description, event.description);
-        assert_eq!(event.is_draft, event.is_draft);
-        assert_eq!(event.venue_id, event.venue_id);
-        assert_eq!(event.owner_id, event.venue_id);
-
-        repository.pool_handler.disconnect().await;
-        Ok(())
+    database_test! {
+        #[fixtures("events")]
+        async fn test_create_event(database: Database) -> Result<()> {
+            let repository = database.repositories().event;
+
+            let new = CreateEvent {
+                name: "Superfest <3".to_string(),
+                date_start: Date::from_calendar_date(2025, Month::May, 13)?,
+                date_end: Date::from_calendar_date(2025, Month::May, 14)?,
+                img_url: "https://url.com".to_string(),
+                description: "Simple description".to_string(),
+                status: EventStatus::Draft,
+                venue_id: 1,
+                owner_id: 1,
+                organization_id: 1,
+                publish_at: None,
+                withdrawal_cutoff_hours: 48,
+            };
+
+            let event = repository
+                .create_event(new.clone())
+                .await
+                .expect("Repository call should succeed");
+
+            assert_eq!(event.name, new.name);
+            assert_eq!(event.date_start, new.date_start);
+            assert_eq!(event.date_end, new.date_end);
+            assert_eq!(event.img_url, new.img_url);
+            assert_eq!(event.description, event.description);
+            assert_eq!(event.status, new.status);
+            assert_eq!(event.venue_id, event.venue_id);
+            assert_eq!(event.owner_id, event.venue_id);
+
+            database.close().await;
+            Ok(())
+        }
+    }
+
+    database_test! {
+        #[fixtures("events")]
+        async fn test_list_events(database: Database) -> Result<()> {
+            let repository = database.repositories().event;
+
+            let empty = SelectManyFilter {
+                organization_id: None,
+                date_from: None,
+                date_to: None,
+                status: None,
+                venue_id: None,
+                owner_id: None,
+                city: None,
+                state: None,
+                name: None,
+                managed_by: None,
+            };
+
+            let all = repository
+                .list_events(empty)
+                .await
+                .expect("Repository call should succeed");
+            assert_eq!(all.len(), 4);
+
+            let filter = SelectManyFilter {
+                organization_id: None,
+                date_from: Some(Date::from_calendar_date(2025, Month::February, 1)?),
+                date_to: Some(Date::from_calendar_date(2025, Month::December, 31)?),
+                status: None,
+                venue_id: None,
+                owner_id: None,
+                city: None,
+                state: None,
+                name: None,
+                managed_by: None,
+            };
+
+            let events = repository
+                .list_events(filter)
+                .await
+                .expect("Repository call should succeed");
+
+            assert_eq!(events.len(), 3);
+
+            let names = vec![
+                "Rocky in 2025".to_string(),
+                "Feets for love".to_string(),
+                "Berlin Music Fest".to_string(),
+            ];
+            let event_names: Vec<String> = events.iter().map(|event| event.name.clone()).collect();
+            assert_eq!(names, event_names);
+
+            database.close().await;
+            Ok(())
+        }
+    }
+
+    database_test! {
+        #[fixtures("events")]
+        async fn test_event_by_id(database: Database) -> Result<()> {
+            let repository = database.repositories().event;
+
+            let event = repository
+                .get_event_by_id(2)
+                .await
+                .expect("Repository call should succeed");
+
+            assert_eq!(event.id, 2);
+            assert_eq!(event.name, "Rocky in 2025");
+            assert_eq!(
+                event.date_start,
+                Date::from_calendar_date(2025, Month::May, 25)?
+            );
+            assert_eq!(
+                event.date_end,
+                Date::from_calendar_date(2025, Month::May, 25)?
+            );
+            assert_eq!(event.img_url, "https://cdn.siteone.io/srv.siteone.cz/imgproxy/LhCs_LjiIr027zmUTqCgc-JgrB4-Dx33eI3QWyD0xoI/w:860/h:740/rt:fill/g:no:0:0/f:avif/q:70/aHR0cHM6Ly93d3cubmVrZGVuZWNvLmN6Ly9jbXMtYXNzZXRzL3JvY2staW4tMjAyNV8yMDI0LTExLTA1LTA3MzAwNl9vc3pxLmpwZw.avif");
+            assert_eq!(event.description, Some("Zažijte to nejlepší z domácí rockové scény v jeden den na jednom pódiu přímo u vás! Ve vašem městě, ve vašem amfiteátru se vystřídají zvučná jména, s důrazem na profesionální zázemí, špičkovou techniku a maximální komfort pro návštěvníky.".to_string()));
+            assert_eq!(event.status, EventStatus::Published);
+            assert_eq!(event.venue_id, 1);
+            assert_eq!(event.owner_id, 1);
+
+            let result = repository.get_event_by_id(999).await;
+
+            assert!(matches!(result, Err(RepositoryError::NotFound)));
+
+            database.close().await;
+            Ok(())
+        }
+    }
+
+    database_test! {
+        #[fixtures("events")]
+        async fn test_delete_event(database: Database) -> Result<()> {
+            let repository = database.repositories().event;
+
+            let empty_filter = SelectManyFilter {
+                organization_id: None,
+                date_from: None,
+                date_to: None,
+                status: None,
+                venue_id: None,
+                owner_id: None,
+                city: None,
+                state: None,
+                name: None,
+                managed_by: None,
+            };
+
+            let all = repository
+                .list_events(empty_filter)
+                .await
+                .expect("Repository call should succeed");
+            assert_eq!(all.len(), 4);
+            repository
+                .delete_event(1)
+                .await
+                .expect("Repository call should succeed");
+
+            let empty_filter = SelectManyFilter {
+                organization_id: None,
+                date_from: None,
+                date_to: None,
+                status: None,
+                venue_id: None,
+                owner_id: None,
+                city: None,
+                state: None,
+                name: None,
+                managed_by: None,
+            };
+
+            let all = repository
+                .list_events(empty_filter)
+                .await
+                .expect("Repository call should succeed");
+            assert_eq!(all.len(), 3);
+
+            let result = repository.delete_event(1).await;
+
+            assert!(matches!(result, Err(RepositoryError::NotFound)));
+
+            database.close().await;
+            Ok(())
+        }
+    }
+
+    database_test! {
+        #[fixtures("events")]
+        async fn test_update_event(database: Database) -> Result<()> {
+            let repository = database.repositories().event;
+
+            let old = repository
+                .get_event_by_id(1)
+                .await
+                .expect("Repository call should succeed");
+
+            let to_update = PartialEvent {
+                name: Some("Traunterberg".to_string()),
+                date_start: None,
+                date_end: None,
+                img_url: None,
+                description: Some("Superfest idk".to_string()),
+                venue_id: None,
+                owner_id: None,
+                publish_at: None,
+                withdrawal_cutoff_hours: None,
+            };
+
+            let updated = repository
+                .update_event(1, to_update.clone())
+                .await
+                .expect("Repository call should succeed");
+
+            assert_eq!(old.id, updated.id);
+            assert_eq!(to_update.name.clone().unwrap(), updated.name);
+            assert_eq!(old.date_start, updated.date_start);
+            assert_eq!(old.date_end, updated.date_end);
+            assert_eq!(old.img_url, updated.img_url);
+            assert_eq!(to_update.description.clone(), updated.description);
+            assert_eq!(old.status, updated.status);
+            assert_eq!(old.venue_id, updated.venue_id);
+            assert_eq!(old.owner_id, updated.owner_id);
+
+            let result = repository.update_event(999, to_update).await;
+
+            assert!(matches!(result, Err(RepositoryError::NotFound)));
+
+            database.close().await;
+            Ok(())
+        }
+    }
+
+    database_test! {
+        #[fixtures("events")]
+        async fn test_filter_event_by_name(database: Database) -> Result<()> {
+            let repository = database.repositories().event;
+
+            let mut filter = SelectManyFilter {
+                organization_id: None,
+                date_from: None,
+                date_to: None,
+                status: None,
+                venue_id: None,
+                owner_id: None,
+                city: None,
+                state: None,
+                name: Some(String::from("fest")),
+                managed_by: None,
+            };
+
+            let some = repository
+                .list_events(filter.clone())
+                .await
+                .expect("Repository call should succeed");
+            assert_eq!(some.len(), 2);
+
+            filter.name = Some(String::from("festival"));
+
+            let none = repository
+                .list_events(filter)
+                .await
+                .expect("Repository call should succeed");
+            assert_eq!(none.len(), 0);
+
+            database.close().await;
+            Ok(())
+        }
     }
 
-    #[sqlx::test(fixtures("events"))]
-    async fn test_list_events(pool: PgPool) -> Result<()> {
-        let mut repository = PgEventRepository::new(PoolHandler::new(Arc::new(pool)));
-
-        let empty = SelectManyFilter {
-            date_from: None,
-            date_to: None,
-            is_draft: None,
-            venue_id: None,
-            owner_id: None,
-            city: None,
-            state: None,
-            name: None,
-        };
-
-        let all = repository
-            .list_events(empty)
-            .await
-            .expect("Repository call should succeed");
-        assert_eq!(all.len(), 4);
-
-        let filter = SelectManyFilter {
-            date_from: Some(Date::from_calendar_date(2025, Month::February, 1)?),
-            date_to: Some(Date::from_calendar_date(2025, Month::December, 31)?),
-            is_draft: None,
-            venue_id: None,
-            owner_id: None,
-            city: None,
-            state: None,
-            name: None,
-        };
-
-        let events = repository
-            .list_events(filter)
-            .await
-            .expect("Repository call should succeed");
-
-        assert_eq!(events.len(), 3);
-
-        let names = vec!["Rocky in 2025".to_string(), "Feets for love".to_string(), "Berlin Music Fest".to_string()];
-        let event_names: Vec<String> = events.iter().map(|event| event.name.clone()).collect();
-        assert_eq!(names, event_names);
-
-        repository.pool_handler.disconnect().await;
-        Ok(())
+    database_test! {
+        #[fixtures("events")]
+        async fn test_filter_event_by_venue(database: Database) -> Result<()> {
+            let repository = database.repositories().event;
+
+            let mut filter = SelectManyFilter {
+                organization_id: None,
+                date_from: None,
+                date_to: None,
+                status: None,
+                venue_id: None,
+                owner_id: None,
+                city: Some(String::from("Plzeň")),
+                state: None,
+                name: None,
+                managed_by: None,
+            };
+
+            let some = repository
+                .list_events(filter.clone())
+                .await
+                .expect("Repository call should succeed");
+            assert_eq!(some.len(), 1);
+
+            filter.city = None;
+            filter.state = Some(String::from("Germany"));
+
+            let some = repository
+                .list_events(filter.clone())
+                .await
+                .expect("Repository call should succeed");
+            assert_eq!(some.len(), 1);
+
+            filter.state = Some(String::from("Česká republika"));
+
+            let some = repository
+                .list_events(filter)
+                .await
+                .expect("Repository call should succeed");
+            assert_eq!(some.len(), 3);
+
+            database.close().await;
+            Ok(())
+        }
     }
 
-    #[sqlx::test(fixtures("events"))]
-    async fn test_event_by_id(pool: PgPool) -> Result<()> {
-        let mut repository = PgEventRepository::new(PoolHandler::new(Arc::new(pool)));
-
-        let event = repository
-            .get_event_by_id(2)
-            .await
-            .expect("Repository call should succeed");
-
-        assert_eq!(event.id, 2);
-        assert_eq!(event.name, "Rocky in 2025");
-        assert_eq!(
-            event.date_start,
-            Date::from_calendar_date(2025, Month::May, 25)?
-        );
-        assert_eq!(
-            event.date_end,
-            Date::from_calendar_date(2025, Month::May, 25)?
-        );
-        assert_eq!(event.img_url, "https://cdn.siteone.io/srv.siteone.cz/imgproxy/LhCs_LjiIr027zmUTqCgc-JgrB4-Dx33eI3QWyD0xoI/w:860/h:740/rt:fill/g:no:0:0/f:avif/q:70/aHR0cHM6Ly93d3cubmVrZGVuZWNvLmN6Ly9jbXMtYXNzZXRzL3JvY2staW4tMjAyNV8yMDI0LTExLTA1LTA3MzAwNl9vc3pxLmpwZw.avif");
-        assert_eq!(event.description, Some("Zažijte to nejlepší z domácí rockové scény v jeden den na jednom pódiu přímo u vás! Ve vašem městě, ve vašem amfiteátru se vystřídají zvučná jména, s důrazem na profesionální zázemí, špičkovou techniku a maximální komfort pro návštěvníky.".to_string()));
-        assert!(!event.is_draft);
-        assert_eq!(event.venue_id, 1);
-        assert_eq!(event.owner_id, 1);
-
-        let result = repository.get_event_by_id(999).await;
-
-        assert!(matches!(result, Err(RepositoryError::NotFound)));
-
-        repository.pool_handler.disconnect().await;
-        Ok(())
+    database_test! {
+        #[fixtures("events")]
+        async fn test_filter_event_by_draft(database: Database) -> Result<()> {
+            let repository = database.repositories().event;
+
+            let filter = SelectManyFilter {
+                organization_id: None,
+                date_from: None,
+                date_to: None,
+                status: Some(EventStatus::Draft),
+                venue_id: None,
+                owner_id: None,
+                city: None,
+                state: None,
+                name: None,
+                managed_by: None,
+            };
+
+            let some = repository
+                .list_events(filter.clone())
+                .await
+                .expect("Repository call should succeed");
+            assert_eq!(some.len(), 1);
+
+            database.close().await;
+            Ok(())
+        }
+    }
+
+    database_test! {
+        #[fixtures("events")]
+        async fn test_restore_event(database: Database) -> Result<()> {
+            let repository = database.repositories().event;
+
+            repository
+                .delete_event(1)
+                .await
+                .expect("Repository call should succeed");
+
+            let deleted = repository
+                .list_deleted_events(1, &ListSpec::default())
+                .await
+                .expect("Repository call should succeed")
+                .items;
+            assert_eq!(deleted.len(), 1);
+            assert_eq!(deleted[0].id, 1);
+
+            let result = repository.restore_event(1, 2).await;
+            assert!(matches!(result, Err(RepositoryError::NotFound)));
+
+            let restored = repository
+                .restore_event(1, 1)
+                .await
+                .expect("Repository call should succeed");
+            assert_eq!(restored.id, 1);
+            assert!(repository.get_event_by_id(1).await.is_ok());
+
+            let result = repository.restore_event(1, 1).await;
+            assert!(matches!(result, Err(RepositoryError::NotFound)));
+
+            database.close().await;
+            Ok(())
+        }
+    }
+
+    database_test! {
+        #[fixtures("events")]
+        async fn test_purge_event(database: Database) -> Result<()> {
+            let repository = database.repositories().event;
+
+            let result = repository.purge_event(1, 1).await;
+            assert!(matches!(result, Err(RepositoryError::NotFound)));
+
+            repository
+                .delete_event(1)
+                .await
+                .expect("Repository call should succeed");
+            repository
+                .purge_event(1, 1)
+                .await
+                .expect("Repository call should succeed");
+
+            let result = repository.restore_event(1, 1).await;
+            assert!(matches!(result, Err(RepositoryError::NotFound)));
+
+            database.close().await;
+            Ok(())
+        }
+    }
+
+    database_test! {
+        #[fixtures("worked_hours")]
+        async fn test_purge_event_with_worked_hours_is_blocked(database: Database) -> Result<()> {
+            let repository = database.repositories().event;
+
+            repository
+                .delete_event(1)
+                .await
+                .expect("Repository call should succeed");
+
+            let result = repository.purge_event(1, 2).await;
+            assert!(matches!(result, Err(RepositoryError::NotFound)));
+
+            let result = repository.purge_event(1, 1).await;
+            assert!(matches!(result, Err(RepositoryError::GenericError(_))));
+
+            let deleted = repository
+                .list_deleted_events(1, &ListSpec::default())
+                .await
+                .expect("Repository call should succeed")
+                .items;
+            assert_eq!(deleted.len(), 1);
+
+            database.close().await;
+            Ok(())
+        }
     }
 
-    #[sqlx::test(fixtures("events"))]
-    async fn test_delete_event(pool: PgPool) -> Result<()> {
-        let mut repository = PgEventRepository::new(PoolHandler::new(Arc::new(pool)));
-
-        let empty_filter = SelectManyFilter {
-            date_from: None,
-            date_to: None,
-            is_draft: None,
-            venue_id: None,
-            owner_id: None,
-            city: None,
-            state: None,
-            name: None,
-        };
-
-        let all = repository
-            .list_events(empty_filter)
-            .await
-            .expect("Repository call should succeed");
-        assert_eq!(all.len(), 4);
-        repository
-            .delete_event(1)
-            .await
-            .expect("Repository call should succeed");
-
-        let empty_filter = SelectManyFilter {
-            date_from: None,
-            date_to: None,
-            is_draft: None,
-            venue_id: None,
-            owner_id: None,
-            city: None,
-            state: None,
-            name: None,
-        };
-
-        let all = repository
-            .list_events(empty_filter)
-            .await
-            .expect("Repository call should succeed");
-        assert_eq!(all.len(), 3);
-
-        let result = repository.delete_event(1).await;
-
-        assert!(matches!(result, Err(RepositoryError::NotFound)));
-
-        repository.pool_handler.disconnect().await;
-        Ok(())
+    database_test! {
+        #[fixtures("employment")]
+        async fn test_cancel_event(database: Database) -> Result<()> {
+            let repository = database.repositories().event;
+            let employment_repository = database.repositories().employment;
+            let job_position_repository = database.repositories().job_position;
+            let worked_hours_repository = database.repositories().worked_hours;
+            let notification_repository = database.repositories().notification;
+
+            let event = repository
+                .cancel_event(
+                    1,
+                    CancelEvent {
+                        reason: "Storm warning".to_string(),
+                        compensation_hours: Some(4.0),
+                    },
+                )
+                .await
+                .expect("Repository call should succeed");
+            assert!(event.is_cancelled());
+            assert_eq!(event.cancellation_reason, Some("Storm warning".to_string()));
+
+            let job_position = job_position_repository
+                .get_job_position_by_id(1)
+                .await
+                .expect("Repository call should succeed");
+            assert!(!job_position.is_opened_for_registration);
+
+            let pending = employment_repository.get_employment_by_id(1).await?;
+            let rejected = employment_repository.get_employment_by_id(2).await?;
+            let accepted = employment_repository.get_employment_by_id(3).await?;
+            assert_eq!(pending.state, EmploymentState::Cancelled);
+            assert_eq!(rejected.state, EmploymentState::Rejected);
+            assert_eq!(accepted.state, EmploymentState::Cancelled);
+
+            let compensation = worked_hours_repository
+                .list_worked_hours(worked_hours::SelectManyFilter {
+                    hours_worked: None,
+                    date: None,
+                    employment_id: Some(3),
+                })
+                .await
+                .expect("Repository call should succeed");
+            assert_eq!(compensation.len(), 1);
+            assert_eq!(compensation[0].hours_worked, 4.0);
+            assert_eq!(compensation[0].date, event.date_start);
+
+            for user_id in [1, 3] {
+                let notifications = notification_repository
+                    .list_notifications(user_id)
+                    .await
+                    .expect("Repository call should succeed");
+                assert_eq!(notifications.len(), 1);
+            }
+            let notifications = notification_repository
+                .list_notifications(2)
+                .await
+                .expect("Repository call should succeed");
+            assert!(notifications.is_empty());
+
+            let result = repository
+                .cancel_event(
+                    1,
+                    CancelEvent {
+                        reason: "Again".to_string(),
+                        compensation_hours: None,
+                    },
+                )
+                .await;
+            assert!(matches!(result, Err(RepositoryError::GenericError(_))));
+
+            database.close().await;
+            Ok(())
+        }
     }
 
-    #[sqlx::test(fixtures("events"))]
-    async fn test_update_event(pool: PgPool) -> Result<()> {
-        let mut repository = PgEventRepository::new(PoolHandler::new(Arc::new(pool)));
-
-        let old = repository
-            .get_event_by_id(1)
-            .await
-            .expect("Repository call should succeed");
-
-        let to_update = PartialEvent {
-            name: Some("Traunterberg".to_string()),
-            date_start: None,
-            date_end: None,
-            img_url: None,
-            description: Some("Superfest idk".to_string()),
-            is_draft: Some(true),
-            venue_id: None,
-            owner_id: None,
-        };
-
-        let updated = repository
-            .update_event(1, to_update.clone())
-            .await
-            .expect("Repository call should succeed");
-
-        assert_eq!(old.id, updated.id);
-        assert_eq!(to_update.name.clone().unwrap(), updated.name);
-        assert_eq!(old.date_start, updated.date_start);
-        assert_eq!(old.date_end, updated.date_end);
-        assert_eq!(old.img_url, updated.img_url);
-        assert_eq!(to_update.description.clone(), updated.description);
-        assert_eq!(to_update.is_draft.unwrap(), updated.is_draft);
-        assert_eq!(old.venue_id, updated.venue_id);
-        assert_eq!(old.owner_id, updated.owner_id);
-
-        let result = repository.update_event(999, to_update).await;
-
-        assert!(matches!(result, Err(RepositoryError::NotFound)));
-
-        repository.pool_handler.disconnect().await;
-        Ok(())
+    database_test! {
+        #[fixtures("events")]
+        async fn test_transition_event(database: Database) -> Result<()> {
+            let repository = database.repositories().event;
+
+            let event = repository
+                .transition_event(4, EventStatus::Published)
+                .await
+                .expect("Repository call should succeed");
+            assert_eq!(event.status, EventStatus::Published);
+
+            let event = repository
+                .transition_event(4, EventStatus::RegistrationClosed)
+                .await
+                .expect("Repository call should succeed");
+            assert_eq!(event.status, EventStatus::RegistrationClosed);
+
+            let result = repository.transition_event(4, EventStatus::Archived).await;
+            assert!(matches!(result, Err(RepositoryError::GenericError(_))));
+
+            let result = repository.transition_event(4, EventStatus::Cancelled).await;
+            assert!(matches!(result, Err(RepositoryError::GenericError(_))));
+
+            let result = repository
+                .transition_event(999, EventStatus::Published)
+                .await;
+            assert!(matches!(result, Err(RepositoryError::NotFound)));
+
+            database.close().await;
+            Ok(())
+        }
     }
 
-    #[sqlx::test(fixtures("events"))]
-    async fn test_filter_event_by_name(pool: PgPool) -> Result<()> {
-        let mut repository = PgEventRepository::new(PoolHandler::new(Arc::new(pool)));
-
-        let mut filter = SelectManyFilter {
-            date_from: None,
-            date_to: None,
-            is_draft: None,
-            venue_id: None,
-            owner_id: None,
-            city: None,
-            state: None,
-            name: Some(String::from("fest")),
-        };
-
-        let some = repository
-            .list_events(filter.clone())
-            .await
-            .expect("Repository call should succeed");
-        assert_eq!(some.len(), 2);
-
-        filter.name = Some(String::from("festival"));
-
-        let none = repository
-            .list_events(filter)
-            .await
-            .expect("Repository call should succeed");
-        assert_eq!(none.len(), 0);
-
-        repository.pool_handler.disconnect().await;
-        Ok(())
+    database_test! {
+        #[fixtures("events")]
+        async fn test_advance_event_statuses(database: Database) -> Result<()> {
+            let repository = database.repositories().event;
+
+            let advanced = repository
+                .advance_event_statuses(Date::from_calendar_date(2025, Month::July, 3)?)
+                .await
+                .expect("Repository call should succeed");
+            let advanced: Vec<(i32, EventStatus)> = advanced
+                .iter()
+                .map(|event| (event.id, event.status))
+                .collect();
+            assert_eq!(
+                advanced,
+                vec![
+                    (1, EventStatus::Completed),
+                    (2, EventStatus::Completed),
+                    (3, EventStatus::InProgress),
+                ]
+            );
+
+            let draft = repository
+                .get_event_by_id(4)
+                .await
+                .expect("Repository call should succeed");
+            assert_eq!(draft.status, EventStatus::Draft);
+
+            database.close().await;
+            Ok(())
+        }
     }
 
-    #[sqlx::test(fixtures("events"))]
-    async fn test_filter_event_by_venue(pool: PgPool) -> Result<()> {
-        let mut repository = PgEventRepository::new(PoolHandler::new(Arc::new(pool)));
-
-        let mut filter = SelectManyFilter {
-            date_from: None,
-            date_to: None,
-            is_draft: None,
-            venue_id: None,
-            owner_id: None,
-            city: Some(String::from("Plzeň")),
-            state: None,
-            name: None,
-        };
-
-        let some = repository
-            .list_events(filter.clone())
-            .await
-            .expect("Repository call should succeed");
-        assert_eq!(some.len(), 1);
-
-        filter.city = None;
-        filter.state = Some(String::from("Germany"));
-
-        let some = repository
-            .list_events(filter.clone())
-            .await
-            .expect("Repository call should succeed");
-        assert_eq!(some.len(), 1);
-
-        filter.state = Some(String::from("Česká republika"));
-
-        let some = repository
-            .list_events(filter)
-            .await
-            .expect("Repository call should succeed");
-        assert_eq!(some.len(), 3);
-
-        repository.pool_handler.disconnect().await;
-        Ok(())
+    database_test! {
+        #[fixtures("events")]
+        async fn test_publish_scheduled_events(database: Database) -> Result<()> {
+            let repository = database.repositories().event;
+
+            let now = OffsetDateTime::now_utc();
+            let schedule = PartialEvent {
+                name: None,
+                date_start: None,
+                date_end: None,
+                img_url: None,
+                description: None,
+                venue_id: None,
+                owner_id: None,
+                publish_at: Some(Some(now + Duration::hours(1))),
+                withdrawal_cutoff_hours: None,
+            };
+            repository
+                .update_event(4, schedule)
+                .await
+                .expect("Repository call should succeed");
+
+            let published = repository
+                .publish_scheduled_events(now)
+                .await
+                .expect("Repository call should succeed");
+            assert!(published.is_empty());
+
+            let published = repository
+                .publish_scheduled_events(now + Duration::hours(2))
+                .await
+                .expect("Repository call should succeed");
+            assert_eq!(published.len(), 1);
+            assert_eq!(published[0].id, 4);
+            assert_eq!(published[0].status, EventStatus::Published);
+            assert_eq!(published[0].publish_at, None);
+
+            database.close().await;
+            Ok(())
+        }
     }
 
-    #[sqlx::test(fixtures("events"))]
-    async fn test_filter_event_by_draft(pool: PgPool) -> Result<()> {
-        let mut repository = PgEventRepository::new(PoolHandler::new(Arc::new(pool)));
-
-        let filter = SelectManyFilter {
-            date_from: None,
-            date_to: None,
-            is_draft: Some(true),
-            venue_id: None,
-            owner_id: None,
-            city: None,
-            state: None,
-            name: None,
-        };
-
-        let some = repository
-            .list_events(filter.clone())
-            .await
-            .expect("Repository call should succeed");
-        assert_eq!(some.len(), 1);
-
-        repository.pool_handler.disconnect().await;
-        Ok(())
+    database_test! {
+        #[fixtures("events")]
+        async fn test_list_events_page(database: Database) -> Result<()> {
+            let repository = database.repositories().event;
+
+            let filter = SelectManyFilter {
+                organization_id: Some(1),
+                date_from: None,
+                date_to: None,
+                status: None,
+                venue_id: None,
+                owner_id: None,
+                city: None,
+                state: None,
+                name: None,
+                managed_by: None,
+            };
+            let mut spec = ListSpec {
+                sort_by: SortColumn::DateStart,
+                sort_direction: SortDirection::Desc,
+                page_size: 3,
+                position: PagePosition::Page(1),
+            };
+
+            let first = repository
+                .list_events_page(filter.clone(), &spec)
+                .await
+                .expect("Repository call should succeed");
+            let ids: Vec<i32> = first.items.iter().map(|event| event.id).collect();
+            assert_eq!(ids, vec![4, 3, 2]);
+            assert_eq!(first.total, 4);
+            assert_eq!(first.next_cursor, Some(2));
+
+            spec.position = PagePosition::After(2);
+            let rest = repository.list_events_page(filter.clone(), &spec).await?;
+            let ids: Vec<i32> = rest.items.iter().map(|event| event.id).collect();
+            assert_eq!(ids, vec![1]);
+            assert_eq!(rest.next_cursor, None);
+
+            spec.position = PagePosition::Page(1);
+            let drafts = SelectManyFilter {
+                status: Some(EventStatus::Draft),
+                ..filter.clone()
+            };
+            assert_eq!(repository.list_events_page(drafts, &spec).await?.total, 1);
+
+            let owned = SelectManyFilter {
+                managed_by: Some(ManagedBy {
+                    user_id: 1,
+                    actions: Vec::new(),
+                }),
+                ..filter.clone()
+            };
+            assert_eq!(repository.list_events_page(owned, &spec).await?.total, 4);
+
+            let foreign = SelectManyFilter {
+                managed_by: Some(ManagedBy {
+                    user_id: 999,
+                    actions: Vec::new(),
+                }),
+                ..filter
+            };
+            let nothing = repository.list_events_page(foreign, &spec).await?;
+            assert!(nothing.items.is_empty());
+            assert_eq!(nothing.total, 0);
+
+            database.close().await;
+            Ok(())
+        }
     }
 }
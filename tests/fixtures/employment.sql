@@ -19,8 +19,8 @@ VALUES
 
 SELECT setval(pg_get_serial_sequence('"user"', 'id'), MAX("id")) FROM "user";
 
-INSERT INTO "venue" ("id", "name", "state", "postal_code", "town", "street_name", "street_number", "description") VALUES
-    (1, 'Amfiteátr Jihlava', 'Česká republika','586 01','Jihlava','Březinovy sady','4733/16', 'V malebném údolí řeky Jihlavy v těsném sousedství ze zologickou zahradou přímo v centru Jihlavy se nachází areál Amfiteátru a parku Malý Heulos, který je místem odpočinku, relaxace a zábavy pro celou rodinu. Areál je ideální pro pořádání venkovních kulturních a společenských akcí. Zázemí pod plátnem umožňuje pořádat celoročně klubovou a schůzovní činnost. Součástí areálu je moderní dětské hřiště se spoustou atrakcí.');
+INSERT INTO "venue" ("id", "name", "state", "postal_code", "town", "street_name", "street_number", "description", "organization_id") VALUES
+    (1, 'Amfiteátr Jihlava', 'Česká republika','586 01','Jihlava','Březinovy sady','4733/16', 'V malebném údolí řeky Jihlavy v těsném sousedství ze zologickou zahradou přímo v centru Jihlavy se nachází areál Amfiteátru a parku Malý Heulos, který je místem odpočinku, relaxace a zábavy pro celou rodinu. Areál je ideální pro pořádání venkovních kulturních a společenských akcí. Zázemí pod plátnem umožňuje pořádat celoročně klubovou a schůzovní činnost. Součástí areálu je moderní dětské hřiště se spoustou atrakcí.', 1);
 SELECT setval(pg_get_serial_sequence('"venue"', 'id'), MAX("id")) FROM "venue";
 
 INSERT INTO "event" (
@@ -30,9 +30,10 @@ INSERT INTO "event" (
     "date_end",
     "img_url",
     "description",
-    "is_draft",
+    "status",
     "venue_id",
-    "owner_id"
+    "owner_id",
+    "organization_id"
 )
 VALUES
     (
@@ -42,7 +43,8 @@ VALUES
         '1/2/2026',
         'https://ticketstream-images.s3.eu-central-1.amazonaws.com/event/2024/08/azeib1c6b6_himlhergotfest2025-1080x1080.png',
         'Echtšlágrgruppe TRAUTENBERK slibuje playlist plný osvědčených pecek i nových songů, koncert plný nasazení a potu. Aničku svůdnější víc, než kdy jindy, Zemského radu přísnějšího, než kdy jindy a zbytek kapely hlasitější, než kdy jindy! V Čechách stále stoupající hvězda a festivalová stálice nenechá tvoji taneční kyčel v klidu a vykouzlí Ti dlouhý úsměv na tváři. Tak doraž na koncert!',
-        False,
+        'published',
+        1,
         1,
         1
     ),
@@ -53,14 +55,15 @@ VALUES
         '1/2/2024',
         'https://ticketstream-images.s3.eu-central-1.amazonaws.com/event/2024/08/azeib1c6b6_himlhergotfest2025-1080x1080.png',
         'Echtšlágrgruppe TRAUTENBERK slibuje playlist plný osvědčených pecek i nových songů, koncert plný nasazení a potu. Aničku svůdnější víc, než kdy jindy, Zemského radu přísnějšího, než kdy jindy a zbytek kapely hlasitější, než kdy jindy! V Čechách stále stoupající hvězda a festivalová stálice nenechá tvoji taneční kyčel v klidu a vykouzlí Ti dlouhý úsměv na tváři. Tak doraž na koncert!',
-        False,
+        'published',
+        1,
         1,
         1
     );
 
 SELECT setval(pg_get_serial_sequence('"event"', 'id'), MAX("id")) FROM "event";
 
-INSERT INTO "position_category" ("id", "name") VALUES (1, 'Technická podpora');
+INSERT INTO "position_category" ("id", "name", "organization_id") VALUES (1, 'Technická podpora', 1);
 
 SELECT setval(pg_get_serial_sequence('"position_category"', 'id'), MAX("id")) FROM "position_category";
 
@@ -68,7 +71,8 @@ INSERT INTO "job_position" (
     "id", "event_id", "position_category_id", "salary", "currency", "capacity", "name", "description", "is_opened_for_registration", "instructions_html")
 VALUES
     (1,1, 1, 150, 'CZK', 2, 'Stánek s hotdogy', 'Prodej hotdogů a dalších rychlých jídel návštěvníkům', True, 'Co vznikne zkřížením komára a mouchy? - Komouš.'),
-    (2,2, 1, 140, 'CZK', 5, 'Stánek s hotdogy', 'Prodej hotdogů a dalších rychlých jídel návštěvníkům', True, 'Co vznikne zkřížením komára a mouchy? - Komouš.');
+    (2,2, 1, 140, 'CZK', 5, 'Stánek s hotdogy', 'Prodej hotdogů a dalších rychlých jídel návštěvníkům', True, 'Co vznikne zkřížením komára a mouchy? - Komouš.'),
+    (3,1, 1, 160, 'CZK', 3, 'Kontrola vstupenek', 'Ověření platnosti vstupenek a náramků při vstupu do areálu', False, '„Nauč se radovat z toho, co máš.“- „Hurá, mám depresi!“');
 
 SELECT setval(pg_get_serial_sequence('"job_position"', 'id'), MAX("id")) FROM "job_position";
 
@@ -18,8 +18,8 @@ VALUES
 
 SELECT setval(pg_get_serial_sequence('"user"', 'id'), MAX("id")) FROM "user";
 
-INSERT INTO "venue" ("id", "name", "state", "postal_code", "town", "street_name", "street_number", "description") VALUES
-    (1, 'Amfiteátr Jihlava', 'Česká republika','586 01','Jihlava','Březinovy sady','4733/16', 'V malebném údolí řeky Jihlavy v těsném sousedství ze zologickou zahradou přímo v centru Jihlavy se nachází areál Amfiteátru a parku Malý Heulos, který je místem odpočinku, relaxace a zábavy pro celou rodinu. Areál je ideální pro pořádání venkovních kulturních a společenských akcí. Zázemí pod plátnem umožňuje pořádat celoročně klubovou a schůzovní činnost. Součástí areálu je moderní dětské hřiště se spoustou atrakcí.');
+INSERT INTO "venue" ("id", "name", "state", "postal_code", "town", "street_name", "street_number", "description", "organization_id") VALUES
+    (1, 'Amfiteátr Jihlava', 'Česká republika','586 01','Jihlava','Březinovy sady','4733/16', 'V malebném údolí řeky Jihlavy v těsném sousedství ze zologickou zahradou přímo v centru Jihlavy se nachází areál Amfiteátru a parku Malý Heulos, který je místem odpočinku, relaxace a zábavy pro celou rodinu. Areál je ideální pro pořádání venkovních kulturních a společenských akcí. Zázemí pod plátnem umožňuje pořádat celoročně klubovou a schůzovní činnost. Součástí areálu je moderní dětské hřiště se spoustou atrakcí.', 1);
 SELECT setval(pg_get_serial_sequence('"venue"', 'id'), MAX("id")) FROM "venue";
 
 INSERT INTO "event" (
@@ -29,9 +29,10 @@ INSERT INTO "event" (
     "date_end",
     "img_url",
     "description",
-    "is_draft",
+    "status",
     "venue_id",
-    "owner_id"
+    "owner_id",
+    "organization_id"
 )
 VALUES
     (
@@ -41,7 +42,8 @@ VALUES
         '1/2/2025',
         'https://ticketstream-images.s3.eu-central-1.amazonaws.com/event/2024/08/azeib1c6b6_himlhergotfest2025-1080x1080.png',
         'Echtšlágrgruppe TRAUTENBERK slibuje playlist plný osvědčených pecek i nových songů, koncert plný nasazení a potu. Aničku svůdnější víc, než kdy jindy, Zemského radu přísnějšího, než kdy jindy a zbytek kapely hlasitější, než kdy jindy! V Čechách stále stoupající hvězda a festivalová stálice nenechá tvoji taneční kyčel v klidu a vykouzlí Ti dlouhý úsměv na tváři. Tak doraž na koncert!',
-        False,
+        'published',
+        1,
         1,
         1
     ),
@@ -52,9 +54,10 @@ VALUES
         '5/25/2025',
         'https://cdn.siteone.io/srv.siteone.cz/imgproxy/LhCs_LjiIr027zmUTqCgc-JgrB4-Dx33eI3QWyD0xoI/w:860/h:740/rt:fill/g:no:0:0/f:avif/q:70/aHR0cHM6Ly93d3cubmVrZGVuZWNvLmN6Ly9jbXMtYXNzZXRzL3JvY2staW4tMjAyNV8yMDI0LTExLTA1LTA3MzAwNl9vc3pxLmpwZw.avif',
         'Zažijte to nejlepší z domácí rockové scény v jeden den na jednom pódiu přímo u vás! Ve vašem městě, ve vašem amfiteátru se vystřídají zvučná jména, s důrazem na profesionální zázemí, špičkovou techniku a maximální komfort pro návštěvníky.',
-        False,
+        'published',
         1,
-        2
+        2,
+        1
     ),
     (
         3,
@@ -63,9 +66,10 @@ VALUES
         '7/5/2025',
         'https://i3.cn.cz/1720345702_PULKRABEK_JAN-240707-004325-_BMD3310_UVODNI.JPG',
         'Beats for Love je festival elektronické taneční hudby pořádaný v jádru industriální národní kulturní památky. Prostředí Dolních Vítkovic plné železných kulis se na čtyři dny zaplní spoustou vynikající hudby a bohatého doprovodného programu. Vzniká tak jedinečná atmosféra plná zábavy a zážitků.',
-        False,
+        'published',
         1,
-        3
+        3,
+        1
     );
 
 SELECT setval(pg_get_serial_sequence('"event"', 'id'), MAX("id")) FROM "event";
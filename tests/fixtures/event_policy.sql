@@ -0,0 +1,79 @@
+DELETE FROM "event_manager_relation";
+DELETE FROM "worked_hours";
+DELETE FROM "employment";
+DELETE FROM "job_position";
+DELETE FROM "event";
+DELETE FROM "position_category";
+DELETE FROM "user";
+DELETE FROM "venue";
+
+
+INSERT INTO "user"
+("id", "first_name", "last_name", "gender", "birth_date", "username", "email", "phone", "role", "tax_rate", "password_hash")
+VALUES
+    (1,'Josefka','Buba','female','4/11/2001','pepe232','joko@nba.com','7151703730','admin',0.15,'$argon2d$v=19$m=12,t=3,p=1$ZXk0ajEzc2k2Zm0wMDAwMA$cMhkzibYVqUSgrkoZ9BP8w'),
+    (2,'Radek','Srejch','male','5/12/2000','brember','brember@mail.com','2212605075','organizer',0.15,'$argon2d$v=19$m=12,t=3,p=1$ZXk0ajEzc2k2Zm0wMDAwMA$cMhkzibYVqUSgrkoZ9BP8w'),
+    (3,'Lukáš','Nadvojský','female','7/31/2001','lasicak','lasicak@mail.com','6284663453','organizer',0.15,'$argon2d$v=19$m=12,t=3,p=1$ZXk0ajEzc2k2Zm0wMDAwMA$cMhkzibYVqUSgrkoZ9BP8w'),
+    (4,'Michal','Uherácký','male','2/25/2003','fousek','michuh@mail.com','8302944138','organizer',0.15,'$argon2d$v=19$m=12,t=3,p=1$ZXk0ajEzc2k2Zm0wMDAwMA$cMhkzibYVqUSgrkoZ9BP8w'),
+    (5,'František','Nvák','male','9/13/2002','fnvak','nvah@mail.com','5499736442','employee',0.15,'$argon2d$v=19$m=12,t=3,p=1$ZXk0ajEzc2k2Zm0wMDAwMA$cMhkzibYVqUSgrkoZ9BP8w');
+
+SELECT setval(pg_get_serial_sequence('"user"', 'id'), MAX("id")) FROM "user";
+
+INSERT INTO "organization_membership" ("organization_id", "user_id", "role") VALUES
+    (1, 1, 'owner'),
+    (1, 2, 'organizer'),
+    (1, 3, 'organizer'),
+    (1, 4, 'organizer'),
+    (1, 5, 'employee');
+
+INSERT INTO "venue" ("id", "name", "state", "postal_code", "town", "street_name", "street_number", "description", "organization_id") VALUES
+    (1, 'Amfiteátr Jihlava', 'Česká republika','586 01','Jihlava','Březinovy sady','4733/16', 'V malebném údolí řeky Jihlavy se nachází areál Amfiteátru a parku Malý Heulos.', 1);
+SELECT setval(pg_get_serial_sequence('"venue"', 'id'), MAX("id")) FROM "venue";
+
+INSERT INTO "event" (
+    "id",
+    "name",
+    "date_start",
+    "date_end",
+    "img_url",
+    "description",
+    "status",
+    "venue_id",
+    "owner_id",
+    "organization_id"
+)
+VALUES
+    (
+        1,
+        'HIMLHERGOTFEST',
+        '1/1/2030',
+        '1/2/2030',
+        'https://ticketstream-images.s3.eu-central-1.amazonaws.com/event/2024/08/azeib1c6b6_himlhergotfest2025-1080x1080.png',
+        'Echtšlágrgruppe TRAUTENBERK slibuje playlist plný osvědčených pecek i nových songů.',
+        'draft',
+        1,
+        2,
+        1
+    );
+
+SELECT setval(pg_get_serial_sequence('"event"', 'id'), MAX("id")) FROM "event";
+
+INSERT INTO "event_manager_relation" ("event_id", "user_id", "can_manage_applicants", "can_approve_hours") VALUES
+    (1, 3, True, True),
+    (1, 5, True, True);
+
+INSERT INTO "position_category" ("id", "name", "organization_id") VALUES (1, 'Technická podpora', 1);
+
+SELECT setval(pg_get_serial_sequence('"position_category"', 'id'), MAX("id")) FROM "position_category";
+
+INSERT INTO "job_position" (
+    "id", "event_id", "position_category_id", "salary", "currency", "capacity", "name", "description", "is_opened_for_registration", "instructions_html")
+VALUES
+    (1,1, 1, 150, 'CZK', 2, 'Stánek s hotdogy', 'Prodej hotdogů a dalších rychlých jídel návštěvníkům', True, 'Co vznikne zkřížením komára a mouchy? - Komouš.');
+
+SELECT setval(pg_get_serial_sequence('"job_position"', 'id'), MAX("id")) FROM "job_position";
+
+INSERT INTO "employment" ("id", "user_id", "position_id", "rating", "state") VALUES
+    (1, 5, 1, 0, 'pending');
+
+SELECT setval(pg_get_serial_sequence('"employment"', 'id'), MAX("id")) FROM "employment";
@@ -0,0 +1,6 @@
+INSERT INTO "employment" ("id", "user_id", "position_id", "rating", "state", "invitation_expires_at") VALUES
+    (4, 4, 1, 0, 'invited', '2030-01-01 00:00:00+00'),
+    (5, 5, 3, 0, 'invited', '2020-01-01 00:00:00+00'),
+    (6, 5, 1, 0, 'invited', '2030-01-01 00:00:00+00');
+
+SELECT setval(pg_get_serial_sequence('"employment"', 'id'), MAX("id")) FROM "employment";
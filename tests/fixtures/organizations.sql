@@ -0,0 +1,37 @@
+DELETE FROM "event_manager_relation";
+DELETE FROM "worked_hours";
+DELETE FROM "employment";
+DELETE FROM "job_position";
+DELETE FROM "event";
+DELETE FROM "position_category";
+DELETE FROM "user";
+DELETE FROM "venue";
+DELETE FROM "organization" WHERE "id" <> 1;
+
+INSERT INTO "organization" ("id", "name") VALUES
+    (2, 'Brno Events');
+
+SELECT setval(pg_get_serial_sequence('"organization"', 'id'), MAX("id")) FROM "organization";
+
+INSERT INTO "user"
+("id", "first_name", "last_name", "gender", "birth_date", "username", "email", "phone", "role", "tax_rate", "password_hash")
+VALUES
+    (1,'Josefka','Buba','female','4/11/2001','pepe232','joko@nba.com','7151703730','admin',0.15,'$argon2d$v=19$m=12,t=3,p=1$ZXk0ajEzc2k2Zm0wMDAwMA$cMhkzibYVqUSgrkoZ9BP8w'),
+    (2,'Radek','Srejch','male','5/12/2000','brember','brember@mail.com','2212605075','organizer',0.15,'$argon2d$v=19$m=12,t=3,p=1$ZXk0ajEzc2k2Zm0wMDAwMA$cMhkzibYVqUSgrkoZ9BP8w'),
+    (3,'František','Nvák','male','9/13/2002','fnvak','nvah@mail.com','5499736442','employee',0.15,'$argon2d$v=19$m=12,t=3,p=1$ZXk0ajEzc2k2Zm0wMDAwMA$cMhkzibYVqUSgrkoZ9BP8w');
+
+SELECT setval(pg_get_serial_sequence('"user"', 'id'), MAX("id")) FROM "user";
+
+INSERT INTO "organization_membership" ("organization_id", "user_id", "role") VALUES
+    (1, 1, 'owner'),
+    (1, 2, 'organizer'),
+    (2, 2, 'owner'),
+    (2, 3, 'employee');
+
+INSERT INTO "venue" ("id", "name", "state", "postal_code", "town", "street_name", "street_number", "description", "organization_id")
+VALUES
+    (1, 'Plzeň Plaza','Česká republika','301 00','Plzeň','Radčická','3', NULL, 1),
+    (2, 'Brněnské výstaviště', 'Česká republika','603 00','Brno','Výstaviště','405/1', NULL, 2),
+    (3, 'Katedrála', 'Česká republika', '301 00', 'Plzeň', 'Neew', '33', NULL, 2);
+
+SELECT setval(pg_get_serial_sequence('"venue"', 'id'), MAX("id")) FROM "venue";
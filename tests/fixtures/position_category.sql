@@ -7,9 +7,9 @@ DELETE FROM "position_category";
 DELETE FROM "user";
 DELETE FROM "venue";
 
-INSERT INTO "position_category" ("id", "name") VALUES
-    (1, 'Obsluha stánků'),
-    (2, 'Koordinace parkoviště'),
-    (3, 'Technická podpora');
+INSERT INTO "position_category" ("id", "name", "organization_id") VALUES
+    (1, 'Obsluha stánků', 1),
+    (2, 'Koordinace parkoviště', 1),
+    (3, 'Technická podpora', 1);
 
 SELECT setval(pg_get_serial_sequence('"position_category"', 'id'), MAX("id")) FROM "position_category";
@@ -0,0 +1,5 @@
+INSERT INTO "employment" ("id", "user_id", "position_id", "rating", "state") VALUES
+    (10, 2, 1, 0, 'done'),
+    (11, 5, 3, 0, 'done');
+
+SELECT setval(pg_get_serial_sequence('"employment"', 'id'), MAX("id")) FROM "employment";
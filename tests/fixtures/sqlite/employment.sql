@@ -0,0 +1,72 @@
+DELETE FROM "event_manager_relation";
+DELETE FROM "worked_hours";
+DELETE FROM "employment";
+DELETE FROM "job_position";
+DELETE FROM "event";
+DELETE FROM "position_category";
+DELETE FROM "user";
+DELETE FROM "venue";
+
+
+INSERT INTO "user"
+("id", "first_name", "last_name", "gender", "birth_date", "username", "email", "phone", "role", "tax_rate", "password_hash")
+VALUES
+    (1,'Josefka','Buba','female','2001-04-11','pepe232','joko@nba.com','7151703730','admin',0.15,'$argon2d$v=19$m=12,t=3,p=1$ZXk0ajEzc2k2Zm0wMDAwMA$cMhkzibYVqUSgrkoZ9BP8w'),
+    (2,'Radek','Srejch','male','2000-05-12','brember','brember@mail.com','2212605075','employee',0.15,'$argon2d$v=19$m=12,t=3,p=1$ZXk0ajEzc2k2Zm0wMDAwMA$cMhkzibYVqUSgrkoZ9BP8w'),
+    (3,'Lukáš','Nadvojský','female','2001-07-31','lasicak','lasicak@mail.com','6284663453','admin',0.15,'$argon2d$v=19$m=12,t=3,p=1$ZXk0ajEzc2k2Zm0wMDAwMA$cMhkzibYVqUSgrkoZ9BP8w'),
+    (4,'Michal','Uherácký','male','2003-02-25','fousek','michuh@mail.com','8302944138','organizer',0.15,'$argon2d$v=19$m=12,t=3,p=1$ZXk0ajEzc2k2Zm0wMDAwMA$cMhkzibYVqUSgrkoZ9BP8w'),
+    (5,'František','Nvák','male','2002-09-13','fnvak','nvah@mail.com','5499736442','employee',0.15,'$argon2d$v=19$m=12,t=3,p=1$ZXk0ajEzc2k2Zm0wMDAwMA$cMhkzibYVqUSgrkoZ9BP8w');
+
+INSERT INTO "venue" ("id", "name", "state", "postal_code", "town", "street_name", "street_number", "description", "organization_id") VALUES
+    (1, 'Amfiteátr Jihlava', 'Česká republika','586 01','Jihlava','Březinovy sady','4733/16', 'V malebném údolí řeky Jihlavy v těsném sousedství ze zologickou zahradou přímo v centru Jihlavy se nachází areál Amfiteátru a parku Malý Heulos, který je místem odpočinku, relaxace a zábavy pro celou rodinu. Areál je ideální pro pořádání venkovních kulturních a společenských akcí. Zázemí pod plátnem umožňuje pořádat celoročně klubovou a schůzovní činnost. Součástí areálu je moderní dětské hřiště se spoustou atrakcí.', 1);
+INSERT INTO "event" (
+    "id",
+    "name",
+    "date_start",
+    "date_end",
+    "img_url",
+    "description",
+    "status",
+    "venue_id",
+    "owner_id",
+    "organization_id"
+)
+VALUES
+    (
+        1,
+        'HIMLHERGOTFEST',
+        '2026-01-01',
+        '2026-01-02',
+        'https://ticketstream-images.s3.eu-central-1.amazonaws.com/event/2024/08/azeib1c6b6_himlhergotfest2025-1080x1080.png',
+        'Echtšlágrgruppe TRAUTENBERK slibuje playlist plný osvědčených pecek i nových songů, koncert plný nasazení a potu. Aničku svůdnější víc, než kdy jindy, Zemského radu přísnějšího, než kdy jindy a zbytek kapely hlasitější, než kdy jindy! V Čechách stále stoupající hvězda a festivalová stálice nenechá tvoji taneční kyčel v klidu a vykouzlí Ti dlouhý úsměv na tváři. Tak doraž na koncert!',
+        'published',
+        1,
+        1,
+        1
+    ),
+    (
+        2,
+        'HIMLHERGOTFEST',
+        '2024-01-01',
+        '2024-01-02',
+        'https://ticketstream-images.s3.eu-central-1.amazonaws.com/event/2024/08/azeib1c6b6_himlhergotfest2025-1080x1080.png',
+        'Echtšlágrgruppe TRAUTENBERK slibuje playlist plný osvědčených pecek i nových songů, koncert plný nasazení a potu. Aničku svůdnější víc, než kdy jindy, Zemského radu přísnějšího, než kdy jindy a zbytek kapely hlasitější, než kdy jindy! V Čechách stále stoupající hvězda a festivalová stálice nenechá tvoji taneční kyčel v klidu a vykouzlí Ti dlouhý úsměv na tváři. Tak doraž na koncert!',
+        'published',
+        1,
+        1,
+        1
+    );
+
+INSERT INTO "position_category" ("id", "name", "organization_id") VALUES (1, 'Technická podpora', 1);
+
+INSERT INTO "job_position" (
+    "id", "event_id", "position_category_id", "salary", "currency", "capacity", "name", "description", "is_opened_for_registration", "instructions_html")
+VALUES
+    (1,1, 1, 150, 'CZK', 2, 'Stánek s hotdogy', 'Prodej hotdogů a dalších rychlých jídel návštěvníkům', True, 'Co vznikne zkřížením komára a mouchy? - Komouš.'),
+    (2,2, 1, 140, 'CZK', 5, 'Stánek s hotdogy', 'Prodej hotdogů a dalších rychlých jídel návštěvníkům', True, 'Co vznikne zkřížením komára a mouchy? - Komouš.'),
+    (3,1, 1, 160, 'CZK', 3, 'Kontrola vstupenek', 'Ověření platnosti vstupenek a náramků při vstupu do areálu', False, '„Nauč se radovat z toho, co máš.“- „Hurá, mám depresi!“');
+
+INSERT INTO "employment" ("id", "user_id", "position_id", "rating", "state") VALUES
+    (1, 1, 1, 8, 'pending'),
+    (2, 2, 1, 4, 'rejected'),
+    (3, 3, 1, 7, 'accepted');
@@ -0,0 +1,4 @@
+INSERT INTO "employment" ("id", "user_id", "position_id", "rating", "state", "invitation_expires_at") VALUES
+    (4, 4, 1, 0, 'invited', '2030-01-01T00:00:00Z'),
+    (5, 5, 3, 0, 'invited', '2020-01-01T00:00:00Z'),
+    (6, 5, 1, 0, 'invited', '2030-01-01T00:00:00Z');
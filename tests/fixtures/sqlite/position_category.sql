@@ -0,0 +1,13 @@
+DELETE FROM "event_manager_relation";
+DELETE FROM "worked_hours";
+DELETE FROM "employment";
+DELETE FROM "job_position";
+DELETE FROM "event";
+DELETE FROM "position_category";
+DELETE FROM "user";
+DELETE FROM "venue";
+
+INSERT INTO "position_category" ("id", "name", "organization_id") VALUES
+    (1, 'Obsluha stánků', 1),
+    (2, 'Koordinace parkoviště', 1),
+    (3, 'Technická podpora', 1);
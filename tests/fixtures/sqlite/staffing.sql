@@ -0,0 +1,15 @@
+INSERT INTO "organization_membership" ("organization_id", "user_id", "role") VALUES
+    (1, 2, 'employee'),
+    (1, 5, 'employee')
+ON CONFLICT DO NOTHING;
+
+INSERT INTO "employment" ("id", "user_id", "position_id", "rating", "state") VALUES
+    (7, 2, 2, 4, 'done'),
+    (8, 5, 1, 0, 'pending'),
+    (9, 5, 2, 0, 'accepted');
+
+INSERT INTO "worked_hours" ("employment_id", "hours_worked", "date") VALUES
+    (7, 8.0, '2024-01-01');
+
+INSERT INTO "attendance_record" ("employment_id", "date", "outcome") VALUES
+    (9, '2024-01-01', 'no_show');
@@ -0,0 +1,17 @@
+INSERT INTO "organization_membership" ("organization_id", "user_id", "role") VALUES
+    (1, 2, 'employee'),
+    (1, 5, 'employee')
+ON CONFLICT DO NOTHING;
+
+INSERT INTO "employment" ("id", "user_id", "position_id", "rating", "state") VALUES
+    (7, 2, 2, 4, 'done'),
+    (8, 5, 1, 0, 'pending'),
+    (9, 5, 2, 0, 'accepted');
+
+SELECT setval(pg_get_serial_sequence('"employment"', 'id'), MAX("id")) FROM "employment";
+
+INSERT INTO "worked_hours" ("employment_id", "hours_worked", "date") VALUES
+    (7, 8.0, '1/1/2024');
+
+INSERT INTO "attendance_record" ("employment_id", "date", "outcome") VALUES
+    (9, '1/1/2024', 'no_show');
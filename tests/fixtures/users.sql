@@ -16,3 +16,9 @@ INSERT INTO "user"
 (4,'Michal','Uherácký','male','2/25/2003','fousek','michuh@mail.com','8302944138','organizer',0.15,'$argon2d$v=19$m=12,t=3,p=1$ZXk0ajEzc2k2Zm0wMDAwMA$cMhkzibYVqUSgrkoZ9BP8w');
 
 SELECT setval(pg_get_serial_sequence('"user"', 'id'), MAX("id")) FROM "user";
+
+INSERT INTO "organization_membership" ("organization_id", "user_id", "role") VALUES
+    (1, 1, 'owner'),
+    (1, 2, 'employee'),
+    (1, 3, 'owner'),
+    (1, 4, 'organizer');
@@ -0,0 +1,632 @@
+#[cfg(test)]
+pub mod handlers_tests {
+    use anyhow::Result;
+    use axum::{
+        body::{to_bytes, Body},
+        http::{header, Method, Request, StatusCode},
+        response::Response,
+        Router,
+    };
+    use axum_login::AuthManagerLayerBuilder;
+    use sqlx::postgres::PgPoolOptions;
+    use std::collections::BTreeMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use time::{Duration, OffsetDateTime};
+    use tower::Service;
+    use tower_sessions::{MemoryStore as SessionStore, SessionManagerLayer};
+    use tracing::Subscriber;
+    use tracing_subscriber::layer::{Context, SubscriberExt};
+    use tracing_subscriber::Layer;
+
+    use pv281_giglog::app::{router, AppState};
+    use pv281_giglog::auth::Backend;
+    use pv281_giglog::csrf::CSRF_HEADER;
+    use pv281_giglog::models::employment::{CreateEmployment, EmploymentState, SelectManyFilter};
+    use pv281_giglog::models::event::{CreateEvent, EventStatus};
+    use pv281_giglog::models::job_position::{CreateJobPosition, JobPosition, SalaryCurrency};
+    use pv281_giglog::models::organization::{
+        CreateOrganization, CreateOrganizationMembership, OrganizationRole,
+    };
+    use pv281_giglog::models::position_category::CreatePositionCategory;
+    use pv281_giglog::models::user::{CreateUser, Gender, User, UserRole};
+    use pv281_giglog::models::venue::CreateVenue;
+    use pv281_giglog::models::worked_hours;
+    use pv281_giglog::repositories::database::{Database, Repositories};
+    use pv281_giglog::repositories::memory::MemoryStore;
+    use pv281_giglog::repositories::pool_handler::PoolHandler;
+    use pv281_giglog::storage::local::LocalBlobStore;
+
+    const PASSWORD: &str = "password123";
+
+    /// Router of the application on `database`, with a browser-like client keeping the
+    /// cookies and the CSRF token of one session.
+    struct Client {
+        router: Router,
+        cookies: BTreeMap<String, String>,
+        csrf_token: Option<String>,
+    }
+
+    impl Client {
+        fn new(database: &Database) -> Self {
+            let app_state = AppState::new(
+                database.clone(),
+                Arc::new(LocalBlobStore::new(std::env::temp_dir())),
+            );
+            let backend = Backend::new(app_state.user_repository.clone());
+            let session_layer =
+                SessionManagerLayer::new(SessionStore::default()).with_secure(false);
+            let auth_layer = AuthManagerLayerBuilder::new(backend, session_layer).build();
+            Self {
+                router: router(app_state).layer(auth_layer),
+                cookies: BTreeMap::new(),
+                csrf_token: None,
+            }
+        }
+
+        /// Opens a session and logs `username` in.
+        async fn logged_in(database: &Database, username: &str) -> Result<Self> {
+            let mut client = Self::new(database);
+            let response = client.send("GET", "/login", None).await?;
+            assert_eq!(response.status(), StatusCode::OK);
+            client.csrf_token = csrf_token(&body(response).await?);
+
+            let response = client
+                .send(
+                    "POST",
+                    "/login",
+                    Some(&format!("username={username}&password={PASSWORD}")),
+                )
+                .await?;
+            assert_eq!(response.status(), StatusCode::OK);
+            assert_eq!(
+                response
+                    .headers()
+                    .get("HX-Redirect")
+                    .map(|value| value.as_bytes()),
+                Some("/".as_bytes())
+            );
+            Ok(client)
+        }
+
+        async fn send(&mut self, method: &str, path: &str, form: Option<&str>) -> Result<Response> {
+            let mut request = Request::builder()
+                .method(Method::from_bytes(method.as_bytes())?)
+                .uri(path)
+                .header("HX-Request", "true");
+            if !self.cookies.is_empty() {
+                let cookies: Vec<String> = self
+                    .cookies
+                    .iter()
+                    .map(|(name, value)| format!("{name}={value}"))
+                    .collect();
+                request = request.header(header::COOKIE, cookies.join("; "));
+            }
+            if let Some(token) = &self.csrf_token {
+                request = request.header(CSRF_HEADER, token);
+            }
+            let body = match form {
+                Some(form) => {
+                    request =
+                        request.header(header::CONTENT_TYPE, "application/x-www-form-urlencoded");
+                    Body::from(form.to_string())
+                }
+                None => Body::empty(),
+            };
+
+            let response = self.router.clone().call(request.body(body)?).await?;
+            for cookie in response
+                .headers()
+                .get_all(header::SET_COOKIE)
+                .iter()
+                .filter_map(|value| value.to_str().ok())
+                .filter_map(|cookie| cookie.split(';').next())
+            {
+                if let Some((name, value)) = cookie.split_once('=') {
+                    self.cookies.insert(name.to_string(), value.to_string());
+                }
+            }
+            Ok(response)
+        }
+    }
+
+    /// Counts the statements sqlx logs while it is the default subscriber.
+    #[derive(Clone, Default)]
+    struct QueryCounter(Arc<AtomicUsize>);
+
+    impl QueryCounter {
+        fn take(&self) -> usize {
+            self.0.swap(0, Ordering::SeqCst)
+        }
+    }
+
+    impl<S: Subscriber> Layer<S> for QueryCounter {
+        fn on_event(&self, event: &tracing::Event<'_>, _ctx: Context<'_, S>) {
+            if event.metadata().target() == "sqlx::query" {
+                self.0.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+    }
+
+    async fn body(response: Response) -> Result<String> {
+        let bytes = to_bytes(response.into_body(), usize::MAX).await?;
+        Ok(String::from_utf8(bytes.to_vec())?)
+    }
+
+    /// The CSRF token the base layout renders into `html`.
+    fn csrf_token(html: &str) -> Option<String> {
+        let (_, rest) = html.split_once(r#"id="csrf-token" value=""#)?;
+        let (token, _) = rest.split_once('"')?;
+        Some(token.to_string())
+    }
+
+    async fn create_user(repositories: &Repositories, username: &str, role: UserRole) -> User {
+        repositories
+            .user
+            .create_user(CreateUser {
+                first_name: "Test".to_string(),
+                last_name: username.to_string(),
+                username: username.to_string(),
+                gender: Gender::Other,
+                birth_date: OffsetDateTime::now_utc().date() - Duration::days(365 * 30),
+                email: format!("{username}@example.com"),
+                phone: "+420123456789".to_string(),
+                password_hash: password_auth::generate_hash(PASSWORD),
+                role,
+                tax_rate: 0.15,
+                avatar_url: None,
+            })
+            .await
+            .expect("Repository call should succeed")
+    }
+
+    struct Seed {
+        database: Database,
+        repositories: Repositories,
+        organizer: User,
+        employee: User,
+        job_position: JobPosition,
+    }
+
+    /// Organization with an organizer running a published event that has one open job
+    /// position, and an employee of the organization.
+    async fn seed() -> Seed {
+        seed_database(Database::Memory(MemoryStore::new())).await
+    }
+
+    async fn seed_database(database: Database) -> Seed {
+        let repositories = database.repositories();
+
+        let organization = repositories
+            .organization
+            .create_organization(CreateOrganization {
+                name: "Brno Events".to_string(),
+            })
+            .await
+            .expect("Repository call should succeed");
+        let organizer = create_user(&repositories, "organizer", UserRole::Organizer).await;
+        let employee = create_user(&repositories, "employee", UserRole::Employee).await;
+        for (user, role) in [
+            (&organizer, OrganizationRole::Organizer),
+            (&employee, OrganizationRole::Employee),
+        ] {
+            repositories
+                .organization
+                .create_membership(CreateOrganizationMembership {
+                    organization_id: organization.id,
+                    user_id: user.id,
+                    role,
+                })
+                .await
+                .expect("Repository call should succeed");
+        }
+
+        let venue = repositories
+            .venue
+            .create_venue(CreateVenue {
+                name: "Bobyhall".to_string(),
+                description: None,
+                state: "Czech Republic".to_string(),
+                postal_code: "60200".to_string(),
+                town: "Brno".to_string(),
+                street_name: "Bobycentrum".to_string(),
+                street_number: "1".to_string(),
+                address_url: None,
+                organization_id: organization.id,
+            })
+            .await
+            .expect("Repository call should succeed");
+        let today = OffsetDateTime::now_utc().date();
+        let event = repositories
+            .event
+            .create_event(CreateEvent {
+                name: "Summer Festival".to_string(),
+                date_start: today,
+                date_end: today + Duration::days(2),
+                img_url: "https://example.com/festival.png".to_string(),
+                description: "Three days of music".to_string(),
+                status: EventStatus::Published,
+                venue_id: venue.id,
+                owner_id: organizer.id,
+                publish_at: None,
+                organization_id: organization.id,
+                withdrawal_cutoff_hours: 24,
+            })
+            .await
+            .expect("Repository call should succeed");
+        let category = repositories
+            .position_category
+            .create_position_category(CreatePositionCategory {
+                name: "Bar".to_string(),
+                organization_id: organization.id,
+            })
+            .await
+            .expect("Repository call should succeed");
+        let job_position = repositories
+            .job_position
+            .create_job_position(CreateJobPosition {
+                name: "Bartender".to_string(),
+                description: "Pouring drinks".to_string(),
+                salary: 200.0,
+                currency: SalaryCurrency::CZK,
+                capacity: 2,
+                instructions_markdown: "Come at **nine**".to_string(),
+                is_opened_for_registration: true,
+                event_id: event.id,
+                position_category_id: category.id,
+                registration_opens_at: None,
+                registration_closes_at: None,
+            })
+            .await
+            .expect("Repository call should succeed");
+
+        Seed {
+            database,
+            repositories,
+            organizer,
+            employee,
+            job_position,
+        }
+    }
+
+    async fn employ(seed: &Seed, state: EmploymentState) -> i32 {
+        seed.repositories
+            .employment
+            .create_employment(CreateEmployment {
+                rating: 0,
+                state,
+                user_id: seed.employee.id,
+                position_id: seed.job_position.id,
+                invitation_expires_at: None,
+            })
+            .await
+            .expect("Repository call should succeed")
+            .id
+    }
+
+    #[tokio::test]
+    async fn test_login_is_required() -> Result<()> {
+        let seed = seed().await;
+        let mut client = Client::new(&seed.database);
+
+        let response = client.send("GET", "/jobs", None).await?;
+        assert_eq!(response.status(), StatusCode::TEMPORARY_REDIRECT);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_apply_for_job() -> Result<()> {
+        let seed = seed().await;
+        let mut client = Client::logged_in(&seed.database, "employee").await?;
+
+        let path = format!(
+            "/partials/base/main/events/event/job-state/{}",
+            seed.job_position.id
+        );
+        let response = client.send("POST", &path, None).await?;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(body(response).await?.contains("Pending"));
+
+        let employments = seed
+            .repositories
+            .employment
+            .list_employment(SelectManyFilter {
+                organization_id: None,
+                position_id: Some(seed.job_position.id),
+                user_id: Some(seed.employee.id),
+                state: None,
+                rating: None,
+                event_name_contains: None,
+                job_name_contains: None,
+                employee_name_contains: None,
+                managed_by: None,
+            })
+            .await?;
+        assert_eq!(employments.len(), 1);
+        assert_eq!(employments[0].state, EmploymentState::Pending);
+
+        // Applying twice is turned into an error toast.
+        let response = client.send("POST", &path, None).await?;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        assert!(body(response)
+            .await?
+            .contains("The event has already been registered"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_accept_applicant() -> Result<()> {
+        let seed = seed().await;
+        let employment_id = employ(&seed, EmploymentState::Pending).await;
+        let mut client = Client::logged_in(&seed.database, "organizer").await?;
+
+        let response = client
+            .send(
+                "POST",
+                "/employments/action",
+                Some(&format!(
+                    "method=Accept&employment_id={employment_id}&state=All+states&event=&job_name=&employee_name="
+                )),
+            )
+            .await?;
+        assert_eq!(response.status(), StatusCode::OK);
+        let html = body(response).await?;
+        assert!(html.contains("Bartender"));
+        assert!(html.contains("Accepted"));
+
+        let employment = seed
+            .repositories
+            .employment
+            .get_employment_by_id(employment_id)
+            .await?;
+        assert_eq!(employment.state, EmploymentState::Accepted);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_accept_requires_managing_the_event() -> Result<()> {
+        let seed = seed().await;
+        let employment_id = employ(&seed, EmploymentState::Pending).await;
+        let mut client = Client::logged_in(&seed.database, "employee").await?;
+
+        let response = client
+            .send(
+                "POST",
+                "/employments/action",
+                Some(&format!(
+                    "method=Accept&employment_id={employment_id}&state=All+states&event=&job_name=&employee_name="
+                )),
+            )
+            .await?;
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+        let employment = seed
+            .repositories
+            .employment
+            .get_employment_by_id(employment_id)
+            .await?;
+        assert_eq!(employment.state, EmploymentState::Pending);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_log_worked_hours() -> Result<()> {
+        let seed = seed().await;
+        let employment_id = employ(&seed, EmploymentState::Accepted).await;
+        let mut client = Client::logged_in(&seed.database, "employee").await?;
+        let today = OffsetDateTime::now_utc().date();
+
+        let response = client
+            .send(
+                "PATCH",
+                "/partials/base/main/attendance/attendance-log",
+                Some(&format!(
+                    "worked_hours=7.5&employment_id={employment_id}&date={today}"
+                )),
+            )
+            .await?;
+        assert_eq!(response.status(), StatusCode::OK);
+        let html = body(response).await?;
+        assert!(html.contains(r#"value="7.5""#));
+
+        let logged = seed
+            .repositories
+            .worked_hours
+            .list_worked_hours(worked_hours::SelectManyFilter {
+                hours_worked: None,
+                date: None,
+                employment_id: Some(employment_id),
+            })
+            .await?;
+        assert_eq!(logged.len(), 1);
+        assert_eq!(logged[0].hours_worked, 7.5);
+        assert_eq!(logged[0].date, today);
+
+        // The hours have to be a number.
+        let response = client
+            .send(
+                "PATCH",
+                "/partials/base/main/attendance/attendance-log",
+                Some(&format!(
+                    "worked_hours=many&employment_id={employment_id}&date={today}"
+                )),
+            )
+            .await?;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        assert!(body(response)
+            .await?
+            .contains("Hours worked value must be a number"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_admin_edits_user() -> Result<()> {
+        let seed = seed().await;
+        create_user(&seed.repositories, "admin", UserRole::Admin).await;
+        let mut client = Client::logged_in(&seed.database, "admin").await?;
+
+        let response = client
+            .send(
+                "PATCH",
+                "/admin/user",
+                Some(&format!(
+                    "first_name=Jana&gender=Female&role=Organizer&birth_date=1990-05-17&user_id={}&new_password=",
+                    seed.employee.id
+                )),
+            )
+            .await?;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(body(response)
+            .await?
+            .contains("User data were successfully updated."));
+
+        let user = seed
+            .repositories
+            .user
+            .get_user_by_id(seed.employee.id)
+            .await?;
+        assert_eq!(user.first_name, "Jana");
+        assert_eq!(user.gender, Gender::Female);
+        assert_eq!(user.role, UserRole::Organizer);
+        assert_eq!(user.password_hash, seed.employee.password_hash);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_admin_pages_are_for_admins() -> Result<()> {
+        let seed = seed().await;
+        let mut client = Client::logged_in(&seed.database, "organizer").await?;
+
+        let response = client
+            .send(
+                "PATCH",
+                "/admin/user",
+                Some(&format!(
+                    "first_name=Jana&gender=Female&role=Admin&birth_date=1990-05-17&user_id={}&new_password=",
+                    seed.organizer.id
+                )),
+            )
+            .await?;
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+        let user = seed
+            .repositories
+            .user
+            .get_user_by_id(seed.organizer.id)
+            .await?;
+        assert_eq!(user.role, UserRole::Organizer);
+        Ok(())
+    }
+
+    /// Listing pages along with the client allowed to see them.
+    const LISTING_PAGES: [(&str, &str); 7] = [
+        ("employee", "/jobs"),
+        ("employee", "/events"),
+        ("organizer", "/employments"),
+        ("organizer", "/manage/events"),
+        ("organizer", "/manage/jobs"),
+        ("administrator", "/admin/events"),
+        ("administrator", "/admin/jobs"),
+    ];
+
+    /// Requests every listing page once and returns the number of statements each took.
+    async fn count_page_statements(
+        clients: &mut BTreeMap<&str, Client>,
+        counter: &QueryCounter,
+    ) -> Result<Vec<usize>> {
+        let mut counts = Vec::new();
+        for (username, path) in LISTING_PAGES {
+            let client = clients
+                .get_mut(username)
+                .expect("Client should be logged in");
+            counter.take();
+            let response = client.send("GET", path, None).await?;
+            assert_eq!(response.status(), StatusCode::OK, "GET {path}");
+            counts.push(counter.take());
+        }
+        Ok(counts)
+    }
+
+    #[sqlx::test]
+    async fn test_listing_page_statement_count_does_not_grow_with_data(
+        pool: sqlx::PgPool,
+    ) -> Result<()> {
+        // A single connection, so that its cached type lookups are not logged again.
+        let pool = PgPoolOptions::new()
+            .max_connections(1)
+            .connect_with((*pool.connect_options()).clone())
+            .await?;
+        let seed = seed_database(Database::Postgres(PoolHandler::new(Arc::new(pool)))).await;
+        create_user(&seed.repositories, "administrator", UserRole::Admin).await;
+        employ(&seed, EmploymentState::Accepted).await;
+
+        let mut clients = BTreeMap::new();
+        for username in ["employee", "organizer", "administrator"] {
+            clients.insert(username, Client::logged_in(&seed.database, username).await?);
+        }
+
+        let counter = QueryCounter::default();
+        let _guard =
+            tracing::subscriber::set_default(tracing_subscriber::registry().with(counter.clone()));
+
+        count_page_statements(&mut clients, &counter).await?;
+        let small = count_page_statements(&mut clients, &counter).await?;
+
+        let event = seed
+            .repositories
+            .event
+            .get_event_by_id(seed.job_position.event_id)
+            .await?;
+        for index in 0..10 {
+            let created = seed
+                .repositories
+                .event
+                .create_event(CreateEvent {
+                    name: format!("Festival {index}"),
+                    date_start: event.date_start,
+                    date_end: event.date_end,
+                    img_url: event.img_url.clone(),
+                    description: "Three days of music".to_string(),
+                    status: EventStatus::Published,
+                    venue_id: event.venue_id,
+                    owner_id: seed.organizer.id,
+                    publish_at: None,
+                    organization_id: event.organization_id,
+                    withdrawal_cutoff_hours: 24,
+                })
+                .await?;
+            let job_position = seed
+                .repositories
+                .job_position
+                .create_job_position(CreateJobPosition {
+                    name: format!("Bartender {index}"),
+                    description: "Pouring drinks".to_string(),
+                    salary: 200.0,
+                    currency: SalaryCurrency::CZK,
+                    capacity: 2,
+                    instructions_markdown: String::new(),
+                    is_opened_for_registration: true,
+                    event_id: created.id,
+                    position_category_id: seed.job_position.position_category_id,
+                    registration_opens_at: None,
+                    registration_closes_at: None,
+                })
+                .await?;
+            seed.repositories
+                .employment
+                .create_employment(CreateEmployment {
+                    rating: 0,
+                    state: EmploymentState::Accepted,
+                    user_id: seed.employee.id,
+                    position_id: job_position.id,
+                    invitation_expires_at: None,
+                })
+                .await?;
+        }
+        counter.take();
+
+        let large = count_page_statements(&mut clients, &counter).await?;
+        assert_eq!(large, small);
+        Ok(())
+    }
+}
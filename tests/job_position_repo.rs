@@ -1,233 +1,562 @@
+#[macro_use]
+mod common;
+
 #[cfg(test)]
 pub mod job_position_repo_tests {
     use anyhow::Result;
     use pv281_giglog::error::RepositoryError;
+    use pv281_giglog::models::event::ManagedBy;
     use pv281_giglog::models::job_position::{
-        CreateJobPosition, PartialJobPosition, SalaryCurrency, SelectManyFilter,
+        CreateJobPosition, PartialJobPosition, SalaryCurrency, SelectManyFilter, SortColumn,
+    };
+    use pv281_giglog::models::pagination::{ListSpec, PagePosition, SortDirection};
+    use pv281_giglog::policy::EventAction;
+    use pv281_giglog::repositories::job_position::{
+        JobPositionRepository, PgJobPositionRepository,
     };
-    use pv281_giglog::repositories::job_position::JobPositionRepository;
-    use pv281_giglog::repositories::job_position::PgJobPositionRepository;
-    use pv281_giglog::repositories::pool_handler::PoolHandler;
-    use sqlx::PgPool;
-    use std::sync::Arc;
-
-    #[sqlx::test(fixtures("jobs"))]
-    async fn test_create_job_position(pool: PgPool) -> Result<()> {
-        let mut repository = PgJobPositionRepository::new(PoolHandler::new(Arc::new(pool)));
-
-        let new = CreateJobPosition {
-            name: r#"Malomocný s cedulí "Free hugs""#.to_string(),
-            description: "Stačí vypadat jako malomocný".to_string(),
-            salary: 350.0,
-            currency: SalaryCurrency::CZK,
-            capacity: 2,
-            instructions_html: "Co dělá hudební skladatel Mozart v hrobě? - Rozkládá.".to_string(),
-            is_opened_for_registration: false,
-            event_id: 1,
-            position_category_id: 1,
-        };
-
-        let result = repository
-            .create_job_position(new.clone())
-            .await
-            .expect("Repository call should succeed");
-
-        assert_eq!(result.name, new.name);
-        assert_eq!(result.description, Some(new.description));
-        assert_eq!(result.salary, new.salary);
-        assert_eq!(result.currency, new.currency);
-        assert_eq!(result.capacity, new.capacity);
-        assert_eq!(result.instructions_html, new.instructions_html);
-        assert_eq!(
-            result.is_opened_for_registration,
-            new.is_opened_for_registration
-        );
-        assert_eq!(result.event_id, result.event_id);
-        assert_eq!(result.position_category_id, result.position_category_id);
-
-        repository.pool_handler.disconnect().await;
-        Ok(())
+    use pv281_giglog::repositories::sqlite::job_position::SqliteJobPositionRepository;
+    use sqlx::types::time::OffsetDateTime;
+    use time::Duration;
+
+    database_test! {
+        #[fixtures("jobs")]
+        async fn test_create_job_position(database: Database) -> Result<()> {
+            crate::common::reschedule_to_next_year(&database, 1).await?;
+            let repository = database.repositories().job_position;
+
+            let new = CreateJobPosition {
+                name: r#"Malomocný s cedulí "Free hugs""#.to_string(),
+                description: "Stačí vypadat jako malomocný".to_string(),
+                salary: 350.0,
+                currency: SalaryCurrency::CZK,
+                capacity: 2,
+                instructions_markdown: "Co dělá hudební skladatel Mozart v hrobě? - Rozkládá."
+                    .to_string(),
+                is_opened_for_registration: false,
+                event_id: 1,
+                position_category_id: 1,
+                registration_opens_at: None,
+                registration_closes_at: None,
+            };
+
+            let result = repository
+                .create_job_position(new.clone())
+                .await
+                .expect("Repository call should succeed");
+
+            assert_eq!(result.name, new.name);
+            assert_eq!(result.description, Some(new.description));
+            assert_eq!(result.salary, new.salary);
+            assert_eq!(result.currency, new.currency);
+            assert_eq!(result.capacity, new.capacity);
+            assert_eq!(result.instructions_markdown, new.instructions_markdown);
+            assert_eq!(
+                result.instructions_html,
+                "<p>Co dělá hudební skladatel Mozart v hrobě? - Rozkládá.</p>\n"
+            );
+            assert_eq!(
+                result.is_opened_for_registration,
+                new.is_opened_for_registration
+            );
+            assert_eq!(result.event_id, result.event_id);
+            assert_eq!(result.position_category_id, result.position_category_id);
+
+            database.close().await;
+            Ok(())
+        }
     }
 
-    #[sqlx::test(fixtures("jobs"))]
-    async fn test_create_job_positions_should_fail(pool: PgPool) -> Result<()> {
-        let mut repository = PgJobPositionRepository::new(PoolHandler::new(Arc::new(pool)));
-
-        let new = CreateJobPosition {
-            name: r#"Malomocný s cedulí "Free hugs""#.to_string(),
-            description: "Stačí vypadat jako malomocný".to_string(),
-            salary: 350.0,
-            currency: SalaryCurrency::CZK,
-            capacity: 2,
-            instructions_html: "Co dělá hudební skladatel Mozart v hrobě? - Rozkládá.".to_string(),
-            is_opened_for_registration: false,
-            event_id: 2,
-            position_category_id: 1,
-        };
-
-        let result = repository
-            .create_job_position(new.clone())
-            .await;
-
-        assert!(matches!(result, Err(RepositoryError::GenericError(ref msg)) if msg.contains("The event has already ended")));
-
-        repository.pool_handler.disconnect().await;
-        Ok(())
+    database_test! {
+        #[fixtures("jobs")]
+        async fn test_create_job_positions_should_fail(database: Database) -> Result<()> {
+            let repository = database.repositories().job_position;
+
+            let new = CreateJobPosition {
+                name: r#"Malomocný s cedulí "Free hugs""#.to_string(),
+                description: "Stačí vypadat jako malomocný".to_string(),
+                salary: 350.0,
+                currency: SalaryCurrency::CZK,
+                capacity: 2,
+                instructions_markdown: "Co dělá hudební skladatel Mozart v hrobě? - Rozkládá."
+                    .to_string(),
+                is_opened_for_registration: false,
+                event_id: 2,
+                position_category_id: 1,
+                registration_opens_at: None,
+                registration_closes_at: None,
+            };
+
+            let result = repository.create_job_position(new.clone()).await;
+
+            assert!(
+                matches!(result, Err(RepositoryError::GenericError(ref msg)) if msg.contains("The event has already ended"))
+            );
+
+            database.close().await;
+            Ok(())
+        }
     }
 
-    #[sqlx::test(fixtures("jobs"))]
-    async fn test_list_job_positions(pool: PgPool) -> Result<()> {
-        let mut repository = PgJobPositionRepository::new(PoolHandler::new(Arc::new(pool)));
-
-        let empty = SelectManyFilter {
-            event_id: None,
-            position_category_id: None,
-            salary: None,
-            currency: None,
-            capacity: None,
-            is_opened_for_registration: None,
-        };
-        let list = repository
-            .list_job_positions(empty)
-            .await
-            .expect("Repository call should succeed");
-        assert_eq!(list.len(), 3);
-
-        let filter = SelectManyFilter {
-            event_id: None,
-            position_category_id: None,
-            salary: Some(150.0),
-            currency: None,
-            capacity: None,
-            is_opened_for_registration: Some(true),
-        };
-
-        let job = repository
-            .list_job_positions(filter)
-            .await
-            .expect("Repository call should succeed");
-        assert_eq!(job.len(), 1);
-
-        let unmatchable = SelectManyFilter {
-            event_id: None,
-            position_category_id: None,
-            salary: Some(160.0),
-            currency: None,
-            capacity: Some(2),
-            is_opened_for_registration: None,
-        };
-
-        let result = repository
-            .list_job_positions(unmatchable)
-            .await
-            .expect("Repository call should succeed");
-        assert_eq!(result.len(), 0);
+    database_test! {
+        #[fixtures("jobs")]
+        async fn test_list_job_positions(database: Database) -> Result<()> {
+            let repository = database.repositories().job_position;
+
+            let empty = SelectManyFilter {
+                organization_id: None,
+                event_id: None,
+                position_category_id: None,
+                salary: None,
+                currency: None,
+                capacity: None,
+                is_opened_for_registration: None,
+                managed_by: None,
+            };
+            let list = repository
+                .list_job_positions(empty)
+                .await
+                .expect("Repository call should succeed");
+            assert_eq!(list.len(), 3);
+
+            let filter = SelectManyFilter {
+                organization_id: None,
+                event_id: None,
+                position_category_id: None,
+                salary: Some(150.0),
+                currency: None,
+                capacity: None,
+                is_opened_for_registration: Some(true),
+                managed_by: None,
+            };
+
+            let job = repository
+                .list_job_positions(filter)
+                .await
+                .expect("Repository call should succeed");
+            assert_eq!(job.len(), 1);
+
+            let unmatchable = SelectManyFilter {
+                organization_id: None,
+                event_id: None,
+                position_category_id: None,
+                salary: Some(160.0),
+                currency: None,
+                capacity: Some(2),
+                is_opened_for_registration: None,
+                managed_by: None,
+            };
+
+            let result = repository
+                .list_job_positions(unmatchable)
+                .await
+                .expect("Repository call should succeed");
+            assert_eq!(result.len(), 0);
+
+            database.close().await;
+            Ok(())
+        }
+    }
 
-        repository.pool_handler.disconnect().await;
-        Ok(())
+    database_test! {
+        #[fixtures("jobs")]
+        async fn test_get_job_position_by_id(database: Database) -> Result<()> {
+            let repository = database.repositories().job_position;
+
+            let job = repository
+                .get_job_position_by_id(1)
+                .await
+                .expect("Repository call should succeed");
+            assert_eq!(job.name, "Stánek s hotdogy".to_string());
+            assert_eq!(job.event_id, 1);
+            assert_eq!(job.position_category_id, 1);
+            assert_eq!(job.salary, 150.0);
+            assert_eq!(job.currency, SalaryCurrency::CZK);
+            assert_eq!(job.capacity, 1);
+            assert!(job.is_opened_for_registration);
+            assert_eq!(
+                job.description,
+                Some("Prodej hotdogů a dalších rychlých jídel návštěvníkům".to_string())
+            );
+            assert_eq!(
+                job.instructions_html,
+                "Co vznikne zkřížením komára a mouchy? - Komouš.".to_string()
+            );
+
+            let result = repository.get_job_position_by_id(999).await;
+            assert!(matches!(result, Err(RepositoryError::NotFound)));
+
+            database.close().await;
+            Ok(())
+        }
     }
 
-    #[sqlx::test(fixtures("jobs"))]
-    async fn test_get_job_position_by_id(pool: PgPool) -> Result<()> {
-        let mut repository = PgJobPositionRepository::new(PoolHandler::new(Arc::new(pool)));
-
-        let job = repository
-            .get_job_position_by_id(1)
-            .await
-            .expect("Repository call should succeed");
-        assert_eq!(job.name, "Stánek s hotdogy".to_string());
-        assert_eq!(job.event_id, 1);
-        assert_eq!(job.position_category_id, 1);
-        assert_eq!(job.salary, 150.0);
-        assert_eq!(job.currency, SalaryCurrency::CZK);
-        assert_eq!(job.capacity, 1);
-        assert!(job.is_opened_for_registration);
-        assert_eq!(
-            job.description,
-            Some("Prodej hotdogů a dalších rychlých jídel návštěvníkům".to_string())
-        );
-        assert_eq!(
-            job.instructions_html,
-            "Co vznikne zkřížením komára a mouchy? - Komouš.".to_string()
-        );
-
-        let result = repository.get_job_position_by_id(999).await;
-        assert!(matches!(result, Err(RepositoryError::NotFound)));
-
-        repository.pool_handler.disconnect().await;
-        Ok(())
+    database_test! {
+        #[fixtures("jobs")]
+        async fn test_create_job_position_rollback(database: Database) -> Result<()> {
+            crate::common::reschedule_to_next_year(&database, 1).await?;
+            let repository = database.repositories().job_position.with_actor(1);
+
+            let new = CreateJobPosition {
+                name: "Šatna".to_string(),
+                description: "Úschova kabátů".to_string(),
+                salary: 200.0,
+                currency: SalaryCurrency::CZK,
+                capacity: 1,
+                instructions_markdown: "".to_string(),
+                is_opened_for_registration: true,
+                event_id: 1,
+                position_category_id: 1,
+                registration_opens_at: None,
+                registration_closes_at: None,
+            };
+
+            let mut unit_of_work = database.begin().await?;
+            let created = repository
+                .create_job_position_in(&mut unit_of_work, new.clone())
+                .await?;
+            // Other connections do not see the position before the unit of work commits.
+            let result = repository.get_job_position_by_id(created.id).await;
+            assert!(matches!(result, Err(RepositoryError::NotFound)));
+            unit_of_work.rollback().await?;
+
+            let result = repository.get_job_position_by_id(created.id).await;
+            assert!(matches!(result, Err(RepositoryError::NotFound)));
+
+            let mut unit_of_work = database.begin().await?;
+            let created = repository
+                .create_job_position_in(&mut unit_of_work, new)
+                .await?;
+            unit_of_work.commit().await?;
+            repository.get_job_position_by_id(created.id).await?;
+
+            database.close().await;
+            Ok(())
+        }
     }
 
-    #[sqlx::test(fixtures("jobs"))]
-    async fn test_delete_job_position(pool: PgPool) -> Result<()> {
-        let mut repository = PgJobPositionRepository::new(PoolHandler::new(Arc::new(pool)));
+    database_test! {
+        #[fixtures("jobs")]
+        async fn test_delete_job_position(database: Database) -> Result<()> {
+            let repository = database.repositories().job_position;
 
-        repository
-            .delete_job_position(1)
-            .await
-            .expect("Repository call should succeed");
+            repository
+                .delete_job_position(1)
+                .await
+                .expect("Repository call should succeed");
 
-        let result = repository.delete_job_position(1).await;
-        assert!(matches!(result, Err(RepositoryError::NotFound)));
+            let result = repository.delete_job_position(1).await;
+            assert!(matches!(result, Err(RepositoryError::NotFound)));
 
-        repository.pool_handler.disconnect().await;
-        Ok(())
+            database.close().await;
+            Ok(())
+        }
     }
 
-    #[sqlx::test(fixtures("jobs"))]
-    async fn test_update_job_position(pool: PgPool) -> Result<()> {
-        let mut repository = PgJobPositionRepository::new(PoolHandler::new(Arc::new(pool)));
+    database_test! {
+        #[fixtures("jobs")]
+        async fn test_update_job_position(database: Database) -> Result<()> {
+            let repository = database.repositories().job_position;
+
+            let old = repository
+                .get_job_position_by_id(2)
+                .await
+                .expect("Repository call should succeed");
+
+            let new = PartialJobPosition {
+                name: Some("New name".to_string()),
+                description: Some("Short description for ".to_string()),
+                salary: Some(69.69),
+                currency: None,
+                capacity: None,
+                instructions_markdown: None,
+                is_opened_for_registration: None,
+                event_id: None,
+                position_category_id: None,
+                registration_opens_at: None,
+                registration_closes_at: None,
+            };
+
+            let updated = repository
+                .update_job_position(2, new.clone())
+                .await
+                .expect("Repository call should succeed");
+            assert_eq!(updated.name, new.name.unwrap());
+            assert_eq!(updated.description, new.description);
+            assert_eq!(updated.salary, new.salary.unwrap());
+            assert_eq!(updated.currency, old.currency);
+            assert_eq!(updated.capacity, old.capacity);
+            assert_eq!(updated.instructions_html, old.instructions_html);
+            assert_eq!(
+                updated.is_opened_for_registration,
+                old.is_opened_for_registration
+            );
+            assert_eq!(updated.event_id, old.event_id);
+            assert_eq!(updated.position_category_id, old.position_category_id);
+
+            let empty = PartialJobPosition {
+                name: None,
+                description: None,
+                salary: None,
+                currency: None,
+                capacity: None,
+                instructions_markdown: None,
+                is_opened_for_registration: None,
+                event_id: None,
+                position_category_id: None,
+                registration_opens_at: None,
+                registration_closes_at: None,
+            };
+
+            let result = repository.update_job_position(999, empty).await;
+            assert!(matches!(result, Err(RepositoryError::NotFound)));
+
+            database.close().await;
+            Ok(())
+        }
+    }
 
-        let old = repository
-            .get_job_position_by_id(2)
-            .await
-            .expect("Repository call should succeed");
+    database_test! {
+        #[fixtures("jobs")]
+        async fn test_update_job_position_sanitizes_instructions(database: Database) -> Result<()> {
+            let repository = database.repositories().job_position;
+
+            let markdown = "**Bring** gloves<script>alert('xss')</script>".to_string();
+            let updated = repository
+                .update_job_position(
+                    2,
+                    PartialJobPosition {
+                        name: None,
+                        description: None,
+                        salary: None,
+                        currency: None,
+                        capacity: None,
+                        instructions_markdown: Some(markdown.clone()),
+                        is_opened_for_registration: None,
+                        event_id: None,
+                        position_category_id: None,
+                        registration_opens_at: None,
+                        registration_closes_at: None,
+                    },
+                )
+                .await?;
+            assert_eq!(updated.instructions_markdown, markdown);
+            assert_eq!(
+                updated.instructions_html,
+                "<p><strong>Bring</strong> gloves</p>\n"
+            );
+
+            database.close().await;
+            Ok(())
+        }
+    }
 
-        let new = PartialJobPosition {
-            name: Some("New name".to_string()),
-            description: Some("Short description for ".to_string()),
-            salary: Some(69.69),
-            currency: None,
-            capacity: None,
-            instructions_html: None,
-            is_opened_for_registration: None,
-            event_id: None,
-            position_category_id: None,
-        };
-
-        let updated = repository
-            .update_job_position(2, new.clone())
-            .await
-            .expect("Repository call should succeed");
-        assert_eq!(updated.name, new.name.unwrap());
-        assert_eq!(updated.description, new.description);
-        assert_eq!(updated.salary, new.salary.unwrap());
-        assert_eq!(updated.currency, old.currency);
-        assert_eq!(updated.capacity, old.capacity);
-        assert_eq!(updated.instructions_html, old.instructions_html);
-        assert_eq!(
-            updated.is_opened_for_registration,
-            old.is_opened_for_registration
-        );
-        assert_eq!(updated.event_id, old.event_id);
-        assert_eq!(updated.position_category_id, old.position_category_id);
-
-        let empty = PartialJobPosition {
+    fn window(
+        registration_opens_at: Option<OffsetDateTime>,
+        registration_closes_at: Option<OffsetDateTime>,
+    ) -> PartialJobPosition {
+        PartialJobPosition {
             name: None,
             description: None,
             salary: None,
             currency: None,
             capacity: None,
-            instructions_html: None,
+            instructions_markdown: None,
             is_opened_for_registration: None,
             event_id: None,
             position_category_id: None,
-        };
+            registration_opens_at: Some(registration_opens_at),
+            registration_closes_at: Some(registration_closes_at),
+        }
+    }
+
+    database_test! {
+        #[fixtures("jobs")]
+        async fn test_update_job_position_invalid_window(database: Database) -> Result<()> {
+            let repository = database.repositories().job_position;
+
+            let now = OffsetDateTime::now_utc();
+            let result = repository
+                .update_job_position(3, window(Some(now), Some(now - Duration::hours(1))))
+                .await;
 
-        let result = repository.update_job_position(999, empty).await;
-        assert!(matches!(result, Err(RepositoryError::NotFound)));
+            assert!(
+                matches!(result, Err(RepositoryError::GenericError(ref msg)) if msg.contains("Registration has to close after it opens"))
+            );
+
+            database.close().await;
+            Ok(())
+        }
+    }
+
+    database_test! {
+        #[fixtures("jobs")]
+        async fn test_apply_registration_windows(database: Database) -> Result<()> {
+            let repository = database.repositories().job_position;
+
+            let now = OffsetDateTime::now_utc();
+            // The scheduler last ran an hour ago, e.g. before the application was restarted.
+            let last_run_at = match &database {
+                Database::Sqlite(_) => "strftime('%Y-%m-%dT%H:%M:%fZ', 'now', '-1 hour')",
+                _ => "now() - interval '1 hour'",
+            };
+            crate::common::execute(
+                &database,
+                &format!(r#"UPDATE "scheduler_run" SET "last_run_at" = {last_run_at}"#),
+            )
+            .await?;
+
+            repository
+                .update_job_position(1, window(None, Some(now - Duration::minutes(30))))
+                .await
+                .expect("Repository call should succeed");
+            repository
+                .update_job_position(
+                    2,
+                    window(
+                        Some(now - Duration::minutes(10)),
+                        Some(now + Duration::days(1)),
+                    ),
+                )
+                .await
+                .expect("Repository call should succeed");
+            repository
+                .update_job_position(3, window(None, Some(now + Duration::days(1))))
+                .await
+                .expect("Repository call should succeed");
+
+            let flipped = repository
+                .apply_registration_windows(now)
+                .await
+                .expect("Repository call should succeed");
+            let flipped: Vec<(i32, bool)> = flipped
+                .iter()
+                .map(|job| (job.id, job.is_opened_for_registration))
+                .collect();
+            assert_eq!(flipped, vec![(1, false), (2, true)]);
+
+            let untouched = repository
+                .get_job_position_by_id(3)
+                .await
+                .expect("Repository call should succeed");
+            assert!(untouched.is_opened_for_registration);
+
+            let flipped = repository
+                .apply_registration_windows(now + Duration::minutes(1))
+                .await
+                .expect("Repository call should succeed");
+            assert!(flipped.is_empty());
+
+            database.close().await;
+            Ok(())
+        }
+    }
+
+    database_test! {
+        #[fixtures("jobs")]
+        async fn test_list_job_positions_page(database: Database) -> Result<()> {
+            let repository = database.repositories().job_position;
+
+            let filter = SelectManyFilter {
+                organization_id: Some(1),
+                event_id: None,
+                position_category_id: None,
+                salary: None,
+                currency: None,
+                capacity: None,
+                is_opened_for_registration: None,
+                managed_by: None,
+            };
+            let mut spec = ListSpec {
+                sort_by: SortColumn::Salary,
+                sort_direction: SortDirection::Desc,
+                page_size: 2,
+                position: PagePosition::Page(1),
+            };
+
+            let first = repository
+                .list_job_positions_page(filter.clone(), &spec)
+                .await
+                .expect("Repository call should succeed");
+            let ids: Vec<i32> = first.items.iter().map(|job| job.id).collect();
+            // Equal salaries are ordered by id in the same direction.
+            assert_eq!(ids, vec![3, 2]);
+            assert_eq!(first.total, 3);
+
+            spec.position = PagePosition::After(2);
+            let rest = repository
+                .list_job_positions_page(filter.clone(), &spec)
+                .await?;
+            let ids: Vec<i32> = rest.items.iter().map(|job| job.id).collect();
+            assert_eq!(ids, vec![1]);
+
+            spec.position = PagePosition::Page(2);
+            let second = repository
+                .list_job_positions_page(filter.clone(), &spec)
+                .await?;
+            let ids: Vec<i32> = second.items.iter().map(|job| job.id).collect();
+            assert_eq!(ids, vec![1]);
+
+            let foreign = SelectManyFilter {
+                managed_by: Some(ManagedBy {
+                    user_id: 999,
+                    actions: vec![EventAction::ManageJobs],
+                }),
+                ..filter
+            };
+            assert_eq!(
+                repository
+                    .list_job_positions_page(foreign, &spec)
+                    .await?
+                    .total,
+                0
+            );
+
+            database.close().await;
+            Ok(())
+        }
+    }
 
-        repository.pool_handler.disconnect().await;
-        Ok(())
+    database_test! {
+        #[fixtures("jobs")]
+        async fn test_sanitize_legacy_instructions(database: Database) -> Result<()> {
+            // Instructions stored as raw HTML are moved to the Markdown source by their
+            // migration, which leaves the HTML empty until the backfill sanitizes it.
+            crate::common::execute(
+                &database,
+                r#"UPDATE "job_position" SET "instructions_markdown" = '<p onclick="steal()">Bring <strong>gloves</strong></p><script>alert(1)</script>', "instructions_html" = ''
+                WHERE "id" = 1"#,
+            )
+            .await?;
+            let sanitize = || async {
+                match &database {
+                    Database::Postgres(pool_handler) => {
+                        PgJobPositionRepository::new(pool_handler.clone())
+                            .sanitize_legacy_instructions()
+                            .await
+                    }
+                    Database::Sqlite(pool) => {
+                        SqliteJobPositionRepository::new(pool.clone())
+                            .sanitize_legacy_instructions()
+                            .await
+                    }
+                    Database::Memory(_) => unreachable!(),
+                }
+            };
+
+            assert_eq!(sanitize().await?, 1);
+            let job_position = database
+                .repositories()
+                .job_position
+                .get_job_position_by_id(1)
+                .await?;
+            assert_eq!(
+                job_position.instructions_html,
+                "<p>Bring <strong>gloves</strong></p>"
+            );
+
+            assert_eq!(sanitize().await?, 0);
+            database.close().await;
+            Ok(())
+        }
     }
 }
@@ -0,0 +1,134 @@
+#[macro_use]
+mod common;
+
+#[cfg(test)]
+pub mod organization_repo_tests {
+    use anyhow::Result;
+
+    use pv281_giglog::error::RepositoryError;
+    use pv281_giglog::models::organization::{
+        CreateOrganization, CreateOrganizationMembership, OrganizationRole,
+    };
+    use pv281_giglog::models::user;
+    use pv281_giglog::models::venue::SelectManyFilter;
+    use pv281_giglog::repositories::organization::OrganizationRepository;
+    use pv281_giglog::repositories::user::UserRepository;
+    use pv281_giglog::repositories::venue::VenueRepository;
+
+    database_test! {
+        #[fixtures("organizations")]
+        async fn test_create_organization(database: Database) -> Result<()> {
+            let repository = database.repositories().organization;
+
+            let organization = repository
+                .create_organization(CreateOrganization {
+                    name: "Ostrava Live".to_string(),
+                })
+                .await
+                .expect("Repository call should succeed");
+            assert_eq!(organization.name, "Ostrava Live");
+
+            let duplicate = repository
+                .create_organization(CreateOrganization {
+                    name: "Brno Events".to_string(),
+                })
+                .await;
+            assert!(matches!(duplicate, Err(RepositoryError::GenericError(_))));
+
+            database.close().await;
+            Ok(())
+        }
+    }
+
+    database_test! {
+        #[fixtures("organizations")]
+        async fn test_memberships(database: Database) -> Result<()> {
+            let repository = database.repositories().organization;
+
+            let organizations = repository.list_user_organizations(2).await?;
+            let names: Vec<String> = organizations
+                .iter()
+                .map(|organization| organization.name.clone())
+                .collect();
+            assert_eq!(names, vec!["Brno Events".to_string(), "GigLog".to_string()]);
+
+            let membership = repository.get_membership(2, 3).await?;
+            assert_eq!(membership.role, OrganizationRole::Employee);
+            assert!(matches!(
+                repository.get_membership(1, 3).await,
+                Err(RepositoryError::NotFound)
+            ));
+
+            // Adding an existing member changes their role.
+            let membership = repository
+                .create_membership(CreateOrganizationMembership {
+                    organization_id: 2,
+                    user_id: 3,
+                    role: OrganizationRole::Organizer,
+                })
+                .await?;
+            assert_eq!(membership.role, OrganizationRole::Organizer);
+
+            repository.delete_membership(2, 3).await?;
+            assert!(repository.list_user_organizations(3).await?.is_empty());
+            assert!(matches!(
+                repository.delete_membership(2, 3).await,
+                Err(RepositoryError::NotFound)
+            ));
+
+            database.close().await;
+            Ok(())
+        }
+    }
+
+    database_test! {
+        #[fixtures("organizations")]
+        async fn test_lists_are_isolated(database: Database) -> Result<()> {
+            let venue_repository = database.repositories().venue;
+            let user_repository = database.repositories().user;
+
+            let filter = |organization_id| SelectManyFilter {
+                organization_id,
+                name: None,
+                description: None,
+                state: None,
+                postal_code: None,
+                town: None,
+                street_name: None,
+                street_number: None,
+            };
+            assert_eq!(
+                venue_repository.list_venues(filter(Some(1))).await?.len(),
+                1
+            );
+            assert_eq!(
+                venue_repository.list_venues(filter(Some(2))).await?.len(),
+                2
+            );
+            assert_eq!(venue_repository.list_venues(filter(None)).await?.len(), 3);
+            let mut cities = venue_repository.list_cities(Some(2)).await?;
+            cities.sort();
+            assert_eq!(cities, vec!["Brno".to_string(), "Plzeň".to_string()]);
+
+            let users = user_repository
+                .list_users(user::SelectManyFilter {
+                    organization_id: Some(2),
+                    first_name: None,
+                    last_name: None,
+                    username: None,
+                    gender: None,
+                    role: None,
+                    tax_rate: None,
+                    last_name_contains: None,
+                    username_contains: None,
+                })
+                .await?;
+            let mut user_ids: Vec<i32> = users.iter().map(|user| user.id).collect();
+            user_ids.sort();
+            assert_eq!(user_ids, vec![2, 3]);
+
+            database.close().await;
+            Ok(())
+        }
+    }
+}
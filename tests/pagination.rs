@@ -0,0 +1,55 @@
+#[cfg(test)]
+pub mod pagination_tests {
+    use pv281_giglog::models::event::SortColumn;
+    use pv281_giglog::models::pagination::{
+        ListParams, PagePosition, SortDirection, DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE,
+    };
+
+    #[test]
+    fn test_list_params_defaults() {
+        let spec = ListParams::<SortColumn>::default().spec();
+
+        assert_eq!(spec.sort_by, SortColumn::DateStart);
+        assert_eq!(spec.sort_direction, SortDirection::Asc);
+        assert_eq!(spec.page_size, DEFAULT_PAGE_SIZE);
+        assert_eq!(spec.position, PagePosition::Page(1));
+    }
+
+    #[test]
+    fn test_list_params_bounds() {
+        let params = ListParams::<SortColumn> {
+            page: Some(-3),
+            page_size: Some(10_000),
+            ..Default::default()
+        };
+        let spec = params.spec();
+        assert_eq!(spec.position, PagePosition::Page(1));
+        assert_eq!(spec.page_size, MAX_PAGE_SIZE);
+
+        let params = ListParams::<SortColumn> {
+            page: Some(4),
+            after: Some(17),
+            page_size: Some(0),
+            ..Default::default()
+        };
+        let spec = params.spec();
+        // A cursor wins over a page number.
+        assert_eq!(spec.position, PagePosition::After(17));
+        assert_eq!(spec.page_size, 1);
+    }
+
+    #[test]
+    fn test_sort_query() {
+        assert_eq!(ListParams::<SortColumn>::default().sort_query(), "");
+
+        let params = ListParams {
+            sort_by: Some(SortColumn::DateStart),
+            sort_direction: Some(SortDirection::Desc),
+            ..Default::default()
+        };
+        assert_eq!(
+            params.sort_query(),
+            "sort_by=date_start&sort_direction=desc"
+        );
+    }
+}
@@ -0,0 +1,284 @@
+#[macro_use]
+mod common;
+
+#[cfg(test)]
+pub mod read_model_repo_tests {
+    use anyhow::Result;
+    use sqlx::types::time::Date;
+    use time::Month;
+
+    use pv281_giglog::models::employment::{
+        JobsFilter, JobsSortColumn, SelectManyFilter as EmploymentFilter,
+    };
+    use pv281_giglog::models::event::SelectManyFilter as EventFilter;
+    use pv281_giglog::models::job_position::SelectManyFilter as JobPositionFilter;
+    use pv281_giglog::models::pagination::{ListSpec, SortDirection};
+    use pv281_giglog::models::review::{CreateReview, ReviewDirection};
+    use pv281_giglog::policy::{EventAction, EventPolicy};
+    use pv281_giglog::repositories::read_model::ReadModelRepository;
+    use pv281_giglog::repositories::review::ReviewRepository;
+    use pv281_giglog::repositories::user::UserRepository;
+
+    fn employment_filter() -> EmploymentFilter {
+        EmploymentFilter {
+            organization_id: Some(1),
+            position_id: None,
+            user_id: None,
+            state: None,
+            rating: None,
+            event_name_contains: None,
+            job_name_contains: None,
+            employee_name_contains: None,
+            managed_by: None,
+        }
+    }
+
+    fn jobs_filter() -> JobsFilter {
+        JobsFilter {
+            organization_id: 1,
+            user_id: 1,
+            state: None,
+            starts_from: None,
+            ends_until: None,
+            event_name_contains: None,
+            venue_name_contains: None,
+            job_name_contains: None,
+        }
+    }
+
+    fn event_filter() -> EventFilter {
+        EventFilter {
+            organization_id: Some(1),
+            date_from: None,
+            date_to: None,
+            status: None,
+            venue_id: None,
+            owner_id: None,
+            city: None,
+            state: None,
+            name: None,
+            managed_by: None,
+        }
+    }
+
+    fn job_position_filter() -> JobPositionFilter {
+        JobPositionFilter {
+            organization_id: Some(1),
+            event_id: None,
+            position_category_id: None,
+            salary: None,
+            currency: None,
+            capacity: None,
+            is_opened_for_registration: None,
+            managed_by: None,
+        }
+    }
+
+    database_test! {
+        #[fixtures("worked_hours")]
+        async fn test_list_employment_listings(database: Database) -> Result<()> {
+            let repository = database.repositories().read_model;
+
+            let listings = repository
+                .list_employment_listings(employment_filter())
+                .await?;
+            let ids: Vec<i32> = listings
+                .iter()
+                .map(|listing| listing.employment.id)
+                .collect();
+            assert_eq!(ids, vec![1, 2, 3, 4]);
+
+            let first = &listings[0];
+            assert_eq!(first.position.id, 1);
+            assert_eq!(first.event.id, 1);
+            assert_eq!(first.venue.id, 1);
+            assert_eq!(first.employee.id, 1);
+            assert_eq!(first.employee.username, "pepe232");
+            assert!((first.hours_worked - 5.1).abs() < 0.001);
+            // Employments 3 and 4 are accepted.
+            assert!(listings.iter().all(|listing| listing.current_capacity == 2));
+            assert_eq!(listings[3].hours_worked, 0.0);
+
+            let by_user = repository
+                .list_employment_listings(EmploymentFilter {
+                    user_id: Some(3),
+                    ..employment_filter()
+                })
+                .await?;
+            assert_eq!(by_user.len(), 1);
+            assert_eq!(by_user[0].employee.username, "lasicak");
+
+            database.close().await;
+            Ok(())
+        }
+    }
+
+    database_test! {
+        #[fixtures("employment", "reviews")]
+        async fn test_employment_listings_reviews(database: Database) -> Result<()> {
+            let repository = database.repositories().read_model;
+            let review_repository = database.repositories().review;
+            let today = Date::from_calendar_date(2026, Month::January, 5)?;
+
+            review_repository
+                .create_review(
+                    CreateReview {
+                        employment_id: 10,
+                        direction: ReviewDirection::OrganizerToWorker,
+                        author_id: 1,
+                        overall: 4,
+                        punctuality: None,
+                        skill: None,
+                        organization: None,
+                        communication: None,
+                        comment: "".to_string(),
+                    },
+                    today,
+                )
+                .await?;
+
+            let listings = repository
+                .list_employment_listings(employment_filter())
+                .await?;
+            let reviewed: Vec<(i32, bool, bool)> = listings
+                .iter()
+                .filter(|listing| listing.employment.id >= 10)
+                .map(|listing| {
+                    (
+                        listing.employment.id,
+                        listing.reviewed_by_organizer,
+                        listing.reviewed_by_worker,
+                    )
+                })
+                .collect();
+            assert_eq!(reviewed, vec![(10, true, false), (11, false, false)]);
+
+            database.close().await;
+            Ok(())
+        }
+    }
+
+    database_test! {
+        #[fixtures("event_policy")]
+        async fn test_event_listings_hide_drafts(database: Database) -> Result<()> {
+            let repository = database.repositories().read_model;
+            let user_repository = database.repositories().user;
+            let policy = EventPolicy::from_repositories(&database.repositories());
+
+            // The only event is a draft, listed exactly for the users the policy lets view it.
+            let anonymous = repository.list_event_listings(event_filter(), None).await?;
+            assert!(anonymous.is_empty());
+
+            let draft = policy
+                .resolve_event(pv281_giglog::policy::EventScope::Event(1))
+                .await?;
+            for user_id in 1..=5 {
+                let user = user_repository.get_user_by_id(user_id).await?;
+                let listings = repository
+                    .list_event_listings(event_filter(), Some(&user))
+                    .await?;
+                let visible = policy
+                    .is_authorized(&user, &draft, EventAction::ViewDraft)
+                    .await?;
+                assert_eq!(listings.len() == 1, visible, "user {user_id}");
+            }
+
+            let page = repository
+                .list_event_listings_page(event_filter(), &ListSpec::default())
+                .await?;
+            assert_eq!(page.total, 1);
+            assert_eq!(page.items[0].owner.id, 2);
+            assert_eq!(page.items[0].venue.id, 1);
+
+            database.close().await;
+            Ok(())
+        }
+    }
+
+    database_test! {
+        #[fixtures("employment")]
+        async fn test_list_job_listings_page(database: Database) -> Result<()> {
+            crate::common::execute(
+                &database,
+                r#"INSERT INTO "employment" ("id", "user_id", "position_id", "rating", "state")
+                VALUES (4, 1, 2, 0, 'done')"#,
+            )
+            .await?;
+            crate::common::execute(
+                &database,
+                r#"INSERT INTO "worked_hours" ("employment_id", "hours_worked", "date")
+                VALUES (1, 3, '2026-01-01'), (4, 2, '2024-01-01')"#,
+            )
+            .await?;
+            let repository = database.repositories().read_model;
+
+            let latest_first = repository
+                .list_job_listings_page(jobs_filter(), &ListSpec::default())
+                .await?;
+            let rows: Vec<(i32, f32)> = latest_first
+                .items
+                .iter()
+                .map(|listing| (listing.employment.id, listing.hours_worked))
+                .collect();
+            assert_eq!(rows, vec![(1, 3.0), (4, 2.0)]);
+            assert_eq!(latest_first.total, 2);
+
+            let by_hours = ListSpec {
+                sort_by: JobsSortColumn::HoursWorked,
+                sort_direction: SortDirection::Asc,
+                ..ListSpec::default()
+            };
+            let by_hours = repository
+                .list_job_listings_page(jobs_filter(), &by_hours)
+                .await?;
+            let ids: Vec<i32> = by_hours
+                .items
+                .iter()
+                .map(|listing| listing.employment.id)
+                .collect();
+            assert_eq!(ids, vec![4, 1]);
+
+            let upcoming = JobsFilter {
+                starts_from: Some(Date::from_calendar_date(2025, Month::January, 1)?),
+                ..jobs_filter()
+            };
+            let upcoming = repository
+                .list_job_listings_page(upcoming, &ListSpec::default())
+                .await?;
+            assert_eq!(upcoming.total, 1);
+            assert_eq!(upcoming.items[0].employment.id, 1);
+
+            database.close().await;
+            Ok(())
+        }
+    }
+
+    database_test! {
+        #[fixtures("employment")]
+        async fn test_list_job_position_listings_page(database: Database) -> Result<()> {
+            let repository = database.repositories().read_model;
+
+            let page = repository
+                .list_job_position_listings_page(job_position_filter(), &ListSpec::default())
+                .await?;
+            assert_eq!(page.total, 3);
+            let rows: Vec<(i32, i32, i64)> = page
+                .items
+                .iter()
+                .map(|listing| {
+                    (
+                        listing.position.id,
+                        listing.event.id,
+                        listing.current_capacity,
+                    )
+                })
+                .collect();
+            // Only employment 3 is accepted.
+            assert_eq!(rows, vec![(1, 1, 1), (2, 2, 0), (3, 1, 0)]);
+            assert!(page.items.iter().all(|listing| listing.category.id == 1));
+
+            database.close().await;
+            Ok(())
+        }
+    }
+}
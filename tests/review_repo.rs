@@ -0,0 +1,198 @@
+#[macro_use]
+mod common;
+
+#[cfg(test)]
+pub mod review_repo_tests {
+    use anyhow::Result;
+    use pv281_giglog::error::RepositoryError;
+    use pv281_giglog::models::pagination::ListSpec;
+    use pv281_giglog::models::review::{
+        review_deadline, CreateReview, PublishedReview, ReviewDirection, ReviewSummary,
+    };
+    use pv281_giglog::repositories::review::ReviewRepository;
+    use sqlx::types::time::{Date, OffsetDateTime};
+    use time::Month;
+
+    fn worker_review(employment_id: i32) -> CreateReview {
+        CreateReview {
+            employment_id,
+            direction: ReviewDirection::OrganizerToWorker,
+            author_id: 1,
+            overall: 4,
+            punctuality: Some(5),
+            skill: Some(3),
+            organization: None,
+            communication: None,
+            comment: " Great work ".to_string(),
+        }
+    }
+
+    fn event_review(employment_id: i32, author_id: i32) -> CreateReview {
+        CreateReview {
+            employment_id,
+            direction: ReviewDirection::WorkerToOrganizer,
+            author_id,
+            overall: 2,
+            punctuality: None,
+            skill: None,
+            organization: Some(2),
+            communication: Some(1),
+            comment: "".to_string(),
+        }
+    }
+
+    database_test! {
+        #[fixtures("employment", "reviews")]
+        async fn test_reviews_are_published_when_both_sides_review(
+            database: Database,
+        ) -> Result<()> {
+            let repository = database.repositories().review;
+            let today = Date::from_calendar_date(2026, Month::January, 5)?;
+
+            let review = repository.create_review(worker_review(10), today).await?;
+            assert_eq!(review.subject_id, 2);
+            assert_eq!(review.event_id, 1);
+            assert_eq!(review.comment, "Great work");
+
+            let duplicate = repository.create_review(worker_review(10), today).await;
+            assert!(
+                matches!(duplicate, Err(RepositoryError::GenericError(ref msg)) if msg.contains("already been reviewed"))
+            );
+
+            // Hidden until the worker reviews the event as well.
+            let published = repository
+                .list_published_reviews(2, ReviewDirection::OrganizerToWorker, Some(1), today)
+                .await?;
+            assert!(published.is_empty());
+
+            let review = repository.create_review(event_review(10, 2), today).await?;
+            assert_eq!(review.subject_id, 1);
+
+            let published = repository
+                .list_published_reviews(2, ReviewDirection::OrganizerToWorker, Some(1), today)
+                .await?;
+            assert_eq!(published.len(), 1);
+            assert_eq!(published[0].skill, Some(3));
+            let event_reviews = repository.list_event_reviews(1, today).await?;
+            assert_eq!(event_reviews.len(), 1);
+            assert_eq!(event_reviews[0].communication, Some(1));
+
+            database.close().await;
+            Ok(())
+        }
+    }
+
+    database_test! {
+        #[fixtures("employment", "reviews")]
+        async fn test_review_deadline(database: Database) -> Result<()> {
+            let repository = database.repositories().review;
+            let event_end = Date::from_calendar_date(2026, Month::January, 2)?;
+            let deadline = review_deadline(event_end);
+            assert_eq!(
+                deadline,
+                Date::from_calendar_date(2026, Month::January, 16)?
+            );
+
+            repository
+                .create_review(event_review(11, 5), deadline)
+                .await?;
+            let late = repository
+                .create_review(worker_review(11), deadline.next_day().unwrap())
+                .await;
+            assert!(
+                matches!(late, Err(RepositoryError::GenericError(ref msg)) if msg.contains("review period"))
+            );
+
+            let pending = repository.create_review(worker_review(1), deadline).await;
+            assert!(
+                matches!(pending, Err(RepositoryError::GenericError(ref msg)) if msg.contains("finished jobs"))
+            );
+
+            // A one-sided review is published once the review period is over.
+            assert!(repository.list_event_reviews(1, deadline).await?.is_empty());
+            let published = repository
+                .list_event_reviews(1, deadline.next_day().unwrap())
+                .await?;
+            assert_eq!(published.len(), 1);
+
+            database.close().await;
+            Ok(())
+        }
+    }
+
+    database_test! {
+        #[fixtures("employment", "reviews")]
+        async fn test_flag_and_moderate_review(database: Database) -> Result<()> {
+            let repository = database.repositories().review;
+            let today = Date::from_calendar_date(2026, Month::January, 5)?;
+
+            let review = repository.create_review(worker_review(10), today).await?;
+            repository.create_review(event_review(10, 2), today).await?;
+
+            let empty_reason = repository.flag_review(review.id, 2, "  ".to_string()).await;
+            assert!(matches!(
+                empty_reason,
+                Err(RepositoryError::GenericError(_))
+            ));
+
+            let flagged = repository
+                .flag_review(review.id, 2, "Not true".to_string())
+                .await?;
+            assert!(flagged.flagged_at.is_some());
+            let flagged = repository
+                .list_flagged_reviews_page(&ListSpec::default())
+                .await?
+                .items;
+            assert_eq!(flagged.len(), 1);
+            assert_eq!(flagged[0].flag_reason, Some("Not true".to_string()));
+            assert_eq!(flagged[0].subject_name, "Radek Srejch");
+
+            let moderated = repository.moderate_review(review.id, true).await?;
+            assert!(moderated.is_hidden);
+            assert!(moderated.flagged_at.is_none());
+            assert!(repository
+                .list_flagged_reviews_page(&ListSpec::default())
+                .await?
+                .items
+                .is_empty());
+            let published = repository
+                .list_published_reviews(2, ReviewDirection::OrganizerToWorker, None, today)
+                .await?;
+            assert!(published.is_empty());
+
+            let missing = repository.moderate_review(999, false).await;
+            assert!(matches!(missing, Err(RepositoryError::NotFound)));
+
+            database.close().await;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_review_summary() {
+        let review = |overall: i32, skill: Option<i32>| PublishedReview {
+            id: 1,
+            direction: ReviewDirection::OrganizerToWorker,
+            author_name: None,
+            event_id: 1,
+            event_name: "Event".to_string(),
+            overall,
+            punctuality: None,
+            skill,
+            organization: None,
+            communication: None,
+            comment: "".to_string(),
+            created_at: OffsetDateTime::UNIX_EPOCH,
+        };
+
+        let summary = ReviewSummary::from_reviews(&[review(5, Some(4)), review(2, None)]);
+        assert_eq!(summary.count, 2);
+        assert_eq!(summary.overall, Some(3.5));
+        assert_eq!(summary.skill, Some(4.0));
+        assert_eq!(summary.punctuality, None);
+
+        let empty = ReviewSummary::from_reviews(&[]);
+        assert_eq!(empty.count, 0);
+        assert_eq!(empty.overall, None);
+    }
+}
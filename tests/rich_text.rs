@@ -0,0 +1,69 @@
+#[cfg(test)]
+pub mod rich_text_tests {
+    use pv281_giglog::rich_text::{render_markdown, sanitize_html};
+    use regex::Regex;
+
+    #[test]
+    fn test_render_markdown() {
+        assert_eq!(
+            render_markdown("Bring **gloves** and *boots*"),
+            "<p>Bring <strong>gloves</strong> and <em>boots</em></p>\n"
+        );
+        assert_eq!(
+            render_markdown("- Gate A\n- Gate B"),
+            "<ul>\n<li>Gate A</li>\n<li>Gate B</li>\n</ul>\n"
+        );
+        assert_eq!(
+            render_markdown("[Map](https://example.com/map)"),
+            "<p><a href=\"https://example.com/map\" rel=\"noopener noreferrer nofollow\">Map</a></p>\n"
+        );
+        assert_eq!(render_markdown(""), "");
+    }
+
+    #[test]
+    fn test_xss_payloads_are_removed() {
+        let payloads = [
+            "<script>alert(1)</script>",
+            "<img src=x onerror=alert(1)>",
+            "<svg onload=alert(1)>",
+            "<iframe src=\"https://evil.example\"></iframe>",
+            "<a href=\"javascript:alert(1)\">click</a>",
+            "[click](javascript:alert(1))",
+            "<a href=\"JaVaScRiPt:alert(1)\">click</a>",
+            "<p onclick=\"alert(1)\">text</p>",
+            "<div style=\"background:url(javascript:alert(1))\">text</div>",
+            "<object data=\"data:text/html,<script>alert(1)</script>\"></object>",
+            "<math><mtext><table><mglyph><style><img src=x onerror=alert(1)>",
+            "<form action=\"https://evil.example\"><input name=\"password\"></form>",
+        ];
+
+        // Escaped text is harmless, only tags and attributes that survived sanitizing count.
+        let forbidden_tag =
+            Regex::new(r"<(script|style|iframe|svg|math|img|object|form|input)\b").unwrap();
+        let forbidden_attribute =
+            Regex::new(r#"<[^>]*\s(on[a-z]+|style)\s*=|<[^>]*=\s*"?\s*javascript:"#).unwrap();
+        for payload in payloads {
+            let rendered = render_markdown(payload).to_lowercase();
+            assert!(
+                !forbidden_tag.is_match(&rendered),
+                "{payload:?} rendered into {rendered:?}"
+            );
+            assert!(
+                !forbidden_attribute.is_match(&rendered),
+                "{payload:?} rendered into {rendered:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_sanitize_html_keeps_formatting() {
+        assert_eq!(
+            sanitize_html("<p>Meet at <b>gate A</b><script>alert(1)</script></p>"),
+            "<p>Meet at <b>gate A</b></p>"
+        );
+        assert_eq!(
+            sanitize_html("<a href=\"https://example.com\" onclick=\"alert(1)\">Map</a>"),
+            "<a href=\"https://example.com\" rel=\"noopener noreferrer nofollow\">Map</a>"
+        );
+    }
+}
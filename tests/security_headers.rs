@@ -0,0 +1,128 @@
+#[cfg(test)]
+pub mod security_headers_tests {
+    use anyhow::Result;
+    use axum::{
+        body::{to_bytes, Body},
+        http::Request,
+        middleware::from_fn_with_state,
+        response::Response,
+        routing::get,
+        Router,
+    };
+    use pv281_giglog::security_headers::{csp_nonce, security_headers, CspMode, SecurityHeaders};
+    use regex::Regex;
+    use std::fs;
+    use std::path::PathBuf;
+    use tower::Service;
+
+    async fn send(config: SecurityHeaders) -> Result<Response> {
+        let mut router = Router::new()
+            .route("/", get(|| async { csp_nonce() }))
+            .layer(from_fn_with_state(config, security_headers));
+        Ok(router.call(Request::get("/").body(Body::empty())?).await?)
+    }
+
+    fn header_value<'a>(response: &'a Response, name: &str) -> Option<&'a str> {
+        response
+            .headers()
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+    }
+
+    #[tokio::test]
+    async fn test_default_headers() -> Result<()> {
+        let response = send(SecurityHeaders::default()).await?;
+
+        assert_eq!(header_value(&response, "x-frame-options"), Some("DENY"));
+        assert_eq!(
+            header_value(&response, "x-content-type-options"),
+            Some("nosniff")
+        );
+        assert_eq!(
+            header_value(&response, "referrer-policy"),
+            Some("strict-origin-when-cross-origin")
+        );
+        assert!(header_value(&response, "permissions-policy").is_some());
+        // Without TLS, browsers would ignore HSTS anyway.
+        assert!(header_value(&response, "strict-transport-security").is_none());
+
+        // Report-only is the default until the policy is rolled out.
+        assert!(header_value(&response, "content-security-policy").is_none());
+        assert!(header_value(&response, "content-security-policy-report-only").is_some());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_csp_modes() -> Result<()> {
+        let enforced = send(SecurityHeaders {
+            csp_mode: CspMode::Enforce,
+            csp_report_uri: Some("/csp-reports".to_string()),
+            tls: true,
+        })
+        .await?;
+        let policy = header_value(&enforced, "content-security-policy").unwrap_or_default();
+        assert!(policy.contains("default-src 'self'"));
+        assert!(policy.contains("frame-ancestors 'none'"));
+        assert!(policy.ends_with("report-uri /csp-reports"));
+        assert!(header_value(&enforced, "content-security-policy-report-only").is_none());
+        assert_eq!(
+            header_value(&enforced, "strict-transport-security"),
+            Some("max-age=31536000; includeSubDomains")
+        );
+
+        let off = send(SecurityHeaders {
+            csp_mode: CspMode::Off,
+            ..SecurityHeaders::default()
+        })
+        .await?;
+        assert!(header_value(&off, "content-security-policy").is_none());
+        assert!(header_value(&off, "content-security-policy-report-only").is_none());
+        assert_eq!(header_value(&off, "x-frame-options"), Some("DENY"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_nonce_is_fresh_and_visible_to_handler() -> Result<()> {
+        assert_eq!(csp_nonce(), "");
+
+        let mut nonces = Vec::new();
+        for _ in 0..2 {
+            let response = send(SecurityHeaders::default()).await?;
+            let policy = header_value(&response, "content-security-policy-report-only")
+                .unwrap_or_default()
+                .to_string();
+            let body = to_bytes(response.into_body(), usize::MAX).await?;
+            let nonce = String::from_utf8(body.to_vec())?;
+
+            assert!(!nonce.is_empty());
+            assert!(policy.contains(&format!("'nonce-{nonce}'")));
+            nonces.push(nonce);
+        }
+        assert_ne!(nonces[0], nonces[1]);
+        Ok(())
+    }
+
+    /// The policy has no `'unsafe-inline'` nor `'unsafe-hashes'`, so browsers enforcing it
+    /// would block inline event handlers and `javascript:` URLs.
+    #[test]
+    fn test_templates_have_no_inline_handlers() -> Result<()> {
+        let inline_handler = Regex::new(r#"\son[a-z]+\s*=|javascript:"#)?;
+        let mut directories = vec![PathBuf::from("templates")];
+        while let Some(directory) = directories.pop() {
+            for entry in fs::read_dir(directory)? {
+                let path = entry?.path();
+                if path.is_dir() {
+                    directories.push(path);
+                    continue;
+                }
+                let template = fs::read_to_string(&path)?;
+                assert!(
+                    !inline_handler.is_match(&template),
+                    "{} has an inline event handler",
+                    path.display()
+                );
+            }
+        }
+        Ok(())
+    }
+}
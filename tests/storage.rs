@@ -0,0 +1,163 @@
+#[cfg(test)]
+pub mod storage_tests {
+    use anyhow::Result;
+    use image::{DynamicImage, ImageFormat, RgbImage};
+    use pv281_giglog::error::{StorageError, UploadError};
+    use pv281_giglog::storage::local::LocalBlobStore;
+    use pv281_giglog::storage::upload::{
+        process_image, store_image, validate_upload, ImageKind, UploadLimits,
+    };
+    use pv281_giglog::storage::{is_valid_key, thumbnail_url, validate_image_url, Blob, BlobStore};
+    use std::io::Cursor;
+    use uuid::Uuid;
+
+    fn encode(image: &DynamicImage, format: ImageFormat) -> Result<Vec<u8>> {
+        let mut bytes = Cursor::new(Vec::new());
+        image.write_to(&mut bytes, format)?;
+        Ok(bytes.into_inner())
+    }
+
+    fn temp_store() -> LocalBlobStore {
+        LocalBlobStore::new(std::env::temp_dir().join(format!("giglog-{}", Uuid::new_v4())))
+    }
+
+    #[test]
+    fn test_validate_upload() -> Result<()> {
+        let png = encode(
+            &DynamicImage::ImageRgb8(RgbImage::new(4, 4)),
+            ImageFormat::Png,
+        )?;
+        let limits = UploadLimits::default();
+
+        assert_eq!(
+            validate_upload(Some("image/png"), &png, &limits),
+            Ok(ImageFormat::Png)
+        );
+        assert_eq!(
+            validate_upload(Some("image/gif"), &png, &limits),
+            Err(UploadError::UnsupportedType)
+        );
+        assert_eq!(
+            validate_upload(None, &png, &limits),
+            Err(UploadError::UnsupportedType)
+        );
+        // The declared type has to match the contents.
+        assert_eq!(
+            validate_upload(Some("image/jpeg"), &png, &limits),
+            Err(UploadError::InvalidImage)
+        );
+        assert_eq!(
+            validate_upload(Some("image/png"), b"not an image", &limits),
+            Err(UploadError::InvalidImage)
+        );
+        assert_eq!(
+            validate_upload(Some("image/png"), &[], &limits),
+            Err(UploadError::MissingFile)
+        );
+
+        let small_limits = UploadLimits { max_bytes: 10 };
+        assert_eq!(
+            validate_upload(Some("image/png"), &png, &small_limits),
+            Err(UploadError::TooLarge(1))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_process_image() -> Result<()> {
+        let png = encode(
+            &DynamicImage::ImageRgb8(RgbImage::new(3000, 1000)),
+            ImageFormat::Png,
+        )?;
+
+        let hero = process_image(&png, ImageFormat::Png, ImageKind::EventHero)?;
+        let image = image::load_from_memory(&hero.image)?;
+        assert_eq!((image.width(), image.height()), (1920, 640));
+        let thumbnail = image::load_from_memory(&hero.thumbnail)?;
+        assert_eq!((thumbnail.width(), thumbnail.height()), (480, 160));
+        assert_eq!(image::guess_format(&hero.image)?, ImageFormat::Jpeg);
+
+        let avatar = process_image(&png, ImageFormat::Png, ImageKind::Avatar)?;
+        let image = image::load_from_memory(&avatar.image)?;
+        assert_eq!((image.width(), image.height()), (512, 512));
+        let thumbnail = image::load_from_memory(&avatar.thumbnail)?;
+        assert_eq!((thumbnail.width(), thumbnail.height()), (128, 128));
+
+        assert!(matches!(
+            process_image(b"broken", ImageFormat::Png, ImageKind::Avatar),
+            Err(UploadError::InvalidImage)
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_image_urls() {
+        assert!(is_valid_key("avatars/1f0e.jpg"));
+        assert!(!is_valid_key("../secret.txt"));
+        assert!(!is_valid_key("avatars//1f0e.jpg"));
+        assert!(!is_valid_key("/etc/passwd"));
+
+        assert_eq!(
+            thumbnail_url("/uploads/events/1f0e.jpg"),
+            "/uploads/events/1f0e_thumb.jpg"
+        );
+        assert_eq!(
+            thumbnail_url("https://images.cdn.com/image.jpg"),
+            "https://images.cdn.com/image.jpg"
+        );
+
+        assert!(validate_image_url("https://images.cdn.com/image.jpg").is_ok());
+        assert!(validate_image_url("/uploads/events/1f0e.jpg").is_ok());
+        assert!(validate_image_url("/uploads/../secret.txt").is_err());
+        assert!(validate_image_url("not a url").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_local_blob_store() -> Result<()> {
+        let store = temp_store();
+        let blob = Blob {
+            content_type: "image/jpeg".to_string(),
+            bytes: vec![1, 2, 3],
+        };
+
+        store.put("avatars/test.jpg", blob.clone()).await?;
+        assert_eq!(store.get("avatars/test.jpg").await?, blob);
+
+        store.delete("avatars/test.jpg").await?;
+        assert!(matches!(
+            store.get("avatars/test.jpg").await,
+            Err(StorageError::NotFound)
+        ));
+        // Deleting a missing blob is fine.
+        store.delete("avatars/test.jpg").await?;
+
+        assert!(matches!(
+            store.get("../Cargo.toml").await,
+            Err(StorageError::NotFound)
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_store_image() -> Result<()> {
+        let store = temp_store();
+        let png = encode(
+            &DynamicImage::ImageRgb8(RgbImage::new(800, 600)),
+            ImageFormat::Png,
+        )?;
+        let processed = process_image(&png, ImageFormat::Png, ImageKind::EventHero)?;
+
+        let url = store_image(&store, ImageKind::EventHero, processed).await?;
+        assert!(url.starts_with("/uploads/events/"));
+
+        let key = url.trim_start_matches("/uploads/");
+        let image = store.get(key).await?;
+        assert_eq!(image.content_type, "image/jpeg");
+        let thumbnail_key = thumbnail_url(&url)
+            .trim_start_matches("/uploads/")
+            .to_string();
+        let thumbnail = image::load_from_memory(&store.get(&thumbnail_key).await?.bytes)?;
+        assert_eq!((thumbnail.width(), thumbnail.height()), (480, 360));
+        Ok(())
+    }
+}
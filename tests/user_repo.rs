@@ -1,284 +1,511 @@
+#[macro_use]
+mod common;
+
 #[cfg(test)]
 pub mod user_repo_tests {
     use anyhow::Result;
     use sqlx::types::time::Date;
-    use sqlx::PgPool;
-    use std::sync::Arc;
     use tower_sessions::cookie::time::Month;
 
     use pv281_giglog::error::RepositoryError;
-    use pv281_giglog::models::user::{CreateUser, Gender, PartialUser, SelectManyFilter, UserRole};
-    use pv281_giglog::repositories::pool_handler::PoolHandler;
-    use pv281_giglog::repositories::user::{PgUserRepository, UserRepository};
-
-    #[sqlx::test(fixtures("users"))]
-    async fn test_create_user(pool: PgPool) -> Result<()> {
-        let mut repository = PgUserRepository::new(PoolHandler::new(Arc::new(pool)));
-
-        let new_user = CreateUser {
-            first_name: "Libor".to_string(),
-            last_name: "Sobotka".to_string(),
-            username: "Lisko".to_string(),
-            gender: Gender::Male,
-            birth_date: Date::from_calendar_date(2000, Month::March, 13)?,
-            email: "Lisko@gmail.com".to_string(),
-            phone: "+420777666123".to_string(),
-            password_hash: "hash".to_string(),
-            tax_rate: 0.25,
-            role: UserRole::Employee,
-            avatar_url: Some("https://www.url.com".to_string()),
-        };
-
-        let created = repository
-            .create_user(new_user.clone())
-            .await
-            .expect("Repository call should succeed");
-
-        assert!(created.id >= 0);
-        assert_eq!(created.first_name, new_user.first_name);
-        assert_eq!(created.last_name, new_user.last_name);
-        assert_eq!(created.username, new_user.username);
-        assert_eq!(created.gender, new_user.gender);
-        assert_eq!(created.birth_date, new_user.birth_date);
-        assert_eq!(created.email, new_user.email);
-        assert_eq!(created.phone, new_user.phone);
-        assert_eq!(created.password_hash, new_user.password_hash);
-        assert_eq!(created.tax_rate, new_user.tax_rate);
-        assert_eq!(created.role, new_user.role);
-
-        let existing_username = CreateUser {
-            first_name: "".to_string(),
-            last_name: "".to_string(),
-            username: "Lisko".to_string(),
-            gender: Gender::Male,
-            birth_date: Date::from_calendar_date(2002, Month::December, 16)?,
-            email: "".to_string(),
-            phone: "".to_string(),
-            password_hash: "".to_string(),
-            role: UserRole::Employee,
-            tax_rate: 0.1,
-            avatar_url: None,
-        };
-
-        let result = repository.create_user(existing_username).await;
-        assert!(result.is_err());
-
-        repository.pool_handler.disconnect().await;
-        Ok(())
+    use pv281_giglog::models::audit_log::{AuditEntity, SelectManyFilter as AuditLogFilter};
+    use pv281_giglog::models::organization::{CreateOrganizationMembership, OrganizationRole};
+    use pv281_giglog::models::pagination::{ListSpec, PagePosition, SortDirection};
+    use pv281_giglog::models::user::{
+        CreateUser, Gender, PartialUser, SelectManyFilter, SortColumn, UserRole,
+    };
+    use pv281_giglog::repositories::audit_log::AuditLogRepository;
+    use pv281_giglog::repositories::organization::OrganizationRepository;
+    use pv281_giglog::repositories::unit_of_work::UnitOfWork;
+    use pv281_giglog::repositories::user::UserRepository;
+
+    database_test! {
+        #[fixtures("users")]
+        async fn test_create_user(database: Database) -> Result<()> {
+            let repository = database.repositories().user;
+
+            let new_user = CreateUser {
+                first_name: "Libor".to_string(),
+                last_name: "Sobotka".to_string(),
+                username: "Lisko".to_string(),
+                gender: Gender::Male,
+                birth_date: Date::from_calendar_date(2000, Month::March, 13)?,
+                email: "Lisko@gmail.com".to_string(),
+                phone: "+420777666123".to_string(),
+                password_hash: "hash".to_string(),
+                tax_rate: 0.25,
+                role: UserRole::Employee,
+                avatar_url: Some("https://www.url.com".to_string()),
+            };
+
+            let created = repository
+                .create_user(new_user.clone())
+                .await
+                .expect("Repository call should succeed");
+
+            assert!(created.id >= 0);
+            assert_eq!(created.first_name, new_user.first_name);
+            assert_eq!(created.last_name, new_user.last_name);
+            assert_eq!(created.username, new_user.username);
+            assert_eq!(created.gender, new_user.gender);
+            assert_eq!(created.birth_date, new_user.birth_date);
+            assert_eq!(created.email, new_user.email);
+            assert_eq!(created.phone, new_user.phone);
+            assert_eq!(created.password_hash, new_user.password_hash);
+            assert_eq!(created.tax_rate, new_user.tax_rate);
+            assert_eq!(created.role, new_user.role);
+
+            let existing_username = CreateUser {
+                first_name: "".to_string(),
+                last_name: "".to_string(),
+                username: "Lisko".to_string(),
+                gender: Gender::Male,
+                birth_date: Date::from_calendar_date(2002, Month::December, 16)?,
+                email: "".to_string(),
+                phone: "".to_string(),
+                password_hash: "".to_string(),
+                role: UserRole::Employee,
+                tax_rate: 0.1,
+                avatar_url: None,
+            };
+
+            let result = repository.create_user(existing_username).await;
+            assert!(matches!(result, Err(RepositoryError::Conflict(_))));
+
+            database.close().await;
+            Ok(())
+        }
+    }
+
+    database_test! {
+        #[fixtures("users")]
+        async fn test_register_user_in_unit_of_work(database: Database) -> Result<()> {
+            let repository = database.repositories().user.with_actor(1);
+            let organization_repository = database.repositories().organization;
+            let audit_log_repository = database.repositories().audit_log;
+
+            let new_user = CreateUser {
+                first_name: "Libor".to_string(),
+                last_name: "Sobotka".to_string(),
+                username: "Lisko".to_string(),
+                gender: Gender::Male,
+                birth_date: Date::from_calendar_date(2000, Month::March, 13)?,
+                email: "Lisko@gmail.com".to_string(),
+                phone: "+420777666123".to_string(),
+                password_hash: "hash".to_string(),
+                tax_rate: 0.25,
+                role: UserRole::Employee,
+                avatar_url: None,
+            };
+            let user_audit_logs = || AuditLogFilter {
+                actor_id: Some(1),
+                entity: Some(AuditEntity::User),
+                entity_id: None,
+                event_id: None,
+                action: None,
+                date_from: None,
+                date_to: None,
+            };
+
+            // Nothing of a unit of work that is not committed is kept, audit entries included.
+            let mut unit_of_work = database.begin().await?;
+            let created = repository
+                .create_user_in(&mut unit_of_work, new_user.clone())
+                .await?;
+            organization_repository
+                .create_membership_in(
+                    &mut unit_of_work,
+                    CreateOrganizationMembership {
+                        organization_id: 1,
+                        user_id: created.id,
+                        role: OrganizationRole::Employee,
+                    },
+                )
+                .await?;
+            unit_of_work.rollback().await?;
+
+            let result = repository
+                .get_user_by_username(new_user.username.clone())
+                .await;
+            assert!(matches!(result, Err(RepositoryError::NotFound)));
+            assert!(matches!(
+                organization_repository.get_membership(1, created.id).await,
+                Err(RepositoryError::NotFound)
+            ));
+            assert!(audit_log_repository
+                .list_audit_logs(user_audit_logs())
+                .await?
+                .is_empty());
+
+            let mut unit_of_work = database.begin().await?;
+            let created = repository
+                .create_user_in(&mut unit_of_work, new_user.clone())
+                .await?;
+            organization_repository
+                .create_membership_in(
+                    &mut unit_of_work,
+                    CreateOrganizationMembership {
+                        organization_id: 1,
+                        user_id: created.id,
+                        role: OrganizationRole::Employee,
+                    },
+                )
+                .await?;
+            unit_of_work.commit().await?;
+
+            let user = repository.get_user_by_username(new_user.username).await?;
+            assert_eq!(user.id, created.id);
+            organization_repository.get_membership(1, user.id).await?;
+            assert_eq!(
+                audit_log_repository
+                    .list_audit_logs(user_audit_logs())
+                    .await?
+                    .len(),
+                1
+            );
+
+            database.close().await;
+            Ok(())
+        }
     }
 
-    #[sqlx::test(fixtures("users"))]
-    async fn test_list_users(pool: PgPool) -> Result<()> {
-        let mut repository = PgUserRepository::new(PoolHandler::new(Arc::new(pool)));
-
-        let user_filter = SelectManyFilter {
-            username: None,
-            first_name: None,
-            last_name: None,
-            gender: None,
-            role: None,
-            tax_rate: None,
-        };
-
-        let users = repository
-            .list_users(user_filter)
-            .await
-            .expect("Repository call should succeed");
-
-        assert_eq!(users.len(), 4);
-        let usernames = vec![
-            "pepe232".to_string(),
-            "brember".to_string(),
-            "lasicak".to_string(),
-            "fousek".to_string(),
-        ];
-        let users_username: Vec<String> = users.iter().map(|user| user.username.clone()).collect();
-
-        assert_eq!(usernames, users_username);
-
-        let user_filter = SelectManyFilter {
-            username: None,
-            first_name: None,
-            last_name: None,
-            gender: Some(Gender::Male),
-            role: None,
-            tax_rate: None,
-        };
-
-        let users = repository
-            .list_users(user_filter)
-            .await
-            .expect("Repository call should succeed");
-        assert_eq!(users.len(), 2);
-        let usernames = vec!["brember".to_string(), "fousek".to_string()];
-        let users_username: Vec<String> = users.iter().map(|user| user.username.clone()).collect();
-
-        assert_eq!(usernames, users_username);
-
-        let user_filter = SelectManyFilter {
-            username: None,
-            first_name: None,
-            last_name: None,
-            gender: Some(Gender::Female),
-            role: Some(UserRole::Admin),
-            tax_rate: None,
-        };
-
-        let users = repository
-            .list_users(user_filter)
-            .await
-            .expect("Repository call should succeed");
-
-        assert_eq!(users.len(), 2);
-        let usernames = vec!["pepe232".to_string(), "lasicak".to_string()];
-        let users_username: Vec<String> = users.iter().map(|user| user.username.clone()).collect();
-
-        assert_eq!(usernames, users_username);
-
-        repository.pool_handler.disconnect().await;
-        Ok(())
+    database_test! {
+        #[fixtures("users")]
+        async fn test_list_users(database: Database) -> Result<()> {
+            let repository = database.repositories().user;
+
+            let user_filter = SelectManyFilter {
+                organization_id: None,
+                username: None,
+                first_name: None,
+                last_name: None,
+                gender: None,
+                role: None,
+                tax_rate: None,
+                last_name_contains: None,
+                username_contains: None,
+            };
+
+            let users = repository
+                .list_users(user_filter)
+                .await
+                .expect("Repository call should succeed");
+
+            assert_eq!(users.len(), 4);
+            let usernames = vec![
+                "pepe232".to_string(),
+                "brember".to_string(),
+                "lasicak".to_string(),
+                "fousek".to_string(),
+            ];
+            let users_username: Vec<String> =
+                users.iter().map(|user| user.username.clone()).collect();
+
+            assert_eq!(usernames, users_username);
+
+            let user_filter = SelectManyFilter {
+                organization_id: None,
+                username: None,
+                first_name: None,
+                last_name: None,
+                gender: Some(Gender::Male),
+                role: None,
+                tax_rate: None,
+                last_name_contains: None,
+                username_contains: None,
+            };
+
+            let users = repository
+                .list_users(user_filter)
+                .await
+                .expect("Repository call should succeed");
+            assert_eq!(users.len(), 2);
+            let usernames = vec!["brember".to_string(), "fousek".to_string()];
+            let users_username: Vec<String> =
+                users.iter().map(|user| user.username.clone()).collect();
+
+            assert_eq!(usernames, users_username);
+
+            let user_filter = SelectManyFilter {
+                organization_id: None,
+                username: None,
+                first_name: None,
+                last_name: None,
+                gender: Some(Gender::Female),
+                role: Some(UserRole::Admin),
+                tax_rate: None,
+                last_name_contains: None,
+                username_contains: None,
+            };
+
+            let users = repository
+                .list_users(user_filter)
+                .await
+                .expect("Repository call should succeed");
+
+            assert_eq!(users.len(), 2);
+            let usernames = vec!["pepe232".to_string(), "lasicak".to_string()];
+            let users_username: Vec<String> =
+                users.iter().map(|user| user.username.clone()).collect();
+
+            assert_eq!(usernames, users_username);
+
+            database.close().await;
+            Ok(())
+        }
     }
 
-    #[sqlx::test(fixtures("users"))]
-    async fn test_get_user_by_id(pool: PgPool) -> Result<()> {
-        let mut repository = PgUserRepository::new(PoolHandler::new(Arc::new(pool)));
-
-        let user = repository
-            .get_user_by_id(1)
-            .await
-            .expect("Repository call should succeed");
-
-        assert_eq!(user.id, 1);
-        assert_eq!(user.first_name, "Josefka");
-        assert_eq!(user.last_name, "Buba");
-        assert_eq!(user.username, "pepe232");
-        assert_eq!(user.gender, Gender::Female);
-        assert_eq!(
-            user.birth_date,
-            Date::from_calendar_date(2001, Month::April, 11)?
-        );
-        assert_eq!(user.email, "joko@nba.com");
-        assert_eq!(user.phone, "7151703730");
-        assert_eq!(user.tax_rate, 0.15);
-        assert_eq!(user.role, UserRole::Admin);
-
-        let result = repository.get_user_by_id(999).await;
-
-        assert!(matches!(result, Err(RepositoryError::NotFound)));
-
-        repository.pool_handler.disconnect().await;
-        Ok(())
+    database_test! {
+        #[fixtures("users")]
+        async fn test_get_user_by_id(database: Database) -> Result<()> {
+            let repository = database.repositories().user;
+
+            let user = repository
+                .get_user_by_id(1)
+                .await
+                .expect("Repository call should succeed");
+
+            assert_eq!(user.id, 1);
+            assert_eq!(user.first_name, "Josefka");
+            assert_eq!(user.last_name, "Buba");
+            assert_eq!(user.username, "pepe232");
+            assert_eq!(user.gender, Gender::Female);
+            assert_eq!(
+                user.birth_date,
+                Date::from_calendar_date(2001, Month::April, 11)?
+            );
+            assert_eq!(user.email, "joko@nba.com");
+            assert_eq!(user.phone, "7151703730");
+            assert_eq!(user.tax_rate, 0.15);
+            assert_eq!(user.role, UserRole::Admin);
+
+            let result = repository.get_user_by_id(999).await;
+
+            assert!(matches!(result, Err(RepositoryError::NotFound)));
+
+            database.close().await;
+            Ok(())
+        }
     }
 
-    #[sqlx::test(fixtures("users"))]
-    async fn test_get_user_username(pool: PgPool) -> Result<()> {
-        let mut repository = PgUserRepository::new(PoolHandler::new(Arc::new(pool)));
-
-        let user = repository
-            .get_user_by_username("brember".to_string())
-            .await
-            .expect("Repository call should succeed");
-
-        assert_eq!(user.id, 2);
-        assert_eq!(user.first_name, "Radek");
-        assert_eq!(user.last_name, "Srejch");
-        assert_eq!(user.username, "brember");
-        assert_eq!(user.gender, Gender::Male);
-        assert_eq!(
-            user.birth_date,
-            Date::from_calendar_date(2000, Month::May, 12)?
-        );
-        assert_eq!(user.email, "brember@mail.com");
-        assert_eq!(user.phone, "2212605075");
-        assert_eq!(user.tax_rate, 0.15);
-        assert_eq!(user.role, UserRole::Employee);
-
-        let result = repository
-            .get_user_by_username("raketaMarketa".to_string())
-            .await;
-
-        assert!(matches!(result, Err(RepositoryError::NotFound)));
-
-        repository.pool_handler.disconnect().await;
-        Ok(())
+    database_test! {
+        #[fixtures("users")]
+        async fn test_get_user_username(database: Database) -> Result<()> {
+            let repository = database.repositories().user;
+
+            let user = repository
+                .get_user_by_username("brember".to_string())
+                .await
+                .expect("Repository call should succeed");
+
+            assert_eq!(user.id, 2);
+            assert_eq!(user.first_name, "Radek");
+            assert_eq!(user.last_name, "Srejch");
+            assert_eq!(user.username, "brember");
+            assert_eq!(user.gender, Gender::Male);
+            assert_eq!(
+                user.birth_date,
+                Date::from_calendar_date(2000, Month::May, 12)?
+            );
+            assert_eq!(user.email, "brember@mail.com");
+            assert_eq!(user.phone, "2212605075");
+            assert_eq!(user.tax_rate, 0.15);
+            assert_eq!(user.role, UserRole::Employee);
+
+            let result = repository
+                .get_user_by_username("raketaMarketa".to_string())
+                .await;
+
+            assert!(matches!(result, Err(RepositoryError::NotFound)));
+
+            database.close().await;
+            Ok(())
+        }
     }
 
-    #[sqlx::test(fixtures("users"))]
-    async fn test_delete_user(pool: PgPool) -> Result<()> {
-        let mut repository = PgUserRepository::new(PoolHandler::new(Arc::new(pool)));
+    database_test! {
+        #[fixtures("users")]
+        async fn test_delete_user(database: Database) -> Result<()> {
+            let repository = database.repositories().user;
+
+            let user = repository.get_user_by_id(1).await;
 
-        let user = repository.get_user_by_id(1).await;
+            assert!(user.is_ok());
 
-        assert!(user.is_ok());
+            repository.delete_user(1).await?;
+            let result = repository.get_user_by_id(1).await;
 
-        repository.delete_user(1).await?;
-        let result = repository.get_user_by_id(1).await;
+            assert!(matches!(result, Err(RepositoryError::NotFound)));
 
-        assert!(matches!(result, Err(RepositoryError::NotFound)));
+            database.close().await;
+            Ok(())
+        }
+    }
+
+    database_test! {
+        #[fixtures("users")]
+        async fn test_update_user(database: Database) -> Result<()> {
+            let repository = database.repositories().user;
+
+            let old_user = repository
+                .get_user_by_id(4)
+                .await
+                .expect("Repository call should succeed");
+
+            let partial = PartialUser {
+                first_name: Some("John".to_string()),
+                last_name: Some("Doe".to_string()),
+                username: Some("IamJonny".to_string()),
+                gender: None,
+                birth_date: None,
+                email: None,
+                phone: None,
+                password_hash: None,
+                role: None,
+                tax_rate: None,
+                avatar_url: None,
+            };
+
+            let updated_user = repository
+                .update_user(4, partial.clone())
+                .await
+                .expect("Repository call should succeed");
+
+            assert_eq!(4, updated_user.id);
+            assert_eq!(partial.first_name.unwrap(), updated_user.first_name);
+            assert_eq!(partial.last_name.unwrap(), updated_user.last_name);
+            assert_eq!(partial.username.unwrap(), updated_user.username);
+            assert_eq!(old_user.gender, updated_user.gender);
+            assert_eq!(old_user.birth_date, updated_user.birth_date);
+            assert_eq!(old_user.email, updated_user.email);
+            assert_eq!(old_user.phone, updated_user.phone);
+            assert_eq!(old_user.password_hash, updated_user.password_hash);
+            assert_eq!(old_user.role, updated_user.role);
+            assert_eq!(old_user.tax_rate, updated_user.tax_rate);
+
+            let empty_partial = PartialUser {
+                first_name: None,
+                last_name: None,
+                username: None,
+                gender: None,
+                birth_date: None,
+                email: None,
+                phone: None,
+                password_hash: None,
+                role: None,
+                tax_rate: None,
+                avatar_url: None,
+            };
+
+            let result = repository.update_user(999, empty_partial).await;
+
+            assert!(matches!(result, Err(RepositoryError::NotFound)));
+
+            database.close().await;
+            Ok(())
+        }
+    }
 
-        repository.pool_handler.disconnect().await;
-        Ok(())
+    database_test! {
+        #[fixtures("users")]
+        async fn test_restore_user(database: Database) -> Result<()> {
+            let repository = database.repositories().user;
+
+            repository.delete_user(2).await?;
+            let result = repository.get_user_by_username("brember".to_string()).await;
+            assert!(matches!(result, Err(RepositoryError::NotFound)));
+
+            let deleted = repository
+                .list_deleted_users(1, &ListSpec::default())
+                .await
+                .expect("Repository call should succeed")
+                .items;
+            assert_eq!(deleted.len(), 1);
+
+            repository
+                .restore_user(2, 1)
+                .await
+                .expect("Repository call should succeed");
+            assert!(repository.get_user_by_id(2).await.is_ok());
+
+            database.close().await;
+            Ok(())
+        }
     }
 
-    #[sqlx::test(fixtures("users"))]
-    async fn test_update_user(pool: PgPool) -> Result<()> {
-        let mut repository = PgUserRepository::new(PoolHandler::new(Arc::new(pool)));
-
-        let old_user = repository
-            .get_user_by_id(4)
-            .await
-            .expect("Repository call should succeed");
-
-        let partial = PartialUser {
-            first_name: Some("John".to_string()),
-            last_name: Some("Doe".to_string()),
-            username: Some("IamJonny".to_string()),
-            gender: None,
-            birth_date: None,
-            email: None,
-            phone: None,
-            password_hash: None,
-            role: None,
-            tax_rate: None,
-            avatar_url: None,
-        };
-
-        let updated_user = repository
-            .update_user(4, partial.clone())
-            .await
-            .expect("Repository call should succeed");
-
-        assert_eq!(4, updated_user.id);
-        assert_eq!(partial.first_name.unwrap(), updated_user.first_name);
-        assert_eq!(partial.last_name.unwrap(), updated_user.last_name);
-        assert_eq!(partial.username.unwrap(), updated_user.username);
-        assert_eq!(old_user.gender, updated_user.gender);
-        assert_eq!(old_user.birth_date, updated_user.birth_date);
-        assert_eq!(old_user.email, updated_user.email);
-        assert_eq!(old_user.phone, updated_user.phone);
-        assert_eq!(old_user.password_hash, updated_user.password_hash);
-        assert_eq!(old_user.role, updated_user.role);
-        assert_eq!(old_user.tax_rate, updated_user.tax_rate);
-
-        let empty_partial = PartialUser {
-            first_name: None,
-            last_name: None,
-            username: None,
-            gender: None,
-            birth_date: None,
-            email: None,
-            phone: None,
-            password_hash: None,
-            role: None,
-            tax_rate: None,
-            avatar_url: None,
-        };
-
-        let result = repository.update_user(999, empty_partial).await;
-
-        assert!(matches!(result, Err(RepositoryError::NotFound)));
-
-        repository.pool_handler.disconnect().await;
-        Ok(())
+    database_test! {
+        #[fixtures("users")]
+        async fn test_list_users_page(database: Database) -> Result<()> {
+            let repository = database.repositories().user;
+
+            let filter = SelectManyFilter {
+                organization_id: None,
+                username: None,
+                first_name: None,
+                last_name: None,
+                gender: None,
+                role: None,
+                tax_rate: None,
+                last_name_contains: None,
+                username_contains: None,
+            };
+            let mut spec = ListSpec {
+                sort_by: SortColumn::Username,
+                sort_direction: SortDirection::Asc,
+                page_size: 2,
+                position: PagePosition::Page(1),
+            };
+
+            let first = repository
+                .list_users_page(filter.clone(), &spec)
+                .await
+                .expect("Repository call should succeed");
+            let usernames: Vec<String> = first.items.iter().map(|u| u.username.clone()).collect();
+            assert_eq!(usernames, vec!["brember", "fousek"]);
+            assert_eq!(first.total, 4);
+            assert_eq!(first.total_pages(), 2);
+            assert_eq!(first.next_cursor, Some(4));
+
+            spec.position = PagePosition::Page(2);
+            let second = repository.list_users_page(filter.clone(), &spec).await?;
+            let usernames: Vec<String> = second.items.iter().map(|u| u.username.clone()).collect();
+            assert_eq!(usernames, vec!["lasicak", "pepe232"]);
+
+            // The cursor continues where the first page ended.
+            spec.position = PagePosition::After(4);
+            let after = repository.list_users_page(filter.clone(), &spec).await?;
+            let usernames: Vec<String> = after.items.iter().map(|u| u.username.clone()).collect();
+            assert_eq!(usernames, vec!["lasicak", "pepe232"]);
+            assert_eq!(after.total, 4);
+
+            spec.sort_direction = SortDirection::Desc;
+            spec.position = PagePosition::Page(1);
+            let descending = repository.list_users_page(filter.clone(), &spec).await?;
+            assert_eq!(descending.items[0].username, "pepe232");
+
+            let contains = SelectManyFilter {
+                username_contains: Some("EMB".to_string()),
+                ..filter.clone()
+            };
+            let matching = repository.list_users_page(contains, &spec).await?;
+            assert_eq!(matching.total, 1);
+            assert_eq!(matching.items[0].username, "brember");
+
+            // Wildcards typed by the user are matched literally.
+            let wildcard = SelectManyFilter {
+                username_contains: Some("%".to_string()),
+                ..filter.clone()
+            };
+            assert_eq!(repository.list_users_page(wildcard, &spec).await?.total, 0);
+
+            let admins = SelectManyFilter {
+                role: Some(UserRole::Admin),
+                ..filter
+            };
+            assert_eq!(repository.list_users_page(admins, &spec).await?.total, 2);
+
+            database.close().await;
+            Ok(())
+        }
     }
 }